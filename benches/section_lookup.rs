@@ -0,0 +1,81 @@
+//! Compares looking up every section by name in an object with thousands of sections, first
+//! with a linear scan of the section header table for each name (as is done without the `alloc`
+//! feature), and then with [ElfBytes::section_header_by_name], which parses the table once and
+//! answers the rest of the lookups from its cache.
+//!
+//! Run with `cargo bench --bench section_lookup`.
+use elf::abi;
+use elf::builder::ElfBuilder;
+use elf::endian::{AnyEndian, LittleEndian};
+use elf::file::Class;
+use elf::section::SectionHeader;
+use elf::ElfBytes;
+use std::time::{Duration, Instant};
+
+const NUM_SECTIONS: usize = 3_000;
+const ROUNDS: usize = 5;
+
+fn many_sections() -> (Vec<u8>, Vec<String>) {
+    let mut builder = ElfBuilder::new(LittleEndian, Class::ELF64, abi::ET_REL, abi::EM_X86_64);
+    let names: Vec<String> = (0..NUM_SECTIONS)
+        .map(|n| format!(".text.function_{n}"))
+        .collect();
+    for name in names.iter() {
+        let shdr = SectionHeader {
+            sh_type: abi::SHT_PROGBITS,
+            sh_flags: (abi::SHF_ALLOC | abi::SHF_EXECINSTR) as u64,
+            ..Default::default()
+        };
+        builder.add_section(name, shdr, &[0xc3]);
+    }
+    (builder.build().expect("should build"), names)
+}
+
+fn linear_scan(file: &ElfBytes<'_, AnyEndian>, name: &str) -> Option<SectionHeader> {
+    let (shdrs, strtab) = file.section_headers_with_strtab().unwrap();
+    let strtab = strtab.unwrap();
+    shdrs
+        .unwrap()
+        .iter()
+        .find(|shdr| matches!(strtab.get(shdr.sh_name as usize), Ok(found) if found == name))
+}
+
+fn time(mut f: impl FnMut() -> u64) -> (Duration, u64) {
+    let mut best = Duration::MAX;
+    let mut sum = 0;
+    for _ in 0..ROUNDS {
+        let start = Instant::now();
+        sum = f();
+        best = best.min(start.elapsed());
+    }
+    (best, sum)
+}
+
+fn main() {
+    let (data, names) = many_sections();
+
+    let (scanned, scanned_sum) = time(|| {
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&data).unwrap();
+        names
+            .iter()
+            .map(|name| linear_scan(&file, name).unwrap().sh_offset)
+            .sum()
+    });
+    let (cached, cached_sum) = time(|| {
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&data).unwrap();
+        names
+            .iter()
+            .map(|name| {
+                file.section_header_by_name(name)
+                    .unwrap()
+                    .unwrap()
+                    .sh_offset
+            })
+            .sum()
+    });
+    assert_eq!(scanned_sum, cached_sum);
+
+    println!("looking up {NUM_SECTIONS} sections by name (best of {ROUNDS}):");
+    println!("  linear scans: {scanned:?}");
+    println!("  cached:       {cached:?}");
+}
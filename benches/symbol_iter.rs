@@ -0,0 +1,50 @@
+//! Compares iterating a large symbol table with the runtime [AnyEndian] against the
+//! compile-time [LittleEndian] parser, which monomorphizes away the byte order checks.
+//!
+//! Run with `cargo bench --bench symbol_iter`.
+use elf::endian::{AnyEndian, EndianParse, LittleEndian};
+use elf::file::Class;
+use elf::symbol::SymbolTable;
+use std::time::{Duration, Instant};
+
+const NUM_SYMBOLS: usize = 1_000_000;
+const ROUNDS: usize = 20;
+
+fn symbol_table_bytes() -> Vec<u8> {
+    let mut data = Vec::with_capacity(NUM_SYMBOLS * 24);
+    for n in 0..NUM_SYMBOLS as u64 {
+        data.extend_from_slice(&(n as u32).to_le_bytes()); // st_name
+        data.push(0x12); // st_info
+        data.push(0); // st_other
+        data.extend_from_slice(&1u16.to_le_bytes()); // st_shndx
+        data.extend_from_slice(&(0x1000 + n * 16).to_le_bytes()); // st_value
+        data.extend_from_slice(&16u64.to_le_bytes()); // st_size
+    }
+    data
+}
+
+fn iterate<E: EndianParse>(endian: E, data: &[u8]) -> (Duration, u64) {
+    let symtab = SymbolTable::new(endian, Class::ELF64, data);
+    let mut best = Duration::MAX;
+    let mut sum = 0u64;
+    for _ in 0..ROUNDS {
+        let start = Instant::now();
+        sum = symtab.iter().fold(0u64, |acc, sym| {
+            acc.wrapping_add(sym.st_value ^ sym.st_size)
+        });
+        best = best.min(start.elapsed());
+    }
+    (best, sum)
+}
+
+fn main() {
+    let data = symbol_table_bytes();
+
+    let (any, any_sum) = iterate(AnyEndian::Little, &data);
+    let (little, little_sum) = iterate(LittleEndian, &data);
+    assert_eq!(any_sum, little_sum);
+
+    println!("iterating {NUM_SYMBOLS} symbols (best of {ROUNDS}):");
+    println!("  AnyEndian:    {any:?}");
+    println!("  LittleEndian: {little:?}");
+}
@@ -0,0 +1,419 @@
+//! A small `readelf -W` work-alike, built on the crate's readelf-style Display impls.
+//!
+//! Usage: `cargo run --example readelf -- [-h] [-S] [-l] [-s] [-d] [-r] <file>`
+//!
+//! The flags can be combined, like `-hSl`. Each one prints the same table as readelf's flag
+//! of the same name, closely enough to diff against readelf's output. Symbol names are shown
+//! without their `@VERSION` suffixes, and `-l` leaves out the section to segment mapping.
+//!
+//! Like readelf, it carries on past recoverable problems with the file, printing warnings
+//! for them. Sections whose names can't be found are shown as `<no-name>`.
+use elf::abi;
+use elf::endian::AnyEndian;
+use elf::file::Class;
+use elf::parse::{ParseError, ParseOptions};
+use elf::string_table::StringTable;
+use elf::symbol::SymbolTable;
+use elf::to_str::e_type_to_human_str;
+use elf::ElfBytes;
+
+#[derive(Default)]
+struct Options {
+    file_header: bool,
+    section_headers: bool,
+    program_headers: bool,
+    symbols: bool,
+    dynamic: bool,
+    relocations: bool,
+}
+
+fn usage() -> ! {
+    eprintln!("Usage: readelf [-h] [-S] [-l] [-s] [-d] [-r] <file>");
+    std::process::exit(1);
+}
+
+fn main() {
+    let mut options = Options::default();
+    let mut path = None;
+    for arg in std::env::args().skip(1) {
+        match arg.strip_prefix('-') {
+            Some(flags) if !flags.is_empty() => {
+                for flag in flags.chars() {
+                    match flag {
+                        'h' => options.file_header = true,
+                        'S' => options.section_headers = true,
+                        'l' => options.program_headers = true,
+                        's' => options.symbols = true,
+                        'd' => options.dynamic = true,
+                        'r' => options.relocations = true,
+                        _ => usage(),
+                    }
+                }
+            }
+            _ if path.is_none() => path = Some(arg),
+            _ => usage(),
+        }
+    }
+    let path = path.unwrap_or_else(|| usage());
+
+    let data = match std::fs::read(&path) {
+        Ok(data) => data,
+        Err(err) => {
+            eprintln!("readelf: {path}: {err}");
+            std::process::exit(1);
+        }
+    };
+    if let Err(err) = run(&options, &data) {
+        eprintln!("readelf: {path}: {err}");
+        std::process::exit(1);
+    }
+}
+
+fn run(options: &Options, data: &[u8]) -> Result<(), ParseError> {
+    let file = ElfBytes::<AnyEndian>::minimal_parse_with_options(data, ParseOptions::permissive())?;
+    for warning in file.warnings() {
+        eprintln!("readelf: Warning: {warning}");
+    }
+    if options.file_header {
+        println!("{}", file.ehdr);
+    }
+    if options.section_headers {
+        print_section_headers(&file)?;
+    }
+    if options.program_headers {
+        print_program_headers(&file)?;
+    }
+    if options.dynamic {
+        print_dynamic(&file)?;
+    }
+    if options.relocations {
+        print_relocations(&file)?;
+    }
+    if options.symbols {
+        print_symbols(&file)?;
+    }
+    Ok(())
+}
+
+fn print_section_headers(file: &ElfBytes<'_, AnyEndian>) -> Result<(), ParseError> {
+    let (shdrs, strtab) = match file.section_headers_with_strtab()? {
+        (Some(shdrs), strtab) if !shdrs.is_empty() => (shdrs, strtab),
+        _ => {
+            println!("\nThere are no sections in this file.");
+            return Ok(());
+        }
+    };
+    if shdrs.len() == 1 {
+        println!(
+            "There is 1 section header, starting at offset {:#x}:\n",
+            file.ehdr.e_shoff
+        );
+        println!("Section Header:");
+    } else {
+        println!(
+            "There are {} section headers, starting at offset {:#x}:\n",
+            shdrs.len(),
+            file.ehdr.e_shoff
+        );
+        println!("Section Headers:");
+    }
+    match file.ehdr.class {
+        Class::ELF32 => println!(
+            "  [Nr] Name              Type            Addr     Off    Size   ES Flg Lk Inf Al"
+        ),
+        Class::ELF64 => println!(
+            "  [Nr] Name              Type            Address          Off    Size   ES Flg Lk Inf Al"
+        ),
+    }
+    for (index, shdr) in shdrs.iter().enumerate() {
+        let name = section_name(strtab.as_ref(), shdr.sh_name);
+        println!(
+            "  [{index:2}] {name:<17} {}",
+            shdr.display(file.ehdr.class, file.ehdr.e_machine)
+        );
+    }
+    println!("Key to Flags:");
+    println!("  W (write), A (alloc), X (execute), M (merge), S (strings), I (info),");
+    println!("  L (link order), O (extra OS processing required), G (group), T (TLS),");
+    println!("  C (compressed), x (unknown), o (OS specific), E (exclude),");
+    let mut last = String::from("  ");
+    if file.ehdr.osabi == abi::ELFOSABI_GNU || file.ehdr.osabi == abi::ELFOSABI_FREEBSD {
+        last.push_str("R (retain), ");
+    }
+    last.push_str("D (mbind), ");
+    match file.ehdr.e_machine {
+        abi::EM_X86_64 => last.push_str("l (large), "),
+        abi::EM_ARM => last.push_str("y (purecode), "),
+        _ => (),
+    }
+    last.push_str("p (processor specific)");
+    println!("{last}");
+    Ok(())
+}
+
+fn print_program_headers(file: &ElfBytes<'_, AnyEndian>) -> Result<(), ParseError> {
+    let phdrs = match file.segments() {
+        Some(phdrs) if !phdrs.is_empty() => phdrs,
+        _ => {
+            println!("\nThere are no program headers in this file.");
+            return Ok(());
+        }
+    };
+    if let Some(e_type) = elf::to_str::e_type_to_str(file.ehdr.e_type) {
+        // Like readelf, only trust the DF_1_PIE flag to pick PIEs out from shared objects
+        let pie = file.dynamic()?.map_or(false, |dynamic| {
+            dynamic.iter().any(|entry| {
+                entry.d_tag == abi::DT_FLAGS_1 && entry.d_val() & abi::DF_1_PIE as u64 != 0
+            })
+        });
+        let human = match e_type_to_human_str(file.ehdr.e_type) {
+            Some(_) if pie => "Position-Independent Executable file",
+            Some(human) => human,
+            None => "",
+        };
+        println!("\nElf file type is {} ({human})", &e_type[3..]);
+    }
+    println!("Entry point {:#x}", file.ehdr.e_entry);
+    if phdrs.len() == 1 {
+        println!(
+            "There is 1 program header, starting at offset {}\n",
+            file.ehdr.e_phoff
+        );
+    } else {
+        println!(
+            "There are {} program headers, starting at offset {}\n",
+            phdrs.len(),
+            file.ehdr.e_phoff
+        );
+    }
+    println!("Program Headers:");
+    match file.ehdr.class {
+        Class::ELF32 => println!(
+            "  Type           Offset   VirtAddr   PhysAddr   FileSiz MemSiz  Flg Align"
+        ),
+        Class::ELF64 => println!(
+            "  Type           Offset   VirtAddr           PhysAddr           FileSiz  MemSiz   Flg Align"
+        ),
+    }
+    for phdr in phdrs.iter() {
+        println!("  {}", phdr.display(file.ehdr.class, file.ehdr.e_machine));
+        if phdr.p_type == abi::PT_INTERP {
+            if let Some(interp) = file.interpreter_str()?.filter(|interp| !interp.is_empty()) {
+                println!("      [Requesting program interpreter: {interp}]");
+            }
+        }
+    }
+    Ok(())
+}
+
+fn print_dynamic(file: &ElfBytes<'_, AnyEndian>) -> Result<(), ParseError> {
+    let dynamic = match file.dynamic()? {
+        Some(dynamic) => dynamic,
+        None => {
+            println!("\nThere is no dynamic section in this file.");
+            return Ok(());
+        }
+    };
+    let strtab = file.dynamic_string_table()?;
+
+    // readelf stops at the DT_NULL which ends the table, but includes it
+    let count = dynamic
+        .iter()
+        .position(|entry| entry.d_tag == abi::DT_NULL)
+        .map_or(dynamic.len(), |index| index + 1);
+    let offset = file
+        .segments()
+        .and_then(|phdrs| phdrs.iter().find(|phdr| phdr.p_type == abi::PT_DYNAMIC))
+        .map_or(0, |phdr| phdr.p_offset);
+    println!("\nDynamic section at offset {offset:#x} contains {count} entries:");
+    println!("  Tag        Type                         Name/Value");
+    for entry in dynamic.iter().take(count) {
+        let label = match entry.d_tag {
+            abi::DT_NEEDED => "Shared library",
+            abi::DT_SONAME => "Library soname",
+            abi::DT_RPATH => "Library rpath",
+            abi::DT_RUNPATH => "Library runpath",
+            abi::DT_CONFIG => "Configuration file",
+            abi::DT_DEPAUDIT => "Dependency audit library",
+            abi::DT_AUDIT => "Audit library",
+            _ => {
+                println!(" {}", entry.display(file.ehdr.class, file.ehdr.e_machine));
+                continue;
+            }
+        };
+        // Swap the string table offset at the end of the line for the string itself
+        let line = entry
+            .display(file.ehdr.class, file.ehdr.e_machine)
+            .to_string();
+        let head = &line[..line.rfind(' ').map_or(0, |index| index + 1)];
+        match strtab.and_then(|strtab| strtab.get(entry.d_val() as usize).ok()) {
+            Some(name) => println!(" {head}{label}: [{name}]"),
+            None => println!(" {line}"),
+        }
+    }
+    Ok(())
+}
+
+fn print_relocations(file: &ElfBytes<'_, AnyEndian>) -> Result<(), ParseError> {
+    let (shdrs, shstrtab) = match file.section_headers_with_strtab()? {
+        (Some(shdrs), strtab) => (shdrs, strtab),
+        _ => {
+            println!("\nThere are no relocations in this file.");
+            return Ok(());
+        }
+    };
+    let (class, e_machine) = (file.ehdr.class, file.ehdr.e_machine);
+
+    let mut found = false;
+    for shdr in shdrs.iter() {
+        if shdr.sh_type != abi::SHT_REL && shdr.sh_type != abi::SHT_RELA {
+            continue;
+        }
+        found = true;
+
+        // The relocations' symbols come from the table in sh_link
+        let symbols = match shdrs.get(shdr.sh_link as usize) {
+            Ok(symtab_shdr) if shdr.sh_link != 0 => match symtab_shdr.sh_type {
+                abi::SHT_DYNSYM => file.dynamic_symbol_table()?,
+                abi::SHT_SYMTAB => file.symbol_table()?,
+                _ => None,
+            },
+            _ => None,
+        };
+
+        let relocations: Vec<_> = if shdr.sh_type == abi::SHT_RELA {
+            file.section_data_as_relas(&shdr)?
+                .map(|rela| {
+                    (
+                        rela.display(class, e_machine),
+                        rela.r_sym,
+                        Some(rela.r_addend),
+                    )
+                })
+                .collect()
+        } else {
+            file.section_data_as_rels(&shdr)?
+                .map(|rel| (rel.display(class, e_machine), rel.r_sym, None))
+                .collect()
+        };
+        let entries = if relocations.len() == 1 {
+            "entry"
+        } else {
+            "entries"
+        };
+        println!(
+            "\nRelocation section '{}' at offset {:#x} contains {} {entries}:",
+            section_name(shstrtab.as_ref(), shdr.sh_name),
+            shdr.sh_offset,
+            relocations.len()
+        );
+        let addend_header = if shdr.sh_type == abi::SHT_RELA {
+            " + Addend"
+        } else {
+            ""
+        };
+        match class {
+            Class::ELF32 => println!(
+                " Offset     Info    Type                Sym. Value  Symbol's Name{addend_header}"
+            ),
+            Class::ELF64 => println!(
+                "    Offset             Info             Type               Symbol's Value  Symbol's Name{addend_header}"
+            ),
+        }
+
+        for (row, r_sym, addend) in relocations {
+            print!("{row}");
+            match (r_sym, &symbols) {
+                (0, _) | (_, None) => {
+                    if let Some(addend) = addend {
+                        let pad = if class == Class::ELF32 { 12 } else { 20 };
+                        print!("{:pad$}", "");
+                        print_addend(addend, "");
+                    }
+                }
+                (r_sym, Some((symtab, strtab))) => {
+                    let sym = symtab.get(r_sym as usize)?;
+                    let name = symbol_name(file, symtab, strtab, r_sym as usize)?;
+                    match class {
+                        Class::ELF32 => print!(" {:08x}   {name}", sym.st_value),
+                        Class::ELF64 => print!(" {:016x} {name}", sym.st_value),
+                    }
+                    if let Some(addend) = addend {
+                        print_addend(addend, " ");
+                    }
+                }
+            }
+            println!();
+        }
+    }
+    if !found {
+        println!("\nThere are no relocations in this file.");
+    }
+    Ok(())
+}
+
+fn print_addend(addend: i64, sep: &str) {
+    if addend < 0 {
+        print!("{sep}- {:x}", addend.unsigned_abs());
+    } else if sep.is_empty() {
+        print!("{addend:x}");
+    } else {
+        print!("{sep}+ {addend:x}");
+    }
+}
+
+/// Get a section's name, or `<no-name>` like binutils if it can't be found
+fn section_name<'data>(shstrtab: Option<&StringTable<'data>>, sh_name: u32) -> &'data str {
+    shstrtab
+        .and_then(|shstrtab| shstrtab.get(sh_name as usize).ok())
+        .unwrap_or("<no-name>")
+}
+
+/// Get the symbol's name, or the name of its section for section symbols, like readelf
+fn symbol_name<'data>(
+    file: &ElfBytes<'data, AnyEndian>,
+    symtab: &SymbolTable<'data, AnyEndian>,
+    strtab: &StringTable<'data>,
+    index: usize,
+) -> Result<&'data str, ParseError> {
+    let sym = symtab.get(index)?;
+    if sym.st_symtype() == abi::STT_SECTION && sym.st_name == 0 {
+        if let (Some(shdrs), shstrtab) = file.section_headers_with_strtab()? {
+            let shdr = shdrs.get(sym.st_shndx as usize)?;
+            return Ok(section_name(shstrtab.as_ref(), shdr.sh_name));
+        }
+    }
+    strtab.get(sym.st_name as usize)
+}
+
+fn print_symbols(file: &ElfBytes<'_, AnyEndian>) -> Result<(), ParseError> {
+    for (table_name, table) in [
+        (".dynsym", file.dynamic_symbol_table()?),
+        (".symtab", file.symbol_table()?),
+    ] {
+        let (symtab, strtab) = match table {
+            Some(table) => table,
+            None => continue,
+        };
+        let entries = if symtab.len() == 1 {
+            "entry"
+        } else {
+            "entries"
+        };
+        println!(
+            "\nSymbol table '{table_name}' contains {} {entries}:",
+            symtab.len()
+        );
+        match file.ehdr.class {
+            Class::ELF32 => println!("   Num:    Value  Size Type    Bind   Vis      Ndx Name"),
+            Class::ELF64 => {
+                println!("   Num:    Value          Size Type    Bind   Vis      Ndx Name")
+            }
+        }
+        for (index, sym) in symtab.iter().enumerate() {
+            let name = symbol_name(file, &symtab, &strtab, index)?;
+            println!("{index:6}: {} {name}", sym.display(file.ehdr.class));
+        }
+    }
+    Ok(())
+}
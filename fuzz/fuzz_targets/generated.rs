@@ -0,0 +1,75 @@
+#![no_main]
+
+// Parses objects from the structured generator end-to-end. The generator's objects always get
+// through the file header and section table, so this reaches the table and note parsers far
+// more often than mutating raw bytes does. Run with -rss_limit_mb to catch huge allocations.
+
+use elf::abi;
+use elf::endian::AnyEndian;
+use elf::ElfBytes;
+use libfuzzer_sys::arbitrary::Unstructured;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    let mut u = Unstructured::new(data);
+    let elf_data = match elf::fuzz::arbitrary_elf(&mut u) {
+        Ok(elf_data) => elf_data,
+        Err(_) => return,
+    };
+    let file = ElfBytes::<AnyEndian>::minimal_parse(&elf_data).expect("generated objects parse");
+
+    if let Ok((Some(shdrs), Some(strtab))) = file.section_headers_with_strtab() {
+        for shdr in shdrs.iter() {
+            let _ = strtab.get(shdr.sh_name as usize);
+            if shdr.sh_type == abi::SHT_NOTE {
+                if let Ok(notes) = file.section_data_as_notes(&shdr) {
+                    let _: Vec<_> = notes.collect();
+                }
+            }
+            if shdr.sh_type == abi::SHT_RELA {
+                if let Ok(relas) = file.section_data_as_relas(&shdr) {
+                    let _: Vec<_> = relas.collect();
+                }
+            }
+        }
+    }
+
+    if let Some(phdrs) = file.segments() {
+        for phdr in phdrs.iter() {
+            if phdr.p_type == abi::PT_NOTE {
+                if let Ok(notes) = file.segment_data_as_notes(&phdr) {
+                    let _: Vec<_> = notes.collect();
+                }
+            }
+        }
+    }
+
+    if let Ok(common) = file.find_common_data() {
+        if let Some(symtab) = common.symtab {
+            let _: Vec<_> = symtab.iter().collect();
+        }
+
+        if let (Some(dynsyms), Some(dynstrs)) = (common.dynsyms, common.dynsyms_strs) {
+            let names: Vec<&[u8]> = dynsyms
+                .iter()
+                .map(|sym| dynstrs.get_raw(sym.st_name as usize).unwrap_or(b"unk"))
+                .collect();
+
+            if let Some(hash) = common.sysv_hash {
+                for name in names.iter() {
+                    let _ = hash.find(name, &dynsyms, &dynstrs);
+                }
+            }
+
+            if let Some(hash) = common.gnu_hash {
+                for name in names.iter() {
+                    let _ = hash.find(name, &dynsyms, &dynstrs);
+                }
+            }
+        }
+
+        if let Some(dyns) = common.dynamic {
+            let _: Vec<_> = dyns.iter().collect();
+        }
+    }
+});
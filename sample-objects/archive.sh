@@ -0,0 +1,14 @@
+#!/bin/bash
+# A static library with a symbol index and a member name too long for the ar header
+cat > a.c <<EOF
+int add(int a, int b) { return a + b; }
+int counter;
+EOF
+cat > long_member_name.c <<EOF
+extern int add(int, int);
+int twice(int x) { return add(x, x); }
+EOF
+gcc -O2 -c -o a.o a.c
+gcc -O2 -c -o long_member_name.o long_member_name.c
+ar rcsD libarchive.x86_64.a a.o long_member_name.o
+rm a.c a.o long_member_name.c long_member_name.o
@@ -0,0 +1,11 @@
+#!/bin/bash
+# RISC-V object with the build attributes for -march=rv64imac
+llvm-mc -triple=riscv64 -mattr=+m,+a,+c -filetype=obj -o attributes.riscv64.o - <<EOF
+.attribute arch, "rv64i2p0_m2p0_a2p0_c2p0"
+.attribute stack_align, 16
+.attribute unaligned_access, 0
+.text
+.globl f
+f:
+    ret
+EOF
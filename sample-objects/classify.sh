@@ -0,0 +1,11 @@
+#!/bin/bash
+# PIE, static, static-pie, and compressed-debuginfo (SHF_COMPRESSED and .zdebug) objects for the classification helpers
+cat > classify.c <<EOF
+void _start(void) { __asm__ volatile("mov \$60, %eax; xor %edi, %edi; syscall"); }
+EOF
+gcc -O2 -nostdlib -pie -fPIE -Wl,-z,noseparate-code -Wl,--build-id=none -o pie.x86_64 classify.c
+gcc -O2 -nostdlib -static -Wl,-z,noseparate-code -Wl,--build-id=none -o static.x86_64 classify.c
+gcc -O2 -nostdlib -static-pie -fPIE -Wl,-z,noseparate-code -Wl,--build-id=none -o static_pie.x86_64 classify.c
+gcc -O2 -g -gz=zlib -c -o debug_gz.x86_64.o classify.c
+objcopy --compress-debug-sections=zlib-gnu debug_gz.x86_64.o debug_zdebug.x86_64.o
+rm classify.c
@@ -0,0 +1,3 @@
+#!/bin/bash
+objcopy --only-keep-debug basic.x86_64 basic.x86_64.debug
+objcopy --strip-debug --add-gnu-debuglink=basic.x86_64.debug basic.x86_64 debuglink.x86_64
@@ -0,0 +1,40 @@
+#!/bin/bash
+# readelf-derived listings of the table entries and their indexes, for checking that the
+# table iterators yield every entry at its on-disk position
+mkdir -p golden
+for obj in basic.x86_64 symver.m68k.so; do
+    {
+        readelf -SW "$obj" | sed 's/\[ */[/' | awk '
+            function h(x) { sub(/^0x/, "", x); sub(/^0+/, "", x); return x == "" ? "0" : x }
+            $1 ~ /^\[[0-9]+\]$/ {
+                idx = substr($1, 2, length($1) - 2)
+                if ($2 == "NULL") { print "section", idx, h($3), h($4), h($5) }
+                else { print "section", idx, h($4), h($5), h($6), $2 }
+            }'
+        readelf -lW "$obj" | awk '
+            function h(x) { sub(/^0x/, "", x); sub(/^0+/, "", x); return x == "" ? "0" : x }
+            $2 ~ /^0x/ { print "segment", n++, h($2), h($3), h($5), h($6) }'
+        readelf -sW "$obj" | awk '
+            function h(x) { sub(/^0x/, "", x); sub(/^0+/, "", x); return x == "" ? "0" : x }
+            /^Symbol table/ { table = substr($3, 2, length($3) - 2) }
+            $1 ~ /^[0-9]+:$/ {
+                ndx = $7
+                if (ndx == "UND") ndx = 0
+                else if (ndx == "ABS") ndx = 65521
+                else if (ndx == "COM") ndx = 65522
+                name = $8
+                # readelf appends the symbol versions to the .dynsym names
+                if (table == ".dynsym") sub(/@.*/, "", name)
+                # readelf switches to hex for large sizes
+                size = $3 ~ /^0x/ ? h($3) : sprintf("%x", $3)
+                print "symbol", table, substr($1, 1, length($1) - 1), h($2), size, ndx, name
+            }' | sed 's/ $//'
+        readelf -dW "$obj" | awk '
+            function h(x) { sub(/^0x/, "", x); sub(/^0+/, "", x); return x == "" ? "0" : x }
+            $1 ~ /^0x/ { print "dynamic", n++, h($1) }'
+        readelf -rW "$obj" | awk '
+            function h(x) { sub(/^0x/, "", x); sub(/^0+/, "", x); return x == "" ? "0" : x }
+            /^Relocation section/ { section = substr($3, 2, length($3) - 2); n = 0 }
+            $1 ~ /^[0-9a-f]+$/ && $2 ~ /^[0-9a-f]+$/ { print "rela", section, n++, h($1), h($2) }'
+    } > "golden/$obj.txt"
+done
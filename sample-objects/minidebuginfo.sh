@@ -0,0 +1,5 @@
+#!/bin/bash
+objcopy --only-keep-debug --strip-debug basic.x86_64 mini
+xz mini
+objcopy --strip-all --add-section .gnu_debugdata=mini.xz basic.x86_64 minidebuginfo.x86_64
+rm mini.xz
@@ -0,0 +1,12 @@
+#!/bin/bash
+# MIPS objects with .MIPS.abiflags sections, and a .reginfo section for the 32-bit one
+cat > mips.s <<EOF
+.text
+.globl f
+f:
+    jr \$ra
+    nop
+EOF
+llvm-mc -triple=mips -mcpu=mips32r2 -mattr=+fpxx -filetype=obj -o abiflags.mips.o mips.s
+llvm-mc -triple=mips64el -mcpu=mips64r2 -filetype=obj -o abiflags.mips64el.o mips.s
+rm mips.s
@@ -0,0 +1,11 @@
+#!/bin/bash
+# Shared library with DT_SONAME, DT_RUNPATH, and a versioned DT_NEEDED for the patch tests
+cat > patch.c <<EOF
+#include <stdio.h>
+void hello(void) { puts("hello"); }
+EOF
+gcc -O2 -shared -fPIC -nostartfiles -Wl,-z,noseparate-code -Wl,--build-id=none \
+    -Wl,-soname,libpatch.so.1 -Wl,-rpath,/opt/patch/lib -Wl,--enable-new-dtags \
+    -o patch.x86_64.so patch.c
+strip patch.x86_64.so
+rm patch.c
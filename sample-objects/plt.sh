@@ -0,0 +1,13 @@
+#!/bin/bash
+# Executable with an IBT-enabled .plt.sec and an IRELATIVE PLT relocation for a local ifunc
+cat > plt.c <<EOF
+#include <stdio.h>
+#include <string.h>
+static int impl(void) { return 1; }
+static int (*resolve(void))(void) { return impl; }
+int ifunc_fn(void) __attribute__((ifunc("resolve")));
+int main(int argc, char **argv) { puts(argv[0]); return ifunc_fn() + (int)strlen(argv[0]); }
+EOF
+gcc -O2 -fcf-protection=full -Wl,-z,ibtplt -Wl,-z,noseparate-code -Wl,--build-id=none -o plt.x86_64 plt.c
+strip plt.x86_64
+rm plt.c
@@ -0,0 +1,13 @@
+#!/bin/bash
+# Shared object with an .sframe section and PT_GNU_SFRAME segment
+cat > sframe.c <<EOF
+int f(int x) { return x * 2; }
+int g(int *p) {
+    int a[64];
+    for (int i = 0; i < 64; i++)
+        a[i] = p[i];
+    return a[p[0] & 63];
+}
+EOF
+gcc -O1 -shared -fPIC -nostdlib -Wa,--gsframe -o sframe.x86_64.so sframe.c
+rm sframe.c
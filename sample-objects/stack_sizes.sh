@@ -0,0 +1,17 @@
+#!/bin/bash
+# Objects with per-function .stack_sizes sections, as emitted by -fstack-size-section
+cat > stack_sizes.ll <<EOF
+define void @small() noredzone nounwind {
+  %a = alloca [16 x i8]
+  call void asm sideeffect "", "r"([16 x i8]* %a)
+  ret void
+}
+define void @big() noredzone nounwind {
+  %a = alloca [256 x i8]
+  call void asm sideeffect "", "r"([256 x i8]* %a)
+  ret void
+}
+EOF
+llc -mtriple=x86_64-unknown-linux-gnu -stack-size-section -function-sections -filetype=obj -o stack_sizes.x86_64.o stack_sizes.ll
+ld -e big -o stack_sizes.x86_64 stack_sizes.x86_64.o
+rm stack_sizes.ll
@@ -0,0 +1,10 @@
+#!/bin/bash
+# Shared library with a text relocation and an executable stack
+cat > textrel.c <<EOF
+extern int counter;
+__asm__(".text\n.globl get_counter_addr\nget_counter_addr:\n\tmovq table(%rip), %rax\n\tret\ntable:\n\t.quad counter\n");
+int counter = 1;
+EOF
+gcc -shared -nostdlib -Wl,-z,notext -Wl,-z,execstack -Wl,-z,noseparate-code -Wl,--build-id=none -o textrel.x86_64.so textrel.c
+strip textrel.x86_64.so
+rm textrel.c
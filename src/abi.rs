@@ -98,7 +98,15 @@ pub const ELFOSABI_FENIXOS: u8 = 16;
 pub const ELFOSABI_CLOUDABI: u8 = 17;
 /// Stratus Technologies OpenVOS
 pub const ELFOSABI_OPENVOS: u8 = 18;
-/// 64-255 Architecture-specific value range
+/// NVIDIA CUDA architecture
+pub const ELFOSABI_CUDA: u8 = 51;
+// 64-255 Architecture-specific value range
+/// ARM EABI
+pub const ELFOSABI_ARM_AEABI: u8 = 64;
+/// ARM
+pub const ELFOSABI_ARM: u8 = 97;
+/// Standalone (embedded) application
+pub const ELFOSABI_STANDALONE: u8 = 255;
 
 // ET_* define constants for the ELF File Header's e_type field.
 // Represented as Elf32_Half in Elf32_Ehdr and Elf64_Half in Elf64_Ehdr which
@@ -500,8 +508,44 @@ pub const EM_MOXIE: u16 = 223;
 pub const EM_AMDGPU: u16 = 224;
 /// RISC-V
 pub const EM_RISCV: u16 = 243;
+/// Lanai 32-bit processor
+pub const EM_LANAI: u16 = 244;
+/// CEVA Processor Architecture Family
+pub const EM_CEVA: u16 = 245;
+/// CEVA X2 Processor Family
+pub const EM_CEVA_X2: u16 = 246;
 /// Linux BPF
 pub const EM_BPF: u16 = 247;
+/// Graphcore Intelligent Processing Unit
+pub const EM_GRAPHCORE_IPU: u16 = 248;
+/// Imagination Technologies
+pub const EM_IMG1: u16 = 249;
+/// Netronome Flow Processor
+pub const EM_NFP: u16 = 250;
+/// NEC Vector Engine
+pub const EM_VE: u16 = 251;
+/// C-SKY processor family
+pub const EM_CSKY: u16 = 252;
+/// Synopsys ARCv2.3 64-bit
+pub const EM_ARC_COMPACT3_64: u16 = 253;
+/// MOS Technology MCS 6502 processor
+pub const EM_MCS6502: u16 = 254;
+/// Synopsys ARCv2.3 32-bit
+pub const EM_ARC_COMPACT3: u16 = 255;
+/// Kalray VLIW core of the MPPA processor family
+pub const EM_KVX: u16 = 256;
+/// WDC 65816/65C816
+pub const EM_65816: u16 = 257;
+/// LoongArch
+pub const EM_LOONGARCH: u16 = 258;
+/// ChipON KungFu32
+pub const EM_KF32: u16 = 259;
+/// LAPIS nX-U16/U8
+pub const EM_U16_U8CORE: u16 = 260;
+/// Tachyum
+pub const EM_TACHYUM: u16 = 261;
+/// NXP 56800EF Digital Signal Controller (DSC)
+pub const EM_56800EF: u16 = 262;
 
 // EV_* define constants for the ELF File Header's e_version field.
 // Represented as Elf32_Word in Elf32_Ehdr and Elf64_Word in Elf64_Ehdr which
@@ -563,6 +607,8 @@ pub const PT_GNU_STACK: u32 = 0x6474e551;
 pub const PT_GNU_RELRO: u32 = 0x6474e552;
 /// The segment contains .note.gnu.property section
 pub const PT_GNU_PROPERTY: u32 = 0x6474e553;
+/// The segment contains the .sframe stack trace section
+pub const PT_GNU_SFRAME: u32 = 0x6474e554;
 /// Values between [PT_LOOS, PT_HIOS] in this inclusive range are reserved for
 /// operating system-specific semantics.
 pub const PT_LOOS: u32 = 0x60000000;
@@ -614,8 +660,12 @@ pub const SHT_PREINIT_ARRAY: u32 = 16;
 pub const SHT_GROUP: u32 = 17;
 /// Extended symbol table section index
 pub const SHT_SYMTAB_SHNDX: u32 = 18;
+/// Relative relocation entries in the compact RELR format
+pub const SHT_RELR: u32 = 19;
 /// Values in [SHT_LOOS, SHT_HIOS] are reserved for operating system-specific semantics.
 pub const SHT_LOOS: u32 = 0x60000000;
+/// SFrame stack trace information
+pub const SHT_GNU_SFRAME: u32 = 0x6ffffff4;
 /// Object attributes
 pub const SHT_GNU_ATTRIBUTES: u32 = 0x6ffffff5;
 /// GNU-style hash section
@@ -646,11 +696,25 @@ pub const SHT_HIUSER: u32 = 0x8fffffff;
 /// This value marks an undefined, missing, irrelevant, or otherwise meaningless
 /// section reference.
 pub const SHN_UNDEF: u16 = 0;
+/// This value specifies the lower bound of the range of reserved section indexes.
+pub const SHN_LORESERVE: u16 = 0xff00;
+/// Values in [SHN_LOPROC, SHN_HIPROC] are reserved for processor-specific semantics.
+pub const SHN_LOPROC: u16 = 0xff00;
+/// Values in [SHN_LOPROC, SHN_HIPROC] are reserved for processor-specific semantics.
+pub const SHN_HIPROC: u16 = 0xff1f;
+/// Values in [SHN_LOOS, SHN_HIOS] are reserved for operating system-specific semantics.
+pub const SHN_LOOS: u16 = 0xff20;
+/// Values in [SHN_LOOS, SHN_HIOS] are reserved for operating system-specific semantics.
+pub const SHN_HIOS: u16 = 0xff3f;
 /// Symbols with st_shndx=SHN_ABS are absolute and are not affected by relocation.
 pub const SHN_ABS: u16 = 0xfff1;
 /// Symbols with st_shndx=SHN_COMMON are sometimes used for unallocated C external variables.
 pub const SHN_COMMON: u16 = 0xfff2;
+/// This value is an escape value. It indicates that the actual section header index is too
+/// large to fit in the containing field and is to be found in another location.
 pub const SHN_XINDEX: u16 = 0xffff;
+/// This value specifies the upper bound of the range of reserved section indexes.
+pub const SHN_HIRESERVE: u16 = 0xffff;
 
 // SHF_* define constants for the ELF Section Header's sh_flags field.
 // Represented as Elf32_Word in Elf32_Ehdr and Elf64_Xword in Elf64_Ehdr which
@@ -946,6 +1010,12 @@ pub const DT_PREINIT_ARRAYSZ: i64 = 33;
 /// This element holds the address of the SHT_SYMTAB_SHNDX section associated
 /// with the dynamic symbol table referenced by the DT_SYMTAB element.
 pub const DT_SYMTAB_SHNDX: i64 = 34;
+/// Total size of the SHT_RELR relative relocation table
+pub const DT_RELRSZ: i64 = 35;
+/// Address of the SHT_RELR relative relocation table
+pub const DT_RELR: i64 = 36;
+/// Size of one entry in the SHT_RELR relative relocation table
+pub const DT_RELRENT: i64 = 37;
 /// Guile offset of GC roots
 pub const DT_GUILE_GC_ROOT: i64 = 0x37146000;
 /// Guile size in machine words of GC roots
@@ -958,6 +1028,8 @@ pub const DT_GUILE_VM_VERSION: i64 = 0x37146003;
 pub const DT_GUILE_FRAME_MAPS: i64 = 0x37146004;
 /// Values in [DT_LOOS, DT_HIOS] are reserved for operating system-specific semantics.
 pub const DT_LOOS: i64 = 0x6000000D;
+/// Tags in [DT_VALRNGLO, DT_VALRNGHI] use the `d_val` of their entries
+pub const DT_VALRNGLO: i64 = 0x6ffffd00;
 /// Prelinking timestamp
 pub const DT_GNU_PRELINKED: i64 = 0x6ffffdf5;
 /// Size of conflict section
@@ -976,6 +1048,10 @@ pub const DT_POSFLAG_1: i64 = 0x6ffffdfd;
 pub const DT_SYMINSZ: i64 = 0x6ffffdfe;
 /// Entry size of syminfo table
 pub const DT_SYMINENT: i64 = 0x6ffffdff;
+/// Tags in [DT_VALRNGLO, DT_VALRNGHI] use the `d_val` of their entries
+pub const DT_VALRNGHI: i64 = 0x6ffffdff;
+/// Tags in [DT_ADDRRNGLO, DT_ADDRRNGHI] use the `d_ptr` of their entries
+pub const DT_ADDRRNGLO: i64 = 0x6ffffe00;
 /// GNU-style hash table
 pub const DT_GNU_HASH: i64 = 0x6ffffef5;
 pub const DT_TLSDESC_PLT: i64 = 0x6ffffef6;
@@ -996,6 +1072,8 @@ pub const DT_PLTPAD: i64 = 0x6ffffefd;
 pub const DT_MOVETAB: i64 = 0x6ffffefe;
 /// Syminfo table
 pub const DT_SYMINFO: i64 = 0x6ffffeff;
+/// Tags in [DT_ADDRRNGLO, DT_ADDRRNGHI] use the `d_ptr` of their entries
+pub const DT_ADDRRNGHI: i64 = 0x6ffffeff;
 pub const DT_VERSYM: i64 = 0x6ffffff0;
 pub const DT_RELACOUNT: i64 = 0x6ffffff9;
 pub const DT_RELCOUNT: i64 = 0x6ffffffa;
@@ -1134,6 +1212,18 @@ pub const VER_FLG_BASE: u16 = 0x1;
 pub const VER_FLG_WEAK: u16 = 0x2;
 pub const VER_FLG_INFO: u16 = 0x4;
 
+// Bit flags which appear in l_flags of the .gnu.liblist entries of prelinked objects
+pub const LL_NONE: u32 = 0;
+/// The library's time stamp and checksum must match exactly
+pub const LL_EXACT_MATCH: u32 = 1 << 0;
+/// Ignore interface version differences
+pub const LL_IGNORE_INT_VER: u32 = 1 << 1;
+/// The library's minor version must match
+pub const LL_REQUIRE_MINOR: u32 = 1 << 2;
+pub const LL_EXPORTS: u32 = 1 << 3;
+pub const LL_DELAY_LOAD: u32 = 1 << 4;
+pub const LL_DELTA: u32 = 1 << 5;
+
 /// ZLIB/DEFLATE
 pub const ELFCOMPRESS_ZLIB: u32 = 1;
 /// zstd algorithm
@@ -1149,6 +1239,20 @@ pub const ELFCOMPRESS_HIPROC: u32 = 0x7fffffff;
 
 /// GNU-extension notes have this name
 pub const ELF_NOTE_GNU: &[u8] = b"GNU\0";
+/// Core file notes with the generic process state have this name
+pub const ELF_NOTE_CORE: &[u8] = b"CORE\0";
+/// Core file notes with Linux-specific process state have this name
+pub const ELF_NOTE_LINUX: &[u8] = b"LINUX\0";
+/// FreeBSD notes have this name
+pub const ELF_NOTE_FREEBSD: &[u8] = b"FreeBSD\0";
+/// NetBSD notes have this name
+pub const ELF_NOTE_NETBSD: &[u8] = b"NetBSD\0";
+/// OpenBSD notes have this name
+pub const ELF_NOTE_OPENBSD: &[u8] = b"OpenBSD\0";
+/// Go toolchain notes have this name
+pub const ELF_NOTE_GO: &[u8] = b"Go\0";
+/// freedesktop.org notes, such as package metadata, have this name
+pub const ELF_NOTE_FDO: &[u8] = b"FDO\0";
 
 // Note header descriptor types constants (n_type)
 
@@ -1279,6 +1383,124 @@ pub const ELF_NOTE_GNU_ABI_TAG_OS_GNU: u32 = 1;
 pub const ELF_NOTE_GNU_ABI_TAG_OS_SOLARIS2: u32 = 2;
 pub const ELF_NOTE_GNU_ABI_TAG_OS_FREEBSD: u32 = 3;
 
+// Go note types, for notes with name ELF_NOTE_GO
+
+/// The Go build ID, as a string
+pub const NT_GO_BUILD_ID: u64 = 4;
+
+// freedesktop.org note types, for notes with name ELF_NOTE_FDO
+// See: https://systemd.io/ELF_PACKAGE_METADATA/
+
+/// The package that the object belongs to, as a NUL-terminated JSON object
+pub const NT_FDO_PACKAGING_METADATA: u64 = 0xcafe1a7e;
+
+// FreeBSD note types, for notes with name ELF_NOTE_FREEBSD
+
+/// The __FreeBSD_version the object was built for, as a 4-byte word
+pub const NT_FREEBSD_ABI_TAG: u64 = 1;
+/// The object doesn't use crt1's initialization (no word)
+pub const NT_FREEBSD_NOINIT_TAG: u64 = 2;
+/// The MACHINE_ARCH the object was built for
+pub const NT_FREEBSD_ARCH_TAG: u64 = 3;
+/// Security feature opt-outs as a 4-byte word, see the NT_FREEBSD_FCTL_* bits
+pub const NT_FREEBSD_FEATURE_CTL: u64 = 4;
+
+// Bits of the NT_FREEBSD_FEATURE_CTL word
+
+/// Disable address space layout randomization
+pub const NT_FREEBSD_FCTL_ASLR_DISABLE: u32 = 0x01;
+/// Don't implicitly limit mappings' maximum protection to their initial protection
+pub const NT_FREEBSD_FCTL_PROTMAX_DISABLE: u32 = 0x02;
+/// Disable the stack gap
+pub const NT_FREEBSD_FCTL_STKGAP_DISABLE: u32 = 0x04;
+/// The object needs writable and executable mappings
+pub const NT_FREEBSD_FCTL_WXNEEDED: u32 = 0x08;
+/// Limit the address space to 48 bits (LA48) on machines with 57-bit addressing
+pub const NT_FREEBSD_FCTL_LA48: u32 = 0x10;
+/// Disable address space guards
+pub const NT_FREEBSD_FCTL_ASG_DISABLE: u32 = 0x20;
+
+// NetBSD note types, for notes with name ELF_NOTE_NETBSD
+
+/// The __NetBSD_Version__ the object was built for, as a 4-byte word
+pub const NT_NETBSD_IDENT: u64 = 1;
+/// PaX security feature flags as a 4-byte word
+pub const NT_NETBSD_PAX: u64 = 3;
+/// The MACHINE_ARCH the object was built for
+pub const NT_NETBSD_MARCH: u64 = 5;
+
+// OpenBSD note types, for notes with name ELF_NOTE_OPENBSD
+
+/// Identifies the object as being built for OpenBSD, with a zero 4-byte word descriptor
+pub const NT_OPENBSD_IDENT: u64 = 1;
+
+// SFrame stack trace format, as found in SHT_GNU_SFRAME sections and PT_GNU_SFRAME segments.
+// See: https://sourceware.org/binutils/docs/sframe-spec.html
+
+/// The magic number at the start of an SFrame section, in the target's byte order
+pub const SFRAME_MAGIC: u16 = 0xdee2;
+pub const SFRAME_VERSION_1: u8 = 1;
+pub const SFRAME_VERSION_2: u8 = 2;
+
+/// The function descriptor entries are sorted by function start address
+pub const SFRAME_F_FDE_SORTED: u8 = 0x1;
+/// All functions in the object preserve the frame pointer
+pub const SFRAME_F_FRAME_POINTER: u8 = 0x2;
+/// The function start addresses are relative to the FDE field holding them, rather than to
+/// the start of the section
+pub const SFRAME_F_FDE_FUNC_START_PCREL: u8 = 0x4;
+
+// Values of the abi/arch field of an SFrame header
+pub const SFRAME_ABI_AARCH64_ENDIAN_BIG: u8 = 1;
+pub const SFRAME_ABI_AARCH64_ENDIAN_LITTLE: u8 = 2;
+pub const SFRAME_ABI_AMD64_ENDIAN_LITTLE: u8 = 3;
+pub const SFRAME_ABI_S390X_ENDIAN_BIG: u8 = 4;
+
+// Values of the FRE type bits of an SFrame FDE's info byte, which give the size of the
+// frame row entries' start address offsets
+pub const SFRAME_FRE_TYPE_ADDR1: u8 = 0;
+pub const SFRAME_FRE_TYPE_ADDR2: u8 = 1;
+pub const SFRAME_FRE_TYPE_ADDR4: u8 = 2;
+
+// Values of the FDE type bit of an SFrame FDE's info byte
+/// The frame row entries' start addresses are offsets from the function start
+pub const SFRAME_FDE_TYPE_PCINC: u8 = 0;
+/// The frame row entries' start addresses are masks, for repetitive code blocks like PLTs
+pub const SFRAME_FDE_TYPE_PCMASK: u8 = 1;
+
+// DWARF exception header pointer encodings, as used by .eh_frame and .eh_frame_hdr.
+// The low 4 bits give the value's format, and the next 3 bits how it's applied.
+// See: https://refspecs.linuxfoundation.org/LSB_5.0.0/LSB-Core-generic/LSB-Core-generic/ehframechpt.html
+
+/// The version of the .eh_frame_hdr format
+pub const EH_FRAME_HDR_VERSION: u8 = 1;
+
+/// An address-sized absolute value
+pub const DW_EH_PE_ABSPTR: u8 = 0x00;
+pub const DW_EH_PE_ULEB128: u8 = 0x01;
+pub const DW_EH_PE_UDATA2: u8 = 0x02;
+pub const DW_EH_PE_UDATA4: u8 = 0x03;
+pub const DW_EH_PE_UDATA8: u8 = 0x04;
+pub const DW_EH_PE_SLEB128: u8 = 0x09;
+pub const DW_EH_PE_SDATA2: u8 = 0x0a;
+pub const DW_EH_PE_SDATA4: u8 = 0x0b;
+pub const DW_EH_PE_SDATA8: u8 = 0x0c;
+/// The value is relative to the address of the field holding it
+pub const DW_EH_PE_PCREL: u8 = 0x10;
+/// The value is relative to the start of the text section
+pub const DW_EH_PE_TEXTREL: u8 = 0x20;
+/// The value is relative to the start of the data section (for .eh_frame_hdr, the start of
+/// the .eh_frame_hdr section itself)
+pub const DW_EH_PE_DATAREL: u8 = 0x30;
+/// The value is relative to the start of the function
+pub const DW_EH_PE_FUNCREL: u8 = 0x40;
+/// The value is aligned to an address-sized boundary
+pub const DW_EH_PE_ALIGNED: u8 = 0x50;
+/// The value is the address of the real value
+pub const DW_EH_PE_INDIRECT: u8 = 0x80;
+/// No value is present
+pub const DW_EH_PE_OMIT: u8 = 0xff;
+
 //     _    ____  __  __
 //    / \  |  _ \|  \/  |
 //   / _ \ | |_) | |\/| |
@@ -1330,6 +1552,9 @@ pub const SHT_ARM_DEBUGOVERLAY: u32 = 0x70000004;
 /// See <https://github.com/ARM-software/abi-aa/blob/main/dbgovl32/dbgovl32.rst>
 pub const SHT_ARM_OVERLAYSECTION: u32 = 0x70000005;
 
+/// The second word of an SHT_ARM_EXIDX entry which marks its function as not unwindable
+pub const EXIDX_CANTUNWIND: u32 = 1;
+
 /// The contents of this section contains only program instructions and no program data.
 ///
 /// If any section contained by a segment does not have the SHF_ARM_PURECODE
@@ -1411,6 +1636,144 @@ pub const DT_ARM_SYMTABSZ: i64 = 0x70000001;
 /// holds the address of the pre-emption map for platforms that use the DLL static binding mode
 pub const DT_ARM_PREEMPTMAP: i64 = 0x70000002;
 
+// Scope tags which group the attributes in a build attributes section's vendor subsection,
+// such as SHT_ARM_ATTRIBUTES.
+
+/// The attributes apply to the whole file
+pub const TAG_FILE: u64 = 1;
+/// The attributes apply to the listed sections
+pub const TAG_SECTION: u64 = 2;
+/// The attributes apply to the listed symbols
+pub const TAG_SYMBOL: u64 = 3;
+
+// Build attribute tags for the "aeabi" vendor subsection of SHT_ARM_ATTRIBUTES sections.
+// See: https://github.com/ARM-software/abi-aa/blob/main/addenda32/addenda32.rst
+/// `Tag_CPU_raw_name`: The raw name of the target CPU, as a string
+pub const TAG_CPU_RAW_NAME: u64 = 4;
+/// `Tag_CPU_name`: The name of the target CPU, as a string
+pub const TAG_CPU_NAME: u64 = 5;
+/// `Tag_CPU_arch`: The target architecture version, see the TAG_CPU_ARCH_* values
+pub const TAG_CPU_ARCH: u64 = 6;
+/// `Tag_CPU_arch_profile`: The target architecture profile: 'A', 'R', 'M', 'S', or 0 for none
+pub const TAG_CPU_ARCH_PROFILE: u64 = 7;
+/// `Tag_ARM_ISA_use`: Whether the code was permitted to use A32 instructions
+pub const TAG_ARM_ISA_USE: u64 = 8;
+/// `Tag_THUMB_ISA_use`: Whether the code was permitted to use T32 instructions
+pub const TAG_THUMB_ISA_USE: u64 = 9;
+/// `Tag_FP_arch`: Which floating point instructions the code was permitted to use
+pub const TAG_FP_ARCH: u64 = 10;
+/// `Tag_WMMX_arch`: Which WMMX instructions the code was permitted to use
+pub const TAG_WMMX_ARCH: u64 = 11;
+/// `Tag_Advanced_SIMD_arch`: Which Advanced SIMD instructions the code was permitted to use
+pub const TAG_ADVANCED_SIMD_ARCH: u64 = 12;
+/// `Tag_PCS_config`: The procedure call standard configuration
+pub const TAG_PCS_CONFIG: u64 = 13;
+/// `Tag_ABI_PCS_R9_use`: How R9 is used
+pub const TAG_ABI_PCS_R9_USE: u64 = 14;
+/// `Tag_ABI_PCS_RW_data`: How writable data is addressed
+pub const TAG_ABI_PCS_RW_DATA: u64 = 15;
+/// `Tag_ABI_PCS_RO_data`: How read-only data is addressed
+pub const TAG_ABI_PCS_RO_DATA: u64 = 16;
+/// `Tag_ABI_PCS_GOT_use`: How imported data is addressed
+pub const TAG_ABI_PCS_GOT_USE: u64 = 17;
+/// `Tag_ABI_PCS_wchar_t`: The size of wchar_t
+pub const TAG_ABI_PCS_WCHAR_T: u64 = 18;
+/// `Tag_ABI_FP_rounding`: Whether the code needs IEEE 754 round-to-nearest only
+pub const TAG_ABI_FP_ROUNDING: u64 = 19;
+/// `Tag_ABI_FP_denormal`: How the code handles denormal numbers
+pub const TAG_ABI_FP_DENORMAL: u64 = 20;
+/// `Tag_ABI_FP_exceptions`: Whether the code checks for IEEE 754 inexact exceptions
+pub const TAG_ABI_FP_EXCEPTIONS: u64 = 21;
+/// `Tag_ABI_FP_user_exceptions`: Whether the code uses IEEE 754 user exceptions
+pub const TAG_ABI_FP_USER_EXCEPTIONS: u64 = 22;
+/// `Tag_ABI_FP_number_model`: Which IEEE 754 numbers the code uses
+pub const TAG_ABI_FP_NUMBER_MODEL: u64 = 23;
+/// `Tag_ABI_align_needed`: The stack and data alignment the code needs
+pub const TAG_ABI_ALIGN_NEEDED: u64 = 24;
+/// `Tag_ABI_align_preserved`: The stack alignment the code preserves
+pub const TAG_ABI_ALIGN_PRESERVED: u64 = 25;
+/// `Tag_ABI_enum_size`: The size of enums
+pub const TAG_ABI_ENUM_SIZE: u64 = 26;
+/// `Tag_ABI_HardFP_use`: Which floating point precisions the code uses
+pub const TAG_ABI_HARDFP_USE: u64 = 27;
+/// `Tag_ABI_VFP_args`: How floating point arguments are passed, see the TAG_ABI_VFP_ARGS_* values
+pub const TAG_ABI_VFP_ARGS: u64 = 28;
+/// `Tag_ABI_WMMX_args`: How WMMX arguments are passed
+pub const TAG_ABI_WMMX_ARGS: u64 = 29;
+/// `Tag_ABI_optimization_goals`: What the code was optimized for
+pub const TAG_ABI_OPTIMIZATION_GOALS: u64 = 30;
+/// `Tag_ABI_FP_optimization_goals`: What the floating point code was optimized for
+pub const TAG_ABI_FP_OPTIMIZATION_GOALS: u64 = 31;
+/// `Tag_compatibility`: A compatibility flag followed by the name of the toolchain it applies to
+pub const TAG_COMPATIBILITY: u64 = 32;
+/// `Tag_CPU_unaligned_access`: Whether the code was permitted to make unaligned accesses
+pub const TAG_CPU_UNALIGNED_ACCESS: u64 = 34;
+/// `Tag_FP_HP_extension`: Whether the code was permitted to use half-precision floating point
+pub const TAG_FP_HP_EXTENSION: u64 = 36;
+/// `Tag_ABI_FP_16bit_format`: The format of 16-bit floating point numbers
+pub const TAG_ABI_FP_16BIT_FORMAT: u64 = 38;
+/// `Tag_MPextension_use`: Whether the code was permitted to use the multiprocessing extension
+pub const TAG_MPEXTENSION_USE: u64 = 42;
+/// `Tag_DIV_use`: Whether the code was permitted to use integer divide instructions
+pub const TAG_DIV_USE: u64 = 44;
+/// `Tag_DSP_extension`: Whether the code was permitted to use the DSP extension
+pub const TAG_DSP_EXTENSION: u64 = 46;
+/// `Tag_MVE_arch`: Which M-profile Vector Extension instructions the code was permitted to use
+pub const TAG_MVE_ARCH: u64 = 48;
+/// `Tag_PAC_extension`: Whether the code was permitted to use pointer authentication instructions
+pub const TAG_PAC_EXTENSION: u64 = 50;
+/// `Tag_BTI_extension`: Whether the code was permitted to use branch target identification instructions
+pub const TAG_BTI_EXTENSION: u64 = 52;
+/// `Tag_nodefaults`: Placeholder (deprecated)
+pub const TAG_NODEFAULTS: u64 = 64;
+/// `Tag_also_compatible_with`: Another architecture that the code is compatible with
+pub const TAG_ALSO_COMPATIBLE_WITH: u64 = 65;
+/// `Tag_T2EE_use`: Whether the code was permitted to use ThumbEE instructions
+pub const TAG_T2EE_USE: u64 = 66;
+/// `Tag_conformance`: The version of the ARM ABI the object conforms to, as a string
+pub const TAG_CONFORMANCE: u64 = 67;
+/// `Tag_Virtualization_use`: Whether the code was permitted to use the virtualization extensions
+pub const TAG_VIRTUALIZATION_USE: u64 = 68;
+/// `Tag_FramePointer_use`: Whether the code uses a frame pointer
+pub const TAG_FRAMEPOINTER_USE: u64 = 72;
+/// `Tag_BTI_use`: Whether the code is compiled with branch target identification
+pub const TAG_BTI_USE: u64 = 74;
+/// `Tag_PACRET_use`: Whether the code is compiled with return address signing
+pub const TAG_PACRET_USE: u64 = 76;
+
+// Values of the Tag_CPU_arch attribute
+pub const TAG_CPU_ARCH_PRE_V4: u64 = 0;
+pub const TAG_CPU_ARCH_V4: u64 = 1;
+pub const TAG_CPU_ARCH_V4T: u64 = 2;
+pub const TAG_CPU_ARCH_V5T: u64 = 3;
+pub const TAG_CPU_ARCH_V5TE: u64 = 4;
+pub const TAG_CPU_ARCH_V5TEJ: u64 = 5;
+pub const TAG_CPU_ARCH_V6: u64 = 6;
+pub const TAG_CPU_ARCH_V6KZ: u64 = 7;
+pub const TAG_CPU_ARCH_V6T2: u64 = 8;
+pub const TAG_CPU_ARCH_V6K: u64 = 9;
+pub const TAG_CPU_ARCH_V7: u64 = 10;
+pub const TAG_CPU_ARCH_V6_M: u64 = 11;
+pub const TAG_CPU_ARCH_V6S_M: u64 = 12;
+pub const TAG_CPU_ARCH_V7E_M: u64 = 13;
+pub const TAG_CPU_ARCH_V8: u64 = 14;
+pub const TAG_CPU_ARCH_V8R: u64 = 15;
+pub const TAG_CPU_ARCH_V8M_BASE: u64 = 16;
+pub const TAG_CPU_ARCH_V8M_MAIN: u64 = 17;
+pub const TAG_CPU_ARCH_V8_1M_MAIN: u64 = 21;
+pub const TAG_CPU_ARCH_V9: u64 = 22;
+
+// Values of the Tag_ABI_VFP_args attribute
+
+/// Floating point arguments are passed in core registers (soft-float)
+pub const TAG_ABI_VFP_ARGS_BASE: u64 = 0;
+/// Floating point arguments are passed in VFP registers (hard-float)
+pub const TAG_ABI_VFP_ARGS_VFP: u64 = 1;
+/// Floating point arguments are passed according to a toolchain-specific convention
+pub const TAG_ABI_VFP_ARGS_TOOLCHAIN: u64 = 2;
+/// The code is compatible with both the base and VFP conventions
+pub const TAG_ABI_VFP_ARGS_COMPATIBLE: u64 = 3;
+
 // ARM relocs
 //
 // * S (when used on its own) is the address of the symbol.
@@ -1964,6 +2327,231 @@ pub const R_AARCH64_TLSDESC: u32 = 1031;
 /// STT_GNU_IFUNC relocation.
 pub const R_AARCH64_IRELATIVE: u32 = 1032;
 
+//   ____      ____  _  ____   __
+//  / ___|    / ___|| |/ /\ \ / /
+// | |   _____\___ \| ' /  \ V /
+// | |__|_____|___) | . \   | |
+//  \____|    |____/|_|\_\  |_|
+//
+// C-SKY specific declarations
+
+/// Object file compatibility attributes
+pub const SHT_CSKY_ATTRIBUTES: u32 = 0x70000001; // SHT_LOPROC + 1;
+pub const SHT_CSKY_ATTRIBUTES_SECTION_NAME: &str = ".csky.attributes";
+
+// Build attribute tags for the "csky" vendor subsection of SHT_CSKY_ATTRIBUTES sections.
+
+/// `Tag_CSKY_ARCH_NAME`: The target architecture name, as a string
+pub const TAG_CSKY_ARCH_NAME: u64 = 4;
+/// `Tag_CSKY_CPU_NAME`: The target CPU name, as a string
+pub const TAG_CSKY_CPU_NAME: u64 = 5;
+/// `Tag_CSKY_ISA_FLAGS`: The bitmask of base instruction sets used
+pub const TAG_CSKY_ISA_FLAGS: u64 = 6;
+/// `Tag_CSKY_ISA_EXT_FLAGS`: The bitmask of instruction set extensions used
+pub const TAG_CSKY_ISA_EXT_FLAGS: u64 = 7;
+/// `Tag_CSKY_DSP_VERSION`: The DSP extension version
+pub const TAG_CSKY_DSP_VERSION: u64 = 8;
+/// `Tag_CSKY_VDSP_VERSION`: The vector DSP extension version
+pub const TAG_CSKY_VDSP_VERSION: u64 = 9;
+/// `Tag_CSKY_FPU_VERSION`: The FPU version
+pub const TAG_CSKY_FPU_VERSION: u64 = 16;
+/// `Tag_CSKY_FPU_ABI`: The floating point calling convention
+pub const TAG_CSKY_FPU_ABI: u64 = 17;
+/// `Tag_CSKY_FPU_ROUNDING`: Whether the code depends on the FPU rounding mode
+pub const TAG_CSKY_FPU_ROUNDING: u64 = 18;
+/// `Tag_CSKY_FPU_DENORMAL`: Whether the code depends on denormal number support
+pub const TAG_CSKY_FPU_DENORMAL: u64 = 19;
+/// `Tag_CSKY_FPU_EXCEPTION`: Whether the code depends on FPU exceptions
+pub const TAG_CSKY_FPU_EXCEPTION: u64 = 20;
+/// `Tag_CSKY_FPU_NUMBER_MODULE`: The floating point number formats used, as a string
+pub const TAG_CSKY_FPU_NUMBER_MODULE: u64 = 21;
+/// `Tag_CSKY_FPU_HARDFP`: Which floating point operations use the FPU
+pub const TAG_CSKY_FPU_HARDFP: u64 = 22;
+
+//  __  __ ___ ____  ____
+// |  \/  |_ _|  _ \/ ___|
+// | |\/| || || |_) \___ \
+// | |  | || ||  __/ ___) |
+// |_|  |_|___|_|   |____/
+//
+// See: https://refspecs.linuxfoundation.org/elf/mipsabi.pdf
+
+/// Shared objects used in link
+pub const SHT_MIPS_LIBLIST: u32 = 0x70000000;
+/// Conflicting symbols
+pub const SHT_MIPS_CONFLICT: u32 = 0x70000002;
+/// Global pointer table
+pub const SHT_MIPS_GPTAB: u32 = 0x70000003;
+/// Reserved for use by the compilation system (ucode)
+pub const SHT_MIPS_UCODE: u32 = 0x70000004;
+/// Section contains debugging information
+pub const SHT_MIPS_DEBUG: u32 = 0x70000005;
+/// Section contains register usage information
+pub const SHT_MIPS_REGINFO: u32 = 0x70000006;
+/// Miscellaneous options
+pub const SHT_MIPS_OPTIONS: u32 = 0x7000000d;
+/// DWARF debugging information
+pub const SHT_MIPS_DWARF: u32 = 0x7000001e;
+/// ABI flags (`.MIPS.abiflags`)
+pub const SHT_MIPS_ABIFLAGS: u32 = 0x7000002a;
+
+// MIPS specific values for the Dyn d_tag field.
+/// Runtime linker interface version
+pub const DT_MIPS_RLD_VERSION: i64 = 0x70000001;
+/// Timestamp
+pub const DT_MIPS_TIME_STAMP: i64 = 0x70000002;
+/// Checksum
+pub const DT_MIPS_ICHECKSUM: i64 = 0x70000003;
+/// Version string (string table index)
+pub const DT_MIPS_IVERSION: i64 = 0x70000004;
+/// Flags
+pub const DT_MIPS_FLAGS: i64 = 0x70000005;
+/// Base address
+pub const DT_MIPS_BASE_ADDRESS: i64 = 0x70000006;
+pub const DT_MIPS_MSYM: i64 = 0x70000007;
+/// Address of CONFLICT section
+pub const DT_MIPS_CONFLICT: i64 = 0x70000008;
+/// Address of LIBLIST section
+pub const DT_MIPS_LIBLIST: i64 = 0x70000009;
+/// Number of local GOT entries
+pub const DT_MIPS_LOCAL_GOTNO: i64 = 0x7000000a;
+/// Number of CONFLICT entries
+pub const DT_MIPS_CONFLICTNO: i64 = 0x7000000b;
+/// Number of LIBLIST entries
+pub const DT_MIPS_LIBLISTNO: i64 = 0x70000010;
+/// Number of DYNSYM entries
+pub const DT_MIPS_SYMTABNO: i64 = 0x70000011;
+/// First external DYNSYM
+pub const DT_MIPS_UNREFEXTNO: i64 = 0x70000012;
+/// First GOT entry in DYNSYM
+pub const DT_MIPS_GOTSYM: i64 = 0x70000013;
+/// Number of GOT page table entries
+pub const DT_MIPS_HIPAGENO: i64 = 0x70000014;
+/// Address of run time loader map
+pub const DT_MIPS_RLD_MAP: i64 = 0x70000016;
+/// Address of the PLT's GOT
+pub const DT_MIPS_PLTGOT: i64 = 0x70000032;
+/// Address of the writable PLT
+pub const DT_MIPS_RWPLT: i64 = 0x70000034;
+/// Relative address of run time loader map
+pub const DT_MIPS_RLD_MAP_REL: i64 = 0x70000035;
+
+// Values of the fp_abi field of the .MIPS.abiflags section
+/// Not tagged or not using any ABIs affected by the differences
+pub const MIPS_ABI_FP_ANY: u8 = 0;
+/// Using hard-float -mdouble-float
+pub const MIPS_ABI_FP_DOUBLE: u8 = 1;
+/// Using hard-float -msingle-float
+pub const MIPS_ABI_FP_SINGLE: u8 = 2;
+/// Using soft-float
+pub const MIPS_ABI_FP_SOFT: u8 = 3;
+/// Using -mips32r2 -mfp64 (deprecated)
+pub const MIPS_ABI_FP_OLD_64: u8 = 4;
+/// Using -mfpxx
+pub const MIPS_ABI_FP_XX: u8 = 5;
+/// Using -mips32r2 -mfp64
+pub const MIPS_ABI_FP_64: u8 = 6;
+/// Using -mips32r2 -mfp64 -mno-odd-spreg
+pub const MIPS_ABI_FP_64A: u8 = 7;
+
+// Values of the gpr_size, cpr1_size and cpr2_size fields of the .MIPS.abiflags section
+pub const AFL_REG_NONE: u8 = 0;
+pub const AFL_REG_32: u8 = 1;
+pub const AFL_REG_64: u8 = 2;
+pub const AFL_REG_128: u8 = 3;
+
+// Bits in the ases field of the .MIPS.abiflags section
+/// DSP ASE
+pub const AFL_ASE_DSP: u32 = 0x00000001;
+/// DSP R2 ASE
+pub const AFL_ASE_DSPR2: u32 = 0x00000002;
+/// Enhanced VA Scheme
+pub const AFL_ASE_EVA: u32 = 0x00000004;
+/// MCU (MicroController) ASE
+pub const AFL_ASE_MCU: u32 = 0x00000008;
+/// MDMX ASE
+pub const AFL_ASE_MDMX: u32 = 0x00000010;
+/// MIPS-3D ASE
+pub const AFL_ASE_MIPS3D: u32 = 0x00000020;
+/// MT ASE
+pub const AFL_ASE_MT: u32 = 0x00000040;
+/// SmartMIPS ASE
+pub const AFL_ASE_SMARTMIPS: u32 = 0x00000080;
+/// VZ ASE
+pub const AFL_ASE_VIRT: u32 = 0x00000100;
+/// MSA ASE
+pub const AFL_ASE_MSA: u32 = 0x00000200;
+/// MIPS16 ASE
+pub const AFL_ASE_MIPS16: u32 = 0x00000400;
+/// MICROMIPS ASE
+pub const AFL_ASE_MICROMIPS: u32 = 0x00000800;
+/// XPA ASE
+pub const AFL_ASE_XPA: u32 = 0x00001000;
+/// DSP R3 ASE
+pub const AFL_ASE_DSPR3: u32 = 0x00002000;
+/// MIPS16e2 ASE
+pub const AFL_ASE_MIPS16E2: u32 = 0x00004000;
+/// CRC ASE
+pub const AFL_ASE_CRC: u32 = 0x00008000;
+/// GINV ASE
+pub const AFL_ASE_GINV: u32 = 0x00020000;
+/// Loongson MMI ASE
+pub const AFL_ASE_LOONGSON_MMI: u32 = 0x00040000;
+/// Loongson CAM ASE
+pub const AFL_ASE_LOONGSON_CAM: u32 = 0x00080000;
+/// Loongson EXT ASE
+pub const AFL_ASE_LOONGSON_EXT: u32 = 0x00100000;
+/// Loongson EXT2 ASE
+pub const AFL_ASE_LOONGSON_EXT2: u32 = 0x00200000;
+
+// Values of the isa_ext field of the .MIPS.abiflags section
+pub const AFL_EXT_XLR: u32 = 1;
+pub const AFL_EXT_OCTEON2: u32 = 2;
+pub const AFL_EXT_OCTEONP: u32 = 3;
+pub const AFL_EXT_LOONGSON_3A: u32 = 4;
+pub const AFL_EXT_OCTEON: u32 = 5;
+pub const AFL_EXT_5900: u32 = 6;
+pub const AFL_EXT_4650: u32 = 7;
+pub const AFL_EXT_4010: u32 = 8;
+pub const AFL_EXT_4100: u32 = 9;
+pub const AFL_EXT_3900: u32 = 10;
+pub const AFL_EXT_10000: u32 = 11;
+pub const AFL_EXT_SB1: u32 = 12;
+pub const AFL_EXT_4111: u32 = 13;
+pub const AFL_EXT_4120: u32 = 14;
+pub const AFL_EXT_5400: u32 = 15;
+pub const AFL_EXT_5500: u32 = 16;
+pub const AFL_EXT_LOONGSON_2E: u32 = 17;
+pub const AFL_EXT_LOONGSON_2F: u32 = 18;
+pub const AFL_EXT_OCTEON3: u32 = 19;
+
+/// Bit in the flags1 field of the .MIPS.abiflags section which is set when the code uses
+/// odd-numbered single-precision FP registers
+pub const AFL_FLAGS1_ODDSPREG: u32 = 1;
+
+// MIPS relocation types. In 64-bit objects, each relocation holds up to three of these
+// which are applied in turn. See [RelocationInfo](crate::relocation::RelocationInfo).
+pub const R_MIPS_NONE: u32 = 0;
+pub const R_MIPS_16: u32 = 1;
+pub const R_MIPS_32: u32 = 2;
+pub const R_MIPS_REL32: u32 = 3;
+pub const R_MIPS_26: u32 = 4;
+pub const R_MIPS_HI16: u32 = 5;
+pub const R_MIPS_LO16: u32 = 6;
+pub const R_MIPS_64: u32 = 18;
+pub const R_MIPS_SUB: u32 = 24;
+pub const R_MIPS_JUMP_SLOT: u32 = 127;
+
+// Values of the r_ssym field of MIPS64 relocations
+/// No special symbol
+pub const RSS_UNDEF: u8 = 0;
+/// The value of the global pointer
+pub const RSS_GP: u8 = 1;
+/// The value of the global pointer before it was changed by the function's prologue
+pub const RSS_GP0: u8 = 2;
+/// The address of the local symbol
+pub const RSS_LOC: u8 = 3;
+
 //  ____                        ____   ____
 // |  _ \ _____      _____ _ __|  _ \ / ___|
 // | |_) / _ \ \ /\ / / _ \ '__| |_) | |
@@ -2440,6 +3028,26 @@ pub const SHT_RISCV_ATTRIBUTES_SECTION_NAME: &str = ".riscv.attributes";
 
 pub const PT_RISCV_ATTRIBUTES: u32 = 0x70000003;
 
+// Build attribute tags for the "riscv" vendor subsection of SHT_RISCV_ATTRIBUTES sections.
+// Even tags have integer values and odd tags have string values.
+
+/// `Tag_RISCV_stack_align`: The stack alignment in bytes
+pub const TAG_RISCV_STACK_ALIGN: u64 = 4;
+/// `Tag_RISCV_arch`: The target ISA string, e.g. "rv64i2p1_m2p0_a2p1_c2p0"
+pub const TAG_RISCV_ARCH: u64 = 5;
+/// `Tag_RISCV_unaligned_access`: Whether the code may make unaligned memory accesses
+pub const TAG_RISCV_UNALIGNED_ACCESS: u64 = 6;
+/// `Tag_RISCV_priv_spec`: The major version of the privileged specification (deprecated)
+pub const TAG_RISCV_PRIV_SPEC: u64 = 8;
+/// `Tag_RISCV_priv_spec_minor`: The minor version of the privileged specification (deprecated)
+pub const TAG_RISCV_PRIV_SPEC_MINOR: u64 = 10;
+/// `Tag_RISCV_priv_spec_revision`: The revision of the privileged specification (deprecated)
+pub const TAG_RISCV_PRIV_SPEC_REVISION: u64 = 12;
+/// `Tag_RISCV_atomic_abi`: Which atomic instruction mapping the code uses
+pub const TAG_RISCV_ATOMIC_ABI: u64 = 14;
+/// `Tag_RISCV_x3_reg_usage`: How the code uses the x3 (gp) register
+pub const TAG_RISCV_X3_REG_USAGE: u64 = 16;
+
 /// Any functions that use registers in a way that is incompatible with the
 /// calling convention of the ABI in use must be annotated with STO_RISCV_VARIANT_CC
 pub const STO_RISCV_VARIANT_CC: u8 = 0x80;
@@ -2561,6 +3169,95 @@ pub const R_RISCV_32_PCREL: u32 = 57;
 /// Relocation against a non-preemptible ifunc symbolifunc_resolver: `(B + A)`
 pub const R_RISCV_IRELATIVE: u32 = 58;
 
+//  ____  ____   _    ____   ____
+// / ___||  _ \ / \  |  _ \ / ___|
+// \___ \| |_) / _ \ | |_) | |
+//  ___) |  __/ ___ \|  _ <| |___
+// |____/|_| /_/   \_\_| \_\____|
+//
+// See: https://docs.oracle.com/cd/E23824_01/html/819-0690/chapter6-54839.html
+
+// SPARC relocation types. In 64-bit objects, the upper 24 bits of the type field hold
+// data for the relocation. See [RelocationInfo](crate::relocation::RelocationInfo).
+pub const R_SPARC_NONE: u32 = 0;
+pub const R_SPARC_8: u32 = 1;
+pub const R_SPARC_16: u32 = 2;
+pub const R_SPARC_32: u32 = 3;
+pub const R_SPARC_WDISP30: u32 = 7;
+pub const R_SPARC_HI22: u32 = 9;
+pub const R_SPARC_13: u32 = 11;
+pub const R_SPARC_LO10: u32 = 12;
+pub const R_SPARC_COPY: u32 = 19;
+pub const R_SPARC_GLOB_DAT: u32 = 20;
+pub const R_SPARC_JMP_SLOT: u32 = 21;
+pub const R_SPARC_RELATIVE: u32 = 22;
+pub const R_SPARC_UA32: u32 = 23;
+pub const R_SPARC_64: u32 = 32;
+/// `(S + A) & 0x3ff + O`, where O is the relocation's type data
+pub const R_SPARC_OLO10: u32 = 33;
+
+//  _  _____  ___    __
+// (_)|___ / ( _ )  / /_
+// | |  |_ \ / _ \ | '_ \
+// | | ___) | (_) || (_) |
+// |_||____/ \___/  \___/
+//
+// See: https://gitlab.com/x86-psABIs/i386-ABI
+
+// i386 reloc types, using the same notation as the x86_64 ones below.
+// i386 objects use SHT_REL, so the addend A is read from the relocated field.
+
+pub const R_386_NONE: u32 = 0;
+/// `S + A`
+pub const R_386_32: u32 = 1;
+/// `S + A - P`
+pub const R_386_PC32: u32 = 2;
+/// `G + A`
+pub const R_386_GOT32: u32 = 3;
+/// `L + A - P`
+pub const R_386_PLT32: u32 = 4;
+pub const R_386_COPY: u32 = 5;
+/// `S`
+pub const R_386_GLOB_DAT: u32 = 6;
+/// `S`
+pub const R_386_JMP_SLOT: u32 = 7;
+/// `B + A`
+pub const R_386_RELATIVE: u32 = 8;
+/// `S + A - GOT`
+pub const R_386_GOTOFF: u32 = 9;
+/// `GOT + A - P`
+pub const R_386_GOTPC: u32 = 10;
+pub const R_386_32PLT: u32 = 11;
+pub const R_386_TLS_TPOFF: u32 = 14;
+pub const R_386_TLS_IE: u32 = 15;
+pub const R_386_TLS_GOTIE: u32 = 16;
+pub const R_386_TLS_LE: u32 = 17;
+pub const R_386_TLS_GD: u32 = 18;
+pub const R_386_TLS_LDM: u32 = 19;
+/// `S + A`, in a 16-bit field
+pub const R_386_16: u32 = 20;
+/// `S + A - P`, in a 16-bit field
+pub const R_386_PC16: u32 = 21;
+/// `S + A`, in an 8-bit field
+pub const R_386_8: u32 = 22;
+/// `S + A - P`, in an 8-bit field
+pub const R_386_PC8: u32 = 23;
+pub const R_386_TLS_LDO_32: u32 = 32;
+pub const R_386_TLS_IE_32: u32 = 33;
+pub const R_386_TLS_LE_32: u32 = 34;
+pub const R_386_TLS_DTPMOD32: u32 = 35;
+pub const R_386_TLS_DTPOFF32: u32 = 36;
+pub const R_386_TLS_TPOFF32: u32 = 37;
+/// `Z + A`
+pub const R_386_SIZE32: u32 = 38;
+pub const R_386_TLS_GOTDESC: u32 = 39;
+pub const R_386_TLS_DESC_CALL: u32 = 40;
+pub const R_386_TLS_DESC: u32 = 41;
+/// `indirect (B + A)`
+pub const R_386_IRELATIVE: u32 = 42;
+/// `G + A`
+pub const R_386_GOT32X: u32 = 43;
+
 //       ___   __      __   _  _
 // __  _( _ ) / /_    / /_ | || |
 // \ \/ / _ \| '_ \  | '_ \| || |_
@@ -0,0 +1,565 @@
+//! Parsing the members of `ar` archives, like static libraries (requires the `archive` feature)
+//!
+//! Static libraries are `ar` archives whose members are ELF relocatable objects. An archive
+//! starts with the [ARCHIVE_MAGIC], followed by the members, each with a 60-byte text header
+//! giving its name and size and padded out to a 2-byte boundary. This parses the GNU (and
+//! System V) variant of the format, where:
+//!
+//! * a member named `/` is the symbol index, mapping the symbols that members define to the
+//!   offsets of their headers, with 32-bit offsets (or `/SYM64/` with 64-bit offsets)
+//! * a member named `//` holds the names which didn't fit in the 16-byte header field, and
+//!   members with such names are named `/<offset into the table>`
+//! * other names are terminated by a `/`
+//!
+//! BSD-style archives, which put long names after the header with a `#1/<len>` name, fail
+//! with a [ParseError::UnsupportedArchiveFormat], as do thin archives.
+//!
+//! Example usage:
+//! ```
+//! use elf::archive::Archive;
+//! use elf::endian::AnyEndian;
+//! use elf::ElfBytes;
+//!
+//! let path = std::path::PathBuf::from("sample-objects/libarchive.x86_64.a");
+//! let file_data = std::fs::read(path).expect("Could not read file.");
+//! let archive = Archive::parse(&file_data).expect("Open archive");
+//!
+//! for member in archive.members() {
+//!     let member = member.expect("member header should parse");
+//!     let file = ElfBytes::<AnyEndian>::minimal_parse(member.data).expect("Open member");
+//!     assert_eq!(file.ehdr.e_type, elf::abi::ET_REL);
+//! }
+//!
+//! // Find which member defines a symbol without parsing them all
+//! let member = archive
+//!     .member_for_symbol(b"add")
+//!     .expect("symbol index should parse")
+//!     .expect("some member should define add");
+//! assert_eq!(member.name, b"a.o");
+//! ```
+use crate::endian::{BigEndian, EndianParse};
+use crate::parse::{ParseError, ReadBytesExt};
+
+/// The magic bytes at the start of an `ar` archive
+pub const ARCHIVE_MAGIC: &[u8] = b"!<arch>\n";
+
+/// The magic bytes at the start of a GNU thin archive, whose members are stored in separate
+/// files
+pub const THIN_ARCHIVE_MAGIC: &[u8] = b"!<thin>\n";
+
+/// The size of a member's header
+pub const MEMBER_HEADER_SIZE: usize = 60;
+
+/// The magic bytes at the end of a member's header
+const MEMBER_HEADER_MAGIC: &[u8] = b"`\n";
+
+/// A member of an `ar` archive
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ArchiveMember<'data> {
+    /// The member's file name
+    pub name: &'data [u8],
+    /// The offset of the member's header in the archive, as referenced by the symbol index
+    pub offset: usize,
+    /// The offset of the member's contents in the archive
+    pub data_offset: usize,
+    /// The member's contents
+    pub data: &'data [u8],
+}
+
+/// The raw fields of a member's header, before its name has been resolved
+#[derive(Debug)]
+struct RawMember<'data> {
+    name: &'data [u8],
+    data_offset: usize,
+    data: &'data [u8],
+    /// The offset of the next member's header
+    next: usize,
+}
+
+/// Parse a space-padded decimal number from a member header field
+fn parse_decimal(field: &[u8], offset: usize) -> Result<usize, ParseError> {
+    let digits = field
+        .iter()
+        .position(|byte| *byte == b' ')
+        .map_or(field, |end| &field[..end]);
+    if digits.is_empty() {
+        return Err(ParseError::BadArchiveHeader(offset));
+    }
+    let mut value: usize = 0;
+    for digit in digits {
+        if !digit.is_ascii_digit() {
+            return Err(ParseError::BadArchiveHeader(offset));
+        }
+        value = value
+            .checked_mul(10)
+            .and_then(|value| value.checked_add((digit - b'0') as usize))
+            .ok_or(ParseError::IntegerOverflow)?;
+    }
+    Ok(value)
+}
+
+/// Parse the header of the member at `offset` along with its contents
+fn parse_member(data: &[u8], offset: usize) -> Result<RawMember<'_>, ParseError> {
+    let header_end = offset
+        .checked_add(MEMBER_HEADER_SIZE)
+        .ok_or(ParseError::IntegerOverflow)?;
+    let header = data.get_bytes(offset..header_end)?;
+    if &header[58..60] != MEMBER_HEADER_MAGIC {
+        return Err(ParseError::BadArchiveHeader(offset));
+    }
+    let name = &header[..16];
+    if name.starts_with(b"#1/") {
+        return Err(ParseError::UnsupportedArchiveFormat(offset));
+    }
+    let size = parse_decimal(&header[48..58], offset)?;
+    let data_end = header_end
+        .checked_add(size)
+        .ok_or(ParseError::IntegerOverflow)?;
+    let member_data = data.get_bytes(header_end..data_end)?;
+    // Members are padded out to an even offset
+    let next = data_end + (data_end & 1);
+    Ok(RawMember {
+        name,
+        data_offset: header_end,
+        data: member_data,
+        next,
+    })
+}
+
+/// Strip the space padding from the end of a member header's name field
+fn trim_name(name: &[u8]) -> &[u8] {
+    let len = name
+        .iter()
+        .rposition(|byte| *byte != b' ')
+        .map_or(0, |i| i + 1);
+    &name[..len]
+}
+
+/// The symbol index of an archive, from its `/` or `/SYM64/` member
+#[derive(Debug, Clone, Copy)]
+pub struct SymbolIndex<'data> {
+    /// The size of each offset, 4 for `/` or 8 for `/SYM64/`
+    offset_size: usize,
+    count: usize,
+    offsets: &'data [u8],
+    names: &'data [u8],
+}
+
+impl<'data> SymbolIndex<'data> {
+    /// Parse the contents of a symbol index member, whose offsets are `offset_size` bytes
+    /// each. The count and offsets are always big-endian.
+    fn parse(data: &'data [u8], offset_size: usize) -> Result<Self, ParseError> {
+        let mut offset = 0;
+        let count: usize = match offset_size {
+            4 => BigEndian.parse_u32_at(&mut offset, data)? as usize,
+            _ => BigEndian.parse_u64_at(&mut offset, data)?.try_into()?,
+        };
+        let offsets_end = count
+            .checked_mul(offset_size)
+            .and_then(|size| size.checked_add(offset))
+            .ok_or(ParseError::IntegerOverflow)?;
+        Ok(SymbolIndex {
+            offset_size,
+            count,
+            offsets: data.get_bytes(offset..offsets_end)?,
+            names: &data[offsets_end..],
+        })
+    }
+
+    /// Get the number of symbols in the index
+    pub fn len(&self) -> usize {
+        self.count
+    }
+
+    /// Returns whether the index has no symbols
+    pub fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+
+    /// Get an iterator over the symbols in the index, as (name, member header offset) pairs.
+    /// Iteration stops early if a name is missing its NUL terminator.
+    pub fn iter(&self) -> SymbolIndexIterator<'data> {
+        SymbolIndexIterator {
+            index: *self,
+            n: 0,
+            names: self.names,
+        }
+    }
+}
+
+/// Iterates over the symbols of a [SymbolIndex]
+#[derive(Debug, Clone)]
+pub struct SymbolIndexIterator<'data> {
+    index: SymbolIndex<'data>,
+    n: usize,
+    names: &'data [u8],
+}
+
+impl<'data> Iterator for SymbolIndexIterator<'data> {
+    type Item = (&'data [u8], u64);
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.n >= self.index.count {
+            return None;
+        }
+        let mut offset = self.n * self.index.offset_size;
+        let member_offset = match self.index.offset_size {
+            4 => BigEndian
+                .parse_u32_at(&mut offset, self.index.offsets)
+                .ok()? as u64,
+            _ => BigEndian
+                .parse_u64_at(&mut offset, self.index.offsets)
+                .ok()?,
+        };
+        let len = self.names.iter().position(|byte| *byte == 0)?;
+        let name = &self.names[..len];
+        self.names = &self.names[len + 1..];
+        self.n += 1;
+        Some((name, member_offset))
+    }
+}
+
+/// An `ar` archive, such as a static library
+#[derive(Debug, Clone, Copy)]
+pub struct Archive<'data> {
+    data: &'data [u8],
+    symbol_index: Option<SymbolIndex<'data>>,
+    /// The contents of the `//` member
+    long_names: Option<&'data [u8]>,
+    /// The offset of the first member after the special ones
+    first_member: usize,
+}
+
+impl<'data> Archive<'data> {
+    /// Parse the archive's magic and its special leading members: the symbol index and the
+    /// long names table.
+    pub fn parse(data: &'data [u8]) -> Result<Self, ParseError> {
+        let magic = data.get_bytes(0..ARCHIVE_MAGIC.len())?;
+        if magic == THIN_ARCHIVE_MAGIC {
+            return Err(ParseError::UnsupportedArchiveFormat(0));
+        }
+        if magic != ARCHIVE_MAGIC {
+            return Err(ParseError::BadMagic([
+                magic[0], magic[1], magic[2], magic[3],
+            ]));
+        }
+
+        let mut archive = Archive {
+            data,
+            symbol_index: None,
+            long_names: None,
+            first_member: ARCHIVE_MAGIC.len(),
+        };
+        while archive.first_member < data.len() {
+            let member = parse_member(data, archive.first_member)?;
+            match trim_name(member.name) {
+                b"/" if archive.symbol_index.is_none() => {
+                    archive.symbol_index = Some(SymbolIndex::parse(member.data, 4)?);
+                }
+                b"/SYM64/" if archive.symbol_index.is_none() => {
+                    archive.symbol_index = Some(SymbolIndex::parse(member.data, 8)?);
+                }
+                b"//" => archive.long_names = Some(member.data),
+                _ => break,
+            }
+            archive.first_member = member.next;
+        }
+        Ok(archive)
+    }
+
+    /// Get the archive's symbol index, if it has one. Archives made without `ar s` or with
+    /// `ar S` don't.
+    pub fn symbol_index(&self) -> Option<SymbolIndex<'data>> {
+        self.symbol_index
+    }
+
+    /// Get an iterator over the archive's regular members, leaving out the symbol index and
+    /// long names table. Iteration stops after yielding an error for a malformed member.
+    pub fn members(&self) -> ArchiveMemberIterator<'data> {
+        ArchiveMemberIterator {
+            archive: *self,
+            offset: self.first_member,
+        }
+    }
+
+    /// Get the member whose header is at the given offset, as referenced by the symbol index
+    pub fn member_at(&self, offset: usize) -> Result<ArchiveMember<'data>, ParseError> {
+        let member = parse_member(self.data, offset)?;
+        Ok(ArchiveMember {
+            name: self.resolve_name(member.name, offset)?,
+            offset,
+            data_offset: member.data_offset,
+            data: member.data,
+        })
+    }
+
+    /// Find the member which defines the given symbol, according to the symbol index.
+    /// Returns an empty Option if the symbol isn't in the index, or there is no index.
+    pub fn member_for_symbol(
+        &self,
+        name: &[u8],
+    ) -> Result<Option<ArchiveMember<'data>>, ParseError> {
+        let index = match self.symbol_index {
+            Some(index) => index,
+            None => return Ok(None),
+        };
+        match index.iter().find(|(symbol, _)| *symbol == name) {
+            Some((_, offset)) => Ok(Some(self.member_at(offset.try_into()?)?)),
+            None => Ok(None),
+        }
+    }
+
+    /// Resolve a member header's name field to the member's name, looking up `/<offset>`
+    /// names in the long names table
+    fn resolve_name(&self, name: &'data [u8], offset: usize) -> Result<&'data [u8], ParseError> {
+        let name = trim_name(name);
+        match name.strip_prefix(b"/") {
+            Some(digits) if !digits.is_empty() => {
+                let long_names = self
+                    .long_names
+                    .ok_or(ParseError::BadArchiveHeader(offset))?;
+                let start = parse_decimal(digits, offset)?;
+                let names = long_names.get_bytes(start..long_names.len())?;
+                let end = names
+                    .iter()
+                    .position(|byte| *byte == b'\n' || *byte == 0)
+                    .unwrap_or(names.len());
+                let name = &names[..end];
+                Ok(name.strip_suffix(b"/").unwrap_or(name))
+            }
+            _ => Ok(name.strip_suffix(b"/").unwrap_or(name)),
+        }
+    }
+}
+
+/// Iterates over the regular members of an [Archive]
+#[derive(Debug, Clone)]
+pub struct ArchiveMemberIterator<'data> {
+    archive: Archive<'data>,
+    offset: usize,
+}
+
+impl<'data> Iterator for ArchiveMemberIterator<'data> {
+    type Item = Result<ArchiveMember<'data>, ParseError>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.offset >= self.archive.data.len() {
+            return None;
+        }
+        let offset = self.offset;
+        match parse_member(self.archive.data, offset) {
+            Ok(member) => {
+                self.offset = member.next;
+                Some(self.archive.member_at(offset))
+            }
+            Err(err) => {
+                self.offset = self.archive.data.len();
+                Some(Err(err))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Build a member header with the given name and size, followed by the data and padding
+    fn member(name: &str, data: &[u8]) -> Vec<u8> {
+        let mut member = format!(
+            "{name:<16}{:<12}{:<6}{:<6}{:<8}{:<10}`\n",
+            0,
+            0,
+            0,
+            644,
+            data.len()
+        )
+        .into_bytes();
+        member.extend_from_slice(data);
+        if data.len() % 2 == 1 {
+            member.push(b'\n');
+        }
+        member
+    }
+
+    #[test]
+    fn fixture_archive() {
+        let data = std::fs::read("sample-objects/libarchive.x86_64.a").expect("should read");
+        let archive = Archive::parse(&data).expect("should parse");
+
+        let members: Vec<_> = archive
+            .members()
+            .map(|member| member.expect("should parse"))
+            .collect();
+        let names: Vec<_> = members.iter().map(|member| member.name).collect();
+        assert_eq!(names, vec![&b"a.o"[..], b"long_member_name.o"]);
+        for member in members.iter() {
+            assert!(member.data.starts_with(b"\x7fELF"));
+            assert_eq!(member.data_offset, member.offset + MEMBER_HEADER_SIZE);
+        }
+
+        let index = archive.symbol_index().expect("should have an index");
+        assert_eq!(index.len(), 3);
+        let symbols: Vec<_> = index.iter().collect();
+        assert_eq!(
+            symbols,
+            vec![
+                (&b"add"[..], members[0].offset as u64),
+                (b"counter", members[0].offset as u64),
+                (b"twice", members[1].offset as u64),
+            ]
+        );
+        assert_eq!(
+            archive.member_for_symbol(b"twice").expect("should parse"),
+            Some(members[1])
+        );
+        assert_eq!(
+            archive.member_for_symbol(b"main").expect("should parse"),
+            None
+        );
+    }
+
+    #[test]
+    fn sym64_index() {
+        let mut index = Vec::new();
+        index.extend_from_slice(&1u64.to_be_bytes());
+        let first_member = ARCHIVE_MAGIC.len() + MEMBER_HEADER_SIZE + 8 + 8 + 6;
+        index.extend_from_slice(&(first_member as u64).to_be_bytes());
+        index.extend_from_slice(b"func\0\0");
+
+        let mut data = ARCHIVE_MAGIC.to_vec();
+        data.extend(member("/SYM64/", &index));
+        assert_eq!(data.len(), first_member);
+        data.extend(member("x.o/", b"odd"));
+        data.extend(member("y.o/", b"even"));
+
+        let archive = Archive::parse(&data).expect("should parse");
+        let index = archive.symbol_index().expect("should have an index");
+        assert_eq!(
+            index.iter().collect::<Vec<_>>(),
+            vec![(&b"func"[..], first_member as u64)]
+        );
+        let found = archive
+            .member_for_symbol(b"func")
+            .expect("should parse")
+            .expect("should find func");
+        assert_eq!((found.name, found.data), (&b"x.o"[..], &b"odd"[..]));
+
+        let members: Vec<_> = archive.members().map(Result::unwrap).collect();
+        assert_eq!(members.len(), 2);
+        assert_eq!(
+            (members[1].name, members[1].data),
+            (&b"y.o"[..], &b"even"[..])
+        );
+    }
+
+    #[test]
+    fn no_index() {
+        let mut data = ARCHIVE_MAGIC.to_vec();
+        data.extend(member("//", b"a_very_long_object_name.o/\n"));
+        data.extend(member("/0", b"data"));
+        let archive = Archive::parse(&data).expect("should parse");
+        assert!(archive.symbol_index().is_none());
+        assert_eq!(archive.member_for_symbol(b"x").expect("should parse"), None);
+        let members: Vec<_> = archive.members().map(Result::unwrap).collect();
+        assert_eq!(members[0].name, b"a_very_long_object_name.o");
+
+        // An empty archive
+        let archive = Archive::parse(ARCHIVE_MAGIC).expect("should parse");
+        assert_eq!(archive.members().count(), 0);
+    }
+
+    #[test]
+    fn unsupported_formats() {
+        let bsd_member = member("#1/20", b"long_bsd_name.o\0\0\0\0\0data");
+        let mut data = ARCHIVE_MAGIC.to_vec();
+        data.extend_from_slice(&bsd_member);
+        assert!(matches!(
+            Archive::parse(&data),
+            Err(ParseError::UnsupportedArchiveFormat(8))
+        ));
+
+        // After a regular member, the iterator yields the error
+        let mut data = ARCHIVE_MAGIC.to_vec();
+        data.extend(member("ok.o/", b"ok"));
+        data.extend_from_slice(&bsd_member);
+        let archive = Archive::parse(&data).expect("should parse");
+        let results: Vec<_> = archive.members().collect();
+        assert_eq!(results.len(), 2);
+        assert!(results[0].is_ok());
+        assert!(matches!(
+            results[1],
+            Err(ParseError::UnsupportedArchiveFormat(70))
+        ));
+
+        let result = Archive::parse(b"!<thin>\n");
+        assert!(matches!(
+            result,
+            Err(ParseError::UnsupportedArchiveFormat(0))
+        ));
+        let result = Archive::parse(b"\x7fELF\x02\x01\x01\x00");
+        assert!(matches!(
+            result,
+            Err(ParseError::BadMagic([0x7f, b'E', b'L', b'F']))
+        ));
+    }
+
+    /// Parse an archive with a regular member followed by the given member, and get the
+    /// error from parsing the second member
+    fn second_member_error(bad_member: &[u8]) -> ParseError {
+        let mut data = ARCHIVE_MAGIC.to_vec();
+        data.extend(member("ok.o/", b"ok"));
+        data.extend_from_slice(bad_member);
+        let archive = Archive::parse(&data).expect("should parse");
+        let mut members = archive.members();
+        members
+            .next()
+            .expect("should have a member")
+            .expect("should parse");
+        let err = members
+            .next()
+            .expect("should have a second member")
+            .expect_err("should fail to parse");
+        assert!(members.next().is_none());
+        err
+    }
+
+    #[test]
+    fn bad_headers() {
+        // A bad header magic
+        let mut bad = member("x.o/", b"data");
+        bad[58] = b'X';
+        assert!(matches!(
+            second_member_error(&bad),
+            ParseError::BadArchiveHeader(70)
+        ));
+
+        // A non-numeric size
+        let mut bad = member("x.o/", b"data");
+        bad[48] = b'z';
+        assert!(matches!(
+            second_member_error(&bad),
+            ParseError::BadArchiveHeader(70)
+        ));
+
+        // A size that runs past the end of the archive
+        let mut bad = member("x.o/", b"data");
+        bad.truncate(bad.len() - 1);
+        assert!(matches!(
+            second_member_error(&bad),
+            ParseError::SliceReadError(_)
+        ));
+
+        // A long name without a long names table
+        assert!(matches!(
+            second_member_error(&member("/0", b"data")),
+            ParseError::BadArchiveHeader(70)
+        ));
+
+        // A symbol index whose offsets run past its end
+        let mut data = ARCHIVE_MAGIC.to_vec();
+        data.extend(member("/", &[0, 0, 0, 2, 0, 0, 0, 8]));
+        assert!(matches!(
+            Archive::parse(&data),
+            Err(ParseError::SliceReadError(_))
+        ));
+    }
+}
@@ -0,0 +1,257 @@
+//! Parsing the `.ARM.exidx` exception index table (`SHT_ARM_EXIDX`/`PT_ARM_EXIDX`)
+//!
+//! The exception index table has one 8-byte entry per function, sorted by function address.
+//! The first word is a prel31 offset from the entry to the start of the function. The second
+//! word is one of:
+//!
+//! * [EXIDX_CANTUNWIND](crate::abi::EXIDX_CANTUNWIND), meaning the function can't be unwound
+//! * An inline unwind table entry in the compact model, marked by its high bit being set
+//! * A prel31 offset from the second word to the function's entry in the `.ARM.extab` section
+//!
+//! See: <https://github.com/ARM-software/abi-aa/blob/main/ehabi32/ehabi32.rst>
+//!
+//! Example usage:
+//! ```
+//! use elf::arm_exidx::{ExidxEntry, ExidxEntryData, ExidxTable};
+//! use elf::endian::LittleEndian;
+//!
+//! // An entry at 0x1000 for a function at 0x0ff0 which can't be unwound
+//! let data = [0xf0, 0xff, 0xff, 0x7f, 0x01, 0x00, 0x00, 0x00];
+//! let table = ExidxTable::new(LittleEndian, 0x1000, &data).expect("should parse");
+//! assert_eq!(
+//!     table.get(0).expect("should parse"),
+//!     ExidxEntry {
+//!         fn_addr: 0x0ff0,
+//!         data: ExidxEntryData::CantUnwind,
+//!     }
+//! );
+//! ```
+use crate::abi;
+use crate::endian::EndianParse;
+use crate::parse::ParseError;
+
+/// The size of an exception index table entry
+pub const EXIDX_ENTRY_SIZE: usize = 8;
+
+/// Resolve a prel31 value, which is a 31-bit signed offset from `place` (the address of the
+/// word holding the value). The high bit of `word` is ignored.
+pub fn prel31_to_addr(word: u32, place: u64) -> u64 {
+    // Shift the 31-bit value up to the top of the word so the arithmetic shift back down
+    // sign extends it
+    let offset = ((word << 1) as i32 >> 1) as i64;
+    place.wrapping_add(offset as u64)
+}
+
+/// What the second word of an exception index table entry describes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExidxEntryData {
+    /// The function can't be unwound
+    CantUnwind,
+    /// The word is an inline unwind table entry in the compact model, with its high bit set
+    Inline(u32),
+    /// The absolute address of the function's entry in the `.ARM.extab` section
+    Table(u64),
+}
+
+/// An entry in the exception index table
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ExidxEntry {
+    /// The absolute address of the start of the function that the entry covers
+    pub fn_addr: u64,
+    /// How to unwind the function
+    pub data: ExidxEntryData,
+}
+
+/// The contents of an exception index table, as found in an `SHT_ARM_EXIDX` section or a
+/// `PT_ARM_EXIDX` segment.
+///
+/// The prel31 offsets in the entries are resolved relative to `addr`, the virtual address that
+/// the table gets loaded at.
+#[derive(Debug, Clone, Copy)]
+pub struct ExidxTable<'data, E: EndianParse> {
+    endian: E,
+    addr: u64,
+    data: &'data [u8],
+}
+
+impl<'data, E: EndianParse> ExidxTable<'data, E> {
+    /// Wrap the table data loaded at `addr` for parsing.
+    ///
+    /// Returns a [ParseError::SliceReadError] if the data doesn't hold a whole number of entries.
+    pub fn new(endian: E, addr: u64, data: &'data [u8]) -> Result<Self, ParseError> {
+        let partial = data.len() % EXIDX_ENTRY_SIZE;
+        if partial != 0 {
+            let start = data.len() - partial;
+            return Err(ParseError::SliceReadError((
+                start,
+                start + EXIDX_ENTRY_SIZE,
+            )));
+        }
+        Ok(ExidxTable { endian, addr, data })
+    }
+
+    /// Get the number of entries in the table
+    pub fn len(&self) -> usize {
+        self.data.len() / EXIDX_ENTRY_SIZE
+    }
+
+    /// Returns true if the table has no entries
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    /// Get the entry at the given index, with its addresses resolved.
+    pub fn get(&self, index: usize) -> Result<ExidxEntry, ParseError> {
+        let mut offset = index
+            .checked_mul(EXIDX_ENTRY_SIZE)
+            .ok_or(ParseError::IntegerOverflow)?;
+        let entry_addr = self.addr.wrapping_add(offset as u64);
+        let fn_word = self.endian.parse_u32_at(&mut offset, self.data)?;
+        let data_word = self.endian.parse_u32_at(&mut offset, self.data)?;
+
+        let data = if data_word == abi::EXIDX_CANTUNWIND {
+            ExidxEntryData::CantUnwind
+        } else if data_word & 0x80000000 != 0 {
+            ExidxEntryData::Inline(data_word)
+        } else {
+            ExidxEntryData::Table(prel31_to_addr(data_word, entry_addr.wrapping_add(4)))
+        };
+        Ok(ExidxEntry {
+            fn_addr: prel31_to_addr(fn_word, entry_addr),
+            data,
+        })
+    }
+
+    /// Get an iterator over the table's entries.
+    pub fn iter(&self) -> ExidxIterator<'data, E> {
+        ExidxIterator {
+            table: *self,
+            index: 0,
+        }
+    }
+
+    /// Find the entry for the function containing `addr`, which is the last entry whose
+    /// function starts at or before it. The table is expected to be sorted by function address.
+    ///
+    /// Returns an empty Option if `addr` is before the first function.
+    pub fn find(&self, addr: u64) -> Result<Option<ExidxEntry>, ParseError> {
+        // Binary search for the number of entries which start at or before addr
+        let (mut lo, mut hi) = (0, self.len());
+        while lo < hi {
+            let mid = lo + (hi - lo) / 2;
+            if self.get(mid)?.fn_addr <= addr {
+                lo = mid + 1;
+            } else {
+                hi = mid;
+            }
+        }
+        match lo {
+            0 => Ok(None),
+            n => self.get(n - 1).map(Some),
+        }
+    }
+}
+
+/// Iterates over the entries of an [ExidxTable]
+#[derive(Debug, Clone)]
+pub struct ExidxIterator<'data, E: EndianParse> {
+    table: ExidxTable<'data, E>,
+    index: usize,
+}
+
+impl<'data, E: EndianParse> Iterator for ExidxIterator<'data, E> {
+    type Item = ExidxEntry;
+    fn next(&mut self) -> Option<Self::Item> {
+        // The table's length was checked on construction, so getting entries can't fail
+        let entry = self.table.get(self.index).ok()?;
+        self.index += 1;
+        Some(entry)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::endian::{BigEndian, LittleEndian};
+
+    #[test]
+    fn prel31_sign_extension() {
+        assert_eq!(prel31_to_addr(0, 0x1000), 0x1000);
+        assert_eq!(prel31_to_addr(0x10, 0x1000), 0x1010);
+        assert_eq!(prel31_to_addr(0x7ffffff0, 0x1000), 0x0ff0);
+        // the largest positive and negative offsets
+        assert_eq!(prel31_to_addr(0x3fffffff, 0), 0x3fffffff);
+        assert_eq!(prel31_to_addr(0x40000000, 0x40000000), 0);
+        // the high bit is ignored
+        assert_eq!(prel31_to_addr(0x80000010, 0x1000), 0x1010);
+        assert_eq!(prel31_to_addr(0xfffffff0, 0x1000), 0x0ff0);
+    }
+
+    #[test]
+    fn parse_entries() {
+        let data = [
+            // fn at 0x8000 - 0x100, can't unwind
+            0x00, 0xff, 0xff, 0x7f, 0x01, 0x00, 0x00, 0x00,
+            // fn at 0x8008 - 0x80, inline entry
+            0x80, 0xff, 0xff, 0x7f, 0xb0, 0xb0, 0xb0, 0x80,
+            // fn at 0x8010 + 0x20, extab entry at 0x8014 + 0x1000
+            0x20, 0x00, 0x00, 0x00, 0x00, 0x10, 0x00, 0x00,
+        ];
+        let table = ExidxTable::new(LittleEndian, 0x8000, &data).expect("should parse");
+        assert_eq!(table.len(), 3);
+        assert!(!table.is_empty());
+        let entries: Vec<_> = table.iter().collect();
+        assert_eq!(
+            entries,
+            vec![
+                ExidxEntry {
+                    fn_addr: 0x7f00,
+                    data: ExidxEntryData::CantUnwind,
+                },
+                ExidxEntry {
+                    fn_addr: 0x7f88,
+                    data: ExidxEntryData::Inline(0x80b0b0b0),
+                },
+                ExidxEntry {
+                    fn_addr: 0x8030,
+                    data: ExidxEntryData::Table(0x9014),
+                },
+            ]
+        );
+
+        assert!(table.get(3).is_err());
+        assert_eq!(table.find(0x7eff).expect("should parse"), None);
+        assert_eq!(table.find(0x7f00).expect("should parse"), Some(entries[0]));
+        assert_eq!(table.find(0x7f87).expect("should parse"), Some(entries[0]));
+        assert_eq!(table.find(0x8000).expect("should parse"), Some(entries[1]));
+        assert_eq!(table.find(0xffff).expect("should parse"), Some(entries[2]));
+    }
+
+    #[test]
+    fn parse_big_endian() {
+        let data = [0x7f, 0xff, 0xff, 0xf0, 0x00, 0x00, 0x00, 0x01];
+        let table = ExidxTable::new(BigEndian, 0x1000, &data).expect("should parse");
+        assert_eq!(
+            table.get(0).expect("should parse"),
+            ExidxEntry {
+                fn_addr: 0x0ff0,
+                data: ExidxEntryData::CantUnwind,
+            }
+        );
+    }
+
+    #[test]
+    fn partial_entry_errors() {
+        let data = [0u8; 12];
+        let result = ExidxTable::new(LittleEndian, 0, &data);
+        assert!(
+            matches!(result, Err(ParseError::SliceReadError((8, 16)))),
+            "Unexpected Error type found: {result:?}"
+        );
+
+        let table = ExidxTable::new(LittleEndian, 0, &[]).expect("should parse");
+        assert!(table.is_empty());
+        assert_eq!(table.iter().count(), 0);
+        assert_eq!(table.find(0).expect("should parse"), None);
+    }
+}
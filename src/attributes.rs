@@ -0,0 +1,913 @@
+//! Parsing build attributes sections, such as `.ARM.attributes` (`SHT_ARM_ATTRIBUTES`),
+//! `.riscv.attributes` (`SHT_RISCV_ATTRIBUTES`) and `.csky.attributes` (`SHT_CSKY_ATTRIBUTES`)
+//!
+//! Build attributes record things like the exact target architecture, the floating point
+//! calling convention, and alignment requirements the code was compiled for. They're encoded
+//! in a small container format:
+//!
+//! * A format-version byte, `'A'`
+//! * A sequence of vendor subsections, each of which is a 4-byte length (including itself) in
+//!   the object's byte order, followed by the NUL-terminated vendor name (e.g. `"aeabi"`), and
+//!   then the vendor's attributes
+//! * The attributes are grouped into file, section, or symbol scopes, each of which is a
+//!   ULEB128 scope tag followed by a 4-byte length (including the tag and length). Section and
+//!   symbol scopes then list the indexes they apply to, terminated by a 0.
+//! * Each attribute is a ULEB128 tag followed by its value, which is either a ULEB128 integer
+//!   or a NUL-terminated string depending on the tag.
+//!
+//! The container is parsed the same way for every architecture, and each architecture's
+//! [AttributesVendor] supplies its vendor name and tag table to [VendorAttributes]. Vendors
+//! without a known tag table can still be dumped through [AttributesSubsection::scopes], and
+//! [generic_attr_kind] makes a best guess at how their values are encoded.
+//!
+//! Example usage:
+//! ```
+//! use elf::abi;
+//! use elf::attributes::AttrValue;
+//! use elf::endian::AnyEndian;
+//! use elf::ElfBytes;
+//!
+//! let path = std::path::PathBuf::from("sample-objects/symver.armhf.so");
+//! let file_data = std::fs::read(path).expect("Could not read file.");
+//! let file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("Open test1");
+//!
+//! let attrs = file
+//!     .arm_attributes()
+//!     .expect("section should parse")
+//!     .expect("file should have a .ARM.attributes section");
+//! assert_eq!(
+//!     attrs.file_attribute(abi::TAG_ABI_VFP_ARGS).expect("should parse"),
+//!     Some(AttrValue::Int(abi::TAG_ABI_VFP_ARGS_VFP))
+//! );
+//! ```
+use crate::abi;
+use crate::endian::EndianParse;
+use crate::parse::{parse_uleb128, ParseError, ReadBytesExt};
+use core::marker::PhantomData;
+
+/// The only build attributes format version that's defined
+pub const ATTRIBUTES_FORMAT_VERSION: u8 = b'A';
+
+/// The name of the ARM EABI vendor subsection in `.ARM.attributes` sections
+pub const ARM_ATTRIBUTES_VENDOR: &[u8] = b"aeabi";
+
+/// The name of the RISC-V vendor subsection in `.riscv.attributes` sections
+pub const RISCV_ATTRIBUTES_VENDOR: &[u8] = b"riscv";
+
+/// The name of the C-SKY vendor subsection in `.csky.attributes` sections
+pub const CSKY_ATTRIBUTES_VENDOR: &[u8] = b"csky";
+
+/// The value of a single build attribute
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AttrValue<'data> {
+    /// A ULEB128-encoded integer value
+    Int(u64),
+    /// A NUL-terminated string value (without its NUL)
+    String(&'data [u8]),
+    /// A ULEB128-encoded integer followed by a NUL-terminated string, as used by ARM's
+    /// `Tag_compatibility`
+    IntAndString(u64, &'data [u8]),
+}
+
+/// Describes how the value of a given attribute tag is encoded.
+///
+/// Each vendor defines the encoding of its own tags, so the attribute parser gets supplied
+/// with a function that maps tags to their [AttrKind].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AttrKind {
+    /// The value is a ULEB128-encoded integer
+    Int,
+    /// The value is a NUL-terminated string
+    String,
+    /// The value is a ULEB128-encoded integer followed by a NUL-terminated string
+    IntAndString,
+}
+
+/// A best guess at the encoding of attribute values for vendors without a known tag table.
+///
+/// This follows the convention that GNU tools use for tags they don't know: tag 32
+/// (`Tag_compatibility`) is an integer and a string, tags from 32 up are integers if they're
+/// even and strings if they're odd, and tags below 32 (whose encoding is up to the vendor) are
+/// assumed to be integers.
+pub fn generic_attr_kind(tag: u64) -> AttrKind {
+    match tag {
+        abi::TAG_COMPATIBILITY => AttrKind::IntAndString,
+        tag if tag < 32 || tag % 2 == 0 => AttrKind::Int,
+        _ => AttrKind::String,
+    }
+}
+
+/// The encoding of attribute values in the `"aeabi"` vendor subsection.
+///
+/// Unknown tags below 32 are integers, and tags from 32 up follow the convention that even
+/// tags are integers and odd tags are strings, so that tools can skip over attributes they
+/// don't know.
+pub fn arm_attr_kind(tag: u64) -> AttrKind {
+    match tag {
+        abi::TAG_CPU_RAW_NAME
+        | abi::TAG_CPU_NAME
+        | abi::TAG_ALSO_COMPATIBLE_WITH
+        | abi::TAG_CONFORMANCE => AttrKind::String,
+        tag => generic_attr_kind(tag),
+    }
+}
+
+/// The encoding of attribute values in the `"riscv"` vendor subsection, where even tags are
+/// integers and odd tags are strings.
+pub fn riscv_attr_kind(tag: u64) -> AttrKind {
+    if tag % 2 == 0 {
+        AttrKind::Int
+    } else {
+        AttrKind::String
+    }
+}
+
+/// The encoding of attribute values in the `"csky"` vendor subsection, where the names are
+/// strings and the rest of the known tags are integers. Unknown tags follow
+/// [generic_attr_kind].
+pub fn csky_attr_kind(tag: u64) -> AttrKind {
+    match tag {
+        abi::TAG_CSKY_ARCH_NAME | abi::TAG_CSKY_CPU_NAME | abi::TAG_CSKY_FPU_NUMBER_MODULE => {
+            AttrKind::String
+        }
+        tag => generic_attr_kind(tag),
+    }
+}
+
+/// Find the value of the first attribute with the given tag.
+fn find_attribute<'data, E: EndianParse>(
+    attrs: AttributeIterator<'data, E>,
+    tag: u64,
+) -> Result<Option<AttrValue<'data>>, ParseError> {
+    for attr in attrs {
+        let (attr_tag, value) = attr?;
+        if attr_tag == tag {
+            return Ok(Some(value));
+        }
+    }
+    Ok(None)
+}
+
+/// Read a NUL-terminated string out of `data` at `offset`, advancing the offset past the NUL.
+fn parse_ntbs<'data>(offset: &mut usize, data: &'data [u8]) -> Result<&'data [u8], ParseError> {
+    let start = *offset;
+    let rest = data.get_bytes(start..data.len())?;
+    let len = rest
+        .iter()
+        .position(|byte| *byte == 0)
+        .ok_or(ParseError::StringTableMissingNul(start as u64))?;
+    *offset = start + len + 1;
+    Ok(&rest[..len])
+}
+
+/// Read a 4-byte length which counts from `start` out of `data` at `offset`, and get the
+/// offset that it ends at. Errors if the length runs past the end of `data`, or is too short
+/// to cover the bytes that were already read.
+fn parse_length<E: EndianParse>(
+    endian: E,
+    start: usize,
+    offset: &mut usize,
+    data: &[u8],
+) -> Result<usize, ParseError> {
+    let len: usize = endian.parse_u32_at(offset, data)?.try_into()?;
+    let end = start.checked_add(len).ok_or(ParseError::IntegerOverflow)?;
+    if end > data.len() {
+        return Err(ParseError::SliceReadError((start, end)));
+    }
+    if end < *offset {
+        return Err(ParseError::BadOffset(len as u64));
+    }
+    Ok(end)
+}
+
+/// Read the scope at `offset` in a vendor subsection's `data`, advancing the offset past it.
+fn parse_scope<'data, E: EndianParse>(
+    endian: E,
+    offset: &mut usize,
+    data: &'data [u8],
+) -> Result<AttributeScope<'data, E>, ParseError> {
+    let start = *offset;
+    let mut scope_offset = start;
+    let tag = parse_uleb128(&mut scope_offset, data)?;
+    let end = parse_length(endian, start, &mut scope_offset, data)?;
+    let contents = &data[scope_offset..end];
+    *offset = end;
+
+    // Section and symbol scopes start with a 0-terminated list of indexes
+    let mut attrs_offset = 0;
+    if tag == abi::TAG_SECTION || tag == abi::TAG_SYMBOL {
+        while parse_uleb128(&mut attrs_offset, contents)? != 0 {}
+    }
+    let indexes = match attrs_offset {
+        0 => &contents[..0],
+        _ => &contents[..attrs_offset - 1],
+    };
+    Ok(AttributeScope {
+        endian,
+        tag,
+        indexes,
+        data: &contents[attrs_offset..],
+    })
+}
+
+/// The contents of a build attributes section
+#[derive(Debug, Clone, Copy)]
+pub struct AttributesSection<'data, E: EndianParse> {
+    endian: E,
+    data: &'data [u8],
+}
+
+impl<'data, E: EndianParse> AttributesSection<'data, E> {
+    /// Check the format-version byte at the start of the section data and wrap it for parsing.
+    pub fn new(endian: E, data: &'data [u8]) -> Result<Self, ParseError> {
+        let version = *data.first().ok_or(ParseError::SliceReadError((0, 1)))?;
+        if version != ATTRIBUTES_FORMAT_VERSION {
+            return Err(ParseError::UnsupportedVersion((
+                version as u64,
+                ATTRIBUTES_FORMAT_VERSION as u64,
+            )));
+        }
+        Ok(AttributesSection { endian, data })
+    }
+
+    /// Get an iterator over the vendor subsections in this section.
+    pub fn subsections(&self) -> AttributesSubsectionIterator<'data, E> {
+        AttributesSubsectionIterator {
+            endian: self.endian,
+            data: self.data,
+            offset: 1,
+        }
+    }
+
+    /// Find the subsection for the given vendor, if there is one.
+    pub fn subsection(
+        &self,
+        vendor: &[u8],
+    ) -> Result<Option<AttributesSubsection<'data, E>>, ParseError> {
+        for subsection in self.subsections() {
+            let subsection = subsection?;
+            if subsection.vendor == vendor {
+                return Ok(Some(subsection));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Get an iterator over the file-scope attributes in the given vendor's subsection, using
+    /// `kind` to determine how each tag's value is encoded.
+    ///
+    /// The iterator is empty if there's no subsection for the vendor.
+    pub fn file_attributes(
+        &self,
+        vendor: &'data [u8],
+        kind: fn(u64) -> AttrKind,
+    ) -> Result<AttributeIterator<'data, E>, ParseError> {
+        let subsection = self.subsection(vendor)?.unwrap_or(AttributesSubsection {
+            endian: self.endian,
+            vendor,
+            data: &[],
+        });
+        Ok(subsection.file_attributes(kind))
+    }
+}
+
+/// Iterates over the vendor subsections of a build attributes section.
+///
+/// Yields an error and stops if a subsection is malformed or its length runs past the end of
+/// the section.
+#[derive(Debug, Clone)]
+pub struct AttributesSubsectionIterator<'data, E: EndianParse> {
+    endian: E,
+    data: &'data [u8],
+    offset: usize,
+}
+
+impl<'data, E: EndianParse> AttributesSubsectionIterator<'data, E> {
+    fn parse_next(&mut self) -> Result<AttributesSubsection<'data, E>, ParseError> {
+        let start = self.offset;
+        let mut offset = start;
+        let end = parse_length(self.endian, start, &mut offset, self.data)?;
+        let contents = self.data.get_bytes(start..end)?;
+        offset -= start;
+        let vendor = parse_ntbs(&mut offset, contents)?;
+        self.offset = end;
+        Ok(AttributesSubsection {
+            endian: self.endian,
+            vendor,
+            data: &contents[offset..],
+        })
+    }
+}
+
+impl<'data, E: EndianParse> Iterator for AttributesSubsectionIterator<'data, E> {
+    type Item = Result<AttributesSubsection<'data, E>, ParseError>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.offset >= self.data.len() {
+            return None;
+        }
+        let result = self.parse_next();
+        if result.is_err() {
+            self.offset = self.data.len();
+        }
+        Some(result)
+    }
+}
+
+/// A vendor subsection of a build attributes section
+#[derive(Debug, Clone, Copy)]
+pub struct AttributesSubsection<'data, E: EndianParse> {
+    endian: E,
+    /// The name of the vendor which defines the attributes in this subsection
+    pub vendor: &'data [u8],
+    data: &'data [u8],
+}
+
+impl<'data, E: EndianParse> AttributesSubsection<'data, E> {
+    /// Get an iterator over the file-scope attributes in this subsection, using `kind` to
+    /// determine how each tag's value is encoded.
+    pub fn file_attributes(&self, kind: fn(u64) -> AttrKind) -> AttributeIterator<'data, E> {
+        AttributeIterator {
+            endian: self.endian,
+            data: self.data,
+            offset: 0,
+            attrs: &[],
+            attrs_offset: 0,
+            kind,
+        }
+    }
+
+    /// Get an iterator over all of the scopes in this subsection, for dumping the attributes
+    /// of any vendor.
+    pub fn scopes(&self) -> AttributeScopeIterator<'data, E> {
+        AttributeScopeIterator {
+            endian: self.endian,
+            data: self.data,
+            offset: 0,
+        }
+    }
+}
+
+/// Iterates over the scopes of a vendor subsection.
+///
+/// Yields an error and stops if a scope is malformed, such as a truncated index list or a
+/// length that runs past the end of the subsection.
+#[derive(Debug, Clone)]
+pub struct AttributeScopeIterator<'data, E: EndianParse> {
+    endian: E,
+    data: &'data [u8],
+    offset: usize,
+}
+
+impl<'data, E: EndianParse> Iterator for AttributeScopeIterator<'data, E> {
+    type Item = Result<AttributeScope<'data, E>, ParseError>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.offset >= self.data.len() {
+            return None;
+        }
+        let result = parse_scope(self.endian, &mut self.offset, self.data);
+        if result.is_err() {
+            self.offset = self.data.len();
+        }
+        Some(result)
+    }
+}
+
+/// A group of attributes in a vendor subsection, which apply to the whole file or to the
+/// listed sections or symbols.
+#[derive(Debug, Clone, Copy)]
+pub struct AttributeScope<'data, E: EndianParse> {
+    endian: E,
+    /// The scope's tag: [abi::TAG_FILE], [abi::TAG_SECTION], or [abi::TAG_SYMBOL]
+    pub tag: u64,
+    indexes: &'data [u8],
+    /// The raw attribute data, after the index list
+    pub data: &'data [u8],
+}
+
+impl<'data, E: EndianParse> AttributeScope<'data, E> {
+    /// Get an iterator over the section or symbol indexes which a [abi::TAG_SECTION] or
+    /// [abi::TAG_SYMBOL] scope applies to. The iterator is empty for other scopes.
+    pub fn indexes(&self) -> impl Iterator<Item = u64> + 'data {
+        let indexes = self.indexes;
+        let mut offset = 0;
+        // The list was already checked when the scope was parsed
+        core::iter::from_fn(move || {
+            if offset < indexes.len() {
+                parse_uleb128(&mut offset, indexes).ok()
+            } else {
+                None
+            }
+        })
+    }
+
+    /// Get an iterator over the attributes in this scope, using `kind` to determine how each
+    /// tag's value is encoded.
+    pub fn attributes(&self, kind: fn(u64) -> AttrKind) -> AttributeIterator<'data, E> {
+        AttributeIterator {
+            endian: self.endian,
+            data: &[],
+            offset: 0,
+            attrs: self.data,
+            attrs_offset: 0,
+            kind,
+        }
+    }
+}
+
+/// Iterates over the `(tag, value)` attribute pairs in the file scopes of a vendor subsection,
+/// or in a single [AttributeScope].
+///
+/// Yields an error and stops if an attribute or scope is malformed, such as a truncated
+/// ULEB128 or a scope length that runs past the end of the subsection.
+#[derive(Debug, Clone)]
+pub struct AttributeIterator<'data, E: EndianParse> {
+    endian: E,
+    data: &'data [u8],
+    offset: usize,
+    attrs: &'data [u8],
+    attrs_offset: usize,
+    kind: fn(u64) -> AttrKind,
+}
+
+impl<'data, E: EndianParse> AttributeIterator<'data, E> {
+    /// Advance to the next file scope in the subsection. Returns false once there are no more.
+    fn next_file_scope(&mut self) -> Result<bool, ParseError> {
+        while self.offset < self.data.len() {
+            let scope = parse_scope(self.endian, &mut self.offset, self.data)?;
+            if scope.tag == abi::TAG_FILE {
+                self.attrs = scope.data;
+                self.attrs_offset = 0;
+                return Ok(true);
+            }
+        }
+        Ok(false)
+    }
+
+    fn parse_next(&mut self) -> Result<(u64, AttrValue<'data>), ParseError> {
+        let tag = parse_uleb128(&mut self.attrs_offset, self.attrs)?;
+        let value = match (self.kind)(tag) {
+            AttrKind::Int => AttrValue::Int(parse_uleb128(&mut self.attrs_offset, self.attrs)?),
+            AttrKind::String => AttrValue::String(parse_ntbs(&mut self.attrs_offset, self.attrs)?),
+            AttrKind::IntAndString => {
+                let int = parse_uleb128(&mut self.attrs_offset, self.attrs)?;
+                let string = parse_ntbs(&mut self.attrs_offset, self.attrs)?;
+                AttrValue::IntAndString(int, string)
+            }
+        };
+        Ok((tag, value))
+    }
+}
+
+impl<'data, E: EndianParse> Iterator for AttributeIterator<'data, E> {
+    type Item = Result<(u64, AttrValue<'data>), ParseError>;
+    fn next(&mut self) -> Option<Self::Item> {
+        // skip over any empty file scopes
+        while self.attrs_offset >= self.attrs.len() {
+            match self.next_file_scope() {
+                Ok(true) => (),
+                Ok(false) => return None,
+                Err(err) => {
+                    self.offset = self.data.len();
+                    return Some(Err(err));
+                }
+            }
+        }
+        let result = self.parse_next();
+        if result.is_err() {
+            self.offset = self.data.len();
+            self.attrs_offset = self.attrs.len();
+        }
+        Some(result)
+    }
+}
+
+/// The tag table for a vendor's attributes: the name of its subsection, and how the values of
+/// its tags are encoded.
+pub trait AttributesVendor {
+    /// The name of the vendor subsection
+    const VENDOR: &'static [u8];
+
+    /// Get how the value of the given tag is encoded
+    fn attr_kind(tag: u64) -> AttrKind;
+}
+
+/// The `"aeabi"` vendor of `SHT_ARM_ATTRIBUTES` sections
+#[derive(Debug, Clone, Copy)]
+pub struct ArmVendor;
+
+impl AttributesVendor for ArmVendor {
+    const VENDOR: &'static [u8] = ARM_ATTRIBUTES_VENDOR;
+
+    fn attr_kind(tag: u64) -> AttrKind {
+        arm_attr_kind(tag)
+    }
+}
+
+/// The `"riscv"` vendor of `SHT_RISCV_ATTRIBUTES` sections
+#[derive(Debug, Clone, Copy)]
+pub struct RiscVVendor;
+
+impl AttributesVendor for RiscVVendor {
+    const VENDOR: &'static [u8] = RISCV_ATTRIBUTES_VENDOR;
+
+    fn attr_kind(tag: u64) -> AttrKind {
+        riscv_attr_kind(tag)
+    }
+}
+
+/// The `"csky"` vendor of `SHT_CSKY_ATTRIBUTES` sections
+#[derive(Debug, Clone, Copy)]
+pub struct CskyVendor;
+
+impl AttributesVendor for CskyVendor {
+    const VENDOR: &'static [u8] = CSKY_ATTRIBUTES_VENDOR;
+
+    fn attr_kind(tag: u64) -> AttrKind {
+        csky_attr_kind(tag)
+    }
+}
+
+/// The build attributes from an attributes section, decoded with the tag table of the
+/// section's [AttributesVendor].
+#[derive(Debug, Clone, Copy)]
+pub struct VendorAttributes<'data, E: EndianParse, V: AttributesVendor> {
+    section: AttributesSection<'data, E>,
+    vendor: PhantomData<V>,
+}
+
+/// The build attributes from an `SHT_ARM_ATTRIBUTES` section
+pub type ArmAttributes<'data, E> = VendorAttributes<'data, E, ArmVendor>;
+
+/// The build attributes from an `SHT_RISCV_ATTRIBUTES` section
+pub type RiscVAttributes<'data, E> = VendorAttributes<'data, E, RiscVVendor>;
+
+/// The build attributes from an `SHT_CSKY_ATTRIBUTES` section
+pub type CskyAttributes<'data, E> = VendorAttributes<'data, E, CskyVendor>;
+
+impl<'data, E: EndianParse, V: AttributesVendor> VendorAttributes<'data, E, V> {
+    /// Check the format-version byte at the start of the section data and wrap it for parsing.
+    pub fn new(endian: E, data: &'data [u8]) -> Result<Self, ParseError> {
+        Ok(VendorAttributes {
+            section: AttributesSection::new(endian, data)?,
+            vendor: PhantomData,
+        })
+    }
+
+    /// Get the underlying container, for parsing other vendors' subsections.
+    pub fn section(&self) -> &AttributesSection<'data, E> {
+        &self.section
+    }
+
+    /// Get an iterator over the file-scope attributes from the vendor's subsection, such as
+    /// [abi::TAG_CPU_ARCH] and [abi::TAG_ABI_VFP_ARGS] for `"aeabi"`.
+    ///
+    /// The iterator is empty if there's no subsection for the vendor.
+    pub fn file_attributes(&self) -> Result<AttributeIterator<'data, E>, ParseError> {
+        self.section.file_attributes(V::VENDOR, V::attr_kind)
+    }
+
+    /// Get the value of the given file-scope attribute from the vendor's subsection, if it's
+    /// present.
+    pub fn file_attribute(&self, tag: u64) -> Result<Option<AttrValue<'data>>, ParseError> {
+        find_attribute(self.file_attributes()?, tag)
+    }
+
+    /// Get the value of the given file-scope string attribute, if it's present.
+    pub fn file_attribute_str(&self, tag: u64) -> Result<Option<&'data str>, ParseError> {
+        match self.file_attribute(tag)? {
+            Some(AttrValue::String(value)) => Ok(Some(core::str::from_utf8(value)?)),
+            _ => Ok(None),
+        }
+    }
+}
+
+impl<'data, E: EndianParse> RiscVAttributes<'data, E> {
+    /// Get the ISA string from `Tag_RISCV_arch`, e.g. "rv64i2p1_m2p0_a2p1_c2p0", if it's present.
+    pub fn arch(&self) -> Result<Option<&'data str>, ParseError> {
+        self.file_attribute_str(abi::TAG_RISCV_ARCH)
+    }
+
+    /// Get the stack alignment in bytes from `Tag_RISCV_stack_align`, if it's present.
+    pub fn stack_align(&self) -> Result<Option<u64>, ParseError> {
+        match self.file_attribute(abi::TAG_RISCV_STACK_ALIGN)? {
+            Some(AttrValue::Int(align)) => Ok(Some(align)),
+            _ => Ok(None),
+        }
+    }
+
+    /// Get whether the code may make unaligned memory accesses from
+    /// `Tag_RISCV_unaligned_access`, if it's present.
+    pub fn unaligned_access(&self) -> Result<Option<bool>, ParseError> {
+        match self.file_attribute(abi::TAG_RISCV_UNALIGNED_ACCESS)? {
+            Some(AttrValue::Int(allowed)) => Ok(Some(allowed != 0)),
+            _ => Ok(None),
+        }
+    }
+}
+
+impl<'data, E: EndianParse> CskyAttributes<'data, E> {
+    /// Get the architecture name from `Tag_CSKY_ARCH_NAME`, e.g. "ck860", if it's present.
+    pub fn arch_name(&self) -> Result<Option<&'data str>, ParseError> {
+        self.file_attribute_str(abi::TAG_CSKY_ARCH_NAME)
+    }
+
+    /// Get the CPU name from `Tag_CSKY_CPU_NAME`, e.g. "ck860f", if it's present.
+    pub fn cpu_name(&self) -> Result<Option<&'data str>, ParseError> {
+        self.file_attribute_str(abi::TAG_CSKY_CPU_NAME)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::endian::{BigEndian, LittleEndian};
+
+    // A section with an "aeabi" subsection holding a file scope with Tag_CPU_name "7-A",
+    // Tag_CPU_arch v7, Tag_compatibility (1, "gnu"), and Tag_ABI_VFP_args VFP, followed by a
+    // section scope that should be skipped.
+    const ARM_ATTRS: [u8; 40] = [
+        b'A', // version
+        39, 0, 0, 0, // subsection length
+        b'a', b'e', b'a', b'b', b'i', 0, // vendor
+        1, 20, 0, 0, 0, // file scope tag and length
+        5, b'7', b'-', b'A', 0, // Tag_CPU_name
+        6, 10, // Tag_CPU_arch
+        32, 1, b'g', b'n', b'u', 0, // Tag_compatibility
+        28, 1, // Tag_ABI_VFP_args
+        2, 9, 0, 0, 0, // section scope tag and length
+        1, 0, // section indexes
+        28, 0, // Tag_ABI_VFP_args
+    ];
+
+    #[test]
+    fn parse_file_attributes() {
+        let attrs = ArmAttributes::new(LittleEndian, &ARM_ATTRS).expect("should parse");
+        let parsed: Vec<_> = attrs
+            .file_attributes()
+            .expect("should parse")
+            .collect::<Result<_, _>>()
+            .expect("should parse");
+        assert_eq!(
+            parsed,
+            vec![
+                (abi::TAG_CPU_NAME, AttrValue::String(b"7-A")),
+                (abi::TAG_CPU_ARCH, AttrValue::Int(abi::TAG_CPU_ARCH_V7)),
+                (abi::TAG_COMPATIBILITY, AttrValue::IntAndString(1, b"gnu")),
+                (
+                    abi::TAG_ABI_VFP_ARGS,
+                    AttrValue::Int(abi::TAG_ABI_VFP_ARGS_VFP)
+                ),
+            ]
+        );
+        assert_eq!(
+            attrs
+                .file_attribute(abi::TAG_ABI_VFP_ARGS)
+                .expect("should parse"),
+            Some(AttrValue::Int(abi::TAG_ABI_VFP_ARGS_VFP))
+        );
+        assert_eq!(
+            attrs
+                .file_attribute(abi::TAG_DIV_USE)
+                .expect("should parse"),
+            None
+        );
+    }
+
+    #[test]
+    fn parse_big_endian_lengths() {
+        let mut data = ARM_ATTRS;
+        data[1..5].copy_from_slice(&[0, 0, 0, 39]);
+        data[12..16].copy_from_slice(&[0, 0, 0, 20]);
+        data[32..36].copy_from_slice(&[0, 0, 0, 9]);
+        let attrs = ArmAttributes::new(BigEndian, &data).expect("should parse");
+        assert_eq!(
+            attrs
+                .file_attribute(abi::TAG_CPU_NAME)
+                .expect("should parse"),
+            Some(AttrValue::String(b"7-A"))
+        );
+    }
+
+    #[test]
+    fn other_vendors_are_skipped() {
+        let mut data = vec![b'A', 9, 0, 0, 0, b'g', b'n', b'u', 0, 0];
+        data.extend_from_slice(&ARM_ATTRS[1..]);
+        let attrs = ArmAttributes::new(LittleEndian, &data).expect("should parse");
+        let vendors: Vec<_> = attrs
+            .section()
+            .subsections()
+            .map(|subsection| subsection.expect("should parse").vendor)
+            .collect();
+        assert_eq!(vendors, vec![&b"gnu"[..], &b"aeabi"[..]]);
+        assert_eq!(attrs.file_attributes().expect("should parse").count(), 4);
+
+        let attrs = ArmAttributes::new(LittleEndian, &data[..10]).expect("should parse");
+        assert_eq!(attrs.file_attributes().expect("should parse").count(), 0);
+    }
+
+    #[test]
+    fn bad_version_errors() {
+        let result = ArmAttributes::new(LittleEndian, b"B");
+        assert!(
+            matches!(result, Err(ParseError::UnsupportedVersion((0x42, 0x41)))),
+            "Unexpected Error type found: {result:?}"
+        );
+        let result = ArmAttributes::new(LittleEndian, &[]);
+        assert!(
+            matches!(result, Err(ParseError::SliceReadError(_))),
+            "Unexpected Error type found: {result:?}"
+        );
+    }
+
+    #[test]
+    fn subsection_length_past_section_errors() {
+        let mut data = ARM_ATTRS;
+        data[1] = 40;
+        let attrs = ArmAttributes::new(LittleEndian, &data).expect("should parse");
+        let result = attrs.file_attributes();
+        assert!(
+            matches!(result, Err(ParseError::SliceReadError((1, 41)))),
+            "Unexpected Error type found: {result:?}"
+        );
+    }
+
+    #[test]
+    fn scope_length_past_subsection_errors() {
+        let mut data = ARM_ATTRS;
+        data[12] = 30;
+        let attrs = ArmAttributes::new(LittleEndian, &data).expect("should parse");
+        let mut iter = attrs.file_attributes().expect("should parse");
+        let result = iter.next().expect("should yield the error");
+        assert!(
+            matches!(result, Err(ParseError::SliceReadError(_))),
+            "Unexpected Error type found: {result:?}"
+        );
+        assert!(iter.next().is_none());
+    }
+
+    #[test]
+    fn truncated_uleb_errors() {
+        // Tag_CPU_arch's value has its continuation bit set at the end of the scope
+        let data = [
+            b'A', 17, 0, 0, 0, b'a', b'e', b'a', b'b', b'i', 0, 1, 7, 0, 0, 0, 6, 0x8a,
+        ];
+        let attrs = ArmAttributes::new(LittleEndian, &data).expect("should parse");
+        let mut iter = attrs.file_attributes().expect("should parse");
+        let result = iter.next().expect("should yield the error");
+        assert!(
+            matches!(result, Err(ParseError::SliceReadError((2, 3)))),
+            "Unexpected Error type found: {result:?}"
+        );
+        assert!(iter.next().is_none());
+    }
+
+    #[test]
+    fn parse_fuzz_too_short() {
+        for n in 2..ARM_ATTRS.len() {
+            let attrs = ArmAttributes::new(LittleEndian, &ARM_ATTRS[..n]).expect("should parse");
+            let result: Result<Vec<_>, _> = attrs
+                .file_attributes()
+                .and_then(|iter| iter.collect::<Result<Vec<_>, _>>());
+            assert!(result.is_err(), "should fail to parse {n} bytes");
+        }
+    }
+
+    const RISCV_ATTRS: [u8; 24] = [
+        b'A', 23, 0, 0, 0, b'r', b'i', b's', b'c', b'v', 0, 1, 13, 0, 0, 0, 4, 16, 5, b'r', b'v',
+        0, 6, 1,
+    ];
+
+    const CSKY_ATTRS: [u8; 26] = [
+        b'A', 25, 0, 0, 0, b'c', b's', b'k', b'y', 0, 1, 16, 0, 0, 0, 4, b'c', b'k', b'8', b'6',
+        b'0', 0, 16, 2, 17, 3,
+    ];
+
+    #[test]
+    fn parse_riscv_attributes() {
+        let data = RISCV_ATTRS;
+        let attrs = RiscVAttributes::new(LittleEndian, &data).expect("should parse");
+        assert_eq!(attrs.arch().expect("should parse"), Some("rv"));
+        assert_eq!(attrs.stack_align().expect("should parse"), Some(16));
+        assert_eq!(attrs.unaligned_access().expect("should parse"), Some(true));
+
+        let attrs = RiscVAttributes::new(LittleEndian, &data[..23]).expect("should parse");
+        assert!(attrs.arch().is_err());
+
+        // the ARM layer doesn't see the riscv vendor's attributes
+        let attrs = ArmAttributes::new(LittleEndian, &data).expect("should parse");
+        assert_eq!(attrs.file_attributes().expect("should parse").count(), 0);
+    }
+
+    #[test]
+    fn parse_csky_attributes() {
+        let attrs = CskyAttributes::new(LittleEndian, &CSKY_ATTRS).expect("should parse");
+        assert_eq!(attrs.arch_name().expect("should parse"), Some("ck860"));
+        assert_eq!(
+            attrs
+                .file_attribute(abi::TAG_CSKY_FPU_VERSION)
+                .expect("should parse"),
+            Some(AttrValue::Int(2))
+        );
+    }
+
+    #[test]
+    fn dump_scopes() {
+        let attrs = ArmAttributes::new(LittleEndian, &ARM_ATTRS).expect("should parse");
+        let subsection = attrs
+            .section()
+            .subsection(b"aeabi")
+            .expect("should parse")
+            .expect("should have subsection");
+        let scopes: Vec<_> = subsection
+            .scopes()
+            .collect::<Result<_, _>>()
+            .expect("should parse");
+        assert_eq!(scopes.len(), 2);
+        assert_eq!(scopes[0].tag, abi::TAG_FILE);
+        assert_eq!(scopes[0].indexes().count(), 0);
+        assert_eq!(scopes[0].data.len(), 15);
+        assert_eq!(scopes[1].tag, abi::TAG_SECTION);
+        assert_eq!(scopes[1].indexes().collect::<Vec<_>>(), vec![1]);
+        let parsed: Vec<_> = scopes[1]
+            .attributes(generic_attr_kind)
+            .collect::<Result<_, _>>()
+            .expect("should parse");
+        assert_eq!(parsed, vec![(abi::TAG_ABI_VFP_ARGS, AttrValue::Int(0))]);
+
+        // An unterminated index list
+        let mut data = ARM_ATTRS;
+        data[37] = 1;
+        data[39] = 1;
+        let attrs = ArmAttributes::new(LittleEndian, &data).expect("should parse");
+        let subsection = attrs
+            .section()
+            .subsections()
+            .next()
+            .expect("should have subsection")
+            .expect("should parse");
+        let mut scopes = subsection.scopes();
+        assert!(scopes.next().expect("should have scope").is_ok());
+        assert!(scopes.next().expect("should have scope").is_err());
+        assert!(scopes.next().is_none());
+    }
+
+    /// Parse everything in a section that might be malformed, which shouldn't panic
+    fn parse_everything(data: &[u8]) {
+        let section = match AttributesSection::new(LittleEndian, data) {
+            Ok(section) => section,
+            Err(_) => return,
+        };
+        for subsection in section.subsections().flatten() {
+            for scope in subsection.scopes().flatten() {
+                let _ = scope.indexes().count();
+                let _ = scope.attributes(generic_attr_kind).count();
+            }
+        }
+        let _ = ArmAttributes::new(LittleEndian, data).map(|attrs| attrs.file_attribute(0));
+        let _ = RiscVAttributes::new(LittleEndian, data).map(|attrs| attrs.arch());
+        let _ = CskyAttributes::new(LittleEndian, data).map(|attrs| attrs.cpu_name());
+    }
+
+    #[test]
+    fn parse_fuzz_mutations() {
+        for attrs in [&ARM_ATTRS[..], &RISCV_ATTRS[..], &CSKY_ATTRS[..]] {
+            for n in 0..attrs.len() {
+                parse_everything(&attrs[..n]);
+                for byte in [0x00, 0x01, 0x7f, 0x80, 0xff] {
+                    let mut data = attrs.to_vec();
+                    data[n] = byte;
+                    parse_everything(&data);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn generic_attr_kinds() {
+        assert_eq!(generic_attr_kind(4), AttrKind::Int);
+        assert_eq!(
+            generic_attr_kind(abi::TAG_COMPATIBILITY),
+            AttrKind::IntAndString
+        );
+        assert_eq!(generic_attr_kind(33), AttrKind::String);
+        assert_eq!(generic_attr_kind(34), AttrKind::Int);
+        assert_eq!(csky_attr_kind(abi::TAG_CSKY_CPU_NAME), AttrKind::String);
+        assert_eq!(csky_attr_kind(abi::TAG_CSKY_ISA_FLAGS), AttrKind::Int);
+    }
+
+    #[test]
+    fn arm_attr_kinds() {
+        assert_eq!(arm_attr_kind(abi::TAG_CPU_NAME), AttrKind::String);
+        assert_eq!(arm_attr_kind(abi::TAG_CPU_ARCH), AttrKind::Int);
+        assert_eq!(
+            arm_attr_kind(abi::TAG_COMPATIBILITY),
+            AttrKind::IntAndString
+        );
+        assert_eq!(arm_attr_kind(abi::TAG_CONFORMANCE), AttrKind::String);
+        assert_eq!(arm_attr_kind(abi::TAG_NODEFAULTS), AttrKind::Int);
+        // unknown tags follow the even/odd convention from 32 up
+        assert_eq!(arm_attr_kind(31), AttrKind::Int);
+        assert_eq!(arm_attr_kind(99), AttrKind::String);
+        assert_eq!(arm_attr_kind(100), AttrKind::Int);
+    }
+}
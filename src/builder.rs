@@ -0,0 +1,539 @@
+//! Building new ELF objects from scratch
+//!
+//! The [ElfBuilder] collects up a [FileHeader], sections, segments and symbols and then lays
+//! them all out into the bytes of a new ELF object on [ElfBuilder::build].
+//!
+//! Example usage:
+//! ```
+//! use elf::abi;
+//! use elf::builder::ElfBuilder;
+//! use elf::endian::{AnyEndian, LittleEndian};
+//! use elf::file::Class;
+//! use elf::section::SectionHeader;
+//! use elf::symbol::Symbol;
+//! use elf::ElfBytes;
+//!
+//! let mut builder = ElfBuilder::new(LittleEndian, Class::ELF64, abi::ET_REL, abi::EM_X86_64);
+//! let text = builder.add_section(
+//!     ".text",
+//!     SectionHeader {
+//!         sh_type: abi::SHT_PROGBITS,
+//!         sh_flags: (abi::SHF_ALLOC | abi::SHF_EXECINSTR) as u64,
+//!         sh_addralign: 16,
+//!         ..Default::default()
+//!     },
+//!     &[0xc3], // ret
+//! );
+//! builder.add_symbol(
+//!     "f",
+//!     Symbol {
+//!         st_info: (abi::STB_GLOBAL << 4) | abi::STT_FUNC,
+//!         st_shndx: text as u16,
+//!         st_size: 1,
+//!         ..Default::default()
+//!     },
+//! );
+//! let data = builder.build().expect("should lay out");
+//!
+//! let file = ElfBytes::<AnyEndian>::minimal_parse(&data).expect("should parse");
+//! let shdr = file.section_header_by_name(".text").expect("should parse").unwrap();
+//! assert_eq!(file.section_data(&shdr).expect("should read").0, &[0xc3]);
+//! ```
+use crate::abi;
+use crate::endian::EndianParse;
+use crate::file::{Class, FileHeader, ELF32_EHDR_TAILSIZE, ELF64_EHDR_TAILSIZE};
+use crate::parse::{ParseAt, ParseError, SerializeAt};
+use crate::section::SectionHeader;
+use crate::segment::ProgramHeader;
+use crate::string_table::StringTableBuilder;
+use crate::symbol::{Symbol, SymbolTableBuilder};
+
+#[cfg(not(feature = "std"))]
+use alloc::{string::String, vec, vec::Vec};
+
+#[derive(Debug)]
+struct BuilderSection {
+    name: String,
+    shdr: SectionHeader,
+    data: Vec<u8>,
+}
+
+#[derive(Debug)]
+struct BuilderSegment {
+    phdr: ProgramHeader,
+    section: Option<usize>,
+}
+
+/// Builder for laying out a new ELF object.
+///
+/// Section 0 is always the NULL section header, so the first added section has index 1.
+/// The builder appends the `.symtab` and `.strtab` sections (if any symbols were added)
+/// followed by the `.shstrtab` after all the user-added sections.
+#[derive(Debug)]
+pub struct ElfBuilder<E: EndianParse> {
+    /// The file header for the new object. The fields describing the layout of the
+    /// program and section header tables get filled in by [ElfBuilder::build].
+    pub ehdr: FileHeader<E>,
+    sections: Vec<BuilderSection>,
+    segments: Vec<BuilderSegment>,
+    symbols: SymbolTableBuilder,
+}
+
+/// Round `offset` up to the next value that is congruent to `addr` modulo `align`.
+/// For a zero `addr`, this is just the usual alignment of `offset` to `align`.
+fn align_offset(offset: usize, addr: u64, align: u64) -> Result<usize, ParseError> {
+    if align <= 1 {
+        return Ok(offset);
+    }
+    let align: usize = align.try_into()?;
+    let want = (addr % align as u64) as usize;
+    let have = offset % align;
+    let pad = (want + align - have) % align;
+    offset.checked_add(pad).ok_or(ParseError::IntegerOverflow)
+}
+
+impl<E: EndianParse> ElfBuilder<E> {
+    /// Create a new builder for an object of the given class, byte order, type and machine.
+    pub fn new(endian: E, class: Class, e_type: u16, e_machine: u16) -> Self {
+        ElfBuilder {
+            ehdr: FileHeader {
+                class,
+                endianness: endian,
+                version: abi::EV_CURRENT as u32,
+                osabi: abi::ELFOSABI_NONE,
+                abiversion: 0,
+                ident_version: abi::EV_CURRENT,
+                ident_pad: [0; 7],
+                e_type,
+                e_machine,
+                e_entry: 0,
+                e_phoff: 0,
+                e_shoff: 0,
+                e_flags: 0,
+                e_ehsize: 0,
+                e_phentsize: 0,
+                e_phnum: 0,
+                e_shentsize: 0,
+                e_shnum: 0,
+                e_shstrndx: 0,
+            },
+            sections: Vec::new(),
+            segments: Vec::new(),
+            symbols: SymbolTableBuilder::new(),
+        }
+    }
+
+    /// Append a section with the given name and contents, returning its section header index.
+    ///
+    /// The `sh_name`, `sh_offset` and `sh_size` fields of the given `shdr` are filled in when
+    /// the object is built. The exception is [abi::SHT_NOBITS] sections, which occupy no
+    /// space in the file and keep the given `sh_size`.
+    pub fn add_section(&mut self, name: &str, shdr: SectionHeader, data: &[u8]) -> usize {
+        self.sections.push(BuilderSection {
+            name: String::from(name),
+            shdr,
+            data: data.to_vec(),
+        });
+        self.sections.len()
+    }
+
+    /// Append a program header. If a `section` index is given, then the segment's `p_offset`
+    /// and `p_filesz` are filled in to cover that section's data, and its `p_memsz` is raised
+    /// to at least the section's size. All other fields are written out as given.
+    pub fn add_segment(&mut self, phdr: ProgramHeader, section: Option<usize>) {
+        self.segments.push(BuilderSegment { phdr, section });
+    }
+
+    /// Add a symbol to the generated `.symtab`, with its name in the generated `.strtab`.
+    ///
+    /// The `st_name` field of the given `sym` is filled in when the object is built. The
+    /// symbol table starts with the NULL symbol, and [abi::STB_LOCAL] symbols are placed
+    /// before all others as required by the gABI, but otherwise symbols keep the order they
+    /// were added in.
+    pub fn add_symbol(&mut self, name: &str, sym: Symbol) {
+        self.symbols.add_symbol(name, sym);
+    }
+
+    /// Lay out all the headers, sections and tables and serialize them into a new ELF object.
+    pub fn build(&self) -> Result<Vec<u8>, ParseError> {
+        let class = self.ehdr.class;
+        let endian = self.ehdr.endianness;
+        let word_align: u64 = match class {
+            Class::ELF32 => 4,
+            Class::ELF64 => 8,
+        };
+
+        // Collect up the final list of sections, including the generated ones
+        let mut sections: Vec<(&str, SectionHeader, &[u8])> = self
+            .sections
+            .iter()
+            .map(|s| (s.name.as_str(), s.shdr, s.data.as_slice()))
+            .collect();
+
+        let symbols;
+        if !self.symbols.is_empty() {
+            symbols = self.symbols.build(endian, class)?;
+            let strtab_idx = sections.len() + 2;
+            sections.push((
+                ".symtab",
+                symbols.symtab_header(strtab_idx.try_into()?),
+                &symbols.symtab,
+            ));
+            sections.push((".strtab", symbols.strtab_header(), &symbols.strtab));
+        }
+
+        // Build up the section names, with the .shstrtab itself being the last section
+        let mut shstrtab = StringTableBuilder::with_tail_merging();
+        let mut names = Vec::with_capacity(sections.len() + 1);
+        for (name, _, _) in sections.iter() {
+            names.push(shstrtab.insert(name.as_bytes()));
+        }
+        names.push(shstrtab.insert(b".shstrtab"));
+        let shstrtab_data = shstrtab.build();
+        sections.push((
+            ".shstrtab",
+            SectionHeader {
+                sh_type: abi::SHT_STRTAB,
+                sh_addralign: 1,
+                ..Default::default()
+            },
+            &shstrtab_data,
+        ));
+
+        // Lay out the file header, then the program headers, then the section data,
+        // and then the section headers.
+        let ehsize = abi::EI_NIDENT
+            + match class {
+                Class::ELF32 => ELF32_EHDR_TAILSIZE,
+                Class::ELF64 => ELF64_EHDR_TAILSIZE,
+            };
+        let phentsize = ProgramHeader::size_for(class);
+        let shentsize = SectionHeader::size_for(class);
+        let mut offset = ehsize;
+
+        let phoff = if self.segments.is_empty() {
+            0
+        } else {
+            align_offset(offset, 0, word_align)?
+        };
+        if !self.segments.is_empty() {
+            let phdrs_size = phentsize
+                .checked_mul(self.segments.len())
+                .ok_or(ParseError::IntegerOverflow)?;
+            offset = phoff
+                .checked_add(phdrs_size)
+                .ok_or(ParseError::IntegerOverflow)?;
+        }
+
+        let mut shdrs = Vec::with_capacity(sections.len() + 1);
+        shdrs.push(SectionHeader::default());
+        for ((_, shdr, data), sh_name) in sections.iter().zip(names) {
+            let mut shdr = *shdr;
+            shdr.sh_name = sh_name;
+            offset = align_offset(offset, shdr.sh_addr, shdr.sh_addralign)?;
+            shdr.sh_offset = offset as u64;
+            if shdr.sh_type != abi::SHT_NOBITS {
+                shdr.sh_size = data.len() as u64;
+                offset = offset
+                    .checked_add(data.len())
+                    .ok_or(ParseError::IntegerOverflow)?;
+            }
+            shdrs.push(shdr);
+        }
+
+        let shoff = align_offset(offset, 0, word_align)?;
+        let shdrs_size = shentsize
+            .checked_mul(shdrs.len())
+            .ok_or(ParseError::IntegerOverflow)?;
+        let total_size = shoff
+            .checked_add(shdrs_size)
+            .ok_or(ParseError::IntegerOverflow)?;
+
+        // Fill in the file header's layout fields, using the extended numbering in
+        // section 0 for counts and indexes that don't fit in the file header.
+        let mut ehdr = self.ehdr;
+        ehdr.e_phoff = phoff as u64;
+        ehdr.e_shoff = shoff as u64;
+        ehdr.e_ehsize = ehsize as u16;
+        ehdr.e_phentsize = phentsize as u16;
+        ehdr.e_shentsize = shentsize as u16;
+        match u16::try_from(self.segments.len()) {
+            Ok(phnum) if phnum < abi::PN_XNUM => ehdr.e_phnum = phnum,
+            _ => {
+                ehdr.e_phnum = abi::PN_XNUM;
+                shdrs[0].sh_info = self.segments.len().try_into()?;
+            }
+        }
+        match u16::try_from(shdrs.len()) {
+            Ok(shnum) if shnum < abi::SHN_LORESERVE => ehdr.e_shnum = shnum,
+            _ => {
+                ehdr.e_shnum = 0;
+                shdrs[0].sh_size = shdrs.len() as u64;
+            }
+        }
+        let shstrndx = shdrs.len() - 1;
+        match u16::try_from(shstrndx) {
+            Ok(shstrndx) if shstrndx < abi::SHN_LORESERVE => ehdr.e_shstrndx = shstrndx,
+            _ => {
+                ehdr.e_shstrndx = abi::SHN_XINDEX;
+                shdrs[0].sh_link = shstrndx.try_into()?;
+            }
+        }
+
+        // Now write it all out
+        let mut buf = vec![0u8; total_size];
+        let mut offset = 0;
+        ehdr.serialize_at(endian, class, &mut offset, &mut buf)?;
+
+        let mut offset = phoff;
+        for segment in self.segments.iter() {
+            let mut phdr = segment.phdr;
+            if let Some(idx) = segment.section {
+                let shdr = shdrs.get(idx).ok_or(ParseError::BadOffset(idx as u64))?;
+                phdr.p_offset = shdr.sh_offset;
+                phdr.p_filesz = match shdr.sh_type {
+                    abi::SHT_NOBITS => 0,
+                    _ => shdr.sh_size,
+                };
+                phdr.p_memsz = phdr.p_memsz.max(shdr.sh_size);
+            }
+            phdr.serialize_at(endian, class, &mut offset, &mut buf)?;
+        }
+
+        for ((_, _, data), shdr) in sections.iter().zip(shdrs.iter().skip(1)) {
+            if shdr.sh_type == abi::SHT_NOBITS {
+                continue;
+            }
+            let start = shdr.sh_offset as usize;
+            buf[start..start + data.len()].copy_from_slice(data);
+        }
+
+        let mut offset = shoff;
+        for shdr in shdrs.iter() {
+            shdr.serialize_at(endian, class, &mut offset, &mut buf)?;
+        }
+
+        Ok(buf)
+    }
+}
+
+#[cfg(test)]
+mod builder_tests {
+    use super::*;
+    use crate::endian::{AnyEndian, BigEndian, LittleEndian};
+    use crate::ElfBytes;
+
+    fn text_shdr() -> SectionHeader {
+        SectionHeader {
+            sh_type: abi::SHT_PROGBITS,
+            sh_flags: (abi::SHF_ALLOC | abi::SHF_EXECINSTR) as u64,
+            sh_addralign: 16,
+            ..Default::default()
+        }
+    }
+
+    /// Check that no two sections' file ranges overlap with each other or the headers,
+    /// and that they respect their alignments.
+    fn check_layout<E: EndianParse>(file: &ElfBytes<'_, E>, file_len: usize) {
+        let shdrs = file.section_headers().expect("should have shdrs");
+        assert_eq!(
+            shdrs.get(0).expect("should parse"),
+            SectionHeader::default()
+        );
+
+        let mut ranges = Vec::new();
+        ranges.push((0, file.ehdr.e_ehsize as u64));
+        ranges.push((
+            file.ehdr.e_phoff,
+            file.ehdr.e_phoff + file.ehdr.e_phnum as u64 * file.ehdr.e_phentsize as u64,
+        ));
+        ranges.push((
+            file.ehdr.e_shoff,
+            file.ehdr.e_shoff + shdrs.len() as u64 * file.ehdr.e_shentsize as u64,
+        ));
+        for shdr in shdrs.iter().skip(1) {
+            if shdr.sh_addralign > 1 {
+                assert_eq!(shdr.sh_offset % shdr.sh_addralign, 0);
+            }
+            if shdr.sh_type != abi::SHT_NOBITS {
+                ranges.push((shdr.sh_offset, shdr.sh_offset + shdr.sh_size));
+            }
+        }
+        ranges.retain(|(start, end)| start != end);
+        ranges.sort();
+        for pair in ranges.windows(2) {
+            assert!(pair[0].1 <= pair[1].0, "{pair:?} overlap");
+        }
+        assert!(ranges.last().unwrap().1 as usize <= file_len);
+    }
+
+    #[test]
+    fn build_relocatable() {
+        let mut builder = ElfBuilder::new(LittleEndian, Class::ELF64, abi::ET_REL, abi::EM_X86_64);
+        let text = builder.add_section(".text", text_shdr(), &[0x55, 0x48, 0x89, 0xe5, 0xc3]);
+        let data = builder.add_section(
+            ".data",
+            SectionHeader {
+                sh_type: abi::SHT_PROGBITS,
+                sh_flags: (abi::SHF_ALLOC | abi::SHF_WRITE) as u64,
+                sh_addralign: 8,
+                ..Default::default()
+            },
+            &[1, 2, 3, 4, 5, 6, 7, 8],
+        );
+        let bss = builder.add_section(
+            ".bss",
+            SectionHeader {
+                sh_type: abi::SHT_NOBITS,
+                sh_flags: (abi::SHF_ALLOC | abi::SHF_WRITE) as u64,
+                sh_addralign: 32,
+                sh_size: 0x100,
+                ..Default::default()
+            },
+            &[],
+        );
+        assert_eq!((text, data, bss), (1, 2, 3));
+
+        builder.add_symbol(
+            "main",
+            Symbol {
+                st_info: (abi::STB_GLOBAL << 4) | abi::STT_FUNC,
+                st_shndx: text as u16,
+                st_size: 5,
+                ..Default::default()
+            },
+        );
+        builder.add_symbol(
+            "counter",
+            Symbol {
+                st_info: (abi::STB_LOCAL << 4) | abi::STT_OBJECT,
+                st_shndx: data as u16,
+                st_size: 8,
+                ..Default::default()
+            },
+        );
+
+        let bytes = builder.build().expect("should build");
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&bytes).expect("should parse");
+        check_layout(&file, bytes.len());
+        assert_eq!(file.ehdr.e_type, abi::ET_REL);
+        assert_eq!(file.ehdr.e_machine, abi::EM_X86_64);
+        assert_eq!(file.ehdr.e_phnum, 0);
+        assert_eq!(file.ehdr.e_shnum, 7);
+
+        let (shdrs, strtab) = file.section_headers_with_strtab().expect("should parse");
+        let (shdrs, strtab) = (shdrs.unwrap(), strtab.unwrap());
+        let names: Vec<&str> = shdrs
+            .iter()
+            .map(|shdr| strtab.get(shdr.sh_name as usize).unwrap())
+            .collect();
+        assert_eq!(
+            names,
+            [
+                "",
+                ".text",
+                ".data",
+                ".bss",
+                ".symtab",
+                ".strtab",
+                ".shstrtab"
+            ]
+        );
+
+        let text_shdr = shdrs.get(text).unwrap();
+        assert_eq!(
+            file.section_data(&text_shdr).unwrap().0,
+            &[0x55, 0x48, 0x89, 0xe5, 0xc3]
+        );
+        let bss_shdr = shdrs.get(bss).unwrap();
+        assert_eq!(bss_shdr.sh_size, 0x100);
+
+        let (symtab, strtab) = file.symbol_table().unwrap().unwrap();
+        let symtab_shdr = shdrs.get(4).unwrap();
+        assert_eq!(symtab_shdr.sh_link, 5);
+        // The local symbol got placed first, after the NULL symbol
+        assert_eq!(symtab_shdr.sh_info, 2);
+        let syms: Vec<(&str, u16)> = symtab
+            .iter()
+            .map(|sym| (strtab.get(sym.st_name as usize).unwrap(), sym.st_shndx))
+            .collect();
+        assert_eq!(syms, [("", 0), ("counter", 2), ("main", 1)]);
+    }
+
+    #[test]
+    fn build_executable() {
+        let mut builder = ElfBuilder::new(BigEndian, Class::ELF32, abi::ET_EXEC, abi::EM_PPC);
+        builder.ehdr.e_entry = 0x10000074;
+        let text = builder.add_section(
+            ".text",
+            SectionHeader {
+                sh_addr: 0x10000074,
+                sh_addralign: 0x1000,
+                ..text_shdr()
+            },
+            &[0x4e, 0x80, 0x00, 0x20],
+        );
+        builder.add_segment(
+            ProgramHeader {
+                p_type: abi::PT_LOAD,
+                p_vaddr: 0x10000074,
+                p_paddr: 0x10000074,
+                p_flags: abi::PF_R | abi::PF_X,
+                p_align: 0x1000,
+                ..Default::default()
+            },
+            Some(text),
+        );
+
+        let bytes = builder.build().expect("should build");
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&bytes).expect("should parse");
+        assert_eq!(file.ehdr.endianness, AnyEndian::Big);
+        assert_eq!(file.ehdr.class, Class::ELF32);
+        assert_eq!(file.ehdr.e_entry, 0x10000074);
+
+        let phdr = file.segments().unwrap().get(0).unwrap();
+        let shdr = file.section_headers().unwrap().get(text).unwrap();
+        // The section's file offset is congruent to its address modulo its alignment
+        assert_eq!(shdr.sh_offset % 0x1000, 0x74);
+        assert_eq!(phdr.p_offset, shdr.sh_offset);
+        assert_eq!(phdr.p_filesz, 4);
+        assert_eq!(phdr.p_memsz, 4);
+        assert_eq!(file.segment_data(&phdr).unwrap(), &[0x4e, 0x80, 0x00, 0x20]);
+    }
+
+    #[test]
+    fn build_empty() {
+        let builder = ElfBuilder::new(LittleEndian, Class::ELF32, abi::ET_REL, abi::EM_386);
+        let bytes = builder.build().expect("should build");
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&bytes).expect("should parse");
+        check_layout(&file, bytes.len());
+        assert!(file.segments().is_none());
+        assert_eq!(file.ehdr.e_shnum, 2);
+        assert_eq!(file.ehdr.e_shstrndx, 1);
+        assert!(file.symbol_table().unwrap().is_none());
+    }
+
+    #[test]
+    fn build_extended_section_numbering() {
+        let mut builder = ElfBuilder::new(LittleEndian, Class::ELF64, abi::ET_REL, abi::EM_X86_64);
+        for _ in 0..abi::SHN_LORESERVE {
+            builder.add_section(".text", text_shdr(), &[]);
+        }
+        let bytes = builder.build().expect("should build");
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&bytes).expect("should parse");
+        assert_eq!(file.ehdr.e_shnum, 0);
+        assert_eq!(file.ehdr.e_shstrndx, abi::SHN_XINDEX);
+
+        let shdrs = file.section_headers().unwrap();
+        assert_eq!(shdrs.len(), abi::SHN_LORESERVE as usize + 2);
+        let shdr0 = shdrs.get(0).unwrap();
+        assert_eq!(shdr0.sh_size, abi::SHN_LORESERVE as u64 + 2);
+        assert_eq!(shdr0.sh_link, abi::SHN_LORESERVE as u32 + 1);
+
+        let shstrtab = file
+            .section_header_by_name(".shstrtab")
+            .expect("should parse")
+            .expect("should find .shstrtab");
+        assert_eq!(shstrtab.sh_type, abi::SHT_STRTAB);
+    }
+}
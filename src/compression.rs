@@ -1,13 +1,23 @@
 //! Parsing [CompressionHeader] from compressed ELF sections
 //!
-//! Note: This library does not provide any decompression functionality, but
-//! does expose parsed ELF compression headers alongside the raw compressed data.
+//! Note: This library doesn't decompress section data by default, but does expose
+//! parsed ELF compression headers alongside the raw compressed data.
 //!
 //! It is up to users of the library to choose the decompression library of
-//! their choice when dealing with compressed section contents.
+//! their choice when dealing with compressed section contents. Alternatively, the `zlib`
+//! feature enables [ElfBytes::debug_section](crate::ElfBytes::debug_section) to decompress
+//! zlib-compressed debug sections, in either the [SHF_COMPRESSED](crate::abi::SHF_COMPRESSED)
+//! format or the legacy GNU `.zdebug_*` format, which starts with a [ZDEBUG_MAGIC] header.
 use crate::endian::EndianParse;
 use crate::file::Class;
-use crate::parse::{ParseAt, ParseError};
+use crate::parse::{ParseAt, ParseError, ReadBytesExt, SerializeAt};
+
+/// The magic bytes at the start of a legacy GNU `.zdebug_*` section
+pub const ZDEBUG_MAGIC: &[u8] = b"ZLIB";
+
+/// The size of a `.zdebug_*` section's header: the [ZDEBUG_MAGIC] and the big-endian
+/// 8-byte uncompressed size
+pub const ZDEBUG_HEADER_SIZE: usize = 12;
 
 /// C-style 32-bit ELF Compression Header definition
 ///
@@ -32,6 +42,7 @@ pub struct Elf64_Chdr {
     pub ch_addralign: u64,
 }
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct CompressionHeader {
     pub ch_type: u32,
@@ -39,6 +50,9 @@ pub struct CompressionHeader {
     pub ch_addralign: u64,
 }
 
+pub const ELF32_CHDR_SIZE: usize = 12;
+pub const ELF64_CHDR_SIZE: usize = 24;
+
 impl ParseAt for CompressionHeader {
     fn parse_at<E: EndianParse>(
         endian: E,
@@ -67,12 +81,116 @@ impl ParseAt for CompressionHeader {
     #[inline]
     fn size_for(class: Class) -> usize {
         match class {
-            Class::ELF32 => 12,
-            Class::ELF64 => 24,
+            Class::ELF32 => ELF32_CHDR_SIZE,
+            Class::ELF64 => ELF64_CHDR_SIZE,
+        }
+    }
+}
+
+impl SerializeAt for CompressionHeader {
+    fn serialize_at<E: EndianParse>(
+        &self,
+        endian: E,
+        class: Class,
+        offset: &mut usize,
+        data: &mut [u8],
+    ) -> Result<(), ParseError> {
+        match class {
+            Class::ELF32 => {
+                endian.write_u32_at(offset, data, self.ch_type)?;
+                endian.write_u32_at(offset, data, self.ch_size.try_into()?)?;
+                endian.write_u32_at(offset, data, self.ch_addralign.try_into()?)
+            }
+            Class::ELF64 => {
+                endian.write_u32_at(offset, data, self.ch_type)?;
+                // ch_reserved
+                endian.write_u32_at(offset, data, 0)?;
+                endian.write_u64_at(offset, data, self.ch_size)?;
+                endian.write_u64_at(offset, data, self.ch_addralign)
+            }
         }
     }
 }
 
+/// Parse the header of a legacy GNU `.zdebug_*` section, returning the uncompressed size and
+/// the zlib stream that follows the header.
+pub fn parse_zdebug_header(data: &[u8]) -> Result<(u64, &[u8]), ParseError> {
+    let header = data.get_bytes(0..ZDEBUG_HEADER_SIZE)?;
+    if &header[..4] != ZDEBUG_MAGIC {
+        return Err(ParseError::BadMagic([
+            header[0], header[1], header[2], header[3],
+        ]));
+    }
+    let size = u64::from_be_bytes(header[4..].try_into()?);
+    Ok((size, &data[ZDEBUG_HEADER_SIZE..]))
+}
+
+/// Decompress a zlib stream which should decompress to exactly `size` bytes, failing with a
+/// [ParseError::IOError] if the data is corrupt or decompresses to a different size.
+#[cfg(feature = "zlib")]
+pub fn decompress_zlib(data: &[u8], size: u64) -> Result<Vec<u8>, ParseError> {
+    use std::io::{Error, ErrorKind};
+
+    let size: usize = size.try_into()?;
+    let output = miniz_oxide::inflate::decompress_to_vec_zlib_with_limit(data, size)
+        .map_err(|err| Error::new(ErrorKind::InvalidData, format!("{:?}", err.status)))?;
+    if output.len() != size {
+        return Err(ParseError::IOError(Error::new(
+            ErrorKind::InvalidData,
+            format!(
+                "decompressed {} bytes, but expected {size} bytes",
+                output.len()
+            ),
+        )));
+    }
+    Ok(output)
+}
+
+#[cfg(test)]
+mod zdebug_tests {
+    use super::*;
+
+    #[test]
+    fn zdebug_header() {
+        let data = b"ZLIB\0\0\0\0\0\0\x01\x02stream";
+        let (size, stream) = parse_zdebug_header(data).expect("should parse");
+        assert_eq!(size, 0x102);
+        assert_eq!(stream, b"stream");
+
+        assert!(matches!(
+            parse_zdebug_header(b"ZLIX\0\0\0\0\0\0\0\0"),
+            Err(ParseError::BadMagic([b'Z', b'L', b'I', b'X']))
+        ));
+        assert!(matches!(
+            parse_zdebug_header(b"ZLIB\0\0"),
+            Err(ParseError::SliceReadError(_))
+        ));
+    }
+
+    #[cfg(feature = "zlib")]
+    #[test]
+    fn decompress() {
+        let data = b"hello hello hello hello";
+        let compressed = miniz_oxide::deflate::compress_to_vec_zlib(data, 6);
+        let output = decompress_zlib(&compressed, data.len() as u64).expect("should decompress");
+        assert_eq!(output, data);
+
+        // The wrong size either way
+        assert!(matches!(
+            decompress_zlib(&compressed, data.len() as u64 - 1),
+            Err(ParseError::IOError(_))
+        ));
+        assert!(matches!(
+            decompress_zlib(&compressed, data.len() as u64 + 1),
+            Err(ParseError::IOError(_))
+        ));
+        assert!(matches!(
+            decompress_zlib(b"not zlib", 10),
+            Err(ParseError::IOError(_))
+        ));
+    }
+}
+
 #[cfg(test)]
 mod parse_tests {
     use super::*;
@@ -151,3 +269,66 @@ mod parse_tests {
         test_parse_fuzz_too_short::<_, CompressionHeader>(BigEndian, Class::ELF64);
     }
 }
+
+#[cfg(test)]
+mod serialize_tests {
+    use super::*;
+    use crate::endian::{BigEndian, LittleEndian};
+    use crate::parse::test_serialize_roundtrip;
+
+    #[test]
+    fn serialize_chdr32_lsb_roundtrip() {
+        test_serialize_roundtrip::<_, CompressionHeader>(LittleEndian, Class::ELF32);
+    }
+
+    #[test]
+    fn serialize_chdr32_msb_roundtrip() {
+        test_serialize_roundtrip::<_, CompressionHeader>(BigEndian, Class::ELF32);
+    }
+
+    #[test]
+    fn serialize_chdr64_zeroes_reserved() {
+        let chdr = CompressionHeader {
+            ch_type: 0x03020100,
+            ch_size: 0x0F0E0D0C0B0A0908,
+            ch_addralign: 0x1716151413121110,
+        };
+        let mut buf = [0xffu8; 24];
+        let mut offset = 0;
+        chdr.serialize_at(LittleEndian, Class::ELF64, &mut offset, &mut buf)
+            .expect("Failed to serialize");
+        assert_eq!(offset, 24);
+        assert_eq!(
+            buf,
+            [
+                0, 1, 2, 3, 0, 0, 0, 0, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22,
+                23
+            ]
+        );
+
+        let mut offset = 0;
+        let parsed = CompressionHeader::parse_at(BigEndian, Class::ELF64, &mut offset, &buf)
+            .expect("Failed to parse");
+        let mut offset = 0;
+        parsed
+            .serialize_at(BigEndian, Class::ELF64, &mut offset, &mut buf)
+            .expect("Failed to serialize");
+        assert_eq!(buf[4..8], [0, 0, 0, 0]);
+    }
+}
+
+#[cfg(all(test, feature = "serde"))]
+mod serde_tests {
+    use super::*;
+    use crate::parse::test_serde_roundtrip;
+
+    #[test]
+    fn serde_compression_header() {
+        let chdr = CompressionHeader {
+            ch_type: 1,
+            ch_size: 1024,
+            ch_addralign: 8,
+        };
+        test_serde_roundtrip(&chdr, r#"{"ch_type":1,"ch_size":1024,"ch_addralign":8}"#);
+    }
+}
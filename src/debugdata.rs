@@ -0,0 +1,105 @@
+//! Decompressing the MiniDebugInfo in `.gnu_debugdata` sections (requires the `xz` feature)
+//!
+//! Some distributions strip their binaries' symbol tables but embed a trimmed-down ELF object
+//! holding just the function symbols in a `.gnu_debugdata` section, compressed with xz. This
+//! lets debuggers and profilers symbolize backtraces without the full separate debug info.
+//!
+//! Example usage:
+//! ```
+//! use elf::endian::AnyEndian;
+//! use elf::ElfBytes;
+//!
+//! let path = std::path::PathBuf::from("sample-objects/minidebuginfo.x86_64");
+//! let file_data = std::fs::read(path).expect("Could not read file.");
+//! let file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("Open test1");
+//! assert!(file.symbol_table().expect("should parse").is_none());
+//!
+//! let mini = file
+//!     .gnu_debugdata_elf()
+//!     .expect("section should decompress")
+//!     .expect("file should have a .gnu_debugdata section");
+//! let mini = mini.elf_bytes().expect("should parse");
+//! let (symtab, strtab) = mini.symbol_table().expect("should parse").unwrap();
+//! assert!(symtab
+//!     .iter()
+//!     .any(|sym| matches!(strtab.get(sym.st_name as usize), Ok("main"))));
+//! ```
+use crate::parse::ParseError;
+use std::io::{Error, ErrorKind, Write};
+
+/// The name of the section holding the compressed MiniDebugInfo
+pub const GNU_DEBUGDATA_SECTION_NAME: &str = ".gnu_debugdata";
+
+/// The default limit on the decompressed size of a `.gnu_debugdata` section, to guard
+/// against decompression bombs.
+pub const DEFAULT_GNU_DEBUGDATA_LIMIT: usize = 64 * 1024 * 1024;
+
+/// A writer that collects up to `limit` bytes and then errors.
+struct LimitedWriter {
+    buf: Vec<u8>,
+    limit: usize,
+}
+
+impl Write for LimitedWriter {
+    fn write(&mut self, data: &[u8]) -> std::io::Result<usize> {
+        if self.buf.len().saturating_add(data.len()) > self.limit {
+            return Err(Error::new(
+                ErrorKind::Other,
+                format!(
+                    "decompressed data exceeds the limit of {} bytes",
+                    self.limit
+                ),
+            ));
+        }
+        self.buf.extend_from_slice(data);
+        Ok(data.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Decompress the xz-compressed contents of a `.gnu_debugdata` section, failing with a
+/// [ParseError::IOError] if the data is corrupt or would decompress to more than `limit` bytes.
+pub fn decompress_gnu_debugdata(data: &[u8], limit: usize) -> Result<Vec<u8>, ParseError> {
+    let mut input = data;
+    let mut output = LimitedWriter {
+        buf: Vec::new(),
+        limit,
+    };
+    lzma_rs::xz_decompress(&mut input, &mut output).map_err(|err| match err {
+        lzma_rs::error::Error::IoError(err) => ParseError::IOError(err),
+        err => ParseError::IOError(Error::new(ErrorKind::InvalidData, err.to_string())),
+    })?;
+    Ok(output.buf)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decompress_with_limit() {
+        let mut compressed = Vec::new();
+        lzma_rs::xz_compress(&mut &[7u8; 1000][..], &mut compressed).expect("should compress");
+
+        let data = decompress_gnu_debugdata(&compressed, 1000).expect("should decompress");
+        assert_eq!(data, [7u8; 1000]);
+
+        let result = decompress_gnu_debugdata(&compressed, 999);
+        assert!(
+            matches!(result, Err(ParseError::IOError(_))),
+            "Unexpected Error type found: {result:?}"
+        );
+    }
+
+    #[test]
+    fn decompress_corrupt_data() {
+        let result = decompress_gnu_debugdata(b"not xz data", DEFAULT_GNU_DEBUGDATA_LIMIT);
+        assert!(
+            matches!(result, Err(ParseError::IOError(_))),
+            "Unexpected Error type found: {result:?}"
+        );
+    }
+}
@@ -0,0 +1,323 @@
+//! Parsing the `.gnu_debuglink` and `.gnu_debugaltlink` sections which point to separate
+//! debug info files
+//!
+//! A `.gnu_debuglink` section holds the file name of the separate debug file along with a
+//! CRC32 of its contents, which can be compared with [gnu_debuglink_crc] of a candidate debug
+//! file to check that it's the right one. A `.gnu_debugaltlink` section (as written by `dwz`)
+//! holds the file name of a supplementary debug file shared between objects, along with that
+//! file's build-id.
+//!
+//! Example usage:
+//! ```
+//! use elf::debuglink::gnu_debuglink_crc;
+//! use elf::endian::AnyEndian;
+//! use elf::ElfBytes;
+//!
+//! let path = std::path::PathBuf::from("sample-objects/debuglink.x86_64");
+//! let file_data = std::fs::read(path).expect("Could not read file.");
+//! let file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("Open test1");
+//!
+//! let (name, crc) = file
+//!     .gnu_debuglink()
+//!     .expect("section should parse")
+//!     .expect("file should have a .gnu_debuglink section");
+//! assert_eq!(name, b"basic.x86_64.debug");
+//!
+//! let debug_data = std::fs::read("sample-objects/basic.x86_64.debug").expect("Could not read file.");
+//! assert_eq!(gnu_debuglink_crc(&debug_data), crc);
+//! ```
+//!
+//! With the `std` feature, [debug_file_candidates] lists the paths where debuggers look for an
+//! object's separate debug file, based on its build-id and `.gnu_debuglink`.
+use crate::endian::EndianParse;
+use crate::parse::{ParseError, ReadBytesExt};
+#[cfg(feature = "std")]
+use crate::ElfBytes;
+#[cfg(feature = "std")]
+use std::path::{Component, Path, PathBuf};
+
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::string::String;
+
+/// The name of the section holding the debug link
+pub const GNU_DEBUGLINK_SECTION_NAME: &str = ".gnu_debuglink";
+/// The name of the section holding the alternate debug link
+pub const GNU_DEBUGALTLINK_SECTION_NAME: &str = ".gnu_debugaltlink";
+
+/// The lookup table for the reflected CRC-32 polynomial 0xedb88320 used by GDB
+const CRC32_TABLE: [u32; 256] = crc32_table();
+
+const fn crc32_table() -> [u32; 256] {
+    let mut table = [0u32; 256];
+    let mut i = 0;
+    while i < 256 {
+        let mut crc = i as u32;
+        let mut bit = 0;
+        while bit < 8 {
+            crc = if crc & 1 != 0 {
+                0xedb88320 ^ (crc >> 1)
+            } else {
+                crc >> 1
+            };
+            bit += 1;
+        }
+        table[i] = crc;
+        i += 1;
+    }
+    table
+}
+
+/// Calculate the CRC32 of a debug file's contents, as recorded in a `.gnu_debuglink` section.
+///
+/// This is the CRC that GDB documents for separate debug files, which is the same CRC-32 used
+/// by zlib and gzip.
+pub fn gnu_debuglink_crc(data: &[u8]) -> u32 {
+    let mut crc = !0u32;
+    for byte in data {
+        crc = CRC32_TABLE[((crc ^ *byte as u32) & 0xff) as usize] ^ (crc >> 8);
+    }
+    !crc
+}
+
+/// Split a NUL-terminated file name off of the start of a debug link section's data, returning
+/// the name (without the NUL) and the offset just past its NUL.
+fn parse_name(data: &[u8]) -> Result<(&[u8], usize), ParseError> {
+    let len = data
+        .iter()
+        .position(|byte| *byte == 0)
+        .ok_or(ParseError::StringTableMissingNul(0))?;
+    Ok((&data[..len], len + 1))
+}
+
+/// Parse the contents of a `.gnu_debuglink` section into the debug file's name and its CRC.
+///
+/// The name is NUL-terminated and padded out to a 4-byte boundary, followed by the 4-byte CRC
+/// in the object's byte order.
+pub fn parse_gnu_debuglink<E: EndianParse>(
+    endian: E,
+    data: &[u8],
+) -> Result<(&[u8], u32), ParseError> {
+    let (name, end) = parse_name(data)?;
+    let mut offset = end.checked_add(3).ok_or(ParseError::IntegerOverflow)? & !3;
+    let crc = endian.parse_u32_at(&mut offset, data)?;
+    Ok((name, crc))
+}
+
+/// The contents of a `.gnu_debugaltlink` section
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GnuDebugAltLink<'data> {
+    /// The supplementary debug file's name
+    pub name: &'data [u8],
+    /// The supplementary debug file's build-id
+    pub build_id: &'data [u8],
+}
+
+/// Parse the contents of a `.gnu_debugaltlink` section into the supplementary debug file's
+/// name and its build-id.
+pub fn parse_gnu_debugaltlink(data: &[u8]) -> Result<GnuDebugAltLink<'_>, ParseError> {
+    let (name, end) = parse_name(data)?;
+    let build_id = data.get_bytes(end..data.len())?;
+    Ok(GnuDebugAltLink { name, build_id })
+}
+
+/// The directory holding separate debug files, relative to the sysroot
+pub const DEBUG_FILE_DIRECTORY: &str = "usr/lib/debug";
+
+/// Format a build-id as the lowercase hex string which debuginfod servers and caches use as
+/// its key, as in `/buildid/<hex>/debuginfo`.
+#[cfg(feature = "alloc")]
+pub fn build_id_hex(build_id: &[u8]) -> String {
+    use core::fmt::Write;
+
+    let mut hex = String::with_capacity(build_id.len() * 2);
+    for byte in build_id {
+        // Writing to a String can't fail
+        let _ = write!(hex, "{byte:02x}");
+    }
+    hex
+}
+
+/// List the paths where debuggers look for the separate debug file of the object at `path`,
+/// in GDB's search order:
+///
+/// 1. `<sysroot>/usr/lib/debug/.build-id/xx/rest.debug`, from the GNU build-id note, where
+///    `xx` is the first byte of the build-id in hex and `rest` is the rest of it
+/// 2. `<dir>/<debuglink>`, where `<dir>` is the directory holding the object and
+///    `<debuglink>` is the file name from the `.gnu_debuglink` section
+/// 3. `<dir>/.debug/<debuglink>`
+/// 4. `<sysroot>/usr/lib/debug/<dir>/<debuglink>`
+///
+/// Candidates which need a build-id or debug link that the object doesn't have are left out.
+/// This doesn't touch the filesystem, so `path` should already be absolute and canonical for
+/// the last candidate to be meaningful. Use a `sysroot` of `/` for the host's own objects.
+#[cfg(feature = "std")]
+pub fn debug_file_candidates<E: EndianParse>(
+    file: &ElfBytes<'_, E>,
+    path: &Path,
+    sysroot: &Path,
+) -> Result<Vec<PathBuf>, ParseError> {
+    let debug_dir = sysroot.join(DEBUG_FILE_DIRECTORY);
+    let mut candidates = Vec::new();
+
+    if let Some(build_id) = file.gnu_build_id()? {
+        if build_id.0.len() >= 2 {
+            let hex = build_id_hex(build_id.0);
+            let (first, rest) = hex.split_at(2);
+            candidates.push(
+                debug_dir
+                    .join(".build-id")
+                    .join(first)
+                    .join(format!("{rest}.debug")),
+            );
+        }
+    }
+
+    if let Some((name, _)) = file.gnu_debuglink()? {
+        let name = core::str::from_utf8(name)?;
+        let dir = path.parent().unwrap_or_else(|| Path::new(""));
+        candidates.push(dir.join(name));
+        candidates.push(dir.join(".debug").join(name));
+        // Re-root the object's directory under the debug directory
+        let relative_dir: PathBuf = dir
+            .components()
+            .filter(|component| !matches!(component, Component::Prefix(_) | Component::RootDir))
+            .collect();
+        candidates.push(debug_dir.join(relative_dir).join(name));
+    }
+
+    Ok(candidates)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::endian::{BigEndian, LittleEndian};
+
+    #[test]
+    fn crc_of_known_values() {
+        assert_eq!(gnu_debuglink_crc(b""), 0);
+        assert_eq!(gnu_debuglink_crc(b"123456789"), 0xcbf43926);
+        assert_eq!(
+            gnu_debuglink_crc(b"The quick brown fox jumps over the lazy dog"),
+            0x414fa339
+        );
+    }
+
+    #[test]
+    fn parse_debuglink() {
+        // name is padded out to 8 bytes, then the CRC
+        let data = [
+            b'a', b'.', b'd', b'b', b'g', 0, 0, 0, 0x01, 0x02, 0x03, 0x04,
+        ];
+        assert_eq!(
+            parse_gnu_debuglink(LittleEndian, &data).expect("should parse"),
+            (&b"a.dbg"[..], 0x04030201)
+        );
+        assert_eq!(
+            parse_gnu_debuglink(BigEndian, &data).expect("should parse"),
+            (&b"a.dbg"[..], 0x01020304)
+        );
+
+        // a name that's 4-byte aligned with its NUL gets no padding
+        let data = [b'a', b'b', b'c', 0, 0x01, 0x02, 0x03, 0x04];
+        assert_eq!(
+            parse_gnu_debuglink(LittleEndian, &data).expect("should parse"),
+            (&b"abc"[..], 0x04030201)
+        );
+    }
+
+    #[test]
+    fn parse_debuglink_fuzz_too_short() {
+        let data = [
+            b'a', b'.', b'd', b'b', b'g', 0, 0, 0, 0x01, 0x02, 0x03, 0x04,
+        ];
+        for n in 0..data.len() {
+            let result = parse_gnu_debuglink(LittleEndian, &data[..n]);
+            assert!(result.is_err(), "should fail to parse {n} bytes");
+        }
+    }
+
+    #[test]
+    fn build_id_hex_string() {
+        assert_eq!(build_id_hex(&[0x8c, 0x33, 0x01, 0xff]), "8c3301ff");
+        assert_eq!(build_id_hex(&[]), "");
+    }
+
+    #[test]
+    fn candidates_from_debuglink() {
+        use crate::endian::AnyEndian;
+
+        let file_data = std::fs::read("sample-objects/debuglink.x86_64").expect("should read");
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("should parse");
+        let build_id = file
+            .gnu_build_id()
+            .expect("should parse")
+            .expect("should have a build-id");
+        let hex = build_id_hex(build_id.0);
+
+        let candidates = debug_file_candidates(
+            &file,
+            Path::new("/usr/bin/debuglink.x86_64"),
+            Path::new("/"),
+        )
+        .expect("should list candidates");
+        assert_eq!(
+            candidates,
+            vec![
+                PathBuf::from(format!(
+                    "/usr/lib/debug/.build-id/{}/{}.debug",
+                    &hex[..2],
+                    &hex[2..]
+                )),
+                PathBuf::from("/usr/bin/basic.x86_64.debug"),
+                PathBuf::from("/usr/bin/.debug/basic.x86_64.debug"),
+                PathBuf::from("/usr/lib/debug/usr/bin/basic.x86_64.debug"),
+            ]
+        );
+
+        // A sysroot prefixes the debug directory, but not the object's own directory
+        let candidates = debug_file_candidates(
+            &file,
+            Path::new("/sysroot/usr/bin/debuglink.x86_64"),
+            Path::new("/sysroot"),
+        )
+        .expect("should list candidates");
+        assert_eq!(
+            candidates[3],
+            PathBuf::from("/sysroot/usr/lib/debug/sysroot/usr/bin/basic.x86_64.debug")
+        );
+        assert!(candidates[0].starts_with("/sysroot/usr/lib/debug/.build-id"));
+    }
+
+    #[test]
+    fn candidates_without_debuglink() {
+        use crate::endian::AnyEndian;
+
+        // basic.x86_64 has a build-id but no .gnu_debuglink
+        let file_data = std::fs::read("sample-objects/basic.x86_64").expect("should read");
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("should parse");
+        let candidates = debug_file_candidates(&file, Path::new("basic.x86_64"), Path::new("/"))
+            .expect("should list candidates");
+        assert_eq!(candidates.len(), 1);
+        assert!(candidates[0].starts_with("/usr/lib/debug/.build-id"));
+        assert_eq!(candidates[0].extension(), Some("debug".as_ref()));
+    }
+
+    #[test]
+    fn parse_debugaltlink() {
+        let data = [b'/', b'x', 0, 0xaa, 0xbb, 0xcc];
+        assert_eq!(
+            parse_gnu_debugaltlink(&data).expect("should parse"),
+            GnuDebugAltLink {
+                name: b"/x",
+                build_id: &[0xaa, 0xbb, 0xcc],
+            }
+        );
+
+        let result = parse_gnu_debugaltlink(b"no nul");
+        assert!(
+            matches!(result, Err(ParseError::StringTableMissingNul(_))),
+            "Unexpected Error type found: {result:?}"
+        );
+    }
+}
@@ -0,0 +1,81 @@
+//! Optional module for demangling Rust and Itanium C++ symbol names
+//!
+//! This is enabled by the non-default `demangle` cargo feature, which pulls in the
+//! [rustc-demangle](https://crates.io/crates/rustc-demangle) and
+//! [cpp_demangle](https://crates.io/crates/cpp_demangle) crates.
+#[cfg(not(feature = "std"))]
+use alloc::{borrow::Cow, format};
+#[cfg(feature = "std")]
+use std::borrow::Cow;
+
+/// Demangle a symbol name that was mangled with either of the Rust mangling schemes
+/// (legacy or v0) or the Itanium C++ ABI mangling scheme.
+///
+/// Names that aren't mangled, or which fail to demangle, are returned unchanged.
+/// Rust legacy hashes (the trailing `::h0123456789abcdef`) are omitted from the result.
+pub fn demangle(name: &str) -> Cow<'_, str> {
+    // Rust's legacy mangling is a subset of Itanium's, so this has to be tried first
+    if let Ok(demangled) = rustc_demangle::try_demangle(name) {
+        return Cow::Owned(format!("{demangled:#}"));
+    }
+
+    if name.starts_with("_Z") {
+        if let Ok(sym) = cpp_demangle::Symbol::new(name) {
+            if let Ok(demangled) = sym.demangle(&cpp_demangle::DemangleOptions::default()) {
+                return Cow::Owned(demangled);
+            }
+        }
+    }
+
+    Cow::Borrowed(name)
+}
+
+#[cfg(test)]
+mod demangle_tests {
+    use super::*;
+
+    #[test]
+    fn demangle_rust_legacy() {
+        assert_eq!(
+            demangle("_ZN3std2io5stdio6_print17h8e5c2f5f4a3d1b2cE"),
+            "std::io::stdio::_print"
+        );
+    }
+
+    #[test]
+    fn demangle_rust_v0() {
+        assert_eq!(
+            demangle("_RNvCs15kBYyAo9fc_7mycrate7example"),
+            "mycrate::example"
+        );
+    }
+
+    #[test]
+    fn demangle_cpp() {
+        assert_eq!(demangle("_ZN3foo3barEv"), "foo::bar()");
+        assert_eq!(
+            demangle("_ZNSt6vectorIiSaIiEE9push_backERKi"),
+            "std::vector<int, std::allocator<int> >::push_back(int const&)"
+        );
+    }
+
+    #[test]
+    fn demangle_passes_through_unmangled() {
+        assert!(matches!(demangle("main"), Cow::Borrowed("main")));
+        assert!(matches!(demangle(""), Cow::Borrowed("")));
+    }
+
+    #[test]
+    fn demangle_garbage_falls_back() {
+        for name in [
+            "_Z",
+            "_ZZZZ",
+            "_R",
+            "_ZN3foo",
+            "_ZN999999999999foo",
+            "_RNvC\u{ff}",
+        ] {
+            assert_eq!(demangle(name), name);
+        }
+    }
+}
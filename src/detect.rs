@@ -0,0 +1,303 @@
+//! Recognizing the other kinds of files that get mistaken for ELF objects
+//!
+//! ELF parsers get handed all sorts of things: static libraries, compressed kernels and
+//! initramfs images, Windows and macOS binaries. [detect_format] tells these apart by their
+//! magic bytes, so that tools can say what they were given instead of just that it isn't ELF.
+//! The [BadMagic](crate::ParseError::BadMagic) error Display includes the same hint.
+//!
+//! With the `zlib` or `xz` features, `decompress` unwraps gzip or xz-compressed inputs, which
+//! `OwnedElfBytes::minimal_parse_decompressed` builds on to parse a compressed ELF object in
+//! one go.
+#[cfg(any(feature = "zlib", feature = "xz"))]
+use crate::parse::ParseError;
+
+/// The kind of file that some bytes hold, as guessed from their magic bytes by
+/// [detect_format].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DetectedFormat {
+    /// An ELF object, starting with [ELFMAGIC](crate::abi::ELFMAGIC)
+    Elf,
+    /// An `ar` archive, like a static library, starting with `!<arch>\n` (or `!<thin>\n` for
+    /// a thin archive)
+    Archive,
+    /// gzip-compressed data
+    Gzip,
+    /// xz-compressed data
+    Xz,
+    /// zstd-compressed data
+    Zstd,
+    /// bzip2-compressed data
+    Bzip2,
+    /// A DOS or PE/COFF executable, as used on Windows and for EFI applications, starting with
+    /// `MZ`
+    Pe,
+    /// A Mach-O object, as used on macOS and iOS, in either byte order and class
+    MachO,
+    /// A Mach-O universal ("fat") binary, which bundles objects for several architectures
+    MachOFat,
+    /// A WebAssembly module
+    Wasm,
+    /// None of the above
+    Unknown,
+}
+
+impl DetectedFormat {
+    /// Get a short description of the format, which reads as the end of "this looks like ..."
+    pub fn description(&self) -> &'static str {
+        match self {
+            DetectedFormat::Elf => "an ELF object",
+            DetectedFormat::Archive => "an ar archive (such as a static library)",
+            DetectedFormat::Gzip => "gzip-compressed data",
+            DetectedFormat::Xz => "xz-compressed data",
+            DetectedFormat::Zstd => "zstd-compressed data",
+            DetectedFormat::Bzip2 => "bzip2-compressed data",
+            DetectedFormat::Pe => "a PE/COFF (Windows or EFI) executable",
+            DetectedFormat::MachO => "a Mach-O object",
+            DetectedFormat::MachOFat => "a Mach-O universal binary",
+            DetectedFormat::Wasm => "a WebAssembly module",
+            DetectedFormat::Unknown => "an unknown format",
+        }
+    }
+
+    /// Check whether this is one of the compression formats, whose contents might well be
+    /// an ELF object, like a compressed kernel image
+    pub fn is_compressed(&self) -> bool {
+        matches!(
+            self,
+            DetectedFormat::Gzip
+                | DetectedFormat::Xz
+                | DetectedFormat::Zstd
+                | DetectedFormat::Bzip2
+        )
+    }
+}
+
+/// `(magic, format)` pairs, checked in order against the start of the data. Every magic but
+/// the ar archive ones fits in the four bytes that a BadMagic error holds, and those are
+/// still told apart by their first four.
+const MAGICS: &[(&[u8], DetectedFormat)] = &[
+    (b"\x7fELF", DetectedFormat::Elf),
+    (b"!<arch>\n", DetectedFormat::Archive),
+    (b"!<thin>\n", DetectedFormat::Archive),
+    (b"\x1f\x8b", DetectedFormat::Gzip),
+    (b"\xfd7zXZ\0", DetectedFormat::Xz),
+    (b"\x28\xb5\x2f\xfd", DetectedFormat::Zstd),
+    (b"BZh", DetectedFormat::Bzip2),
+    (b"MZ", DetectedFormat::Pe),
+    (b"\xfe\xed\xfa\xce", DetectedFormat::MachO),
+    (b"\xce\xfa\xed\xfe", DetectedFormat::MachO),
+    (b"\xfe\xed\xfa\xcf", DetectedFormat::MachO),
+    (b"\xcf\xfa\xed\xfe", DetectedFormat::MachO),
+    (b"\xca\xfe\xba\xbe", DetectedFormat::MachOFat),
+    (b"\xca\xfe\xba\xbf", DetectedFormat::MachOFat),
+    (b"\0asm", DetectedFormat::Wasm),
+];
+
+/// Guess the format of the given data from its magic bytes.
+///
+/// Only the start of the data is looked at, so this is cheap but can be fooled. Data that's
+/// shorter than a format's magic is only matched against the part of the magic it has, so
+/// that the four bytes of a [BadMagic](crate::ParseError::BadMagic) error are enough to
+/// recognize every format.
+///
+/// Note: Java class files share the `0xcafebabe` magic of Mach-O universal binaries. Like
+/// `file(1)`, this tells them apart by the word after it when there's one, which is a small
+/// architecture count for universal binaries but the class file version for Java.
+pub fn detect_format(data: &[u8]) -> DetectedFormat {
+    let found = MAGICS.iter().find(|(magic, _)| {
+        let len = magic.len().min(data.len());
+        len >= 2 && data[..len] == magic[..len]
+    });
+    // Java's class file versions start at 45, while universal binaries have a few objects
+    let nfat_arch = data
+        .get(4..8)
+        .map(|word| u32::from_be_bytes([word[0], word[1], word[2], word[3]]));
+    match found {
+        Some((_, DetectedFormat::MachOFat)) if nfat_arch.map_or(false, |n| n >= 45) => {
+            DetectedFormat::Unknown
+        }
+        Some((_, format)) => *format,
+        None => DetectedFormat::Unknown,
+    }
+}
+
+/// Decompress gzip or xz-compressed data, failing with a [ParseError::IOError] if it's
+/// corrupt or would decompress to more than `limit` bytes. Returns an empty Option if the
+/// data isn't compressed in a format that the enabled features can decompress: gzip needs
+/// the `zlib` feature and xz needs the `xz` feature.
+///
+/// Only the first member of a multi-member gzip file is decompressed, and its trailing
+/// checksum isn't checked, so parse the result rather than trusting it.
+#[cfg(any(feature = "zlib", feature = "xz"))]
+pub fn decompress(data: &[u8], limit: usize) -> Result<Option<Vec<u8>>, ParseError> {
+    match detect_format(data) {
+        #[cfg(feature = "zlib")]
+        DetectedFormat::Gzip => decompress_gzip(data, limit).map(Some),
+        #[cfg(feature = "xz")]
+        DetectedFormat::Xz => crate::debugdata::decompress_gnu_debugdata(data, limit).map(Some),
+        _ => Ok(None),
+    }
+}
+
+/// Skip over a gzip member's header (RFC 1952) and inflate the deflate stream after it.
+#[cfg(feature = "zlib")]
+fn decompress_gzip(data: &[u8], limit: usize) -> Result<Vec<u8>, ParseError> {
+    use crate::parse::ReadBytesExt;
+    use std::io::{Error, ErrorKind};
+
+    const FHCRC: u8 = 0x02;
+    const FEXTRA: u8 = 0x04;
+    const FNAME: u8 = 0x08;
+    const FCOMMENT: u8 = 0x10;
+    // Deflate is the only compression method gzip defines
+    const CM_DEFLATE: u8 = 8;
+
+    let header = data.get_bytes(0..10)?;
+    if header[2] != CM_DEFLATE {
+        return Err(ParseError::IOError(Error::new(
+            ErrorKind::InvalidData,
+            format!("unknown gzip compression method {}", header[2]),
+        )));
+    }
+    let flags = header[3];
+    let mut offset = 10;
+    if flags & FEXTRA != 0 {
+        let xlen = data.get_bytes(offset..offset + 2)?;
+        offset += 2 + u16::from_le_bytes([xlen[0], xlen[1]]) as usize;
+    }
+    // The file name and comment are NUL-terminated
+    for flag in [FNAME, FCOMMENT] {
+        if flags & flag != 0 {
+            let rest = data.get_bytes(offset..data.len())?;
+            let len = rest
+                .iter()
+                .position(|byte| *byte == 0)
+                .ok_or(ParseError::SliceReadError((offset, data.len())))?;
+            offset += len + 1;
+        }
+    }
+    if flags & FHCRC != 0 {
+        offset += 2;
+    }
+
+    let stream = data.get_bytes(offset..data.len())?;
+    miniz_oxide::inflate::decompress_to_vec_with_limit(stream, limit).map_err(|err| {
+        ParseError::IOError(Error::new(
+            ErrorKind::InvalidData,
+            format!("{:?}", err.status),
+        ))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::endian::AnyEndian;
+    use crate::{ElfBytes, ParseError};
+
+    #[test]
+    fn detect_formats() {
+        let elf = std::fs::read("sample-objects/basic.x86_64").expect("should read");
+        assert_eq!(detect_format(&elf), DetectedFormat::Elf);
+        let cases: &[(&[u8], DetectedFormat)] = &[
+            (b"!<arch>\n/               0", DetectedFormat::Archive),
+            (b"!<thin>\n", DetectedFormat::Archive),
+            (b"\x1f\x8b\x08\x00\x00\x00\x00\x00", DetectedFormat::Gzip),
+            (b"\xfd7zXZ\0\0\x04", DetectedFormat::Xz),
+            (b"\x28\xb5\x2f\xfd\x24", DetectedFormat::Zstd),
+            (b"BZh91AY&SY", DetectedFormat::Bzip2),
+            (b"MZ\x90\0\x03\0", DetectedFormat::Pe),
+            (b"\xcf\xfa\xed\xfe\x0c\0\0\x01", DetectedFormat::MachO),
+            (b"\xfe\xed\xfa\xce\0\0\0\x12", DetectedFormat::MachO),
+            (b"\xca\xfe\xba\xbe\0\0\0\x02", DetectedFormat::MachOFat),
+            (b"\0asm\x01\0\0\0", DetectedFormat::Wasm),
+            // A Java class file, version 52.0
+            (b"\xca\xfe\xba\xbe\0\0\0\x34", DetectedFormat::Unknown),
+            (b"#!/bin/sh\n", DetectedFormat::Unknown),
+            (b"", DetectedFormat::Unknown),
+            (b"M", DetectedFormat::Unknown),
+        ];
+        for (data, format) in cases {
+            assert_eq!(detect_format(data), *format, "{data:x?}");
+        }
+
+        // The four bytes of a BadMagic are enough
+        for (data, format) in &cases[..11] {
+            assert_eq!(detect_format(&data[..4]), *format, "{data:x?}");
+        }
+    }
+
+    #[test]
+    fn bad_magic_hint() {
+        let mut data = [0u8; 64];
+        data[..2].copy_from_slice(b"MZ");
+        let err = ElfBytes::<AnyEndian>::minimal_parse(&data).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid Magic Bytes: [4D, 5A, 0, 0] (this looks like a PE/COFF (Windows or EFI) executable)"
+        );
+        assert_eq!(
+            ParseError::BadMagic(*b"#!/b").to_string(),
+            "Invalid Magic Bytes: [23, 21, 2F, 62]"
+        );
+    }
+
+    #[test]
+    fn compressed_formats() {
+        assert!(DetectedFormat::Gzip.is_compressed());
+        assert!(DetectedFormat::Zstd.is_compressed());
+        assert!(!DetectedFormat::Elf.is_compressed());
+        assert!(!DetectedFormat::Archive.is_compressed());
+    }
+
+    /// Wrap a raw deflate stream of `data` in a gzip header with the given flags and fields
+    #[cfg(feature = "zlib")]
+    fn gzip(data: &[u8], flags: u8, fields: &[u8]) -> Vec<u8> {
+        let mut out = vec![0x1f, 0x8b, 8, flags, 0, 0, 0, 0, 0, 3];
+        out.extend_from_slice(fields);
+        out.extend(miniz_oxide::deflate::compress_to_vec(data, 6));
+        // The CRC-32 and size trailer, which isn't checked
+        out.extend_from_slice(&[0; 8]);
+        out
+    }
+
+    #[cfg(feature = "zlib")]
+    #[test]
+    fn decompress_gzip() {
+        let elf = std::fs::read("sample-objects/basic.x86_64").expect("should read");
+        let data = gzip(&elf, 0, &[]);
+        assert_eq!(detect_format(&data), DetectedFormat::Gzip);
+        let output = decompress(&data, elf.len()).expect("should decompress");
+        assert_eq!(output.as_deref(), Some(elf.as_slice()));
+
+        // With an extra field, file name, comment and header CRC
+        let fields = b"\x02\0xyvmlinux\0a kernel\0\0\0";
+        let data = gzip(&elf, 0x1e, fields);
+        let output = decompress(&data, elf.len()).expect("should decompress");
+        assert_eq!(output.as_deref(), Some(elf.as_slice()));
+
+        let result = decompress(&data, elf.len() - 1);
+        assert!(
+            matches!(result, Err(ParseError::IOError(_))),
+            "Unexpected result: {result:?}"
+        );
+        // An unterminated file name
+        let result = decompress(b"\x1f\x8b\x08\x08\0\0\0\0\0\x03vmlinux", 100);
+        assert!(
+            matches!(result, Err(ParseError::SliceReadError(_))),
+            "Unexpected result: {result:?}"
+        );
+        assert!(decompress(&elf, elf.len()).expect("should check").is_none());
+    }
+
+    #[cfg(feature = "xz")]
+    #[test]
+    fn decompress_xz() {
+        let elf = std::fs::read("sample-objects/basic.x86_64").expect("should read");
+        let mut data = Vec::new();
+        lzma_rs::xz_compress(&mut elf.as_slice(), &mut data).expect("should compress");
+        assert_eq!(detect_format(&data), DetectedFormat::Xz);
+        let output = decompress(&data, elf.len()).expect("should decompress");
+        assert_eq!(output.as_deref(), Some(elf.as_slice()));
+    }
+}
@@ -0,0 +1,498 @@
+//! Structurally comparing two ELF files
+//!
+//! Rather than a byte-for-byte diff, [diff] reports which header fields differ, which sections
+//! were added, removed, resized, or had their contents change (matched by name), which symbols
+//! were added or removed (matched by name), and which dynamic entries changed. The differences
+//! are reported in a deterministic order, so they can be snapshot-tested.
+//!
+//! Example usage:
+//! ```
+//! use elf::diff::{diff, DiffOptions, Difference, HeaderField};
+//! use elf::endian::AnyEndian;
+//! use elf::ElfBytes;
+//!
+//! let path = std::path::PathBuf::from("sample-objects/basic.x86_64");
+//! let a_data = std::fs::read(path).expect("Could not read file.");
+//! let mut b_data = a_data.clone();
+//! // Move the entry point
+//! b_data[0x18] = 0xf4;
+//!
+//! let a = ElfBytes::<AnyEndian>::minimal_parse(&a_data).expect("Open a");
+//! let b = ElfBytes::<AnyEndian>::minimal_parse(&b_data).expect("Open b");
+//! let result = diff(&a, &b, &DiffOptions::default()).expect("should diff");
+//! assert_eq!(
+//!     result.differences,
+//!     vec![Difference::Header {
+//!         field: HeaderField::Entry,
+//!         a: 0x4003f0,
+//!         b: 0x4003f4,
+//!     }]
+//! );
+//! assert_eq!(result.to_string(), "header e_entry: 0x4003f0 -> 0x4003f4\n");
+//! ```
+use crate::abi;
+use crate::debuglink::gnu_debuglink_crc;
+use crate::endian::EndianParse;
+use crate::file::{Class, FileHeader};
+use crate::parse::ParseError;
+use crate::section::SectionHeader;
+use crate::ElfBytes;
+
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::collections::{BTreeMap, BTreeSet};
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use std::collections::{BTreeMap, BTreeSet};
+
+/// Sections whose contents typically differ between otherwise identical builds
+pub const NOISY_SECTIONS: &[&str] = &[".note.gnu.build-id", ".comment", ".gnu_debuglink"];
+
+/// Options controlling what [diff] compares
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DiffOptions<'a> {
+    /// The names of sections to leave out of the comparison entirely
+    pub ignore_sections: &'a [&'a str],
+}
+
+impl DiffOptions<'static> {
+    /// Options which ignore the [NOISY_SECTIONS]
+    pub fn ignore_noisy() -> Self {
+        DiffOptions {
+            ignore_sections: NOISY_SECTIONS,
+        }
+    }
+}
+
+/// A compared field of the ELF file header
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HeaderField {
+    /// `e_ident[EI_CLASS]`
+    Class,
+    /// `e_ident[EI_DATA]`
+    Endianness,
+    /// `e_ident[EI_OSABI]`
+    OsAbi,
+    /// `e_ident[EI_ABIVERSION]`
+    AbiVersion,
+    /// `e_type`
+    Type,
+    /// `e_machine`
+    Machine,
+    /// `e_version`
+    Version,
+    /// `e_entry`
+    Entry,
+    /// `e_flags`
+    Flags,
+}
+
+impl HeaderField {
+    fn name(&self) -> &'static str {
+        match self {
+            HeaderField::Class => "EI_CLASS",
+            HeaderField::Endianness => "EI_DATA",
+            HeaderField::OsAbi => "EI_OSABI",
+            HeaderField::AbiVersion => "EI_ABIVERSION",
+            HeaderField::Type => "e_type",
+            HeaderField::Machine => "e_machine",
+            HeaderField::Version => "e_version",
+            HeaderField::Entry => "e_entry",
+            HeaderField::Flags => "e_flags",
+        }
+    }
+}
+
+/// A structural difference between file `a` and file `b`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Difference<'data> {
+    /// A file header field has a different value
+    Header { field: HeaderField, a: u64, b: u64 },
+    /// A section in `a` has no counterpart in `b`
+    SectionRemoved { name: &'data str, size: u64 },
+    /// A section in `b` has no counterpart in `a`
+    SectionAdded { name: &'data str, size: u64 },
+    /// A section has a different size
+    SectionResized {
+        name: &'data str,
+        a_size: u64,
+        b_size: u64,
+    },
+    /// A section has the same size but different contents, with the CRC-32 of each
+    SectionContents {
+        name: &'data str,
+        a_crc: u32,
+        b_crc: u32,
+    },
+    /// A named symbol in `a` has no counterpart in `b`. `dynamic` is true for `.dynsym`
+    /// symbols, and false for `.symtab` symbols.
+    SymbolRemoved { name: &'data str, dynamic: bool },
+    /// A named symbol in `b` has no counterpart in `a`
+    SymbolAdded { name: &'data str, dynamic: bool },
+    /// The values of the dynamic entries with the given tag differ, listed in table order
+    Dynamic {
+        d_tag: i64,
+        a: Vec<u64>,
+        b: Vec<u64>,
+    },
+}
+
+impl<'data> core::fmt::Display for Difference<'data> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let table = |dynamic: bool| if dynamic { ".dynsym" } else { ".symtab" };
+        match self {
+            Difference::Header { field, a, b } => {
+                write!(f, "header {}: {a:#x} -> {b:#x}", field.name())
+            }
+            Difference::SectionRemoved { name, size } => {
+                write!(f, "section {name} removed ({size} bytes)")
+            }
+            Difference::SectionAdded { name, size } => {
+                write!(f, "section {name} added ({size} bytes)")
+            }
+            Difference::SectionResized {
+                name,
+                a_size,
+                b_size,
+            } => {
+                write!(f, "section {name} resized: {a_size} -> {b_size} bytes")
+            }
+            Difference::SectionContents { name, a_crc, b_crc } => {
+                write!(f, "section {name} contents: crc {a_crc:08x} -> {b_crc:08x}")
+            }
+            Difference::SymbolRemoved { name, dynamic } => {
+                write!(f, "symbol {name} removed from {}", table(*dynamic))
+            }
+            Difference::SymbolAdded { name, dynamic } => {
+                write!(f, "symbol {name} added to {}", table(*dynamic))
+            }
+            Difference::Dynamic { d_tag, a, b } => {
+                write!(f, "dynamic tag {d_tag:#x}: {a:x?} -> {b:x?}")
+            }
+        }
+    }
+}
+
+/// The structural differences between two ELF files, as found by [diff].
+///
+/// The differences are ordered by kind: header fields, then sections, then symbols, then
+/// dynamic entries. Removed and changed sections are listed in `a`'s section order and added
+/// sections in `b`'s. Symbols are sorted by name and dynamic entries by tag.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ElfDiff<'data> {
+    /// The differences found, in order
+    pub differences: Vec<Difference<'data>>,
+}
+
+impl<'data> ElfDiff<'data> {
+    /// Returns true if no differences were found
+    pub fn is_empty(&self) -> bool {
+        self.differences.is_empty()
+    }
+}
+
+impl<'data> core::fmt::Display for ElfDiff<'data> {
+    /// Formats each difference on its own line
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        for difference in self.differences.iter() {
+            writeln!(f, "{difference}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Compare two ELF files structurally, returning the differences in a deterministic order.
+///
+/// Sections are matched by name, with repeated names matched in order. Sections with the same
+/// size are compared by their file contents (`SHT_NOBITS` sections have none).
+pub fn diff<'data, E: EndianParse>(
+    a: &ElfBytes<'data, E>,
+    b: &ElfBytes<'data, E>,
+    options: &DiffOptions<'_>,
+) -> Result<ElfDiff<'data>, ParseError> {
+    let mut differences = Vec::new();
+    diff_headers(&a.ehdr, &b.ehdr, &mut differences);
+    diff_sections(a, b, options, &mut differences)?;
+    diff_symbols(a, b, false, &mut differences)?;
+    diff_symbols(a, b, true, &mut differences)?;
+    diff_dynamic(a, b, &mut differences)?;
+    Ok(ElfDiff { differences })
+}
+
+fn diff_headers<E: EndianParse>(
+    a: &FileHeader<E>,
+    b: &FileHeader<E>,
+    differences: &mut Vec<Difference<'_>>,
+) {
+    let class = |ehdr: &FileHeader<E>| match ehdr.class {
+        Class::ELF32 => abi::ELFCLASS32,
+        Class::ELF64 => abi::ELFCLASS64,
+    };
+    let data = |ehdr: &FileHeader<E>| match ehdr.endianness.is_little() {
+        true => abi::ELFDATA2LSB,
+        false => abi::ELFDATA2MSB,
+    };
+    let fields = [
+        (HeaderField::Class, class(a) as u64, class(b) as u64),
+        (HeaderField::Endianness, data(a) as u64, data(b) as u64),
+        (HeaderField::OsAbi, a.osabi as u64, b.osabi as u64),
+        (
+            HeaderField::AbiVersion,
+            a.abiversion as u64,
+            b.abiversion as u64,
+        ),
+        (HeaderField::Type, a.e_type as u64, b.e_type as u64),
+        (HeaderField::Machine, a.e_machine as u64, b.e_machine as u64),
+        (HeaderField::Version, a.version as u64, b.version as u64),
+        (HeaderField::Entry, a.e_entry, b.e_entry),
+        (HeaderField::Flags, a.e_flags as u64, b.e_flags as u64),
+    ];
+    for (field, a, b) in fields {
+        if a != b {
+            differences.push(Difference::Header { field, a, b });
+        }
+    }
+}
+
+/// Get a file's section headers and their names, leaving out the ignored sections
+fn named_sections<'data, E: EndianParse>(
+    file: &ElfBytes<'data, E>,
+    options: &DiffOptions<'_>,
+) -> Result<Vec<(&'data str, SectionHeader)>, ParseError> {
+    let (shdrs, strtab) = file.section_headers_with_strtab()?;
+    let (shdrs, strtab) = match (shdrs, strtab) {
+        (Some(shdrs), Some(strtab)) => (shdrs, strtab),
+        (Some(shdrs), None) => {
+            return Ok(shdrs.iter().map(|shdr| ("", shdr)).collect());
+        }
+        _ => return Ok(Vec::new()),
+    };
+    let mut sections = Vec::new();
+    for shdr in shdrs.iter() {
+        let name = strtab.get(shdr.sh_name as usize)?;
+        if !options.ignore_sections.contains(&name) {
+            sections.push((name, shdr));
+        }
+    }
+    Ok(sections)
+}
+
+fn diff_sections<'data, E: EndianParse>(
+    a: &ElfBytes<'data, E>,
+    b: &ElfBytes<'data, E>,
+    options: &DiffOptions<'_>,
+    differences: &mut Vec<Difference<'data>>,
+) -> Result<(), ParseError> {
+    let a_sections = named_sections(a, options)?;
+    let mut b_sections: Vec<_> = named_sections(b, options)?.into_iter().map(Some).collect();
+
+    for (name, a_shdr) in a_sections {
+        // Match against the first unmatched section of the same name
+        let b_shdr = b_sections
+            .iter_mut()
+            .find(|section| matches!(section, Some((b_name, _)) if *b_name == name))
+            .and_then(|section| section.take());
+        let b_shdr = match b_shdr {
+            Some((_, b_shdr)) => b_shdr,
+            None => {
+                differences.push(Difference::SectionRemoved {
+                    name,
+                    size: a_shdr.sh_size,
+                });
+                continue;
+            }
+        };
+
+        if a_shdr.sh_size != b_shdr.sh_size {
+            differences.push(Difference::SectionResized {
+                name,
+                a_size: a_shdr.sh_size,
+                b_size: b_shdr.sh_size,
+            });
+            continue;
+        }
+        let (a_data, _) = a.section_data(&a_shdr)?;
+        let (b_data, _) = b.section_data(&b_shdr)?;
+        if a_data != b_data {
+            differences.push(Difference::SectionContents {
+                name,
+                a_crc: gnu_debuglink_crc(a_data),
+                b_crc: gnu_debuglink_crc(b_data),
+            });
+        }
+    }
+
+    for (name, b_shdr) in b_sections.into_iter().flatten() {
+        differences.push(Difference::SectionAdded {
+            name,
+            size: b_shdr.sh_size,
+        });
+    }
+    Ok(())
+}
+
+/// Get the set of named symbols in a file's `.symtab` or `.dynsym`
+fn symbol_names<'data, E: EndianParse>(
+    file: &ElfBytes<'data, E>,
+    dynamic: bool,
+) -> Result<BTreeSet<&'data str>, ParseError> {
+    let table = if dynamic {
+        file.dynamic_symbol_table()?
+    } else {
+        file.symbol_table()?
+    };
+    let mut names = BTreeSet::new();
+    if let Some((symtab, strtab)) = table {
+        for sym in symtab.iter() {
+            let name = strtab.get(sym.st_name as usize)?;
+            if !name.is_empty() {
+                names.insert(name);
+            }
+        }
+    }
+    Ok(names)
+}
+
+fn diff_symbols<'data, E: EndianParse>(
+    a: &ElfBytes<'data, E>,
+    b: &ElfBytes<'data, E>,
+    dynamic: bool,
+    differences: &mut Vec<Difference<'data>>,
+) -> Result<(), ParseError> {
+    let a_names = symbol_names(a, dynamic)?;
+    let b_names = symbol_names(b, dynamic)?;
+    for name in a_names.difference(&b_names) {
+        differences.push(Difference::SymbolRemoved { name, dynamic });
+    }
+    for name in b_names.difference(&a_names) {
+        differences.push(Difference::SymbolAdded { name, dynamic });
+    }
+    Ok(())
+}
+
+/// Get the values of a file's dynamic entries, grouped by tag
+fn dynamic_values<E: EndianParse>(
+    file: &ElfBytes<'_, E>,
+) -> Result<BTreeMap<i64, Vec<u64>>, ParseError> {
+    let mut values: BTreeMap<i64, Vec<u64>> = BTreeMap::new();
+    if let Some(dynamic) = file.dynamic()? {
+        for entry in dynamic.iter() {
+            if entry.d_tag == abi::DT_NULL {
+                break;
+            }
+            values.entry(entry.d_tag).or_default().push(entry.d_val());
+        }
+    }
+    Ok(values)
+}
+
+fn diff_dynamic<E: EndianParse>(
+    a: &ElfBytes<'_, E>,
+    b: &ElfBytes<'_, E>,
+    differences: &mut Vec<Difference<'_>>,
+) -> Result<(), ParseError> {
+    let mut a_values = dynamic_values(a)?;
+    let mut b_values = dynamic_values(b)?;
+    let tags: BTreeSet<i64> = a_values.keys().chain(b_values.keys()).copied().collect();
+    for d_tag in tags {
+        let a = a_values.remove(&d_tag).unwrap_or_default();
+        let b = b_values.remove(&d_tag).unwrap_or_default();
+        if a != b {
+            differences.push(Difference::Dynamic { d_tag, a, b });
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::endian::AnyEndian;
+
+    fn read_basic() -> Vec<u8> {
+        let path = std::path::PathBuf::from("sample-objects/basic.x86_64");
+        std::fs::read(path).expect("Could not read file.")
+    }
+
+    /// The file offset of the given field of the given section header in basic.x86_64
+    fn shdr_field(index: usize, field: usize) -> usize {
+        0x12c0 + index * 64 + field
+    }
+
+    #[test]
+    fn identical_files() {
+        let data = read_basic();
+        let a = ElfBytes::<AnyEndian>::minimal_parse(&data).expect("Open a");
+        let b = ElfBytes::<AnyEndian>::minimal_parse(&data).expect("Open b");
+        let result = diff(&a, &b, &DiffOptions::default()).expect("should diff");
+        assert!(result.is_empty());
+        assert_eq!(result.to_string(), "");
+    }
+
+    #[test]
+    fn all_kinds_of_differences() {
+        let a_data = read_basic();
+        let mut b_data = a_data.clone();
+        // e_flags
+        b_data[0x30] = 0x01;
+        // Rename .jcr to "strtab", the tail of ".shstrtab"
+        b_data[shdr_field(21, 0)] = 0x14;
+        // Shrink .comment
+        b_data[shdr_field(27, 0x20)] = 0x30;
+        // Change .data's contents
+        b_data[0x8e0] = 0xff;
+        // Rename main to mair in .strtab
+        b_data[0x1178] = b'r';
+        // Change the DT_NEEDED value
+        b_data[0x6e0] = 0x10;
+
+        let a = ElfBytes::<AnyEndian>::minimal_parse(&a_data).expect("Open a");
+        let b = ElfBytes::<AnyEndian>::minimal_parse(&b_data).expect("Open b");
+        let result = diff(&a, &b, &DiffOptions::default()).expect("should diff");
+        assert_eq!(
+            result.to_string(),
+            "header e_flags: 0x0 -> 0x1\n\
+             section .jcr removed (8 bytes)\n\
+             section .dynamic contents: crc d5947ba0 -> da6ed4f0\n\
+             section .data contents: crc ecbb4b55 -> caf04db3\n\
+             section .comment resized: 52 -> 48 bytes\n\
+             section .strtab contents: crc 0a5966b2 -> 6cffeb0a\n\
+             section strtab added (8 bytes)\n\
+             symbol main removed from .symtab\n\
+             symbol mair added to .symtab\n\
+             dynamic tag 0x1: [1] -> [10]\n"
+        );
+        assert_eq!(
+            result.differences[7],
+            Difference::SymbolRemoved {
+                name: "main",
+                dynamic: false
+            }
+        );
+    }
+
+    #[test]
+    fn ignored_sections() {
+        let a_data = read_basic();
+        let mut b_data = a_data.clone();
+        // Change the build id and .comment
+        b_data[0x23c + 16] ^= 0xff;
+        b_data[0x8e8] ^= 0xff;
+
+        let a = ElfBytes::<AnyEndian>::minimal_parse(&a_data).expect("Open a");
+        let b = ElfBytes::<AnyEndian>::minimal_parse(&b_data).expect("Open b");
+        let result = diff(&a, &b, &DiffOptions::default()).expect("should diff");
+        let names: Vec<_> = result
+            .differences
+            .iter()
+            .map(|difference| match difference {
+                Difference::SectionContents { name, .. } => *name,
+                other => panic!("Unexpected difference: {other:?}"),
+            })
+            .collect();
+        assert_eq!(names, vec![".note.gnu.build-id", ".comment"]);
+
+        let result = diff(&a, &b, &DiffOptions::ignore_noisy()).expect("should diff");
+        assert!(result.is_empty());
+    }
+}
@@ -1,7 +1,10 @@
 //! Parsing `.dynamic` section or [PT_DYNAMIC](crate::abi::PT_DYNAMIC) segment contents
+use crate::abi;
 use crate::endian::EndianParse;
 use crate::file::Class;
-use crate::parse::{ParseAt, ParseError, ParsingTable};
+use crate::parse::{ParseAt, ParseError, ParsingTable, SerializeAt};
+#[cfg(feature = "to_str")]
+use crate::to_str::ReadelfDisplay;
 
 pub type DynamicTable<'data, E> = ParsingTable<'data, E, Dyn>;
 
@@ -27,6 +30,8 @@ pub struct Elf64_Dyn {
     pub d_un: u64,
 }
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Dyn {
     pub d_tag: i64,
@@ -41,8 +46,127 @@ impl Dyn {
     pub fn d_ptr(&self) -> u64 {
         self.d_un
     }
+
+    /// Classify what the entry's `d_un` holds, based on its tag. See [DynEntryKind::of].
+    pub fn kind(&self) -> DynEntryKind {
+        DynEntryKind::of(self.d_tag)
+    }
+
+    /// Get a value which renders the entry in the style of a `readelf -dW` line, with the tag
+    /// column sized for the given class and processor-specific tags named for the given
+    /// machine. See [Dyn]'s Display impl.
+    #[cfg(feature = "to_str")]
+    pub fn display(&self, class: Class, e_machine: u16) -> ReadelfDisplay<'_, Dyn> {
+        ReadelfDisplay {
+            value: self,
+            class,
+            e_machine,
+        }
+    }
+}
+
+/// What the `d_un` of a dynamic entry holds, which depends on the entry's tag.
+///
+/// This tells which entries need adjusting when an object's addresses change ([Address] entries),
+/// and how to print them: [Size]s and [Count]s are decimal, and everything else is hex.
+///
+/// [Address]: DynEntryKind::Address
+/// [Size]: DynEntryKind::Size
+/// [Count]: DynEntryKind::Count
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DynEntryKind {
+    /// A virtual address (`d_ptr`), e.g. [DT_STRTAB](abi::DT_STRTAB) or
+    /// [DT_PREINIT_ARRAY](abi::DT_PREINIT_ARRAY)
+    Address,
+    /// A size in bytes, e.g. [DT_STRSZ](abi::DT_STRSZ)
+    Size,
+    /// A number of entries, e.g. [DT_VERNEEDNUM](abi::DT_VERNEEDNUM)
+    Count,
+    /// An offset into the dynamic string table, e.g. [DT_NEEDED](abi::DT_NEEDED) or
+    /// [DT_AUDIT](abi::DT_AUDIT)
+    String,
+    /// A set of flags, e.g. [DT_FLAGS_1](abi::DT_FLAGS_1)
+    Flags,
+    /// Some other value (`d_val`), or an ignored one, e.g. [DT_PLTREL](abi::DT_PLTREL) or
+    /// [DT_BIND_NOW](abi::DT_BIND_NOW)
+    Value,
+    /// The tag isn't known, so neither is its `d_un`. This includes the processor-specific tags.
+    Unknown,
 }
 
+impl DynEntryKind {
+    /// Classify what the `d_un` of an entry with the given tag holds.
+    ///
+    /// Unlisted tags in the [DT_ADDRRNGLO](abi::DT_ADDRRNGLO) and
+    /// [DT_VALRNGLO](abi::DT_VALRNGLO) ranges are [Address](DynEntryKind::Address)es and
+    /// [Value](DynEntryKind::Value)s respectively.
+    pub fn of(d_tag: i64) -> Self {
+        match d_tag {
+            abi::DT_PLTGOT
+            | abi::DT_HASH
+            | abi::DT_STRTAB
+            | abi::DT_SYMTAB
+            | abi::DT_RELA
+            | abi::DT_INIT
+            | abi::DT_FINI
+            | abi::DT_REL
+            | abi::DT_DEBUG
+            | abi::DT_JMPREL
+            | abi::DT_INIT_ARRAY
+            | abi::DT_FINI_ARRAY
+            | abi::DT_PREINIT_ARRAY
+            | abi::DT_SYMTAB_SHNDX
+            | abi::DT_RELR
+            | abi::DT_VERSYM
+            | abi::DT_VERDEF
+            | abi::DT_VERNEED => DynEntryKind::Address,
+            abi::DT_PLTRELSZ
+            | abi::DT_RELASZ
+            | abi::DT_RELAENT
+            | abi::DT_STRSZ
+            | abi::DT_SYMENT
+            | abi::DT_RELSZ
+            | abi::DT_RELENT
+            | abi::DT_INIT_ARRAYSZ
+            | abi::DT_FINI_ARRAYSZ
+            | abi::DT_PREINIT_ARRAYSZ
+            | abi::DT_RELRSZ
+            | abi::DT_RELRENT
+            | abi::DT_GNU_CONFLICTSZ
+            | abi::DT_GNU_LIBLISTSZ
+            | abi::DT_PLTPADSZ
+            | abi::DT_MOVEENT
+            | abi::DT_MOVESZ
+            | abi::DT_SYMINSZ
+            | abi::DT_SYMINENT => DynEntryKind::Size,
+            abi::DT_RELACOUNT | abi::DT_RELCOUNT | abi::DT_VERDEFNUM | abi::DT_VERNEEDNUM => {
+                DynEntryKind::Count
+            }
+            abi::DT_NEEDED
+            | abi::DT_SONAME
+            | abi::DT_RPATH
+            | abi::DT_RUNPATH
+            | abi::DT_CONFIG
+            | abi::DT_DEPAUDIT
+            | abi::DT_AUDIT => DynEntryKind::String,
+            abi::DT_FLAGS | abi::DT_FLAGS_1 | abi::DT_FEATURE_1 | abi::DT_POSFLAG_1 => {
+                DynEntryKind::Flags
+            }
+            abi::DT_NULL
+            | abi::DT_SYMBOLIC
+            | abi::DT_PLTREL
+            | abi::DT_TEXTREL
+            | abi::DT_BIND_NOW => DynEntryKind::Value,
+            abi::DT_ADDRRNGLO..=abi::DT_ADDRRNGHI => DynEntryKind::Address,
+            abi::DT_VALRNGLO..=abi::DT_VALRNGHI => DynEntryKind::Value,
+            _ => DynEntryKind::Unknown,
+        }
+    }
+}
+
+pub const ELF32_DYN_SIZE: usize = 8;
+pub const ELF64_DYN_SIZE: usize = 16;
+
 impl ParseAt for Dyn {
     fn parse_at<E: EndianParse>(
         endian: E,
@@ -65,8 +189,336 @@ impl ParseAt for Dyn {
     #[inline]
     fn size_for(class: Class) -> usize {
         match class {
-            Class::ELF32 => 8,
-            Class::ELF64 => 16,
+            Class::ELF32 => ELF32_DYN_SIZE,
+            Class::ELF64 => ELF64_DYN_SIZE,
+        }
+    }
+}
+
+impl SerializeAt for Dyn {
+    fn serialize_at<E: EndianParse>(
+        &self,
+        endian: E,
+        class: Class,
+        offset: &mut usize,
+        data: &mut [u8],
+    ) -> Result<(), ParseError> {
+        match class {
+            Class::ELF32 => {
+                endian.write_i32_at(offset, data, self.d_tag.try_into()?)?;
+                endian.write_u32_at(offset, data, self.d_un.try_into()?)
+            }
+            Class::ELF64 => {
+                endian.write_i64_at(offset, data, self.d_tag)?;
+                endian.write_u64_at(offset, data, self.d_un)
+            }
+        }
+    }
+}
+
+/// The flags from the `d_val` of a [DT_FLAGS](abi::DT_FLAGS) entry, see the `DF_*` constants.
+///
+/// Displays as the space-separated names of the set flags, with any unknown bits shown as hex.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct DtFlags(pub u64);
+
+/// The flags from the `d_val` of a [DT_FLAGS_1](abi::DT_FLAGS_1) entry, see the `DF_1_*`
+/// constants.
+///
+/// Displays as the space-separated names of the set flags, with any unknown bits shown as hex.
+/// ```
+/// use elf::dynamic::DtFlags1;
+///
+/// assert_eq!(format!("{}", DtFlags1(0x08000001)), "NOW PIE");
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct DtFlags1(pub u64);
+
+/// The flags from the `d_val` of a [DT_FEATURE_1](abi::DT_FEATURE_1) entry, see the
+/// `DTF_1_*` constants.
+///
+/// Displays as the space-separated names of the set flags, with any unknown bits shown as hex.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct DtFeature1(pub u64);
+
+impl DtFlags {
+    /// Check whether all of the bits in `flag` (one of the `DF_*` constants) are set.
+    pub fn contains(self, flag: i64) -> bool {
+        self.0 & flag as u64 == flag as u64
+    }
+
+    /// [DF_ORIGIN](abi::DF_ORIGIN): the object's paths may use `$ORIGIN`
+    pub fn is_origin(self) -> bool {
+        self.contains(abi::DF_ORIGIN)
+    }
+
+    /// [DF_SYMBOLIC](abi::DF_SYMBOLIC): the object's own symbols are searched first when
+    /// resolving its references
+    pub fn is_symbolic(self) -> bool {
+        self.contains(abi::DF_SYMBOLIC)
+    }
+
+    /// [DF_TEXTREL](abi::DF_TEXTREL): relocations may modify non-writable segments
+    pub fn has_textrel(self) -> bool {
+        self.contains(abi::DF_TEXTREL)
+    }
+
+    /// [DF_BIND_NOW](abi::DF_BIND_NOW): all relocations are processed at load time, rather
+    /// than lazily
+    pub fn is_bind_now(self) -> bool {
+        self.contains(abi::DF_BIND_NOW)
+    }
+
+    /// [DF_STATIC_TLS](abi::DF_STATIC_TLS): the object uses the static TLS model
+    pub fn is_static_tls(self) -> bool {
+        self.contains(abi::DF_STATIC_TLS)
+    }
+}
+
+impl DtFlags1 {
+    /// Check whether all of the bits in `flag` (one of the `DF_1_*` constants) are set.
+    pub fn contains(self, flag: i64) -> bool {
+        self.0 & flag as u64 == flag as u64
+    }
+
+    /// [DF_1_NOW](abi::DF_1_NOW): all relocations are processed at load time, rather than
+    /// lazily
+    pub fn is_now(self) -> bool {
+        self.contains(abi::DF_1_NOW)
+    }
+
+    /// [DF_1_GLOBAL](abi::DF_1_GLOBAL): the object's symbols are available to every object
+    pub fn is_global(self) -> bool {
+        self.contains(abi::DF_1_GLOBAL)
+    }
+
+    /// [DF_1_NODELETE](abi::DF_1_NODELETE): the object can't be unloaded
+    pub fn is_nodelete(self) -> bool {
+        self.contains(abi::DF_1_NODELETE)
+    }
+
+    /// [DF_1_INITFIRST](abi::DF_1_INITFIRST): the object is initialized before any others
+    pub fn is_initfirst(self) -> bool {
+        self.contains(abi::DF_1_INITFIRST)
+    }
+
+    /// [DF_1_NOOPEN](abi::DF_1_NOOPEN): the object can't be loaded with `dlopen`
+    pub fn is_noopen(self) -> bool {
+        self.contains(abi::DF_1_NOOPEN)
+    }
+
+    /// [DF_1_ORIGIN](abi::DF_1_ORIGIN): the object's paths may use `$ORIGIN`
+    pub fn is_origin(self) -> bool {
+        self.contains(abi::DF_1_ORIGIN)
+    }
+
+    /// [DF_1_INTERPOSE](abi::DF_1_INTERPOSE): the object's symbols interpose those of every
+    /// object loaded after it, except the executable
+    pub fn is_interpose(self) -> bool {
+        self.contains(abi::DF_1_INTERPOSE)
+    }
+
+    /// [DF_1_PIE](abi::DF_1_PIE): the object is a position-independent executable
+    pub fn is_pie(self) -> bool {
+        self.contains(abi::DF_1_PIE)
+    }
+}
+
+impl DtFeature1 {
+    /// Check whether all of the bits in `flag` (one of the `DTF_1_*` constants) are set.
+    pub fn contains(self, flag: i64) -> bool {
+        self.0 & flag as u64 == flag as u64
+    }
+
+    /// [DTF_1_PARINIT](abi::DTF_1_PARINIT): partial initialization is required
+    pub fn is_parinit(self) -> bool {
+        self.contains(abi::DTF_1_PARINIT)
+    }
+
+    /// [DTF_1_CONFEXP](abi::DTF_1_CONFEXP): a configuration file is expected
+    pub fn is_confexp(self) -> bool {
+        self.contains(abi::DTF_1_CONFEXP)
+    }
+}
+
+impl<'data, E: EndianParse> DynamicTable<'data, E> {
+    /// Get the value of the first entry with the given tag before the table's terminating
+    /// [DT_NULL](abi::DT_NULL) entry (if any).
+    pub fn find_value(&self, d_tag: i64) -> Option<u64> {
+        self.iter()
+            .take_while(|entry| entry.d_tag != abi::DT_NULL)
+            .find(|entry| entry.d_tag == d_tag)
+            .map(|entry| entry.d_val())
+    }
+
+    /// Get the table's [DT_FLAGS](abi::DT_FLAGS), which are empty if there's no such entry
+    pub fn flags(&self) -> DtFlags {
+        DtFlags(self.find_value(abi::DT_FLAGS).unwrap_or(0))
+    }
+
+    /// Get the table's [DT_FLAGS_1](abi::DT_FLAGS_1), which are empty if there's no such entry
+    pub fn flags_1(&self) -> DtFlags1 {
+        DtFlags1(self.find_value(abi::DT_FLAGS_1).unwrap_or(0))
+    }
+
+    /// Get the table's [DT_FEATURE_1](abi::DT_FEATURE_1), which are empty if there's no such
+    /// entry
+    pub fn feature_1(&self) -> DtFeature1 {
+        DtFeature1(self.find_value(abi::DT_FEATURE_1).unwrap_or(0))
+    }
+
+    /// Check whether the dynamic linker must process all relocations at load time rather than
+    /// lazily, which any of a [DT_BIND_NOW](abi::DT_BIND_NOW) entry, the
+    /// [DF_BIND_NOW](abi::DF_BIND_NOW) flag or the [DF_1_NOW](abi::DF_1_NOW) flag asks for.
+    pub fn is_bind_now(&self) -> bool {
+        self.find_value(abi::DT_BIND_NOW).is_some()
+            || self.flags().is_bind_now()
+            || self.flags_1().is_now()
+    }
+}
+
+const DF_NAMES: &[(i64, &str)] = &[
+    (abi::DF_ORIGIN, "ORIGIN"),
+    (abi::DF_SYMBOLIC, "SYMBOLIC"),
+    (abi::DF_TEXTREL, "TEXTREL"),
+    (abi::DF_BIND_NOW, "BIND_NOW"),
+    (abi::DF_STATIC_TLS, "STATIC_TLS"),
+];
+
+const DF_1_NAMES: &[(i64, &str)] = &[
+    (abi::DF_1_NOW, "NOW"),
+    (abi::DF_1_GLOBAL, "GLOBAL"),
+    (abi::DF_1_GROUP, "GROUP"),
+    (abi::DF_1_NODELETE, "NODELETE"),
+    (abi::DF_1_LOADFLTR, "LOADFLTR"),
+    (abi::DF_1_INITFIRST, "INITFIRST"),
+    (abi::DF_1_NOOPEN, "NOOPEN"),
+    (abi::DF_1_ORIGIN, "ORIGIN"),
+    (abi::DF_1_DIRECT, "DIRECT"),
+    (abi::DF_1_TRANS, "TRANS"),
+    (abi::DF_1_INTERPOSE, "INTERPOSE"),
+    (abi::DF_1_NODEFLIB, "NODEFLIB"),
+    (abi::DF_1_NODUMP, "NODUMP"),
+    (abi::DF_1_CONFALT, "CONFALT"),
+    (abi::DF_1_ENDFILTEE, "ENDFILTEE"),
+    (abi::DF_1_DISPRELDNE, "DISPRELDNE"),
+    (abi::DF_1_DISPRELPND, "DISPRELPND"),
+    (abi::DF_1_NODIRECT, "NODIRECT"),
+    (abi::DF_1_IGNMULDEF, "IGNMULDEF"),
+    (abi::DF_1_NOKSYMS, "NOKSYMS"),
+    (abi::DF_1_NOHDR, "NOHDR"),
+    (abi::DF_1_EDITED, "EDITED"),
+    (abi::DF_1_NORELOC, "NORELOC"),
+    (abi::DF_1_SYMINTPOSE, "SYMINTPOSE"),
+    (abi::DF_1_GLOBAUDIT, "GLOBAUDIT"),
+    (abi::DF_1_SINGLETON, "SINGLETON"),
+    (abi::DF_1_STUB, "STUB"),
+    (abi::DF_1_PIE, "PIE"),
+    (abi::DF_1_KMOD, "KMOD"),
+    (abi::DF_1_WEAKFILTER, "WEAKFILTER"),
+    (abi::DF_1_NOCOMMON, "NOCOMMON"),
+];
+
+const DTF_1_NAMES: &[(i64, &str)] = &[
+    (abi::DTF_1_PARINIT, "PARINIT"),
+    (abi::DTF_1_CONFEXP, "CONFEXP"),
+];
+
+fn fmt_flags(
+    f: &mut core::fmt::Formatter<'_>,
+    bits: u64,
+    names: &[(i64, &str)],
+) -> core::fmt::Result {
+    let mut remaining = bits;
+    let mut sep = "";
+    for &(flag, name) in names {
+        if remaining & flag as u64 != 0 {
+            write!(f, "{sep}{name}")?;
+            remaining &= !(flag as u64);
+            sep = " ";
+        }
+    }
+    if remaining != 0 {
+        write!(f, "{sep}{remaining:#x}")?;
+    }
+    Ok(())
+}
+
+impl core::fmt::Display for DtFlags {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        fmt_flags(f, self.0, DF_NAMES)
+    }
+}
+
+impl core::fmt::Display for DtFlags1 {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        fmt_flags(f, self.0, DF_1_NAMES)
+    }
+}
+
+impl core::fmt::Display for DtFeature1 {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        fmt_flags(f, self.0, DTF_1_NAMES)
+    }
+}
+
+/// Renders the entry in the style of an ELF64 `readelf -dW` line: tag, tag name and value.
+///
+/// The values of string tags like `DT_NEEDED` are offsets into the dynamic string table, so
+/// they're shown as hex rather than as readelf's `Shared library: [...]`. The entry doesn't
+/// know which machine it's for, so processor-specific tags are shown by number. Use
+/// [Dyn::display] for ELF32's narrower tag column and the machine's tag names.
+#[cfg(feature = "to_str")]
+impl core::fmt::Display for Dyn {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.display(Class::ELF64, abi::EM_NONE).fmt(f)
+    }
+}
+
+#[cfg(feature = "to_str")]
+impl core::fmt::Display for ReadelfDisplay<'_, Dyn> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        use crate::to_str::{d_tag_to_str, write_padded};
+
+        let entry = self.value;
+        // readelf pads the name out so that the values line up for both classes, and always
+        // follows it with at least one space
+        let width = match self.class {
+            Class::ELF32 => {
+                write!(f, "0x{:08x} ", entry.d_tag as u32)?;
+                28
+            }
+            Class::ELF64 => {
+                write!(f, "0x{:016x} ", entry.d_tag)?;
+                20
+            }
+        };
+        match (d_tag_to_str(entry.d_tag, self.e_machine), entry.d_tag) {
+            (Some(name), _) => write_padded(f, width, format_args!("({})", &name[3..]))?,
+            (None, d_tag @ abi::DT_LOOS..=0x6fffffff) => write_padded(
+                f,
+                width,
+                format_args!("(Operating System specific: {d_tag:x})"),
+            )?,
+            (None, d_tag @ abi::DT_LOPROC..=abi::DT_HIPROC) => {
+                write_padded(f, width, format_args!("(Processor Specific: {d_tag:x})"))?
+            }
+            (None, d_tag) => write_padded(f, width, format_args!("(<unknown>: {d_tag:x})"))?,
+        }
+        f.write_str(" ")?;
+
+        match (entry.kind(), entry.d_tag) {
+            (DynEntryKind::Size, _) => write!(f, "{} (bytes)", entry.d_un),
+            (DynEntryKind::Count, _) => write!(f, "{}", entry.d_un),
+            (_, abi::DT_PLTREL) => match entry.d_un as i64 {
+                abi::DT_REL => f.write_str("REL"),
+                abi::DT_RELA => f.write_str("RELA"),
+                _ => write!(f, "{:#x}", entry.d_un),
+            },
+            (_, abi::DT_FLAGS) => write!(f, "{}", DtFlags(entry.d_un)),
+            (_, abi::DT_FLAGS_1) => write!(f, "Flags: {}", DtFlags1(entry.d_un)),
+            (_, abi::DT_FEATURE_1) => write!(f, "Flags: {}", DtFeature1(entry.d_un)),
+            _ => write!(f, "{:#x}", entry.d_un),
         }
     }
 }
@@ -77,6 +529,27 @@ mod parse_tests {
     use crate::endian::{BigEndian, LittleEndian};
     use crate::parse::{test_parse_for, test_parse_fuzz_too_short};
 
+    #[test]
+    fn test_entry_kinds() {
+        let kind = |d_tag| Dyn { d_tag, d_un: 0 }.kind();
+        assert_eq!(kind(abi::DT_PLTGOT), DynEntryKind::Address);
+        assert_eq!(kind(abi::DT_DEBUG), DynEntryKind::Address);
+        assert_eq!(kind(abi::DT_PREINIT_ARRAY), DynEntryKind::Address);
+        assert_eq!(kind(abi::DT_SYMTAB_SHNDX), DynEntryKind::Address);
+        assert_eq!(kind(abi::DT_GNU_HASH), DynEntryKind::Address);
+        assert_eq!(kind(abi::DT_PREINIT_ARRAYSZ), DynEntryKind::Size);
+        assert_eq!(kind(abi::DT_RELACOUNT), DynEntryKind::Count);
+        assert_eq!(kind(abi::DT_AUDIT), DynEntryKind::String);
+        assert_eq!(kind(abi::DT_DEPAUDIT), DynEntryKind::String);
+        assert_eq!(kind(abi::DT_FEATURE_1), DynEntryKind::Flags);
+        assert_eq!(kind(abi::DT_BIND_NOW), DynEntryKind::Value);
+        assert_eq!(kind(abi::DT_CHECKSUM), DynEntryKind::Value);
+        // Unlisted tags in the address and value ranges
+        assert_eq!(kind(0x6ffffe10), DynEntryKind::Address);
+        assert_eq!(kind(0x6ffffd10), DynEntryKind::Value);
+        assert_eq!(kind(0x70000001), DynEntryKind::Unknown);
+    }
+
     #[test]
     fn test_d_val_and_d_ptr() {
         let val = Dyn {
@@ -155,3 +628,174 @@ mod parse_tests {
         test_parse_fuzz_too_short::<_, Dyn>(BigEndian, Class::ELF64);
     }
 }
+
+#[cfg(test)]
+mod flags_tests {
+    use super::*;
+    use crate::endian::LittleEndian;
+
+    #[test]
+    fn display_dt_flags() {
+        assert_eq!(format!("{}", DtFlags(0)), "");
+        assert_eq!(format!("{}", DtFlags(0x8)), "BIND_NOW");
+        assert_eq!(format!("{}", DtFlags(0x1 | 0x8)), "ORIGIN BIND_NOW");
+        assert_eq!(format!("{}", DtFlags(0x8 | 0x300)), "BIND_NOW 0x300");
+    }
+
+    #[test]
+    fn display_dt_flags_1() {
+        assert_eq!(format!("{}", DtFlags1(0x08000001)), "NOW PIE");
+        assert_eq!(format!("{}", DtFlags1(0x8)), "NODELETE");
+        assert_eq!(format!("{}", DtFlags1(0x80000000)), "0x80000000");
+    }
+
+    #[test]
+    fn display_dt_feature_1() {
+        assert_eq!(format!("{}", DtFeature1(0x3)), "PARINIT CONFEXP");
+        assert_eq!(format!("{}", DtFeature1(0x5)), "PARINIT 0x4");
+    }
+
+    #[test]
+    fn flag_predicates() {
+        let flags = DtFlags1((abi::DF_1_NOW | abi::DF_1_NODELETE | abi::DF_1_PIE) as u64);
+        assert!(flags.is_now() && flags.is_nodelete() && flags.is_pie());
+        assert!(!flags.is_noopen() && !flags.is_global() && !flags.is_interpose());
+        let flags = DtFlags((abi::DF_ORIGIN | abi::DF_TEXTREL) as u64);
+        assert!(flags.is_origin() && flags.has_textrel());
+        assert!(!flags.is_bind_now() && !flags.is_symbolic() && !flags.is_static_tls());
+        assert!(DtFeature1(abi::DTF_1_CONFEXP as u64).is_confexp());
+        assert!(!DtFeature1(abi::DTF_1_CONFEXP as u64).is_parinit());
+    }
+
+    #[test]
+    fn table_flags() {
+        let mut data = Vec::new();
+        for (d_tag, d_val) in [
+            (abi::DT_FLAGS, abi::DF_ORIGIN as u64),
+            (abi::DT_FLAGS_1, (abi::DF_1_PIE | 0x80000000) as u64),
+            (abi::DT_NULL, 0),
+            // Entries after the DT_NULL aren't part of the table
+            (abi::DT_FEATURE_1, abi::DTF_1_PARINIT as u64),
+        ] {
+            data.extend_from_slice(&d_tag.to_le_bytes());
+            data.extend_from_slice(&d_val.to_le_bytes());
+        }
+        let table = DynamicTable::new(LittleEndian, Class::ELF64, &data);
+        assert_eq!(table.flags(), DtFlags(abi::DF_ORIGIN as u64));
+        assert!(table.flags_1().is_pie());
+        // Unknown bits are kept
+        assert_eq!(format!("{}", table.flags_1()), "PIE 0x80000000");
+        assert_eq!(table.feature_1(), DtFeature1(0));
+        assert!(!table.is_bind_now());
+
+        data[8..16].copy_from_slice(&(abi::DF_BIND_NOW as u64).to_le_bytes());
+        let table = DynamicTable::new(LittleEndian, Class::ELF64, &data);
+        assert!(table.is_bind_now());
+    }
+
+    #[test]
+    fn contains_flags() {
+        let flags = DtFlags1(0x08000001);
+        assert!(flags.contains(abi::DF_1_PIE));
+        assert!(flags.contains(abi::DF_1_NOW));
+        assert!(!flags.contains(abi::DF_1_NODELETE));
+        assert!(DtFlags(0x18).contains(abi::DF_STATIC_TLS));
+    }
+}
+
+#[cfg(all(test, feature = "to_str"))]
+mod display_tests {
+    use super::*;
+
+    #[test]
+    fn display_dyn() {
+        // Rows from `readelf -dW` of sample-objects/basic.x86_64 and pie.x86_64
+        let entry = |d_tag, d_un| format!("{}", Dyn { d_tag, d_un });
+        assert_eq!(
+            entry(abi::DT_NEEDED, 1),
+            "0x0000000000000001 (NEEDED)             0x1"
+        );
+        assert_eq!(
+            entry(abi::DT_INIT_ARRAYSZ, 8),
+            "0x000000000000001b (INIT_ARRAYSZ)       8 (bytes)"
+        );
+        assert_eq!(
+            entry(abi::DT_VERNEEDNUM, 1),
+            "0x000000006fffffff (VERNEEDNUM)         1"
+        );
+        assert_eq!(
+            entry(abi::DT_PLTREL, abi::DT_RELA as u64),
+            "0x0000000000000014 (PLTREL)             RELA"
+        );
+        assert_eq!(
+            entry(abi::DT_FLAGS_1, abi::DF_1_PIE as u64),
+            "0x000000006ffffffb (FLAGS_1)            Flags: PIE"
+        );
+        assert_eq!(
+            entry(abi::DT_PREINIT_ARRAYSZ, 16),
+            "0x0000000000000021 (PREINIT_ARRAYSZ)    16 (bytes)"
+        );
+        assert_eq!(
+            entry(abi::DT_DEBUG, 0),
+            "0x0000000000000015 (DEBUG)              0x0"
+        );
+        assert_eq!(
+            entry(0x7fff0000, 0),
+            "0x000000007fff0000 (Processor Specific: 7fff0000) 0x0"
+        );
+    }
+
+    #[test]
+    fn display_dyn_elf32() {
+        // Rows from `readelf -dW sample-objects/symver.armhf.so`
+        let entry =
+            |d_tag, d_un| format!("{}", Dyn { d_tag, d_un }.display(Class::ELF32, abi::EM_ARM));
+        assert_eq!(
+            entry(abi::DT_VERDEF, 0x30c),
+            "0x6ffffffc (VERDEF)                     0x30c"
+        );
+        assert_eq!(
+            entry(abi::DT_VERDEFNUM, 3),
+            "0x6ffffffd (VERDEFNUM)                  3"
+        );
+    }
+}
+
+#[cfg(test)]
+mod serialize_tests {
+    use super::*;
+    use crate::endian::{BigEndian, LittleEndian};
+    use crate::parse::test_serialize_roundtrip;
+
+    #[test]
+    fn serialize_dyn32_lsb_roundtrip() {
+        test_serialize_roundtrip::<_, Dyn>(LittleEndian, Class::ELF32);
+    }
+
+    #[test]
+    fn serialize_dyn32_msb_roundtrip() {
+        test_serialize_roundtrip::<_, Dyn>(BigEndian, Class::ELF32);
+    }
+
+    #[test]
+    fn serialize_dyn64_lsb_roundtrip() {
+        test_serialize_roundtrip::<_, Dyn>(LittleEndian, Class::ELF64);
+    }
+
+    #[test]
+    fn serialize_dyn64_msb_roundtrip() {
+        test_serialize_roundtrip::<_, Dyn>(BigEndian, Class::ELF64);
+    }
+}
+
+#[cfg(all(test, feature = "serde"))]
+mod serde_tests {
+    use super::*;
+    use crate::parse::test_serde_roundtrip;
+
+    #[test]
+    fn serde_dyn() {
+        let dyn_ = Dyn { d_tag: 1, d_un: 42 };
+        test_serde_roundtrip(&dyn_, r#"{"d_tag":1,"d_un":42}"#);
+    }
+}
@@ -0,0 +1,405 @@
+//! Parsing the `.eh_frame_hdr` section (`PT_GNU_EH_FRAME`)
+//!
+//! The header holds a pointer to the `.eh_frame` section and a binary search table of
+//! (initial location, FDE address) pairs sorted by initial location, which lets unwinders find
+//! the FDE covering a given pc without parsing all of `.eh_frame`. Each of the header's values
+//! is stored in the `DW_EH_PE_*` pointer encoding named by the header's encoding bytes.
+//!
+//! See: <https://refspecs.linuxfoundation.org/LSB_5.0.0/LSB-Core-generic/LSB-Core-generic/ehframechpt.html>
+//!
+//! Example usage:
+//! ```
+//! use elf::endian::AnyEndian;
+//! use elf::ElfBytes;
+//!
+//! let path = std::path::PathBuf::from("sample-objects/basic.x86_64");
+//! let file_data = std::fs::read(path).expect("Could not read file.");
+//! let file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("Open test1");
+//!
+//! let hdr = file
+//!     .eh_frame_hdr()
+//!     .expect("section should parse")
+//!     .expect("file should have a .eh_frame_hdr section");
+//! assert_eq!(hdr.eh_frame_ptr, Some(0x4005c8));
+//! assert_eq!(hdr.fde_count(), 5);
+//! // main() at 0x4004e6 is covered by the FDE at offset 0x70 in .eh_frame
+//! assert_eq!(hdr.lookup(0x4004f0).expect("should parse"), Some(0x4005c8 + 0x70));
+//! ```
+use crate::abi;
+use crate::endian::EndianParse;
+use crate::file::Class;
+use crate::parse::{parse_uleb128, ParseError};
+
+/// The name of the section holding the header
+pub const EH_FRAME_HDR_SECTION_NAME: &str = ".eh_frame_hdr";
+
+/// Get the size of a value in the given pointer encoding, or None if its size varies.
+fn encoded_size(class: Class, encoding: u8) -> Result<Option<usize>, ParseError> {
+    let size = match encoding & 0x0f {
+        abi::DW_EH_PE_ABSPTR => match class {
+            Class::ELF32 => 4,
+            Class::ELF64 => 8,
+        },
+        abi::DW_EH_PE_UDATA2 | abi::DW_EH_PE_SDATA2 => 2,
+        abi::DW_EH_PE_UDATA4 | abi::DW_EH_PE_SDATA4 => 4,
+        abi::DW_EH_PE_UDATA8 | abi::DW_EH_PE_SDATA8 => 8,
+        abi::DW_EH_PE_ULEB128 => return Ok(None),
+        _ => return Err(ParseError::UnsupportedPointerEncoding(encoding)),
+    };
+    Ok(Some(size))
+}
+
+/// Decode a value in the given pointer encoding at `offset` in the data of a section loaded
+/// at `addr`. Values relative to the data section are relative to `addr`.
+fn parse_encoded<E: EndianParse>(
+    endian: E,
+    class: Class,
+    encoding: u8,
+    addr: u64,
+    offset: &mut usize,
+    data: &[u8],
+) -> Result<u64, ParseError> {
+    // Indirect values would need to be read out of the loaded image
+    if encoding & abi::DW_EH_PE_INDIRECT != 0 {
+        return Err(ParseError::UnsupportedPointerEncoding(encoding));
+    }
+    let place = addr.wrapping_add(*offset as u64);
+    let value = match encoding & 0x0f {
+        abi::DW_EH_PE_ABSPTR => match class {
+            Class::ELF32 => endian.parse_u32_at(offset, data)? as u64,
+            Class::ELF64 => endian.parse_u64_at(offset, data)?,
+        },
+        abi::DW_EH_PE_ULEB128 => parse_uleb128(offset, data)?,
+        abi::DW_EH_PE_UDATA2 => endian.parse_u16_at(offset, data)? as u64,
+        abi::DW_EH_PE_UDATA4 => endian.parse_u32_at(offset, data)? as u64,
+        abi::DW_EH_PE_UDATA8 => endian.parse_u64_at(offset, data)?,
+        abi::DW_EH_PE_SDATA2 => endian.parse_u16_at(offset, data)? as i16 as u64,
+        abi::DW_EH_PE_SDATA4 => endian.parse_i32_at(offset, data)? as u64,
+        abi::DW_EH_PE_SDATA8 => endian.parse_i64_at(offset, data)? as u64,
+        _ => return Err(ParseError::UnsupportedPointerEncoding(encoding)),
+    };
+    match encoding & 0x70 {
+        0 => Ok(value),
+        abi::DW_EH_PE_PCREL => Ok(place.wrapping_add(value)),
+        abi::DW_EH_PE_DATAREL => Ok(addr.wrapping_add(value)),
+        _ => Err(ParseError::UnsupportedPointerEncoding(encoding)),
+    }
+}
+
+/// An entry in the binary search table
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EhFrameHdrEntry {
+    /// The address of the start of the code that the FDE covers
+    pub initial_location: u64,
+    /// The address of the FDE in `.eh_frame`
+    pub fde_address: u64,
+}
+
+/// The contents of an `.eh_frame_hdr` section, as found by its name or by the
+/// `PT_GNU_EH_FRAME` segment.
+///
+/// The values are decoded with the pointer encodings named in the header. The absolute,
+/// pc-relative, and data-relative (relative to the start of the header) applications of the
+/// fixed-size formats are supported, along with ULEB128 outside of the search table.
+#[derive(Debug, Clone, Copy)]
+pub struct EhFrameHdr<'data, E: EndianParse> {
+    endian: E,
+    class: Class,
+    addr: u64,
+    data: &'data [u8],
+    /// The encoding of the `.eh_frame` pointer
+    pub eh_frame_ptr_enc: u8,
+    /// The encoding of the search table's entry count
+    pub fde_count_enc: u8,
+    /// The encoding of the values in the search table
+    pub table_enc: u8,
+    /// The address of the `.eh_frame` section, or None if it was omitted
+    pub eh_frame_ptr: Option<u64>,
+    fde_count: usize,
+    table_offset: usize,
+    value_size: usize,
+}
+
+impl<'data, E: EndianParse> EhFrameHdr<'data, E> {
+    /// Parse the header of the `.eh_frame_hdr` data loaded at `addr`.
+    ///
+    /// Returns a [ParseError::UnsupportedPointerEncoding] naming the encoding byte if one of
+    /// the header's values uses an encoding that can't be decoded, and a
+    /// [ParseError::SliceReadError] if the data is too short to hold the search table.
+    pub fn new(endian: E, class: Class, addr: u64, data: &'data [u8]) -> Result<Self, ParseError> {
+        let mut offset = 0;
+        let version = endian.parse_u8_at(&mut offset, data)?;
+        if version != abi::EH_FRAME_HDR_VERSION {
+            return Err(ParseError::UnsupportedVersion((
+                version as u64,
+                abi::EH_FRAME_HDR_VERSION as u64,
+            )));
+        }
+        let eh_frame_ptr_enc = endian.parse_u8_at(&mut offset, data)?;
+        let fde_count_enc = endian.parse_u8_at(&mut offset, data)?;
+        let table_enc = endian.parse_u8_at(&mut offset, data)?;
+
+        let eh_frame_ptr = match eh_frame_ptr_enc {
+            abi::DW_EH_PE_OMIT => None,
+            enc => Some(parse_encoded(endian, class, enc, addr, &mut offset, data)?),
+        };
+
+        // The search table is only present if both its count and its values are
+        let (fde_count, value_size) =
+            if fde_count_enc == abi::DW_EH_PE_OMIT || table_enc == abi::DW_EH_PE_OMIT {
+                (0, 0)
+            } else {
+                let count = parse_encoded(endian, class, fde_count_enc, addr, &mut offset, data)?;
+                let value_size = encoded_size(class, table_enc)?
+                    .ok_or(ParseError::UnsupportedPointerEncoding(table_enc))?;
+                (usize::try_from(count)?, value_size)
+            };
+
+        let table_size = fde_count
+            .checked_mul(value_size * 2)
+            .ok_or(ParseError::IntegerOverflow)?;
+        let end = offset
+            .checked_add(table_size)
+            .ok_or(ParseError::IntegerOverflow)?;
+        if end > data.len() {
+            return Err(ParseError::SliceReadError((offset, end)));
+        }
+
+        let hdr = EhFrameHdr {
+            endian,
+            class,
+            addr,
+            data,
+            eh_frame_ptr_enc,
+            fde_count_enc,
+            table_enc,
+            eh_frame_ptr,
+            fde_count,
+            table_offset: offset,
+            value_size,
+        };
+        // Decode the first entry to check that the table's encoding is applied in a supported way
+        if fde_count > 0 {
+            hdr.get(0)?;
+        }
+        Ok(hdr)
+    }
+
+    /// Get the number of entries in the binary search table
+    pub fn fde_count(&self) -> usize {
+        self.fde_count
+    }
+
+    /// Get the search table entry at the given index, with its addresses decoded.
+    pub fn get(&self, index: usize) -> Result<EhFrameHdrEntry, ParseError> {
+        if index >= self.fde_count {
+            return Err(ParseError::BadOffset(index as u64));
+        }
+        let mut offset = self.table_offset + index * self.value_size * 2;
+        let initial_location = self.parse_value(&mut offset)?;
+        let fde_address = self.parse_value(&mut offset)?;
+        Ok(EhFrameHdrEntry {
+            initial_location,
+            fde_address,
+        })
+    }
+
+    fn parse_value(&self, offset: &mut usize) -> Result<u64, ParseError> {
+        parse_encoded(
+            self.endian,
+            self.class,
+            self.table_enc,
+            self.addr,
+            offset,
+            self.data,
+        )
+    }
+
+    /// Get an iterator over the search table's entries.
+    pub fn iter(&self) -> EhFrameHdrIterator<'data, E> {
+        EhFrameHdrIterator {
+            hdr: *self,
+            index: 0,
+        }
+    }
+
+    /// Find the address of the FDE for the code containing `pc`, which is the FDE of the last
+    /// entry whose initial location is at or before it.
+    ///
+    /// Returns an empty Option if `pc` is before the first entry. The FDE's address range
+    /// isn't checked, so callers should check that the returned FDE actually covers `pc`.
+    pub fn lookup(&self, pc: u64) -> Result<Option<u64>, ParseError> {
+        // Binary search for the number of entries which start at or before pc
+        let (mut lo, mut hi) = (0, self.fde_count);
+        while lo < hi {
+            let mid = lo + (hi - lo) / 2;
+            if self.get(mid)?.initial_location <= pc {
+                lo = mid + 1;
+            } else {
+                hi = mid;
+            }
+        }
+        match lo {
+            0 => Ok(None),
+            n => Ok(Some(self.get(n - 1)?.fde_address)),
+        }
+    }
+}
+
+/// Iterates over the binary search table entries of an [EhFrameHdr]
+#[derive(Debug, Clone)]
+pub struct EhFrameHdrIterator<'data, E: EndianParse> {
+    hdr: EhFrameHdr<'data, E>,
+    index: usize,
+}
+
+impl<'data, E: EndianParse> Iterator for EhFrameHdrIterator<'data, E> {
+    type Item = EhFrameHdrEntry;
+    fn next(&mut self) -> Option<Self::Item> {
+        // The table's encoding and size were checked on construction, so getting entries
+        // can't fail
+        let entry = self.hdr.get(self.index).ok()?;
+        self.index += 1;
+        Some(entry)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::endian::{BigEndian, LittleEndian};
+
+    #[test]
+    fn parse_datarel_sdata4_table() {
+        #[rustfmt::skip]
+        let data = [
+            // version, pcrel|sdata4, udata4, datarel|sdata4
+            0x01, 0x1b, 0x03, 0x3b,
+            // .eh_frame at 0x1004 + 0x20
+            0x20, 0x00, 0x00, 0x00,
+            // 3 entries
+            0x03, 0x00, 0x00, 0x00,
+            // 0x1000 - 0x800 -> 0x1000 + 0x40
+            0x00, 0xf8, 0xff, 0xff, 0x40, 0x00, 0x00, 0x00,
+            // 0x1000 + 0x100 -> 0x1000 + 0x60
+            0x00, 0x01, 0x00, 0x00, 0x60, 0x00, 0x00, 0x00,
+            // 0x1000 + 0x200 -> 0x1000 + 0x80
+            0x00, 0x02, 0x00, 0x00, 0x80, 0x00, 0x00, 0x00,
+        ];
+        let hdr = EhFrameHdr::new(LittleEndian, Class::ELF64, 0x1000, &data).expect("should parse");
+        assert_eq!(hdr.eh_frame_ptr, Some(0x1024));
+        assert_eq!(hdr.fde_count(), 3);
+        assert_eq!(
+            hdr.iter().collect::<Vec<_>>(),
+            vec![
+                EhFrameHdrEntry {
+                    initial_location: 0x800,
+                    fde_address: 0x1040,
+                },
+                EhFrameHdrEntry {
+                    initial_location: 0x1100,
+                    fde_address: 0x1060,
+                },
+                EhFrameHdrEntry {
+                    initial_location: 0x1200,
+                    fde_address: 0x1080,
+                },
+            ]
+        );
+
+        assert!(hdr.get(3).is_err());
+        assert_eq!(hdr.lookup(0x7ff).expect("should parse"), None);
+        assert_eq!(hdr.lookup(0x800).expect("should parse"), Some(0x1040));
+        assert_eq!(hdr.lookup(0x10ff).expect("should parse"), Some(0x1040));
+        assert_eq!(hdr.lookup(0x1100).expect("should parse"), Some(0x1060));
+        assert_eq!(hdr.lookup(u64::MAX).expect("should parse"), Some(0x1080));
+    }
+
+    #[test]
+    fn parse_absolute_udata8_table() {
+        #[rustfmt::skip]
+        let data = [
+            // version, absptr, uleb128, udata8
+            0x01, 0x00, 0x01, 0x04,
+            // .eh_frame at 0x2000
+            0x00, 0x00, 0x20, 0x00,
+            // 1 entry
+            0x01,
+            // 0x3000 -> 0x2010
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x30, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x20, 0x10,
+        ];
+        let hdr = EhFrameHdr::new(BigEndian, Class::ELF32, 0x1000, &data).expect("should parse");
+        assert_eq!(hdr.eh_frame_ptr, Some(0x2000));
+        assert_eq!(hdr.fde_count(), 1);
+        assert_eq!(hdr.lookup(0x3000).expect("should parse"), Some(0x2010));
+    }
+
+    #[test]
+    fn omitted_table() {
+        let data = [0x01, 0xff, 0xff, 0xff];
+        let hdr = EhFrameHdr::new(LittleEndian, Class::ELF64, 0, &data).expect("should parse");
+        assert_eq!(hdr.eh_frame_ptr, None);
+        assert_eq!(hdr.fde_count(), 0);
+        assert_eq!(hdr.iter().count(), 0);
+        assert_eq!(hdr.lookup(0).expect("should parse"), None);
+    }
+
+    #[test]
+    fn unsupported_encodings_error() {
+        // textrel application
+        let data = [0x01, 0x23, 0xff, 0xff, 0x00, 0x00, 0x00, 0x00];
+        let result = EhFrameHdr::new(LittleEndian, Class::ELF64, 0, &data);
+        assert!(
+            matches!(result, Err(ParseError::UnsupportedPointerEncoding(0x23))),
+            "Unexpected Error type found: {result:?}"
+        );
+
+        // variable-sized table values
+        let data = [0x01, 0xff, 0x03, 0x31, 0x00, 0x00, 0x00, 0x00];
+        let result = EhFrameHdr::new(LittleEndian, Class::ELF64, 0, &data);
+        assert!(
+            matches!(result, Err(ParseError::UnsupportedPointerEncoding(0x31))),
+            "Unexpected Error type found: {result:?}"
+        );
+
+        // table values relative to the function
+        let data = [
+            0x01, 0xff, 0x03, 0x43, 0x01, 0x00, 0x00, 0x00, 0, 0, 0, 0, 0, 0, 0, 0,
+        ];
+        let result = EhFrameHdr::new(LittleEndian, Class::ELF64, 0, &data);
+        assert!(
+            matches!(result, Err(ParseError::UnsupportedPointerEncoding(0x43))),
+            "Unexpected Error type found: {result:?}"
+        );
+
+        // indirect values
+        let data = [0x01, 0x9b, 0xff, 0xff, 0x00, 0x00, 0x00, 0x00];
+        let result = EhFrameHdr::new(LittleEndian, Class::ELF64, 0, &data);
+        assert!(
+            matches!(result, Err(ParseError::UnsupportedPointerEncoding(0x9b))),
+            "Unexpected Error type found: {result:?}"
+        );
+    }
+
+    #[test]
+    fn bad_version_and_truncated_table_error() {
+        let data = [0x02, 0xff, 0xff, 0xff];
+        let result = EhFrameHdr::new(LittleEndian, Class::ELF64, 0, &data);
+        assert!(
+            matches!(result, Err(ParseError::UnsupportedVersion((2, 1)))),
+            "Unexpected Error type found: {result:?}"
+        );
+
+        // claims 2 entries but only has room for 1
+        let data = [
+            0x01, 0xff, 0x03, 0x3b, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00,
+        ];
+        let result = EhFrameHdr::new(LittleEndian, Class::ELF64, 0, &data);
+        assert!(
+            matches!(result, Err(ParseError::SliceReadError((8, 24)))),
+            "Unexpected Error type found: {result:?}"
+        );
+    }
+}
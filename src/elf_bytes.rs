@@ -1,19 +1,70 @@
 use crate::abi;
+use crate::arm_exidx::ExidxTable;
+use crate::attributes::{ArmAttributes, CskyAttributes, RiscVAttributes};
+#[cfg(feature = "alloc")]
+use crate::compression::parse_zdebug_header;
 use crate::compression::CompressionHeader;
+use crate::debuglink::{
+    parse_gnu_debugaltlink, parse_gnu_debuglink, GnuDebugAltLink, GNU_DEBUGALTLINK_SECTION_NAME,
+    GNU_DEBUGLINK_SECTION_NAME,
+};
 use crate::dynamic::{Dyn, DynamicTable};
+use crate::eh_frame_hdr::{EhFrameHdr, EH_FRAME_HDR_SECTION_NAME};
 use crate::endian::EndianParse;
-use crate::file::{parse_ident, Class, FileHeader};
+use crate::file::{parse_ident_with_options, verify_version, Class, FileHeader};
 use crate::gnu_symver::{
-    SymbolVersionTable, VerDefIterator, VerNeedIterator, VersionIndex, VersionIndexTable,
+    SymbolVersionTable, VerDef, VerDefIterator, VerNeed, VerNeedIterator, VersionIndex,
+    VersionIndexTable,
+};
+use crate::go::{
+    parse_ptr, strip_modinfo_sentinels, GoBuildInfo, GoBuildInfoHeader, GO_BUILDINFO_MAGIC,
+    GO_BUILDINFO_SECTION_NAME,
 };
+#[cfg(feature = "alloc")]
+use crate::got::{GotSlot, GotTarget};
 use crate::hash::{GnuHashTable, SysVHashTable};
-use crate::note::NoteIterator;
-use crate::parse::{ParseAt, ParseError, ReadBytesExt};
-use crate::relocation::{RelIterator, RelaIterator};
-use crate::section::{SectionHeader, SectionHeaderTable};
-use crate::segment::{ProgramHeader, SegmentTable};
+use crate::init_array::{AddrTable, InitFunctions};
+#[cfg(feature = "alloc")]
+use crate::layout::{FileLayout, LayoutOwner, LayoutRange};
+use crate::liblist::LibListIterator;
+use crate::mips::{MipsAbiFlags, MipsRegInfo};
+use crate::modinfo::{ModInfoIterator, ModuleSignature, MODINFO_SECTION_NAME};
+use crate::note::{Note, NoteGnuBuildId, NoteIterator, OsIdent};
+use crate::parse::{
+    file_range, BareParseError, ErrorContext, ParseAt, ParseError, ParseOptions, ParseWarning,
+    ParsingTable, ReadBytesExt, ResultContextExt, WordSize,
+};
+#[cfg(feature = "alloc")]
+use crate::plt::{PltEntry, PltLayout, PltTarget};
+#[cfg(feature = "alloc")]
+use crate::relocation::Rela;
+#[cfg(feature = "alloc")]
+use crate::relocation::{implicit_addend_bits, rel_to_rela, SectionRelocation};
+use crate::relocation::{DynamicRelocations, RelIterator, RelaIterator};
+use crate::section::{SectionHeader, SectionHeaderNameIterator, SectionHeaderTable};
+use crate::segment::{ProgramHeader, Relro, RelroCoverage, SegmentTable, TlsSegment};
+use crate::sframe::{SFrameSection, SFRAME_SECTION_NAME};
+#[cfg(feature = "alloc")]
+use crate::stack_sizes::{
+    FunctionStackSize, StackSizeIterator, StackSizesSection, STACK_SIZES_SECTION_NAME,
+};
 use crate::string_table::StringTable;
-use crate::symbol::{Symbol, SymbolTable};
+use crate::symbol::{SectionSymbolIterator, Symbol, SymbolShndxTable, SymbolTable};
+#[cfg(feature = "alloc")]
+use crate::symbol::{SymbolExport, SymbolImport, Symbolizer};
+
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::borrow::Cow;
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::boxed::Box;
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::collections::BTreeMap;
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use std::borrow::Cow;
+#[cfg(feature = "std")]
+use std::collections::BTreeMap;
 
 //  _____ _     _____ ____        _
 // | ____| |   |  ___| __ ) _   _| |_ ___  ___
@@ -27,7 +78,9 @@ use crate::symbol::{Symbol, SymbolTable};
 ///
 /// This parser is no_std and zero-alloc, returning lazy-parsing interfaces wrapped around
 /// subslices of the provided ELF bytes `&[u8]`. The various ELF structures are
-/// parsed on-demand into a native Rust representation.
+/// parsed on-demand into a native Rust representation. The one exception is that with the
+/// `alloc` feature, the section headers and their names are cached the first time they're
+/// looked up, see [ElfBytes::section_header_by_name].
 ///
 /// Example usage:
 /// ```
@@ -64,9 +117,161 @@ use crate::symbol::{Symbol, SymbolTable};
 #[derive(Debug)]
 pub struct ElfBytes<'data, E: EndianParse> {
     pub ehdr: FileHeader<E>,
-    data: &'data [u8],
+    pub(crate) data: &'data [u8],
     shdrs: Option<SectionHeaderTable<'data, E>>,
     phdrs: Option<SegmentTable<'data, E>>,
+    /// The load bias of an image parsed from memory with [ElfBytes::parse_loaded]
+    load_bias: Option<u64>,
+    /// The options the file was parsed with, for checking the sizes of tables found later
+    pub(crate) options: ParseOptions,
+    #[cfg(feature = "alloc")]
+    warnings: Vec<ParseWarning>,
+    /// The section headers and names, parsed up front by [ElfBytes::minimal_parse]
+    #[cfg(feature = "alloc")]
+    shdr_cache: Option<SectionHeaderCache>,
+}
+
+/// The parsed section headers, kept by [ElfBytes] so that repeated lookups don't re-walk
+/// the section header table.
+///
+/// This doesn't borrow the data, so that it has no say in how long the data must live when
+/// the [ElfBytes] is dropped.
+#[cfg(feature = "alloc")]
+#[derive(Debug)]
+struct SectionHeaderCache {
+    shdrs: Box<[SectionHeader]>,
+    /// The range of the data holding the section name string table, if there is one
+    strtab: Option<core::ops::Range<usize>>,
+    /// Whether e_shstrndx is bad, in which case [ElfBytes::section_headers_with_strtab]
+    /// reports the error in strict mode
+    bad_shstrndx: bool,
+    /// The indexes of the sections with valid names, sorted by name and then by index
+    by_name: Box<[usize]>,
+}
+
+/// Check whether a section with the given name holds DWARF debug info. Sections which have
+/// had their contents stripped out to [abi::SHT_NOBITS] don't count.
+fn is_debug_info_section(name: &[u8], shdr: &SectionHeader) -> bool {
+    (name.starts_with(b".debug_") || name.starts_with(b".zdebug_"))
+        && shdr.sh_type != abi::SHT_NOBITS
+}
+
+/// Strip the trailing NUL padding from a string note description.
+fn trim_note_desc(name: &[u8]) -> &[u8] {
+    let len = name
+        .iter()
+        .rposition(|byte| *byte != 0)
+        .map_or(0, |i| i + 1);
+    &name[..len]
+}
+
+/// Parse the [FileHeader] at the start of the given data buffer.
+///
+/// In strict mode, both `EI_VERSION` and `e_version` must be [abi::EV_CURRENT]. Otherwise,
+/// they're left for the caller to warn about.
+fn parse_ehdr<E: EndianParse>(
+    data: &[u8],
+    options: &ParseOptions,
+) -> Result<FileHeader<E>, ParseError> {
+    let ident_buf = data.get_bytes(0..abi::EI_NIDENT)?;
+    let ident = parse_ident_with_options(ident_buf, options)?;
+
+    let tail_start = abi::EI_NIDENT;
+    let tail_end = match ident.class {
+        Class::ELF32 => tail_start + crate::file::ELF32_EHDR_TAILSIZE,
+        Class::ELF64 => tail_start + crate::file::ELF64_EHDR_TAILSIZE,
+    };
+    let tail_buf = data.get_bytes(tail_start..tail_end)?;
+
+    let ehdr = FileHeader::parse_tail(ident, tail_buf)?;
+    if options.strict {
+        verify_version(ehdr.version)?;
+    }
+    Ok(ehdr)
+}
+
+/// The warnings for an object parsed in permissive mode whose versions aren't
+/// [abi::EV_CURRENT], see [parse_ehdr].
+#[cfg(feature = "alloc")]
+fn version_warnings<E: EndianParse>(ehdr: &FileHeader<E>) -> Vec<ParseWarning> {
+    [
+        ("EI_VERSION", ehdr.ident_version as u32),
+        ("e_version", ehdr.version),
+    ]
+    .into_iter()
+    .filter(|(_, found)| verify_version(*found).is_err())
+    .map(|(what, found)| ParseWarning::UnsupportedVersion { what, found })
+    .collect()
+}
+
+/// The warnings for the section headers of an object parsed in permissive mode, for the
+/// problems that the section lookups work around: out of range links, tables with an
+/// `sh_entsize` of 0 and overlapping section data.
+#[cfg(feature = "alloc")]
+fn section_warnings(shnum: usize, shdrs: impl Iterator<Item = SectionHeader>) -> Vec<ParseWarning> {
+    let mut warnings = Vec::new();
+    let mut ranges = Vec::new();
+    for (index, shdr) in shdrs.enumerate() {
+        let links = matches!(
+            shdr.sh_type,
+            abi::SHT_SYMTAB
+                | abi::SHT_DYNSYM
+                | abi::SHT_DYNAMIC
+                | abi::SHT_REL
+                | abi::SHT_RELA
+                | abi::SHT_GNU_LIBLIST
+        );
+        if links && shdr.sh_link as usize >= shnum {
+            warnings.push(ParseWarning::BadLink {
+                index,
+                sh_link: shdr.sh_link,
+            });
+        }
+
+        let is_table = matches!(
+            shdr.sh_type,
+            abi::SHT_SYMTAB
+                | abi::SHT_DYNSYM
+                | abi::SHT_DYNAMIC
+                | abi::SHT_SYMTAB_SHNDX
+                | abi::SHT_GNU_VERSYM
+        );
+        if is_table && shdr.sh_entsize == 0 {
+            warnings.push(ParseWarning::ZeroEntsize { index });
+        }
+
+        if index != 0 && shdr.sh_type != abi::SHT_NOBITS && shdr.sh_size != 0 {
+            let end = shdr.sh_offset.saturating_add(shdr.sh_size);
+            ranges.push((shdr.sh_offset, end, index));
+        }
+    }
+
+    // Sweep through in order of start offset, tracking the section that reaches the furthest
+    ranges.sort_unstable();
+    let mut furthest: Option<(u64, usize)> = None;
+    for (start, end, index) in ranges {
+        match furthest {
+            Some((furthest_end, other)) if start < furthest_end => {
+                warnings.push(ParseWarning::OverlappingSections { index, other });
+                if end > furthest_end {
+                    furthest = Some((end, index));
+                }
+            }
+            _ => furthest = Some((end, index)),
+        }
+    }
+    warnings
+}
+
+/// Parse the section header at index 0, which holds the real counts for tables too big for
+/// the file header's fields.
+fn parse_shdr0<E: EndianParse>(
+    ehdr: &FileHeader<E>,
+    data: &[u8],
+) -> Result<SectionHeader, ParseError> {
+    let size = SectionHeader::size_for(ehdr.class) as u64;
+    let buf = data.get_file_bytes(file_range(ehdr.e_shoff, size)?)?;
+    SectionHeader::parse_at(ehdr.endianness, ehdr.class, &mut 0, buf)
 }
 
 /// Find the location (if any) of the section headers in the given data buffer and take a
@@ -76,6 +281,7 @@ pub struct ElfBytes<'data, E: EndianParse> {
 fn find_shdrs<'data, E: EndianParse>(
     ehdr: &FileHeader<E>,
     data: &'data [u8],
+    options: &ParseOptions,
 ) -> Result<Option<SectionHeaderTable<'data, E>>, ParseError> {
     // It's Ok to have no section headers
     if ehdr.e_shoff == 0 {
@@ -85,25 +291,24 @@ fn find_shdrs<'data, E: EndianParse>(
     // If the number of sections is greater than or equal to SHN_LORESERVE (0xff00),
     // e_shnum is zero and the actual number of section header table entries
     // is contained in the sh_size field of the section header at index 0.
-    let shoff: usize = ehdr.e_shoff.try_into()?;
-    let mut shnum = ehdr.e_shnum as usize;
+    let mut shnum = ehdr.e_shnum as u64;
     if shnum == 0 {
-        let mut offset = shoff;
-        let shdr0 = SectionHeader::parse_at(ehdr.endianness, ehdr.class, &mut offset, data)?;
-        shnum = shdr0.sh_size.try_into()?;
+        let shdr0 = parse_shdr0(ehdr, data)?;
+        shnum = shdr0.sh_size;
     }
 
     // Validate shentsize before trying to read the table so that we can error early for corrupted files
     let entsize = SectionHeader::validate_entsize(ehdr.class, ehdr.e_shentsize as usize)?;
 
-    let size = entsize
+    let size = (entsize as u64)
         .checked_mul(shnum)
         .ok_or(ParseError::IntegerOverflow)?;
-    let end = shoff.checked_add(size).ok_or(ParseError::IntegerOverflow)?;
-    let buf = data.get_bytes(shoff..end)?;
-    Ok(Some(SectionHeaderTable::new(
+    options.check_table_size(size, data.len())?;
+    let buf = data.get_file_bytes(file_range(ehdr.e_shoff, size)?)?;
+    Ok(Some(SectionHeaderTable::with_entsize(
         ehdr.endianness,
         ehdr.class,
+        entsize,
         buf,
     )))
 }
@@ -113,6 +318,7 @@ fn find_shdrs<'data, E: EndianParse>(
 fn find_phdrs<'data, E: EndianParse>(
     ehdr: &FileHeader<E>,
     data: &'data [u8],
+    options: &ParseOptions,
 ) -> Result<Option<SegmentTable<'data, E>>, ParseError> {
     // It's Ok to have no program headers
     if ehdr.e_phoff == 0 {
@@ -122,28 +328,126 @@ fn find_phdrs<'data, E: EndianParse>(
     // If the number of segments is greater than or equal to PN_XNUM (0xffff),
     // e_phnum is set to PN_XNUM, and the actual number of program header table
     // entries is contained in the sh_info field of the section header at index 0.
-    let mut phnum = ehdr.e_phnum as usize;
-    if phnum == abi::PN_XNUM as usize {
-        let shoff: usize = ehdr.e_shoff.try_into()?;
-        let mut offset = shoff;
-        let shdr0 = SectionHeader::parse_at(ehdr.endianness, ehdr.class, &mut offset, data)?;
-        phnum = shdr0.sh_info.try_into()?;
+    let mut phnum = ehdr.e_phnum as u64;
+    if phnum == abi::PN_XNUM as u64 {
+        let shdr0 = parse_shdr0(ehdr, data)?;
+        phnum = shdr0.sh_info as u64;
     }
 
     // Validate phentsize before trying to read the table so that we can error early for corrupted files
     let entsize = ProgramHeader::validate_entsize(ehdr.class, ehdr.e_phentsize as usize)?;
 
-    let phoff: usize = ehdr.e_phoff.try_into()?;
-    let size = entsize
+    let size = (entsize as u64)
         .checked_mul(phnum)
         .ok_or(ParseError::IntegerOverflow)?;
-    let end = phoff.checked_add(size).ok_or(ParseError::IntegerOverflow)?;
-    let buf = data.get_bytes(phoff..end)?;
-    Ok(Some(SegmentTable::new(ehdr.endianness, ehdr.class, buf)))
+    options.check_table_size(size, data.len())?;
+    let buf = data.get_file_bytes(file_range(ehdr.e_phoff, size)?)?;
+    Ok(Some(SegmentTable::with_entsize(
+        ehdr.endianness,
+        ehdr.class,
+        entsize,
+        buf,
+    )))
+}
+
+/// Split a colon-separated list of libraries, like the value of a `DT_AUDIT` entry
+#[cfg(feature = "alloc")]
+fn split_library_list(list: Option<&str>) -> Vec<&str> {
+    list.map_or_else(Vec::new, |list| {
+        list.split(':').filter(|name| !name.is_empty()).collect()
+    })
+}
+
+/// Shift a range of bytes within a table to be relative to the start of the file
+fn offset_range(base: usize, range: core::ops::Range<usize>) -> core::ops::Range<usize> {
+    base + range.start..base + range.end
+}
+
+/// Tables which are too big to parse aren't salvaged in permissive mode, since the limit is
+/// there to stop them from being used at all.
+fn is_limit_exceeded(err: &ParseError) -> bool {
+    matches!(
+        err,
+        ParseError::LimitExceeded(_)
+            | ParseError::Context(ErrorContext {
+                source: BareParseError::LimitExceeded(_),
+                ..
+            })
+    )
+}
+
+/// Best-effort recovery of a header table that failed to parse in permissive mode.
+///
+/// If the table has the expected entry size, then it's truncated to the whole entries that fit
+/// in the file (up to the `declared` number of entries, if known). Otherwise, or if no
+/// entries fit, then the table is ignored. Returns the table along with a warning describing
+/// what was done.
+fn salvage_table<'data, E: EndianParse, P: ParseAt>(
+    ehdr: &FileHeader<E>,
+    data: &'data [u8],
+    what: &'static str,
+    file_offset: u64,
+    entsize: u16,
+    declared: Option<usize>,
+    error: ParseError,
+) -> (Option<ParsingTable<'data, E, P>>, ParseWarning) {
+    let ignored = |error| {
+        let warning = ParseWarning::IgnoredTable {
+            what,
+            file_offset,
+            error,
+        };
+        (None, warning)
+    };
+
+    let entsize = match P::validate_entsize(ehdr.class, entsize as usize) {
+        Ok(entsize) => entsize,
+        Err(_) => return ignored(error),
+    };
+    let rest = match usize::try_from(file_offset)
+        .ok()
+        .and_then(|start| data.get(start..))
+    {
+        Some(rest) => rest,
+        None => return ignored(error),
+    };
+    let fit = rest.len() / entsize;
+    let found = declared.map_or(fit, |declared| declared.min(fit));
+    if found == 0 {
+        return ignored(error);
+    }
+
+    let table = ParsingTable::with_entsize(
+        ehdr.endianness,
+        ehdr.class,
+        entsize,
+        &rest[..found * entsize],
+    );
+    let warning = ParseWarning::TruncatedTable {
+        what,
+        file_offset,
+        expected: declared.unwrap_or(found),
+        found,
+    };
+    (Some(table), warning)
+}
+
+/// Find the first symbol in the table with the given name by walking the whole table.
+fn find_symbol_linear<'data, E: EndianParse>(
+    name: &[u8],
+    symtab: &SymbolTable<'data, E>,
+    strtab: &StringTable<'data>,
+) -> Result<Option<(usize, Symbol)>, ParseError> {
+    for (idx, sym) in symtab.iter().enumerate() {
+        if strtab.get_raw(sym.st_name as usize)? == name {
+            return Ok(Some((idx, sym)));
+        }
+    }
+    Ok(None)
 }
 
 /// This struct collects the common sections found in ELF objects
-#[derive(Debug, Default)]
+#[derive(Debug, Clone, Default)]
 pub struct CommonElfData<'data, E: EndianParse> {
     /// .symtab section
     pub symtab: Option<SymbolTable<'data, E>>,
@@ -165,36 +469,306 @@ pub struct CommonElfData<'data, E: EndianParse> {
     pub gnu_hash: Option<GnuHashTable<'data, E>>,
 }
 
+/// The values of the dynamic section entries which locate the dynamic linking tables
+#[derive(Debug, Default)]
+struct DynamicAddrs {
+    symtab: Option<u64>,
+    syment: Option<u64>,
+    strtab: Option<u64>,
+    strsz: Option<u64>,
+    hash: Option<u64>,
+    gnu_hash: Option<u64>,
+    pltgot: Option<u64>,
+    gnu_liblist: Option<u64>,
+    gnu_liblistsz: Option<u64>,
+    versym: Option<u64>,
+    verneed: Option<u64>,
+    verneednum: Option<u64>,
+    verdef: Option<u64>,
+    verdefnum: Option<u64>,
+    rel: Option<u64>,
+    relsz: Option<u64>,
+    rela: Option<u64>,
+    relasz: Option<u64>,
+    jmprel: Option<u64>,
+    pltrelsz: Option<u64>,
+    pltrel: Option<u64>,
+    init: Option<u64>,
+    fini: Option<u64>,
+    preinit_array: Option<u64>,
+    preinit_arraysz: Option<u64>,
+    init_array: Option<u64>,
+    init_arraysz: Option<u64>,
+    fini_array: Option<u64>,
+    fini_arraysz: Option<u64>,
+}
+
 impl<'data, E: EndianParse> ElfBytes<'data, E> {
     /// Do the minimal parsing work to get an [ElfBytes] handle from a byte slice containing an ELF object.
     ///
     /// This parses the ELF [FileHeader], and locates (but does not parse) the
-    /// Section Header Table and Segment Table.
+    /// Section Header Table and Segment Table. With the `alloc` feature, the section headers
+    /// are also parsed and their names indexed up front, so that the lookups by name don't
+    /// each walk the table.
     ///
     // N.B. I thought about calling this "sparse_parse", but it felt too silly for a serious lib like this
     pub fn minimal_parse(data: &'data [u8]) -> Result<Self, ParseError> {
-        let ident_buf = data.get_bytes(0..abi::EI_NIDENT)?;
-        let ident = parse_ident(ident_buf)?;
+        Self::minimal_parse_with_options(data, ParseOptions::default())
+    }
 
-        let tail_start = abi::EI_NIDENT;
-        let tail_end = match ident.1 {
-            Class::ELF32 => tail_start + crate::file::ELF32_EHDR_TAILSIZE,
-            Class::ELF64 => tail_start + crate::file::ELF64_EHDR_TAILSIZE,
+    /// Like [ElfBytes::minimal_parse], but with the given [ParseOptions].
+    ///
+    /// In permissive mode, a section header or program header table that runs off the end of
+    /// the file is truncated to the entries that fit (or ignored if none do) rather than
+    /// being an error. Likewise, an `EI_VERSION` or `e_version` other than [abi::EV_CURRENT]
+    /// isn't an error, as the kernel's loader doesn't check them either. If `e_shstrndx`
+    /// doesn't refer to a usable string table, then the sections are left without names
+    /// rather than [ParseError::BadShstrndx] being returned by the by-name lookups. A section
+    /// whose `sh_link` is out of range links to an empty section, and a symbol, dynamic or
+    /// symbol versioning table with an `sh_entsize` of 0 is read with the natural entry size.
+    /// The problems that were worked around, along with any overlapping sections, are
+    /// available from [ElfBytes::warnings] with the `alloc` feature.
+    ///
+    /// Example usage:
+    /// ```
+    /// use elf::endian::AnyEndian;
+    /// use elf::parse::ParseOptions;
+    /// use elf::ElfBytes;
+    ///
+    /// let path = std::path::PathBuf::from("sample-objects/basic.x86_64");
+    /// let file_data = std::fs::read(path).unwrap();
+    ///
+    /// // Chop the section header table off the end of the file
+    /// let truncated = &file_data[..file_data.len() - 100];
+    /// assert!(ElfBytes::<AnyEndian>::minimal_parse(truncated).is_err());
+    ///
+    /// let file = ElfBytes::<AnyEndian>::minimal_parse_with_options(
+    ///     truncated,
+    ///     ParseOptions::permissive(),
+    /// )
+    /// .unwrap();
+    /// assert!(file.segments().is_some());
+    /// #[cfg(feature = "alloc")]
+    /// for warning in file.warnings() {
+    ///     println!("warning: {warning}");
+    /// }
+    /// ```
+    pub fn minimal_parse_with_options(
+        data: &'data [u8],
+        options: ParseOptions,
+    ) -> Result<Self, ParseError> {
+        let ehdr = parse_ehdr(data, &options)?;
+
+        #[cfg(feature = "alloc")]
+        let mut warnings = version_warnings(&ehdr);
+
+        let what = "section header table";
+        let shdrs = match find_shdrs(&ehdr, data, &options).context(what, None, ehdr.e_shoff) {
+            Ok(shdrs) => shdrs,
+            Err(err) if !options.strict && !is_limit_exceeded(&err) => {
+                let declared = match ehdr.e_shnum {
+                    0 => None,
+                    shnum => Some(shnum as usize),
+                };
+                let (shdrs, _warning) = salvage_table(
+                    &ehdr,
+                    data,
+                    what,
+                    ehdr.e_shoff,
+                    ehdr.e_shentsize,
+                    declared,
+                    err,
+                );
+                #[cfg(feature = "alloc")]
+                warnings.push(_warning);
+                shdrs
+            }
+            Err(err) => return Err(err),
         };
-        let tail_buf = data.get_bytes(tail_start..tail_end)?;
 
-        let ehdr = FileHeader::parse_tail(ident, tail_buf)?;
+        let what = "program header table";
+        let phdrs = match find_phdrs(&ehdr, data, &options).context(what, None, ehdr.e_phoff) {
+            Ok(phdrs) => phdrs,
+            Err(err) if !options.strict && !is_limit_exceeded(&err) => {
+                let declared = match ehdr.e_phnum {
+                    abi::PN_XNUM => None,
+                    phnum => Some(phnum as usize),
+                };
+                let (phdrs, _warning) = salvage_table(
+                    &ehdr,
+                    data,
+                    what,
+                    ehdr.e_phoff,
+                    ehdr.e_phentsize,
+                    declared,
+                    err,
+                );
+                #[cfg(feature = "alloc")]
+                warnings.push(_warning);
+                phdrs
+            }
+            Err(err) => return Err(err),
+        };
 
-        let shdrs = find_shdrs(&ehdr, data)?;
-        let phdrs = find_phdrs(&ehdr, data)?;
-        Ok(ElfBytes {
+        let file = ElfBytes {
             ehdr,
             data,
             shdrs,
             phdrs,
+            load_bias: None,
+            options,
+            #[cfg(feature = "alloc")]
+            warnings,
+            #[cfg(feature = "alloc")]
+            shdr_cache: None,
+        };
+
+        // In permissive mode, a bad section header string table leaves the sections nameless,
+        // and the section header problems that the lookups work around are reported
+        #[cfg(feature = "alloc")]
+        let file = {
+            let mut file = file;
+            file.shdr_cache = file.build_section_header_cache();
+            if let (false, Some(shdrs)) = (options.strict, file.shdrs) {
+                if let Err(err) = file.shstrtab(&shdrs) {
+                    file.warnings.push(ParseWarning::IgnoredSectionNames(err));
+                }
+                let warnings = section_warnings(shdrs.len(), file.iter_section_headers());
+                file.warnings.extend(warnings);
+            }
+            file
+        };
+        Ok(file)
+    }
+
+    /// Get an [ElfBytes] handle for an image that was loaded into memory, such as one read
+    /// out of `/proc/<pid>/mem` or a core dump.
+    ///
+    /// The `data` holds the image's memory starting from its ELF file header, which is mapped
+    /// at `load_bias` plus the link-time address of the first `PT_LOAD` segment's file offset 0.
+    /// Virtual addresses are resolved to positions in the image through the `PT_LOAD`
+    /// segments, rather than to file offsets.
+    ///
+    /// Section headers usually aren't loaded, so they are not used at all. The dynamic symbol
+    /// table, hash tables, symbol versioning tables and dynamic relocations are found through
+    /// the [abi::PT_DYNAMIC] entries instead. The dynamic linker relocates the addresses in
+    /// those entries by the load bias on some architectures but not others, so each is
+    /// accepted either way.
+    ///
+    /// Example usage:
+    /// ```
+    /// use elf::endian::AnyEndian;
+    /// use elf::ElfBytes;
+    ///
+    /// let path = std::path::PathBuf::from("sample-objects/patch.x86_64.so");
+    /// let file_data = std::fs::read(path).expect("Could not read file.");
+    /// let file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("should parse");
+    ///
+    /// // Lay the segments out in memory the way the dynamic linker would
+    /// let mut memory = vec![0u8; 0x2008];
+    /// for phdr in file.segments().expect("should have segments").iter() {
+    ///     if phdr.p_type == elf::abi::PT_LOAD {
+    ///         let data = file.segment_data(&phdr).expect("should read");
+    ///         let start = phdr.p_vaddr as usize;
+    ///         memory[start..start + data.len()].copy_from_slice(data);
+    ///     }
+    /// }
+    ///
+    /// let image = ElfBytes::<AnyEndian>::parse_loaded(&memory, 0x7f12_3456_0000)
+    ///     .expect("should parse");
+    /// assert!(image.section_headers().is_none());
+    /// let (_, index, _) = image
+    ///     .symbol_by_name(b"hello")
+    ///     .expect("should parse")
+    ///     .expect("should find hello");
+    /// assert_eq!(index, 2);
+    /// ```
+    pub fn parse_loaded(data: &'data [u8], load_bias: u64) -> Result<Self, ParseError> {
+        let options = ParseOptions::default();
+        let ehdr = parse_ehdr(data, &options)?;
+        let phdrs = find_phdrs(&ehdr, data, &options).context(
+            "program header table",
+            None,
+            ehdr.e_phoff,
+        )?;
+        Ok(ElfBytes {
+            ehdr,
+            data,
+            shdrs: None,
+            phdrs,
+            load_bias: Some(load_bias),
+            options,
+            #[cfg(feature = "alloc")]
+            warnings: Vec::new(),
+            #[cfg(feature = "alloc")]
+            shdr_cache: None,
         })
     }
 
+    /// Get an [ElfBytes] handle for a vDSO image, like the one that Linux maps into each
+    /// process at the address in its `AT_SYSINFO_EHDR` auxiliary vector entry.
+    ///
+    /// The vDSO is a complete shared object whose file offsets equal its virtual addresses,
+    /// but it isn't backed by a file and may not have section headers, so it's parsed as a
+    /// loaded image (see [ElfBytes::parse_loaded]). Its symbols are versioned (e.g.
+    /// `LINUX_2.6` on x86_64), and their `st_value`s are relative to the start of the image.
+    ///
+    /// Example usage:
+    /// ```
+    /// use elf::endian::AnyEndian;
+    /// use elf::ElfBytes;
+    ///
+    /// let path = std::path::PathBuf::from("sample-objects/vdso.x86_64");
+    /// let vdso_data = std::fs::read(path).expect("Could not read file.");
+    ///
+    /// let vdso = ElfBytes::<AnyEndian>::parse_vdso(&vdso_data).expect("should parse");
+    /// let (_, _, symbol) = vdso
+    ///     .symbol_by_name(b"__vdso_clock_gettime")
+    ///     .expect("should parse")
+    ///     .expect("should find __vdso_clock_gettime");
+    /// // The function lives at the vDSO's base address plus its st_value
+    /// assert!((symbol.st_value as usize) < vdso_data.len());
+    /// ```
+    pub fn parse_vdso(data: &'data [u8]) -> Result<Self, ParseError> {
+        Self::parse_loaded(data, 0)
+    }
+
+    /// Get the load bias that was given to [ElfBytes::parse_loaded], or an empty Option if
+    /// this object was parsed from a file.
+    pub fn load_bias(&self) -> Option<u64> {
+        self.load_bias
+    }
+
+    /// Get the lowest `p_vaddr` of the object's [abi::PT_LOAD] segments, i.e. the address
+    /// that it was linked to be loaded at. This is 0 for most shared objects and PIEs, and
+    /// something like 0x400000 for fixed-address executables.
+    ///
+    /// The load bias of a running image is the address that its lowest segment was mapped
+    /// at minus this, and adding that bias to a link-time address gives its loaded address.
+    /// Returns an empty Option if there are no [abi::PT_LOAD] segments.
+    pub fn ehdr_load_bias(&self) -> Option<u64> {
+        self.segments_by_type(abi::PT_LOAD)
+            .map(|phdr| phdr.p_vaddr)
+            .min()
+    }
+
+    /// Check the object's cross-field structural invariants which the parser itself doesn't
+    /// enforce, such as header sizes, overlapping section data, `sh_link`/`sh_info` targets,
+    /// symbol section indexes and names, segment alignment and the [abi::PT_PHDR] segment.
+    ///
+    /// See the [validate](crate::validate) module for details.
+    #[cfg(feature = "alloc")]
+    pub fn validate(&self) -> Vec<crate::validate::ValidationIssue> {
+        crate::validate::validate(self, self.data.len())
+    }
+
+    /// Get the recoverable problems that were worked around when parsing this object in
+    /// permissive mode (see [ElfBytes::minimal_parse_with_options]).
+    #[cfg(feature = "alloc")]
+    pub fn warnings(&self) -> &[ParseWarning] {
+        &self.warnings
+    }
+
     /// Get this Elf object's zero-alloc lazy-parsing [SegmentTable] (if any).
     ///
     /// This table parses [ProgramHeader]s on demand and does not make any internal heap allocations
@@ -211,10 +785,76 @@ impl<'data, E: EndianParse> ElfBytes<'data, E> {
         self.shdrs
     }
 
+    /// Parse the reserved [SectionHeader] at index 0 (if there are section headers).
+    ///
+    /// This header doesn't describe a section, and is all zeros except when it holds the
+    /// values that don't fit in the file header: its `sh_size` is the number of section
+    /// headers when `e_shnum` is 0, its `sh_link` is the section name string table's index
+    /// when `e_shstrndx` is [abi::SHN_XINDEX], and its `sh_info` is the number of program
+    /// headers when `e_phnum` is [abi::PN_XNUM]. [ElfBytes::validate] checks that its other
+    /// fields are zero.
+    ///
+    /// It's included when iterating over the [SectionHeaderTable] so that section indexes
+    /// line up, but the by-name lookups like [ElfBytes::section_header_by_name] skip it.
+    pub fn shdr0(&self) -> Result<Option<SectionHeader>, ParseError> {
+        match self.shdrs {
+            Some(shdrs) if !shdrs.is_empty() => Ok(Some(self.section_header_at(&shdrs, 0)?)),
+            _ => Ok(None),
+        }
+    }
+
+    /// Parse the [SectionHeader] at `index`, along with the range of file bytes it was parsed
+    /// from, for tools which patch headers in place.
+    ///
+    /// The range spans `e_shentsize` bytes, including any padding after the header's fields.
+    /// Returns a [ParseError::BadOffset] with the `index` if there's no such header.
+    pub fn section_header_with_range(
+        &self,
+        index: usize,
+    ) -> Result<(SectionHeader, core::ops::Range<usize>), ParseError> {
+        let shdrs = self
+            .section_headers()
+            .ok_or(ParseError::BadOffset(index as u64))?;
+        let file_offset = (index as u64)
+            .wrapping_mul(self.ehdr.e_shentsize as u64)
+            .wrapping_add(self.ehdr.e_shoff);
+        let (shdr, range) =
+            shdrs
+                .get_with_range(index)
+                .context("section header", Some(index), file_offset)?;
+        // find_shdrs already checked that the table's offset fits in a usize
+        Ok((shdr, offset_range(self.ehdr.e_shoff as usize, range)))
+    }
+
+    /// Parse the [ProgramHeader] at `index`, along with the range of file bytes it was parsed
+    /// from, for tools which patch headers in place.
+    ///
+    /// The range spans `e_phentsize` bytes, including any padding after the header's fields.
+    /// Returns a [ParseError::BadOffset] with the `index` if there's no such header.
+    pub fn segment_with_range(
+        &self,
+        index: usize,
+    ) -> Result<(ProgramHeader, core::ops::Range<usize>), ParseError> {
+        let phdrs = self.segments().ok_or(ParseError::BadOffset(index as u64))?;
+        let file_offset = (index as u64)
+            .wrapping_mul(self.ehdr.e_phentsize as u64)
+            .wrapping_add(self.ehdr.e_phoff);
+        let (phdr, range) =
+            phdrs
+                .get_with_range(index)
+                .context("program header", Some(index), file_offset)?;
+        // find_phdrs already checked that the table's offset fits in a usize
+        Ok((phdr, offset_range(self.ehdr.e_phoff as usize, range)))
+    }
+
     /// Get this ELF object's [SectionHeaderTable] alongside its corresponding [StringTable].
     ///
     /// This is useful if you want to know the string name of sections.
     ///
+    /// Returns a [ParseError::BadShstrndx] if `e_shstrndx` is out of range or refers to a
+    /// section that isn't a [abi::SHT_STRTAB], like a [abi::SHT_NOBITS] one. In permissive
+    /// mode, the [StringTable] is left out instead (see [ElfBytes::minimal_parse_with_options]).
+    ///
     /// Example usage:
     /// ```
     /// use std::collections::HashMap;
@@ -277,9 +917,44 @@ impl<'data, E: EndianParse> ElfBytes<'data, E> {
             }
         };
 
+        #[cfg(feature = "alloc")]
+        if let Some(cache) = self.section_header_cache() {
+            if !cache.bad_shstrndx {
+                let strtab = cache.strtab.clone();
+                return Ok((
+                    Some(shdrs),
+                    strtab.map(|range| StringTable::new(&self.data[range])),
+                ));
+            }
+        }
+
+        match self.shstrtab(&shdrs) {
+            Ok(strtab) => Ok((Some(shdrs), strtab)),
+            // The problem was recorded as a warning when the object was parsed
+            Err(_) if !self.options.strict => Ok((Some(shdrs), None)),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Internal helper to load the section header string table that e_shstrndx refers to,
+    /// if there is one.
+    fn shstrtab(
+        &self,
+        shdrs: &SectionHeaderTable<'data, E>,
+    ) -> Result<Option<StringTable<'data>>, ParseError> {
+        let range = self.shstrtab_range(shdrs)?;
+        Ok(range.map(|range| StringTable::new(&self.data[range])))
+    }
+
+    /// Internal helper for [ElfBytes::shstrtab] which gets the range of the data that holds
+    /// the section header string table.
+    fn shstrtab_range(
+        &self,
+        shdrs: &SectionHeaderTable<'data, E>,
+    ) -> Result<Option<core::ops::Range<usize>>, ParseError> {
         // It's Ok to not have a string table
         if self.ehdr.e_shstrndx == abi::SHN_UNDEF {
-            return Ok((Some(shdrs), None));
+            return Ok(None);
         }
 
         // If the section name string table section index is greater than or
@@ -288,18 +963,310 @@ impl<'data, E: EndianParse> ElfBytes<'data, E> {
         // is contained in the sh_link field of the section header at index 0.
         let mut shstrndx = self.ehdr.e_shstrndx as usize;
         if self.ehdr.e_shstrndx == abi::SHN_XINDEX {
-            let shdr_0 = shdrs.get(0)?;
+            let shdr_0 = self.section_header_at(shdrs, 0)?;
             shstrndx = shdr_0.sh_link as usize;
         }
 
-        let strtab = shdrs.get(shstrndx)?;
-        let (strtab_start, strtab_end) = strtab.get_data_range()?;
-        let strtab_buf = self.data.get_bytes(strtab_start..strtab_end)?;
-        Ok((Some(shdrs), Some(StringTable::new(strtab_buf))))
+        // Out of range indexes and ones pointing at other kinds of sections (including
+        // SHT_NOBITS ones, which have no data) are a bad e_shstrndx rather than some bad
+        // offset found deep inside the string table
+        let strtab = match shdrs.get(shstrndx) {
+            Ok(shdr) if shdr.sh_type == abi::SHT_STRTAB => shdr,
+            _ => return Err(ParseError::BadShstrndx(shstrndx as u32)),
+        };
+        let strtab_buf = self.section_bytes(&strtab).context(
+            "section header string table",
+            Some(shstrndx),
+            strtab.sh_offset,
+        )?;
+        // The section data was in bounds, so its offset fits
+        let start = strtab.sh_offset as usize;
+        Ok(Some(start..start + strtab_buf.len()))
+    }
+
+    /// Internal helper to get the position in the data of the link-time virtual address
+    /// `vaddr`, along with the end of the `PT_LOAD` segment that maps it.
+    ///
+    /// For images parsed from memory, this is where it's mapped in the image. Otherwise, it's
+    /// the file offset of the segment data that it's loaded from.
+    fn vaddr_range(&self, vaddr: u64) -> Option<(usize, usize)> {
+        let phdrs = self.phdrs?;
+        let loaded = self.load_bias.is_some();
+        let phdr = phdrs.iter().find(|phdr| {
+            let size = if loaded { phdr.p_memsz } else { phdr.p_filesz };
+            phdr.p_type == abi::PT_LOAD && vaddr >= phdr.p_vaddr && vaddr - phdr.p_vaddr < size
+        })?;
+        let (start, end) = if loaded {
+            let base = self.image_base()?;
+            (
+                vaddr.checked_sub(base)?,
+                phdr.p_vaddr.checked_add(phdr.p_memsz)?.checked_sub(base)?,
+            )
+        } else {
+            (
+                phdr.p_offset.checked_add(vaddr - phdr.p_vaddr)?,
+                phdr.p_offset.checked_add(phdr.p_filesz)?,
+            )
+        };
+        Some((start.try_into().ok()?, end.try_into().ok()?))
+    }
+
+    /// Internal helper to get the link-time virtual address that the start of an image
+    /// parsed from memory corresponds to, which is where its first `PT_LOAD` maps the file
+    /// header.
+    fn image_base(&self) -> Option<u64> {
+        let phdr = self
+            .phdrs?
+            .iter()
+            .filter(|phdr| phdr.p_type == abi::PT_LOAD)
+            .min_by_key(|phdr| phdr.p_vaddr)?;
+        phdr.p_vaddr.checked_sub(phdr.p_offset)
+    }
+
+    /// Internal helper to check whether the dynamic linking tables have to be found through
+    /// the addresses in the dynamic section, which is the case for images parsed from memory
+    /// and for files without section headers, like those run through `sstrip`.
+    fn uses_dynamic_addrs(&self) -> bool {
+        self.load_bias.is_some() || self.shdrs.map_or(true, |shdrs| shdrs.is_empty())
+    }
+
+    /// Internal helper to undo the load bias on an address from the dynamic section, if it
+    /// was relocated in an image parsed from memory.
+    fn unbiased_addr(&self, addr: u64) -> u64 {
+        match self.load_bias {
+            Some(bias) if bias != 0 => match addr.checked_sub(bias) {
+                Some(vaddr) if self.vaddr_range(vaddr).is_some() => vaddr,
+                _ => addr,
+            },
+            _ => addr,
+        }
+    }
+
+    /// Internal helper to get the data for a table at an address from the dynamic section,
+    /// either `size` bytes long or up to the end of its `PT_LOAD` segment.
+    ///
+    /// The address may have been relocated by the load bias in an image parsed from memory.
+    fn dynamic_addr_data(&self, addr: u64, size: Option<u64>) -> Result<&'data [u8], ParseError> {
+        let (start, segment_end) = match self.vaddr_range(self.unbiased_addr(addr)) {
+            Some(range) => range,
+            None => return Err(ParseError::BadOffset(addr)),
+        };
+        let end = match size {
+            Some(size) => {
+                self.options.check_table_size(size, self.data.len())?;
+                start
+                    .checked_add(size.try_into()?)
+                    .ok_or(ParseError::IntegerOverflow)?
+            }
+            // A truncated dump may not have the whole segment
+            None => segment_end.min(self.data.len()),
+        };
+        self.data.get_bytes(start..end)
+    }
+
+    /// Internal helper to collect the values of the [abi::PT_DYNAMIC] entries which locate
+    /// the dynamic linking tables. Returns an empty Option if there's no dynamic section.
+    fn dynamic_addrs(&self) -> Result<Option<DynamicAddrs>, ParseError> {
+        let dynamic = match self.dynamic()? {
+            Some(dynamic) => dynamic,
+            None => return Ok(None),
+        };
+        let mut addrs = DynamicAddrs::default();
+        for entry in dynamic.iter() {
+            let value = Some(entry.d_val());
+            match entry.d_tag {
+                abi::DT_NULL => break,
+                abi::DT_SYMTAB => addrs.symtab = value,
+                abi::DT_SYMENT => addrs.syment = value,
+                abi::DT_STRTAB => addrs.strtab = value,
+                abi::DT_STRSZ => addrs.strsz = value,
+                abi::DT_HASH => addrs.hash = value,
+                abi::DT_GNU_HASH => addrs.gnu_hash = value,
+                abi::DT_PLTGOT => addrs.pltgot = value,
+                abi::DT_GNU_LIBLIST => addrs.gnu_liblist = value,
+                abi::DT_GNU_LIBLISTSZ => addrs.gnu_liblistsz = value,
+                abi::DT_VERSYM => addrs.versym = value,
+                abi::DT_VERNEED => addrs.verneed = value,
+                abi::DT_VERNEEDNUM => addrs.verneednum = value,
+                abi::DT_VERDEF => addrs.verdef = value,
+                abi::DT_VERDEFNUM => addrs.verdefnum = value,
+                abi::DT_REL => addrs.rel = value,
+                abi::DT_RELSZ => addrs.relsz = value,
+                abi::DT_RELA => addrs.rela = value,
+                abi::DT_RELASZ => addrs.relasz = value,
+                abi::DT_JMPREL => addrs.jmprel = value,
+                abi::DT_PLTRELSZ => addrs.pltrelsz = value,
+                abi::DT_PLTREL => addrs.pltrel = value,
+                abi::DT_INIT => addrs.init = value,
+                abi::DT_FINI => addrs.fini = value,
+                abi::DT_PREINIT_ARRAY => addrs.preinit_array = value,
+                abi::DT_PREINIT_ARRAYSZ => addrs.preinit_arraysz = value,
+                abi::DT_INIT_ARRAY => addrs.init_array = value,
+                abi::DT_INIT_ARRAYSZ => addrs.init_arraysz = value,
+                abi::DT_FINI_ARRAY => addrs.fini_array = value,
+                abi::DT_FINI_ARRAYSZ => addrs.fini_arraysz = value,
+                _ => (),
+            }
+        }
+        Ok(Some(addrs))
+    }
+
+    /// Internal helper to check that `count` entries of type `P`, as declared by the file,
+    /// would fit within [ParseOptions::max_table_bytes].
+    fn check_entry_count<P: ParseAt>(&self, count: u64) -> Result<(), ParseError> {
+        let size = count.saturating_mul(P::size_for(self.ehdr.class) as u64);
+        self.options.check_table_size(size, self.data.len())
+    }
+
+    /// Internal helper to check the size of a hash table's buckets and chains, which are
+    /// what its symbol counts are derived from, against [ParseOptions::max_table_bytes].
+    fn checked_sysv_hash(
+        &self,
+        table: SysVHashTable<'data, E>,
+    ) -> Result<SysVHashTable<'data, E>, ParseError> {
+        let size = (table.nbucket() as u64 + table.nchain() as u64) * table.word_size() as u64;
+        self.options.check_table_size(size, self.data.len())?;
+        Ok(table)
+    }
+
+    /// Internal helper to fill in the dynamic symbol table, its strings, and the hash tables
+    /// from the addresses in the dynamic section, for images without section headers.
+    ///
+    /// The dynamic section doesn't say how many symbols there are, so this relies on the
+    /// hash tables to count them. If they can't, the symbol table is assumed to end where the
+    /// string table starts, which is where linkers put it.
+    fn find_dynamic_common_data(
+        &self,
+        result: &mut CommonElfData<'data, E>,
+    ) -> Result<(), ParseError> {
+        let addrs = match self.dynamic_addrs()? {
+            Some(addrs) => addrs,
+            None => return Ok(()),
+        };
+        let (endian, class) = (self.ehdr.endianness, self.ehdr.class);
+
+        if let (Some(addr), Some(size)) = (addrs.strtab, addrs.strsz) {
+            let buf = self.dynamic_addr_data(addr, Some(size)).context(
+                "dynamic string table",
+                None,
+                addr,
+            )?;
+            result.dynsyms_strs = Some(StringTable::new(buf));
+        }
+        if let Some(addr) = addrs.hash {
+            let buf = self
+                .dynamic_addr_data(addr, None)
+                .context("hash table", None, addr)?;
+            result.sysv_hash = Some(self.checked_sysv_hash(SysVHashTable::new_for_machine(
+                endian,
+                class,
+                self.ehdr.e_machine,
+                buf,
+            )?)?);
+        }
+        if let Some(addr) = addrs.gnu_hash {
+            let buf = self
+                .dynamic_addr_data(addr, None)
+                .context("GNU hash table", None, addr)?;
+            result.gnu_hash = Some(GnuHashTable::new(endian, class, buf)?);
+        }
+
+        let count = match (&result.sysv_hash, &result.gnu_hash) {
+            (Some(sysv_hash), _) => Some(sysv_hash.nchain()),
+            (None, Some(gnu_hash)) => gnu_hash.symbol_count()?,
+            (None, None) => None,
+        };
+        if let Some(addr) = addrs.symtab {
+            let entsize = match addrs.syment {
+                Some(syment) => Symbol::validate_entsize(class, syment.try_into()?)? as u64,
+                None => Symbol::size_for(class) as u64,
+            };
+            let size = match (count, addrs.strtab) {
+                (Some(count), _) => (count as u64)
+                    .checked_mul(entsize)
+                    .ok_or(ParseError::IntegerOverflow)?,
+                (None, Some(strtab)) if strtab > addr => (strtab - addr) / entsize * entsize,
+                (None, _) => return Ok(()),
+            };
+            let buf = self.dynamic_addr_data(addr, Some(size)).context(
+                "dynamic symbol table",
+                None,
+                addr,
+            )?;
+            result.dynsyms = Some(SymbolTable::with_entsize(
+                endian,
+                class,
+                entsize as usize,
+                buf,
+            ));
+        }
+        Ok(())
+    }
+
+    /// Internal helper to parse the [SectionHeader] at `index`, with the error context of
+    /// where the header lives in the file.
+    fn section_header_at(
+        &self,
+        shdrs: &SectionHeaderTable<'data, E>,
+        index: usize,
+    ) -> Result<SectionHeader, ParseError> {
+        // The cache is built with this, so it may not be filled in yet
+        #[cfg(feature = "alloc")]
+        if let Some(shdr) = self
+            .shdr_cache
+            .as_ref()
+            .and_then(|cache| cache.shdrs.get(index))
+        {
+            return Ok(*shdr);
+        }
+
+        let file_offset = (index as u64)
+            .wrapping_mul(self.ehdr.e_shentsize as u64)
+            .wrapping_add(self.ehdr.e_shoff);
+        shdrs
+            .get(index)
+            .context("section header", Some(index), file_offset)
+    }
+
+    /// Internal helper to parse the [SectionHeader] that `shdr`'s `sh_link` points at.
+    ///
+    /// In permissive mode, an out of range link gives an all-zero header instead, so that the
+    /// linked table reads as empty (see [ParseWarning::BadLink]).
+    fn linked_section_header(
+        &self,
+        shdrs: &SectionHeaderTable<'data, E>,
+        shdr: &SectionHeader,
+    ) -> Result<SectionHeader, ParseError> {
+        if !self.options.strict && shdr.sh_link as usize >= shdrs.len() {
+            return Ok(SectionHeader::default());
+        }
+        self.section_header_at(shdrs, shdr.sh_link as usize)
+    }
+
+    /// Internal helper to get the entry size of the `P` table in the given section, checking
+    /// that it's big enough to hold a `P`.
+    ///
+    /// In permissive mode, an `sh_entsize` of 0 is taken to be the size of a `P` (see
+    /// [ParseWarning::ZeroEntsize]).
+    fn table_entsize<P: ParseAt>(&self, shdr: &SectionHeader) -> Result<usize, ParseError> {
+        if !self.options.strict && shdr.sh_entsize == 0 {
+            return Ok(P::size_for(self.ehdr.class));
+        }
+        P::validate_entsize(self.ehdr.class, shdr.sh_entsize.try_into()?)
     }
 
     /// Parse section headers until one is found with the given name
     ///
+    /// If several sections have the name, then this returns the first one in the section
+    /// header table. Use [ElfBytes::section_headers_by_name] to get all of them. The reserved
+    /// header at index 0 is never found, see [ElfBytes::shdr0].
+    ///
+    /// With the `alloc` feature, the first lookup parses the whole section header table and
+    /// indexes the section names, and later ones are answered from that cache without
+    /// re-walking the table. The other section header lookups, like [ElfBytes::symbol_table]
+    /// and [ElfBytes::dynamic], share the cache. Otherwise, each call walks the section header
+    /// table.
+    ///
     /// Example to get the ELF file's ABI-tag note
     /// ```
     /// use elf::ElfBytes;
@@ -332,6 +1299,30 @@ impl<'data, E: EndianParse> ElfBytes<'data, E> {
     ///     }));
     /// ```
     pub fn section_header_by_name(&self, name: &str) -> Result<Option<SectionHeader>, ParseError> {
+        #[cfg(feature = "alloc")]
+        {
+            let (cache, strtab) = match self.section_headers_with_strtab()? {
+                (Some(_), Some(strtab)) => match self.section_header_cache() {
+                    Some(cache) => (cache, strtab),
+                    None => return Ok(None),
+                },
+                _ => return Ok(None),
+            };
+            // Every section in by_name has a valid name
+            let name_of = |index: &usize| {
+                let sh_name = cache.shdrs[*index].sh_name as usize;
+                strtab.get_raw(sh_name).unwrap_or_default()
+            };
+            let name = name.as_bytes();
+            let first = cache.by_name.partition_point(|index| name_of(index) < name);
+            Ok(cache
+                .by_name
+                .get(first)
+                .filter(|index| name_of(index) == name)
+                .map(|index| cache.shdrs[*index]))
+        }
+
+        #[cfg(not(feature = "alloc"))]
         let (shdrs, strtab) = match self.section_headers_with_strtab()? {
             (Some(shdrs), Some(strtab)) => (shdrs, strtab),
             _ => {
@@ -340,7 +1331,9 @@ impl<'data, E: EndianParse> ElfBytes<'data, E> {
             }
         };
 
-        Ok(shdrs.iter().find(|shdr| {
+        // Header 0 isn't a real section
+        #[cfg(not(feature = "alloc"))]
+        Ok(shdrs.iter().skip(1).find(|shdr| {
             let sh_name = match strtab.get(shdr.sh_name as usize) {
                 Ok(name) => name,
                 _ => {
@@ -351,6 +1344,109 @@ impl<'data, E: EndianParse> ElfBytes<'data, E> {
         }))
     }
 
+    /// Get an iterator over all the section headers with the given name, along with their
+    /// indexes, in section header table order.
+    ///
+    /// Objects can legitimately have several sections with the same name, such as multiple
+    /// `.note` sections, or `.text` sections from several groups. The iterator is empty if
+    /// there are no section headers or no section name string table.
+    pub fn section_headers_by_name<'name>(
+        &self,
+        name: &'name str,
+    ) -> Result<SectionHeaderNameIterator<'data, 'name, E>, ParseError> {
+        match self.section_headers_with_strtab()? {
+            (Some(shdrs), Some(strtab)) => Ok(shdrs.iter_named(&strtab, name.as_bytes())),
+            _ => Ok(
+                SectionHeaderTable::new(self.ehdr.endianness, self.ehdr.class, &[])
+                    .iter_named(&StringTable::default(), name.as_bytes()),
+            ),
+        }
+    }
+
+    /// Get the first program header with the given `p_type` (if any). Use
+    /// [ElfBytes::segments_by_type] to get all of them.
+    pub fn segment_by_type(&self, p_type: u32) -> Option<ProgramHeader> {
+        self.segments_by_type(p_type).next()
+    }
+
+    /// Get an iterator over all the program headers with the given `p_type`, in program
+    /// header table order, such as all the [abi::PT_NOTE] or [abi::PT_LOAD] segments.
+    pub fn segments_by_type(&self, p_type: u32) -> impl Iterator<Item = ProgramHeader> + '_ {
+        self.phdrs
+            .iter()
+            .flat_map(|phdrs| phdrs.iter())
+            .filter(move |phdr| phdr.p_type == p_type)
+    }
+
+    /// Get the object's [abi::PT_TLS] segment (if any) along with the layout of the TLS
+    /// block it describes. The template's bytes can be read with [ElfBytes::segment_data]
+    /// on its `phdr`.
+    ///
+    /// An object has at most one PT_TLS segment, which [ElfBytes::validate] checks. If there
+    /// are more, the first one is used.
+    pub fn tls_segment(&self) -> Result<Option<TlsSegment>, ParseError> {
+        self.segment_by_type(abi::PT_TLS)
+            .map(TlsSegment::new)
+            .transpose()
+    }
+
+    /// Get the [abi::PT_PHDR] segment (if any), which tells the loader where the program
+    /// header table itself is in memory. [ElfBytes::validate] checks that it matches
+    /// `e_phoff` and is mapped by a [abi::PT_LOAD] segment.
+    pub fn phdr_segment(&self) -> Option<ProgramHeader> {
+        self.segment_by_type(abi::PT_PHDR)
+    }
+
+    /// Internal helper to get the parsed section headers and the index of their names.
+    /// Returns an empty Option if there are no section headers.
+    #[cfg(feature = "alloc")]
+    fn section_header_cache(&self) -> Option<&SectionHeaderCache> {
+        self.shdr_cache.as_ref()
+    }
+
+    /// Internal helper to parse the section headers and sort their names for
+    /// [ElfBytes::section_header_cache]
+    #[cfg(feature = "alloc")]
+    fn build_section_header_cache(&self) -> Option<SectionHeaderCache> {
+        let shdrs = self.shdrs?;
+        let (strtab, bad_shstrndx) = match self.shstrtab_range(&shdrs) {
+            Ok(strtab) => (strtab, false),
+            Err(_) => (None, true),
+        };
+        let shdrs: Box<[SectionHeader]> = shdrs.iter().collect();
+        let mut by_name = Vec::new();
+        if let Some(strtab) = strtab.clone() {
+            let strtab = StringTable::new(&self.data[strtab]);
+            let name_of = |shdr: &SectionHeader| strtab.get_raw(shdr.sh_name as usize);
+            // Header 0 isn't a real section, and sections with bad names can't be looked up
+            by_name.extend((1..shdrs.len()).filter(|index| name_of(&shdrs[*index]).is_ok()));
+            // The sort is stable, so sections with the same name stay in index order
+            by_name.sort_by(|a, b| {
+                let name = |index: &usize| name_of(&shdrs[*index]).unwrap_or_default();
+                name(a).cmp(name(b))
+            });
+        }
+        Some(SectionHeaderCache {
+            shdrs,
+            strtab,
+            bad_shstrndx,
+            by_name: by_name.into_boxed_slice(),
+        })
+    }
+
+    /// Internal helper to iterate over the section headers in table order, which come from
+    /// the cache with the `alloc` feature.
+    fn iter_section_headers(&self) -> impl Iterator<Item = SectionHeader> + '_ {
+        #[cfg(feature = "alloc")]
+        let shdrs = self
+            .section_header_cache()
+            .into_iter()
+            .flat_map(|cache| cache.shdrs.iter().copied());
+        #[cfg(not(feature = "alloc"))]
+        let shdrs = self.shdrs.into_iter().flat_map(|shdrs| shdrs.iter());
+        shdrs
+    }
+
     /// Efficiently locate the set of common sections found in ELF files by doing a single iteration
     /// over the SectionHeaders table.
     ///
@@ -385,17 +1481,17 @@ impl<'data, E: EndianParse> ElfBytes<'data, E> {
                         result.dynamic = Some(self.section_data_as_dynamic(&shdr)?);
                     }
                     abi::SHT_HASH => {
-                        let (start, end) = shdr.get_data_range()?;
-                        let buf = self.data.get_bytes(start..end)?;
-                        result.sysv_hash = Some(SysVHashTable::new(
+                        let buf = self.section_bytes(&shdr)?;
+                        let table = SysVHashTable::new_for_machine(
                             self.ehdr.endianness,
                             self.ehdr.class,
+                            self.ehdr.e_machine,
                             buf,
-                        )?);
+                        )?;
+                        result.sysv_hash = Some(self.checked_sysv_hash(table)?);
                     }
                     abi::SHT_GNU_HASH => {
-                        let (start, end) = shdr.get_data_range()?;
-                        let buf = self.data.get_bytes(start..end)?;
+                        let buf = self.section_bytes(&shdr)?;
                         result.gnu_hash = Some(GnuHashTable::new(
                             self.ehdr.endianness,
                             self.ehdr.class,
@@ -409,12 +1505,17 @@ impl<'data, E: EndianParse> ElfBytes<'data, E> {
             }
         }
 
+        // Images parsed from memory and files without sections find their tables through the
+        // dynamic section instead
+        if self.uses_dynamic_addrs() {
+            self.find_dynamic_common_data(&mut result)?;
+        }
+
         // If we didn't find SHT_DYNAMIC from the section headers, try the program headers
         if result.dynamic.is_none() {
             if let Some(phdrs) = self.phdrs {
                 if let Some(dyn_phdr) = phdrs.iter().find(|phdr| phdr.p_type == abi::PT_DYNAMIC) {
-                    let (start, end) = dyn_phdr.get_file_data_range()?;
-                    let buf = self.data.get_bytes(start..end)?;
+                    let buf = self.data.get_file_bytes(dyn_phdr.file_range()?)?;
                     result.dynamic = Some(DynamicTable::new(
                         self.ehdr.endianness,
                         self.ehdr.class,
@@ -429,6 +1530,16 @@ impl<'data, E: EndianParse> ElfBytes<'data, E> {
 
     /// Get the section data for a given [SectionHeader], alongside an optional compression context.
     ///
+    /// [abi::SHT_NOBITS] sections (like `.bss`) have no data in the file, so their data is
+    /// empty whatever their `sh_offset` and `sh_size` say. For other sections, the range of
+    /// bytes is checked against the file: a range which overflows is an
+    /// [IntegerOverflow](ParseError::IntegerOverflow), one which starts past the end of the
+    /// file is a [BadOffset](ParseError::BadOffset) holding the `sh_offset`, and one which
+    /// starts in the file but runs past its end is a [SliceReadError](ParseError::SliceReadError)
+    /// holding the offending range.
+    /// The other section data accessors, like [ElfBytes::section_data_as_strtab], read the
+    /// data the same way.
+    ///
     /// This library does not do any decompression for the user, but merely returns the raw compressed
     /// section data if the section is compressed alongside its ELF compression structure describing the
     /// compression algorithm used.
@@ -444,12 +1555,36 @@ impl<'data, E: EndianParse> ElfBytes<'data, E> {
             return Ok((&[], None));
         }
 
-        let (start, end) = shdr.get_data_range()?;
-        let buf = self.data.get_bytes(start..end)?;
+        self.section_data_unchecked(shdr)
+            .context("section data", None, shdr.sh_offset)
+    }
 
-        if shdr.sh_flags & abi::SHF_COMPRESSED as u64 == 0 {
-            Ok((buf, None))
-        } else {
+    /// Internal helper to get the raw bytes of a section's data, which are empty for
+    /// [abi::SHT_NOBITS] sections. All of the section data accessors read through this, so
+    /// that they share the bounds checks described in [ElfBytes::section_data].
+    fn section_bytes(&self, shdr: &SectionHeader) -> Result<&'data [u8], ParseError> {
+        if shdr.sh_type == abi::SHT_NOBITS {
+            return Ok(&[]);
+        }
+        let range = shdr.file_range()?;
+        // An empty section can sit right at the end of the file
+        let len = self.data.len() as u64;
+        if shdr.sh_offset > len || (shdr.sh_offset == len && shdr.sh_size > 0) {
+            return Err(ParseError::BadOffset(shdr.sh_offset));
+        }
+        self.data.get_file_bytes(range)
+    }
+
+    /// Internal helper for [ElfBytes::section_data] without the error context.
+    fn section_data_unchecked(
+        &self,
+        shdr: &SectionHeader,
+    ) -> Result<(&'data [u8], Option<CompressionHeader>), ParseError> {
+        let buf = self.section_bytes(shdr)?;
+
+        if shdr.sh_flags & abi::SHF_COMPRESSED as u64 == 0 {
+            Ok((buf, None))
+        } else {
             let mut offset = 0;
             let chdr = CompressionHeader::parse_at(
                 self.ehdr.endianness,
@@ -499,7 +1634,12 @@ impl<'data, E: EndianParse> ElfBytes<'data, E> {
         }
 
         let (buf, _) = self.section_data(shdr)?;
-        Ok(RelIterator::new(self.ehdr.endianness, self.ehdr.class, buf))
+        Ok(RelIterator::with_entsize(
+            self.ehdr.endianness,
+            self.ehdr.class,
+            shdr.sh_entsize.try_into()?,
+            buf,
+        ))
     }
 
     /// Get the section data for a given [SectionHeader], and interpret it as an
@@ -518,9 +1658,10 @@ impl<'data, E: EndianParse> ElfBytes<'data, E> {
         }
 
         let (buf, _) = self.section_data(shdr)?;
-        Ok(RelaIterator::new(
+        Ok(RelaIterator::with_entsize(
             self.ehdr.endianness,
             self.ehdr.class,
+            shdr.sh_entsize.try_into()?,
             buf,
         ))
     }
@@ -549,6 +1690,797 @@ impl<'data, E: EndianParse> ElfBytes<'data, E> {
         ))
     }
 
+    /// Find the first section of the given processor-specific type, if the object is for the
+    /// given machine.
+    fn machine_section_header(&self, e_machine: u16, sh_type: u32) -> Option<SectionHeader> {
+        if self.ehdr.e_machine != e_machine {
+            return None;
+        }
+        self.section_headers()?
+            .iter()
+            .find(|shdr| shdr.sh_type == sh_type)
+    }
+
+    /// Get the library list that the prelink tool recorded in the [abi::SHT_GNU_LIBLIST]
+    /// section, with names from the section's linked string table. See the
+    /// [liblist](crate::liblist) module for details.
+    ///
+    /// Without section headers, the list is found through the `DT_GNU_LIBLIST` and
+    /// `DT_GNU_LIBLISTSZ` dynamic entries instead, with names from the dynamic string table.
+    /// Returns an empty Option if the object hasn't been prelinked.
+    pub fn gnu_liblist(&self) -> Result<Option<LibListIterator<'data, E>>, ParseError> {
+        let (endian, class) = (self.ehdr.endianness, self.ehdr.class);
+        if let Some(shdrs) = self.section_headers() {
+            let shdr = match shdrs
+                .iter()
+                .find(|shdr| shdr.sh_type == abi::SHT_GNU_LIBLIST)
+            {
+                Some(shdr) => shdr,
+                None => return Ok(None),
+            };
+            let strtab_shdr = self.linked_section_header(&shdrs, &shdr)?;
+            let strtab = self.section_data_as_strtab(&strtab_shdr)?;
+            let (buf, _) = self.section_data(&shdr)?;
+            return Ok(Some(LibListIterator::new(endian, class, buf, strtab)));
+        }
+
+        let addrs = match self.dynamic_addrs()? {
+            Some(addrs) => addrs,
+            None => return Ok(None),
+        };
+        match (addrs.gnu_liblist, addrs.gnu_liblistsz, addrs.strtab) {
+            (Some(addr), Some(size), Some(strtab)) => {
+                let buf =
+                    self.dynamic_addr_data(addr, Some(size))
+                        .context("library list", None, addr)?;
+                let strtab = self.dynamic_addr_data(strtab, addrs.strsz).context(
+                    "dynamic string table",
+                    None,
+                    strtab,
+                )?;
+                Ok(Some(LibListIterator::new(
+                    endian,
+                    class,
+                    buf,
+                    StringTable::new(strtab),
+                )))
+            }
+            _ => Ok(None),
+        }
+    }
+
+    /// Get the build attributes from the `SHT_ARM_ATTRIBUTES` section of an ARM object.
+    /// Returns an empty Option if the object isn't for ARM or has no such section.
+    pub fn arm_attributes(&self) -> Result<Option<ArmAttributes<'data, E>>, ParseError> {
+        let shdr = match self.machine_section_header(abi::EM_ARM, abi::SHT_ARM_ATTRIBUTES) {
+            Some(shdr) => shdr,
+            None => return Ok(None),
+        };
+        let (buf, _) = self.section_data(&shdr)?;
+        ArmAttributes::new(self.ehdr.endianness, buf)
+            .context("section data", None, shdr.sh_offset)
+            .map(Some)
+    }
+
+    /// Get the build attributes from the `SHT_RISCV_ATTRIBUTES` section of a RISC-V object.
+    /// Returns an empty Option if the object isn't for RISC-V or has no such section.
+    pub fn riscv_attributes(&self) -> Result<Option<RiscVAttributes<'data, E>>, ParseError> {
+        let shdr = match self.machine_section_header(abi::EM_RISCV, abi::SHT_RISCV_ATTRIBUTES) {
+            Some(shdr) => shdr,
+            None => return Ok(None),
+        };
+        let (buf, _) = self.section_data(&shdr)?;
+        RiscVAttributes::new(self.ehdr.endianness, buf)
+            .context("section data", None, shdr.sh_offset)
+            .map(Some)
+    }
+
+    /// Get the build attributes from the `SHT_CSKY_ATTRIBUTES` section of a C-SKY object.
+    /// Returns an empty Option if the object isn't for C-SKY or has no such section.
+    pub fn csky_attributes(&self) -> Result<Option<CskyAttributes<'data, E>>, ParseError> {
+        let shdr = match self.machine_section_header(abi::EM_CSKY, abi::SHT_CSKY_ATTRIBUTES) {
+            Some(shdr) => shdr,
+            None => return Ok(None),
+        };
+        let (buf, _) = self.section_data(&shdr)?;
+        CskyAttributes::new(self.ehdr.endianness, buf)
+            .context("section data", None, shdr.sh_offset)
+            .map(Some)
+    }
+
+    /// Get the exception index table of an ARM object from its `SHT_ARM_EXIDX` section, or from
+    /// its `PT_ARM_EXIDX` segment if there's no section header table (as in stripped images).
+    /// Returns an empty Option if the object isn't for ARM or has no exception index table.
+    ///
+    /// Note: in relocatable objects, the table's prel31 offsets haven't been relocated yet.
+    pub fn arm_exidx(&self) -> Result<Option<ExidxTable<'data, E>>, ParseError> {
+        if let Some(shdr) = self.machine_section_header(abi::EM_ARM, abi::SHT_ARM_EXIDX) {
+            let (buf, _) = self.section_data(&shdr)?;
+            return ExidxTable::new(self.ehdr.endianness, shdr.sh_addr, buf)
+                .context("section data", None, shdr.sh_offset)
+                .map(Some);
+        }
+        if self.ehdr.e_machine != abi::EM_ARM || self.section_headers().is_some() {
+            return Ok(None);
+        }
+        let phdr = match self.segments() {
+            Some(phdrs) => phdrs.iter().find(|phdr| phdr.p_type == abi::PT_ARM_EXIDX),
+            None => None,
+        };
+        let phdr = match phdr {
+            Some(phdr) => phdr,
+            None => return Ok(None),
+        };
+        let buf = self.segment_data(&phdr)?;
+        ExidxTable::new(self.ehdr.endianness, phdr.p_vaddr, buf)
+            .context("segment data", None, phdr.p_offset)
+            .map(Some)
+    }
+
+    /// Get the contents of the `SHT_MIPS_ABIFLAGS` section of a MIPS object.
+    /// Returns an empty Option if the object isn't for MIPS or has no such section.
+    pub fn mips_abiflags(&self) -> Result<Option<MipsAbiFlags>, ParseError> {
+        let shdr = match self.machine_section_header(abi::EM_MIPS, abi::SHT_MIPS_ABIFLAGS) {
+            Some(shdr) => shdr,
+            None => return Ok(None),
+        };
+        let (buf, _) = self.section_data(&shdr)?;
+        MipsAbiFlags::parse_at(self.ehdr.endianness, self.ehdr.class, &mut 0, buf)
+            .context("section data", None, shdr.sh_offset)
+            .map(Some)
+    }
+
+    /// Get the contents of the `SHT_MIPS_REGINFO` section of a MIPS object.
+    /// Returns an empty Option if the object isn't for MIPS or has no such section.
+    pub fn mips_reginfo(&self) -> Result<Option<MipsRegInfo>, ParseError> {
+        let shdr = match self.machine_section_header(abi::EM_MIPS, abi::SHT_MIPS_REGINFO) {
+            Some(shdr) => shdr,
+            None => return Ok(None),
+        };
+        let (buf, _) = self.section_data(&shdr)?;
+        MipsRegInfo::parse_at(self.ehdr.endianness, self.ehdr.class, &mut 0, buf)
+            .context("section data", None, shdr.sh_offset)
+            .map(Some)
+    }
+
+    /// Get the `.stack_sizes` sections of the object, as emitted by LLVM's
+    /// `-fstack-size-section`. With `-ffunction-sections`, there's one for each function.
+    #[cfg(feature = "alloc")]
+    pub fn stack_sizes(&self) -> Result<Vec<StackSizesSection<'data, E>>, ParseError> {
+        let (shdrs, strtab) = match self.section_headers_with_strtab()? {
+            (Some(shdrs), Some(strtab)) => (shdrs, strtab),
+            _ => return Ok(Vec::new()),
+        };
+
+        let mut sections = Vec::new();
+        for (shndx, shdr) in shdrs.iter().enumerate() {
+            if shdr.sh_type != abi::SHT_PROGBITS
+                || !matches!(
+                    strtab.get(shdr.sh_name as usize),
+                    Ok(STACK_SIZES_SECTION_NAME)
+                )
+            {
+                continue;
+            }
+            let (buf, _) = self.section_data(&shdr)?;
+            sections.push(StackSizesSection {
+                shndx,
+                text_shndx: shdr.sh_link as usize,
+                sizes: StackSizeIterator::new(self.ehdr.endianness, self.ehdr.class, buf),
+            });
+        }
+        Ok(sections)
+    }
+
+    /// Get the entries from all the `.stack_sizes` sections, joined with the names of the
+    /// [abi::STT_FUNC] symbols from the `.symtab` at their addresses.
+    ///
+    /// A symbol matches an entry if it has the entry's address and, when the `.stack_sizes`
+    /// section is linked to a text section, is defined in that section. This is what makes
+    /// entries in relocatable objects built with `-ffunction-sections` resolvable, as each
+    /// function starts at address 0 of its own section.
+    #[cfg(feature = "alloc")]
+    pub fn function_stack_sizes(&self) -> Result<Vec<FunctionStackSize<'data>>, ParseError> {
+        let sections = self.stack_sizes()?;
+        if sections.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        // Index the function symbols by (address, section index) for the lookups
+        let symtab = self.symbol_table()?;
+        let mut funcs: Vec<(u64, Option<usize>, u32)> = match &symtab {
+            Some((symtab, _)) => symtab
+                .iter()
+                .filter(|sym| sym.st_symtype() == abi::STT_FUNC)
+                .map(|sym| {
+                    let shndx = sym.shndx().section().map(|shndx| shndx as usize);
+                    (sym.st_value, shndx, sym.st_name)
+                })
+                .collect(),
+            None => Vec::new(),
+        };
+        funcs.sort_unstable();
+
+        let mut sizes = Vec::new();
+        for section in sections {
+            for entry in section.sizes {
+                let entry = entry?;
+                let start = funcs.partition_point(|(addr, _, _)| *addr < entry.addr);
+                let st_name = funcs[start..]
+                    .iter()
+                    .take_while(|(addr, _, _)| *addr == entry.addr)
+                    .find(|(_, shndx, _)| {
+                        section.text_shndx == 0 || *shndx == Some(section.text_shndx)
+                    })
+                    .map(|(_, _, st_name)| *st_name);
+                let name = match (st_name, &symtab) {
+                    (Some(st_name), Some((_, strtab))) => Some(strtab.get(st_name as usize)?),
+                    _ => None,
+                };
+                sizes.push(FunctionStackSize {
+                    name,
+                    addr: entry.addr,
+                    stack_size: entry.stack_size,
+                });
+            }
+        }
+        Ok(sizes)
+    }
+
+    /// Get the SFrame stack trace information from the `SHT_GNU_SFRAME` section (or the
+    /// `.sframe` section, which older toolchains emit as `SHT_PROGBITS`), or from the
+    /// `PT_GNU_SFRAME` segment if there's no section header table.
+    /// Returns an empty Option if the object has no SFrame data.
+    pub fn sframe(&self) -> Result<Option<SFrameSection<'data, E>>, ParseError> {
+        if let Some(shdrs) = self.section_headers() {
+            let shdr = match shdrs
+                .iter()
+                .find(|shdr| shdr.sh_type == abi::SHT_GNU_SFRAME)
+            {
+                Some(shdr) => Some(shdr),
+                None => self.section_header_by_name(SFRAME_SECTION_NAME)?,
+            };
+            let shdr = match shdr {
+                Some(shdr) => shdr,
+                None => return Ok(None),
+            };
+            let (buf, _) = self.section_data(&shdr)?;
+            return SFrameSection::new(self.ehdr.endianness, shdr.sh_addr, buf)
+                .context("section data", None, shdr.sh_offset)
+                .map(Some);
+        }
+
+        let phdr = match self.segments() {
+            Some(phdrs) => phdrs.iter().find(|phdr| phdr.p_type == abi::PT_GNU_SFRAME),
+            None => None,
+        };
+        let phdr = match phdr {
+            Some(phdr) => phdr,
+            None => return Ok(None),
+        };
+        let buf = self.segment_data(&phdr)?;
+        SFrameSection::new(self.ehdr.endianness, phdr.p_vaddr, buf)
+            .context("segment data", None, phdr.p_offset)
+            .map(Some)
+    }
+
+    /// Get the `.eh_frame_hdr` binary search table, from the `PT_GNU_EH_FRAME` segment or from
+    /// the `.eh_frame_hdr` section if there's no such segment (as in relocatable objects).
+    /// Returns an empty Option if the object has neither.
+    pub fn eh_frame_hdr(&self) -> Result<Option<EhFrameHdr<'data, E>>, ParseError> {
+        let phdr = match self.segments() {
+            Some(phdrs) => phdrs
+                .iter()
+                .find(|phdr| phdr.p_type == abi::PT_GNU_EH_FRAME),
+            None => None,
+        };
+        if let Some(phdr) = phdr {
+            let buf = self.segment_data(&phdr)?;
+            return EhFrameHdr::new(self.ehdr.endianness, self.ehdr.class, phdr.p_vaddr, buf)
+                .context("segment data", None, phdr.p_offset)
+                .map(Some);
+        }
+
+        let shdr = match self.section_header_by_name(EH_FRAME_HDR_SECTION_NAME)? {
+            Some(shdr) => shdr,
+            None => return Ok(None),
+        };
+        let (buf, _) = self.section_data(&shdr)?;
+        EhFrameHdr::new(self.ehdr.endianness, self.ehdr.class, shdr.sh_addr, buf)
+            .context("section data", None, shdr.sh_offset)
+            .map(Some)
+    }
+
+    /// Map out which file bytes are claimed by the file header, the program and section header
+    /// tables, and the contents of each section and segment.
+    ///
+    /// See [FileLayout] for finding the unclaimed gaps and overlay, and overlapping claims.
+    #[cfg(feature = "alloc")]
+    pub fn layout(&self) -> Result<FileLayout, ParseError> {
+        let mut ranges = Vec::new();
+        let mut claim = |start: usize, size: usize, owner| -> Result<(), ParseError> {
+            let end = start.checked_add(size).ok_or(ParseError::IntegerOverflow)?;
+            ranges.push(LayoutRange {
+                range: start..end,
+                owner,
+            });
+            Ok(())
+        };
+
+        claim(0, self.ehdr.e_ehsize as usize, LayoutOwner::FileHeader)?;
+        if let Some(phdrs) = self.phdrs {
+            let size = (self.ehdr.e_phentsize as usize)
+                .checked_mul(phdrs.len())
+                .ok_or(ParseError::IntegerOverflow)?;
+            claim(
+                self.ehdr.e_phoff.try_into()?,
+                size,
+                LayoutOwner::ProgramHeaders,
+            )?;
+            for (index, phdr) in phdrs.iter().enumerate() {
+                claim(
+                    phdr.p_offset.try_into()?,
+                    phdr.p_filesz.try_into()?,
+                    LayoutOwner::Segment(index),
+                )?;
+            }
+        }
+        if let Some(shdrs) = self.shdrs {
+            let size = (self.ehdr.e_shentsize as usize)
+                .checked_mul(shdrs.len())
+                .ok_or(ParseError::IntegerOverflow)?;
+            claim(
+                self.ehdr.e_shoff.try_into()?,
+                size,
+                LayoutOwner::SectionHeaders,
+            )?;
+            for (index, shdr) in shdrs.iter().enumerate() {
+                if shdr.sh_type == abi::SHT_NOBITS {
+                    continue;
+                }
+                claim(
+                    shdr.sh_offset.try_into()?,
+                    shdr.sh_size.try_into()?,
+                    LayoutOwner::Section(index),
+                )?;
+            }
+        }
+        Ok(FileLayout::new(ranges, self.data.len()))
+    }
+
+    /// Get the range of bytes after the last byte claimed by any of the file's headers, up to
+    /// the end of the file. Returns an empty Option if there's no such appended data.
+    #[cfg(feature = "alloc")]
+    pub fn overlay(&self) -> Result<Option<core::ops::Range<usize>>, ParseError> {
+        Ok(self.layout()?.overlay())
+    }
+
+    /// Get the PLT entries and the functions that they call, for labeling calls through the
+    /// PLT. The entries are in address order. See the [plt](crate::plt) module for details.
+    ///
+    /// The entries start after the header in the `.plt` section, or at the start of the
+    /// `.plt.sec` section for x86_64 objects with IBT-enabled PLTs. Without section headers,
+    /// the PLT is located through the initial value of the first `DT_JMPREL` relocation's GOT
+    /// slot, which only works for the non-IBT PLTs and for GOTs that the dynamic linker hasn't
+    /// filled in yet.
+    ///
+    /// Returns an empty Option if the machine isn't supported (only x86_64 and AArch64 are),
+    /// the object has no PLT relocations, or the PLT can't be located.
+    #[cfg(feature = "alloc")]
+    pub fn plt_entries(&self) -> Result<Option<Vec<PltEntry<'data>>>, ParseError> {
+        let layout = match PltLayout::for_machine(self.ehdr.e_machine) {
+            Some(layout) => layout,
+            None => return Ok(None),
+        };
+        let relas: Vec<Rela> = match self.dynamic_relocations()? {
+            Some(DynamicRelocations {
+                plt_rela: Some(relas),
+                ..
+            }) => relas.filter(|rela| rela.r_type != layout.tlsdesc).collect(),
+            _ => return Ok(None),
+        };
+        let first = match relas.first() {
+            Some(rela) => self.first_plt_entry(&layout, rela)?,
+            None => None,
+        };
+        let first = match first {
+            Some(first) => first,
+            None => return Ok(None),
+        };
+
+        let dynsyms = self.dynamic_symbol_table()?;
+        let mut entries = Vec::with_capacity(relas.len());
+        for (index, rela) in relas.iter().enumerate() {
+            let addr = (index as u64)
+                .checked_mul(layout.entry_size)
+                .and_then(|offset| offset.checked_add(first))
+                .ok_or(ParseError::IntegerOverflow)?;
+            let target = if rela.r_type == layout.irelative {
+                PltTarget::IRelative {
+                    resolver: rela.r_addend as u64,
+                }
+            } else {
+                let name = match &dynsyms {
+                    Some((symtab, strtab)) => {
+                        let symbol = symtab.get(rela.r_sym as usize)?;
+                        Some(strtab.get(symbol.st_name as usize)?)
+                    }
+                    None => None,
+                };
+                PltTarget::Symbol {
+                    index: rela.r_sym,
+                    name,
+                }
+            };
+            entries.push(PltEntry { addr, target });
+        }
+        Ok(Some(entries))
+    }
+
+    /// Internal helper to find the address of the first function's PLT entry, given the
+    /// first `DT_JMPREL` relocation.
+    #[cfg(feature = "alloc")]
+    fn first_plt_entry(&self, layout: &PltLayout, rela: &Rela) -> Result<Option<u64>, ParseError> {
+        if let Some(shdr) = self.section_header_by_name(".plt.sec")? {
+            return Ok(Some(shdr.sh_addr));
+        }
+        if let Some(shdr) = self.section_header_by_name(".plt")? {
+            return Ok(shdr.sh_addr.checked_add(layout.header_size));
+        }
+
+        // The GOT slot starts out pointing back into the PLT, for lazy binding
+        let slot = match self.ehdr.class {
+            Class::ELF32 => self
+                .dynamic_addr_data(rela.r_offset, Some(4))
+                .and_then(|buf| self.ehdr.endianness.parse_u32_at(&mut 0, buf))
+                .map(u64::from),
+            Class::ELF64 => self
+                .dynamic_addr_data(rela.r_offset, Some(8))
+                .and_then(|buf| self.ehdr.endianness.parse_u64_at(&mut 0, buf)),
+        };
+        let slot = match slot {
+            Ok(slot) => slot,
+            Err(_) => return Ok(None),
+        };
+        match self.ehdr.e_machine {
+            // To the push instruction after the entry's 6 byte indirect jmp
+            abi::EM_X86_64 => match self.dynamic_addr_data(slot, Some(1)) {
+                Ok([0x68]) => Ok(slot.checked_sub(6)),
+                _ => Ok(None),
+            },
+            // To the PLT header
+            abi::EM_AARCH64 => Ok(slot.checked_add(layout.header_size)),
+            _ => Ok(None),
+        }
+    }
+
+    /// Get the slots of the GOT and the relocations which fill them in, in address order. See
+    /// the [got](crate::got) module for details.
+    ///
+    /// The GOT is made up of the `.got` and `.got.plt` sections. Without section headers, it's
+    /// found through `DT_PLTGOT` instead, and runs from the reserved slots up to the last slot
+    /// covered by a `DT_JMPREL` relocation, which leaves out the `.got` slots for data.
+    ///
+    /// Returns an empty Option if the object has no GOT.
+    #[cfg(feature = "alloc")]
+    pub fn got_slots(&self) -> Result<Option<Vec<GotSlot<'data>>>, ParseError> {
+        let word: u64 = match self.ehdr.class {
+            Class::ELF32 => 4,
+            Class::ELF64 => 8,
+        };
+        let pltgot = match self.dynamic_addrs()? {
+            Some(addrs) => addrs.pltgot.map(|addr| self.unbiased_addr(addr)),
+            None => None,
+        };
+        let reserved_end = pltgot.and_then(|addr| addr.checked_add(3 * word));
+
+        // The (r_type, r_sym) of the relocation covering each slot
+        let mut relocations = BTreeMap::new();
+        let mut plt_end = reserved_end;
+        if let Some(dynamic) = self.dynamic_relocations()? {
+            let rels = dynamic.rel.into_iter().flatten();
+            let relas = dynamic.rela.into_iter().flatten();
+            for (r_offset, r_type, r_sym) in rels
+                .map(|rel| (rel.r_offset, rel.r_type, rel.r_sym))
+                .chain(relas.map(|rela| (rela.r_offset, rela.r_type, rela.r_sym)))
+            {
+                relocations.insert(r_offset, (r_type, r_sym));
+            }
+
+            let plt_rels = dynamic.plt_rel.into_iter().flatten();
+            let plt_relas = dynamic.plt_rela.into_iter().flatten();
+            for (r_offset, r_type, r_sym) in plt_rels
+                .map(|rel| (rel.r_offset, rel.r_type, rel.r_sym))
+                .chain(plt_relas.map(|rela| (rela.r_offset, rela.r_type, rela.r_sym)))
+            {
+                relocations.insert(r_offset, (r_type, r_sym));
+                plt_end = plt_end.max(r_offset.checked_add(word));
+            }
+        }
+
+        let mut regions = Vec::new();
+        for name in [".got", ".got.plt"] {
+            if let Some(shdr) = self.section_header_by_name(name)? {
+                if shdr.sh_type != abi::SHT_NOBITS {
+                    regions.push((shdr.sh_addr, shdr.sh_size));
+                }
+            }
+        }
+        if regions.is_empty() {
+            match (pltgot, plt_end) {
+                (Some(start), Some(end)) => regions.push((start, end - start)),
+                _ => return Ok(None),
+            }
+        }
+
+        let dynsyms = self.dynamic_symbol_table()?;
+        let mut slots = Vec::new();
+        for (start, size) in regions {
+            let buf = self
+                .dynamic_addr_data(start, Some(size))
+                .context("GOT", None, start)?;
+            let table = AddrTable::new(self.ehdr.endianness, self.ehdr.class, WordSize::Class, buf);
+            for (index, value) in table.iter().enumerate() {
+                let addr = (index as u64)
+                    .checked_mul(word)
+                    .and_then(|offset| offset.checked_add(start))
+                    .ok_or(ParseError::IntegerOverflow)?;
+                let target = match relocations.get(&addr) {
+                    Some(&(r_type, 0)) => GotTarget::Local { r_type },
+                    Some(&(r_type, index)) => {
+                        let name = match &dynsyms {
+                            Some((symtab, strtab)) => {
+                                let symbol = symtab.get(index as usize)?;
+                                Some(strtab.get(symbol.st_name as usize)?)
+                            }
+                            None => None,
+                        };
+                        GotTarget::Symbol {
+                            r_type,
+                            index,
+                            name,
+                        }
+                    }
+                    None => match (pltgot, reserved_end) {
+                        (Some(start), Some(end)) if (start..end).contains(&addr) => {
+                            GotTarget::Reserved
+                        }
+                        _ => GotTarget::Unrelocated,
+                    },
+                };
+                slots.push(GotSlot {
+                    addr,
+                    value,
+                    target,
+                });
+            }
+        }
+        slots.sort_by_key(|slot| slot.addr);
+        Ok(Some(slots))
+    }
+
+    /// Get the functions which run when the object is loaded and unloaded.
+    ///
+    /// The `.preinit_array`, `.init_array` and `.fini_array` tables are found by their section
+    /// types if the object has section headers. Otherwise, as for stripped images, they're found
+    /// through the dynamic section's `DT_PREINIT_ARRAY`, `DT_INIT_ARRAY` and `DT_FINI_ARRAY`
+    /// entries, as are the single `DT_INIT` and `DT_FINI` functions.
+    ///
+    /// Static executables don't have a dynamic section, so their `init` and `fini` functions
+    /// aren't found. Relocatable objects may have several tables of each type, of which only
+    /// the first is returned.
+    pub fn init_functions(&self) -> Result<InitFunctions<'data, E>, ParseError> {
+        let (endian, class) = (self.ehdr.endianness, self.ehdr.class);
+        let mut functions = InitFunctions {
+            preinit_array: None,
+            init: None,
+            init_array: None,
+            fini_array: None,
+            fini: None,
+        };
+        let addrs = self.dynamic_addrs()?;
+
+        if let Some(shdrs) = self.shdrs {
+            for shdr in shdrs.iter() {
+                let table = match shdr.sh_type {
+                    abi::SHT_PREINIT_ARRAY => &mut functions.preinit_array,
+                    abi::SHT_INIT_ARRAY => &mut functions.init_array,
+                    abi::SHT_FINI_ARRAY => &mut functions.fini_array,
+                    _ => continue,
+                };
+                if table.is_none() {
+                    let (buf, _) = self.section_data(&shdr)?;
+                    *table = Some(AddrTable::new(endian, class, WordSize::Class, buf));
+                }
+            }
+        } else if let Some(addrs) = &addrs {
+            for (table, addr, size) in [
+                (
+                    &mut functions.preinit_array,
+                    addrs.preinit_array,
+                    addrs.preinit_arraysz,
+                ),
+                (
+                    &mut functions.init_array,
+                    addrs.init_array,
+                    addrs.init_arraysz,
+                ),
+                (
+                    &mut functions.fini_array,
+                    addrs.fini_array,
+                    addrs.fini_arraysz,
+                ),
+            ] {
+                if let (Some(addr), Some(size)) = (addr, size) {
+                    let buf = self.dynamic_addr_data(addr, Some(size)).context(
+                        "function address table",
+                        None,
+                        addr,
+                    )?;
+                    *table = Some(AddrTable::new(endian, class, WordSize::Class, buf));
+                }
+            }
+        }
+
+        if let Some(addrs) = addrs {
+            functions.init = addrs.init;
+            functions.fini = addrs.fini;
+        }
+        Ok(functions)
+    }
+
+    /// Get the file name and CRC of the separate debug file from the `.gnu_debuglink` section.
+    ///
+    /// The CRC can be checked against a candidate debug file's contents with
+    /// [gnu_debuglink_crc](crate::debuglink::gnu_debuglink_crc).
+    /// Returns an empty Option if the object has no `.gnu_debuglink` section.
+    pub fn gnu_debuglink(&self) -> Result<Option<(&'data [u8], u32)>, ParseError> {
+        let shdr = match self.section_header_by_name(GNU_DEBUGLINK_SECTION_NAME)? {
+            Some(shdr) => shdr,
+            None => return Ok(None),
+        };
+        let (buf, _) = self.section_data(&shdr)?;
+        parse_gnu_debuglink(self.ehdr.endianness, buf)
+            .context("section data", None, shdr.sh_offset)
+            .map(Some)
+    }
+
+    /// Get the file name and build-id of the supplementary debug file from the
+    /// `.gnu_debugaltlink` section.
+    /// Returns an empty Option if the object has no `.gnu_debugaltlink` section.
+    pub fn gnu_debugaltlink(&self) -> Result<Option<GnuDebugAltLink<'data>>, ParseError> {
+        let shdr = match self.section_header_by_name(GNU_DEBUGALTLINK_SECTION_NAME)? {
+            Some(shdr) => shdr,
+            None => return Ok(None),
+        };
+        let (buf, _) = self.section_data(&shdr)?;
+        parse_gnu_debugaltlink(buf)
+            .context("section data", None, shdr.sh_offset)
+            .map(Some)
+    }
+
+    /// Get the decompressed MiniDebugInfo ELF object from the `.gnu_debugdata` section, limiting
+    /// its size to [DEFAULT_GNU_DEBUGDATA_LIMIT](crate::debugdata::DEFAULT_GNU_DEBUGDATA_LIMIT).
+    /// Returns an empty Option if the object has no `.gnu_debugdata` section.
+    #[cfg(feature = "xz")]
+    pub fn gnu_debugdata(&self) -> Result<Option<Vec<u8>>, ParseError> {
+        self.gnu_debugdata_with_limit(crate::debugdata::DEFAULT_GNU_DEBUGDATA_LIMIT)
+    }
+
+    /// Like [ElfBytes::gnu_debugdata], but fails if the decompressed data would be larger than
+    /// `limit` bytes.
+    #[cfg(feature = "xz")]
+    pub fn gnu_debugdata_with_limit(&self, limit: usize) -> Result<Option<Vec<u8>>, ParseError> {
+        use crate::debugdata::{decompress_gnu_debugdata, GNU_DEBUGDATA_SECTION_NAME};
+
+        let shdr = match self.section_header_by_name(GNU_DEBUGDATA_SECTION_NAME)? {
+            Some(shdr) => shdr,
+            None => return Ok(None),
+        };
+        let (buf, _) = self.section_data(&shdr)?;
+        decompress_gnu_debugdata(buf, limit)
+            .context("section data", None, shdr.sh_offset)
+            .map(Some)
+    }
+
+    /// Get the MiniDebugInfo ELF object from the `.gnu_debugdata` section, decompressed and
+    /// parsed, so that its `.symtab` can be used to symbolize this stripped object.
+    #[cfg(feature = "xz")]
+    pub fn gnu_debugdata_elf(&self) -> Result<Option<crate::OwnedElfBytes<E>>, ParseError> {
+        match self.gnu_debugdata()? {
+            Some(data) => Ok(Some(crate::OwnedElfBytes::minimal_parse(data)?)),
+            None => Ok(None),
+        }
+    }
+
+    /// Get the contents of the DWARF debug section with the given name, like `.debug_info`,
+    /// decompressed if need be. This is meant to be the section loader for DWARF consumers
+    /// like gimli, so a section that doesn't exist, or which is [abi::SHT_NOBITS] as in a
+    /// stripped object, is returned as empty rather than as an error.
+    ///
+    /// If there's no section with the given name, the legacy GNU `.zdebug_*` counterpart of a
+    /// `.debug_*` name is looked up instead. Compressed sections are only decompressed with
+    /// the `zlib` feature enabled, and otherwise fail with a
+    /// [ParseError::UnsupportedCompression].
+    ///
+    /// Example usage, as the loader passed to `gimli::Dwarf::load` would use it:
+    /// ```
+    /// use elf::endian::AnyEndian;
+    /// use elf::ElfBytes;
+    ///
+    /// let path = std::path::PathBuf::from("sample-objects/debug_zdebug.x86_64.o");
+    /// let file_data = std::fs::read(path).expect("Could not read file.");
+    /// let file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("Open test1");
+    ///
+    /// // With gimli, this would be `|id: gimli::SectionId| file.debug_section(id.name())`
+    /// let load = |name: &str| file.debug_section(name);
+    /// assert_eq!(load(".debug_abbrev").expect("should load").len(), 0x2d);
+    /// assert!(load(".debug_loclists").expect("should load").is_empty());
+    /// # #[cfg(feature = "zlib")]
+    /// assert_eq!(load(".debug_info").expect("should decompress").len(), 0x49);
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn debug_section(&self, name: &str) -> Result<Cow<'data, [u8]>, ParseError> {
+        let (shdrs, strtab) = match self.section_headers_with_strtab()? {
+            (Some(shdrs), Some(strtab)) => (shdrs, strtab),
+            _ => return Ok(Cow::Borrowed(&[])),
+        };
+        let suffix = name.strip_prefix(".debug_").map(str::as_bytes);
+        let mut zdebug = None;
+        for shdr in shdrs.iter() {
+            let shdr_name = strtab.get_raw(shdr.sh_name as usize)?;
+            if shdr_name == name.as_bytes() {
+                return self.decompressed_debug_data(&shdr, false).context(
+                    "section data",
+                    None,
+                    shdr.sh_offset,
+                );
+            }
+            if zdebug.is_none() && suffix.is_some() && shdr_name.strip_prefix(b".zdebug_") == suffix
+            {
+                zdebug = Some(shdr);
+            }
+        }
+        match zdebug {
+            Some(shdr) => self.decompressed_debug_data(&shdr, true).context(
+                "section data",
+                None,
+                shdr.sh_offset,
+            ),
+            None => Ok(Cow::Borrowed(&[])),
+        }
+    }
+
+    /// Internal helper for [ElfBytes::debug_section] to get a section's data, decompressing it
+    /// as an [abi::SHF_COMPRESSED] section or, if `zdebug` is set, as a `.zdebug_*` section.
+    #[cfg(feature = "alloc")]
+    fn decompressed_debug_data(
+        &self,
+        shdr: &SectionHeader,
+        zdebug: bool,
+    ) -> Result<Cow<'data, [u8]>, ParseError> {
+        let (buf, chdr) = self.section_data(shdr)?;
+        let (ch_type, size, buf) = match chdr {
+            Some(chdr) => (chdr.ch_type, chdr.ch_size, buf),
+            None if zdebug && shdr.sh_type != abi::SHT_NOBITS => {
+                let (size, buf) = parse_zdebug_header(buf)?;
+                (abi::ELFCOMPRESS_ZLIB, size, buf)
+            }
+            None => return Ok(Cow::Borrowed(buf)),
+        };
+        match ch_type {
+            #[cfg(feature = "zlib")]
+            abi::ELFCOMPRESS_ZLIB => {
+                Ok(Cow::Owned(crate::compression::decompress_zlib(buf, size)?))
+            }
+            _ => {
+                // Only used by the enabled decompressors
+                let _ = (size, buf);
+                Err(ParseError::UnsupportedCompression(ch_type))
+            }
+        }
+    }
+
     /// Internal helper to get the section data for an SHT_DYNAMIC section as a .dynamic section table.
     /// See [ElfBytes::dynamic] or [ElfBytes::find_common_data] for the public interface
     fn section_data_as_dynamic(
@@ -563,21 +2495,36 @@ impl<'data, E: EndianParse> ElfBytes<'data, E> {
         }
 
         // Validate entsize before trying to read the table so that we can error early for corrupted files
-        Dyn::validate_entsize(self.ehdr.class, shdr.sh_entsize.try_into()?)?;
+        let entsize = self.table_entsize::<Dyn>(shdr)?;
         let (buf, _) = self.section_data(shdr)?;
-        Ok(DynamicTable::new(
+        Ok(DynamicTable::with_entsize(
             self.ehdr.endianness,
             self.ehdr.class,
+            entsize,
             buf,
         ))
     }
 
     /// Get the segment's file data for a given segment/[ProgramHeader].
     ///
-    /// This is the segment's data as found in the file.
+    /// This is the segment's data as found in the file. For images parsed from memory with
+    /// [ElfBytes::parse_loaded], it's the same data as found at the segment's address instead.
     pub fn segment_data(&self, phdr: &ProgramHeader) -> Result<&'data [u8], ParseError> {
-        let (start, end) = phdr.get_file_data_range()?;
-        self.data.get_bytes(start..end)
+        if self.load_bias.is_some() {
+            if phdr.p_filesz == 0 {
+                return Ok(&[]);
+            }
+            let start = self
+                .image_base()
+                .and_then(|base| phdr.p_vaddr.checked_sub(base))
+                .ok_or(ParseError::BadOffset(phdr.p_vaddr))?;
+            return file_range(start, phdr.p_filesz)
+                .and_then(|range| self.data.get_file_bytes(range))
+                .context("segment data", None, phdr.p_vaddr);
+        }
+        phdr.file_range()
+            .and_then(|range| self.data.get_file_bytes(range))
+            .context("segment data", None, phdr.p_offset)
     }
 
     /// Get the segment's file data for a given [ProgramHeader], and interpret it as an
@@ -604,30 +2551,577 @@ impl<'data, E: EndianParse> ElfBytes<'data, E> {
         ))
     }
 
-    /// Get the .dynamic section or [abi::PT_DYNAMIC] segment contents.
-    pub fn dynamic(&self) -> Result<Option<DynamicTable<'data, E>>, ParseError> {
-        // If we have section headers, look for the SHT_DYNAMIC section
-        if let Some(shdrs) = self.section_headers() {
-            if let Some(shdr) = shdrs.iter().find(|shdr| shdr.sh_type == abi::SHT_DYNAMIC) {
-                return Ok(Some(self.section_data_as_dynamic(&shdr)?));
+    /// Internal helper to find the description of the first note with the given name and type,
+    /// in the [abi::SHT_NOTE] sections or, without section headers, the [abi::PT_NOTE]
+    /// segments. Names are compared up to their first NUL.
+    ///
+    /// This only finds notes which parse as [Note::Unknown].
+    fn find_note_desc(&self, name: &[u8], n_type: u64) -> Result<Option<&'data [u8]>, ParseError> {
+        self.find_note(|note| match note {
+            Note::Unknown(note) if note.n_type == n_type && note.is_owned_by(name) => {
+                Some(note.desc)
             }
-        // Otherwise, look up the PT_DYNAMIC segment (if any)
-        } else if let Some(phdrs) = self.segments() {
-            if let Some(phdr) = phdrs.iter().find(|phdr| phdr.p_type == abi::PT_DYNAMIC) {
-                let (start, end) = phdr.get_file_data_range()?;
-                let buf = self.data.get_bytes(start..end)?;
-                return Ok(Some(DynamicTable::new(
-                    self.ehdr.endianness,
-                    self.ehdr.class,
-                    buf,
-                )));
+            _ => None,
+        })
+    }
+
+    /// Internal helper to find the first note for which `f` returns a value, in the
+    /// [abi::SHT_NOTE] sections or, without section headers, the [abi::PT_NOTE] segments.
+    fn find_note<T>(&self, f: impl Fn(Note<'data>) -> Option<T>) -> Result<Option<T>, ParseError> {
+        if self.shdrs.is_some() {
+            let notes = self
+                .iter_section_headers()
+                .filter(|shdr| shdr.sh_type == abi::SHT_NOTE);
+            for shdr in notes {
+                if let Some(value) = self.section_data_as_notes(&shdr)?.find_map(&f) {
+                    return Ok(Some(value));
+                }
+            }
+        } else if let Some(phdrs) = self.phdrs {
+            for phdr in phdrs.iter().filter(|phdr| phdr.p_type == abi::PT_NOTE) {
+                if let Some(value) = self.segment_data_as_notes(&phdr)?.find_map(&f) {
+                    return Ok(Some(value));
+                }
             }
         }
-
         Ok(None)
     }
 
-    /// Helper method to get the section data for a given pair of [SectionHeader] for the symbol
+    /// Get the GNU build-id from the [abi::ELF_NOTE_GNU] note of type [abi::NT_GNU_BUILD_ID],
+    /// which is usually in the `.note.gnu.build-id` section.
+    /// Returns an empty Option if there's no such note.
+    pub fn gnu_build_id(&self) -> Result<Option<NoteGnuBuildId<'data>>, ParseError> {
+        self.find_note(|note| match note {
+            Note::GnuBuildId(build_id) => Some(build_id),
+            _ => None,
+        })
+    }
+
+    /// Get the Go build ID from the [abi::ELF_NOTE_GO] note of type [abi::NT_GO_BUILD_ID],
+    /// which the Go linker puts in the `.note.go.buildid` section.
+    /// Returns an empty Option if there's no such note.
+    pub fn go_build_id(&self) -> Result<Option<&'data str>, ParseError> {
+        match self.find_note_desc(abi::ELF_NOTE_GO, abi::NT_GO_BUILD_ID)? {
+            Some(desc) => Ok(Some(core::str::from_utf8(desc)?)),
+            None => Ok(None),
+        }
+    }
+
+    /// Get the JSON description of the package that the object belongs to, from the
+    /// [abi::ELF_NOTE_FDO] note of type [abi::NT_FDO_PACKAGING_METADATA], with its NUL
+    /// terminator and padding removed. The JSON isn't parsed or validated.
+    ///
+    /// The note is usually in the `.note.package` section.
+    /// Returns an empty Option if there's no such note.
+    pub fn package_metadata(&self) -> Result<Option<&'data [u8]>, ParseError> {
+        let desc = self.find_note_desc(abi::ELF_NOTE_FDO, abi::NT_FDO_PACKAGING_METADATA)?;
+        Ok(desc.map(trim_note_desc))
+    }
+
+    /// Get the operating system that the object was built for from whichever BSD ident note
+    /// it has: the [abi::ELF_NOTE_FREEBSD] note of type [abi::NT_FREEBSD_ABI_TAG] (along with
+    /// any [abi::NT_FREEBSD_FEATURE_CTL] note), the [abi::ELF_NOTE_NETBSD] note of type
+    /// [abi::NT_NETBSD_IDENT], or the [abi::ELF_NOTE_OPENBSD] note of type
+    /// [abi::NT_OPENBSD_IDENT].
+    ///
+    /// Returns an empty Option if there's no such note, as is the case for Linux objects.
+    pub fn os_abi_note(&self) -> Result<Option<OsIdent>, ParseError> {
+        let word = |desc: &[u8]| self.ehdr.endianness.parse_u32_at(&mut 0, desc);
+        if let Some(desc) = self.find_note_desc(abi::ELF_NOTE_FREEBSD, abi::NT_FREEBSD_ABI_TAG)? {
+            let feature_ctl =
+                match self.find_note_desc(abi::ELF_NOTE_FREEBSD, abi::NT_FREEBSD_FEATURE_CTL)? {
+                    Some(desc) => Some(word(desc)?),
+                    None => None,
+                };
+            return Ok(Some(OsIdent::FreeBsd {
+                version: word(desc)?,
+                feature_ctl,
+            }));
+        }
+        if let Some(desc) = self.find_note_desc(abi::ELF_NOTE_NETBSD, abi::NT_NETBSD_IDENT)? {
+            return Ok(Some(OsIdent::NetBsd {
+                version: word(desc)?,
+            }));
+        }
+        if self
+            .find_note_desc(abi::ELF_NOTE_OPENBSD, abi::NT_OPENBSD_IDENT)?
+            .is_some()
+        {
+            return Ok(Some(OsIdent::OpenBsd));
+        }
+        Ok(None)
+    }
+
+    /// Get an iterator over the `key=value` entries of a kernel module's `.modinfo` section,
+    /// as (key, value) pairs. See the [modinfo](crate::modinfo) module for details.
+    ///
+    /// Returns an empty Option if there's no `.modinfo` section.
+    pub fn modinfo(&self) -> Result<Option<ModInfoIterator<'data>>, ParseError> {
+        match self.section_header_by_name(MODINFO_SECTION_NAME)? {
+            Some(shdr) => Ok(Some(ModInfoIterator::new(self.section_data(&shdr)?.0))),
+            None => Ok(None),
+        }
+    }
+
+    /// Get the signature which was appended to a signed kernel module after the end of the
+    /// ELF object, as marked by the [MODULE_SIG_STRING](crate::modinfo::MODULE_SIG_STRING)
+    /// trailer.
+    ///
+    /// Returns an empty Option if the file doesn't end with a module signature.
+    pub fn module_signature(&self) -> Result<Option<ModuleSignature<'data>>, ParseError> {
+        ModuleSignature::parse(self.data)
+    }
+
+    /// Get the Go toolchain version and module info from the `.go.buildinfo` section, in
+    /// either the inline or the older pointer-based encoding. See the [go](crate::go) module
+    /// for details.
+    ///
+    /// Without section headers, the writable [abi::PT_LOAD] segments are searched for the
+    /// section's header instead, at its 16-byte alignment.
+    /// Returns an empty Option if the object wasn't built by the Go toolchain.
+    pub fn go_build_info(&self) -> Result<Option<GoBuildInfo<'data>>, ParseError> {
+        let buf = match self.section_header_by_name(GO_BUILDINFO_SECTION_NAME)? {
+            Some(shdr) => self.section_data(&shdr)?.0,
+            None => match self.find_go_build_info() {
+                Some(buf) => buf,
+                None => return Ok(None),
+            },
+        };
+
+        let (endian, ptr_size, version, modinfo) = match GoBuildInfoHeader::parse(buf)? {
+            GoBuildInfoHeader::Inline(info) => return Ok(Some(info)),
+            GoBuildInfoHeader::Pointers {
+                endian,
+                ptr_size,
+                version,
+                modinfo,
+            } => (endian, ptr_size, version, modinfo),
+        };
+        // Each pointer is to a Go string header: a pointer to the data, and its length
+        let read_string = |addr: u64| -> Result<&'data [u8], ParseError> {
+            let header_size = 2 * ptr_size as u64;
+            let header = self.dynamic_addr_data(addr, Some(header_size)).context(
+                "Go string header",
+                None,
+                addr,
+            )?;
+            let mut offset = 0;
+            let data = parse_ptr(endian, ptr_size, &mut offset, header)?;
+            let len = parse_ptr(endian, ptr_size, &mut offset, header)?;
+            self.dynamic_addr_data(data, Some(len))
+                .context("Go string", None, data)
+        };
+        let version = core::str::from_utf8(read_string(version)?)?;
+        let modinfo = strip_modinfo_sentinels(read_string(modinfo)?);
+        Ok(Some(GoBuildInfo { version, modinfo }))
+    }
+
+    /// Internal helper to search the writable [abi::PT_LOAD] segments for the `.go.buildinfo`
+    /// header, for images without section headers.
+    fn find_go_build_info(&self) -> Option<&'data [u8]> {
+        let wanted =
+            |phdr: &ProgramHeader| phdr.p_type == abi::PT_LOAD && phdr.p_flags & abi::PF_W != 0;
+        for phdr in self.phdrs?.iter().filter(wanted) {
+            let data = match self.segment_data(&phdr) {
+                Ok(data) => data,
+                Err(_) => continue,
+            };
+            // The header is aligned in memory, and so at the same alignment in the file
+            let skip = (16 - (phdr.p_vaddr % 16) as usize) % 16;
+            let mut start = skip;
+            while start < data.len() {
+                if data[start..].starts_with(GO_BUILDINFO_MAGIC) {
+                    return Some(&data[start..]);
+                }
+                start += 16;
+            }
+        }
+        None
+    }
+
+    /// Get the path of the program interpreter (dynamic linker) that the object requests,
+    /// from its [abi::PT_INTERP] segment, or from its `.interp` section if it doesn't have
+    /// program headers.
+    ///
+    /// The path is returned up to its NUL terminator. If it's missing the terminator, the
+    /// whole segment's contents are returned rather than an error, as readelf does.
+    /// Returns an empty Option if the object doesn't request an interpreter, as for static
+    /// executables, most shared libraries, and relocatable objects.
+    pub fn interpreter(&self) -> Result<Option<&'data [u8]>, ParseError> {
+        let buf = if let Some(phdrs) = self.phdrs {
+            match phdrs.iter().find(|phdr| phdr.p_type == abi::PT_INTERP) {
+                Some(phdr) => self.segment_data(&phdr)?,
+                None => return Ok(None),
+            }
+        } else {
+            match self.section_header_by_name(".interp")? {
+                Some(shdr) => self.section_data(&shdr)?.0,
+                None => return Ok(None),
+            }
+        };
+        match buf.iter().position(|byte| *byte == 0) {
+            Some(len) => Ok(Some(&buf[..len])),
+            None => Ok(Some(buf)),
+        }
+    }
+
+    /// Like [ElfBytes::interpreter], but as a `&str`.
+    ///
+    /// Returns a [ParseError::Utf8Error] if the path isn't valid UTF-8.
+    pub fn interpreter_str(&self) -> Result<Option<&'data str>, ParseError> {
+        match self.interpreter()? {
+            Some(path) => Ok(Some(core::str::from_utf8(path)?)),
+            None => Ok(None),
+        }
+    }
+
+    /// Internal helper to check whether the object has a segment of the given type.
+    fn has_segment(&self, p_type: u32) -> bool {
+        self.phdrs.map_or(false, |phdrs| {
+            phdrs.iter().any(|phdr| phdr.p_type == p_type)
+        })
+    }
+
+    /// Internal helper to check whether the object's dynamic section has the [abi::DF_1_PIE]
+    /// flag, and whether it has any `DT_NEEDED` entries.
+    fn dynamic_pie_and_needed(&self) -> Result<(bool, bool), ParseError> {
+        let mut pie = false;
+        let mut needed = false;
+        if let Some(dynamic) = self.dynamic()? {
+            for entry in dynamic.iter() {
+                match entry.d_tag {
+                    abi::DT_NULL => break,
+                    abi::DT_FLAGS_1 => pie = entry.d_val() & abi::DF_1_PIE as u64 != 0,
+                    abi::DT_NEEDED => needed = true,
+                    _ => (),
+                }
+            }
+        }
+        Ok((pie, needed))
+    }
+
+    /// Check whether the object is a position-independent executable.
+    ///
+    /// PIEs are [abi::ET_DYN] objects like shared libraries. Linkers mark them with the
+    /// [abi::DF_1_PIE] flag, but older ones didn't, so an [abi::ET_DYN] object which requests a
+    /// program interpreter is also taken to be a PIE. That heuristic misclassifies the few
+    /// shared libraries which can also be run, like glibc's `libc.so.6`.
+    ///
+    /// Static PIEs are PIEs too.
+    pub fn is_pie(&self) -> Result<bool, ParseError> {
+        if self.ehdr.e_type != abi::ET_DYN {
+            return Ok(false);
+        }
+        let (pie, _) = self.dynamic_pie_and_needed()?;
+        Ok(pie || self.has_segment(abi::PT_INTERP))
+    }
+
+    /// Check whether the object is a statically linked executable, which has program headers
+    /// but no program interpreter and no dynamic section.
+    ///
+    /// Static PIEs have a dynamic section to relocate themselves with, but they're counted as
+    /// statically linked as long as they don't need any shared libraries.
+    /// Relocatable objects haven't been linked at all, so they aren't statically linked.
+    pub fn is_statically_linked(&self) -> Result<bool, ParseError> {
+        if self.phdrs.is_none() || self.has_segment(abi::PT_INTERP) {
+            return Ok(false);
+        }
+        if !self.has_segment(abi::PT_DYNAMIC) {
+            return Ok(true);
+        }
+        let (pie, needed) = self.dynamic_pie_and_needed()?;
+        Ok(self.ehdr.e_type == abi::ET_DYN && pie && !needed)
+    }
+
+    /// Check whether the object has been stripped of its `.symtab` symbol table.
+    ///
+    /// This only looks at the `.symtab`, so objects which have been stripped of their debug
+    /// info but kept their symbols aren't stripped; see [ElfBytes::has_debug_info].
+    pub fn is_stripped(&self) -> Result<bool, ParseError> {
+        Ok(match self.section_headers() {
+            Some(_) => !self
+                .iter_section_headers()
+                .any(|shdr| shdr.sh_type == abi::SHT_SYMTAB),
+            None => true,
+        })
+    }
+
+    /// Check whether the object contains DWARF debug info, in any `.debug_*` section or in a
+    /// `.zdebug_*` section compressed the old GNU way.
+    ///
+    /// Separate debug info files made by `objcopy --only-keep-debug` have debug info, while
+    /// the stripped objects which point at them with a `.gnu_debuglink` don't.
+    pub fn has_debug_info(&self) -> Result<bool, ParseError> {
+        let (shdrs, strtab) = match self.section_headers_with_strtab()? {
+            (Some(shdrs), Some(strtab)) => (shdrs, strtab),
+            _ => return Ok(false),
+        };
+        for shdr in shdrs.iter() {
+            if is_debug_info_section(strtab.get_raw(shdr.sh_name as usize)?, &shdr) {
+                return Ok(true);
+            }
+        }
+        Ok(false)
+    }
+
+    /// Check whether the object has text relocations, which make the dynamic linker write to
+    /// segments which aren't writable, like the code.
+    ///
+    /// Linkers mark these objects with a `DT_TEXTREL` entry or the [abi::DF_TEXTREL] flag, but
+    /// the marking can be missing, so this also checks whether any dynamic relocation lands in
+    /// a [abi::PT_LOAD] segment without [abi::PF_W].
+    pub fn has_text_relocations(&self) -> Result<bool, ParseError> {
+        if let Some(dynamic) = self.dynamic()? {
+            for entry in dynamic.iter() {
+                match entry.d_tag {
+                    abi::DT_NULL => break,
+                    abi::DT_TEXTREL => return Ok(true),
+                    abi::DT_FLAGS if entry.d_val() & abi::DF_TEXTREL as u64 != 0 => {
+                        return Ok(true)
+                    }
+                    _ => (),
+                }
+            }
+        }
+
+        let phdrs = match self.phdrs {
+            Some(phdrs) => phdrs,
+            None => return Ok(false),
+        };
+        let is_read_only = |r_offset: u64| {
+            phdrs.iter().any(|phdr| {
+                phdr.p_type == abi::PT_LOAD
+                    && phdr.p_flags & abi::PF_W == 0
+                    && r_offset >= phdr.p_vaddr
+                    && r_offset - phdr.p_vaddr < phdr.p_memsz
+            })
+        };
+        let relocations = match self.dynamic_relocations()? {
+            Some(relocations) => relocations,
+            None => return Ok(false),
+        };
+        let rels = relocations.rel.into_iter().flatten();
+        let plt_rels = relocations.plt_rel.into_iter().flatten();
+        if rels.chain(plt_rels).any(|rel| is_read_only(rel.r_offset)) {
+            return Ok(true);
+        }
+        let relas = relocations.rela.into_iter().flatten();
+        let plt_relas = relocations.plt_rela.into_iter().flatten();
+        Ok(relas
+            .chain(plt_relas)
+            .any(|rela| is_read_only(rela.r_offset)))
+    }
+
+    /// Check whether any [abi::PT_LOAD] segment is both writable and executable, or the
+    /// [abi::PT_GNU_STACK] segment asks for an executable stack (which is also writable).
+    pub fn has_wx_segments(&self) -> bool {
+        let wx = abi::PF_W | abi::PF_X;
+        self.phdrs.map_or(false, |phdrs| {
+            phdrs.iter().any(|phdr| {
+                matches!(phdr.p_type, abi::PT_LOAD | abi::PT_GNU_STACK) && phdr.p_flags & wx == wx
+            })
+        })
+    }
+
+    /// Check whether the object's [abi::PT_GNU_STACK] segment asks for an executable stack,
+    /// from its [abi::PF_X] flag.
+    ///
+    /// Returns an empty Option if there's no such segment. What that means is up to the
+    /// caller: some kernels and dynamic linkers give such programs an executable stack.
+    /// Relocatable objects don't have segments, see [ElfBytes::object_stack_executable].
+    pub fn stack_executable(&self) -> Option<bool> {
+        self.phdrs
+            .and_then(|phdrs| phdrs.iter().find(|phdr| phdr.p_type == abi::PT_GNU_STACK))
+            .map(|phdr| phdr.p_flags & abi::PF_X != 0)
+    }
+
+    /// Check whether a relocatable object's `.note.GNU-stack` section asks for an executable
+    /// stack, from its [abi::SHF_EXECINSTR] flag. The linker combines these markers into the
+    /// [abi::PT_GNU_STACK] segment of its output, see [ElfBytes::stack_executable].
+    ///
+    /// Returns an empty Option if there's no such section. Linkers may give the output an
+    /// executable stack if any of their input objects is missing the marker.
+    pub fn object_stack_executable(&self) -> Result<Option<bool>, ParseError> {
+        Ok(self
+            .section_header_by_name(".note.GNU-stack")?
+            .map(|shdr| shdr.sh_flags & abi::SHF_EXECINSTR as u64 != 0))
+    }
+
+    /// Check which of the security-relevant sections lie within the [abi::PT_GNU_RELRO]
+    /// segment, i.e. become read-only once the dynamic linker has applied relocations. See
+    /// [ProgramHeader::contains_section] for how sections are matched against the segment.
+    ///
+    /// Returns an empty Option if there's no PT_GNU_RELRO segment. Without section headers,
+    /// all of the coverage fields are empty.
+    pub fn relro_coverage(&self) -> Result<Option<RelroCoverage>, ParseError> {
+        let phdr = match self
+            .phdrs
+            .and_then(|phdrs| phdrs.iter().find(|phdr| phdr.p_type == abi::PT_GNU_RELRO))
+        {
+            Some(phdr) => phdr,
+            None => return Ok(None),
+        };
+        let covered = |name| -> Result<Option<bool>, ParseError> {
+            Ok(self
+                .section_header_by_name(name)?
+                .map(|shdr| phdr.contains_section(&shdr)))
+        };
+        Ok(Some(RelroCoverage {
+            phdr,
+            got_plt: covered(".got.plt")?,
+            got: covered(".got")?,
+            init_array: covered(".init_array")?,
+            fini_array: covered(".fini_array")?,
+            dynamic: covered(".dynamic")?,
+        }))
+    }
+
+    /// Classify the object's RELRO protection like checksec does: [Relro::Full] needs a
+    /// [abi::PT_GNU_RELRO] segment covering `.got.plt` (or no `.got.plt` at all, as linkers
+    /// merge it into `.got` for such objects) and load-time binding, see
+    /// [DynamicTable::is_bind_now]. Otherwise, any PT_GNU_RELRO segment gives [Relro::Partial].
+    pub fn relro(&self) -> Result<Relro, ParseError> {
+        let coverage = match self.relro_coverage()? {
+            Some(coverage) => coverage,
+            None => return Ok(Relro::None),
+        };
+        let bind_now = self
+            .dynamic()?
+            .map_or(false, |dynamic| dynamic.is_bind_now());
+        if bind_now && coverage.got_plt != Some(false) {
+            Ok(Relro::Full)
+        } else {
+            Ok(Relro::Partial)
+        }
+    }
+
+    /// Get the .dynamic section or [abi::PT_DYNAMIC] segment contents.
+    pub fn dynamic(&self) -> Result<Option<DynamicTable<'data, E>>, ParseError> {
+        // If we have section headers, look for the SHT_DYNAMIC section
+        if self.shdrs.is_some() {
+            let mut shdrs = self.iter_section_headers();
+            if let Some(shdr) = shdrs.find(|shdr| shdr.sh_type == abi::SHT_DYNAMIC) {
+                return Ok(Some(self.section_data_as_dynamic(&shdr)?));
+            }
+        // Otherwise, look up the PT_DYNAMIC segment (if any)
+        } else if let Some(phdrs) = self.segments() {
+            if let Some(phdr) = phdrs.iter().find(|phdr| phdr.p_type == abi::PT_DYNAMIC) {
+                let buf = self.segment_data(&phdr)?;
+                return Ok(Some(DynamicTable::new(
+                    self.ehdr.endianness,
+                    self.ehdr.class,
+                    buf,
+                )));
+            }
+        }
+
+        Ok(None)
+    }
+
+    /// Get the string table which holds the names referenced from the dynamic section, such
+    /// as those of `DT_NEEDED` libraries and the `DT_SONAME` (if any).
+    ///
+    /// This is the `.dynstr` section linked from the `.dynamic` section. For images parsed from
+    /// memory with [ElfBytes::parse_loaded] and files without section headers, it's found
+    /// through the dynamic section's `DT_STRTAB` address and `DT_STRSZ` size instead.
+    pub fn dynamic_string_table(&self) -> Result<Option<StringTable<'data>>, ParseError> {
+        if !self.uses_dynamic_addrs() {
+            if let Some(shdrs) = self.section_headers() {
+                let dynamic = self
+                    .iter_section_headers()
+                    .find(|shdr| shdr.sh_type == abi::SHT_DYNAMIC);
+                if let Some(shdr) = dynamic.filter(|shdr| shdr.sh_link != 0) {
+                    let strtab_shdr = self.linked_section_header(&shdrs, &shdr)?;
+                    return Ok(Some(self.section_data_as_strtab(&strtab_shdr)?));
+                }
+            }
+        }
+
+        let addrs = match self.dynamic_addrs()? {
+            Some(addrs) => addrs,
+            None => return Ok(None),
+        };
+        match (addrs.strtab, addrs.strsz) {
+            (Some(addr), Some(size)) => {
+                let buf = self.dynamic_addr_data(addr, Some(size)).context(
+                    "dynamic string table",
+                    None,
+                    addr,
+                )?;
+                Ok(Some(StringTable::new(buf)))
+            }
+            _ => Ok(None),
+        }
+    }
+
+    /// Get the names of the shared libraries that this object needs, from its `DT_NEEDED`
+    /// entries, in the order the dynamic section lists them.
+    ///
+    /// The names are found in [ElfBytes::dynamic_string_table], so this works for files
+    /// without section headers too.
+    #[cfg(feature = "alloc")]
+    pub fn needed_libraries(&self) -> Result<Vec<&'data str>, ParseError> {
+        let dynamic = match self.dynamic()? {
+            Some(dynamic) => dynamic,
+            None => return Ok(Vec::new()),
+        };
+        let strtab = match self.dynamic_string_table()? {
+            Some(strtab) => strtab,
+            None => return Ok(Vec::new()),
+        };
+        dynamic
+            .iter()
+            .take_while(|entry| entry.d_tag != abi::DT_NULL)
+            .filter(|entry| entry.d_tag == abi::DT_NEEDED)
+            .map(|entry| strtab.get(entry.d_val() as usize))
+            .collect()
+    }
+
+    /// Get the object's `DT_SONAME`, which is the name that shared libraries are linked
+    /// against (if any).
+    ///
+    /// The name is found in [ElfBytes::dynamic_string_table], so this works for files without
+    /// section headers too.
+    pub fn soname(&self) -> Result<Option<&'data str>, ParseError> {
+        self.dynamic_string(abi::DT_SONAME)
+    }
+
+    /// Get the libraries named by the object's `DT_AUDIT` entry, which the dynamic linker loads
+    /// as auditors for the whole process (only honored in executables).
+    ///
+    /// The entry holds a colon-separated list, which is split up here. Empty names are skipped.
+    #[cfg(feature = "alloc")]
+    pub fn audit_libraries(&self) -> Result<Vec<&'data str>, ParseError> {
+        Ok(split_library_list(self.dynamic_string(abi::DT_AUDIT)?))
+    }
+
+    /// Get the libraries named by the object's `DT_DEPAUDIT` entry, which the dynamic linker
+    /// loads as auditors when the object is a dependency of a process.
+    ///
+    /// The entry holds a colon-separated list, which is split up here. Empty names are skipped.
+    #[cfg(feature = "alloc")]
+    pub fn depaudit_libraries(&self) -> Result<Vec<&'data str>, ParseError> {
+        Ok(split_library_list(self.dynamic_string(abi::DT_DEPAUDIT)?))
+    }
+
+    /// Get the [ElfBytes::dynamic_string_table] string named by the first dynamic entry with
+    /// the given tag (if any).
+    fn dynamic_string(&self, d_tag: i64) -> Result<Option<&'data str>, ParseError> {
+        let entry = match self.dynamic()? {
+            Some(dynamic) => dynamic.find_value(d_tag),
+            None => return Ok(None),
+        };
+        let offset = match entry {
+            Some(offset) => offset,
+            None => return Ok(None),
+        };
+        match self.dynamic_string_table()? {
+            Some(strtab) => Ok(Some(strtab.get(offset as usize)?)),
+            None => Err(ParseError::BadOffset(offset)),
+        }
+    }
+
+    /// Helper method to get the section data for a given pair of [SectionHeader] for the symbol
     /// table and its linked strtab, and interpret them as [SymbolTable] and [StringTable].
     fn section_data_as_symbol_table(
         &self,
@@ -635,27 +3129,58 @@ impl<'data, E: EndianParse> ElfBytes<'data, E> {
         strtab_shdr: &SectionHeader,
     ) -> Result<(SymbolTable<'data, E>, StringTable<'data>), ParseError> {
         // Validate entsize before trying to read the table so that we can error early for corrupted files
-        Symbol::validate_entsize(self.ehdr.class, shdr.sh_entsize.try_into()?)?;
+        let entsize =
+            self.table_entsize::<Symbol>(shdr)
+                .context("symbol table", None, shdr.sh_offset)?;
 
         // Load the section bytes for the symtab
         // (we want immutable references to both the symtab and its strtab concurrently)
-        let (symtab_start, symtab_end) = shdr.get_data_range()?;
-        let symtab_buf = self.data.get_bytes(symtab_start..symtab_end)?;
+        let symtab_buf = self
+            .section_bytes(shdr)
+            .context("symbol table", None, shdr.sh_offset)?;
 
         // Load the section bytes for the strtab
         // (we want immutable references to both the symtab and its strtab concurrently)
-        let (strtab_start, strtab_end) = strtab_shdr.get_data_range()?;
-        let strtab_buf = self.data.get_bytes(strtab_start..strtab_end)?;
-
-        let symtab = SymbolTable::new(self.ehdr.endianness, self.ehdr.class, symtab_buf);
+        let strtab_buf = self.section_bytes(strtab_shdr).context(
+            "symbol string table",
+            None,
+            strtab_shdr.sh_offset,
+        )?;
+
+        let symtab =
+            SymbolTable::with_entsize(self.ehdr.endianness, self.ehdr.class, entsize, symtab_buf);
         let strtab = StringTable::new(strtab_buf);
         Ok((symtab, strtab))
     }
 
-    /// Get the ELF file's `.symtab` and associated strtab (if any)
-    pub fn symbol_table(
+    /// Parse the [Symbol] at `index` in the symbol table section `symtab_shdr` (such as the
+    /// `.symtab` or `.dynsym`), along with the range of file bytes it was parsed from, for
+    /// tools which patch symbols in place.
+    ///
+    /// The range spans the section's `sh_entsize` bytes, including any padding after the
+    /// symbol's fields. Returns a [ParseError::BadOffset] with the `index` if it's past the end
+    /// of the table.
+    pub fn symbol_with_range(
         &self,
-    ) -> Result<Option<(SymbolTable<'data, E>, StringTable<'data>)>, ParseError> {
+        symtab_shdr: &SectionHeader,
+        index: usize,
+    ) -> Result<(Symbol, core::ops::Range<usize>), ParseError> {
+        let entsize = self.table_entsize::<Symbol>(symtab_shdr).context(
+            "symbol table",
+            None,
+            symtab_shdr.sh_offset,
+        )?;
+        let buf =
+            self.section_bytes(symtab_shdr)
+                .context("symbol table", None, symtab_shdr.sh_offset)?;
+        let symtab = SymbolTable::with_entsize(self.ehdr.endianness, self.ehdr.class, entsize, buf);
+        let (sym, range) = symtab.get_with_range(index)?;
+        // The table isn't empty, so section_bytes() checked that sh_offset fits in a usize
+        Ok((sym, offset_range(symtab_shdr.sh_offset as usize, range)))
+    }
+
+    /// Find the section headers of the `.symtab` and its associated strtab (if any)
+    fn symtab_headers(&self) -> Result<Option<(SectionHeader, SectionHeader)>, ParseError> {
         let shdrs = match self.section_headers() {
             Some(shdrs) => shdrs,
             None => {
@@ -664,24 +3189,70 @@ impl<'data, E: EndianParse> ElfBytes<'data, E> {
         };
 
         // Get the symtab header for the symtab. The GABI states there can be zero or one per ELF file.
-        let symtab_shdr = match shdrs.iter().find(|shdr| shdr.sh_type == abi::SHT_SYMTAB) {
+        let symtab_shdr = match self
+            .iter_section_headers()
+            .find(|shdr| shdr.sh_type == abi::SHT_SYMTAB)
+        {
             Some(shdr) => shdr,
             None => {
                 return Ok(None);
             }
         };
 
-        let strtab_shdr = shdrs.get(symtab_shdr.sh_link as usize)?;
-        Ok(Some(self.section_data_as_symbol_table(
-            &symtab_shdr,
-            &strtab_shdr,
-        )?))
+        let strtab_shdr = self.linked_section_header(&shdrs, &symtab_shdr)?;
+        Ok(Some((symtab_shdr, strtab_shdr)))
+    }
+
+    /// Get the ELF file's `.symtab` and associated strtab (if any)
+    pub fn symbol_table(
+        &self,
+    ) -> Result<Option<(SymbolTable<'data, E>, StringTable<'data>)>, ParseError> {
+        match self.symtab_headers()? {
+            Some((symtab_shdr, strtab_shdr)) => Ok(Some(
+                self.section_data_as_symbol_table(&symtab_shdr, &strtab_shdr)?,
+            )),
+            None => Ok(None),
+        }
+    }
+
+    /// Get the sub-range of the ELF file's `.symtab` holding its non-local symbols, which start
+    /// at the section's `sh_info`, along with its associated strtab (if any).
+    ///
+    /// The sub-range takes and yields the symbols' indexes in the whole `.symtab`. See
+    /// [SymbolTable::globals].
+    pub fn global_symbols(
+        &self,
+    ) -> Result<Option<(SymbolTable<'data, E>, StringTable<'data>)>, ParseError> {
+        let (symtab_shdr, strtab_shdr) = match self.symtab_headers()? {
+            Some(shdrs) => shdrs,
+            None => return Ok(None),
+        };
+        let (symtab, strtab) = self.section_data_as_symbol_table(&symtab_shdr, &strtab_shdr)?;
+        let globals = symtab.globals(symtab_shdr.sh_info).context(
+            "symbol table",
+            None,
+            symtab_shdr.sh_offset,
+        )?;
+        Ok(Some((globals, strtab)))
     }
 
     /// Get the ELF file's `.dynsym` and associated strtab (if any)
+    ///
+    /// For images parsed from memory with [ElfBytes::parse_loaded] and files without section
+    /// headers, these are found through the dynamic section's `DT_SYMTAB` and `DT_STRTAB`
+    /// entries. The number of symbols is taken from the `DT_HASH` or `DT_GNU_HASH` table, or
+    /// else from the distance to the string table.
     pub fn dynamic_symbol_table(
         &self,
     ) -> Result<Option<(SymbolTable<'data, E>, StringTable<'data>)>, ParseError> {
+        if self.uses_dynamic_addrs() {
+            let common = self.find_common_data()?;
+            return match (common.dynsyms, common.dynsyms_strs) {
+                (Some(dynsyms), Some(strtab)) => Ok(Some((dynsyms, strtab))),
+                _ => Ok(None),
+            };
+        }
+
         let shdrs = match self.section_headers() {
             Some(shdrs) => shdrs,
             None => {
@@ -690,109 +3261,499 @@ impl<'data, E: EndianParse> ElfBytes<'data, E> {
         };
 
         // Get the symtab header for the symtab. The GABI states there can be zero or one per ELF file.
-        let symtab_shdr = match shdrs.iter().find(|shdr| shdr.sh_type == abi::SHT_DYNSYM) {
+        let symtab_shdr = match self
+            .iter_section_headers()
+            .find(|shdr| shdr.sh_type == abi::SHT_DYNSYM)
+        {
             Some(shdr) => shdr,
             None => {
                 return Ok(None);
             }
         };
 
-        let strtab_shdr = shdrs.get(symtab_shdr.sh_link as usize)?;
+        let strtab_shdr = self.linked_section_header(&shdrs, &symtab_shdr)?;
         Ok(Some(self.section_data_as_symbol_table(
             &symtab_shdr,
             &strtab_shdr,
         )?))
     }
 
-    /// Locate the section data for the various GNU Symbol Versioning sections (if any)
-    /// and return them in a [SymbolVersionTable] that which can interpret them in-place to
-    /// yield [SymbolRequirement](crate::gnu_symver::SymbolRequirement)s
-    /// and [SymbolDefinition](crate::gnu_symver::SymbolDefinition)s
+    /// Get the ELF file's [abi::SHT_SYMTAB_SHNDX] section (if any) which holds the extended
+    /// section header indexes for the symbols in the `.symtab`. These can be resolved with
+    /// [SymbolTable::get_with_shndx].
     ///
-    /// This is a GNU extension and not all objects use symbol versioning.
-    /// Returns an empty Option if the object does not use symbol versioning.
-    pub fn symbol_version_table(&self) -> Result<Option<SymbolVersionTable<'data, E>>, ParseError> {
-        // No sections means no GNU symbol versioning sections, which is ok
-        let shdrs = match self.section_headers() {
-            Some(shdrs) => shdrs,
+    /// This section is only present when the object has symbols defined in sections whose
+    /// index doesn't fit in st_shndx, i.e. those with st_shndx == [abi::SHN_XINDEX].
+    pub fn symbol_table_shndx(&self) -> Result<Option<SymbolShndxTable<'data, E>>, ParseError> {
+        let symtab_idx = match self
+            .iter_section_headers()
+            .position(|shdr| shdr.sh_type == abi::SHT_SYMTAB)
+        {
+            Some(idx) => idx,
             None => {
                 return Ok(None);
             }
         };
 
-        let mut versym_opt: Option<SectionHeader> = None;
-        let mut needs_opt: Option<SectionHeader> = None;
-        let mut defs_opt: Option<SectionHeader> = None;
-        // Find the GNU Symbol versioning sections (if any)
-        for shdr in shdrs.iter() {
-            if shdr.sh_type == abi::SHT_GNU_VERSYM {
-                versym_opt = Some(shdr);
-            } else if shdr.sh_type == abi::SHT_GNU_VERNEED {
-                needs_opt = Some(shdr);
-            } else if shdr.sh_type == abi::SHT_GNU_VERDEF {
-                defs_opt = Some(shdr);
-            }
-
-            // If we've found all three sections, then we're done
-            if versym_opt.is_some() && needs_opt.is_some() && defs_opt.is_some() {
-                break;
-            }
-        }
-
-        let versym_shdr = match versym_opt {
+        // The SHT_SYMTAB_SHNDX section's sh_link points back at the symtab it goes with
+        let shndx_shdr = match self.iter_section_headers().find(|shdr| {
+            shdr.sh_type == abi::SHT_SYMTAB_SHNDX && shdr.sh_link as usize == symtab_idx
+        }) {
             Some(shdr) => shdr,
-            // No VERSYM section means the object doesn't use symbol versioning, which is ok.
             None => {
                 return Ok(None);
             }
         };
 
-        // Load the versym table
-        // Validate VERSYM entsize before trying to read the table so that we can error early for corrupted files
-        VersionIndex::validate_entsize(self.ehdr.class, versym_shdr.sh_entsize.try_into()?)?;
-        let (versym_start, versym_end) = versym_shdr.get_data_range()?;
-        let version_ids = VersionIndexTable::new(
+        // Validate entsize before trying to read the table so that we can error early for corrupted files
+        let entsize = self.table_entsize::<u32>(&shndx_shdr)?;
+        let buf = self.section_bytes(&shndx_shdr)?;
+        Ok(Some(SymbolShndxTable::with_entsize(
             self.ehdr.endianness,
             self.ehdr.class,
-            self.data.get_bytes(versym_start..versym_end)?,
-        );
-
-        // Wrap the VERNEED section and strings data in an iterator and string table (if any)
-        let verneeds = match needs_opt {
-            Some(shdr) => {
-                let (start, end) = shdr.get_data_range()?;
-                let needs_buf = self.data.get_bytes(start..end)?;
-
-                let strs_shdr = shdrs.get(shdr.sh_link as usize)?;
-                let (strs_start, strs_end) = strs_shdr.get_data_range()?;
-                let strs_buf = self.data.get_bytes(strs_start..strs_end)?;
+            WordSize::U32,
+            entsize,
+            buf,
+        )))
+    }
 
-                Some((
-                    VerNeedIterator::new(
-                        self.ehdr.endianness,
-                        self.ehdr.class,
-                        shdr.sh_info as u64,
-                        0,
-                        needs_buf,
-                    ),
-                    StringTable::new(strs_buf),
-                ))
+    /// Get the index of the section that the `.symtab` symbol at the given index is defined
+    /// in, resolving [abi::SHN_XINDEX] through the [abi::SHT_SYMTAB_SHNDX] section.
+    ///
+    /// Returns `None` if there's no `.symtab`, or if the symbol's st_shndx is a reserved
+    /// value that doesn't refer to a section. See [SymbolTable::section_index].
+    pub fn symbol_section_index(&self, index: usize) -> Result<Option<u32>, ParseError> {
+        let symtab = match self.symbol_table()? {
+            Some((symtab, _)) => symtab,
+            None => {
+                return Ok(None);
             }
-            // It's possible to have symbol versioning with no NEEDs if we're an object that only
-            // exports defined symbols.
-            None => None,
+        };
+        let shndx_table = self.symbol_table_shndx()?;
+        symtab.section_index(index, shndx_table.as_ref())
+    }
+
+    /// Get the symbols defined in the given section along with their indexes in the symbol
+    /// table, sorted by st_value. Together with their st_size, this says which symbols the
+    /// section's bytes belong to.
+    ///
+    /// The symbols come from the `.symtab`, or from the `.dynsym` if there isn't one. Symbols
+    /// with st_shndx == [abi::SHN_XINDEX] are matched by their index in the
+    /// [SHT_SYMTAB_SHNDX](abi::SHT_SYMTAB_SHNDX) table.
+    ///
+    /// Returns an empty Option if the object has no symbol table, or if `shdr` isn't one of
+    /// its section headers.
+    #[cfg(feature = "alloc")]
+    pub fn symbols_in_section(
+        &self,
+        shdr: &SectionHeader,
+    ) -> Result<Option<Vec<(usize, Symbol)>>, ParseError> {
+        let mut symbols: Vec<_> = match self.section_symbols(shdr)? {
+            Some(symbols) => symbols.collect(),
+            None => return Ok(None),
+        };
+        symbols.sort_by_key(|(_, sym)| sym.st_value);
+        Ok(Some(symbols))
+    }
+
+    /// Get the symbols defined in the given section along with their indexes in the symbol
+    /// table, in table order. Together with their st_size, this says which symbols the
+    /// section's bytes belong to.
+    ///
+    /// The symbols come from the `.symtab`, or from the `.dynsym` if there isn't one. Symbols
+    /// with st_shndx == [abi::SHN_XINDEX] are matched by their index in the
+    /// [SHT_SYMTAB_SHNDX](abi::SHT_SYMTAB_SHNDX) table.
+    ///
+    /// Returns an empty Option if the object has no symbol table, or if `shdr` isn't one of
+    /// its section headers.
+    #[cfg(not(feature = "alloc"))]
+    pub fn symbols_in_section(
+        &self,
+        shdr: &SectionHeader,
+    ) -> Result<Option<SectionSymbolIterator<'data, E>>, ParseError> {
+        self.section_symbols(shdr)
+    }
+
+    fn section_symbols(
+        &self,
+        shdr: &SectionHeader,
+    ) -> Result<Option<SectionSymbolIterator<'data, E>>, ParseError> {
+        let shndx = match self.iter_section_headers().position(|other| other == *shdr) {
+            Some(shndx) => shndx as u32,
+            None => return Ok(None),
+        };
+
+        let (symtab, shndx_table) = match self.symbol_table()? {
+            Some((symtab, _)) => (symtab, self.symbol_table_shndx()?),
+            None => match self.dynamic_symbol_table()? {
+                Some((symtab, _)) => (symtab, None),
+                None => return Ok(None),
+            },
+        };
+        // Without an extended index table, the SHN_XINDEX symbols can't be resolved, but this
+        // still stops the reserved indexes from matching a section with a large index.
+        let shndx_table = shndx_table.unwrap_or_else(|| {
+            SymbolShndxTable::new(self.ehdr.endianness, self.ehdr.class, WordSize::U32, &[])
+        });
+        Ok(Some(
+            symtab.iter_for_section_with_shndx(shndx, &shndx_table),
+        ))
+    }
+
+    /// Get all the relocations that apply to the given section's data, sorted by the offset
+    /// into the section that they relocate, along with the names of their symbols. This is
+    /// handy for masking out the relocated bytes of a section before comparing or hashing it.
+    ///
+    /// The relocations come from every [abi::SHT_REL] and [abi::SHT_RELA] section whose
+    /// sh_info refers to `target`, so a section with both a `.rel` and a `.rela` section gets
+    /// the relocations of both. [Rel](crate::relocation::Rel)s are converted with
+    /// [rel_to_rela], which fails for compressed targets with
+    /// [ParseError::UnsupportedCompression]. Relocations at the same offset keep the order of
+    /// their sections and their order within them.
+    ///
+    /// Each relocation is flagged if its field overlaps with another's. The size of a field
+    /// is taken from [implicit_addend_bits] when it's known, and otherwise only relocations
+    /// at the same offset are considered to overlap.
+    ///
+    /// The offsets of relocatable objects' relocations are already section offsets, while
+    /// those of other objects are addresses that have the target's sh_addr subtracted.
+    /// Returns an empty Vec if `target` isn't one of the object's section headers.
+    #[cfg(feature = "alloc")]
+    pub fn relocation_map_for(
+        &self,
+        target: &SectionHeader,
+    ) -> Result<Vec<SectionRelocation<'data>>, ParseError> {
+        let (shdrs, shstrtab) = match self.section_headers_with_strtab()? {
+            (Some(shdrs), shstrtab) => (shdrs, shstrtab),
+            (None, _) => return Ok(Vec::new()),
+        };
+        let target_index = match self.iter_section_headers().position(|shdr| shdr == *target) {
+            Some(index) => index,
+            None => return Ok(Vec::new()),
+        };
+        let base = match self.ehdr.e_type {
+            abi::ET_REL => 0,
+            _ => target.sh_addr,
+        };
+
+        let mut map = Vec::new();
+        for shdr in shdrs.iter() {
+            if !matches!(shdr.sh_type, abi::SHT_REL | abi::SHT_RELA)
+                || shdr.sh_info as usize != target_index
+            {
+                continue;
+            }
+
+            // Relocations against absolute values only might not have any symbol table
+            let symbols = match shdr.sh_link {
+                0 => None,
+                _ => {
+                    let symtab_shdr = self.linked_section_header(&shdrs, &shdr)?;
+                    let strtab_shdr = self.linked_section_header(&shdrs, &symtab_shdr)?;
+                    Some(self.section_data_as_symbol_table(&symtab_shdr, &strtab_shdr)?)
+                }
+            };
+
+            let relas: Vec<Rela> = if shdr.sh_type == abi::SHT_RELA {
+                self.section_data_as_relas(&shdr)?.collect()
+            } else {
+                let data = match self.section_data(target)? {
+                    (data, None) => data,
+                    (_, Some(chdr)) => {
+                        return Err(ParseError::UnsupportedCompression(chdr.ch_type))
+                    }
+                };
+                let (endian, e_machine) = (self.ehdr.endianness, self.ehdr.e_machine);
+                self.section_data_as_rels(&shdr)?
+                    .map(|rel| rel_to_rela(&rel, endian, e_machine, data, base))
+                    .collect::<Result<_, _>>()?
+            };
+
+            for rela in relas {
+                let offset = rela
+                    .r_offset
+                    .checked_sub(base)
+                    .ok_or(ParseError::BadOffset(rela.r_offset))?;
+                let symbol_name = match (&symbols, rela.r_sym) {
+                    (None, _) | (_, 0) => None,
+                    (Some((symtab, strtab)), r_sym) => {
+                        let sym = symtab.get(r_sym as usize)?;
+                        match (sym.st_symtype(), sym.shndx().section(), &shstrtab) {
+                            // Section symbols are named after their section, like readelf does
+                            (abi::STT_SECTION, Some(shndx), Some(shstrtab)) if sym.st_name == 0 => {
+                                let section = self.section_header_at(&shdrs, shndx as usize)?;
+                                Some(shstrtab.get(section.sh_name as usize)?)
+                            }
+                            _ => Some(strtab.get(sym.st_name as usize)?),
+                        }
+                    }
+                };
+                map.push(SectionRelocation {
+                    offset,
+                    rela,
+                    symbol_name,
+                    overlaps: false,
+                });
+            }
+        }
+        map.sort_by_key(|reloc| reloc.offset);
+
+        // With the map sorted by offset, a field overlaps one before it if it starts before
+        // the furthest end of theirs, and one after it if the next field starts before its end.
+        let e_machine = self.ehdr.e_machine;
+        let field_end = |reloc: &SectionRelocation<'_>| {
+            let bits = implicit_addend_bits(e_machine, reloc.rela.r_type).unwrap_or(0);
+            reloc.offset.saturating_add(((bits as u64 + 7) / 8).max(1))
+        };
+        let mut furthest_end = 0;
+        for index in 0..map.len() {
+            let end = field_end(&map[index]);
+            let overlaps_next = map.get(index + 1).map_or(false, |next| next.offset < end);
+            map[index].overlaps = (index > 0 && map[index].offset < furthest_end) || overlaps_next;
+            furthest_end = furthest_end.max(end);
+        }
+        Ok(map)
+    }
+
+    /// Look up a symbol by name, returning the `sh_type` of the table it was found in
+    /// ([abi::SHT_DYNSYM] or [abi::SHT_SYMTAB]) alongside its index and parsed [Symbol].
+    ///
+    /// The `.dynsym` is searched first, using the `.gnu.hash` table if present, then the
+    /// `.hash` table, and otherwise by a linear scan. If the symbol isn't found there, then
+    /// the `.symtab` (if any) is searched with a linear scan.
+    pub fn symbol_by_name(&self, name: &[u8]) -> Result<Option<(u32, usize, Symbol)>, ParseError> {
+        let common = self.find_common_data()?;
+
+        if let (Some(dynsyms), Some(dynsyms_strs)) = (&common.dynsyms, &common.dynsyms_strs) {
+            let found = if let Some(gnu_hash) = &common.gnu_hash {
+                gnu_hash.find(name, dynsyms, dynsyms_strs)?
+            } else if let Some(sysv_hash) = &common.sysv_hash {
+                sysv_hash.find(name, dynsyms, dynsyms_strs)?
+            } else {
+                find_symbol_linear(name, dynsyms, dynsyms_strs)?
+            };
+
+            if let Some((idx, sym)) = found {
+                return Ok(Some((abi::SHT_DYNSYM, idx, sym)));
+            }
+        }
+
+        if let (Some(symtab), Some(symtab_strs)) = (&common.symtab, &common.symtab_strs) {
+            if let Some((idx, sym)) = find_symbol_linear(name, symtab, symtab_strs)? {
+                return Ok(Some((abi::SHT_SYMTAB, idx, sym)));
+            }
+        }
+
+        Ok(None)
+    }
+
+    /// Build a [Symbolizer] for resolving addresses to the function and object symbols that
+    /// contain them. The `.symtab` is preferred as it is the more complete table, and the
+    /// `.dynsym` is used if the object has been stripped.
+    ///
+    /// The `load_bias` is subtracted from the addresses passed to [Symbolizer::symbolize].
+    /// Use 0 for [abi::ET_EXEC] files or when looking up unrelocated addresses.
+    #[cfg(feature = "alloc")]
+    pub fn symbolizer(&self, load_bias: u64) -> Result<Option<Symbolizer<'data>>, ParseError> {
+        let symtab = match self.symbol_table()? {
+            Some(symtab) => Some(symtab),
+            None => self.dynamic_symbol_table()?,
+        };
+
+        match symtab {
+            Some((symtab, strtab)) => Ok(Some(Symbolizer::new(&symtab, &strtab, load_bias)?)),
+            None => Ok(None),
+        }
+    }
+
+    /// Get the symbols that this object makes available to others, which are the `.dynsym`
+    /// symbols that are defined, not [abi::STB_LOCAL], and not [abi::STV_HIDDEN] or
+    /// [abi::STV_INTERNAL]. Each is joined with the symbol version it defines, if the object
+    /// uses GNU symbol versioning.
+    ///
+    /// Note that [abi::STT_GNU_IFUNC] symbols are exported with the address of their resolver
+    /// function, not the final implementation that the resolver selects at load time.
+    #[cfg(feature = "alloc")]
+    pub fn exports(&self) -> Result<Vec<SymbolExport<'data>>, ParseError> {
+        let (dynsyms, strtab) = match self.dynamic_symbol_table()? {
+            Some(tables) => tables,
+            None => {
+                return Ok(Vec::new());
+            }
+        };
+        let versions = self.symbol_version_table()?;
+
+        let mut exports = Vec::new();
+        for (index, symbol) in dynsyms.iter().enumerate() {
+            if symbol.is_undefined()
+                || symbol.st_bind() == abi::STB_LOCAL
+                || matches!(symbol.st_vis(), abi::STV_HIDDEN | abi::STV_INTERNAL)
+            {
+                continue;
+            }
+
+            let name = strtab.get(symbol.st_name as usize)?;
+            let mut version = None;
+            let mut hidden = false;
+            if let Some(versions) = &versions {
+                if let Some(mut def) = versions.get_definition(index)? {
+                    // The base definition names the object itself rather than a version
+                    if def.flags & abi::VER_FLG_BASE == 0 {
+                        version = def.names.next().transpose()?;
+                        hidden = def.hidden;
+                    }
+                }
+            }
+
+            exports.push(SymbolExport {
+                index,
+                symbol,
+                name,
+                version,
+                hidden,
+            });
+        }
+        Ok(exports)
+    }
+
+    /// Get the symbols that this object requires from others, which are the undefined
+    /// `.dynsym` symbols that are not [abi::STB_LOCAL]. Each is joined with the symbol version
+    /// it requires and the needed library that provides the version, if the object uses
+    /// GNU symbol versioning.
+    ///
+    /// Weak imports are optional, and resolve to 0 if nothing defines them. See [SymbolImport::is_optional].
+    #[cfg(feature = "alloc")]
+    pub fn imports(&self) -> Result<Vec<SymbolImport<'data>>, ParseError> {
+        let (dynsyms, strtab) = match self.dynamic_symbol_table()? {
+            Some(tables) => tables,
+            None => {
+                return Ok(Vec::new());
+            }
+        };
+        let versions = self.symbol_version_table()?;
+
+        let mut imports = Vec::new();
+        for (index, symbol) in dynsyms.iter().enumerate() {
+            if !symbol.is_undefined() || symbol.st_bind() == abi::STB_LOCAL {
+                continue;
+            }
+
+            let name = strtab.get(symbol.st_name as usize)?;
+            let (version, library) = match &versions {
+                Some(versions) => match versions.get_requirement(index)? {
+                    Some(req) => (Some(req.name), Some(req.file)),
+                    None => (None, None),
+                },
+                None => (None, None),
+            };
+
+            imports.push(SymbolImport {
+                index,
+                symbol,
+                name,
+                version,
+                library,
+            });
+        }
+        Ok(imports)
+    }
+
+    /// Locate the section data for the various GNU Symbol Versioning sections (if any)
+    /// and return them in a [SymbolVersionTable] that which can interpret them in-place to
+    /// yield [SymbolRequirement](crate::gnu_symver::SymbolRequirement)s
+    /// and [SymbolDefinition](crate::gnu_symver::SymbolDefinition)s
+    ///
+    /// This is a GNU extension and not all objects use symbol versioning.
+    /// Returns an empty Option if the object does not use symbol versioning.
+    ///
+    /// For images parsed from memory with [ElfBytes::parse_loaded] and files without section
+    /// headers, the tables are found through the dynamic section's `DT_VERSYM`, `DT_VERNEED`
+    /// and `DT_VERDEF` entries, and the `DT_VERNEEDNUM` and `DT_VERDEFNUM` counts bound how
+    /// many entries are read from each chain.
+    pub fn symbol_version_table(&self) -> Result<Option<SymbolVersionTable<'data, E>>, ParseError> {
+        if self.uses_dynamic_addrs() {
+            return self.dynamic_symbol_version_table();
+        }
+
+        // No sections means no GNU symbol versioning sections, which is ok
+        let shdrs = match self.section_headers() {
+            Some(shdrs) => shdrs,
+            None => {
+                return Ok(None);
+            }
+        };
+
+        let mut versym_opt: Option<SectionHeader> = None;
+        let mut needs_opt: Option<SectionHeader> = None;
+        let mut defs_opt: Option<SectionHeader> = None;
+        // Find the GNU Symbol versioning sections (if any)
+        for shdr in shdrs.iter() {
+            if shdr.sh_type == abi::SHT_GNU_VERSYM {
+                versym_opt = Some(shdr);
+            } else if shdr.sh_type == abi::SHT_GNU_VERNEED {
+                needs_opt = Some(shdr);
+            } else if shdr.sh_type == abi::SHT_GNU_VERDEF {
+                defs_opt = Some(shdr);
+            }
+
+            // If we've found all three sections, then we're done
+            if versym_opt.is_some() && needs_opt.is_some() && defs_opt.is_some() {
+                break;
+            }
+        }
+
+        let versym_shdr = match versym_opt {
+            Some(shdr) => shdr,
+            // No VERSYM section means the object doesn't use symbol versioning, which is ok.
+            None => {
+                return Ok(None);
+            }
+        };
+
+        // Load the versym table
+        // Validate VERSYM entsize before trying to read the table so that we can error early for corrupted files
+        let entsize = self.table_entsize::<VersionIndex>(&versym_shdr)?;
+        let version_ids = VersionIndexTable::with_entsize(
+            self.ehdr.endianness,
+            self.ehdr.class,
+            entsize,
+            self.section_bytes(&versym_shdr)?,
+        );
+
+        // Wrap the VERNEED section and strings data in an iterator and string table (if any)
+        let verneeds = match needs_opt {
+            Some(shdr) => {
+                let needs_buf = self.section_bytes(&shdr)?;
+
+                let strs_shdr = shdrs.get(shdr.sh_link as usize)?;
+                let strs_buf = self.section_bytes(&strs_shdr)?;
+
+                self.check_entry_count::<VerNeed>(shdr.sh_info as u64)?;
+                Some((
+                    VerNeedIterator::new(
+                        self.ehdr.endianness,
+                        self.ehdr.class,
+                        shdr.sh_info as u64,
+                        0,
+                        needs_buf,
+                    ),
+                    StringTable::new(strs_buf),
+                ))
+            }
+            // It's possible to have symbol versioning with no NEEDs if we're an object that only
+            // exports defined symbols.
+            None => None,
         };
 
         // Wrap the VERDEF section and strings data in an iterator and string table (if any)
         let verdefs = match defs_opt {
             Some(shdr) => {
-                let (start, end) = shdr.get_data_range()?;
-                let defs_buf = self.data.get_bytes(start..end)?;
+                let defs_buf = self.section_bytes(&shdr)?;
 
                 let strs_shdr = shdrs.get(shdr.sh_link as usize)?;
-                let (strs_start, strs_end) = strs_shdr.get_data_range()?;
-                let strs_buf = self.data.get_bytes(strs_start..strs_end)?;
+                let strs_buf = self.section_bytes(&strs_shdr)?;
 
+                self.check_entry_count::<VerDef>(shdr.sh_info as u64)?;
                 Some((
                     VerDefIterator::new(
                         self.ehdr.endianness,
@@ -816,6 +3777,116 @@ impl<'data, E: EndianParse> ElfBytes<'data, E> {
             verdefs,
         )))
     }
+
+    /// Internal helper to find the GNU symbol versioning tables through the dynamic section.
+    fn dynamic_symbol_version_table(
+        &self,
+    ) -> Result<Option<SymbolVersionTable<'data, E>>, ParseError> {
+        let addrs = match self.dynamic_addrs()? {
+            Some(addrs) => addrs,
+            None => return Ok(None),
+        };
+        let versym = match addrs.versym {
+            Some(versym) => versym,
+            None => return Ok(None),
+        };
+        let (dynsyms, strtab) = match self.dynamic_symbol_table()? {
+            Some(tables) => tables,
+            None => return Ok(None),
+        };
+        let (endian, class) = (self.ehdr.endianness, self.ehdr.class);
+
+        // There's one version index for each dynamic symbol
+        let size = (dynsyms.len() as u64)
+            .checked_mul(VersionIndex::size_for(class) as u64)
+            .ok_or(ParseError::IntegerOverflow)?;
+        let version_ids = VersionIndexTable::new(
+            endian,
+            class,
+            self.dynamic_addr_data(versym, Some(size)).context(
+                "symbol version table",
+                None,
+                versym,
+            )?,
+        );
+
+        let verneeds = match (addrs.verneed, addrs.verneednum) {
+            (Some(addr), Some(count)) => {
+                let buf = self.dynamic_addr_data(addr, None).context(
+                    "version requirements",
+                    None,
+                    addr,
+                )?;
+                self.check_entry_count::<VerNeed>(count)?;
+                Some((VerNeedIterator::new(endian, class, count, 0, buf), strtab))
+            }
+            _ => None,
+        };
+        let verdefs = match (addrs.verdef, addrs.verdefnum) {
+            (Some(addr), Some(count)) => {
+                let buf = self.dynamic_addr_data(addr, None).context(
+                    "version definitions",
+                    None,
+                    addr,
+                )?;
+                self.check_entry_count::<VerDef>(count)?;
+                Some((VerDefIterator::new(endian, class, count, 0, buf), strtab))
+            }
+            _ => None,
+        };
+
+        Ok(Some(SymbolVersionTable::new(
+            version_ids,
+            verneeds,
+            verdefs,
+        )))
+    }
+
+    /// Get the dynamic relocations that the dynamic linker applies, as found through the
+    /// dynamic section's `DT_REL`, `DT_RELA` and `DT_JMPREL` entries.
+    ///
+    /// This works for both files and images parsed from memory with
+    /// [ElfBytes::parse_loaded]. Returns an empty Option if there's no dynamic section.
+    pub fn dynamic_relocations(&self) -> Result<Option<DynamicRelocations<'data, E>>, ParseError> {
+        let addrs = match self.dynamic_addrs()? {
+            Some(addrs) => addrs,
+            None => return Ok(None),
+        };
+        let (endian, class) = (self.ehdr.endianness, self.ehdr.class);
+        let mut relocations = DynamicRelocations::default();
+
+        if let (Some(addr), Some(size)) = (addrs.rel, addrs.relsz) {
+            let buf = self.dynamic_addr_data(addr, Some(size)).context(
+                "dynamic relocations",
+                None,
+                addr,
+            )?;
+            relocations.rel = Some(RelIterator::new(endian, class, buf));
+        }
+        if let (Some(addr), Some(size)) = (addrs.rela, addrs.relasz) {
+            let buf = self.dynamic_addr_data(addr, Some(size)).context(
+                "dynamic relocations",
+                None,
+                addr,
+            )?;
+            relocations.rela = Some(RelaIterator::new(endian, class, buf));
+        }
+        if let (Some(addr), Some(size)) = (addrs.jmprel, addrs.pltrelsz) {
+            let buf =
+                self.dynamic_addr_data(addr, Some(size))
+                    .context("PLT relocations", None, addr)?;
+            match addrs.pltrel.map(|pltrel| pltrel as i64) {
+                Some(abi::DT_REL) => {
+                    relocations.plt_rel = Some(RelIterator::new(endian, class, buf))
+                }
+                Some(abi::DT_RELA) => {
+                    relocations.plt_rela = Some(RelaIterator::new(endian, class, buf))
+                }
+                _ => return Err(ParseError::BadOffset(addrs.pltrel.unwrap_or(0))),
+            }
+        }
+        Ok(Some(relocations))
+    }
 }
 
 //  _            _
@@ -998,6 +4069,50 @@ mod interface_tests {
         assert_eq!(shdr.sh_type, abi::SHT_STRTAB);
     }
 
+    #[test]
+    fn shdr0() {
+        // The usual all-zero form
+        let file_data = std::fs::read("sample-objects/basic.x86_64").expect("Could not read file.");
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("should parse");
+        let shdr0 = file
+            .shdr0()
+            .expect("should parse")
+            .expect("should have shdrs");
+        assert_eq!(shdr0, SectionHeader::default());
+        assert_eq!(file.section_headers().unwrap().iter().next(), Some(shdr0));
+
+        // The extended form holding e_shnum and e_shstrndx
+        let file_data = std::fs::read("sample-objects/shnum.x86_64").expect("Could not read file.");
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("should parse");
+        assert_eq!(file.ehdr.e_shnum, 0);
+        assert_eq!(file.ehdr.e_shstrndx, abi::SHN_XINDEX);
+        let shdr0 = file
+            .shdr0()
+            .expect("should parse")
+            .expect("should have shdrs");
+        assert_eq!(shdr0.sh_size, 0xFF15);
+        assert_eq!(shdr0.sh_link, 0xFF14);
+        assert_eq!(
+            SectionHeader {
+                sh_size: 0,
+                sh_link: 0,
+                ..shdr0
+            },
+            SectionHeader::default()
+        );
+        assert!(!file.validate().iter().any(|issue| matches!(
+            issue.kind,
+            crate::validate::IssueKind::NonzeroShdr0Field { .. }
+        )));
+
+        // Header 0 has an empty name, but it isn't a section that can be looked up
+        let (shdrs, strtab) = file.section_headers_with_strtab().expect("should parse");
+        assert_eq!(
+            shdrs.unwrap().iter_named(&strtab.unwrap(), b"").next(),
+            None
+        );
+    }
+
     #[test]
     fn section_header_by_name() {
         let path = std::path::PathBuf::from("sample-objects/basic.x86_64");
@@ -1020,51 +4135,665 @@ mod interface_tests {
     }
 
     #[test]
-    fn find_common_data() {
-        let path = std::path::PathBuf::from("sample-objects/symver.x86_64.so");
-        let file_data = std::fs::read(path).expect("Could not read file.");
-        let slice = file_data.as_slice();
-        let file = ElfBytes::<AnyEndian>::minimal_parse(slice).expect("Open test1");
-
-        let elf_scns = file.find_common_data().expect("file should parse");
+    fn duplicate_section_names() {
+        let mut builder = crate::builder::ElfBuilder::new(
+            crate::endian::LittleEndian,
+            Class::ELF64,
+            abi::ET_REL,
+            abi::EM_X86_64,
+        );
+        let note = SectionHeader {
+            sh_type: abi::SHT_NOTE,
+            sh_addralign: 4,
+            ..Default::default()
+        };
+        builder.add_section(".note", note, &[]);
+        builder.add_section(".text", SectionHeader::default(), &[]);
+        builder.add_section(".note", note, &[]);
+        let data = builder.build().expect("should build");
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&data).expect("should parse");
 
-        // hello.so should find everything
-        assert!(elf_scns.symtab.is_some());
-        assert!(elf_scns.symtab_strs.is_some());
-        assert!(elf_scns.dynsyms.is_some());
-        assert!(elf_scns.dynsyms_strs.is_some());
-        assert!(elf_scns.dynamic.is_some());
-        assert!(elf_scns.sysv_hash.is_some());
-        assert!(elf_scns.gnu_hash.is_some());
+        // The single-match lookup returns the first one
+        let shdr = file
+            .section_header_by_name(".note")
+            .expect("should parse")
+            .expect("should find .note");
+        assert_eq!(shdr, file.section_headers().unwrap().get(1).unwrap());
+
+        let indexes: Vec<usize> = file
+            .section_headers_by_name(".note")
+            .expect("should parse")
+            .map(|(index, shdr)| {
+                assert_eq!(shdr.sh_type, abi::SHT_NOTE);
+                index
+            })
+            .collect();
+        assert_eq!(indexes, [1, 3]);
+        assert_eq!(
+            file.section_headers_by_name(".missing")
+                .expect("should parse")
+                .count(),
+            0
+        );
     }
 
     #[test]
-    fn section_data() {
+    fn segments_by_type() {
         let path = std::path::PathBuf::from("sample-objects/basic.x86_64");
         let file_data = std::fs::read(path).expect("Could not read file.");
-        let slice = file_data.as_slice();
-        let file = ElfBytes::<AnyEndian>::minimal_parse(slice).expect("Open test1");
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("Open test1");
+
+        let loads: Vec<ProgramHeader> = file.segments_by_type(abi::PT_LOAD).collect();
+        assert_eq!(loads.len(), 2);
+        assert_eq!(file.segment_by_type(abi::PT_LOAD), Some(loads[0]));
+        assert!(loads[0].p_vaddr < loads[1].p_vaddr);
+        assert_eq!(file.segment_by_type(abi::PT_TLS), None);
+
+        // Drop the program headers
+        let mut file_data = file_data.clone();
+        file_data[0x20..0x28].fill(0);
+        file_data[0x38..0x3a].fill(0);
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("should parse");
+        assert!(file.segments().is_none());
+        assert_eq!(file.segments_by_type(abi::PT_LOAD).count(), 0);
+    }
 
-        let shdr = file
-            .section_headers()
-            .expect("File should have section table")
-            .get(26)
-            .expect("shdr should be parsable");
+    #[test]
+    fn phdr_segment_and_ehdr_load_bias() {
+        let file_data = std::fs::read("sample-objects/basic.x86_64").expect("Could not read file.");
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("should parse");
+        let phdr = file.phdr_segment().expect("should have PT_PHDR");
+        assert_eq!(phdr.p_offset, file.ehdr.e_phoff);
+        assert_eq!(phdr.p_vaddr, 0x400040);
+        // A fixed-address executable is linked to load at 0x400000
+        assert_eq!(file.ehdr_load_bias(), Some(0x400000));
+
+        // Shared objects don't need PT_PHDR and are linked to load at 0
+        let file_data =
+            std::fs::read("sample-objects/symver.x86_64.so").expect("Could not read file.");
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("should parse");
+        assert_eq!(file.phdr_segment(), None);
+        assert_eq!(file.ehdr_load_bias(), Some(0));
+
+        // Relocatable objects don't have any segments
+        let file_data =
+            std::fs::read("sample-objects/stack_sizes.x86_64.o").expect("Could not read file.");
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("should parse");
+        assert_eq!(file.phdr_segment(), None);
+        assert_eq!(file.ehdr_load_bias(), None);
+    }
 
-        assert_eq!(shdr.sh_type, SHT_NOBITS);
+    fn build_tls_object(tls_segments: usize) -> Vec<u8> {
+        use crate::builder::ElfBuilder;
+        use crate::endian::LittleEndian;
+
+        let mut builder = ElfBuilder::new(LittleEndian, Class::ELF64, abi::ET_EXEC, abi::EM_X86_64);
+        let tdata = builder.add_section(
+            ".tdata",
+            SectionHeader {
+                sh_type: abi::SHT_PROGBITS,
+                sh_flags: (abi::SHF_ALLOC | abi::SHF_WRITE | abi::SHF_TLS) as u64,
+                sh_addr: 0x1000,
+                sh_addralign: 8,
+                ..Default::default()
+            },
+            &[1, 2, 3, 4, 5, 6, 7, 8],
+        );
+        for _ in 0..tls_segments {
+            builder.add_segment(
+                ProgramHeader {
+                    p_type: abi::PT_TLS,
+                    p_flags: abi::PF_R,
+                    p_vaddr: 0x1000,
+                    p_paddr: 0x1000,
+                    p_memsz: 0x18,
+                    p_align: 8,
+                    ..Default::default()
+                },
+                Some(tdata),
+            );
+        }
+        // A TLS variable in .tbss whose offset happens to look like a .text address
+        builder.add_symbol(
+            "counter",
+            Symbol {
+                st_info: (abi::STB_GLOBAL << 4) | abi::STT_TLS,
+                st_shndx: tdata as u16,
+                st_value: 0x10,
+                st_size: 8,
+                ..Default::default()
+            },
+        );
+        builder.build().expect("should build")
+    }
 
-        let (data, chdr) = file
-            .section_data(&shdr)
-            .expect("Failed to get section data");
+    #[test]
+    fn tls_segment() {
+        let data = build_tls_object(1);
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&data).expect("should parse");
+        let tls = file
+            .tls_segment()
+            .expect("should parse")
+            .expect("should have PT_TLS");
+        assert_eq!(tls.template.end - tls.template.start, 8);
+        assert_eq!(tls.memsz, 0x18);
+        assert_eq!(tls.align, 8);
+        assert_eq!(tls.zero_fill_size(), 0x10);
+        assert_eq!(
+            file.segment_data(&tls.phdr).expect("should read"),
+            &[1, 2, 3, 4, 5, 6, 7, 8]
+        );
 
-        assert_eq!(chdr, None);
-        assert_eq!(data, &[]);
+        let (symtab, _) = file.symbol_table().expect("should parse").unwrap();
+        let sym = symtab.get(1).expect("should parse");
+        assert!(sym.is_tls());
+        assert_eq!(sym.tls_offset(), Some(0x10));
+        // The offset isn't an address, so the symbolizer doesn't resolve anything to it
+        let symbolizer = file.symbolizer(0).expect("should parse").unwrap();
+        assert!(symbolizer.is_empty());
+        assert!(symbolizer.symbolize(0x10).is_none());
+
+        let file_data = std::fs::read("sample-objects/basic.x86_64").expect("Could not read file.");
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("should parse");
+        assert_eq!(file.tls_segment().expect("should parse"), None);
     }
 
-    // Test all the different section_data_as* with a section of the wrong type
     #[test]
-    fn section_data_as_wrong_type() {
-        let path = std::path::PathBuf::from("sample-objects/basic.x86_64");
+    fn multiple_tls_segments_are_invalid() {
+        use crate::validate::{IssueKind, Location, Severity, ValidationIssue};
+
+        let data = build_tls_object(2);
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&data).expect("should parse");
+        assert!(file.validate().contains(&ValidationIssue {
+            severity: Severity::Error,
+            location: Location::ProgramHeader(1),
+            kind: IssueKind::MultipleTls(0),
+        }));
+
+        let data = build_tls_object(1);
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&data).expect("should parse");
+        assert!(!file
+            .validate()
+            .iter()
+            .any(|issue| matches!(issue.kind, IssueKind::MultipleTls(_))));
+    }
+
+    #[test]
+    fn dynamic_strings_without_sections() {
+        let mut file_data =
+            std::fs::read("sample-objects/patch.x86_64.so").expect("Could not read file.");
+        for with_shdrs in [true, false] {
+            if !with_shdrs {
+                // Like sstrip, drop the section headers
+                file_data[0x28..0x30].fill(0);
+                file_data[0x3c..0x40].fill(0);
+            }
+            let file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("should parse");
+            assert_eq!(file.section_headers().is_some(), with_shdrs);
+
+            let strtab = file
+                .dynamic_string_table()
+                .expect("should parse")
+                .expect("should have a .dynstr");
+            assert_eq!(strtab.get(0xc).expect("should have a string"), "libc.so.6");
+            assert_eq!(
+                file.needed_libraries().expect("should parse"),
+                vec!["libc.so.6"]
+            );
+            assert_eq!(file.soname().expect("should parse"), Some("libpatch.so.1"));
+
+            let (symtab, strtab) = file
+                .dynamic_symbol_table()
+                .expect("should parse")
+                .expect("should have a .dynsym");
+            let names: Vec<_> = symtab
+                .iter()
+                .map(|sym| {
+                    strtab
+                        .get(sym.st_name as usize)
+                        .expect("should have a name")
+                })
+                .collect();
+            assert!(names.contains(&"puts"), "{names:?}");
+        }
+
+        // Objects without a dynamic section don't have dynamic strings
+        let file_data = std::fs::read("sample-objects/static.x86_64").expect("should read");
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("should parse");
+        assert!(file.dynamic_string_table().expect("should parse").is_none());
+        assert!(file.needed_libraries().expect("should parse").is_empty());
+        assert_eq!(file.soname().expect("should parse"), None);
+    }
+
+    #[test]
+    fn audit_libraries() {
+        let mut file_data =
+            std::fs::read("sample-objects/patch.x86_64.so").expect("Could not read file.");
+        let (dynamic_offset, dynstr_offset) = {
+            let file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("should parse");
+            let dynamic = file
+                .section_header_by_name(".dynamic")
+                .expect("should parse");
+            let dynstr = file
+                .section_header_by_name(".dynstr")
+                .expect("should parse");
+            (
+                dynamic.expect("should have .dynamic").sh_offset as usize,
+                dynstr.expect("should have .dynstr").sh_offset as usize,
+            )
+        };
+        // Turn the DT_NEEDED into a DT_AUDIT of two libraries, and the DT_SONAME into a
+        // DT_DEPAUDIT
+        file_data[dynstr_offset + 0xc..][..9].copy_from_slice(b"a.so::b.s");
+        for index in 0.. {
+            let offset = dynamic_offset + 16 * index;
+            let d_tag = i64::from_le_bytes(file_data[offset..offset + 8].try_into().unwrap());
+            let d_tag = match d_tag {
+                abi::DT_NULL => break,
+                abi::DT_NEEDED => abi::DT_AUDIT,
+                abi::DT_SONAME => abi::DT_DEPAUDIT,
+                _ => continue,
+            };
+            file_data[offset..offset + 8].copy_from_slice(&d_tag.to_le_bytes());
+        }
+
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("should parse");
+        assert!(file.needed_libraries().expect("should parse").is_empty());
+        assert_eq!(
+            file.audit_libraries().expect("should parse"),
+            vec!["a.so", "b.s"]
+        );
+        assert_eq!(
+            file.depaudit_libraries().expect("should parse"),
+            vec!["libpatch.so.1"]
+        );
+        assert_eq!(file.soname().expect("should parse"), None);
+    }
+
+    #[test]
+    fn segment_with_range() {
+        use crate::endian::LittleEndian;
+        use crate::parse::SerializeAt;
+
+        let path = std::path::PathBuf::from("sample-objects/basic.x86_64");
+        let file_data = std::fs::read(path).expect("Could not read file.");
+        let slice = file_data.as_slice();
+        let file = ElfBytes::<AnyEndian>::minimal_parse(slice).expect("Open test1");
+
+        for (index, phdr) in file.segments().unwrap().iter().enumerate() {
+            let (parsed, range) = file.segment_with_range(index).expect("should parse");
+            assert_eq!(parsed, phdr);
+            assert_eq!(range, 64 + index * 56..64 + (index + 1) * 56);
+        }
+        assert!(file.segment_with_range(8).is_err());
+
+        // Overwriting the header's bytes is seen by the next parse
+        let (mut phdr, range) = file.segment_with_range(2).expect("should parse");
+        phdr.p_flags |= abi::PF_X;
+        let mut patched = file_data.clone();
+        phdr.serialize_at(
+            LittleEndian,
+            Class::ELF64,
+            &mut range.start.clone(),
+            &mut patched,
+        )
+        .expect("should serialize");
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&patched).expect("should parse");
+        assert_eq!(file.segments().unwrap().get(2).unwrap(), phdr);
+    }
+
+    #[test]
+    fn relocation_map_for() {
+        let file_data =
+            std::fs::read("sample-objects/debug_gz.x86_64.o").expect("Could not read file.");
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("should parse");
+        let shdr = file
+            .section_header_by_name(".debug_info")
+            .expect("should parse")
+            .expect("should find .debug_info");
+        let map = file.relocation_map_for(&shdr).expect("should parse");
+        let entries: Vec<_> = map
+            .iter()
+            .map(|reloc| {
+                (
+                    reloc.offset,
+                    reloc.rela.r_addend,
+                    reloc.symbol_name.unwrap(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            entries,
+            vec![
+                (0x08, 0, ".debug_abbrev"),
+                (0x0d, 0, ".debug_str"),
+                (0x12, 0, ".debug_line_str"),
+                (0x16, 0xb, ".debug_line_str"),
+                (0x1a, 0, ".text"),
+                (0x2a, 0, ".debug_line"),
+                (0x2f, 0x59, ".debug_str"),
+                (0x36, 0, ".text"),
+            ]
+        );
+        assert!(map.iter().all(|reloc| !reloc.overlaps));
+
+        // Each .stack_sizes only gets the relocations whose sh_info refers to it
+        let file_data =
+            std::fs::read("sample-objects/stack_sizes.x86_64.o").expect("Could not read file.");
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("should parse");
+        let names: Vec<_> = file
+            .section_headers_by_name(".stack_sizes")
+            .expect("should parse")
+            .map(|(_, shdr)| {
+                let map = file.relocation_map_for(&shdr).expect("should parse");
+                assert_eq!(map.len(), 1);
+                map[0].symbol_name.unwrap()
+            })
+            .collect();
+        assert_eq!(names, vec![".text.small", ".text.big"]);
+
+        let (shdrs, _) = file.section_headers_with_strtab().expect("should parse");
+        let symtab = shdrs.unwrap().get(10).expect("should parse");
+        assert!(file
+            .relocation_map_for(&symtab)
+            .expect("should parse")
+            .is_empty());
+    }
+
+    #[test]
+    fn relocation_map_for_rel_and_rela() {
+        use crate::builder::ElfBuilder;
+        use crate::endian::LittleEndian;
+        use crate::relocation::{build_rel_section, build_rela_section, Rel};
+
+        let mut builder = ElfBuilder::new(LittleEndian, Class::ELF32, abi::ET_REL, abi::EM_386);
+        let text = builder.add_section(
+            ".text",
+            SectionHeader {
+                sh_type: abi::SHT_PROGBITS,
+                sh_flags: (abi::SHF_ALLOC | abi::SHF_EXECINSTR) as u64,
+                sh_addralign: 4,
+                ..Default::default()
+            },
+            &[0x10, 0x00, 0xf0, 0xff, 0xff, 0xff, 0x00, 0x00],
+        );
+        // Two overlapping relocations with implicit addends, and one with an explicit addend
+        let rel = |r_offset, r_type| Rel {
+            r_offset,
+            r_sym: 0,
+            r_type,
+        };
+        let rels = build_rel_section(
+            LittleEndian,
+            Class::ELF32,
+            &[rel(2, abi::R_386_PC32), rel(0, abi::R_386_32)],
+        )
+        .expect("should build");
+        let relas = build_rela_section(
+            LittleEndian,
+            Class::ELF32,
+            &[Rela {
+                r_offset: 6,
+                r_sym: 0,
+                r_type: abi::R_386_16,
+                r_addend: 5,
+            }],
+        )
+        .expect("should build");
+        for (name, sh_type, data) in [
+            (".rela.text", abi::SHT_RELA, relas),
+            (".rel.text", abi::SHT_REL, rels),
+        ] {
+            builder.add_section(
+                name,
+                SectionHeader {
+                    sh_type,
+                    sh_flags: abi::SHF_INFO_LINK as u64,
+                    sh_info: text as u32,
+                    sh_addralign: 4,
+                    sh_entsize: data.entsize,
+                    ..Default::default()
+                },
+                &data.data,
+            );
+        }
+        let data = builder.build().expect("should build");
+
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&data).expect("should parse");
+        let shdr = file
+            .section_header_by_name(".text")
+            .expect("should parse")
+            .expect("should find .text");
+        let map = file.relocation_map_for(&shdr).expect("should parse");
+        let entries: Vec<_> = map
+            .iter()
+            .map(|reloc| {
+                assert_eq!(reloc.symbol_name, None);
+                (reloc.offset, reloc.rela.r_addend, reloc.overlaps)
+            })
+            .collect();
+        assert_eq!(
+            entries,
+            vec![
+                (0, 0xfff00010u32 as i32 as i64, true),
+                (2, -16, true),
+                (6, 5, false)
+            ]
+        );
+    }
+
+    #[test]
+    fn symbols_in_section() {
+        let path = std::path::PathBuf::from("sample-objects/basic.x86_64");
+        let file_data = std::fs::read(path).expect("Could not read file.");
+        let slice = file_data.as_slice();
+        let file = ElfBytes::<AnyEndian>::minimal_parse(slice).expect("Open test1");
+
+        let text = file
+            .section_header_by_name(".text")
+            .expect("should parse")
+            .expect("should have .text");
+        let (_, strtab) = file
+            .symbol_table()
+            .expect("should parse")
+            .expect("should have .symtab");
+        let symbols = file
+            .symbols_in_section(&text)
+            .expect("should parse")
+            .expect("should have symbols");
+        let names: Vec<&str> = symbols
+            .iter()
+            .map(|(_, sym)| strtab.get(sym.st_name as usize).expect("should parse"))
+            .collect();
+        assert_eq!(
+            names,
+            vec![
+                "",
+                "_start",
+                "deregister_tm_clones",
+                "register_tm_clones",
+                "__do_global_dtors_aux",
+                "frame_dummy",
+                "main",
+                "__libc_csu_init",
+                "__libc_csu_fini",
+            ]
+        );
+        assert_eq!(symbols[6].0, 64);
+        assert_eq!(symbols[6].1.st_size, 32);
+
+        let mut bogus = text;
+        bogus.sh_name += 1;
+        assert!(file
+            .symbols_in_section(&bogus)
+            .expect("should parse")
+            .is_none());
+    }
+
+    #[test]
+    fn section_header_by_name_repeated() {
+        use crate::builder::ElfBuilder;
+        use crate::endian::LittleEndian;
+
+        let mut builder = ElfBuilder::new(LittleEndian, Class::ELF64, abi::ET_REL, abi::EM_X86_64);
+        for (name, sh_type) in [
+            (".text", abi::SHT_PROGBITS),
+            (".data", abi::SHT_PROGBITS),
+            (".text", abi::SHT_NOTE),
+        ] {
+            let shdr = SectionHeader {
+                sh_type,
+                ..Default::default()
+            };
+            builder.add_section(name, shdr, b"\0");
+        }
+        let data = builder.build().unwrap();
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&data).expect("should parse");
+
+        for _ in 0..2 {
+            // The first section with a name is the one that's found
+            let shdr = file.section_header_by_name(".text").unwrap().unwrap();
+            assert_eq!(shdr.sh_type, abi::SHT_PROGBITS);
+            assert_eq!(
+                file.section_header_by_name(".data").unwrap(),
+                Some(file.section_headers().unwrap().get(2).unwrap())
+            );
+            assert_eq!(file.section_header_by_name(".bss").unwrap(), None);
+            // The reserved header at index 0 doesn't count as a section named ""
+            assert_eq!(file.section_header_by_name("").unwrap(), None);
+        }
+    }
+
+    #[test]
+    fn lookups_match_the_section_header_table() {
+        let path = std::path::PathBuf::from("sample-objects/symver.x86_64.so");
+        let file_data = std::fs::read(path).expect("Could not read file.");
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("should parse");
+        assert!(file.section_header_cache().is_some());
+
+        // The cached headers are the ones in the table
+        let shdrs = file.section_headers().unwrap();
+        for index in 0..shdrs.len() {
+            let shdr = shdrs.get(index).unwrap();
+            assert_eq!(file.section_header_at(&shdrs, index).unwrap(), shdr);
+        }
+        assert!(file.iter_section_headers().eq(shdrs.iter()));
+
+        let strtab = file.section_headers_with_strtab().unwrap().1.unwrap();
+        let dynsym = shdrs
+            .iter()
+            .find(|shdr| strtab.get(shdr.sh_name as usize).ok() == Some(".dynsym"))
+            .unwrap();
+        assert_eq!(
+            file.section_header_by_name(".dynsym").unwrap(),
+            Some(dynsym)
+        );
+    }
+
+    #[test]
+    fn find_common_data() {
+        let path = std::path::PathBuf::from("sample-objects/symver.x86_64.so");
+        let file_data = std::fs::read(path).expect("Could not read file.");
+        let slice = file_data.as_slice();
+        let file = ElfBytes::<AnyEndian>::minimal_parse(slice).expect("Open test1");
+
+        let elf_scns = file.find_common_data().expect("file should parse");
+
+        // hello.so should find everything
+        assert!(elf_scns.symtab.is_some());
+        assert!(elf_scns.symtab_strs.is_some());
+        assert!(elf_scns.dynsyms.is_some());
+        assert!(elf_scns.dynsyms_strs.is_some());
+        assert!(elf_scns.dynamic.is_some());
+        assert!(elf_scns.sysv_hash.is_some());
+        assert!(elf_scns.gnu_hash.is_some());
+    }
+
+    #[test]
+    fn section_data() {
+        let path = std::path::PathBuf::from("sample-objects/basic.x86_64");
+        let file_data = std::fs::read(path).expect("Could not read file.");
+        let slice = file_data.as_slice();
+        let file = ElfBytes::<AnyEndian>::minimal_parse(slice).expect("Open test1");
+
+        let shdr = file
+            .section_headers()
+            .expect("File should have section table")
+            .get(26)
+            .expect("shdr should be parsable");
+
+        assert_eq!(shdr.sh_type, SHT_NOBITS);
+
+        let (data, chdr) = file
+            .section_data(&shdr)
+            .expect("Failed to get section data");
+
+        assert_eq!(chdr, None);
+        assert_eq!(data, &[] as &[u8]);
+    }
+
+    #[test]
+    fn section_data_bounds() {
+        let file_data = std::fs::read("sample-objects/basic.x86_64").expect("should read");
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("should parse");
+        let len = file_data.len() as u64;
+
+        // SHT_NOBITS sections are empty, even when their range is nonsense
+        let shdr = SectionHeader {
+            sh_type: abi::SHT_NOBITS,
+            sh_offset: u64::MAX,
+            sh_size: u64::MAX,
+            ..Default::default()
+        };
+        assert_eq!(
+            file.section_data(&shdr).expect("should be empty").0,
+            &[] as &[u8]
+        );
+
+        // Other sections can't run past the end of the file
+        let shdr = SectionHeader {
+            sh_type: abi::SHT_STRTAB,
+            sh_offset: len - 4,
+            sh_size: 8,
+            ..Default::default()
+        };
+        let end = len as usize + 4;
+        let err = file
+            .section_data(&shdr)
+            .expect_err("should be out of bounds");
+        let cause = err.into_root_cause();
+        assert!(
+            matches!(cause, BareParseError::SliceReadError((start, e)) if start == end - 8 && e == end),
+            "{cause:?}"
+        );
+        let err = file
+            .section_data_as_strtab(&shdr)
+            .expect_err("should be out of bounds");
+        let cause = err.into_root_cause();
+        assert!(
+            matches!(cause, BareParseError::SliceReadError(_)),
+            "{cause:?}"
+        );
+
+        // or overflow
+        let shdr = SectionHeader {
+            sh_type: abi::SHT_PROGBITS,
+            sh_offset: usize::MAX as u64,
+            sh_size: 1,
+            ..Default::default()
+        };
+        let err = file.section_data(&shdr).expect_err("should overflow");
+        let cause = err.into_root_cause();
+        assert!(
+            matches!(cause, BareParseError::IntegerOverflow),
+            "{cause:?}"
+        );
+    }
+
+    // Test all the different section_data_as* with a section of the wrong type
+    #[test]
+    fn section_data_as_wrong_type() {
+        let path = std::path::PathBuf::from("sample-objects/basic.x86_64");
         let file_data = std::fs::read(path).expect("Could not read file.");
         let slice = file_data.as_slice();
         let file = ElfBytes::<AnyEndian>::minimal_parse(slice).expect("Open test1");
@@ -1294,6 +5023,27 @@ mod interface_tests {
         );
     }
 
+    #[test]
+    fn global_symbols() {
+        let path = std::path::PathBuf::from("sample-objects/basic.x86_64");
+        let file_data = std::fs::read(path).expect("Could not read file.");
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("Open test1");
+
+        let (symtab, _) = file.symbol_table().expect("should parse").unwrap();
+        let (globals, _) = file.global_symbols().expect("should parse").unwrap();
+        let first_global = globals.first_index();
+        assert_eq!(first_global + globals.len(), symtab.len());
+        assert!(symtab
+            .iter()
+            .take(first_global)
+            .all(|sym| sym.st_bind() == abi::STB_LOCAL));
+        for (index, sym) in globals.iter_enumerated() {
+            assert_ne!(sym.st_bind(), abi::STB_LOCAL);
+            assert_eq!(sym, symtab.get(index).expect("should parse"));
+            assert_eq!(sym, globals.get(index).expect("should parse"));
+        }
+    }
+
     #[test]
     fn dynamic_symbol_table() {
         let path = std::path::PathBuf::from("sample-objects/basic.x86_64");
@@ -1325,6 +5075,180 @@ mod interface_tests {
         );
     }
 
+    #[test]
+    fn symbol_table_shndx() {
+        let path = std::path::PathBuf::from("sample-objects/basic.x86_64");
+        let file_data = std::fs::read(path).expect("Could not read file.");
+        let slice = file_data.as_slice();
+        let file = ElfBytes::<AnyEndian>::minimal_parse(slice).expect("Open test1");
+
+        // This object has few enough sections that it doesn't need extended symbol indexes
+        assert!(file
+            .symbol_table_shndx()
+            .expect("Failed to look for shndx table")
+            .is_none());
+    }
+
+    #[test]
+    fn symbol_by_name() {
+        let path = std::path::PathBuf::from("sample-objects/symver.x86_64.so");
+        let file_data = std::fs::read(path).expect("Could not read file.");
+        let slice = file_data.as_slice();
+        let file = ElfBytes::<AnyEndian>::minimal_parse(slice).expect("Open test1");
+
+        // Exported symbols get found in the .dynsym via the hash table
+        let (sh_type, idx, sym) = file
+            .symbol_by_name(b"use_memset")
+            .expect("Failed to look up symbol")
+            .expect("Failed to find symbol");
+        assert_eq!(sh_type, abi::SHT_DYNSYM);
+        assert_eq!(idx, 9);
+        assert_eq!(sym.st_symtype(), abi::STT_FUNC);
+
+        // Local symbols only live in the .symtab
+        let (sh_type, _, sym) = file
+            .symbol_by_name(b"completed.0")
+            .expect("Failed to look up symbol")
+            .expect("Failed to find symbol");
+        assert_eq!(sh_type, abi::SHT_SYMTAB);
+        assert_eq!(sym.st_bind(), abi::STB_LOCAL);
+
+        assert!(file
+            .symbol_by_name(b"does_not_exist")
+            .expect("Failed to look up symbol")
+            .is_none());
+    }
+
+    #[test]
+    fn symbol_by_name_falls_back_to_symtab() {
+        let path = std::path::PathBuf::from("sample-objects/basic.x86_64");
+        let file_data = std::fs::read(path).expect("Could not read file.");
+        let slice = file_data.as_slice();
+        let file = ElfBytes::<AnyEndian>::minimal_parse(slice).expect("Open test1");
+
+        // main isn't exported in the .dynsym, so it misses the .gnu.hash lookup
+        let (sh_type, _, sym) = file
+            .symbol_by_name(b"main")
+            .expect("Failed to look up symbol")
+            .expect("Failed to find symbol");
+        assert_eq!(sh_type, abi::SHT_SYMTAB);
+        assert_eq!(sym.st_symtype(), abi::STT_FUNC);
+    }
+
+    #[test]
+    fn symbolizer() {
+        let path = std::path::PathBuf::from("sample-objects/basic.x86_64");
+        let file_data = std::fs::read(path).expect("Could not read file.");
+        let slice = file_data.as_slice();
+        let file = ElfBytes::<AnyEndian>::minimal_parse(slice).expect("Open test1");
+
+        let symbolizer = file
+            .symbolizer(0)
+            .expect("Failed to build symbolizer")
+            .expect("Failed to find symbol table");
+        let (sym, name, offset) = symbolizer.symbolize(0x4004f0).expect("Failed to symbolize");
+        assert_eq!(name, "main");
+        assert_eq!(offset, 0xa);
+        assert_eq!(sym.st_size, 32);
+    }
+
+    #[test]
+    fn symbolizer_stripped() {
+        let path = std::path::PathBuf::from("sample-objects/stripped.x86_64.so");
+        let file_data = std::fs::read(path).expect("Could not read file.");
+        let slice = file_data.as_slice();
+        let file = ElfBytes::<AnyEndian>::minimal_parse(slice).expect("Open test1");
+
+        // Falls back to the .dynsym when there's no .symtab
+        let symbolizer = file
+            .symbolizer(0x7f00_0000_0000)
+            .expect("Failed to build symbolizer")
+            .expect("Failed to find symbol table");
+        let (_, name, offset) = symbolizer
+            .symbolize(0x7f00_0000_1120)
+            .expect("Failed to symbolize");
+        assert_eq!(name, "use_memset");
+        assert_eq!(offset, 7);
+    }
+
+    #[test]
+    fn exports() {
+        let path = std::path::PathBuf::from("sample-objects/symver.x86_64.so");
+        let file_data = std::fs::read(path).expect("Could not read file.");
+        let slice = file_data.as_slice();
+        let file = ElfBytes::<AnyEndian>::minimal_parse(slice).expect("Open test1");
+
+        let exports = file.exports().expect("Failed to get exports");
+        let summary: Vec<(usize, &str, Option<&str>, bool)> = exports
+            .iter()
+            .map(|e| (e.index, e.name, e.version, e.hidden))
+            .collect();
+        assert_eq!(
+            summary,
+            [
+                (6, "HELLO_1.0", Some("HELLO_1.0"), false),
+                (7, "use_memset_v2", Some("HELLO_1.42"), true),
+                (8, "HELLO_1.42", Some("HELLO_1.42"), false),
+                (9, "use_memset", Some("HELLO_1.0"), true),
+            ]
+        );
+        assert_eq!(exports[1].symbol.st_value, 0x1145);
+        assert_eq!(exports[3].symbol.st_symtype(), abi::STT_FUNC);
+    }
+
+    #[test]
+    fn imports() {
+        let path = std::path::PathBuf::from("sample-objects/symver.x86_64.so");
+        let file_data = std::fs::read(path).expect("Could not read file.");
+        let slice = file_data.as_slice();
+        let file = ElfBytes::<AnyEndian>::minimal_parse(slice).expect("Open test1");
+
+        let imports = file.imports().expect("Failed to get imports");
+        let summary: Vec<(&str, Option<&str>, Option<&str>, bool)> = imports
+            .iter()
+            .map(|i| (i.name, i.version, i.library, i.is_optional()))
+            .collect();
+        assert_eq!(
+            summary,
+            [
+                ("_ITM_deregisterTMCloneTable", None, None, true),
+                ("memset", Some("GLIBC_2.2.5"), Some("libc.so.6"), false),
+                ("__gmon_start__", None, None, true),
+                ("_ITM_registerTMCloneTable", None, None, true),
+                (
+                    "__cxa_finalize",
+                    Some("GLIBC_2.2.5"),
+                    Some("libc.so.6"),
+                    true
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn exports_and_imports_for_executable() {
+        let path = std::path::PathBuf::from("sample-objects/basic.x86_64");
+        let file_data = std::fs::read(path).expect("Could not read file.");
+        let slice = file_data.as_slice();
+        let file = ElfBytes::<AnyEndian>::minimal_parse(slice).expect("Open test1");
+
+        assert!(file.exports().expect("Failed to get exports").is_empty());
+        let imports = file.imports().expect("Failed to get imports");
+        let summary: Vec<(&str, Option<&str>, Option<&str>)> = imports
+            .iter()
+            .map(|i| (i.name, i.version, i.library))
+            .collect();
+        assert_eq!(
+            summary,
+            [
+                ("memset", Some("GLIBC_2.2.5"), Some("libc.so.6")),
+                ("__libc_start_main", Some("GLIBC_2.2.5"), Some("libc.so.6")),
+                // versym index 0 (local) has no requirement
+                ("__gmon_start__", None, None),
+            ]
+        );
+    }
+
     #[test]
     fn symbol_version_table() {
         let path = std::path::PathBuf::from("sample-objects/symver.x86_64.so");
@@ -1381,14 +5305,80 @@ mod interface_tests {
     }
 
     #[test]
-    fn sysv_hash_table() {
+    fn symbol_version_table_without_sections() {
         let path = std::path::PathBuf::from("sample-objects/symver.x86_64.so");
-        let file_data = std::fs::read(path).expect("Could not read file.");
-        let slice = file_data.as_slice();
-        let file = ElfBytes::<AnyEndian>::minimal_parse(slice).expect("Open test1");
+        let original = std::fs::read(path).expect("Could not read file.");
+        let mut file_data = original.clone();
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&original).expect("Open test1");
+        let expected: Vec<_> = {
+            let vst = file
+                .symbol_version_table()
+                .expect("should parse")
+                .expect("should have versions");
+            (0..file.dynamic_symbol_table().unwrap().unwrap().0.len())
+                .map(|index| {
+                    let req = vst.get_requirement(index).expect("should parse");
+                    let def = vst.get_definition(index).expect("should parse").map(|def| {
+                        let names: Vec<_> = def.names.map(|name| name.unwrap()).collect();
+                        (def.hash, def.flags, def.hidden, names)
+                    });
+                    (req, def)
+                })
+                .collect()
+        };
+        let dynamic_offset = file
+            .section_header_by_name(".dynamic")
+            .expect("should parse")
+            .expect("should have .dynamic")
+            .sh_offset as usize;
+        let verdefnum_index = file
+            .dynamic()
+            .expect("should parse")
+            .expect("should have .dynamic")
+            .iter()
+            .position(|entry| entry.d_tag == abi::DT_VERDEFNUM)
+            .expect("should have DT_VERDEFNUM");
+
+        // Like sstrip, drop the section headers
+        file_data[0x28..0x30].fill(0);
+        file_data[0x3c..0x40].fill(0);
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("should parse");
+        assert!(file.section_headers().is_none());
+        let vst = file
+            .symbol_version_table()
+            .expect("should parse")
+            .expect("should have versions");
+        for (index, (req, def)) in expected.iter().enumerate() {
+            assert_eq!(&vst.get_requirement(index).expect("should parse"), req);
+            let found = vst.get_definition(index).expect("should parse").map(|def| {
+                let names: Vec<_> = def.names.map(|name| name.unwrap()).collect();
+                (def.hash, def.flags, def.hidden, names)
+            });
+            assert_eq!(&found, def);
+        }
 
-        // Look up the SysV hash section header
-        let common = file.find_common_data().expect("should parse");
+        // DT_VERDEFNUM bounds the walk along the chain of definitions, so only the first one
+        // (for the file itself) is found
+        let offset = dynamic_offset + verdefnum_index * 16 + 8;
+        file_data[offset..offset + 8].copy_from_slice(&1u64.to_le_bytes());
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("should parse");
+        let vst = file
+            .symbol_version_table()
+            .expect("should parse")
+            .expect("should have versions");
+        assert!(vst.get_definition(3).expect("should parse").is_some());
+        assert!(vst.get_definition(7).expect("should parse").is_none());
+    }
+
+    #[test]
+    fn sysv_hash_table() {
+        let path = std::path::PathBuf::from("sample-objects/symver.x86_64.so");
+        let file_data = std::fs::read(path).expect("Could not read file.");
+        let slice = file_data.as_slice();
+        let file = ElfBytes::<AnyEndian>::minimal_parse(slice).expect("Open test1");
+
+        // Look up the SysV hash section header
+        let common = file.find_common_data().expect("should parse");
         let hash_table = common.sysv_hash.expect("should have .hash section");
 
         // Get the dynamic symbol table.
@@ -1576,3 +5566,3114 @@ mod arch_tests {
         arch_test!("riscv64", abi::EM_RISCV, AnyEndian::Little);
     }
 }
+
+#[cfg(test)]
+mod native_fast_path_tests {
+    use super::*;
+    use crate::endian::AnyEndian;
+    use crate::relocation::{Rel, Rela};
+
+    /// Check that the native-endian fast path parses every entry of the given type in
+    /// `data` exactly the same as the field-by-field path does.
+    fn diff_entries<P: ParseAt + PartialEq + core::fmt::Debug>(
+        class: Class,
+        data: &[u8],
+        parse_native: fn(Class, &mut usize, &[u8]) -> Result<P, ParseError>,
+        parse_fields: fn(AnyEndian, Class, &mut usize, &[u8]) -> Result<P, ParseError>,
+    ) -> usize {
+        let entsize = P::size_for(class);
+        let endian = if cfg!(target_endian = "little") {
+            AnyEndian::Little
+        } else {
+            AnyEndian::Big
+        };
+        let mut count = 0;
+        // Also parse at unaligned offsets to make sure those get copied out correctly
+        for start in 0..entsize {
+            let (mut fast, mut slow) = (start, start);
+            while fast + entsize <= data.len() {
+                let expected = parse_fields(endian, class, &mut slow, data).expect("should parse");
+                let actual = parse_native(class, &mut fast, data).expect("should parse");
+                assert_eq!(actual, expected);
+                assert_eq!(fast, slow);
+                count += 1;
+            }
+        }
+        count
+    }
+
+    #[test]
+    fn native_matches_fields_across_corpus() {
+        let mut count = 0;
+        for entry in std::fs::read_dir("sample-objects").expect("should list sample-objects") {
+            let path = entry.expect("should read dir entry").path();
+            if !path.is_file() {
+                continue;
+            }
+            let file_data = std::fs::read(&path).expect("Could not read file.");
+            let file = match ElfBytes::<AnyEndian>::minimal_parse(&file_data) {
+                Ok(file) => file,
+                Err(_) => continue,
+            };
+            if !file.ehdr.endianness.is_native() {
+                continue;
+            }
+            let class = file.ehdr.class;
+            let shdrs = match file.section_headers() {
+                Some(shdrs) => shdrs,
+                None => continue,
+            };
+
+            for shdr in shdrs.iter() {
+                let data = match file.section_data(&shdr) {
+                    Ok((data, None)) => data,
+                    _ => continue,
+                };
+                count += match shdr.sh_type {
+                    abi::SHT_SYMTAB | abi::SHT_DYNSYM => {
+                        diff_entries(class, data, Symbol::parse_native, Symbol::parse_fields)
+                    }
+                    abi::SHT_REL => diff_entries(class, data, Rel::parse_native, Rel::parse_fields),
+                    abi::SHT_RELA => {
+                        diff_entries(class, data, Rela::parse_native, Rela::parse_fields)
+                    }
+                    _ => 0,
+                };
+            }
+        }
+        // On either byte order there's some sample object to exercise this with
+        assert!(count > 0);
+    }
+}
+
+#[cfg(test)]
+mod debuglink_tests {
+    use super::*;
+    use crate::debuglink::gnu_debuglink_crc;
+    use crate::endian::AnyEndian;
+
+    #[test]
+    fn gnu_debuglink() {
+        let path = std::path::PathBuf::from("sample-objects/debuglink.x86_64");
+        let file_data = std::fs::read(path).expect("Could not read file.");
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("Open test1");
+
+        let (name, crc) = file
+            .gnu_debuglink()
+            .expect("should parse")
+            .expect("should have a debuglink");
+        assert_eq!(name, b"basic.x86_64.debug");
+        assert_eq!(crc, 0x42fd95f6);
+
+        let debug_data =
+            std::fs::read("sample-objects/basic.x86_64.debug").expect("Could not read file.");
+        assert_eq!(gnu_debuglink_crc(&debug_data), crc);
+
+        // The unstripped original doesn't match
+        let other_data =
+            std::fs::read("sample-objects/basic.x86_64").expect("Could not read file.");
+        assert_ne!(gnu_debuglink_crc(&other_data), crc);
+
+        assert!(file.gnu_debugaltlink().expect("should parse").is_none());
+    }
+
+    #[test]
+    fn no_gnu_debuglink() {
+        let path = std::path::PathBuf::from("sample-objects/basic.x86_64");
+        let file_data = std::fs::read(path).expect("Could not read file.");
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("Open test1");
+        assert!(file.gnu_debuglink().expect("should parse").is_none());
+    }
+}
+
+#[cfg(test)]
+mod attributes_tests {
+    use super::*;
+    use crate::attributes::AttrValue;
+    use crate::builder::ElfBuilder;
+    use crate::endian::{AnyEndian, LittleEndian};
+
+    #[test]
+    fn arm_attributes() {
+        let path = std::path::PathBuf::from("sample-objects/symver.armhf.so");
+        let file_data = std::fs::read(path).expect("Could not read file.");
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("Open test1");
+
+        let attrs = file
+            .arm_attributes()
+            .expect("should parse")
+            .expect("should have attributes");
+        let parsed: Vec<_> = attrs
+            .file_attributes()
+            .expect("should parse")
+            .collect::<Result<_, _>>()
+            .expect("should parse");
+        assert!(parsed.contains(&(abi::TAG_CPU_NAME, AttrValue::String(b"7-A"))));
+        assert!(parsed.contains(&(abi::TAG_CPU_ARCH, AttrValue::Int(abi::TAG_CPU_ARCH_V7))));
+        assert!(parsed.contains(&(abi::TAG_CPU_UNALIGNED_ACCESS, AttrValue::Int(1))));
+        assert_eq!(
+            attrs
+                .file_attribute(abi::TAG_ABI_VFP_ARGS)
+                .expect("should parse"),
+            Some(AttrValue::Int(abi::TAG_ABI_VFP_ARGS_VFP))
+        );
+    }
+
+    #[test]
+    fn riscv_attributes() {
+        let path = std::path::PathBuf::from("sample-objects/attributes.riscv64.o");
+        let file_data = std::fs::read(path).expect("Could not read file.");
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("Open test1");
+
+        let attrs = file
+            .riscv_attributes()
+            .expect("should parse")
+            .expect("should have attributes");
+        assert_eq!(
+            attrs.arch().expect("should parse"),
+            Some("rv64i2p0_m2p0_a2p0_c2p0")
+        );
+        assert_eq!(attrs.stack_align().expect("should parse"), Some(16));
+        assert_eq!(attrs.unaligned_access().expect("should parse"), Some(false));
+        assert!(file.arm_attributes().expect("should parse").is_none());
+
+        let path = std::path::PathBuf::from("sample-objects/symver.riscv64.so");
+        let file_data = std::fs::read(path).expect("Could not read file.");
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("Open test1");
+        let attrs = file
+            .riscv_attributes()
+            .expect("should parse")
+            .expect("should have attributes");
+        assert_eq!(
+            attrs.arch().expect("should parse"),
+            Some("rv64i2p0_m2p0_a2p0_f2p0_d2p0_c2p0")
+        );
+        assert_eq!(attrs.stack_align().expect("should parse"), None);
+    }
+
+    #[test]
+    fn no_arm_attributes() {
+        // RISC-V attributes share the section type, but aren't ARM's
+        let path = std::path::PathBuf::from("sample-objects/symver.riscv64.so");
+        let file_data = std::fs::read(path).expect("Could not read file.");
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("Open test1");
+        assert!(file.arm_attributes().expect("should parse").is_none());
+
+        let path = std::path::PathBuf::from("sample-objects/symver.armhf.so");
+        let file_data = std::fs::read(path).expect("Could not read file.");
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("Open test1");
+        assert!(file.riscv_attributes().expect("should parse").is_none());
+        assert!(file.csky_attributes().expect("should parse").is_none());
+    }
+
+    #[test]
+    fn csky_attributes() {
+        let mut builder = ElfBuilder::new(LittleEndian, Class::ELF32, abi::ET_REL, abi::EM_CSKY);
+        builder.add_section(
+            abi::SHT_CSKY_ATTRIBUTES_SECTION_NAME,
+            SectionHeader {
+                sh_type: abi::SHT_CSKY_ATTRIBUTES,
+                sh_addralign: 1,
+                ..Default::default()
+            },
+            &[
+                b'A', 25, 0, 0, 0, b'c', b's', b'k', b'y', 0, 1, 16, 0, 0, 0, 4, b'c', b'k', b'8',
+                b'6', b'0', 0, 16, 2, 17, 3,
+            ],
+        );
+        let file_data = builder.build().expect("should build");
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("should parse");
+        let attrs = file
+            .csky_attributes()
+            .expect("should parse")
+            .expect("should have attributes");
+        assert_eq!(attrs.arch_name().expect("should parse"), Some("ck860"));
+        assert_eq!(attrs.cpu_name().expect("should parse"), None);
+        assert_eq!(
+            attrs
+                .file_attribute(abi::TAG_CSKY_FPU_ABI)
+                .expect("should parse"),
+            Some(AttrValue::Int(3))
+        );
+        // The section type is shared with other machines' sections
+        assert!(file.arm_attributes().expect("should parse").is_none());
+    }
+}
+
+#[cfg(test)]
+mod arm_exidx_tests {
+    use super::*;
+    use crate::arm_exidx::{ExidxEntry, ExidxEntryData};
+    use crate::builder::ElfBuilder;
+    use crate::endian::{AnyEndian, LittleEndian};
+
+    fn build_exidx_object(e_machine: u16) -> Vec<u8> {
+        let mut builder = ElfBuilder::new(LittleEndian, Class::ELF32, abi::ET_EXEC, e_machine);
+        let exidx = builder.add_section(
+            ".ARM.exidx",
+            SectionHeader {
+                sh_type: abi::SHT_ARM_EXIDX,
+                sh_flags: abi::SHF_ALLOC as u64,
+                sh_addr: 0x8000,
+                sh_addralign: 4,
+                ..Default::default()
+            },
+            &[0x00, 0xff, 0xff, 0x7f, 0x01, 0x00, 0x00, 0x00],
+        );
+        builder.add_segment(
+            ProgramHeader {
+                p_type: abi::PT_ARM_EXIDX,
+                p_flags: abi::PF_R,
+                p_vaddr: 0x8000,
+                p_paddr: 0x8000,
+                p_align: 4,
+                ..Default::default()
+            },
+            Some(exidx),
+        );
+        builder.build().expect("should build")
+    }
+
+    const ENTRY: ExidxEntry = ExidxEntry {
+        fn_addr: 0x7f00,
+        data: ExidxEntryData::CantUnwind,
+    };
+
+    #[test]
+    fn arm_exidx_from_section() {
+        let data = build_exidx_object(abi::EM_ARM);
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&data).expect("should parse");
+        let table = file
+            .arm_exidx()
+            .expect("should parse")
+            .expect("should have a table");
+        assert_eq!(table.iter().collect::<Vec<_>>(), vec![ENTRY]);
+    }
+
+    #[test]
+    fn arm_exidx_from_segment() {
+        // Strip the section header table, leaving just the segment
+        let mut data = build_exidx_object(abi::EM_ARM);
+        data[0x20..0x24].copy_from_slice(&[0, 0, 0, 0]);
+        data[0x30..0x34].copy_from_slice(&[0, 0, 0, 0]);
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&data).expect("should parse");
+        assert!(file.section_headers().is_none());
+        let table = file
+            .arm_exidx()
+            .expect("should parse")
+            .expect("should have a table");
+        assert_eq!(table.iter().collect::<Vec<_>>(), vec![ENTRY]);
+    }
+
+    #[test]
+    fn no_arm_exidx() {
+        // MIPS reuses the processor-specific section type number
+        let data = build_exidx_object(abi::EM_MIPS);
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&data).expect("should parse");
+        assert!(file.arm_exidx().expect("should parse").is_none());
+
+        let path = std::path::PathBuf::from("sample-objects/symver.armhf.so");
+        let file_data = std::fs::read(path).expect("Could not read file.");
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("Open test1");
+        assert!(file.arm_exidx().expect("should parse").is_none());
+    }
+}
+
+#[cfg(test)]
+mod mips_tests {
+    use super::*;
+    use crate::endian::AnyEndian;
+
+    #[test]
+    fn mips_abiflags_and_reginfo() {
+        let path = std::path::PathBuf::from("sample-objects/abiflags.mips.o");
+        let file_data = std::fs::read(path).expect("Could not read file.");
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("Open test1");
+
+        let abiflags = file
+            .mips_abiflags()
+            .expect("should parse")
+            .expect("should have abiflags");
+        assert_eq!(
+            abiflags,
+            MipsAbiFlags {
+                version: 0,
+                isa_level: 32,
+                isa_rev: 2,
+                gpr_size: abi::AFL_REG_32,
+                cpr1_size: abi::AFL_REG_32,
+                cpr2_size: abi::AFL_REG_NONE,
+                fp_abi: abi::MIPS_ABI_FP_XX,
+                isa_ext: 0,
+                ases: 0,
+                flags1: abi::AFL_FLAGS1_ODDSPREG,
+                flags2: 0,
+            }
+        );
+
+        let reginfo = file
+            .mips_reginfo()
+            .expect("should parse")
+            .expect("should have reginfo");
+        assert_eq!(reginfo.ri_gprmask, 0x80000001);
+        assert_eq!(reginfo.ri_gp_value, 0);
+    }
+
+    #[test]
+    fn mips64_abiflags() {
+        let path = std::path::PathBuf::from("sample-objects/abiflags.mips64el.o");
+        let file_data = std::fs::read(path).expect("Could not read file.");
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("Open test1");
+
+        let abiflags = file
+            .mips_abiflags()
+            .expect("should parse")
+            .expect("should have abiflags");
+        assert_eq!((abiflags.isa_level, abiflags.isa_rev), (64, 2));
+        assert_eq!(abiflags.gpr_size, abi::AFL_REG_64);
+        assert_eq!(abiflags.fp_abi, abi::MIPS_ABI_FP_DOUBLE);
+        assert!(file.mips_reginfo().expect("should parse").is_none());
+    }
+
+    #[test]
+    fn no_mips_abiflags() {
+        let path = std::path::PathBuf::from("sample-objects/basic.x86_64");
+        let file_data = std::fs::read(path).expect("Could not read file.");
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("Open test1");
+        assert!(file.mips_abiflags().expect("should parse").is_none());
+        assert!(file.mips_reginfo().expect("should parse").is_none());
+    }
+}
+
+#[cfg(test)]
+mod stack_sizes_tests {
+    use super::*;
+    use crate::endian::AnyEndian;
+    use crate::stack_sizes::StackSize;
+
+    #[test]
+    fn stack_sizes_per_function_section() {
+        let path = std::path::PathBuf::from("sample-objects/stack_sizes.x86_64.o");
+        let file_data = std::fs::read(path).expect("Could not read file.");
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("Open test1");
+
+        let sections = file.stack_sizes().expect("should parse");
+        let linked: Vec<_> = sections
+            .iter()
+            .map(|section| (section.shndx, section.text_shndx))
+            .collect();
+        assert_eq!(linked, vec![(4, 3), (7, 6)]);
+
+        // The addresses are left for relocations to fill in, with each function at the start
+        // of its own section
+        let sizes: Vec<StackSize> = sections
+            .into_iter()
+            .flat_map(|section| section.sizes)
+            .collect::<Result<_, _>>()
+            .expect("should parse");
+        assert_eq!(
+            sizes,
+            vec![
+                StackSize {
+                    addr: 0,
+                    stack_size: 16
+                },
+                StackSize {
+                    addr: 0,
+                    stack_size: 256
+                },
+            ]
+        );
+
+        let funcs = file.function_stack_sizes().expect("should parse");
+        assert_eq!(
+            funcs,
+            vec![
+                FunctionStackSize {
+                    name: Some("small"),
+                    addr: 0,
+                    stack_size: 16
+                },
+                FunctionStackSize {
+                    name: Some("big"),
+                    addr: 0,
+                    stack_size: 256
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn stack_sizes_linked() {
+        let path = std::path::PathBuf::from("sample-objects/stack_sizes.x86_64");
+        let file_data = std::fs::read(path).expect("Could not read file.");
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("Open test1");
+
+        let funcs = file.function_stack_sizes().expect("should parse");
+        assert_eq!(
+            funcs,
+            vec![
+                FunctionStackSize {
+                    name: Some("small"),
+                    addr: 0x401000,
+                    stack_size: 16
+                },
+                FunctionStackSize {
+                    name: Some("big"),
+                    addr: 0x401010,
+                    stack_size: 256
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn no_stack_sizes() {
+        let path = std::path::PathBuf::from("sample-objects/basic.x86_64");
+        let file_data = std::fs::read(path).expect("Could not read file.");
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("Open test1");
+        assert!(file.stack_sizes().expect("should parse").is_empty());
+        assert!(file
+            .function_stack_sizes()
+            .expect("should parse")
+            .is_empty());
+    }
+}
+
+#[cfg(test)]
+mod sframe_tests {
+    use super::*;
+    use crate::endian::AnyEndian;
+    use crate::sframe::SFrameFde;
+
+    fn check_sframe(file: &ElfBytes<'_, AnyEndian>) {
+        let sframe = file
+            .sframe()
+            .expect("should parse")
+            .expect("should have sframe data");
+        assert_eq!(sframe.header.version, abi::SFRAME_VERSION_1);
+        assert_eq!(sframe.header.flags, abi::SFRAME_F_FDE_SORTED);
+        assert_eq!(sframe.header.num_fdes, 2);
+        assert_eq!(sframe.header.num_fres, 4);
+
+        let fdes: Vec<_> = sframe.fdes().collect();
+        assert_eq!(
+            fdes[1],
+            SFrameFde {
+                func_start_address: 0x1004,
+                func_size: 48,
+                func_start_fre_off: 3,
+                func_num_fres: 3,
+                func_info: 0,
+                func_rep_size: 0,
+            }
+        );
+        assert_eq!(fdes[1].fre_type(), abi::SFRAME_FRE_TYPE_ADDR1);
+        assert_eq!(fdes[0].func_start_address, 0x1000);
+    }
+
+    #[test]
+    fn sframe_from_section() {
+        let path = std::path::PathBuf::from("sample-objects/sframe.x86_64.so");
+        let file_data = std::fs::read(path).expect("Could not read file.");
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("Open test1");
+        check_sframe(&file);
+    }
+
+    #[test]
+    fn sframe_from_segment() {
+        // Strip the section header table, leaving just the segment
+        let path = std::path::PathBuf::from("sample-objects/sframe.x86_64.so");
+        let mut file_data = std::fs::read(path).expect("Could not read file.");
+        file_data[0x28..0x30].copy_from_slice(&[0; 8]);
+        file_data[0x3c..0x40].copy_from_slice(&[0; 4]);
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("Open test1");
+        assert!(file.section_headers().is_none());
+        check_sframe(&file);
+    }
+
+    #[test]
+    fn no_sframe() {
+        let path = std::path::PathBuf::from("sample-objects/basic.x86_64");
+        let file_data = std::fs::read(path).expect("Could not read file.");
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("Open test1");
+        assert!(file.sframe().expect("should parse").is_none());
+    }
+}
+
+#[cfg(test)]
+mod eh_frame_hdr_tests {
+    use super::*;
+    use crate::endian::AnyEndian;
+
+    fn check_eh_frame_hdr(file: &ElfBytes<'_, AnyEndian>) {
+        let hdr = file
+            .eh_frame_hdr()
+            .expect("should parse")
+            .expect("should have a .eh_frame_hdr");
+        assert_eq!(
+            hdr.eh_frame_ptr_enc,
+            abi::DW_EH_PE_PCREL | abi::DW_EH_PE_SDATA4
+        );
+        assert_eq!(hdr.table_enc, abi::DW_EH_PE_DATAREL | abi::DW_EH_PE_SDATA4);
+        assert_eq!(hdr.eh_frame_ptr, Some(0x4005c8));
+
+        // From readelf --debug-dump=frames, as (pc, FDE offset in .eh_frame)
+        let expected = [
+            (0x4003b0, 0x48),
+            (0x4003f0, 0x18),
+            (0x4004e6, 0x70),
+            (0x400510, 0x90),
+            (0x400580, 0xd8),
+        ];
+        let entries: Vec<_> = hdr
+            .iter()
+            .map(|entry| (entry.initial_location, entry.fde_address - 0x4005c8))
+            .collect();
+        assert_eq!(entries, expected);
+
+        assert_eq!(hdr.lookup(0x4003af).expect("should parse"), None);
+        for (pc, fde_offset) in expected {
+            assert_eq!(
+                hdr.lookup(pc + 1).expect("should parse"),
+                Some(0x4005c8 + fde_offset)
+            );
+        }
+    }
+
+    #[test]
+    fn eh_frame_hdr_from_segment() {
+        let path = std::path::PathBuf::from("sample-objects/basic.x86_64");
+        let file_data = std::fs::read(path).expect("Could not read file.");
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("Open test1");
+        check_eh_frame_hdr(&file);
+    }
+
+    #[test]
+    fn eh_frame_hdr_from_section() {
+        // Drop the program headers, leaving just the section
+        let path = std::path::PathBuf::from("sample-objects/basic.x86_64");
+        let mut file_data = std::fs::read(path).expect("Could not read file.");
+        file_data[0x20..0x28].copy_from_slice(&[0; 8]);
+        file_data[0x38..0x3a].copy_from_slice(&[0; 2]);
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("Open test1");
+        assert!(file.segments().is_none());
+        check_eh_frame_hdr(&file);
+    }
+
+    #[test]
+    fn no_eh_frame_hdr() {
+        let path = std::path::PathBuf::from("sample-objects/stack_sizes.x86_64.o");
+        let file_data = std::fs::read(path).expect("Could not read file.");
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("Open test1");
+        assert!(file.eh_frame_hdr().expect("should parse").is_none());
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod layout_tests {
+    use super::*;
+    use crate::endian::AnyEndian;
+
+    #[test]
+    fn layout_of_basic() {
+        let path = std::path::PathBuf::from("sample-objects/basic.x86_64");
+        let file_data = std::fs::read(path).expect("Could not read file.");
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("Open test1");
+        let layout = file.layout().expect("should compute layout");
+
+        assert_eq!(
+            &layout.ranges[..3],
+            &[
+                LayoutRange {
+                    range: 0..0x40,
+                    owner: LayoutOwner::FileHeader,
+                },
+                LayoutRange {
+                    range: 0..0x6bc,
+                    owner: LayoutOwner::Segment(2),
+                },
+                LayoutRange {
+                    range: 0x40..0x200,
+                    owner: LayoutOwner::ProgramHeaders,
+                },
+            ]
+        );
+        assert_eq!(
+            layout.ranges.last(),
+            Some(&LayoutRange {
+                range: 0x12c0..0x1a80,
+                owner: LayoutOwner::SectionHeaders,
+            })
+        );
+        // .bss and the empty GNU_STACK segment don't claim any bytes
+        assert!(!layout.ranges.iter().any(|claim| {
+            claim.owner == LayoutOwner::Section(26) || claim.owner == LayoutOwner::Segment(7)
+        }));
+
+        // Padding between the two LOAD segments and before .symtab's alignment
+        assert_eq!(layout.gaps(), vec![0x6bc..0x6c0, 0x91c..0x920]);
+        assert!(layout.overlaps().is_empty());
+        assert_eq!(layout.overlay(), None);
+        assert_eq!(file.overlay().expect("should compute layout"), None);
+    }
+
+    #[test]
+    fn layout_reports_overlay_and_overlaps() {
+        let path = std::path::PathBuf::from("sample-objects/basic.x86_64");
+        let mut file_data = std::fs::read(path).expect("Could not read file.");
+        // Point .comment at .symtab's data
+        let sh_offset = 0x12c0 + 27 * 64 + 0x18;
+        file_data[sh_offset..sh_offset + 8].copy_from_slice(&0x920u64.to_le_bytes());
+        file_data.extend_from_slice(&[0xaa; 16]);
+
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("Open test1");
+        let layout = file.layout().expect("should compute layout");
+        assert_eq!(layout.overlay(), Some(0x1a80..0x1a90));
+        assert_eq!(
+            file.overlay().expect("should compute layout"),
+            Some(0x1a80..0x1a90)
+        );
+        let overlaps: Vec<_> = layout
+            .overlaps()
+            .into_iter()
+            .map(|(first, second)| (first.owner, second.owner))
+            .collect();
+        assert_eq!(
+            overlaps,
+            vec![(LayoutOwner::Section(27), LayoutOwner::Section(29))]
+        );
+        // .comment's old location is no longer claimed
+        assert_eq!(layout.gaps(), vec![0x6bc..0x6c0, 0x8e8..0x920]);
+    }
+}
+
+#[cfg(test)]
+mod loaded_tests {
+    use super::*;
+    use crate::endian::AnyEndian;
+    use crate::parse::SerializeAt;
+
+    const BIAS: u64 = 0x7f12_3456_0000;
+
+    /// Lay out the PT_LOAD segments of the file at their addresses, like the dynamic linker.
+    fn load(file_data: &[u8]) -> Vec<u8> {
+        let file = ElfBytes::<AnyEndian>::minimal_parse(file_data).expect("should parse");
+        let loads: Vec<ProgramHeader> = file
+            .segments()
+            .expect("should have segments")
+            .iter()
+            .filter(|phdr| phdr.p_type == abi::PT_LOAD)
+            .collect();
+        let base = loads[0].p_vaddr - loads[0].p_offset;
+        let end = loads
+            .iter()
+            .map(|phdr| phdr.p_vaddr + phdr.p_memsz)
+            .max()
+            .unwrap();
+        let mut image = vec![0u8; (end - base) as usize];
+        for phdr in loads.iter() {
+            let data = file.segment_data(phdr).expect("should read");
+            let start = (phdr.p_vaddr - base) as usize;
+            image[start..start + data.len()].copy_from_slice(data);
+        }
+        image
+    }
+
+    /// Relocate the dynamic entries' addresses by the load bias, like glibc does on x86_64.
+    fn relocate(image: &mut [u8]) {
+        let file = ElfBytes::<AnyEndian>::parse_loaded(image, BIAS).expect("should parse");
+        let phdr = file
+            .segments()
+            .expect("should have segments")
+            .iter()
+            .find(|phdr| phdr.p_type == abi::PT_DYNAMIC)
+            .expect("should have PT_DYNAMIC");
+        let entries: Vec<Dyn> = file.dynamic().unwrap().unwrap().iter().collect();
+        let (endian, class) = (file.ehdr.endianness, file.ehdr.class);
+        let mut offset = phdr.p_vaddr as usize;
+        for mut entry in entries {
+            if matches!(
+                entry.d_tag,
+                abi::DT_GNU_HASH
+                    | abi::DT_STRTAB
+                    | abi::DT_SYMTAB
+                    | abi::DT_JMPREL
+                    | abi::DT_VERNEED
+                    | abi::DT_VERSYM
+                    | abi::DT_PLTGOT
+            ) {
+                entry.d_un += BIAS;
+            }
+            entry
+                .serialize_at(endian, class, &mut offset, image)
+                .expect("should write");
+        }
+    }
+
+    fn check_loaded(image: &[u8]) {
+        let file = ElfBytes::<AnyEndian>::parse_loaded(image, BIAS).expect("should parse");
+        assert_eq!(file.load_bias(), Some(BIAS));
+        assert!(file.section_headers().is_none());
+
+        let (_, index, symbol) = file
+            .symbol_by_name(b"hello")
+            .expect("should parse")
+            .expect("should find hello");
+        assert_eq!(index, 2);
+        assert_eq!(symbol.st_value, 0x2a0);
+
+        let (dynsyms, strtab) = file
+            .dynamic_symbol_table()
+            .expect("should parse")
+            .expect("should have dynsyms");
+        assert_eq!(dynsyms.len(), 3);
+        assert_eq!(
+            strtab
+                .get(dynsyms.get(1).unwrap().st_name as usize)
+                .expect("should parse"),
+            "puts"
+        );
+
+        let requirement = file
+            .symbol_version_table()
+            .expect("should parse")
+            .expect("should have versions")
+            .get_requirement(1)
+            .expect("should parse")
+            .expect("should have requirement");
+        assert_eq!(requirement.file, "libc.so.6");
+        assert_eq!(requirement.name, "GLIBC_2.2.5");
+
+        let relocations = file
+            .dynamic_relocations()
+            .expect("should parse")
+            .expect("should have relocations");
+        assert!(relocations.rel.is_none() && relocations.rela.is_none());
+        let plt: Vec<_> = relocations
+            .plt_rela
+            .expect("should have PLT relocations")
+            .collect();
+        assert_eq!(plt.len(), 1);
+        assert_eq!(plt[0].r_offset, 0x2000);
+        assert_eq!(plt[0].r_sym, 1);
+    }
+
+    #[test]
+    fn parse_loaded_image() {
+        let file_data = std::fs::read("sample-objects/patch.x86_64.so").expect("should read");
+        let image = load(&file_data);
+        check_loaded(&image);
+    }
+
+    #[test]
+    fn parse_loaded_relocated_dynamic() {
+        let file_data = std::fs::read("sample-objects/patch.x86_64.so").expect("should read");
+        let mut image = load(&file_data);
+        relocate(&mut image);
+        check_loaded(&image);
+    }
+
+    #[test]
+    fn parse_loaded_exec_image() {
+        let file_data = std::fs::read("sample-objects/basic.x86_64").expect("should read");
+        let image = load(&file_data);
+        let file = ElfBytes::<AnyEndian>::parse_loaded(&image, 0).expect("should parse");
+        let interp = file
+            .segments()
+            .expect("should have segments")
+            .iter()
+            .find(|phdr| phdr.p_type == abi::PT_INTERP)
+            .expect("should have PT_INTERP");
+        assert_eq!(
+            file.segment_data(&interp).expect("should read"),
+            b"/lib64/ld-linux-x86-64.so.2\0"
+        );
+        let (dynsyms, _) = file
+            .dynamic_symbol_table()
+            .expect("should parse")
+            .expect("should have dynsyms");
+        assert_eq!(dynsyms.len(), 4);
+    }
+
+    #[test]
+    fn parse_vdso() {
+        let mut vdso_data = std::fs::read("sample-objects/vdso.x86_64").expect("should read");
+        for with_shdrs in [true, false] {
+            if !with_shdrs {
+                // Some architectures' vDSOs don't have section headers
+                vdso_data[0x28..0x30].fill(0);
+                vdso_data[0x3c..0x40].fill(0);
+            }
+            let vdso = ElfBytes::<AnyEndian>::parse_vdso(&vdso_data).expect("should parse");
+            assert_eq!(vdso.ehdr.e_type, abi::ET_DYN);
+
+            let (_, index, symbol) = vdso
+                .symbol_by_name(b"__vdso_clock_gettime")
+                .expect("should parse")
+                .expect("should find __vdso_clock_gettime");
+            assert_eq!(index, 9);
+            assert_eq!(symbol.st_value, 0xec0);
+            assert!(vdso
+                .symbol_by_name(b"clock_settime")
+                .expect("should parse")
+                .is_none());
+
+            let exports = vdso.exports().expect("should parse");
+            let export = exports
+                .iter()
+                .find(|export| export.name == "__vdso_getcpu")
+                .expect("should export __vdso_getcpu");
+            assert_eq!(export.symbol.st_value, 0xf50);
+            assert_eq!(export.version, Some("LINUX_2.6"));
+        }
+    }
+
+    #[test]
+    fn dynamic_relocations_from_file() {
+        let file_data = std::fs::read("sample-objects/basic.x86_64").expect("should read");
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("should parse");
+        let relocations = file
+            .dynamic_relocations()
+            .expect("should parse")
+            .expect("should have relocations");
+        assert_eq!(relocations.rela.expect("should have .rela.dyn").count(), 1);
+        assert_eq!(
+            relocations.plt_rela.expect("should have .rela.plt").count(),
+            2
+        );
+    }
+}
+
+#[cfg(test)]
+mod interpreter_tests {
+    use super::*;
+    use crate::endian::AnyEndian;
+
+    fn basic() -> Vec<u8> {
+        std::fs::read("sample-objects/basic.x86_64").expect("should read")
+    }
+
+    #[test]
+    fn interpreter() {
+        let file_data = basic();
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("should parse");
+        assert_eq!(
+            file.interpreter().expect("should parse"),
+            Some(b"/lib64/ld-linux-x86-64.so.2".as_slice())
+        );
+        assert_eq!(
+            file.interpreter_str().expect("should parse"),
+            Some("/lib64/ld-linux-x86-64.so.2")
+        );
+    }
+
+    #[test]
+    fn interpreter_without_phdrs_uses_section() {
+        let mut file_data = basic();
+        file_data[0x20..0x28].fill(0);
+        file_data[0x38..0x3a].fill(0);
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("should parse");
+        assert!(file.segments().is_none());
+        assert_eq!(
+            file.interpreter_str().expect("should parse"),
+            Some("/lib64/ld-linux-x86-64.so.2")
+        );
+    }
+
+    #[test]
+    fn interpreter_corrupt_size() {
+        // PT_INTERP is the second program header, and its p_filesz is at 0x98
+        let mut file_data = basic();
+        file_data[0x98] = 0;
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("should parse");
+        assert_eq!(
+            file.interpreter().expect("should parse"),
+            Some(b"".as_slice())
+        );
+
+        // Cut the NUL terminator off
+        file_data[0x98] = 0x1b;
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("should parse");
+        assert_eq!(
+            file.interpreter().expect("should parse"),
+            Some(b"/lib64/ld-linux-x86-64.so.2".as_slice())
+        );
+
+        file_data[0x98] = 0x10;
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("should parse");
+        assert_eq!(
+            file.interpreter().expect("should parse"),
+            Some(b"/lib64/ld-linux-".as_slice())
+        );
+    }
+
+    #[test]
+    fn no_interpreter() {
+        let file_data = std::fs::read("sample-objects/symver.x86_64.so").expect("should read");
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("should parse");
+        assert_eq!(file.interpreter().expect("should parse"), None);
+
+        let file_data = std::fs::read("sample-objects/stack_sizes.x86_64.o").expect("should read");
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("should parse");
+        assert!(file.segments().is_none());
+        assert_eq!(file.interpreter_str().expect("should parse"), None);
+    }
+}
+
+#[cfg(test)]
+mod classify_tests {
+    use super::*;
+    use crate::endian::AnyEndian;
+
+    /// Get (is_pie, is_statically_linked, is_stripped, has_debug_info) for the data
+    fn classify(file_data: &[u8]) -> (bool, bool, bool, bool) {
+        let file = ElfBytes::<AnyEndian>::minimal_parse(file_data).expect("should parse");
+        (
+            file.is_pie().expect("should parse"),
+            file.is_statically_linked().expect("should parse"),
+            file.is_stripped().expect("should parse"),
+            file.has_debug_info().expect("should parse"),
+        )
+    }
+
+    fn classify_path(path: &str) -> (bool, bool, bool, bool) {
+        classify(&std::fs::read(path).expect("should read"))
+    }
+
+    #[test]
+    fn classify_executables() {
+        assert_eq!(
+            classify_path("sample-objects/basic.x86_64"),
+            (false, false, false, false)
+        );
+        assert_eq!(
+            classify_path("sample-objects/pie.x86_64"),
+            (true, false, false, false)
+        );
+        assert_eq!(
+            classify_path("sample-objects/static.x86_64"),
+            (false, true, false, false)
+        );
+        assert_eq!(
+            classify_path("sample-objects/static_pie.x86_64"),
+            (true, true, false, false)
+        );
+    }
+
+    #[test]
+    fn classify_libraries_and_objects() {
+        assert_eq!(
+            classify_path("sample-objects/symver.x86_64.so"),
+            (false, false, false, false)
+        );
+        assert_eq!(
+            classify_path("sample-objects/stripped.x86_64.so"),
+            (false, false, true, false)
+        );
+        // Relocatable objects aren't linked yet
+        assert_eq!(
+            classify_path("sample-objects/debug_gz.x86_64.o"),
+            (false, false, false, true)
+        );
+        // The separate debug info lives in the file named by .gnu_debuglink
+        assert_eq!(
+            classify_path("sample-objects/debuglink.x86_64"),
+            (false, false, false, false)
+        );
+    }
+
+    /// Read the file and clear its DF_1_PIE flag, like older linkers left it
+    fn without_pie_flag(path: &str) -> Vec<u8> {
+        let mut file_data = std::fs::read(path).expect("should read");
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("should parse");
+        let shdr = file
+            .section_header_by_name(".dynamic")
+            .expect("should parse")
+            .expect("should have .dynamic");
+        let index = file
+            .dynamic()
+            .expect("should parse")
+            .expect("should have .dynamic")
+            .iter()
+            .position(|entry| entry.d_tag == abi::DT_FLAGS_1)
+            .expect("should have DT_FLAGS_1");
+        // Elf64_Dyn's d_val follows its 8 byte d_tag
+        let offset = shdr.sh_offset as usize + index * 16 + 8;
+        file_data[offset..offset + 8].fill(0);
+        file_data
+    }
+
+    #[test]
+    fn is_pie_without_flag() {
+        // Falls back to checking for an interpreter
+        let file_data = without_pie_flag("sample-objects/pie.x86_64");
+        assert_eq!(classify(&file_data), (true, false, false, false));
+
+        // Without either, it looks like a shared library
+        let file_data = without_pie_flag("sample-objects/static_pie.x86_64");
+        assert_eq!(classify(&file_data), (false, false, false, false));
+    }
+
+    #[test]
+    fn debug_info_section_names() {
+        let progbits = SectionHeader {
+            sh_type: abi::SHT_PROGBITS,
+            ..Default::default()
+        };
+        let nobits = SectionHeader {
+            sh_type: abi::SHT_NOBITS,
+            ..Default::default()
+        };
+        assert!(is_debug_info_section(b".debug_info", &progbits));
+        assert!(is_debug_info_section(b".zdebug_line", &progbits));
+        assert!(!is_debug_info_section(b".debug_info", &nobits));
+        assert!(!is_debug_info_section(b".gnu_debuglink", &progbits));
+        assert!(!is_debug_info_section(b".rela.debug_info", &progbits));
+    }
+}
+
+#[cfg(test)]
+mod init_functions_tests {
+    use super::*;
+    use crate::endian::AnyEndian;
+
+    fn addrs(table: Option<AddrTable<'_, AnyEndian>>) -> Vec<u64> {
+        table.expect("should have table").functions().collect()
+    }
+
+    #[test]
+    fn init_functions() {
+        let mut file_data = std::fs::read("sample-objects/basic.x86_64").expect("should read");
+        for with_shdrs in [true, false] {
+            if !with_shdrs {
+                // The tables are found through the dynamic section instead
+                file_data[0x28..0x30].fill(0);
+                file_data[0x3c..0x40].fill(0);
+            }
+            let file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("should parse");
+            let init = file.init_functions().expect("should parse");
+            assert!(init.preinit_array.is_none());
+            assert_eq!(init.init, Some(0x400390));
+            assert_eq!(addrs(init.init_array), vec![0x4004c0]);
+            assert_eq!(addrs(init.fini_array), vec![0x4004a0]);
+            assert_eq!(init.fini, Some(0x400584));
+        }
+    }
+
+    #[test]
+    fn init_functions_shared_library() {
+        let file_data = std::fs::read("sample-objects/symver.x86_64.so").expect("should read");
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("should parse");
+        let init = file.init_functions().expect("should parse");
+        assert_eq!(init.init, Some(0x1000));
+        assert_eq!(addrs(init.init_array), vec![0x1110]);
+        assert_eq!(addrs(init.fini_array), vec![0x10d0]);
+        assert_eq!(init.fini, Some(0x1170));
+    }
+
+    #[test]
+    fn init_functions_static() {
+        let file_data = std::fs::read("sample-objects/static.x86_64").expect("should read");
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("should parse");
+        let init = file.init_functions().expect("should parse");
+        assert!(init.preinit_array.is_none());
+        assert!(init.init.is_none());
+        assert!(init.init_array.is_none());
+        assert!(init.fini_array.is_none());
+        assert!(init.fini.is_none());
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod plt_tests {
+    use super::*;
+    use crate::endian::AnyEndian;
+    use crate::plt::PltTarget;
+
+    fn symbol_entries(entries: Vec<PltEntry<'_>>) -> Vec<(u64, Option<&str>)> {
+        entries
+            .into_iter()
+            .map(|entry| match entry.target {
+                PltTarget::Symbol { name, .. } => (entry.addr, name),
+                other => panic!("Unexpected target: {other:?}"),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn plt_entries_lazy_binding() {
+        let mut file_data = std::fs::read("sample-objects/basic.x86_64").expect("should read");
+        for with_shdrs in [true, false] {
+            if !with_shdrs {
+                // The PLT is found through the initial GOT values instead
+                file_data[0x28..0x30].fill(0);
+                file_data[0x3c..0x40].fill(0);
+            }
+            let file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("should parse");
+            let entries = file.plt_entries().expect("should parse");
+            // Without sections, the .dynsym is found through the dynamic section
+            assert_eq!(
+                symbol_entries(entries.expect("should have entries")),
+                vec![
+                    (0x4003c0, Some("memset")),
+                    (0x4003d0, Some("__libc_start_main"))
+                ]
+            );
+        }
+    }
+
+    #[test]
+    fn plt_entries_ibt_with_irelative() {
+        let mut file_data = std::fs::read("sample-objects/plt.x86_64").expect("should read");
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("should parse");
+        let entries = file
+            .plt_entries()
+            .expect("should parse")
+            .expect("should have entries");
+        assert_eq!(
+            entries,
+            vec![
+                PltEntry {
+                    addr: 0x640,
+                    target: PltTarget::Symbol {
+                        index: 3,
+                        name: Some("puts")
+                    }
+                },
+                PltEntry {
+                    addr: 0x650,
+                    target: PltTarget::Symbol {
+                        index: 4,
+                        name: Some("strlen")
+                    }
+                },
+                PltEntry {
+                    addr: 0x660,
+                    target: PltTarget::IRelative { resolver: 0x7a0 }
+                },
+            ]
+        );
+
+        // The GOT values point into .plt rather than .plt.sec, so the layout is unknown
+        file_data[0x28..0x30].fill(0);
+        file_data[0x3c..0x40].fill(0);
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("should parse");
+        assert!(file.plt_entries().expect("should parse").is_none());
+    }
+
+    #[test]
+    fn plt_entries_aarch64() {
+        let mut file_data = std::fs::read("sample-objects/symver.aarch64.so").expect("should read");
+        for with_shdrs in [true, false] {
+            if !with_shdrs {
+                file_data[0x28..0x30].fill(0);
+                file_data[0x3c..0x40].fill(0);
+            }
+            let file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("should parse");
+            let entries = file.plt_entries().expect("should parse");
+            assert_eq!(
+                symbol_entries(entries.expect("should have entries")),
+                vec![
+                    (0x600, Some("__cxa_finalize")),
+                    (0x610, Some("memset")),
+                    (0x620, Some("__gmon_start__")),
+                ]
+            );
+        }
+    }
+
+    #[test]
+    fn plt_entries_unsupported() {
+        let file_data = std::fs::read("sample-objects/phnum.m68k.so").expect("should read");
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("should parse");
+        assert!(file.plt_entries().expect("should parse").is_none());
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod got_tests {
+    use super::*;
+    use crate::endian::AnyEndian;
+
+    #[test]
+    fn got_slots() {
+        let mut file_data = std::fs::read("sample-objects/basic.x86_64").expect("should read");
+        let symbol = |r_type, index, name| GotTarget::Symbol {
+            r_type,
+            index,
+            name,
+        };
+        let reserved = |addr, value| GotSlot {
+            addr,
+            value,
+            target: GotTarget::Reserved,
+        };
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("should parse");
+        let slots = file.got_slots().expect("should parse");
+        assert_eq!(
+            slots.expect("should have a GOT"),
+            vec![
+                GotSlot {
+                    addr: 0x6008a8,
+                    value: 0,
+                    target: symbol(abi::R_X86_64_GLOB_DAT, 3, Some("__gmon_start__")),
+                },
+                reserved(0x6008b0, 0x6006d8),
+                reserved(0x6008b8, 0),
+                reserved(0x6008c0, 0),
+                GotSlot {
+                    addr: 0x6008c8,
+                    value: 0x4003c6,
+                    target: symbol(abi::R_X86_64_JUMP_SLOT, 1, Some("memset")),
+                },
+                GotSlot {
+                    addr: 0x6008d0,
+                    value: 0x4003d6,
+                    target: symbol(abi::R_X86_64_JUMP_SLOT, 2, Some("__libc_start_main")),
+                },
+            ]
+        );
+
+        // Without section headers, only the .got.plt is found, but the symbol names still are
+        file_data[0x28..0x30].fill(0);
+        file_data[0x3c..0x40].fill(0);
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("should parse");
+        let slots = file.got_slots().expect("should parse");
+        assert_eq!(
+            slots.expect("should have a GOT"),
+            vec![
+                reserved(0x6008b0, 0x6006d8),
+                reserved(0x6008b8, 0),
+                reserved(0x6008c0, 0),
+                GotSlot {
+                    addr: 0x6008c8,
+                    value: 0x4003c6,
+                    target: symbol(abi::R_X86_64_JUMP_SLOT, 1, Some("memset")),
+                },
+                GotSlot {
+                    addr: 0x6008d0,
+                    value: 0x4003d6,
+                    target: symbol(abi::R_X86_64_JUMP_SLOT, 2, Some("__libc_start_main")),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn got_slots_with_irelative() {
+        let file_data = std::fs::read("sample-objects/plt.x86_64").expect("should read");
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("should parse");
+        let slots = file
+            .got_slots()
+            .expect("should parse")
+            .expect("should have a GOT");
+        assert_eq!(slots.len(), 11);
+        assert!(matches!(
+            slots[4].target,
+            GotTarget::Symbol {
+                name: Some("__cxa_finalize"),
+                ..
+            }
+        ));
+        assert_eq!(
+            slots[10].target,
+            GotTarget::Local {
+                r_type: abi::R_X86_64_IRELATIVE
+            }
+        );
+    }
+
+    #[test]
+    fn got_slots_no_dynamic() {
+        let file_data = std::fs::read("sample-objects/stack_sizes.x86_64.o").expect("should read");
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("should parse");
+        assert!(file.got_slots().expect("should parse").is_none());
+    }
+}
+
+#[cfg(test)]
+mod textrel_tests {
+    use super::*;
+    use crate::endian::AnyEndian;
+
+    #[test]
+    fn no_text_relocations() {
+        let file_data = std::fs::read("sample-objects/basic.x86_64").expect("should read");
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("should parse");
+        assert!(!file.has_text_relocations().expect("should parse"));
+        assert!(!file.has_wx_segments());
+    }
+
+    #[test]
+    fn text_relocations() {
+        let mut file_data = std::fs::read("sample-objects/textrel.x86_64.so").expect("should read");
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("should parse");
+        assert!(file.has_text_relocations().expect("should parse"));
+        // The GNU_STACK segment is RWE
+        assert!(file.has_wx_segments());
+
+        // Replace DT_TEXTREL with DT_DEBUG and clear DT_FLAGS, so that only the
+        // relocation in the read-only segment is left
+        file_data[0xf80] = abi::DT_DEBUG as u8;
+        file_data[0xf98] = 0;
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("should parse");
+        assert!(file.has_text_relocations().expect("should parse"));
+
+        // Make the first PT_LOAD writable, which makes it a W+X segment instead
+        file_data[0x44] |= abi::PF_W as u8;
+        // and make the stack non-executable
+        file_data[0x40 + 3 * 0x38 + 4] = (abi::PF_R | abi::PF_W) as u8;
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("should parse");
+        assert!(!file.has_text_relocations().expect("should parse"));
+        assert!(file.has_wx_segments());
+        file_data[0x44] &= !(abi::PF_W as u8);
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("should parse");
+        assert!(!file.has_wx_segments());
+    }
+}
+
+#[cfg(test)]
+mod stack_tests {
+    use super::*;
+    use crate::builder::ElfBuilder;
+    use crate::endian::{AnyEndian, LittleEndian};
+
+    #[test]
+    fn stack_segment() {
+        let file_data = std::fs::read("sample-objects/basic.x86_64").expect("should read");
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("should parse");
+        assert_eq!(file.stack_executable(), Some(false));
+        assert_eq!(file.object_stack_executable().expect("should parse"), None);
+
+        // The GNU_STACK segment is RWE
+        let mut file_data = std::fs::read("sample-objects/textrel.x86_64.so").expect("should read");
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("should parse");
+        assert_eq!(file.stack_executable(), Some(true));
+
+        // Turn the GNU_STACK segment into a PT_NULL one
+        file_data[0x40 + 3 * 0x38..][..4].fill(0);
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("should parse");
+        assert_eq!(file.stack_executable(), None);
+    }
+
+    #[test]
+    fn stack_note_section() {
+        let file_data = std::fs::read("sample-objects/stack_sizes.x86_64.o").expect("should read");
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("should parse");
+        assert_eq!(file.stack_executable(), None);
+        assert_eq!(
+            file.object_stack_executable().expect("should parse"),
+            Some(false)
+        );
+
+        let build = |sections: &[(&str, u64)]| {
+            let mut builder =
+                ElfBuilder::new(LittleEndian, Class::ELF64, abi::ET_REL, abi::EM_X86_64);
+            for (name, sh_flags) in sections {
+                let shdr = SectionHeader {
+                    sh_type: abi::SHT_PROGBITS,
+                    sh_flags: *sh_flags,
+                    ..Default::default()
+                };
+                builder.add_section(name, shdr, b"");
+            }
+            builder.build().expect("should build")
+        };
+        let text = (".text", (abi::SHF_ALLOC | abi::SHF_EXECINSTR) as u64);
+        let data = build(&[text, (".note.GNU-stack", abi::SHF_EXECINSTR as u64)]);
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&data).expect("should parse");
+        assert_eq!(
+            file.object_stack_executable().expect("should parse"),
+            Some(true)
+        );
+
+        let data = build(&[text]);
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&data).expect("should parse");
+        assert_eq!(file.object_stack_executable().expect("should parse"), None);
+    }
+}
+
+#[cfg(test)]
+mod relro_tests {
+    use super::*;
+    use crate::endian::AnyEndian;
+
+    #[test]
+    fn relro_coverage() {
+        let file_data = std::fs::read("sample-objects/basic.x86_64").expect("should read");
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("should parse");
+        assert_eq!(file.relro_coverage().expect("should parse"), None);
+        assert_eq!(file.relro().expect("should parse"), Relro::None);
+
+        // GNU_RELRO spans 0x1dd0..0x2000, which ends partway into .got.plt at 0x1fe8..0x2018
+        let file_data = std::fs::read("sample-objects/plt.x86_64").expect("should read");
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("should parse");
+        let coverage = file
+            .relro_coverage()
+            .expect("should parse")
+            .expect("should have GNU_RELRO");
+        assert_eq!(coverage.phdr.p_vaddr, 0x1dd0);
+        assert_eq!(coverage.got_plt, Some(false));
+        assert_eq!(coverage.got, Some(true));
+        assert_eq!(coverage.init_array, Some(true));
+        assert_eq!(coverage.fini_array, Some(true));
+        assert_eq!(coverage.dynamic, Some(true));
+        assert_eq!(file.relro().expect("should parse"), Relro::Partial);
+    }
+
+    #[test]
+    fn relro_full() {
+        let mut file_data = std::fs::read("sample-objects/plt.x86_64").expect("should read");
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("should parse");
+        let (phdr_index, phdr) = file
+            .segments()
+            .expect("should have segments")
+            .iter()
+            .enumerate()
+            .find(|(_, phdr)| phdr.p_type == abi::PT_GNU_RELRO)
+            .expect("should have GNU_RELRO");
+        let flags_1 = file
+            .dynamic()
+            .expect("should parse")
+            .expect("should have .dynamic")
+            .iter()
+            .position(|dyn_| dyn_.d_tag == abi::DT_FLAGS_1)
+            .expect("should have DT_FLAGS_1");
+        let dynamic = file
+            .section_header_by_name(".dynamic")
+            .expect("should parse")
+            .expect("should have .dynamic");
+
+        // Grow GNU_RELRO over .got.plt: that's not enough without BIND_NOW
+        let memsz_offset = file.ehdr.e_phoff as usize + phdr_index * 0x38 + 40;
+        file_data[memsz_offset..][..8].copy_from_slice(&(phdr.p_memsz + 0x18).to_le_bytes());
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("should parse");
+        assert_eq!(file.relro().expect("should parse"), Relro::Partial);
+
+        // Then turn DT_FLAGS_1 into DT_FLAGS_1 | DF_1_NOW
+        let value_offset = dynamic.sh_offset as usize + flags_1 * 0x10 + 8;
+        file_data[value_offset] |= abi::DF_1_NOW as u8;
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("should parse");
+        let coverage = file.relro_coverage().expect("should parse").unwrap();
+        assert_eq!(coverage.got_plt, Some(true));
+        assert_eq!(file.relro().expect("should parse"), Relro::Full);
+    }
+
+    #[test]
+    fn contains_section() {
+        let phdr = ProgramHeader {
+            p_type: abi::PT_LOAD,
+            p_vaddr: 0x1000,
+            p_memsz: 0x100,
+            ..Default::default()
+        };
+        let shdr = |sh_type, sh_flags: u32, sh_addr, sh_size| SectionHeader {
+            sh_type,
+            sh_flags: sh_flags as u64,
+            sh_addr,
+            sh_size,
+            ..Default::default()
+        };
+        let alloc = abi::SHF_ALLOC;
+        assert!(phdr.contains_section(&shdr(abi::SHT_PROGBITS, alloc, 0x1000, 0x100)));
+        assert!(!phdr.contains_section(&shdr(abi::SHT_PROGBITS, alloc, 0x1080, 0x100)));
+        assert!(!phdr.contains_section(&shdr(abi::SHT_PROGBITS, alloc, 0xf00, 0x200)));
+        assert!(!phdr.contains_section(&shdr(abi::SHT_PROGBITS, 0, 0x1000, 0x10)));
+        // .bss counts with its memory size
+        assert!(phdr.contains_section(&shdr(abi::SHT_NOBITS, alloc, 0x1080, 0x80)));
+        // .tbss doesn't take space outside of PT_TLS
+        let tbss = shdr(abi::SHT_NOBITS, alloc | abi::SHF_TLS, 0x1080, 0x10);
+        assert!(!phdr.contains_section(&tbss));
+        let tls = ProgramHeader {
+            p_type: abi::PT_TLS,
+            ..phdr
+        };
+        assert!(tls.contains_section(&tbss));
+        // Empty sections count inside the segment, but not at its end
+        assert!(phdr.contains_section(&shdr(abi::SHT_PROGBITS, alloc, 0x10ff, 0)));
+        assert!(!phdr.contains_section(&shdr(abi::SHT_PROGBITS, alloc, 0x1100, 0)));
+        assert!(!phdr.contains_section(&shdr(abi::SHT_PROGBITS, alloc, u64::MAX, 2)));
+    }
+}
+
+#[cfg(test)]
+mod liblist_tests {
+    use super::*;
+    use crate::builder::ElfBuilder;
+    use crate::endian::{AnyEndian, LittleEndian};
+
+    fn build_prelinked() -> Vec<u8> {
+        let mut builder = ElfBuilder::new(LittleEndian, Class::ELF32, abi::ET_EXEC, abi::EM_386);
+        let sections: [(&str, u32, u64, Vec<u8>); 3] = [
+            (
+                ".dynstr",
+                abi::SHT_STRTAB,
+                0x1000,
+                b"\0libc.so.6\0".to_vec(),
+            ),
+            (
+                ".gnu.liblist",
+                abi::SHT_GNU_LIBLIST,
+                0x2000,
+                [1u32, 0x4a5b6c7d, 0x12345678, 0, abi::LL_EXACT_MATCH]
+                    .iter()
+                    .flat_map(|field| field.to_le_bytes())
+                    .collect(),
+            ),
+            (
+                ".dynamic",
+                abi::SHT_DYNAMIC,
+                0x3000,
+                [
+                    (abi::DT_STRTAB, 0x1000),
+                    (abi::DT_STRSZ, 11),
+                    (abi::DT_GNU_LIBLIST, 0x2000),
+                    (abi::DT_GNU_LIBLISTSZ, 20),
+                    (abi::DT_NULL, 0),
+                ]
+                .iter()
+                .flat_map(|(tag, val)| [*tag as u32, *val])
+                .flat_map(|field| field.to_le_bytes())
+                .collect(),
+            ),
+        ];
+        for (name, sh_type, addr, data) in sections {
+            let index = builder.add_section(
+                name,
+                SectionHeader {
+                    sh_type,
+                    sh_flags: abi::SHF_ALLOC as u64,
+                    sh_addr: addr,
+                    sh_link: if sh_type == abi::SHT_STRTAB { 0 } else { 1 },
+                    sh_addralign: 4,
+                    ..Default::default()
+                },
+                &data,
+            );
+            let p_types: &[u32] = match sh_type {
+                abi::SHT_DYNAMIC => &[abi::PT_LOAD, abi::PT_DYNAMIC],
+                _ => &[abi::PT_LOAD],
+            };
+            for p_type in p_types {
+                builder.add_segment(
+                    ProgramHeader {
+                        p_type: *p_type,
+                        p_flags: abi::PF_R,
+                        p_vaddr: addr,
+                        p_paddr: addr,
+                        p_align: 4,
+                        ..Default::default()
+                    },
+                    Some(index),
+                );
+            }
+        }
+        builder.build().expect("should build")
+    }
+
+    #[test]
+    fn gnu_liblist() {
+        let mut file_data = build_prelinked();
+        for with_shdrs in [true, false] {
+            if !with_shdrs {
+                // The list is found through the dynamic section instead
+                file_data[0x20..0x24].fill(0);
+                file_data[0x30..0x34].fill(0);
+            }
+            let file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("should parse");
+            let libs: Vec<_> = file
+                .gnu_liblist()
+                .expect("should parse")
+                .expect("should have a library list")
+                .collect::<Result<_, _>>()
+                .expect("should parse");
+            assert_eq!(libs.len(), 1);
+            let (name, entry) = libs[0];
+            assert_eq!(name, "libc.so.6");
+            assert_eq!(entry.l_time_stamp, 0x4a5b6c7d);
+            assert_eq!(entry.l_flags, abi::LL_EXACT_MATCH);
+        }
+    }
+
+    #[test]
+    fn no_gnu_liblist() {
+        let file_data = std::fs::read("sample-objects/basic.x86_64").expect("should read");
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("should parse");
+        assert!(file.gnu_liblist().expect("should parse").is_none());
+    }
+}
+
+#[cfg(test)]
+mod go_tests {
+    use super::*;
+    use crate::builder::ElfBuilder;
+    use crate::endian::{AnyEndian, BigEndian, LittleEndian};
+    use crate::go::{GO_BUILDINFO_FLAG_BIG_ENDIAN, GO_BUILDINFO_FLAG_INLINE};
+
+    const MODINFO: &[u8] = b"0123456789abcdefpath\texample.com/hello\nfedcba9876543210";
+
+    /// Add a `.go.buildinfo` section at 0x2000 and a writable segment covering it
+    fn add_buildinfo<E: EndianParse>(builder: &mut ElfBuilder<E>, data: &[u8]) {
+        let index = builder.add_section(
+            GO_BUILDINFO_SECTION_NAME,
+            SectionHeader {
+                sh_type: abi::SHT_PROGBITS,
+                sh_flags: (abi::SHF_ALLOC | abi::SHF_WRITE) as u64,
+                sh_addr: 0x2000,
+                sh_addralign: 16,
+                ..Default::default()
+            },
+            data,
+        );
+        builder.add_segment(
+            ProgramHeader {
+                p_type: abi::PT_LOAD,
+                p_flags: abi::PF_R | abi::PF_W,
+                p_vaddr: 0x2000,
+                p_paddr: 0x2000,
+                p_align: 16,
+                ..Default::default()
+            },
+            Some(index),
+        );
+    }
+
+    fn buildinfo_header(ptr_size: u8, flags: u8) -> Vec<u8> {
+        let mut data = GO_BUILDINFO_MAGIC.to_vec();
+        data.extend_from_slice(&[ptr_size, flags]);
+        data.resize(32, 0);
+        data
+    }
+
+    #[test]
+    fn go_build_metadata_inline() {
+        let mut builder = ElfBuilder::new(LittleEndian, Class::ELF64, abi::ET_EXEC, abi::EM_X86_64);
+        let mut note = Vec::new();
+        for word in [4u32, 8, abi::NT_GO_BUILD_ID as u32] {
+            note.extend_from_slice(&word.to_le_bytes());
+        }
+        note.extend_from_slice(b"Go\0\0abc/defg");
+        let index = builder.add_section(
+            ".note.go.buildid",
+            SectionHeader {
+                sh_type: abi::SHT_NOTE,
+                sh_flags: abi::SHF_ALLOC as u64,
+                sh_addr: 0x1000,
+                sh_addralign: 4,
+                ..Default::default()
+            },
+            &note,
+        );
+        builder.add_segment(
+            ProgramHeader {
+                p_type: abi::PT_NOTE,
+                p_flags: abi::PF_R,
+                p_vaddr: 0x1000,
+                p_paddr: 0x1000,
+                p_align: 4,
+                ..Default::default()
+            },
+            Some(index),
+        );
+        let mut buildinfo = buildinfo_header(8, GO_BUILDINFO_FLAG_INLINE);
+        buildinfo.push(8);
+        buildinfo.extend_from_slice(b"go1.21.5");
+        buildinfo.push(MODINFO.len() as u8);
+        buildinfo.extend_from_slice(MODINFO);
+        add_buildinfo(&mut builder, &buildinfo);
+        let mut file_data = builder.build().expect("should build");
+
+        for with_shdrs in [true, false] {
+            if !with_shdrs {
+                // The note and build info are found through the segments instead
+                file_data[0x28..0x30].fill(0);
+                file_data[0x3c..0x40].fill(0);
+            }
+            let file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("should parse");
+            assert_eq!(file.go_build_id().expect("should parse"), Some("abc/defg"));
+            let info = file
+                .go_build_info()
+                .expect("should parse")
+                .expect("should have build info");
+            assert_eq!(info.version, "go1.21.5");
+            assert_eq!(info.modinfo, b"path\texample.com/hello\n");
+        }
+    }
+
+    #[test]
+    fn go_build_info_pointers() {
+        let mut builder = ElfBuilder::new(BigEndian, Class::ELF32, abi::ET_EXEC, abi::EM_PPC);
+        let mut buildinfo = buildinfo_header(4, GO_BUILDINFO_FLAG_BIG_ENDIAN);
+        // Pointers to the string headers at 0x2020 and 0x2028
+        buildinfo[16..24].copy_from_slice(&[0, 0, 0x20, 0x20, 0, 0, 0x20, 0x28]);
+        // The string headers, pointing to the strings at 0x2030 and 0x2038
+        for word in [0x2030u32, 8, 0x2038, MODINFO.len() as u32] {
+            buildinfo.extend_from_slice(&word.to_be_bytes());
+        }
+        buildinfo.extend_from_slice(b"go1.16.2");
+        buildinfo.extend_from_slice(MODINFO);
+        add_buildinfo(&mut builder, &buildinfo);
+        let file_data = builder.build().expect("should build");
+
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("should parse");
+        assert_eq!(file.go_build_id().expect("should parse"), None);
+        let info = file
+            .go_build_info()
+            .expect("should parse")
+            .expect("should have build info");
+        assert_eq!(info.version, "go1.16.2");
+        assert_eq!(info.modinfo, b"path\texample.com/hello\n");
+    }
+
+    #[test]
+    fn no_go_build_info() {
+        let file_data = std::fs::read("sample-objects/basic.x86_64").expect("should read");
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("should parse");
+        assert_eq!(file.go_build_id().expect("should parse"), None);
+        assert_eq!(file.go_build_info().expect("should parse"), None);
+    }
+}
+
+#[cfg(test)]
+mod package_metadata_tests {
+    use super::*;
+    use crate::builder::ElfBuilder;
+    use crate::endian::{AnyEndian, BigEndian};
+
+    const METADATA: &[u8] = br#"{"type":"rpm","name":"hello","version":"1.0-1"}"#;
+
+    #[test]
+    fn package_metadata() {
+        let mut builder = ElfBuilder::new(BigEndian, Class::ELF64, abi::ET_DYN, abi::EM_PPC64);
+        // A GNU build id note comes first in the same section
+        let mut notes = Vec::new();
+        for word in [4u32, 4, abi::NT_GNU_BUILD_ID as u32] {
+            notes.extend_from_slice(&word.to_be_bytes());
+        }
+        notes.extend_from_slice(b"GNU\0\x01\x02\x03\x04");
+        let descsz = METADATA.len() as u32 + 1;
+        for word in [4, descsz, abi::NT_FDO_PACKAGING_METADATA as u32] {
+            notes.extend_from_slice(&word.to_be_bytes());
+        }
+        notes.extend_from_slice(b"FDO\0");
+        notes.extend_from_slice(METADATA);
+        notes.push(0);
+        notes.resize((notes.len() + 3) & !3, 0);
+
+        let index = builder.add_section(
+            ".note.package",
+            SectionHeader {
+                sh_type: abi::SHT_NOTE,
+                sh_flags: abi::SHF_ALLOC as u64,
+                sh_addr: 0x1000,
+                sh_addralign: 4,
+                ..Default::default()
+            },
+            &notes,
+        );
+        builder.add_segment(
+            ProgramHeader {
+                p_type: abi::PT_NOTE,
+                p_flags: abi::PF_R,
+                p_vaddr: 0x1000,
+                p_paddr: 0x1000,
+                p_align: 4,
+                ..Default::default()
+            },
+            Some(index),
+        );
+        let mut file_data = builder.build().expect("should build");
+
+        for with_shdrs in [true, false] {
+            if !with_shdrs {
+                file_data[0x28..0x30].fill(0);
+                file_data[0x3c..0x40].fill(0);
+            }
+            let file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("should parse");
+            assert_eq!(
+                file.package_metadata().expect("should parse"),
+                Some(METADATA)
+            );
+        }
+    }
+
+    #[test]
+    fn no_package_metadata() {
+        let file_data = std::fs::read("sample-objects/basic.x86_64").expect("should read");
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("should parse");
+        assert_eq!(file.package_metadata().expect("should parse"), None);
+    }
+}
+
+#[cfg(test)]
+mod os_abi_note_tests {
+    use super::*;
+    use crate::builder::ElfBuilder;
+    use crate::endian::{AnyEndian, BigEndian, LittleEndian};
+
+    /// Build an object with a `.note.tag` section holding the given notes, each with a 4-byte
+    /// word as its descriptor
+    fn build_with_notes<E: EndianParse>(
+        endian: E,
+        class: Class,
+        notes: &[(&[u8], u64, &[u8])],
+    ) -> Vec<u8> {
+        let word = |value: u32| match endian.is_little() {
+            true => value.to_le_bytes(),
+            false => value.to_be_bytes(),
+        };
+        let mut data = Vec::new();
+        for (name, n_type, desc) in notes {
+            data.extend_from_slice(&word(name.len() as u32));
+            data.extend_from_slice(&word(desc.len() as u32));
+            data.extend_from_slice(&word(*n_type as u32));
+            data.extend_from_slice(name);
+            data.resize((data.len() + 3) & !3, 0);
+            data.extend_from_slice(desc);
+            data.resize((data.len() + 3) & !3, 0);
+        }
+
+        let mut builder = ElfBuilder::new(endian, class, abi::ET_EXEC, abi::EM_X86_64);
+        builder.add_section(
+            ".note.tag",
+            SectionHeader {
+                sh_type: abi::SHT_NOTE,
+                sh_flags: abi::SHF_ALLOC as u64,
+                sh_addralign: 4,
+                ..Default::default()
+            },
+            &data,
+        );
+        builder.build().expect("should build")
+    }
+
+    #[test]
+    fn freebsd() {
+        let version = 1400097u32.to_le_bytes();
+        let feature_ctl = abi::NT_FREEBSD_FCTL_WXNEEDED.to_le_bytes();
+        let file_data = build_with_notes(
+            LittleEndian,
+            Class::ELF64,
+            &[
+                (abi::ELF_NOTE_FREEBSD, abi::NT_FREEBSD_ABI_TAG, &version),
+                (abi::ELF_NOTE_FREEBSD, abi::NT_FREEBSD_NOINIT_TAG, &[]),
+                (
+                    abi::ELF_NOTE_FREEBSD,
+                    abi::NT_FREEBSD_FEATURE_CTL,
+                    &feature_ctl,
+                ),
+            ],
+        );
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("should parse");
+        let ident = file
+            .os_abi_note()
+            .expect("should parse")
+            .expect("should have an ident note");
+        assert_eq!(
+            ident,
+            OsIdent::FreeBsd {
+                version: 1400097,
+                feature_ctl: Some(abi::NT_FREEBSD_FCTL_WXNEEDED),
+            }
+        );
+        assert_eq!((ident.major(), ident.minor()), (Some(14), Some(0)));
+
+        let file_data = build_with_notes(
+            LittleEndian,
+            Class::ELF64,
+            &[(
+                abi::ELF_NOTE_FREEBSD,
+                abi::NT_FREEBSD_ABI_TAG,
+                &version[..2],
+            )],
+        );
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("should parse");
+        assert!(matches!(
+            file.os_abi_note(),
+            Err(ParseError::SliceReadError(_))
+        ));
+    }
+
+    #[test]
+    fn netbsd() {
+        let version = 999001000u32.to_be_bytes();
+        let file_data = build_with_notes(
+            BigEndian,
+            Class::ELF32,
+            &[
+                (abi::ELF_NOTE_NETBSD, abi::NT_NETBSD_PAX, &[0, 0, 0, 1]),
+                (abi::ELF_NOTE_NETBSD, abi::NT_NETBSD_IDENT, &version),
+            ],
+        );
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("should parse");
+        let ident = file
+            .os_abi_note()
+            .expect("should parse")
+            .expect("should have an ident note");
+        assert_eq!(ident, OsIdent::NetBsd { version: 999001000 });
+        assert_eq!((ident.major(), ident.minor()), (Some(9), Some(99)));
+    }
+
+    #[test]
+    fn openbsd() {
+        let file_data = build_with_notes(
+            LittleEndian,
+            Class::ELF64,
+            &[(abi::ELF_NOTE_OPENBSD, abi::NT_OPENBSD_IDENT, &[0; 4])],
+        );
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("should parse");
+        let ident = file
+            .os_abi_note()
+            .expect("should parse")
+            .expect("should have an ident note");
+        assert_eq!(ident, OsIdent::OpenBsd);
+        assert_eq!((ident.major(), ident.minor()), (None, None));
+    }
+
+    #[test]
+    fn linux_has_no_ident_note() {
+        let file_data = std::fs::read("sample-objects/basic.x86_64").expect("should read");
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("should parse");
+        assert_eq!(file.os_abi_note().expect("should parse"), None);
+    }
+}
+
+#[cfg(test)]
+mod modinfo_tests {
+    use super::*;
+    use crate::builder::ElfBuilder;
+    use crate::endian::{AnyEndian, LittleEndian};
+    use crate::modinfo::{MODULE_SIG_STRING, PKEY_ID_PKCS7};
+
+    const MODINFO: &[u8] =
+        b"license=GPL\0author=Someone\0\0\0\0depends=\0retpoline=Y\0name=hello\0";
+
+    fn build_module() -> Vec<u8> {
+        let mut builder = ElfBuilder::new(LittleEndian, Class::ELF64, abi::ET_REL, abi::EM_X86_64);
+        builder.add_section(
+            ".modinfo",
+            SectionHeader {
+                sh_type: abi::SHT_PROGBITS,
+                sh_flags: abi::SHF_ALLOC as u64,
+                sh_addralign: 8,
+                ..Default::default()
+            },
+            MODINFO,
+        );
+        builder.build().expect("should build")
+    }
+
+    #[test]
+    fn modinfo() {
+        let file_data = build_module();
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("should parse");
+        let entries: Vec<_> = file
+            .modinfo()
+            .expect("should parse")
+            .expect("should have .modinfo")
+            .collect();
+        assert_eq!(
+            entries,
+            vec![
+                (&b"license"[..], &b"GPL"[..]),
+                (b"author", b"Someone"),
+                (b"depends", b""),
+                (b"retpoline", b"Y"),
+                (b"name", b"hello"),
+            ]
+        );
+        assert_eq!(file.module_signature().expect("should parse"), None);
+    }
+
+    #[test]
+    fn module_signature() {
+        let mut file_data = build_module();
+        let signature = [0x30, 0x82, 0x01, 0x02, 0xaa, 0xbb];
+        file_data.extend_from_slice(&signature);
+        file_data.extend_from_slice(&[0, 0, PKEY_ID_PKCS7, 0, 0, 0, 0, 0, 0, 0, 0, 6]);
+        file_data.extend_from_slice(MODULE_SIG_STRING);
+
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("should parse");
+        assert_eq!(file.modinfo().expect("should parse").unwrap().count(), 5);
+        let sig = file
+            .module_signature()
+            .expect("should parse")
+            .expect("should be signed");
+        assert_eq!(sig.id_type, PKEY_ID_PKCS7);
+        assert_eq!(sig.signature, signature);
+    }
+
+    #[test]
+    fn not_a_module() {
+        let file_data = std::fs::read("sample-objects/basic.x86_64").expect("should read");
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("should parse");
+        assert!(file.modinfo().expect("should parse").is_none());
+        assert_eq!(file.module_signature().expect("should parse"), None);
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod debug_section_tests {
+    use super::*;
+    use crate::endian::AnyEndian;
+
+    #[test]
+    fn plain_and_missing_sections() {
+        for path in [
+            "sample-objects/debug_gz.x86_64.o",
+            "sample-objects/debug_zdebug.x86_64.o",
+        ] {
+            let file_data = std::fs::read(path).expect("should read");
+            let file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("should parse");
+            let abbrev = file.debug_section(".debug_abbrev").expect("should load");
+            assert!(matches!(abbrev, Cow::Borrowed(_)));
+            assert_eq!(abbrev.len(), 0x2d);
+            let str_data = file.debug_section(".debug_str").expect("should load");
+            assert!(str_data.starts_with(b"GNU C"));
+            assert!(file
+                .debug_section(".debug_rnglists")
+                .expect("should load")
+                .is_empty());
+        }
+    }
+
+    #[cfg(feature = "zlib")]
+    #[test]
+    fn compressed_sections() {
+        for path in [
+            "sample-objects/debug_gz.x86_64.o",
+            "sample-objects/debug_zdebug.x86_64.o",
+        ] {
+            let file_data = std::fs::read(path).expect("should read");
+            let file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("should parse");
+            let info = file
+                .debug_section(".debug_info")
+                .expect("should decompress");
+            assert!(matches!(info, Cow::Owned(_)));
+            assert_eq!(info.len(), 0x49);
+            // The unit length and DWARF 5 version
+            assert!(info.starts_with(&[0x45, 0, 0, 0, 5, 0]));
+            let line_str = file
+                .debug_section(".debug_line_str")
+                .expect("should decompress");
+            assert!(line_str.ends_with(b"\0"));
+        }
+
+        // Both formats decompress to the same contents
+        let gz_data = std::fs::read("sample-objects/debug_gz.x86_64.o").expect("should read");
+        let gz = ElfBytes::<AnyEndian>::minimal_parse(&gz_data).expect("should parse");
+        let zdebug_data =
+            std::fs::read("sample-objects/debug_zdebug.x86_64.o").expect("should read");
+        let zdebug = ElfBytes::<AnyEndian>::minimal_parse(&zdebug_data).expect("should parse");
+        for name in [".debug_aranges", ".debug_line", ".debug_line_str"] {
+            assert_eq!(
+                gz.debug_section(name).expect("should decompress"),
+                zdebug.debug_section(name).expect("should decompress"),
+                "{name}"
+            );
+        }
+    }
+
+    #[cfg(not(feature = "zlib"))]
+    #[test]
+    fn compressed_sections_unsupported() {
+        let file_data = std::fs::read("sample-objects/debug_zdebug.x86_64.o").expect("should read");
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("should parse");
+        let err = file.debug_section(".debug_info").unwrap_err();
+        assert!(matches!(
+            err.into_root_cause(),
+            BareParseError::UnsupportedCompression(abi::ELFCOMPRESS_ZLIB)
+        ));
+    }
+
+    #[test]
+    fn nobits_section() {
+        let file_data = std::fs::read("sample-objects/basic.x86_64.debug").expect("should read");
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("should parse");
+        let shdr = file
+            .section_header_by_name(".text")
+            .expect("should parse")
+            .expect("should have .text");
+        assert_eq!(shdr.sh_type, abi::SHT_NOBITS);
+        assert!(file.debug_section(".text").expect("should load").is_empty());
+    }
+}
+
+#[cfg(test)]
+mod padded_entsize_tests {
+    use super::*;
+    use crate::builder::ElfBuilder;
+    use crate::endian::{AnyEndian, LittleEndian};
+    use crate::parse::SerializeAt;
+    use crate::relocation::Rela;
+    use crate::symbol::Symbol;
+
+    /// Serialize the entries `entsize` bytes apart, filling the gaps with junk
+    fn padded<P: SerializeAt>(entries: &[P], entsize: usize) -> Vec<u8> {
+        let mut data = vec![0xaa; entries.len() * entsize];
+        for (i, entry) in entries.iter().enumerate() {
+            let mut offset = i * entsize;
+            entry
+                .serialize_at(LittleEndian, Class::ELF64, &mut offset, &mut data)
+                .unwrap();
+        }
+        data
+    }
+
+    /// Build an object whose symbol table, relocations and section headers all have padding
+    /// after each entry
+    fn build_padded() -> Vec<u8> {
+        let mut builder = ElfBuilder::new(LittleEndian, Class::ELF64, abi::ET_REL, abi::EM_X86_64);
+        let text = builder.add_section(
+            ".text",
+            SectionHeader {
+                sh_type: abi::SHT_PROGBITS,
+                sh_flags: abi::SHF_ALLOC as u64 | abi::SHF_EXECINSTR as u64,
+                ..Default::default()
+            },
+            &[0x90; 16],
+        );
+        let strtab = builder.add_section(
+            ".strtab",
+            SectionHeader {
+                sh_type: abi::SHT_STRTAB,
+                ..Default::default()
+            },
+            b"\0foo\0bar\0",
+        );
+        let symbols = [
+            Symbol::default(),
+            Symbol {
+                st_name: 1,
+                st_info: (abi::STB_GLOBAL << 4) | abi::STT_FUNC,
+                st_shndx: text as u16,
+                st_value: 0x4,
+                st_size: 4,
+                ..Default::default()
+            },
+            Symbol {
+                st_name: 5,
+                st_info: abi::STB_GLOBAL << 4,
+                ..Default::default()
+            },
+        ];
+        let symtab = builder.add_section(
+            ".symtab",
+            SectionHeader {
+                sh_type: abi::SHT_SYMTAB,
+                sh_link: strtab as u32,
+                sh_info: 1,
+                sh_entsize: 32,
+                ..Default::default()
+            },
+            &padded(&symbols, 32),
+        );
+        let relas = [
+            Rela {
+                r_offset: 0x1,
+                r_sym: 2,
+                r_type: abi::R_X86_64_PLT32,
+                r_addend: -4,
+            },
+            Rela {
+                r_offset: 0x9,
+                r_sym: 1,
+                r_type: abi::R_X86_64_PC32,
+                r_addend: -4,
+            },
+        ];
+        builder.add_section(
+            ".rela.text",
+            SectionHeader {
+                sh_type: abi::SHT_RELA,
+                sh_link: symtab as u32,
+                sh_info: text as u32,
+                sh_entsize: 32,
+                ..Default::default()
+            },
+            &padded(&relas, 32),
+        );
+        let mut data = builder.build().unwrap();
+
+        // Move the section headers to the end of the file with 8 bytes of padding after each
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&data).unwrap();
+        let shdrs: Vec<_> = file.section_headers().unwrap().iter().collect();
+        let shoff = data.len();
+        data.extend(padded(&shdrs, 0x48));
+        data[0x28..0x30].copy_from_slice(&(shoff as u64).to_le_bytes());
+        data[0x3a..0x3c].copy_from_slice(&0x48u16.to_le_bytes());
+        data
+    }
+
+    #[test]
+    fn padded_tables() {
+        let data = build_padded();
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&data).expect("should parse");
+        assert_eq!(file.ehdr.e_shentsize, 0x48);
+        assert_eq!(file.section_headers().unwrap().entsize(), 0x48);
+
+        let (symtab, strtab) = file
+            .symbol_table()
+            .expect("should parse")
+            .expect("should have a symtab");
+        assert_eq!(symtab.len(), 3);
+        let names: Vec<_> = symtab
+            .iter()
+            .map(|sym| (strtab.get(sym.st_name as usize).unwrap(), sym.st_value))
+            .collect();
+        assert_eq!(names, [("", 0), ("foo", 0x4), ("bar", 0)]);
+        assert_eq!(symtab.get(1).unwrap().st_shndx, 1);
+
+        let shdr = file
+            .section_header_by_name(".rela.text")
+            .expect("should parse")
+            .expect("should have relocations");
+        let relas: Vec<_> = file
+            .section_data_as_relas(&shdr)
+            .expect("should parse")
+            .map(|rela| (rela.r_offset, rela.r_sym, rela.r_type, rela.r_addend))
+            .collect();
+        assert_eq!(
+            relas,
+            [
+                (0x1, 2, abi::R_X86_64_PLT32, -4),
+                (0x9, 1, abi::R_X86_64_PC32, -4)
+            ]
+        );
+    }
+
+    #[test]
+    fn padded_entry_ranges() {
+        let data = build_padded();
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&data).expect("should parse");
+        let shoff = file.ehdr.e_shoff as usize;
+
+        let (shdr, range) = file
+            .section_header_with_range(3)
+            .expect("should have a symtab header");
+        assert_eq!(shdr.sh_type, abi::SHT_SYMTAB);
+        assert_eq!(range, shoff + 3 * 0x48..shoff + 4 * 0x48);
+        // The range includes the junk padding after the header's fields
+        assert_eq!(&data[range.end - 8..range.end], &[0xaa; 8]);
+        let mut offset = range.start;
+        assert_eq!(
+            SectionHeader::parse_at(LittleEndian, Class::ELF64, &mut offset, &data).unwrap(),
+            shdr
+        );
+
+        let (sym, range) = file
+            .symbol_with_range(&shdr, 1)
+            .expect("should have a symbol");
+        assert_eq!(sym.st_value, 0x4);
+        let start = shdr.sh_offset as usize + 32;
+        assert_eq!(range, start..start + 32);
+        assert!(matches!(
+            file.symbol_with_range(&shdr, 3),
+            Err(ParseError::BadOffset(3))
+        ));
+        assert!(file.section_header_with_range(6).is_err());
+    }
+
+    #[test]
+    fn entsize_too_small() {
+        let mut data = build_padded();
+        data[0x3a..0x3c].copy_from_slice(&0x38u16.to_le_bytes());
+        let result = ElfBytes::<AnyEndian>::minimal_parse(&data).expect_err("should fail");
+        let cause = result.into_root_cause();
+        assert!(
+            matches!(cause, BareParseError::BadEntsize((0x38, 0x40))),
+            "Unexpected Error type found: {cause:?}"
+        );
+    }
+}
+
+#[cfg(test)]
+mod table_limit_tests {
+    use super::*;
+    use crate::endian::AnyEndian;
+
+    fn open(path: &str, options: ParseOptions) -> Result<(), ParseError> {
+        let file_data = std::fs::read(path).expect("Could not read file.");
+        ElfBytes::<AnyEndian>::minimal_parse_with_options(&file_data, options).map(|_| ())
+    }
+
+    #[test]
+    fn header_tables() {
+        let path = "sample-objects/basic.x86_64";
+        open(path, ParseOptions::default()).expect("should parse");
+
+        // basic.x86_64 has 31 section headers and 8 program headers
+        let options = ParseOptions::default().with_max_table_bytes(31 * 64);
+        open(path, options).expect("should parse");
+
+        let options = ParseOptions::default().with_max_table_bytes(1000);
+        let err = open(path, options).expect_err("should fail");
+        let cause = err.into_root_cause();
+        assert!(
+            matches!(cause, BareParseError::LimitExceeded((1984, 1000))),
+            "Unexpected Error type found: {cause:?}"
+        );
+
+        // Permissive mode doesn't salvage tables that are over the limit
+        let options = ParseOptions::permissive().with_max_table_bytes(1000);
+        let err = open(path, options).expect_err("should fail");
+        assert!(matches!(
+            err.into_root_cause(),
+            BareParseError::LimitExceeded(_)
+        ));
+    }
+
+    #[test]
+    fn huge_shnum() {
+        let path = std::path::PathBuf::from("sample-objects/basic.x86_64");
+        let mut file_data = std::fs::read(path).expect("Could not read file.");
+        // e_shnum = 0 with shdr[0].sh_size claiming a huge number of sections
+        file_data[0x3c..0x3e].copy_from_slice(&[0, 0]);
+        let shoff = u64::from_le_bytes(file_data[0x28..0x30].try_into().unwrap()) as usize;
+        file_data[shoff + 0x20..shoff + 0x28].copy_from_slice(&0x0400_0000u64.to_le_bytes());
+
+        let err = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect_err("should fail");
+        let limit = file_data.len() as u64;
+        let cause = err.into_root_cause();
+        assert!(
+            matches!(cause, BareParseError::LimitExceeded((0x1_0000_0000, l)) if l == limit),
+            "Unexpected Error type found: {cause:?}"
+        );
+    }
+
+    #[test]
+    fn dynamic_tables() {
+        let path = std::path::PathBuf::from("sample-objects/symver.x86_64.so");
+        let mut file_data = std::fs::read(path).expect("Could not read file.");
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("should parse");
+        let shdr = file
+            .section_header_by_name(".dynamic")
+            .expect("should parse")
+            .expect("should have .dynamic");
+        let index = file
+            .dynamic()
+            .expect("should parse")
+            .expect("should have .dynamic")
+            .iter()
+            .position(|entry| entry.d_tag == abi::DT_RELASZ)
+            .expect("should have DT_RELASZ");
+
+        // Claim there are far more relocations than the file could hold
+        let offset = shdr.sh_offset as usize + index * 16 + 8;
+        file_data[offset..offset + 8].copy_from_slice(&0x1_0000_0000u64.to_le_bytes());
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("should parse");
+        let err = file.dynamic_relocations().expect_err("should fail");
+        let limit = file_data.len() as u64;
+        let cause = err.into_root_cause();
+        assert!(
+            matches!(cause, BareParseError::LimitExceeded((0x1_0000_0000, l)) if l == limit),
+            "Unexpected Error type found: {cause:?}"
+        );
+    }
+}
+
+#[cfg(test)]
+mod thread_safety_tests {
+    use super::*;
+    use crate::endian::AnyEndian;
+    use crate::gnu_symver::SymbolVersionTable;
+    use crate::note::NoteIterator;
+    use crate::symbol::Symbolizer;
+    use std::sync::Arc;
+
+    fn assert_send_sync<T: Send + Sync>() {}
+    fn assert_clone<T: Clone>() {}
+
+    #[test]
+    fn views_are_send_sync() {
+        assert_send_sync::<ElfBytes<'_, AnyEndian>>();
+        assert_send_sync::<CommonElfData<'_, AnyEndian>>();
+        assert_send_sync::<SectionHeaderTable<'_, AnyEndian>>();
+        assert_send_sync::<SegmentTable<'_, AnyEndian>>();
+        assert_send_sync::<SymbolTable<'_, AnyEndian>>();
+        assert_send_sync::<StringTable<'_>>();
+        assert_send_sync::<DynamicTable<'_, AnyEndian>>();
+        assert_send_sync::<SysVHashTable<'_, AnyEndian>>();
+        assert_send_sync::<GnuHashTable<'_, AnyEndian>>();
+        assert_send_sync::<SymbolVersionTable<'_, AnyEndian>>();
+        assert_send_sync::<NoteIterator<'_, AnyEndian>>();
+        assert_send_sync::<RelaIterator<'_, AnyEndian>>();
+        assert_send_sync::<Symbolizer<'_>>();
+        assert_send_sync::<ParseError>();
+
+        assert_clone::<CommonElfData<'_, AnyEndian>>();
+        assert_clone::<SectionHeaderTable<'_, AnyEndian>>();
+        assert_clone::<SymbolTable<'_, AnyEndian>>();
+        assert_clone::<StringTable<'_>>();
+        assert_clone::<SysVHashTable<'_, AnyEndian>>();
+        assert_clone::<GnuHashTable<'_, AnyEndian>>();
+        assert_clone::<SymbolVersionTable<'_, AnyEndian>>();
+        assert_clone::<Symbolizer<'_>>();
+    }
+
+    #[test]
+    fn symbolize_from_threads() {
+        let path = std::path::PathBuf::from("sample-objects/basic.x86_64");
+        let file_data: &'static [u8] = std::fs::read(path).expect("Could not read file.").leak();
+        let file = Arc::new(ElfBytes::<AnyEndian>::minimal_parse(file_data).expect("should parse"));
+        let symbolizer = Arc::new(
+            file.symbolizer(0)
+                .expect("should parse")
+                .expect("should have symbols"),
+        );
+
+        let addrs: Vec<u64> = (0x400400..0x400600).step_by(4).collect();
+        let expected: Vec<_> = addrs
+            .iter()
+            .map(|addr| symbolizer.symbolize(*addr))
+            .collect();
+        assert!(expected
+            .iter()
+            .any(|found| matches!(found, Some((_, "main", _)))));
+
+        let handles: Vec<_> = (0..4)
+            .map(|_| {
+                let file = Arc::clone(&file);
+                let symbolizer = Arc::clone(&symbolizer);
+                let addrs = addrs.clone();
+                std::thread::spawn(move || {
+                    // Each worker also builds its own tables from the shared file
+                    let own = file.symbolizer(0).unwrap().unwrap();
+                    let found: Vec<_> = addrs
+                        .iter()
+                        .map(|addr| symbolizer.symbolize(*addr))
+                        .collect();
+                    let own_found: Vec<_> = addrs.iter().map(|addr| own.symbolize(*addr)).collect();
+                    assert_eq!(found, own_found);
+                    found
+                })
+            })
+            .collect();
+        for handle in handles {
+            assert_eq!(handle.join().expect("thread should finish"), expected);
+        }
+    }
+}
+
+#[cfg(all(test, feature = "xz"))]
+mod debugdata_tests {
+    use super::*;
+    use crate::endian::AnyEndian;
+
+    #[test]
+    fn gnu_debugdata() {
+        let path = std::path::PathBuf::from("sample-objects/minidebuginfo.x86_64");
+        let file_data = std::fs::read(path).expect("Could not read file.");
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("Open test1");
+
+        let data = file
+            .gnu_debugdata()
+            .expect("should decompress")
+            .expect("should have .gnu_debugdata");
+        let mini = ElfBytes::<AnyEndian>::minimal_parse(&data).expect("should parse");
+        assert_eq!(mini.ehdr.e_machine, file.ehdr.e_machine);
+        assert!(mini.symbol_table().expect("should parse").is_some());
+
+        let result = file
+            .gnu_debugdata_with_limit(data.len() - 1)
+            .map_err(ParseError::into_root_cause);
+        assert!(
+            matches!(result, Err(BareParseError::IOError(_))),
+            "Unexpected Error type found: {result:?}"
+        );
+    }
+
+    #[test]
+    fn no_gnu_debugdata() {
+        let path = std::path::PathBuf::from("sample-objects/basic.x86_64");
+        let file_data = std::fs::read(path).expect("Could not read file.");
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("Open test1");
+        assert!(file.gnu_debugdata().expect("should parse").is_none());
+        assert!(file.gnu_debugdata_elf().expect("should parse").is_none());
+    }
+}
+
+#[cfg(test)]
+mod context_tests {
+    use super::*;
+    use crate::endian::AnyEndian;
+
+    fn sample() -> Vec<u8> {
+        let path = std::path::PathBuf::from("sample-objects/basic.x86_64");
+        std::fs::read(path).expect("Could not read file.")
+    }
+
+    #[test]
+    fn bad_symtab_strtab_link_has_context() {
+        let mut data = sample();
+        let (index, mut shdr) = {
+            let file = ElfBytes::<AnyEndian>::minimal_parse(&data).expect("Open test1");
+            let shdrs = file.section_headers().expect("should have shdrs");
+            shdrs
+                .iter()
+                .enumerate()
+                .find(|(_, shdr)| shdr.sh_type == abi::SHT_SYMTAB)
+                .expect("should have a symtab")
+        };
+        let ehdr = ElfBytes::<AnyEndian>::minimal_parse(&data)
+            .expect("Open test1")
+            .ehdr;
+        shdr.sh_link = 999;
+        ehdr.patch_section_header(&mut data, index, &shdr)
+            .expect("should patch");
+
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&data).expect("Open test1");
+        let err = file.symbol_table().expect_err("Expected an error");
+        let ctx = err.context().expect("should have context");
+        assert_eq!((ctx.what, ctx.index), ("section header", Some(999)));
+        assert!(matches!(ctx.source, BareParseError::BadOffset(999)));
+        assert_eq!(
+            format!("{err}"),
+            format!(
+                "error parsing section header 999 at file offset {:#x}: Bad offset: 0x3E7",
+                ehdr.e_shoff + 999 * ehdr.e_shentsize as u64
+            )
+        );
+    }
+
+    #[test]
+    fn truncated_section_data_has_context() {
+        let data = sample();
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&data).expect("Open test1");
+        let mut shdr = file
+            .section_header_by_name(".text")
+            .expect("should parse")
+            .expect("should have .text");
+        shdr.sh_offset = data.len() as u64 - 1;
+
+        let err = file.section_data(&shdr).expect_err("Expected an error");
+        let ctx = err.context().expect("should have context");
+        assert!(matches!(ctx.source, BareParseError::SliceReadError(_)));
+        assert!(format!("{err}").starts_with(&format!(
+            "error parsing section data at file offset {:#x}: ",
+            data.len() - 1
+        )));
+
+        // A section that starts past the end of the file has a bad offset
+        shdr.sh_offset = data.len() as u64;
+        let err = file.section_data(&shdr).expect_err("Expected an error");
+        let ctx = err.context().expect("should have context");
+        assert!(matches!(ctx.source, BareParseError::BadOffset(x) if x == data.len() as u64));
+    }
+
+    #[test]
+    fn truncated_shdrs_have_context() {
+        let data = sample();
+        let ehdr = ElfBytes::<AnyEndian>::minimal_parse(&data)
+            .expect("Open test1")
+            .ehdr;
+        let truncated = &data[..data.len() - 1];
+        let err = ElfBytes::<AnyEndian>::minimal_parse(truncated).expect_err("Expected an error");
+        let ctx = err.context().expect("should have context");
+        assert!(matches!(ctx.source, BareParseError::SliceReadError(_)));
+        assert!(format!("{err}").starts_with(&format!(
+            "error parsing section header table at file offset {:#x}: ",
+            ehdr.e_shoff
+        )));
+    }
+}
+
+#[cfg(test)]
+mod permissive_tests {
+    use super::*;
+    use crate::endian::AnyEndian;
+
+    fn sample() -> (Vec<u8>, FileHeader<AnyEndian>) {
+        let path = std::path::PathBuf::from("sample-objects/basic.x86_64");
+        let data = std::fs::read(path).expect("Could not read file.");
+        let ehdr = ElfBytes::<AnyEndian>::minimal_parse(&data)
+            .expect("Open test1")
+            .ehdr;
+        (data, ehdr)
+    }
+
+    #[test]
+    fn shoff_past_eof() {
+        let (mut data, mut ehdr) = sample();
+        ehdr.e_shoff = data.len() as u64 + 0x1000;
+        ehdr.write_back(&mut data).expect("should write");
+
+        let err = ElfBytes::<AnyEndian>::minimal_parse(&data).expect_err("Expected an error");
+        assert!(matches!(
+            err.into_root_cause(),
+            BareParseError::SliceReadError(_)
+        ));
+
+        let file =
+            ElfBytes::<AnyEndian>::minimal_parse_with_options(&data, ParseOptions::permissive())
+                .expect("should parse permissively");
+        assert!(file.section_headers().is_none());
+        let phdrs = file.segments().expect("should still have phdrs");
+        assert_eq!(phdrs.len(), ehdr.e_phnum as usize);
+
+        let warnings = file.warnings();
+        assert_eq!(warnings.len(), 1);
+        assert!(matches!(
+            warnings[0],
+            ParseWarning::IgnoredTable {
+                what: "section header table",
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn truncated_shdrs() {
+        let (data, ehdr) = sample();
+        // Cut the last section header in half
+        let entsize = ehdr.e_shentsize as usize;
+        let data = &data[..data.len() - entsize / 2];
+
+        let file =
+            ElfBytes::<AnyEndian>::minimal_parse_with_options(data, ParseOptions::permissive())
+                .expect("should parse permissively");
+        let shdrs = file.section_headers().expect("should have some shdrs");
+        assert_eq!(shdrs.len(), ehdr.e_shnum as usize - 1);
+
+        // The lost section is the .strtab, so the .symtab's link now dangles
+        let warnings = file.warnings();
+        assert_eq!(warnings.len(), 2);
+        assert!(matches!(
+            warnings[0],
+            ParseWarning::TruncatedTable { expected, found, .. }
+                if expected == ehdr.e_shnum as usize && found == expected - 1
+        ));
+        assert!(matches!(
+            warnings[1],
+            ParseWarning::BadLink { sh_link, .. } if sh_link as usize == shdrs.len()
+        ));
+        assert_eq!(
+            format!("{}", warnings[0]),
+            format!(
+                "section header table at file offset {:#x} was truncated to {} of {} entries",
+                ehdr.e_shoff,
+                ehdr.e_shnum - 1,
+                ehdr.e_shnum
+            )
+        );
+    }
+
+    #[test]
+    fn bad_phentsize_ignored() {
+        let (mut data, mut ehdr) = sample();
+        ehdr.e_phentsize = 7;
+        ehdr.write_back(&mut data).expect("should write");
+
+        let file =
+            ElfBytes::<AnyEndian>::minimal_parse_with_options(&data, ParseOptions::permissive())
+                .expect("should parse permissively");
+        assert!(file.segments().is_none());
+        assert!(file.section_headers().is_some());
+        assert!(matches!(
+            file.warnings(),
+            [ParseWarning::IgnoredTable {
+                what: "program header table",
+                ..
+            }]
+        ));
+    }
+
+    #[test]
+    fn bad_magic_is_still_an_error() {
+        let (mut data, _) = sample();
+        data[0] = 0;
+        let err =
+            ElfBytes::<AnyEndian>::minimal_parse_with_options(&data, ParseOptions::permissive())
+                .expect_err("Expected an error");
+        assert!(matches!(err, ParseError::BadMagic(_)));
+    }
+
+    #[test]
+    fn unsupported_versions() {
+        let (mut data, mut ehdr) = sample();
+        ehdr.version = 0x42;
+        ehdr.write_back(&mut data).expect("should write");
+        data[abi::EI_VERSION] = 0;
+
+        let err = ElfBytes::<AnyEndian>::minimal_parse(&data).expect_err("Expected an error");
+        assert!(matches!(err, ParseError::UnsupportedVersion((0, 1))));
+        data[abi::EI_VERSION] = abi::EV_CURRENT;
+        let err = ElfBytes::<AnyEndian>::minimal_parse(&data).expect_err("Expected an error");
+        assert!(matches!(err, ParseError::UnsupportedVersion((0x42, 1))));
+        data[abi::EI_VERSION] = 0;
+
+        let file =
+            ElfBytes::<AnyEndian>::minimal_parse_with_options(&data, ParseOptions::permissive())
+                .expect("should parse permissively");
+        assert_eq!(file.ehdr.ident_version, 0);
+        assert_eq!(file.ehdr.version, 0x42);
+        assert!(file
+            .section_header_by_name(".text")
+            .expect("should parse")
+            .is_some());
+        let warnings = file.warnings();
+        assert!(
+            matches!(
+                warnings,
+                [
+                    ParseWarning::UnsupportedVersion {
+                        what: "EI_VERSION",
+                        found: 0
+                    },
+                    ParseWarning::UnsupportedVersion {
+                        what: "e_version",
+                        found: 0x42
+                    },
+                ]
+            ),
+            "{warnings:?}"
+        );
+        assert_eq!(format!("{}", warnings[1]), "e_version is 66, expected 1");
+    }
+
+    /// The three shapes of bad e_shstrndx found in the wild: out of range, pointing at the
+    /// SHT_NOBITS .bss and pointing at the SHT_PROGBITS .text
+    fn bad_shstrndx_fixtures() -> Vec<(u16, Vec<u8>)> {
+        let (data, ehdr) = sample();
+        [ehdr.e_shnum + 5, 26, 14]
+            .into_iter()
+            .map(|shstrndx| {
+                let (mut data, mut ehdr) = (data.clone(), ehdr);
+                ehdr.e_shstrndx = shstrndx;
+                ehdr.write_back(&mut data).expect("should write");
+                (shstrndx, data)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn bad_shstrndx_strict() {
+        for (shstrndx, data) in bad_shstrndx_fixtures() {
+            let file = ElfBytes::<AnyEndian>::minimal_parse(&data).expect("should parse");
+            let err = file
+                .section_headers_with_strtab()
+                .expect_err("Expected an error");
+            assert!(
+                matches!(err, ParseError::BadShstrndx(index) if index == shstrndx as u32),
+                "{err:?}"
+            );
+            let err = file
+                .section_header_by_name(".text")
+                .expect_err("Expected an error");
+            assert!(matches!(
+                err.into_root_cause(),
+                BareParseError::BadShstrndx(_)
+            ));
+        }
+    }
+
+    #[test]
+    fn bad_shstrndx_permissive() {
+        for (shstrndx, data) in bad_shstrndx_fixtures() {
+            let file = ElfBytes::<AnyEndian>::minimal_parse_with_options(
+                &data,
+                ParseOptions::permissive(),
+            )
+            .expect("should parse permissively");
+            let (shdrs, strtab) = file
+                .section_headers_with_strtab()
+                .expect("should parse permissively");
+            assert!(shdrs.is_some());
+            assert!(strtab.is_none());
+            assert!(file
+                .section_header_by_name(".text")
+                .expect("should parse permissively")
+                .is_none());
+
+            let warnings = file.warnings();
+            assert!(
+                matches!(
+                    warnings,
+                    [ParseWarning::IgnoredSectionNames(ParseError::BadShstrndx(index))]
+                        if *index == shstrndx as u32
+                ),
+                "{warnings:?}"
+            );
+            assert_eq!(
+                format!("{}", warnings[0]),
+                format!(
+                    "ignoring the section names: Section header string table index {shstrndx} does not refer to a string table"
+                )
+            );
+        }
+    }
+
+    #[test]
+    fn bad_shstrndx_stream() {
+        for (shstrndx, data) in bad_shstrndx_fixtures() {
+            let mut file =
+                crate::ElfStream::<AnyEndian, _>::open_stream(std::io::Cursor::new(data))
+                    .expect("should open");
+            let err = file
+                .section_headers_with_strtab()
+                .expect_err("Expected an error");
+            assert!(matches!(err, ParseError::BadShstrndx(index) if index == shstrndx as u32));
+        }
+    }
+
+    /// Patch the section header of the first section with the given type, returning the
+    /// section's index
+    fn patch_section(
+        data: &mut [u8],
+        ehdr: &FileHeader<AnyEndian>,
+        sh_type: u32,
+        patch: impl FnOnce(&mut SectionHeader),
+    ) -> usize {
+        let (index, mut shdr) = {
+            let file = ElfBytes::<AnyEndian>::minimal_parse(data).expect("should parse");
+            let shdrs = file.section_headers().expect("should have shdrs");
+            let index = shdrs
+                .iter()
+                .position(|shdr| shdr.sh_type == sh_type)
+                .expect("should find section");
+            (index, shdrs.get(index).expect("should get"))
+        };
+        patch(&mut shdr);
+        ehdr.patch_section_header(data, index, &shdr)
+            .expect("should patch");
+        index
+    }
+
+    #[test]
+    fn zero_entsize_symtab() {
+        let (mut data, ehdr) = sample();
+        let nsyms = {
+            let file = ElfBytes::<AnyEndian>::minimal_parse(&data).expect("should parse");
+            let (symtab, _) = file.symbol_table().expect("should parse").expect("symtab");
+            symtab.len()
+        };
+        let index = patch_section(&mut data, &ehdr, abi::SHT_SYMTAB, |shdr| {
+            shdr.sh_entsize = 0;
+        });
+
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&data).expect("should parse");
+        let err = file.symbol_table().expect_err("Expected an error");
+        assert!(matches!(
+            err.into_root_cause(),
+            BareParseError::BadEntsize((0, 24))
+        ));
+
+        let file =
+            ElfBytes::<AnyEndian>::minimal_parse_with_options(&data, ParseOptions::permissive())
+                .expect("should parse permissively");
+        let (symtab, strtab) = file
+            .symbol_table()
+            .expect("should parse permissively")
+            .expect("should find symtab");
+        assert_eq!(symtab.len(), nsyms);
+        let sym = symtab.get(30).expect("should get");
+        assert_eq!(
+            strtab.get(sym.st_name as usize).expect("should get"),
+            "__JCR_LIST__"
+        );
+
+        let warnings = file.warnings();
+        assert!(
+            matches!(warnings, [ParseWarning::ZeroEntsize { index: i }] if *i == index),
+            "{warnings:?}"
+        );
+        assert_eq!(
+            format!("{}", warnings[0]),
+            format!("section {index} has an sh_entsize of 0")
+        );
+    }
+
+    #[test]
+    fn bad_link_symtab() {
+        let (mut data, ehdr) = sample();
+        let index = patch_section(&mut data, &ehdr, abi::SHT_SYMTAB, |shdr| {
+            shdr.sh_link = 1000;
+        });
+
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&data).expect("should parse");
+        let err = file.symbol_table().expect_err("Expected an error");
+        assert!(matches!(
+            err.into_root_cause(),
+            BareParseError::BadOffset(_)
+        ));
+
+        // The symbols are still there, but without names
+        let file =
+            ElfBytes::<AnyEndian>::minimal_parse_with_options(&data, ParseOptions::permissive())
+                .expect("should parse permissively");
+        let (symtab, strtab) = file
+            .symbol_table()
+            .expect("should parse permissively")
+            .expect("should find symtab");
+        assert!(!symtab.is_empty());
+        assert!(strtab.get_raw(1).is_err());
+
+        let warnings = file.warnings();
+        assert!(
+            matches!(
+                warnings,
+                [ParseWarning::BadLink { index: i, sh_link: 1000 }] if *i == index
+            ),
+            "{warnings:?}"
+        );
+        assert_eq!(
+            format!("{}", warnings[0]),
+            format!("section {index} has an out of range sh_link 1000")
+        );
+    }
+
+    #[test]
+    fn overlapping_sections() {
+        let (mut data, ehdr) = sample();
+        let text = {
+            let file = ElfBytes::<AnyEndian>::minimal_parse(&data).expect("should parse");
+            file.section_header_by_name(".text")
+                .expect("should parse")
+                .expect("should find .text")
+        };
+        let index = patch_section(&mut data, &ehdr, abi::SHT_DYNAMIC, |shdr| {
+            shdr.sh_offset = text.sh_offset + 0x10;
+        });
+
+        let file =
+            ElfBytes::<AnyEndian>::minimal_parse_with_options(&data, ParseOptions::permissive())
+                .expect("should parse permissively");
+        assert!(file.dynamic().expect("should parse").is_some());
+
+        // The .dynamic now starts inside the .text, and runs over the sections after it
+        let warnings = file.warnings();
+        assert!(
+            matches!(
+                warnings[0],
+                ParseWarning::OverlappingSections { index: i, other: 14 } if i == index
+            ),
+            "{warnings:?}"
+        );
+        assert!(warnings[1..]
+            .iter()
+            .all(|warning| matches!(warning, ParseWarning::OverlappingSections { other, .. } if *other == index)));
+        assert_eq!(
+            format!("{}", warnings[0]),
+            format!("section {index} overlaps with section 14")
+        );
+    }
+
+    #[test]
+    fn well_formed_has_no_warnings() {
+        let (data, _) = sample();
+        let file =
+            ElfBytes::<AnyEndian>::minimal_parse_with_options(&data, ParseOptions::permissive())
+                .expect("should parse");
+        assert!(file.warnings().is_empty());
+    }
+}
+
+#[cfg(test)]
+mod golden_tests {
+    use super::*;
+    use crate::endian::AnyEndian;
+    use crate::relocation::RelaTable;
+
+    /// List the table entries with their indexes the same way sample-objects/golden.sh does
+    /// from readelf's output.
+    fn listing(file: &ElfBytes<'_, AnyEndian>) -> Vec<String> {
+        let mut lines = Vec::new();
+        let (shdrs, shstrtab) = file.section_headers_with_strtab().expect("should parse");
+        let (shdrs, shstrtab) = (shdrs.unwrap(), shstrtab.unwrap());
+        let section_name = |shdr: &SectionHeader| shstrtab.get(shdr.sh_name as usize).unwrap();
+        let with_name = |line: String, name: &str| match name {
+            "" => line,
+            name => format!("{line} {name}"),
+        };
+
+        for (index, shdr) in shdrs.iter_enumerated() {
+            let line = format!(
+                "section {index} {:x} {:x} {:x}",
+                shdr.sh_addr, shdr.sh_offset, shdr.sh_size
+            );
+            lines.push(with_name(line, section_name(&shdr)));
+        }
+
+        for (index, phdr) in file.segments().unwrap().iter_enumerated() {
+            lines.push(format!(
+                "segment {index} {:x} {:x} {:x} {:x}",
+                phdr.p_offset, phdr.p_vaddr, phdr.p_filesz, phdr.p_memsz
+            ));
+        }
+
+        for shdr in shdrs.iter() {
+            if shdr.sh_type != abi::SHT_DYNSYM && shdr.sh_type != abi::SHT_SYMTAB {
+                continue;
+            }
+            let strtab_shdr = shdrs.get(shdr.sh_link as usize).unwrap();
+            let (symtab, strtab) = file
+                .section_data_as_symbol_table(&shdr, &strtab_shdr)
+                .expect("should parse");
+            for (index, sym) in symtab.iter_enumerated() {
+                let mut name = strtab.get(sym.st_name as usize).unwrap();
+                // readelf shows section symbols with their section's name
+                if name.is_empty() && sym.st_symtype() == abi::STT_SECTION {
+                    name = section_name(&shdrs.get(sym.st_shndx as usize).unwrap());
+                }
+                let line = format!(
+                    "symbol {} {index} {:x} {:x} {}",
+                    section_name(&shdr),
+                    sym.st_value,
+                    sym.st_size,
+                    sym.st_shndx
+                );
+                lines.push(with_name(line, name));
+            }
+        }
+
+        // readelf stops at the first DT_NULL
+        let dynamic = file.dynamic().expect("should parse").unwrap();
+        for (index, entry) in dynamic.iter_enumerated() {
+            let tag = match file.ehdr.class {
+                Class::ELF32 => entry.d_tag as u32 as u64,
+                Class::ELF64 => entry.d_tag as u64,
+            };
+            lines.push(format!("dynamic {index} {tag:x}"));
+            if entry.d_tag == abi::DT_NULL {
+                break;
+            }
+        }
+
+        for shdr in shdrs.iter().filter(|shdr| shdr.sh_type == abi::SHT_RELA) {
+            let (data, _) = file.section_data(&shdr).expect("should parse");
+            let relas = RelaTable::new(file.ehdr.endianness, file.ehdr.class, data);
+            for (index, rela) in relas.iter_enumerated() {
+                let info = match file.ehdr.class {
+                    Class::ELF32 => ((rela.r_sym as u64) << 8) | rela.r_type as u64,
+                    Class::ELF64 => ((rela.r_sym as u64) << 32) | rela.r_type as u64,
+                };
+                lines.push(format!(
+                    "rela {} {index} {:x} {info:x}",
+                    section_name(&shdr),
+                    rela.r_offset
+                ));
+            }
+        }
+        lines
+    }
+
+    fn check_golden(name: &str) {
+        let file_data = std::fs::read(format!("sample-objects/{name}")).expect("should read");
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("should parse");
+        let golden = std::fs::read_to_string(format!("sample-objects/golden/{name}.txt"))
+            .expect("should read golden file");
+        let golden: Vec<&str> = golden.lines().collect();
+        assert_eq!(listing(&file), golden);
+    }
+
+    #[test]
+    fn golden_basic_x86_64() {
+        check_golden("basic.x86_64");
+    }
+
+    #[test]
+    fn golden_symver_m68k() {
+        check_golden("symver.m68k.so");
+    }
+}
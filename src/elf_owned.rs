@@ -0,0 +1,269 @@
+use crate::endian::EndianParse;
+use crate::file::FileHeader;
+use crate::parse::{ParseError, ParseOptions};
+use crate::ElfBytes;
+use std::path::Path;
+
+/// This type owns the bytes of an ELF object, so that a parsed object can be returned
+/// from functions, stored in structs, and sent across threads without threading the
+/// `'data` lifetime of an [ElfBytes] through the rest of the program.
+///
+/// The data is validated with [ElfBytes::minimal_parse] on construction, and the borrowed
+/// [ElfBytes] interface is re-derived on demand with [OwnedElfBytes::elf_bytes]. That's
+/// cheap, as it only re-parses the [FileHeader] and locates the header tables.
+///
+/// The owned data can be anything that derefs to bytes, such as a `Vec<u8>` (the default),
+/// an `Arc<[u8]>`, or a memory mapping (see `open_mmap` with the `mmap` feature).
+///
+/// Example usage:
+/// ```
+/// use elf::endian::AnyEndian;
+/// use elf::OwnedElfBytes;
+/// use std::path::Path;
+///
+/// fn load(path: &Path) -> Result<OwnedElfBytes<AnyEndian>, elf::ParseError> {
+///     OwnedElfBytes::open(path)
+/// }
+///
+/// let owned = load(Path::new("sample-objects/basic.x86_64")).expect("should load");
+/// let handle = std::thread::spawn(move || {
+///     let file = owned.elf_bytes().expect("should parse");
+///     file.section_header_by_name(".text").expect("should parse").is_some()
+/// });
+/// assert!(handle.join().unwrap());
+/// ```
+pub struct OwnedElfBytes<E: EndianParse, D: AsRef<[u8]> = Vec<u8>> {
+    data: D,
+    ehdr: FileHeader<E>,
+    options: ParseOptions,
+}
+
+impl<E: EndianParse + core::fmt::Debug, D: AsRef<[u8]>> core::fmt::Debug for OwnedElfBytes<E, D> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("OwnedElfBytes")
+            .field("ehdr", &self.ehdr)
+            .field("options", &self.options)
+            .field("len", &self.data.as_ref().len())
+            .finish()
+    }
+}
+
+impl<E: EndianParse> OwnedElfBytes<E> {
+    /// Read the whole file at `path` into memory and parse it.
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Self, ParseError> {
+        let data = std::fs::read(path)?;
+        Self::minimal_parse(data)
+    }
+
+    /// Parse an ELF object which may be gzip or xz-compressed, like a compressed kernel
+    /// image, decompressing it first if need be. The decompressed object may be at most
+    /// `limit` bytes, see [decompress](crate::detect::decompress) for the details.
+    ///
+    /// Data that isn't compressed in a format the enabled features handle is parsed as is, so
+    /// a zstd-compressed object fails with a [ParseError::BadMagic] that says so.
+    #[cfg(any(feature = "zlib", feature = "xz"))]
+    pub fn minimal_parse_decompressed(data: &[u8], limit: usize) -> Result<Self, ParseError> {
+        match crate::detect::decompress(data, limit)? {
+            Some(decompressed) => Self::minimal_parse(decompressed),
+            None => Self::minimal_parse(data.to_vec()),
+        }
+    }
+}
+
+#[cfg(feature = "mmap")]
+impl<E: EndianParse> OwnedElfBytes<E, memmap2::Mmap> {
+    /// Map the file at `path` into memory read-only and parse it, without copying its
+    /// contents onto the heap.
+    ///
+    /// Note: the mapping reflects any changes made to the underlying file while it's mapped.
+    /// If another process truncates or modifies the file concurrently, then reads from the
+    /// mapping can see inconsistent data or fault with SIGBUS, which is outside of this
+    /// crate's control. Only map files which won't be modified while in use.
+    pub fn open_mmap<P: AsRef<Path>>(path: P) -> Result<Self, ParseError> {
+        let file = std::fs::File::open(path)?;
+        // Safety: see the note above about concurrent modification of the mapped file.
+        #[allow(unsafe_code)]
+        let map = unsafe { memmap2::Mmap::map(&file)? };
+        Self::minimal_parse(map)
+    }
+}
+
+impl<E: EndianParse, D: AsRef<[u8]>> OwnedElfBytes<E, D> {
+    /// Take ownership of `data` and validate that it holds an ELF object, per
+    /// [ElfBytes::minimal_parse].
+    pub fn minimal_parse(data: D) -> Result<Self, ParseError> {
+        Self::minimal_parse_with_options(data, ParseOptions::default())
+    }
+
+    /// Like [OwnedElfBytes::minimal_parse], but with the given [ParseOptions], which also get
+    /// used for each [OwnedElfBytes::elf_bytes].
+    pub fn minimal_parse_with_options(data: D, options: ParseOptions) -> Result<Self, ParseError> {
+        let ehdr = ElfBytes::<E>::minimal_parse_with_options(data.as_ref(), options)?.ehdr;
+        Ok(OwnedElfBytes {
+            data,
+            ehdr,
+            options,
+        })
+    }
+
+    /// Get the borrowed [ElfBytes] interface for parsing the owned data.
+    pub fn elf_bytes(&self) -> Result<ElfBytes<'_, E>, ParseError> {
+        ElfBytes::minimal_parse_with_options(self.data.as_ref(), self.options)
+    }
+
+    /// Get the object's parsed [FileHeader].
+    pub fn ehdr(&self) -> &FileHeader<E> {
+        &self.ehdr
+    }
+
+    /// Get the raw bytes of the owned ELF object.
+    pub fn data(&self) -> &[u8] {
+        self.data.as_ref()
+    }
+
+    /// Give up the parsed handle and get back the owned data.
+    pub fn into_inner(self) -> D {
+        self.data
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::endian::{AnyEndian, LittleEndian};
+    use std::sync::Arc;
+
+    #[derive(Debug)]
+    struct Loaded {
+        file: OwnedElfBytes<AnyEndian>,
+    }
+
+    fn load(path: &Path) -> Result<Loaded, ParseError> {
+        Ok(Loaded {
+            file: OwnedElfBytes::open(path)?,
+        })
+    }
+
+    #[test]
+    fn open_and_parse() {
+        let loaded = load(Path::new("sample-objects/symver.x86_64.so")).expect("should load");
+        let file = loaded.file.elf_bytes().expect("should parse");
+        assert_eq!(file.ehdr, *loaded.file.ehdr());
+        let common = file.find_common_data().expect("should parse");
+        assert!(common.dynsyms.is_some());
+    }
+
+    #[test]
+    fn open_missing_file_errors() {
+        let result = load(Path::new("sample-objects/does-not-exist"));
+        assert!(
+            matches!(result, Err(ParseError::IOError(_))),
+            "Unexpected Error type found: {result:?}"
+        );
+    }
+
+    #[test]
+    fn minimal_parse_bad_data_errors() {
+        let result = OwnedElfBytes::<AnyEndian>::minimal_parse(vec![0u8; 64]);
+        assert!(
+            matches!(result, Err(ParseError::BadMagic(_))),
+            "Unexpected Error type found: {result:?}"
+        );
+    }
+
+    #[test]
+    fn minimal_parse_permissive() {
+        let mut data = std::fs::read("sample-objects/basic.x86_64").expect("Could not read file.");
+        data.truncate(data.len() - 1);
+        assert!(OwnedElfBytes::<AnyEndian>::minimal_parse(data.clone()).is_err());
+
+        let owned = OwnedElfBytes::<AnyEndian>::minimal_parse_with_options(
+            data,
+            ParseOptions::permissive(),
+        )
+        .expect("should parse permissively");
+        let file = owned.elf_bytes().expect("should parse permissively");
+        assert!(matches!(
+            file.warnings()[0],
+            crate::parse::ParseWarning::TruncatedTable { .. }
+        ));
+    }
+
+    #[test]
+    fn shared_across_threads() {
+        let data: Arc<[u8]> = std::fs::read("sample-objects/basic.x86_64")
+            .expect("Could not read file.")
+            .into();
+        let owned = Arc::new(
+            OwnedElfBytes::<LittleEndian, Arc<[u8]>>::minimal_parse(data.clone())
+                .expect("should parse"),
+        );
+
+        let handles: Vec<_> = (0..4)
+            .map(|_| {
+                let owned = owned.clone();
+                std::thread::spawn(move || {
+                    let file = owned.elf_bytes().expect("should parse");
+                    file.section_headers().expect("should have shdrs").len()
+                })
+            })
+            .collect();
+        for handle in handles {
+            assert_eq!(handle.join().unwrap(), owned.ehdr().e_shnum as usize);
+        }
+        assert_eq!(owned.data(), &data[..]);
+    }
+
+    #[cfg(feature = "mmap")]
+    #[test]
+    fn open_mmap() {
+        let owned =
+            OwnedElfBytes::<AnyEndian, memmap2::Mmap>::open_mmap("sample-objects/basic.x86_64")
+                .expect("should map");
+        let data = std::fs::read("sample-objects/basic.x86_64").expect("Could not read file.");
+        assert_eq!(owned.data(), &data[..]);
+
+        let file = owned.elf_bytes().expect("should parse");
+        let (symtab, strtab) = file.symbol_table().expect("should parse").unwrap();
+        assert!(symtab
+            .iter()
+            .any(|sym| matches!(strtab.get(sym.st_name as usize), Ok("main"))));
+
+        let result = OwnedElfBytes::<AnyEndian, memmap2::Mmap>::open_mmap("sample-objects");
+        assert!(
+            matches!(result, Err(ParseError::IOError(_))),
+            "Unexpected Error type found: {result:?}"
+        );
+    }
+
+    #[cfg(feature = "xz")]
+    #[test]
+    fn parse_decompressed() {
+        let data = std::fs::read("sample-objects/basic.x86_64").expect("Could not read file.");
+        let mut compressed = Vec::new();
+        lzma_rs::xz_compress(&mut data.as_slice(), &mut compressed).expect("should compress");
+
+        let owned = OwnedElfBytes::<AnyEndian>::minimal_parse_decompressed(&compressed, data.len())
+            .expect("should parse");
+        assert_eq!(owned.data(), &data[..]);
+        let owned = OwnedElfBytes::<AnyEndian>::minimal_parse_decompressed(&data, data.len())
+            .expect("should parse");
+        assert_eq!(owned.data(), &data[..]);
+
+        let result =
+            OwnedElfBytes::<AnyEndian>::minimal_parse_decompressed(&compressed, data.len() - 1);
+        assert!(
+            matches!(result, Err(ParseError::IOError(_))),
+            "Unexpected Error type found: {result:?}"
+        );
+        // Still compressed, just in a format that isn't handled
+        let result = OwnedElfBytes::<AnyEndian>::minimal_parse_decompressed(
+            b"\x28\xb5\x2f\xfd\x24\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00",
+            data.len(),
+        );
+        let err = result.unwrap_err();
+        assert!(err
+            .to_string()
+            .ends_with("(this looks like zstd-compressed data)"));
+    }
+}
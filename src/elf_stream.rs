@@ -6,12 +6,12 @@ use crate::abi;
 use crate::compression::CompressionHeader;
 use crate::dynamic::DynamicTable;
 use crate::endian::EndianParse;
-use crate::file::{parse_ident, Class};
+use crate::file::{parse_ident_with_options, Class};
 use crate::gnu_symver::{
     SymbolVersionTable, VerDefIterator, VerNeedIterator, VersionIndex, VersionIndexTable,
 };
 use crate::note::NoteIterator;
-use crate::parse::{ParseAt, ParseError};
+use crate::parse::{file_range, ParseAt, ParseError, ParseOptions};
 use crate::relocation::{RelIterator, RelaIterator};
 use crate::section::{SectionHeader, SectionHeaderTable};
 use crate::segment::ProgramHeader;
@@ -51,24 +51,20 @@ fn parse_section_headers<E: EndianParse, S: Read + Seek>(
     // If the number of sections is greater than or equal to SHN_LORESERVE (0xff00),
     // e_shnum is zero and the actual number of section header table entries
     // is contained in the sh_size field of the section header at index 0.
-    let shoff: usize = ehdr.e_shoff.try_into()?;
-    let mut shnum = ehdr.e_shnum as usize;
+    let mut shnum = ehdr.e_shnum as u64;
     if shnum == 0 {
-        let end = shoff
-            .checked_add(entsize)
-            .ok_or(ParseError::IntegerOverflow)?;
         let mut offset = 0;
-        let data = reader.read_bytes(shoff, end)?;
+        let data = reader.read_bytes(file_range(ehdr.e_shoff, entsize as u64)?)?;
         let shdr0 = SectionHeader::parse_at(ehdr.endianness, ehdr.class, &mut offset, data)?;
-        shnum = shdr0.sh_size.try_into()?;
+        shnum = shdr0.sh_size;
     }
 
-    let size = entsize
+    let size = (entsize as u64)
         .checked_mul(shnum)
         .ok_or(ParseError::IntegerOverflow)?;
-    let end = shoff.checked_add(size).ok_or(ParseError::IntegerOverflow)?;
-    let buf = reader.read_bytes(shoff, end)?;
-    let shdr_vec = SectionHeaderTable::new(ehdr.endianness, ehdr.class, buf)
+    reader.check_table_size(size)?;
+    let buf = reader.read_bytes(file_range(ehdr.e_shoff, size)?)?;
+    let shdr_vec = SectionHeaderTable::with_entsize(ehdr.endianness, ehdr.class, entsize, buf)
         .iter()
         .collect();
     Ok(shdr_vec)
@@ -86,28 +82,24 @@ fn parse_program_headers<E: EndianParse, S: Read + Seek>(
     // If the number of segments is greater than or equal to PN_XNUM (0xffff),
     // e_phnum is set to PN_XNUM, and the actual number of program header table
     // entries is contained in the sh_info field of the section header at index 0.
-    let mut phnum = ehdr.e_phnum as usize;
-    if phnum == abi::PN_XNUM as usize {
-        let shoff: usize = ehdr.e_shoff.try_into()?;
-        let end = shoff
-            .checked_add(SectionHeader::size_for(ehdr.class))
-            .ok_or(ParseError::IntegerOverflow)?;
-        let data = reader.read_bytes(shoff, end)?;
+    let mut phnum = ehdr.e_phnum as u64;
+    if phnum == abi::PN_XNUM as u64 {
+        let size = SectionHeader::size_for(ehdr.class) as u64;
+        let data = reader.read_bytes(file_range(ehdr.e_shoff, size)?)?;
         let mut offset = 0;
         let shdr0 = SectionHeader::parse_at(ehdr.endianness, ehdr.class, &mut offset, data)?;
-        phnum = shdr0.sh_info.try_into()?;
+        phnum = shdr0.sh_info as u64;
     }
 
     // Validate phentsize before trying to read the table so that we can error early for corrupted files
     let entsize = ProgramHeader::validate_entsize(ehdr.class, ehdr.e_phentsize as usize)?;
 
-    let phoff: usize = ehdr.e_phoff.try_into()?;
-    let size = entsize
+    let size = (entsize as u64)
         .checked_mul(phnum)
         .ok_or(ParseError::IntegerOverflow)?;
-    let end = phoff.checked_add(size).ok_or(ParseError::IntegerOverflow)?;
-    let buf = reader.read_bytes(phoff, end)?;
-    let phdrs_vec = SegmentTable::new(ehdr.endianness, ehdr.class, buf)
+    reader.check_table_size(size)?;
+    let buf = reader.read_bytes(file_range(ehdr.e_phoff, size)?)?;
+    let phdrs_vec = SegmentTable::with_entsize(ehdr.endianness, ehdr.class, entsize, buf)
         .iter()
         .collect();
     Ok(phdrs_vec)
@@ -119,16 +111,29 @@ impl<E: EndianParse, S: std::io::Read + std::io::Seek> ElfStream<E, S> {
     /// This parses the ELF [FileHeader], [SectionHeader] table, and [ProgramHeader] (segments) table.
     /// All other file data (section data, segment data) is left unread and unparsed.
     pub fn open_stream(reader: S) -> Result<ElfStream<E, S>, ParseError> {
-        let mut cr = CachingReader::new(reader)?;
-        let ident_buf = cr.read_bytes(0, abi::EI_NIDENT)?;
-        let ident = parse_ident(ident_buf)?;
+        Self::open_stream_with_options(reader, ParseOptions::default())
+    }
+
+    /// Like [ElfStream::open_stream], but with the given [ParseOptions].
+    ///
+    /// The [ParseOptions::max_table_bytes] limit applies to every read of the stream, including
+    /// those for the header tables here and for section and segment data later, so that no
+    /// buffer larger than it gets allocated. Permissive mode only relaxes the `EI_VERSION`
+    /// check, as the tables are otherwise always parsed strictly.
+    pub fn open_stream_with_options(
+        reader: S,
+        options: ParseOptions,
+    ) -> Result<ElfStream<E, S>, ParseError> {
+        let mut cr = CachingReader::new(reader, options)?;
+        let ident_buf = cr.read_bytes(0..abi::EI_NIDENT as u64)?;
+        let ident = parse_ident_with_options(ident_buf, &options)?;
 
         let tail_start = abi::EI_NIDENT;
-        let tail_end = match ident.1 {
+        let tail_end = match ident.class {
             Class::ELF32 => tail_start + crate::file::ELF32_EHDR_TAILSIZE,
             Class::ELF64 => tail_start + crate::file::ELF64_EHDR_TAILSIZE,
         };
-        let tail_buf = cr.read_bytes(tail_start, tail_end)?;
+        let tail_buf = cr.read_bytes(tail_start as u64..tail_end as u64)?;
 
         let ehdr = FileHeader::parse_tail(ident, tail_buf)?;
 
@@ -170,7 +175,8 @@ impl<E: EndianParse, S: std::io::Read + std::io::Seek> ElfStream<E, S> {
     /// [e_shoff](FileHeader#structfield.e_shoff),
     /// [e_shentsize](FileHeader#structfield.e_shentsize),
     /// [e_shstrndx](FileHeader#structfield.e_shstrndx) are invalid and point
-    /// to a ranges in the file data that does not actually exist.
+    /// to a ranges in the file data that does not actually exist. An e_shstrndx that's out
+    /// of range or doesn't refer to a [abi::SHT_STRTAB] section is a [ParseError::BadShstrndx].
     pub fn section_headers_with_strtab(
         &mut self,
     ) -> Result<(&Vec<SectionHeader>, Option<StringTable<'_>>), ParseError> {
@@ -194,18 +200,20 @@ impl<E: EndianParse, S: std::io::Read + std::io::Seek> ElfStream<E, S> {
         }
 
         // We have a strtab, so wrap it in a zero-copy StringTable
-        let strtab = self
-            .shdrs
-            .get(shstrndx)
-            .ok_or(ParseError::BadOffset(shstrndx as u64))?;
-        let (strtab_start, strtab_end) = strtab.get_data_range()?;
-        let strtab_buf = self.reader.read_bytes(strtab_start, strtab_end)?;
+        let strtab = match self.shdrs.get(shstrndx) {
+            Some(shdr) if shdr.sh_type == abi::SHT_STRTAB => shdr,
+            _ => return Err(ParseError::BadShstrndx(shstrndx as u32)),
+        };
+        let strtab_buf = self.reader.read_bytes(strtab.file_range()?)?;
         let strtab = StringTable::new(strtab_buf);
         Ok((&self.shdrs, Some(strtab)))
     }
 
     /// Find the parsed section header with the given name (if any).
     ///
+    /// If several sections have the name, then this returns the first one in the section
+    /// header table.
+    ///
     /// Returns a ParseError if the section headers string table can't be read
     ///
     /// Example to get the ELF file's ABI-tag note
@@ -219,7 +227,7 @@ impl<E: EndianParse, S: std::io::Read + std::io::Seek> ElfStream<E, S> {
     /// let path = std::path::PathBuf::from("sample-objects/basic.x86_64");
     /// let io = std::fs::File::open(path).expect("Could not open file.");
     /// let mut file = ElfStream::<AnyEndian, _>::open_stream(io).expect("Open test1");
-
+    ///
     /// let shdr: SectionHeader = *file
     ///     .section_header_by_name(".note.ABI-tag")
     ///     .expect("section table should be parseable")
@@ -251,7 +259,8 @@ impl<E: EndianParse, S: std::io::Read + std::io::Seek> ElfStream<E, S> {
             }
         };
 
-        Ok(shdrs.iter().find(|shdr| {
+        // Header 0 isn't a real section
+        Ok(shdrs.iter().skip(1).find(|shdr| {
             let sh_name = match strtab.get(shdr.sh_name as usize) {
                 Ok(name) => name,
                 _ => {
@@ -284,8 +293,7 @@ impl<E: EndianParse, S: std::io::Read + std::io::Seek> ElfStream<E, S> {
             return Ok((&[], None));
         }
 
-        let (start, end) = shdr.get_data_range()?;
-        let buf = self.reader.read_bytes(start, end)?;
+        let buf = self.reader.read_bytes(shdr.file_range()?)?;
 
         if shdr.sh_flags & abi::SHF_COMPRESSED as u64 == 0 {
             Ok((buf, None))
@@ -323,8 +331,7 @@ impl<E: EndianParse, S: std::io::Read + std::io::Seek> ElfStream<E, S> {
             )));
         }
 
-        let (start, end) = shdr.get_data_range()?;
-        let buf = self.reader.read_bytes(start, end)?;
+        let buf = self.reader.read_bytes(shdr.file_range()?)?;
         Ok(StringTable::new(buf))
     }
 
@@ -341,8 +348,8 @@ impl<E: EndianParse, S: std::io::Read + std::io::Seek> ElfStream<E, S> {
             Some(shdr) => {
                 // Load the section bytes for the symtab
                 // (we want immutable references to both the symtab and its strtab concurrently)
-                let (symtab_start, symtab_end) = shdr.get_data_range()?;
-                self.reader.load_bytes(symtab_start..symtab_end)?;
+                let symtab_range = shdr.file_range()?;
+                self.reader.load_bytes(symtab_range.clone())?;
 
                 // Load the section bytes for the strtab
                 // (we want immutable references to both the symtab and its strtab concurrently)
@@ -350,17 +357,19 @@ impl<E: EndianParse, S: std::io::Read + std::io::Seek> ElfStream<E, S> {
                     .shdrs
                     .get(shdr.sh_link as usize)
                     .ok_or(ParseError::BadOffset(shdr.sh_link as u64))?;
-                let (strtab_start, strtab_end) = strtab.get_data_range()?;
-                self.reader.load_bytes(strtab_start..strtab_end)?;
+                let strtab_range = strtab.file_range()?;
+                self.reader.load_bytes(strtab_range.clone())?;
 
                 // Validate entsize before trying to read the table so that we can error early for corrupted files
-                Symbol::validate_entsize(self.ehdr.class, shdr.sh_entsize.try_into()?)?;
-                let symtab = SymbolTable::new(
+                let entsize =
+                    Symbol::validate_entsize(self.ehdr.class, shdr.sh_entsize.try_into()?)?;
+                let symtab = SymbolTable::with_entsize(
                     self.ehdr.endianness,
                     self.ehdr.class,
-                    self.reader.get_bytes(symtab_start..symtab_end),
+                    entsize,
+                    self.reader.get_bytes(symtab_range),
                 );
-                let strtab = StringTable::new(self.reader.get_bytes(strtab_start..strtab_end));
+                let strtab = StringTable::new(self.reader.get_bytes(strtab_range));
                 Ok(Some((symtab, strtab)))
             }
             None => Ok(None),
@@ -394,8 +403,7 @@ impl<E: EndianParse, S: std::io::Read + std::io::Seek> ElfStream<E, S> {
                 .iter()
                 .find(|shdr| shdr.sh_type == abi::SHT_DYNAMIC)
             {
-                let (start, end) = shdr.get_data_range()?;
-                let buf = self.reader.read_bytes(start, end)?;
+                let buf = self.reader.read_bytes(shdr.file_range()?)?;
                 return Ok(Some(DynamicTable::new(
                     self.ehdr.endianness,
                     self.ehdr.class,
@@ -409,8 +417,7 @@ impl<E: EndianParse, S: std::io::Read + std::io::Seek> ElfStream<E, S> {
                 .iter()
                 .find(|phdr| phdr.p_type == abi::PT_DYNAMIC)
             {
-                let (start, end) = phdr.get_file_data_range()?;
-                let buf = self.reader.read_bytes(start, end)?;
+                let buf = self.reader.read_bytes(phdr.file_range()?)?;
                 return Ok(Some(DynamicTable::new(
                     self.ehdr.endianness,
                     self.ehdr.class,
@@ -463,22 +470,21 @@ impl<E: EndianParse, S: std::io::Read + std::io::Seek> ElfStream<E, S> {
         // Load the versym table
         let versym_shdr = versym_opt.unwrap();
         // Validate VERSYM entsize before trying to read the table so that we can error early for corrupted files
-        VersionIndex::validate_entsize(self.ehdr.class, versym_shdr.sh_entsize.try_into()?)?;
-        let (versym_start, versym_end) = versym_shdr.get_data_range()?;
-        self.reader.load_bytes(versym_start..versym_end)?;
+        let entsize =
+            VersionIndex::validate_entsize(self.ehdr.class, versym_shdr.sh_entsize.try_into()?)?;
+        let versym_range = versym_shdr.file_range()?;
+        self.reader.load_bytes(versym_range.clone())?;
 
         // Get the VERNEED string shdr and load the VERNEED section data (if any)
         let needs_shdrs = match needs_opt {
             Some(shdr) => {
-                let (start, end) = shdr.get_data_range()?;
-                self.reader.load_bytes(start..end)?;
+                self.reader.load_bytes(shdr.file_range()?)?;
 
                 let strs_shdr = self
                     .shdrs
                     .get(shdr.sh_link as usize)
                     .ok_or(ParseError::BadOffset(shdr.sh_link as u64))?;
-                let (strs_start, strs_end) = strs_shdr.get_data_range()?;
-                self.reader.load_bytes(strs_start..strs_end)?;
+                self.reader.load_bytes(strs_shdr.file_range()?)?;
 
                 Some((shdr, strs_shdr))
             }
@@ -490,15 +496,13 @@ impl<E: EndianParse, S: std::io::Read + std::io::Seek> ElfStream<E, S> {
         // Get the VERDEF string shdr and load the VERDEF section data (if any)
         let defs_shdrs = match defs_opt {
             Some(shdr) => {
-                let (start, end) = shdr.get_data_range()?;
-                self.reader.load_bytes(start..end)?;
+                self.reader.load_bytes(shdr.file_range()?)?;
 
                 let strs_shdr = self
                     .shdrs
                     .get(shdr.sh_link as usize)
                     .ok_or(ParseError::BadOffset(shdr.sh_link as u64))?;
-                let (strs_start, strs_end) = strs_shdr.get_data_range()?;
-                self.reader.load_bytes(strs_start..strs_end)?;
+                self.reader.load_bytes(strs_shdr.file_range()?)?;
 
                 Some((shdr, strs_shdr))
             }
@@ -510,11 +514,9 @@ impl<E: EndianParse, S: std::io::Read + std::io::Seek> ElfStream<E, S> {
         // Wrap the VERNEED section and strings data in an iterator and string table
         let verneeds = match needs_shdrs {
             Some((shdr, strs_shdr)) => {
-                let (strs_start, strs_end) = strs_shdr.get_data_range()?;
-                let strs_buf = self.reader.get_bytes(strs_start..strs_end);
+                let strs_buf = self.reader.get_bytes(strs_shdr.file_range()?);
 
-                let (start, end) = shdr.get_data_range()?;
-                let buf = self.reader.get_bytes(start..end);
+                let buf = self.reader.get_bytes(shdr.file_range()?);
                 Some((
                     VerNeedIterator::new(
                         self.ehdr.endianness,
@@ -533,11 +535,9 @@ impl<E: EndianParse, S: std::io::Read + std::io::Seek> ElfStream<E, S> {
         // Wrap the VERDEF section and strings data in an iterator and string table
         let verdefs = match defs_shdrs {
             Some((shdr, strs_shdr)) => {
-                let (strs_start, strs_end) = strs_shdr.get_data_range()?;
-                let strs_buf = self.reader.get_bytes(strs_start..strs_end);
+                let strs_buf = self.reader.get_bytes(strs_shdr.file_range()?);
 
-                let (start, end) = shdr.get_data_range()?;
-                let buf = self.reader.get_bytes(start..end);
+                let buf = self.reader.get_bytes(shdr.file_range()?);
                 Some((
                     VerDefIterator::new(
                         self.ehdr.endianness,
@@ -554,10 +554,11 @@ impl<E: EndianParse, S: std::io::Read + std::io::Seek> ElfStream<E, S> {
         };
 
         // Wrap the versym section data in a parsing table
-        let version_ids = VersionIndexTable::new(
+        let version_ids = VersionIndexTable::with_entsize(
             self.ehdr.endianness,
             self.ehdr.class,
-            self.reader.get_bytes(versym_start..versym_end),
+            entsize,
+            self.reader.get_bytes(versym_range),
         );
 
         // whew, we're done here!
@@ -586,9 +587,13 @@ impl<E: EndianParse, S: std::io::Read + std::io::Seek> ElfStream<E, S> {
             )));
         }
 
-        let (start, end) = shdr.get_data_range()?;
-        let buf = self.reader.read_bytes(start, end)?;
-        Ok(RelIterator::new(self.ehdr.endianness, self.ehdr.class, buf))
+        let buf = self.reader.read_bytes(shdr.file_range()?)?;
+        Ok(RelIterator::with_entsize(
+            self.ehdr.endianness,
+            self.ehdr.class,
+            shdr.sh_entsize.try_into()?,
+            buf,
+        ))
     }
 
     /// Read the section data for the given
@@ -609,11 +614,11 @@ impl<E: EndianParse, S: std::io::Read + std::io::Seek> ElfStream<E, S> {
             )));
         }
 
-        let (start, end) = shdr.get_data_range()?;
-        let buf = self.reader.read_bytes(start, end)?;
-        Ok(RelaIterator::new(
+        let buf = self.reader.read_bytes(shdr.file_range()?)?;
+        Ok(RelaIterator::with_entsize(
             self.ehdr.endianness,
             self.ehdr.class,
+            shdr.sh_entsize.try_into()?,
             buf,
         ))
     }
@@ -636,8 +641,7 @@ impl<E: EndianParse, S: std::io::Read + std::io::Seek> ElfStream<E, S> {
             )));
         }
 
-        let (start, end) = shdr.get_data_range()?;
-        let buf = self.reader.read_bytes(start, end)?;
+        let buf = self.reader.read_bytes(shdr.file_range()?)?;
         Ok(NoteIterator::new(
             self.ehdr.endianness,
             self.ehdr.class,
@@ -664,8 +668,7 @@ impl<E: EndianParse, S: std::io::Read + std::io::Seek> ElfStream<E, S> {
             )));
         }
 
-        let (start, end) = phdr.get_file_data_range()?;
-        let buf = self.reader.read_bytes(start, end)?;
+        let buf = self.reader.read_bytes(phdr.file_range()?)?;
         Ok(NoteIterator::new(
             self.ehdr.endianness,
             self.ehdr.class,
@@ -679,27 +682,36 @@ impl<E: EndianParse, S: std::io::Read + std::io::Seek> ElfStream<E, S> {
 struct CachingReader<R: Read + Seek> {
     reader: R,
     stream_len: u64,
-    bufs: HashMap<(usize, usize), Box<[u8]>>,
+    options: ParseOptions,
+    bufs: HashMap<(u64, u64), Box<[u8]>>,
 }
 
 impl<R: Read + Seek> CachingReader<R> {
-    fn new(mut reader: R) -> Result<Self, ParseError> {
+    fn new(mut reader: R, options: ParseOptions) -> Result<Self, ParseError> {
         // Cache the size of the stream so that we can err (rather than OOM) on invalid
         // huge read requests.
         let stream_len = reader.seek(SeekFrom::End(0))?;
         Ok(CachingReader {
             reader,
             stream_len,
-            bufs: HashMap::<(usize, usize), Box<[u8]>>::default(),
+            options,
+            bufs: HashMap::<(u64, u64), Box<[u8]>>::default(),
         })
     }
 
-    fn read_bytes(&mut self, start: usize, end: usize) -> Result<&[u8], ParseError> {
-        self.load_bytes(start..end)?;
-        Ok(self.get_bytes(start..end))
+    /// Check a table's size against the [ParseOptions::max_table_bytes] limit, which
+    /// defaults to the length of the stream
+    fn check_table_size(&self, size: u64) -> Result<(), ParseError> {
+        let stream_len = usize::try_from(self.stream_len).unwrap_or(usize::MAX);
+        self.options.check_table_size(size, stream_len)
+    }
+
+    fn read_bytes(&mut self, range: Range<u64>) -> Result<&[u8], ParseError> {
+        self.load_bytes(range.clone())?;
+        Ok(self.get_bytes(range))
     }
 
-    fn get_bytes(&self, range: Range<usize>) -> &[u8] {
+    fn get_bytes(&self, range: Range<u64>) -> &[u8] {
         // It's a programmer error to call get_bytes without first calling load_bytes, so
         // we want to panic here.
         self.bufs
@@ -707,19 +719,21 @@ impl<R: Read + Seek> CachingReader<R> {
             .expect("load_bytes must be called before get_bytes for every range")
     }
 
-    fn load_bytes(&mut self, range: Range<usize>) -> Result<(), ParseError> {
+    fn load_bytes(&mut self, range: Range<u64>) -> Result<(), ParseError> {
         if self.bufs.contains_key(&(range.start, range.end)) {
             return Ok(());
         }
+        self.check_table_size(range.end.saturating_sub(range.start))?;
 
         // Verify that the read range doesn't go past the end of the stream (corrupted files)
-        let end = range.end as u64;
-        if end > self.stream_len {
-            return Err(ParseError::BadOffset(end));
+        if range.end > self.stream_len {
+            return Err(ParseError::BadOffset(range.end));
         }
 
-        self.reader.seek(SeekFrom::Start(range.start as u64))?;
-        let mut bytes = vec![0; range.len()].into_boxed_slice();
+        // Only the length of the read needs to fit in memory, not the offsets
+        let len: usize = (range.end - range.start).try_into()?;
+        self.reader.seek(SeekFrom::Start(range.start))?;
+        let mut bytes = vec![0; len].into_boxed_slice();
         self.reader.read_exact(&mut bytes)?;
         self.bufs.insert((range.start, range.end), bytes);
         Ok(())
@@ -822,7 +836,7 @@ mod interface_tests {
             .section_data(&shdr)
             .expect("Failed to get section data");
         assert_eq!(chdr, None);
-        assert_eq!(data, &[]);
+        assert_eq!(data, &[] as &[u8]);
     }
 
     #[test]
@@ -1186,6 +1200,166 @@ mod interface_tests {
     }
 }
 
+#[cfg(test)]
+mod large_offset_tests {
+    use super::*;
+    use crate::endian::AnyEndian;
+    use crate::parse::SerializeAt;
+    use crate::ElfBytes;
+
+    /// A stream with chunks of data at the given offsets, reading as zeros everywhere else, so
+    /// that tests can put ELF structures past 4GiB without a 4GiB file.
+    #[derive(Debug)]
+    struct SparseStream {
+        chunks: Vec<(u64, Vec<u8>)>,
+        len: u64,
+        pos: u64,
+    }
+
+    impl Read for SparseStream {
+        fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+            let mut len = buf.len().min(self.len.saturating_sub(self.pos) as usize);
+            buf[..len].fill(0);
+            for (start, data) in self.chunks.iter() {
+                let end = start + data.len() as u64;
+                if (*start..end).contains(&self.pos) {
+                    let from = (self.pos - start) as usize;
+                    len = len.min(data.len() - from);
+                    buf[..len].copy_from_slice(&data[from..from + len]);
+                } else if *start > self.pos {
+                    // Stop the zeros at the start of the next chunk
+                    len = len.min((start - self.pos) as usize);
+                }
+            }
+            self.pos += len as u64;
+            Ok(len)
+        }
+    }
+
+    impl Seek for SparseStream {
+        fn seek(&mut self, pos: SeekFrom) -> std::io::Result<u64> {
+            self.pos = match pos {
+                SeekFrom::Start(pos) => pos,
+                SeekFrom::End(delta) => (self.len as i64 + delta) as u64,
+                SeekFrom::Current(delta) => (self.pos as i64 + delta) as u64,
+            };
+            Ok(self.pos)
+        }
+    }
+
+    #[test]
+    fn tables_past_4gib() {
+        let mut data = std::fs::read("sample-objects/basic.x86_64").expect("should read");
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&data).expect("should parse");
+        let mut ehdr = file.ehdr;
+        let (shdrs, _) = file.section_headers_with_strtab().expect("should parse");
+        let shdrs: Vec<_> = shdrs.expect("should have shdrs").iter().collect();
+        let shstrndx = ehdr.e_shstrndx as usize;
+        let shstrtab_data = file
+            .section_data(&shdrs[shstrndx])
+            .expect("should read")
+            .0
+            .to_vec();
+        let shdr_table = data[ehdr.e_shoff as usize..].to_vec();
+
+        // Move the section header table and its string table past 4GiB
+        let shoff = 0x1_2345_0000u64;
+        let shstrtab_offset = 0x1_0000_0ff8u64;
+        let mut shstrtab = shdrs[shstrndx];
+        shstrtab.sh_offset = shstrtab_offset;
+        ehdr.e_shoff = shoff;
+        ehdr.write_back(&mut data).expect("should write");
+        let mut stream = SparseStream {
+            chunks: vec![
+                (0, data),
+                (shstrtab_offset, shstrtab_data),
+                (shoff, shdr_table),
+            ],
+            len: shoff + 0x10000,
+            pos: 0,
+        };
+        let mut shdr_buf = vec![0; ehdr.e_shentsize as usize];
+        shstrtab
+            .serialize_at(ehdr.endianness, ehdr.class, &mut 0, &mut shdr_buf)
+            .expect("should write");
+        let entry = shstrndx * ehdr.e_shentsize as usize;
+        stream.chunks[2].1[entry..entry + shdr_buf.len()].copy_from_slice(&shdr_buf);
+
+        let mut file = ElfStream::<AnyEndian, _>::open_stream(stream).expect("should open");
+        assert_eq!(file.section_headers().len(), shdrs.len());
+        let text = *file
+            .section_header_by_name(".text")
+            .expect("should parse")
+            .expect("should find .text");
+        assert_eq!(text, shdrs[14]);
+        let strtab = file
+            .section_data_as_strtab(&file.section_headers()[shstrndx].clone())
+            .expect("should read");
+        assert_eq!(
+            strtab.get(text.sh_name as usize).expect("should get"),
+            ".text"
+        );
+    }
+
+    #[test]
+    fn huge_table_exceeds_limit() {
+        let mut data = std::fs::read("sample-objects/basic.x86_64").expect("should read");
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&data).expect("should parse");
+        let mut ehdr = file.ehdr;
+
+        // Declare 2^34 section headers (1TiB of them) through section header 0, in a stream
+        // that's long enough to hold them
+        let shoff = 0x1_0000_0000u64;
+        let shdr0 = SectionHeader {
+            sh_size: 1 << 34,
+            ..Default::default()
+        };
+        let mut shdr_buf = vec![0; ehdr.e_shentsize as usize];
+        shdr0
+            .serialize_at(ehdr.endianness, ehdr.class, &mut 0, &mut shdr_buf)
+            .expect("should write");
+        ehdr.e_shoff = shoff;
+        ehdr.e_shnum = 0;
+        ehdr.write_back(&mut data).expect("should write");
+        let stream = SparseStream {
+            chunks: vec![(0, data), (shoff, shdr_buf)],
+            len: 1 << 42,
+            pos: 0,
+        };
+
+        let options = ParseOptions::default().with_max_table_bytes(1 << 20);
+        let err = ElfStream::<AnyEndian, _>::open_stream_with_options(stream, options)
+            .expect_err("should be too big");
+        assert!(
+            matches!(err, ParseError::LimitExceeded((size, limit)) if size == 64 << 34 && limit == 1 << 20),
+            "{err:?}"
+        );
+    }
+
+    #[test]
+    fn section_data_exceeds_limit() {
+        let path = std::path::PathBuf::from("sample-objects/basic.x86_64");
+        let io = std::fs::File::open(path).expect("Could not open file.");
+        // Just big enough for the section header table
+        let options = ParseOptions::default().with_max_table_bytes(0x7c0);
+        let mut file =
+            ElfStream::<AnyEndian, _>::open_stream_with_options(io, options).expect("should open");
+        let text = *file
+            .section_header_by_name(".text")
+            .expect("should parse")
+            .expect("should find .text");
+        assert!(file.section_data(&text).is_ok());
+
+        let mut big = text;
+        big.sh_offset = 0;
+        big.sh_size = 0x1000;
+        assert!(matches!(
+            file.section_data(&big),
+            Err(ParseError::LimitExceeded((0x1000, 0x7c0)))
+        ));
+    }
+}
+
 #[cfg(test)]
 mod arch_tests {
     use super::*;
@@ -47,6 +47,35 @@ macro_rules! safe_from {
     }};
 }
 
+/// This macro writes out safe code to get a mutable subslice from the the byte slice $data
+/// at the given $off as a [u8; size_of<$typ>], then fills it with the corresponding safe
+/// endian-aware byte conversion of $val.
+///
+/// This uses safe integer math and returns a ParseError on overflow or if $data did
+/// not contain enough bytes at $off to perform the conversion.
+macro_rules! safe_to {
+    ( $self:ident, $typ:ty, $off:ident, $data:ident, $val:ident) => {{
+        const SIZE: usize = core::mem::size_of::<$typ>();
+
+        let end = (*$off)
+            .checked_add(SIZE)
+            .ok_or(ParseError::IntegerOverflow)?;
+
+        let buf = $data
+            .get_mut(*$off..end)
+            .ok_or(ParseError::SliceReadError((*$off, end)))?;
+
+        if $self.is_little() {
+            buf.copy_from_slice(&<$typ>::to_le_bytes($val));
+        } else {
+            buf.copy_from_slice(&<$typ>::to_be_bytes($val));
+        }
+
+        *$off = end;
+        Ok(())
+    }};
+}
+
 /// An all-safe-code endian-aware integer parsing trait.
 ///
 /// These methods use safe code to get a subslice from the the byte slice $data
@@ -80,6 +109,55 @@ pub trait EndianParse: Clone + Copy + Default + PartialEq + Eq {
         safe_from!(self, i64, offset, data)
     }
 
+    fn write_u8_at(self, offset: &mut usize, data: &mut [u8], value: u8) -> Result<(), ParseError> {
+        safe_to!(self, u8, offset, data, value)
+    }
+
+    fn write_u16_at(
+        self,
+        offset: &mut usize,
+        data: &mut [u8],
+        value: u16,
+    ) -> Result<(), ParseError> {
+        safe_to!(self, u16, offset, data, value)
+    }
+
+    fn write_u32_at(
+        self,
+        offset: &mut usize,
+        data: &mut [u8],
+        value: u32,
+    ) -> Result<(), ParseError> {
+        safe_to!(self, u32, offset, data, value)
+    }
+
+    fn write_u64_at(
+        self,
+        offset: &mut usize,
+        data: &mut [u8],
+        value: u64,
+    ) -> Result<(), ParseError> {
+        safe_to!(self, u64, offset, data, value)
+    }
+
+    fn write_i32_at(
+        self,
+        offset: &mut usize,
+        data: &mut [u8],
+        value: i32,
+    ) -> Result<(), ParseError> {
+        safe_to!(self, i32, offset, data, value)
+    }
+
+    fn write_i64_at(
+        self,
+        offset: &mut usize,
+        data: &mut [u8],
+        value: i64,
+    ) -> Result<(), ParseError> {
+        safe_to!(self, i64, offset, data, value)
+    }
+
     /// Get an endian-aware integer parsing spec for an ELF [FileHeader](crate::file::FileHeader)'s
     /// `ident[EI_DATA]` byte.
     ///
@@ -95,11 +173,19 @@ pub trait EndianParse: Clone + Copy + Default + PartialEq + Eq {
     fn is_big(self) -> bool {
         !self.is_little()
     }
+
+    /// Returns whether this byte order matches the compilation target's byte order.
+    #[inline(always)]
+    fn is_native(self) -> bool {
+        self.is_little() == cfg!(target_endian = "little")
+    }
 }
 
 /// An endian parsing type that can choose at runtime which byte order to parse integers as.
 /// This is useful for scenarios where a single compiled binary wants to dynamically
 /// interpret ELF files of any byte order.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
 pub enum AnyEndian {
     /// Used for a little-endian ELF structures that have been parsed with AnyEndian
@@ -113,6 +199,8 @@ pub enum AnyEndian {
 /// This is useful for scenarios where a combiled binary knows it only wants to interpret
 /// little-endian ELF files and doesn't want the performance penalty of evaluating a match
 /// each time it parses an integer.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
 pub struct LittleEndian;
 
@@ -120,6 +208,8 @@ pub struct LittleEndian;
 /// This is useful for scenarios where a combiled binary knows it only wants to interpret
 /// big-endian ELF files and doesn't want the performance penalty of evaluating a match
 /// each time it parses an integer.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
 pub struct BigEndian;
 
@@ -324,4 +414,130 @@ mod tests {
         fuzz_too_short_test!(AnyEndian::Little, i64, parse_i64_at);
         fuzz_too_short_test!(AnyEndian::Big, i64, parse_i64_at);
     }
+
+    macro_rules! write_test {
+        ( $endian:expr, $typ:ty, $method:ident, $val:expr, $expect:expr) => {{
+            let mut bytes = [0u8; 8];
+            let mut offset = 0;
+            $endian.$method(&mut offset, &mut bytes, $val).unwrap();
+            let size = core::mem::size_of::<$typ>();
+            assert_eq!(bytes[..size], $expect[..size]);
+            assert!(bytes[size..].iter().all(|&b| b == 0));
+            assert_eq!(offset, size);
+        }};
+    }
+
+    macro_rules! write_too_short_test {
+        ( $endian:expr, $typ:ty, $method:ident) => {{
+            let size = core::mem::size_of::<$typ>();
+            for n in 0..size {
+                let mut bytes = [0u8; 8];
+                let buf = bytes.split_at_mut(n).0;
+                let mut offset: usize = 0;
+                let error = $endian
+                    .$method(&mut offset, buf, 0)
+                    .expect_err("Expected an error, but wrote");
+                assert!(
+                    matches!(error, ParseError::SliceReadError(_)),
+                    "Unexpected Error type found: {error}"
+                );
+                assert_eq!(offset, 0);
+            }
+        }};
+    }
+
+    const LSB_BYTES: [u8; 8] = [0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08];
+
+    #[test]
+    fn write_u8_at() {
+        write_test!(LittleEndian, u8, write_u8_at, 0x01u8, LSB_BYTES);
+        write_test!(AnyEndian::Big, u8, write_u8_at, 0x01u8, LSB_BYTES);
+    }
+
+    #[test]
+    fn write_u16_at() {
+        write_test!(LittleEndian, u16, write_u16_at, 0x0201u16, LSB_BYTES);
+        write_test!(BigEndian, u16, write_u16_at, 0x0102u16, LSB_BYTES);
+        write_test!(AnyEndian::Little, u16, write_u16_at, 0x0201u16, LSB_BYTES);
+        write_test!(AnyEndian::Big, u16, write_u16_at, 0x0102u16, LSB_BYTES);
+    }
+
+    #[test]
+    fn write_u32_at() {
+        write_test!(LittleEndian, u32, write_u32_at, 0x04030201u32, LSB_BYTES);
+        write_test!(BigEndian, u32, write_u32_at, 0x01020304u32, LSB_BYTES);
+        write_test!(
+            AnyEndian::Little,
+            u32,
+            write_u32_at,
+            0x04030201u32,
+            LSB_BYTES
+        );
+        write_test!(AnyEndian::Big, u32, write_u32_at, 0x01020304u32, LSB_BYTES);
+    }
+
+    #[test]
+    fn write_u64_at() {
+        write_test!(
+            LittleEndian,
+            u64,
+            write_u64_at,
+            0x0807060504030201u64,
+            LSB_BYTES
+        );
+        write_test!(
+            BigEndian,
+            u64,
+            write_u64_at,
+            0x0102030405060708u64,
+            LSB_BYTES
+        );
+        write_test!(
+            AnyEndian::Little,
+            u64,
+            write_u64_at,
+            0x0807060504030201u64,
+            LSB_BYTES
+        );
+        write_test!(
+            AnyEndian::Big,
+            u64,
+            write_u64_at,
+            0x0102030405060708u64,
+            LSB_BYTES
+        );
+    }
+
+    #[test]
+    fn write_i32_at() {
+        write_test!(LittleEndian, i32, write_i32_at, 0x04030201i32, LSB_BYTES);
+        write_test!(BigEndian, i32, write_i32_at, 0x01020304i32, LSB_BYTES);
+    }
+
+    #[test]
+    fn write_i64_at() {
+        write_test!(
+            LittleEndian,
+            i64,
+            write_i64_at,
+            0x0807060504030201i64,
+            LSB_BYTES
+        );
+        write_test!(
+            BigEndian,
+            i64,
+            write_i64_at,
+            0x0102030405060708i64,
+            LSB_BYTES
+        );
+    }
+
+    #[test]
+    fn write_too_short() {
+        write_too_short_test!(LittleEndian, u16, write_u16_at);
+        write_too_short_test!(BigEndian, u32, write_u32_at);
+        write_too_short_test!(AnyEndian::Little, u64, write_u64_at);
+        write_too_short_test!(AnyEndian::Big, i32, write_i32_at);
+        write_too_short_test!(LittleEndian, i64, write_i64_at);
+    }
 }
@@ -1,9 +1,13 @@
 //! Parsing the ELF File Header
 use crate::abi;
 use crate::endian::EndianParse;
-use crate::parse::ParseError;
+use crate::parse::{ParseAt, ParseError, ParseOptions, ReadBytesExt, SerializeAt};
+use crate::section::SectionHeader;
+use crate::segment::ProgramHeader;
 
 /// Represents the ELF file word size (32-bit vs 64-bit)
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub enum Class {
     ELF32,
@@ -60,6 +64,8 @@ pub struct Elf64_Ehdr {
 /// file contents and informs how to interpret said contents. This includes
 /// the width of certain fields (32-bit vs 64-bit), the data endianness, the
 /// file type, and more.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub struct FileHeader<E: EndianParse> {
     /// 32-bit vs 64-bit
@@ -72,6 +78,11 @@ pub struct FileHeader<E: EndianParse> {
     pub osabi: u8,
     /// Version of the OS ABI
     pub abiversion: u8,
+    /// The `e_ident[EI_VERSION]` byte, which should be [abi::EV_CURRENT] but is kept as
+    /// found when parsing permissively
+    pub ident_version: u8,
+    /// The reserved `EI_PAD` bytes at the end of `e_ident`, see [FileHeader::ident_padding]
+    pub ident_pad: [u8; abi::EI_NIDENT - abi::EI_PAD],
     /// ELF file type
     pub e_type: u16,
     /// Target machine architecture
@@ -122,28 +133,75 @@ pub const ELF32_EHDR_TAILSIZE: usize = 36;
 pub const ELF64_EHDR_TAILSIZE: usize = 48;
 
 fn verify_ident(buf: &[u8]) -> Result<(), ParseError> {
-    // Verify the magic number
+    verify_magic(buf)?;
+    verify_version(buf[abi::EI_VERSION] as u32)
+}
+
+fn verify_magic(buf: &[u8]) -> Result<(), ParseError> {
     let magic = buf.split_at(abi::EI_CLASS).0;
     if magic != abi::ELFMAGIC {
         return Err(ParseError::BadMagic([
             magic[0], magic[1], magic[2], magic[3],
         ]));
     }
+    Ok(())
+}
 
-    // Verify ELF Version
-    let version = buf[abi::EI_VERSION];
-    if version != abi::EV_CURRENT {
+/// Check an ELF version, from either `EI_VERSION` or `e_version`.
+pub(crate) fn verify_version(version: u32) -> Result<(), ParseError> {
+    if version != abi::EV_CURRENT as u32 {
         return Err(ParseError::UnsupportedVersion((
             version as u64,
             abi::EV_CURRENT as u64,
         )));
     }
-
     Ok(())
 }
 
-pub fn parse_ident<E: EndianParse>(data: &[u8]) -> Result<(E, Class, u8, u8), ParseError> {
-    verify_ident(data)?;
+/// The identification bytes (`e_ident`) at the start of an ELF file, which say how to parse the
+/// rest of the file header. See [parse_ident].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Ident<E: EndianParse> {
+    /// 32-bit vs 64-bit, from `EI_CLASS`
+    pub class: Class,
+    /// The file's byte order, from `EI_DATA`
+    pub endianness: E,
+    /// OS ABI, from `EI_OSABI`
+    pub osabi: u8,
+    /// Version of the OS ABI, from `EI_ABIVERSION`
+    pub abiversion: u8,
+    /// All of the `e_ident` bytes
+    pub raw: [u8; abi::EI_NIDENT],
+}
+
+impl<E: EndianParse> Ident<E> {
+    /// Get the `EI_PAD` bytes at the end of `e_ident`, which are reserved and should be zero.
+    ///
+    /// Some packers and protection tools stash data in them, which loaders ignore.
+    pub fn padding(&self) -> &[u8] {
+        &self.raw[abi::EI_PAD..]
+    }
+}
+
+/// Parse the identification bytes at the start of an ELF file, checking the magic number and
+/// version and that the class and byte order are ones we know how to parse.
+pub fn parse_ident<E: EndianParse>(data: &[u8]) -> Result<Ident<E>, ParseError> {
+    parse_ident_with_options(data, &ParseOptions::default())
+}
+
+/// Like [parse_ident], but with the given [ParseOptions].
+///
+/// In permissive mode, the `EI_VERSION` isn't checked, since loaders ignore it. It's still
+/// available from [Ident::raw].
+pub fn parse_ident_with_options<E: EndianParse>(
+    data: &[u8],
+    options: &ParseOptions,
+) -> Result<Ident<E>, ParseError> {
+    if options.strict {
+        verify_ident(data)?;
+    } else {
+        verify_magic(data)?;
+    }
 
     let e_class = data[abi::EI_CLASS];
     let class = match e_class {
@@ -157,17 +215,21 @@ pub fn parse_ident<E: EndianParse>(data: &[u8]) -> Result<(E, Class, u8, u8), Pa
     // Verify endianness is something we know how to parse
     let file_endian = E::from_ei_data(data[abi::EI_DATA])?;
 
-    Ok((
-        file_endian,
+    let mut raw = [0u8; abi::EI_NIDENT];
+    raw.copy_from_slice(data.get_bytes(0..abi::EI_NIDENT)?);
+    Ok(Ident {
         class,
-        data[abi::EI_OSABI],
-        data[abi::EI_ABIVERSION],
-    ))
+        endianness: file_endian,
+        osabi: data[abi::EI_OSABI],
+        abiversion: data[abi::EI_ABIVERSION],
+        raw,
+    })
 }
 
 impl<E: EndianParse> FileHeader<E> {
-    pub fn parse_tail(ident: (E, Class, u8, u8), data: &[u8]) -> Result<FileHeader<E>, ParseError> {
-        let (file_endian, class, osabi, abiversion) = ident;
+    pub fn parse_tail(ident: Ident<E>, data: &[u8]) -> Result<FileHeader<E>, ParseError> {
+        let file_endian = ident.endianness;
+        let class = ident.class;
 
         let mut offset = 0;
         let e_type = file_endian.parse_u16_at(&mut offset, data)?;
@@ -202,8 +264,10 @@ impl<E: EndianParse> FileHeader<E> {
             version,
             e_type,
             e_machine,
-            osabi,
-            abiversion,
+            osabi: ident.osabi,
+            abiversion: ident.abiversion,
+            ident_version: ident.raw[abi::EI_VERSION],
+            ident_pad: ident.padding().try_into()?,
             e_entry,
             e_phoff,
             e_shoff,
@@ -218,11 +282,268 @@ impl<E: EndianParse> FileHeader<E> {
     }
 }
 
+impl<E: EndianParse> SerializeAt for FileHeader<E> {
+    /// Serialize the full file header, including the `e_ident` bytes. The `EI_CLASS` and
+    /// `EI_DATA` identification bytes are written out for the given `class` and `endian`,
+    /// and the rest come from the header's fields.
+    fn serialize_at<W: EndianParse>(
+        &self,
+        endian: W,
+        class: Class,
+        offset: &mut usize,
+        data: &mut [u8],
+    ) -> Result<(), ParseError> {
+        let ei_class = match class {
+            Class::ELF32 => abi::ELFCLASS32,
+            Class::ELF64 => abi::ELFCLASS64,
+        };
+        let ei_data = if endian.is_little() {
+            abi::ELFDATA2LSB
+        } else {
+            abi::ELFDATA2MSB
+        };
+        let mut ident = [0u8; abi::EI_NIDENT];
+        ident[..abi::EI_CLASS].copy_from_slice(&abi::ELFMAGIC);
+        ident[abi::EI_CLASS] = ei_class;
+        ident[abi::EI_DATA] = ei_data;
+        ident[abi::EI_VERSION] = self.ident_version;
+        ident[abi::EI_OSABI] = self.osabi;
+        ident[abi::EI_ABIVERSION] = self.abiversion;
+        ident[abi::EI_PAD..].copy_from_slice(&self.ident_pad);
+        for byte in ident {
+            endian.write_u8_at(offset, data, byte)?;
+        }
+
+        endian.write_u16_at(offset, data, self.e_type)?;
+        endian.write_u16_at(offset, data, self.e_machine)?;
+        endian.write_u32_at(offset, data, self.version)?;
+        match class {
+            Class::ELF32 => {
+                endian.write_u32_at(offset, data, self.e_entry.try_into()?)?;
+                endian.write_u32_at(offset, data, self.e_phoff.try_into()?)?;
+                endian.write_u32_at(offset, data, self.e_shoff.try_into()?)?;
+            }
+            Class::ELF64 => {
+                endian.write_u64_at(offset, data, self.e_entry)?;
+                endian.write_u64_at(offset, data, self.e_phoff)?;
+                endian.write_u64_at(offset, data, self.e_shoff)?;
+            }
+        }
+        endian.write_u32_at(offset, data, self.e_flags)?;
+        endian.write_u16_at(offset, data, self.e_ehsize)?;
+        endian.write_u16_at(offset, data, self.e_phentsize)?;
+        endian.write_u16_at(offset, data, self.e_phnum)?;
+        endian.write_u16_at(offset, data, self.e_shentsize)?;
+        endian.write_u16_at(offset, data, self.e_shnum)?;
+        endian.write_u16_at(offset, data, self.e_shstrndx)
+    }
+}
+
+impl<E: EndianParse> FileHeader<E> {
+    /// Get the `EI_PAD` bytes at the end of `e_ident`, which are reserved and should be
+    /// zero. [ElfBytes::validate](crate::ElfBytes::validate) warns when they aren't.
+    pub fn ident_padding(&self) -> &[u8] {
+        &self.ident_pad
+    }
+
+    /// Write this file header back into the start of `buf`, using the header's own
+    /// class and endianness.
+    ///
+    /// This is meant for patching fields (such as `e_type` or `e_entry`) of an existing
+    /// object in place, and leaves the rest of `buf` untouched.
+    pub fn write_back(&self, buf: &mut [u8]) -> Result<(), ParseError> {
+        let mut offset = 0;
+        self.serialize_at(self.endianness, self.class, &mut offset, buf)
+    }
+
+    /// Overwrite the section header at `index` in the section header table described by
+    /// this file header with the given `shdr`.
+    ///
+    /// Returns a [ParseError::BadEntsize] if the file's `e_shentsize` is too small to hold a
+    /// header for its class, and a [ParseError::BadOffset] if `index` is past the end of the
+    /// table. Padding after each entry in a table with a larger `e_shentsize` is left untouched.
+    pub fn patch_section_header(
+        &self,
+        buf: &mut [u8],
+        index: usize,
+        shdr: &SectionHeader,
+    ) -> Result<(), ParseError> {
+        let entsize = SectionHeader::validate_entsize(self.class, self.e_shentsize as usize)?;
+        let shoff: usize = self.e_shoff.try_into()?;
+        if shoff == 0 {
+            return Err(ParseError::BadOffset(index as u64));
+        }
+
+        // If the number of sections is greater than or equal to SHN_LORESERVE (0xff00),
+        // e_shnum is zero and the actual number of section header table entries
+        // is contained in the sh_size field of the section header at index 0.
+        let mut shnum = self.e_shnum as usize;
+        if shnum == 0 {
+            let mut offset = shoff;
+            let shdr0 = SectionHeader::parse_at(self.endianness, self.class, &mut offset, buf)?;
+            shnum = shdr0.sh_size.try_into()?;
+        }
+
+        let mut offset = table_entry_offset(shoff, entsize, shnum, index)?;
+        shdr.serialize_at(self.endianness, self.class, &mut offset, buf)
+    }
+
+    /// Overwrite the program header at `index` in the program header table described by
+    /// this file header with the given `phdr`.
+    ///
+    /// Returns a [ParseError::BadEntsize] if the file's `e_phentsize` is too small to hold a
+    /// header for its class, and a [ParseError::BadOffset] if `index` is past the end of the
+    /// table. Padding after each entry in a table with a larger `e_phentsize` is left untouched.
+    pub fn patch_program_header(
+        &self,
+        buf: &mut [u8],
+        index: usize,
+        phdr: &ProgramHeader,
+    ) -> Result<(), ParseError> {
+        let entsize = ProgramHeader::validate_entsize(self.class, self.e_phentsize as usize)?;
+        let phoff: usize = self.e_phoff.try_into()?;
+        if phoff == 0 {
+            return Err(ParseError::BadOffset(index as u64));
+        }
+
+        // If the number of segments is greater than or equal to PN_XNUM (0xffff),
+        // e_phnum is set to PN_XNUM, and the actual number of program header table
+        // entries is contained in the sh_info field of the section header at index 0.
+        let mut phnum = self.e_phnum as usize;
+        if phnum == abi::PN_XNUM as usize {
+            let mut offset: usize = self.e_shoff.try_into()?;
+            let shdr0 = SectionHeader::parse_at(self.endianness, self.class, &mut offset, buf)?;
+            phnum = shdr0.sh_info.try_into()?;
+        }
+
+        let mut offset = table_entry_offset(phoff, entsize, phnum, index)?;
+        phdr.serialize_at(self.endianness, self.class, &mut offset, buf)
+    }
+}
+
+/// Formats the header as a block in the style of `readelf -h`.
+///
+/// ```
+/// use elf::endian::AnyEndian;
+/// use elf::ElfBytes;
+///
+/// let path = std::path::PathBuf::from("sample-objects/basic.x86_64");
+/// let file_data = std::fs::read(path).expect("Could not read file.");
+/// let file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("Open test1");
+///
+/// let text = format!("{}", file.ehdr);
+/// assert!(text.contains("Machine:                           AMD x86-64 architecture"));
+/// ```
+#[cfg(feature = "to_str")]
+impl<E: EndianParse> core::fmt::Display for FileHeader<E> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        use crate::to_str::{e_machine_to_human_str, e_osabi_to_human_str, e_type_to_str};
+
+        writeln!(f, "ELF Header:")?;
+        let class = match self.class {
+            Class::ELF32 => "ELF32",
+            Class::ELF64 => "ELF64",
+        };
+        writeln!(f, "  {:<35}{class}", "Class:")?;
+        let data = if self.endianness.is_little() {
+            "2's complement, little endian"
+        } else {
+            "2's complement, big endian"
+        };
+        writeln!(f, "  {:<35}{data}", "Data:")?;
+        write!(f, "  {:<35}", "OS/ABI:")?;
+        match e_osabi_to_human_str(self.osabi) {
+            Some(name) => writeln!(f, "{name}")?,
+            None => writeln!(f, "<unknown: {:#x}>", self.osabi)?,
+        }
+        writeln!(f, "  {:<35}{}", "ABI Version:", self.abiversion)?;
+        write!(f, "  {:<35}", "Type:")?;
+        match e_type_to_str(self.e_type) {
+            Some(name) => writeln!(f, "{}", name.trim_start_matches("ET_"))?,
+            None => match self.e_type {
+                abi::ET_LOOS..=abi::ET_HIOS => writeln!(f, "OS Specific: ({:#x})", self.e_type)?,
+                abi::ET_LOPROC..=abi::ET_HIPROC => {
+                    writeln!(f, "Processor Specific: ({:#x})", self.e_type)?
+                }
+                _ => writeln!(f, "<unknown>: {:#x}", self.e_type)?,
+            },
+        }
+        write!(f, "  {:<35}", "Machine:")?;
+        match e_machine_to_human_str(self.e_machine) {
+            Some(name) => writeln!(f, "{name}")?,
+            None => writeln!(f, "<unknown>: {:#x}", self.e_machine)?,
+        }
+        writeln!(f, "  {:<35}{:#x}", "Version:", self.version)?;
+        writeln!(f, "  {:<35}{:#x}", "Entry point address:", self.e_entry)?;
+        writeln!(
+            f,
+            "  {:<35}{} (bytes into file)",
+            "Start of program headers:", self.e_phoff
+        )?;
+        writeln!(
+            f,
+            "  {:<35}{} (bytes into file)",
+            "Start of section headers:", self.e_shoff
+        )?;
+        writeln!(f, "  {:<35}{:#x}", "Flags:", self.e_flags)?;
+        writeln!(
+            f,
+            "  {:<35}{} (bytes)",
+            "Size of this header:", self.e_ehsize
+        )?;
+        writeln!(
+            f,
+            "  {:<35}{} (bytes)",
+            "Size of program headers:", self.e_phentsize
+        )?;
+        writeln!(f, "  {:<35}{}", "Number of program headers:", self.e_phnum)?;
+        writeln!(
+            f,
+            "  {:<35}{} (bytes)",
+            "Size of section headers:", self.e_shentsize
+        )?;
+        writeln!(f, "  {:<35}{}", "Number of section headers:", self.e_shnum)?;
+        write!(
+            f,
+            "  {:<35}{}",
+            "Section header string table index:", self.e_shstrndx
+        )
+    }
+}
+
+/// Compute the file offset of entry `index` in a table of `num` entries of `entsize` bytes.
+fn table_entry_offset(
+    tableoff: usize,
+    entsize: usize,
+    num: usize,
+    index: usize,
+) -> Result<usize, ParseError> {
+    if index >= num {
+        return Err(ParseError::BadOffset(index as u64));
+    }
+    let entoff = entsize
+        .checked_mul(index)
+        .ok_or(ParseError::IntegerOverflow)?;
+    tableoff
+        .checked_add(entoff)
+        .ok_or(ParseError::IntegerOverflow)
+}
+
 #[cfg(test)]
 mod parse_tests {
     use super::*;
     use crate::endian::AnyEndian;
 
+    fn test_ident(endianness: AnyEndian, class: Class) -> Ident<AnyEndian> {
+        Ident {
+            class,
+            endianness,
+            osabi: abi::ELFOSABI_LINUX,
+            abiversion: 7,
+            raw: [0; abi::EI_NIDENT],
+        }
+    }
+
     #[test]
     fn test_verify_ident_valid() {
         let data: [u8; abi::EI_NIDENT] = [
@@ -384,7 +705,7 @@ mod parse_tests {
 
     #[test]
     fn test_parse_ehdr32_works() {
-        let ident = (AnyEndian::Little, Class::ELF32, abi::ELFOSABI_LINUX, 7u8);
+        let ident = test_ident(AnyEndian::Little, Class::ELF32);
         let mut tail = [0u8; ELF64_EHDR_TAILSIZE];
         for (n, elem) in tail.iter_mut().enumerate().take(ELF64_EHDR_TAILSIZE) {
             *elem = n as u8;
@@ -398,6 +719,8 @@ mod parse_tests {
                 version: 0x7060504,
                 osabi: abi::ELFOSABI_LINUX,
                 abiversion: 7,
+                ident_version: 0,
+                ident_pad: [0; 7],
                 e_type: 0x100,
                 e_machine: 0x302,
                 e_entry: 0x0B0A0908,
@@ -414,9 +737,29 @@ mod parse_tests {
         );
     }
 
+    #[test]
+    fn test_parse_ident_padding() {
+        let mut data = std::fs::read("sample-objects/basic.x86_64").expect("should read");
+        data[abi::EI_PAD..abi::EI_NIDENT].copy_from_slice(b"packer!");
+
+        let ident = parse_ident::<AnyEndian>(&data).expect("should parse ident");
+        assert_eq!(ident.class, Class::ELF64);
+        assert_eq!(ident.endianness, AnyEndian::Little);
+        assert_eq!(ident.raw, data[..abi::EI_NIDENT]);
+        assert_eq!(ident.padding(), b"packer!");
+
+        let ehdr = FileHeader::parse_tail(ident, &data[abi::EI_NIDENT..]).expect("should parse");
+        assert_eq!(ehdr.ident_padding(), b"packer!");
+
+        assert!(matches!(
+            parse_ident::<AnyEndian>(&data[..abi::EI_PAD]),
+            Err(ParseError::SliceReadError(_))
+        ));
+    }
+
     #[test]
     fn test_parse_ehdr32_fuzz_too_short() {
-        let ident = (AnyEndian::Little, Class::ELF32, abi::ELFOSABI_LINUX, 7u8);
+        let ident = test_ident(AnyEndian::Little, Class::ELF32);
         let tail = [0u8; ELF32_EHDR_TAILSIZE];
 
         for n in 0..ELF32_EHDR_TAILSIZE {
@@ -431,7 +774,7 @@ mod parse_tests {
 
     #[test]
     fn test_parse_ehdr64_works() {
-        let ident = (AnyEndian::Big, Class::ELF64, abi::ELFOSABI_LINUX, 7u8);
+        let ident = test_ident(AnyEndian::Big, Class::ELF64);
         let mut tail = [0u8; ELF64_EHDR_TAILSIZE];
         for (n, elem) in tail.iter_mut().enumerate().take(ELF64_EHDR_TAILSIZE) {
             *elem = n as u8;
@@ -445,6 +788,8 @@ mod parse_tests {
                 version: 0x04050607,
                 osabi: abi::ELFOSABI_LINUX,
                 abiversion: 7,
+                ident_version: 0,
+                ident_pad: [0; 7],
                 e_type: 0x0001,
                 e_machine: 0x0203,
                 e_entry: 0x08090A0B0C0D0E0F,
@@ -463,7 +808,7 @@ mod parse_tests {
 
     #[test]
     fn test_parse_ehdr64_fuzz_too_short() {
-        let ident = (AnyEndian::Little, Class::ELF64, abi::ELFOSABI_LINUX, 7u8);
+        let ident = test_ident(AnyEndian::Little, Class::ELF64);
         let tail = [0u8; ELF64_EHDR_TAILSIZE];
 
         for n in 0..ELF64_EHDR_TAILSIZE {
@@ -476,3 +821,376 @@ mod parse_tests {
         }
     }
 }
+
+#[cfg(test)]
+mod serialize_tests {
+    use super::*;
+    use crate::endian::{AnyEndian, BigEndian, LittleEndian};
+
+    fn roundtrip<E: EndianParse>(data: &[u8], endian: E, class: Class) {
+        let ident = parse_ident::<AnyEndian>(data).expect("Failed to parse ident");
+        let ehdr =
+            FileHeader::parse_tail(ident, &data[abi::EI_NIDENT..]).expect("Failed to parse ehdr");
+
+        let mut buf = vec![0u8; data.len()];
+        let mut offset = 0;
+        ehdr.serialize_at(endian, class, &mut offset, &mut buf)
+            .expect("Failed to serialize");
+        assert_eq!(offset, data.len());
+        assert_eq!(buf, data);
+
+        for n in 0..data.len() {
+            let mut offset = 0;
+            let result = ehdr
+                .serialize_at(endian, class, &mut offset, &mut buf[..n])
+                .expect_err("Expected an error");
+            assert!(
+                matches!(result, ParseError::SliceReadError(_)),
+                "Unexpected Error type found: {result:?}"
+            );
+        }
+    }
+
+    fn ident_for(class: u8, data: u8) -> [u8; abi::EI_NIDENT] {
+        let mut ident = [0u8; abi::EI_NIDENT];
+        ident[..4].copy_from_slice(&abi::ELFMAGIC);
+        ident[abi::EI_CLASS] = class;
+        ident[abi::EI_DATA] = data;
+        ident[abi::EI_VERSION] = abi::EV_CURRENT;
+        ident[abi::EI_OSABI] = abi::ELFOSABI_LINUX;
+        ident[abi::EI_ABIVERSION] = 7;
+        ident
+    }
+
+    fn ehdr_bytes(ident: [u8; abi::EI_NIDENT], tailsize: usize) -> Vec<u8> {
+        let mut data = ident.to_vec();
+        data.extend((0..tailsize).map(|n| n as u8));
+        data
+    }
+
+    #[test]
+    fn serialize_ehdr32_lsb_roundtrip() {
+        let data = ehdr_bytes(
+            ident_for(abi::ELFCLASS32, abi::ELFDATA2LSB),
+            ELF32_EHDR_TAILSIZE,
+        );
+        roundtrip(&data, LittleEndian, Class::ELF32);
+    }
+
+    #[test]
+    fn serialize_ehdr32_msb_roundtrip() {
+        let data = ehdr_bytes(
+            ident_for(abi::ELFCLASS32, abi::ELFDATA2MSB),
+            ELF32_EHDR_TAILSIZE,
+        );
+        roundtrip(&data, BigEndian, Class::ELF32);
+    }
+
+    #[test]
+    fn serialize_ehdr64_lsb_roundtrip() {
+        let data = ehdr_bytes(
+            ident_for(abi::ELFCLASS64, abi::ELFDATA2LSB),
+            ELF64_EHDR_TAILSIZE,
+        );
+        roundtrip(&data, LittleEndian, Class::ELF64);
+    }
+
+    #[test]
+    fn serialize_ehdr64_msb_roundtrip() {
+        let data = ehdr_bytes(
+            ident_for(abi::ELFCLASS64, abi::ELFDATA2MSB),
+            ELF64_EHDR_TAILSIZE,
+        );
+        roundtrip(&data, AnyEndian::Big, Class::ELF64);
+    }
+
+    #[test]
+    fn serialize_ehdr_from_file() {
+        let path = std::path::PathBuf::from("sample-objects/basic.x86_64");
+        let file_data = std::fs::read(path).expect("Could not read file.");
+        roundtrip(
+            &file_data[..abi::EI_NIDENT + ELF64_EHDR_TAILSIZE],
+            LittleEndian,
+            Class::ELF64,
+        );
+    }
+
+    #[test]
+    fn serialize_ehdr_keeps_ident_padding() {
+        let path = std::path::PathBuf::from("sample-objects/basic.x86_64");
+        let mut file_data = std::fs::read(path).expect("Could not read file.");
+        file_data[abi::EI_PAD] = 0x42;
+        file_data[abi::EI_NIDENT - 1] = 0xff;
+        roundtrip(
+            &file_data[..abi::EI_NIDENT + ELF64_EHDR_TAILSIZE],
+            LittleEndian,
+            Class::ELF64,
+        );
+    }
+
+    #[test]
+    fn serialize_ehdr32_value_too_large() {
+        let data = ehdr_bytes(
+            ident_for(abi::ELFCLASS64, abi::ELFDATA2LSB),
+            ELF64_EHDR_TAILSIZE,
+        );
+        let ident = parse_ident::<AnyEndian>(&data).expect("Failed to parse ident");
+        let ehdr =
+            FileHeader::parse_tail(ident, &data[abi::EI_NIDENT..]).expect("Failed to parse ehdr");
+
+        let mut buf = [0u8; abi::EI_NIDENT + ELF32_EHDR_TAILSIZE];
+        let mut offset = 0;
+        let result = ehdr
+            .serialize_at(LittleEndian, Class::ELF32, &mut offset, &mut buf)
+            .expect_err("Expected an error");
+        assert!(
+            matches!(result, ParseError::TryFromIntError(_)),
+            "Unexpected Error type found: {result:?}"
+        );
+    }
+}
+
+#[cfg(test)]
+mod patch_tests {
+    use super::*;
+    use crate::endian::AnyEndian;
+    use crate::ElfBytes;
+
+    fn sample() -> (Vec<u8>, FileHeader<AnyEndian>) {
+        let path = std::path::PathBuf::from("sample-objects/basic.x86_64");
+        let data = std::fs::read(path).expect("Could not read file.");
+        let ehdr = ElfBytes::<AnyEndian>::minimal_parse(&data)
+            .expect("Open test1")
+            .ehdr;
+        (data, ehdr)
+    }
+
+    #[test]
+    fn write_back_ehdr() {
+        let (mut data, mut ehdr) = sample();
+        let orig = data.clone();
+
+        // Writing back the unmodified header is a no-op
+        ehdr.write_back(&mut data).expect("Failed to write back");
+        assert_eq!(data, orig);
+
+        ehdr.e_type = abi::ET_DYN;
+        ehdr.e_entry = 0x1234;
+        ehdr.write_back(&mut data).expect("Failed to write back");
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&data).expect("Open test1");
+        assert_eq!(file.ehdr, ehdr);
+        assert_eq!(&data[64..], &orig[64..]);
+    }
+
+    #[test]
+    fn write_back_keeps_ident_version() {
+        let (mut data, _) = sample();
+        data[abi::EI_VERSION] = 0x42;
+        let orig = data.clone();
+
+        let mut ehdr =
+            ElfBytes::<AnyEndian>::minimal_parse_with_options(&data, ParseOptions::permissive())
+                .expect("should parse permissively")
+                .ehdr;
+        assert_eq!(ehdr.ident_version, 0x42);
+        ehdr.write_back(&mut data).expect("Failed to write back");
+        assert_eq!(data, orig);
+
+        ehdr.ident_version = abi::EV_CURRENT;
+        ehdr.write_back(&mut data).expect("Failed to write back");
+        assert_eq!(data[abi::EI_VERSION], abi::EV_CURRENT);
+    }
+
+    #[test]
+    fn patch_shdr() {
+        let (mut data, ehdr) = sample();
+        let mut shdr = {
+            let file = ElfBytes::<AnyEndian>::minimal_parse(&data).expect("Open test1");
+            file.section_headers()
+                .expect("should parse")
+                .get(5)
+                .expect("should get")
+        };
+        shdr.sh_flags |= abi::SHF_WRITE as u64;
+
+        ehdr.patch_section_header(&mut data, 5, &shdr)
+            .expect("Failed to patch");
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&data).expect("Open test1");
+        let shdrs = file.section_headers().expect("should parse");
+        assert_eq!(shdrs.get(5).expect("should get"), shdr);
+        let num = shdrs.len();
+
+        let result = ehdr
+            .patch_section_header(&mut data, num, &shdr)
+            .expect_err("Expected an error");
+        assert!(
+            matches!(result, ParseError::BadOffset(_)),
+            "Unexpected Error type found: {result:?}"
+        );
+    }
+
+    #[test]
+    fn patch_phdr() {
+        let (mut data, ehdr) = sample();
+        let mut phdr = {
+            let file = ElfBytes::<AnyEndian>::minimal_parse(&data).expect("Open test1");
+            file.segments()
+                .expect("should parse")
+                .get(0)
+                .expect("should get")
+        };
+        phdr.p_flags |= abi::PF_W;
+
+        ehdr.patch_program_header(&mut data, 0, &phdr)
+            .expect("Failed to patch");
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&data).expect("Open test1");
+        let phdrs = file.segments().expect("should parse");
+        assert_eq!(phdrs.get(0).expect("should get"), phdr);
+        let num = phdrs.len();
+
+        let result = ehdr
+            .patch_program_header(&mut data, num, &phdr)
+            .expect_err("Expected an error");
+        assert!(
+            matches!(result, ParseError::BadOffset(_)),
+            "Unexpected Error type found: {result:?}"
+        );
+    }
+
+    #[test]
+    fn patch_with_bad_entsize() {
+        let (mut data, mut ehdr) = sample();
+        let orig = data.clone();
+        ehdr.e_shentsize -= 8;
+        ehdr.e_phentsize -= 8;
+
+        let result = ehdr
+            .patch_section_header(&mut data, 1, &SectionHeader::default())
+            .expect_err("Expected an error");
+        assert!(
+            matches!(result, ParseError::BadEntsize(_)),
+            "Unexpected Error type found: {result:?}"
+        );
+        let result = ehdr
+            .patch_program_header(&mut data, 1, &ProgramHeader::default())
+            .expect_err("Expected an error");
+        assert!(
+            matches!(result, ParseError::BadEntsize(_)),
+            "Unexpected Error type found: {result:?}"
+        );
+        assert_eq!(data, orig);
+    }
+}
+
+#[cfg(all(test, feature = "to_str"))]
+mod display_tests {
+    use super::*;
+    use crate::endian::AnyEndian;
+
+    #[test]
+    fn display_file_header() {
+        let data = std::fs::read("sample-objects/basic.x86_64").expect("Could not read file.");
+        let ident = parse_ident::<AnyEndian>(&data).expect("should parse ident");
+        let ehdr = FileHeader::parse_tail(ident, &data[abi::EI_NIDENT..]).expect("should parse");
+        assert_eq!(
+            format!("{ehdr}"),
+            "\
+ELF Header:
+  Class:                             ELF64
+  Data:                              2's complement, little endian
+  OS/ABI:                            UNIX - System V
+  ABI Version:                       0
+  Type:                              EXEC
+  Machine:                           AMD x86-64 architecture
+  Version:                           0x1
+  Entry point address:               0x4003f0
+  Start of program headers:          64 (bytes into file)
+  Start of section headers:          4800 (bytes into file)
+  Flags:                             0x0
+  Size of this header:               64 (bytes)
+  Size of program headers:           56 (bytes)
+  Number of program headers:         8
+  Size of section headers:           64 (bytes)
+  Number of section headers:         31
+  Section header string table index: 28"
+        );
+    }
+
+    #[test]
+    fn display_unknown_values() {
+        let ehdr = FileHeader {
+            class: Class::ELF32,
+            endianness: AnyEndian::Big,
+            version: 1,
+            osabi: 200,
+            abiversion: 0,
+            ident_version: 1,
+            ident_pad: [0; 7],
+            e_type: abi::ET_LOPROC + 1,
+            e_machine: 0xfff0,
+            e_entry: 0,
+            e_phoff: 0,
+            e_shoff: 0,
+            e_flags: 0,
+            e_ehsize: 52,
+            e_phentsize: 32,
+            e_phnum: 0,
+            e_shentsize: 40,
+            e_shnum: 0,
+            e_shstrndx: 0,
+        };
+        let text = format!("{ehdr}");
+        assert!(text.contains("Data:                              2's complement, big endian\n"));
+        assert!(text.contains("OS/ABI:                            <unknown: 0xc8>\n"));
+        assert!(text.contains("Type:                              Processor Specific: (0xff01)\n"));
+        assert!(text.contains("Machine:                           <unknown>: 0xfff0\n"));
+    }
+}
+
+#[cfg(all(test, feature = "serde"))]
+mod serde_tests {
+    use super::*;
+    use crate::endian::AnyEndian;
+    use crate::parse::test_serde_roundtrip;
+
+    #[test]
+    fn serde_class() {
+        test_serde_roundtrip(&Class::ELF32, r#""ELF32""#);
+        test_serde_roundtrip(&Class::ELF64, r#""ELF64""#);
+    }
+
+    #[test]
+    fn serde_file_header() {
+        let ehdr = FileHeader {
+            class: Class::ELF64,
+            endianness: AnyEndian::Little,
+            version: 1,
+            osabi: 3,
+            abiversion: 0,
+            ident_version: 1,
+            ident_pad: [0; 7],
+            e_type: 2,
+            e_machine: 62,
+            e_entry: 0x401000,
+            e_phoff: 64,
+            e_shoff: 6000,
+            e_flags: 0,
+            e_ehsize: 64,
+            e_phentsize: 56,
+            e_phnum: 9,
+            e_shentsize: 64,
+            e_shnum: 30,
+            e_shstrndx: 29,
+        };
+        test_serde_roundtrip(
+            &ehdr,
+            concat!(
+                r#"{"class":"ELF64","endianness":"Little","version":1,"osabi":3,"abiversion":0,"#,
+                r#""ident_version":1,"ident_pad":[0,0,0,0,0,0,0],"#,
+                r#""e_type":2,"e_machine":62,"e_entry":4198400,"e_phoff":64,"e_shoff":6000,"#,
+                r#""e_flags":0,"e_ehsize":64,"e_phentsize":56,"e_phnum":9,"e_shentsize":64,"#,
+                r#""e_shnum":30,"e_shstrndx":29}"#
+            ),
+        );
+    }
+}
@@ -0,0 +1,400 @@
+//! Generating structurally valid ELF objects for fuzzing
+//!
+//! Mutating raw bytes rarely gets a fuzzer past the file header and section table checks, so
+//! [arbitrary_elf] instead lays out a whole object with the [ElfBuilder] from
+//! [Arbitrary] headers, symbols, notes, relocations, dynamic entries and hash tables. The
+//! layout is always consistent, but the headers keep their random flags, addresses, links
+//! and infos so the parsers still see plenty of nonsense cross references.
+//!
+//! Example usage from a cargo-fuzz target:
+//! ```
+//! use arbitrary::Unstructured;
+//! use elf::endian::AnyEndian;
+//! use elf::ElfBytes;
+//!
+//! let input = [0x5au8; 512];
+//! let mut u = Unstructured::new(&input);
+//! let data = elf::fuzz::arbitrary_elf(&mut u).expect("should generate");
+//! let file = ElfBytes::<AnyEndian>::minimal_parse(&data).expect("should parse");
+//! assert!(file.section_headers().is_some());
+//! ```
+use crate::abi;
+use crate::builder::ElfBuilder;
+use crate::dynamic::Dyn;
+use crate::endian::AnyEndian;
+use crate::file::{Class, FileHeader};
+use crate::hash::{build_gnu_hash, build_sysv_hash};
+use crate::note::NoteBuilder;
+use crate::parse::{ParseAt, SerializeAt};
+use crate::relocation::{build_rela_section, Rela};
+use crate::section::SectionHeader;
+use crate::segment::ProgramHeader;
+use crate::symbol::{Symbol, SymbolTableBuilder};
+use arbitrary::{Arbitrary, Result, Unstructured};
+
+#[cfg(not(feature = "std"))]
+use alloc::{string::String, vec, vec::Vec};
+
+/// The most sections of each kind that get generated
+const MAX_SECTIONS: usize = 16;
+
+/// The most segments that get generated, on top of the ones covering note and dynamic sections
+const MAX_SEGMENTS: usize = 4;
+
+/// The largest section alignment, as a power of two, to keep the layout padding small
+const MAX_ALIGN_SHIFT: u32 = 6;
+
+/// Generate a random ELF object of either class and byte order.
+///
+/// The object has a random mix of data, `NOBITS`, note, dynamic and relocation sections,
+/// optionally a `.dynsym` with matching SysV and GNU hash tables, a `.symtab`, and program
+/// headers (including `PT_NOTE` and `PT_DYNAMIC` segments covering the matching sections).
+/// Every section's size and contents are drawn from `u`, so the object's size is bounded by
+/// the size of the fuzzer input.
+pub fn arbitrary_elf(u: &mut Unstructured<'_>) -> Result<Vec<u8>> {
+    let class = Class::arbitrary(u)?;
+    let endian = AnyEndian::arbitrary(u)?;
+    let ehdr = FileHeader::<AnyEndian>::arbitrary(u)?;
+
+    let mut builder = ElfBuilder::new(endian, class, ehdr.e_type, ehdr.e_machine);
+    builder.ehdr.osabi = ehdr.osabi;
+    builder.ehdr.abiversion = ehdr.abiversion;
+    builder.ehdr.ident_pad = ehdr.ident_pad;
+    builder.ehdr.e_entry = narrow(class, ehdr.e_entry);
+    builder.ehdr.e_flags = ehdr.e_flags;
+
+    let mut segments = Vec::new();
+    let mut nsections = 0;
+
+    let dynstr = if bool::arbitrary(u)? {
+        Some(add_dynsym(u, &mut builder, class, endian)?)
+    } else {
+        None
+    };
+
+    for _ in 0..u.int_in_range(0..=MAX_SECTIONS)? {
+        let mut shdr = arbitrary_shdr(u, class)?;
+        let name = arbitrary_name(u)?;
+        let data = match u.int_in_range(0..=4)? {
+            0 => {
+                shdr.sh_type = abi::SHT_PROGBITS;
+                Vec::from(<&[u8]>::arbitrary(u)?)
+            }
+            1 => {
+                shdr.sh_type = abi::SHT_NOBITS;
+                Vec::new()
+            }
+            2 => {
+                shdr.sh_type = abi::SHT_NOTE;
+                arbitrary_notes(u, endian, &mut shdr)?
+            }
+            3 => {
+                shdr.sh_type = abi::SHT_DYNAMIC;
+                shdr.sh_entsize = Dyn::size_for(class) as u64;
+                if let Some(dynstr) = dynstr {
+                    shdr.sh_link = dynstr;
+                }
+                let mut data = Vec::new();
+                for mut entry in Vec::<Dyn>::arbitrary(u)? {
+                    if class == Class::ELF32 {
+                        entry.d_tag = entry.d_tag as i32 as i64;
+                    }
+                    entry.d_un = narrow(class, entry.d_un);
+                    serialize_entry(&entry, endian, class, &mut data)?;
+                }
+                data
+            }
+            _ => {
+                shdr.sh_type = abi::SHT_RELA;
+                let relas: Vec<Rela> = Vec::<Rela>::arbitrary(u)?
+                    .into_iter()
+                    .map(|rela| narrow_rela(class, rela))
+                    .collect();
+                let rela = build_rela_section(endian, class, &relas)
+                    .map_err(|_| arbitrary::Error::IncorrectFormat)?;
+                shdr.sh_entsize = rela.entsize;
+                rela.data
+            }
+        };
+
+        let index = builder.add_section(&name, shdr, &data);
+        nsections = index;
+        match shdr.sh_type {
+            abi::SHT_NOTE => segments.push((abi::PT_NOTE, shdr.sh_addralign, index)),
+            abi::SHT_DYNAMIC => segments.push((abi::PT_DYNAMIC, shdr.sh_addralign, index)),
+            _ => {}
+        }
+    }
+
+    for (p_type, p_align, index) in segments {
+        let mut phdr = arbitrary_phdr(u, class)?;
+        phdr.p_type = p_type;
+        phdr.p_align = p_align;
+        builder.add_segment(phdr, Some(index));
+    }
+    for _ in 0..u.int_in_range(0..=MAX_SEGMENTS)? {
+        let phdr = arbitrary_phdr(u, class)?;
+        let section = if nsections > 0 && bool::arbitrary(u)? {
+            Some(u.int_in_range(1..=nsections)?)
+        } else {
+            None
+        };
+        builder.add_segment(phdr, section);
+    }
+
+    for _ in 0..u.arbitrary_len::<Symbol>()? {
+        let name = arbitrary_name(u)?;
+        let sym = arbitrary_symbol(u, class)?;
+        builder.add_symbol(&name, sym);
+    }
+
+    builder
+        .build()
+        .map_err(|_| arbitrary::Error::IncorrectFormat)
+}
+
+/// Add a `.dynstr`, `.dynsym` and its SysV and GNU hash tables to the builder, returning the
+/// section index of the `.dynstr`.
+fn add_dynsym(
+    u: &mut Unstructured<'_>,
+    builder: &mut ElfBuilder<AnyEndian>,
+    class: Class,
+    endian: AnyEndian,
+) -> Result<u32> {
+    let mut locals = Vec::new();
+    let mut globals = Vec::new();
+    for _ in 0..u.arbitrary_len::<Symbol>()? {
+        let name = arbitrary_name(u)?;
+        let sym = arbitrary_symbol(u, class)?;
+        if sym.st_bind() == abi::STB_LOCAL {
+            locals.push((name, sym));
+        } else {
+            globals.push((name, sym));
+        }
+    }
+
+    // The SymbolTableBuilder places the locals first, so order the globals for the GNU hash
+    // table ahead of time so that the final symbol order works for both hash tables.
+    let symoffset = locals.len() + 1;
+    let global_names: Vec<&[u8]> = globals.iter().map(|(name, _)| name.as_bytes()).collect();
+    let gnu_hash = build_gnu_hash(endian, class, symoffset, &global_names)
+        .map_err(|_| arbitrary::Error::IncorrectFormat)?;
+
+    let mut symbols = SymbolTableBuilder::new();
+    let mut names: Vec<&[u8]> = vec![b""];
+    for (name, sym) in locals.iter() {
+        symbols.add_symbol(name, sym.clone());
+        names.push(name.as_bytes());
+    }
+    for index in gnu_hash.order.iter() {
+        let (name, sym) = &globals[*index];
+        symbols.add_symbol(name, sym.clone());
+        names.push(name.as_bytes());
+    }
+    let sysv_hash =
+        build_sysv_hash(endian, class, &names).map_err(|_| arbitrary::Error::IncorrectFormat)?;
+    let table = symbols
+        .build(endian, class)
+        .map_err(|_| arbitrary::Error::IncorrectFormat)?;
+
+    let dynstr = builder.add_section(".dynstr", table.strtab_header(), &table.strtab);
+    let dynstr: u32 = dynstr
+        .try_into()
+        .map_err(|_| arbitrary::Error::IncorrectFormat)?;
+    let mut dynsym_shdr = table.symtab_header(dynstr);
+    dynsym_shdr.sh_type = abi::SHT_DYNSYM;
+    dynsym_shdr.sh_flags = abi::SHF_ALLOC as u64;
+    let dynsym = builder.add_section(".dynsym", dynsym_shdr, &table.symtab) as u32;
+
+    builder.add_section(
+        ".hash",
+        SectionHeader {
+            sh_type: abi::SHT_HASH,
+            sh_flags: abi::SHF_ALLOC as u64,
+            sh_link: dynsym,
+            sh_addralign: 4,
+            sh_entsize: 4,
+            ..Default::default()
+        },
+        &sysv_hash,
+    );
+    builder.add_section(
+        ".gnu.hash",
+        SectionHeader {
+            sh_type: abi::SHT_GNU_HASH,
+            sh_flags: abi::SHF_ALLOC as u64,
+            sh_link: dynsym,
+            sh_addralign: match class {
+                Class::ELF32 => 4,
+                Class::ELF64 => 8,
+            },
+            ..Default::default()
+        },
+        &gnu_hash.data,
+    );
+    Ok(dynstr)
+}
+
+/// Generate the contents of a note section, setting the header's alignment to match.
+fn arbitrary_notes(
+    u: &mut Unstructured<'_>,
+    endian: AnyEndian,
+    shdr: &mut SectionHeader,
+) -> Result<Vec<u8>> {
+    let align = if bool::arbitrary(u)? { 8 } else { 4 };
+    shdr.sh_addralign = align as u64;
+    let mut notes =
+        NoteBuilder::new(endian, align).map_err(|_| arbitrary::Error::IncorrectFormat)?;
+    for _ in 0..u.arbitrary_len::<u32>()? {
+        let name: &[u8] = if bool::arbitrary(u)? {
+            abi::ELF_NOTE_GNU
+        } else {
+            <&[u8]>::arbitrary(u)?
+        };
+        let n_type = u.int_in_range(0..=8)?;
+        let desc = <&[u8]>::arbitrary(u)?;
+        notes
+            .add(name, n_type, desc)
+            .map_err(|_| arbitrary::Error::IncorrectFormat)?;
+    }
+    Ok(notes.build())
+}
+
+/// Get a random name with no NUL bytes, which can't be put in a string table.
+fn arbitrary_name(u: &mut Unstructured<'_>) -> Result<String> {
+    let name = <&str>::arbitrary(u)?;
+    Ok(name.replace('\0', ""))
+}
+
+fn arbitrary_shdr(u: &mut Unstructured<'_>, class: Class) -> Result<SectionHeader> {
+    let mut shdr = SectionHeader::arbitrary(u)?;
+    shdr.sh_flags = narrow(class, shdr.sh_flags);
+    shdr.sh_addr = narrow(class, shdr.sh_addr);
+    shdr.sh_size = narrow(class, shdr.sh_size);
+    shdr.sh_addralign = 1 << u.int_in_range(0..=MAX_ALIGN_SHIFT)?;
+    shdr.sh_entsize = narrow(class, shdr.sh_entsize);
+    Ok(shdr)
+}
+
+fn arbitrary_phdr(u: &mut Unstructured<'_>, class: Class) -> Result<ProgramHeader> {
+    let mut phdr = ProgramHeader::arbitrary(u)?;
+    phdr.p_offset = narrow(class, phdr.p_offset);
+    phdr.p_vaddr = narrow(class, phdr.p_vaddr);
+    phdr.p_paddr = narrow(class, phdr.p_paddr);
+    phdr.p_filesz = narrow(class, phdr.p_filesz);
+    phdr.p_memsz = narrow(class, phdr.p_memsz);
+    phdr.p_align = narrow(class, phdr.p_align);
+    Ok(phdr)
+}
+
+fn arbitrary_symbol(u: &mut Unstructured<'_>, class: Class) -> Result<Symbol> {
+    let mut sym = Symbol::arbitrary(u)?;
+    sym.st_value = narrow(class, sym.st_value);
+    sym.st_size = narrow(class, sym.st_size);
+    Ok(sym)
+}
+
+fn narrow_rela(class: Class, rela: Rela) -> Rela {
+    match class {
+        Class::ELF32 => Rela {
+            r_offset: rela.r_offset as u32 as u64,
+            r_sym: rela.r_sym & 0x00ff_ffff,
+            r_type: rela.r_type & 0xff,
+            r_addend: rela.r_addend as i32 as i64,
+        },
+        Class::ELF64 => rela,
+    }
+}
+
+/// Truncate an address-sized value to fit in the given class.
+fn narrow(class: Class, value: u64) -> u64 {
+    match class {
+        Class::ELF32 => value as u32 as u64,
+        Class::ELF64 => value,
+    }
+}
+
+fn serialize_entry<P: ParseAt + SerializeAt>(
+    entry: &P,
+    endian: AnyEndian,
+    class: Class,
+    data: &mut Vec<u8>,
+) -> Result<()> {
+    let mut offset = data.len();
+    data.resize(offset + P::size_for(class), 0);
+    entry
+        .serialize_at(endian, class, &mut offset, data)
+        .map_err(|_| arbitrary::Error::IncorrectFormat)
+}
+
+#[cfg(test)]
+mod generator_tests {
+    use super::*;
+    use crate::ElfBytes;
+
+    /// Fill a buffer with a simple xorshift sequence so each seed gives a different object
+    fn input(seed: u64, len: usize) -> Vec<u8> {
+        let mut state = seed.wrapping_mul(0x9e37_79b9_7f4a_7c15) | 1;
+        (0..len)
+            .map(|_| {
+                state ^= state << 13;
+                state ^= state >> 7;
+                state ^= state << 17;
+                state as u8
+            })
+            .collect()
+    }
+
+    #[test]
+    fn generated_objects_parse() {
+        let mut classes = [false; 2];
+        let mut hashed = 0;
+        for seed in 0..128 {
+            let input = input(seed, 4096);
+            let mut u = Unstructured::new(&input);
+            let data = arbitrary_elf(&mut u).expect("should generate");
+
+            let file = ElfBytes::<AnyEndian>::minimal_parse(&data).expect("should parse");
+            classes[(file.ehdr.class == Class::ELF64) as usize] = true;
+            let (shdrs, strtab) = file
+                .section_headers_with_strtab()
+                .expect("section table should parse");
+            let (shdrs, strtab) = (shdrs.unwrap(), strtab.unwrap());
+            for shdr in shdrs.iter() {
+                strtab
+                    .get(shdr.sh_name as usize)
+                    .expect("section name should parse");
+            }
+
+            // Random flags can mark tables as compressed, so the common data can fail to parse
+            let common = match file.find_common_data() {
+                Ok(common) => common,
+                Err(_) => continue,
+            };
+            if let (Some(dynsyms), Some(dynstrs), Some(hash), Some(gnu_hash)) = (
+                common.dynsyms,
+                common.dynsyms_strs,
+                common.sysv_hash,
+                common.gnu_hash,
+            ) {
+                hashed += 1;
+                for sym in dynsyms.iter().skip(1) {
+                    let name = dynstrs.get_raw(sym.st_name as usize).expect("should parse");
+                    if name.is_empty() {
+                        continue;
+                    }
+                    let found = hash.find(name, &dynsyms, &dynstrs).expect("should parse");
+                    assert!(found.is_some(), "{name:?} should be in the SysV hash");
+                    if sym.st_bind() != abi::STB_LOCAL {
+                        let found = gnu_hash
+                            .find(name, &dynsyms, &dynstrs)
+                            .expect("should parse");
+                        assert!(found.is_some(), "{name:?} should be in the GNU hash");
+                    }
+                }
+            }
+        }
+        assert_eq!(classes, [true, true]);
+        assert!(hashed > 0);
+    }
+}
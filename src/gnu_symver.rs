@@ -5,7 +5,7 @@ use crate::file::Class;
 use crate::parse::{ParseAt, ParseError, ParsingTable};
 use crate::string_table::StringTable;
 
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct SymbolRequirement<'data> {
     pub file: &'data str,
     pub name: &'data str,
@@ -14,7 +14,7 @@ pub struct SymbolRequirement<'data> {
     pub hidden: bool,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct SymbolDefinition<'data, E: EndianParse> {
     pub hash: u32,
     pub flags: u16,
@@ -22,15 +22,18 @@ pub struct SymbolDefinition<'data, E: EndianParse> {
     pub hidden: bool,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct SymbolNamesIterator<'data, E: EndianParse> {
     vda_iter: VerDefAuxIterator<'data, E>,
-    strtab: &'data StringTable<'data>,
+    strtab: StringTable<'data>,
 }
 
 impl<'data, E: EndianParse> SymbolNamesIterator<'data, E> {
-    pub fn new(vda_iter: VerDefAuxIterator<'data, E>, strtab: &'data StringTable<'data>) -> Self {
-        SymbolNamesIterator { vda_iter, strtab }
+    pub fn new(vda_iter: VerDefAuxIterator<'data, E>, strtab: &StringTable<'data>) -> Self {
+        SymbolNamesIterator {
+            vda_iter,
+            strtab: *strtab,
+        }
     }
 }
 
@@ -45,7 +48,7 @@ impl<'data, E: EndianParse> Iterator for SymbolNamesIterator<'data, E> {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct SymbolVersionTable<'data, E: EndianParse> {
     version_ids: VersionIndexTable<'data, E>,
 
@@ -69,7 +72,7 @@ impl<'data, E: EndianParse> SymbolVersionTable<'data, E> {
     pub fn get_requirement(
         &self,
         sym_idx: usize,
-    ) -> Result<Option<SymbolRequirement<'_>>, ParseError> {
+    ) -> Result<Option<SymbolRequirement<'data>>, ParseError> {
         let (verneeds, verneed_strs) = match self.verneeds {
             Some(verneeds) => verneeds,
             None => {
@@ -109,16 +112,16 @@ impl<'data, E: EndianParse> SymbolVersionTable<'data, E> {
     pub fn get_definition(
         &self,
         sym_idx: usize,
-    ) -> Result<Option<SymbolDefinition<'_, E>>, ParseError> {
-        let (ref verdefs, ref verdef_strs) = match self.verdefs {
-            Some(ref verdefs) => verdefs,
+    ) -> Result<Option<SymbolDefinition<'data, E>>, ParseError> {
+        let (verdefs, verdef_strs) = match self.verdefs {
+            Some(verdefs) => verdefs,
             None => {
                 return Ok(None);
             }
         };
 
         let ver_ndx = self.version_ids.get(sym_idx)?;
-        let iter = *verdefs;
+        let iter = verdefs;
         for (vd, vda_iter) in iter {
             if vd.vd_ndx != ver_ndx.index() {
                 continue;
@@ -170,7 +173,7 @@ pub type VersionIndexTable<'data, E> = ParsingTable<'data, E, VersionIndex>;
 /// structures in the .gnu.version_d and .gnu.version_r sections. These values
 /// are located in identifiers provided by the the vna_other member of the VerNeedAux
 /// structure or the vd_ndx member of the VerDef structure.
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct VersionIndex(pub u16);
 
 impl VersionIndex {
@@ -225,7 +228,7 @@ impl ParseAt for VersionIndex {
 ///
 /// The .gnu.version_d section shall contain an array of VerDef structures
 /// optionally followed by an array of VerDefAux structures.
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct VerDef {
     /// Version information flag bitmask.
     pub vd_flags: u16,
@@ -321,11 +324,11 @@ impl<'data, E: EndianParse> Iterator for VerDefIterator<'data, E> {
         );
 
         // If offset overflows, silently end iteration
+        self.count -= 1;
         match self.offset.checked_add(vd.vd_next as usize) {
             Some(new_off) => self.offset = new_off,
             None => self.count = 0,
         }
-        self.count -= 1;
 
         // Silently end iteration early if the next link stops pointing somewhere new
         // TODO: Make this an error condition by allowing the iterator to yield a ParseError
@@ -337,7 +340,7 @@ impl<'data, E: EndianParse> Iterator for VerDefIterator<'data, E> {
 }
 
 /// Version Definition Auxiliary Entries from the .gnu.version_d section
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct VerDefAux {
     /// Offset to the version or dependency name string in the linked string table, in bytes.
     pub vda_name: u32,
@@ -364,7 +367,7 @@ impl ParseAt for VerDefAux {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct VerDefAuxIterator<'data, E: EndianParse> {
     endian: E,
     class: Class,
@@ -460,7 +463,7 @@ impl<'data, E: EndianParse> Iterator for VerDefAuxIterator<'data, E> {
 ///
 /// The section shall contain an array of VerNeed structures optionally
 /// followed by an array of VerNeedAux structures.
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct VerNeed {
     /// Number of associated verneed array entries.
     pub vn_cnt: u16,
@@ -549,11 +552,11 @@ impl<'data, E: EndianParse> Iterator for VerNeedIterator<'data, E> {
         );
 
         // If offset overflows, silently end iteration
+        self.count -= 1;
         match self.offset.checked_add(vn.vn_next as usize) {
             Some(new_off) => self.offset = new_off,
             None => self.count = 0,
         }
-        self.count -= 1;
 
         // Silently end iteration early if the next link stops pointing somewhere new
         // TODO: Make this an error condition by allowing the iterator to yield a ParseError
@@ -565,7 +568,7 @@ impl<'data, E: EndianParse> Iterator for VerNeedIterator<'data, E> {
 }
 
 /// Version Need Auxiliary Entries from the .gnu.version_r section
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct VerNeedAux {
     /// Dependency name hash value (ELF hash function).
     pub vna_hash: u32,
@@ -601,7 +604,7 @@ impl ParseAt for VerNeedAux {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct VerNeedAuxIterator<'data, E: EndianParse> {
     endian: E,
     class: Class,
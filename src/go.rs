@@ -0,0 +1,214 @@
+//! Parsing the build metadata that the Go toolchain embeds in binaries: the `.go.buildinfo`
+//! section, as found by [ElfBytes::go_build_info](crate::ElfBytes::go_build_info)
+//!
+//! The section starts with a 32-byte header: the 14-byte [GO_BUILDINFO_MAGIC], the pointer
+//! size, and a flags byte. Since Go 1.18, the flags have [GO_BUILDINFO_FLAG_INLINE] set, and
+//! the version and module info strings follow the header, each prefixed with its length as
+//! a varint. Older toolchains instead put two pointers after the flags, which point to the Go
+//! string headers (a data pointer and a length) of the version and module info, in the byte
+//! order given by [GO_BUILDINFO_FLAG_BIG_ENDIAN].
+//!
+//! The Go build ID is in a separate note, as found by
+//! [ElfBytes::go_build_id](crate::ElfBytes::go_build_id).
+use crate::endian::{AnyEndian, EndianParse};
+use crate::parse::{parse_uleb128, ParseError, ReadBytesExt};
+
+/// The magic bytes at the start of the `.go.buildinfo` section
+pub const GO_BUILDINFO_MAGIC: &[u8] = b"\xff Go buildinf:";
+
+/// The name of the section holding the Go build info
+pub const GO_BUILDINFO_SECTION_NAME: &str = ".go.buildinfo";
+
+/// The pointers in the header are big-endian
+pub const GO_BUILDINFO_FLAG_BIG_ENDIAN: u8 = 0x1;
+
+/// The version and module info strings follow the header, instead of being pointed to
+pub const GO_BUILDINFO_FLAG_INLINE: u8 = 0x2;
+
+/// The size of the `.go.buildinfo` header, which is also its alignment
+pub const GO_BUILDINFO_HEADER_SIZE: usize = 32;
+
+/// The build info from a Go binary's `.go.buildinfo` section
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GoBuildInfo<'data> {
+    /// The version of the Go toolchain that built the binary, e.g. "go1.21.5"
+    pub version: &'data str,
+    /// The module info, as printed by `go version -m`: the main package path, its module, its
+    /// dependencies, and the build settings, one per line. Empty if the binary was built
+    /// without module support.
+    pub modinfo: &'data [u8],
+}
+
+/// The `.go.buildinfo` header, which either holds the strings or points to them
+#[derive(Debug)]
+pub(crate) enum GoBuildInfoHeader<'data> {
+    Inline(GoBuildInfo<'data>),
+    Pointers {
+        endian: AnyEndian,
+        ptr_size: usize,
+        version: u64,
+        modinfo: u64,
+    },
+}
+
+impl<'data> GoBuildInfoHeader<'data> {
+    /// Parse the header at the start of `data`, and the inline strings that follow it
+    pub(crate) fn parse(data: &'data [u8]) -> Result<Self, ParseError> {
+        let header = data.get_bytes(0..GO_BUILDINFO_HEADER_SIZE)?;
+        let magic_len = GO_BUILDINFO_MAGIC.len();
+        if &header[..magic_len] != GO_BUILDINFO_MAGIC {
+            return Err(ParseError::BadMagic([
+                header[0], header[1], header[2], header[3],
+            ]));
+        }
+        let ptr_size = header[magic_len] as usize;
+        let flags = header[magic_len + 1];
+
+        if flags & GO_BUILDINFO_FLAG_INLINE != 0 {
+            let mut offset = GO_BUILDINFO_HEADER_SIZE;
+            let version = parse_varint_string(&mut offset, data)?;
+            let modinfo = parse_varint_string(&mut offset, data)?;
+            return Ok(GoBuildInfoHeader::Inline(GoBuildInfo {
+                version: core::str::from_utf8(version)?,
+                modinfo: strip_modinfo_sentinels(modinfo),
+            }));
+        }
+
+        let endian = match flags & GO_BUILDINFO_FLAG_BIG_ENDIAN {
+            0 => AnyEndian::Little,
+            _ => AnyEndian::Big,
+        };
+        let mut offset = magic_len + 2;
+        let version = parse_ptr(endian, ptr_size, &mut offset, header)?;
+        let modinfo = parse_ptr(endian, ptr_size, &mut offset, header)?;
+        Ok(GoBuildInfoHeader::Pointers {
+            endian,
+            ptr_size,
+            version,
+            modinfo,
+        })
+    }
+}
+
+/// Read a pointer-sized value, which must be 4 or 8 bytes
+pub(crate) fn parse_ptr(
+    endian: AnyEndian,
+    ptr_size: usize,
+    offset: &mut usize,
+    data: &[u8],
+) -> Result<u64, ParseError> {
+    match ptr_size {
+        4 => Ok(endian.parse_u32_at(offset, data)? as u64),
+        8 => endian.parse_u64_at(offset, data),
+        _ => Err(ParseError::UnsupportedPointerEncoding(ptr_size as u8)),
+    }
+}
+
+/// Read a string prefixed with its varint length
+fn parse_varint_string<'data>(
+    offset: &mut usize,
+    data: &'data [u8],
+) -> Result<&'data [u8], ParseError> {
+    let len: usize = parse_uleb128(offset, data)?.try_into()?;
+    let end = offset.checked_add(len).ok_or(ParseError::IntegerOverflow)?;
+    let string = data.get_bytes(*offset..end)?;
+    *offset = end;
+    Ok(string)
+}
+
+/// Remove the 16-byte sentinels that newer toolchains put around the module info, so that it
+/// can be found by scanning the binary.
+pub(crate) fn strip_modinfo_sentinels(modinfo: &[u8]) -> &[u8] {
+    let len = modinfo.len();
+    if len >= 33 && modinfo[len - 17] == b'\n' {
+        &modinfo[16..len - 16]
+    } else {
+        modinfo
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn header(ptr_size: u8, flags: u8) -> Vec<u8> {
+        let mut data = GO_BUILDINFO_MAGIC.to_vec();
+        data.extend_from_slice(&[ptr_size, flags]);
+        data.resize(GO_BUILDINFO_HEADER_SIZE, 0);
+        data
+    }
+
+    #[test]
+    fn parse_inline() {
+        let mut data = header(8, GO_BUILDINFO_FLAG_INLINE);
+        data.push(8);
+        data.extend_from_slice(b"go1.21.5");
+        let modinfo = b"0123456789abcdefpath\texample.com/hello\nfedcba9876543210";
+        data.push(modinfo.len() as u8);
+        data.extend_from_slice(modinfo);
+        match GoBuildInfoHeader::parse(&data).expect("should parse") {
+            GoBuildInfoHeader::Inline(info) => {
+                assert_eq!(info.version, "go1.21.5");
+                assert_eq!(info.modinfo, b"path\texample.com/hello\n");
+            }
+            other => panic!("Unexpected header: {other:?}"),
+        }
+
+        // A truncated module info string
+        let result = GoBuildInfoHeader::parse(&data[..data.len() - 1]);
+        assert!(
+            matches!(result, Err(ParseError::SliceReadError(_))),
+            "Unexpected Error type found: {result:?}"
+        );
+    }
+
+    #[test]
+    fn parse_pointers() {
+        let mut data = header(4, GO_BUILDINFO_FLAG_BIG_ENDIAN);
+        data[16..24].copy_from_slice(&[0, 0, 0x10, 0, 0, 0, 0x10, 0x08]);
+        match GoBuildInfoHeader::parse(&data).expect("should parse") {
+            GoBuildInfoHeader::Pointers {
+                endian,
+                ptr_size,
+                version,
+                modinfo,
+            } => {
+                assert_eq!(endian, AnyEndian::Big);
+                assert_eq!(ptr_size, 4);
+                assert_eq!(version, 0x1000);
+                assert_eq!(modinfo, 0x1008);
+            }
+            other => panic!("Unexpected header: {other:?}"),
+        }
+    }
+
+    #[test]
+    fn parse_bad_header() {
+        let mut data = header(8, 0);
+        data[1] = b'X';
+        let result = GoBuildInfoHeader::parse(&data);
+        assert!(
+            matches!(result, Err(ParseError::BadMagic([0xff, b'X', b'G', b'o']))),
+            "Unexpected Error type found: {result:?}"
+        );
+
+        let data = header(3, 0);
+        let result = GoBuildInfoHeader::parse(&data);
+        assert!(
+            matches!(result, Err(ParseError::UnsupportedPointerEncoding(3))),
+            "Unexpected Error type found: {result:?}"
+        );
+        let data = header(8, 0);
+        let result = GoBuildInfoHeader::parse(&data[..31]);
+        assert!(
+            matches!(result, Err(ParseError::SliceReadError((0, 32)))),
+            "Unexpected Error type found: {result:?}"
+        );
+    }
+
+    #[test]
+    fn modinfo_without_sentinels() {
+        assert_eq!(strip_modinfo_sentinels(b"path\tx\n"), b"path\tx\n");
+        assert_eq!(strip_modinfo_sentinels(b""), b"");
+    }
+}
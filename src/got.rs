@@ -0,0 +1,58 @@
+//! Interpreting the contents of the Global Offset Table, as found by [ElfBytes::got_slots](crate::ElfBytes::got_slots)
+//!
+//! The GOT holds the addresses of the symbols that an object references through the dynamic
+//! linker. Each slot starts out with its link-time value, and gets filled in when the dynamic
+//! linker applies the relocation which covers it: `R_*_GLOB_DAT` for data and `R_*_JUMP_SLOT`
+//! for the functions called through the PLT. The first 3 slots at `DT_PLTGOT` are reserved for
+//! the dynamic linker, and have no relocations.
+//!
+//! Example usage:
+//! ```
+//! use elf::endian::AnyEndian;
+//! use elf::got::GotTarget;
+//! use elf::ElfBytes;
+//!
+//! let path = std::path::PathBuf::from("sample-objects/basic.x86_64");
+//! let file_data = std::fs::read(path).expect("Could not read file.");
+//! let file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("Open test1");
+//!
+//! let slots = file
+//!     .got_slots()
+//!     .expect("relocations should parse")
+//!     .expect("should have a GOT");
+//! assert_eq!(slots[0].addr, 0x6008a8);
+//! assert!(matches!(slots[0].target, GotTarget::Symbol { name: Some("__gmon_start__"), .. }));
+//! assert_eq!(slots[1].target, GotTarget::Reserved);
+//! ```
+
+/// What fills in a GOT slot
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GotTarget<'data> {
+    /// One of the 3 slots at `DT_PLTGOT` which are reserved for the dynamic linker. The first
+    /// holds the address of the dynamic section, and the other two are filled in at runtime.
+    Reserved,
+    /// A relocation of type `r_type` fills in the address of the symbol at the given index in
+    /// the `.dynsym`. The name is an empty Option if the dynamic symbol table couldn't be found.
+    Symbol {
+        r_type: u32,
+        index: u32,
+        name: Option<&'data str>,
+    },
+    /// A relocation of type `r_type` without a symbol fills in the slot, like a relative or
+    /// `IRELATIVE` relocation for a local address.
+    Local { r_type: u32 },
+    /// No relocation covers the slot, so it keeps its initial value.
+    Unrelocated,
+}
+
+/// A class-sized slot in the `.got` or `.got.plt` section
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GotSlot<'data> {
+    /// The virtual address of the slot
+    pub addr: u64,
+    /// The value in the slot before any relocations are applied (or, for an image parsed from
+    /// memory, the value at the time it was read)
+    pub value: u64,
+    /// What fills in the slot
+    pub target: GotTarget<'data>,
+}
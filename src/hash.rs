@@ -1,12 +1,18 @@
 //! Parsing hash table sections for symbol tables: `.hash`, and `.gnu.hash`
 use core::mem::size_of;
 
+use crate::abi;
 use crate::endian::EndianParse;
 use crate::file::Class;
-use crate::parse::{ParseAt, ParseError, ParsingTable, ReadBytesExt};
+use crate::parse::{
+    ParseAt, ParseError, ReadBytesExt, SerializeAt, WordIterator, WordSize, WordTable,
+};
 use crate::string_table::StringTable;
 use crate::symbol::{Symbol, SymbolTable};
 
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::{vec, vec::Vec};
+
 impl ParseAt for u32 {
     fn parse_at<E: EndianParse>(
         endian: E,
@@ -23,7 +29,17 @@ impl ParseAt for u32 {
     }
 }
 
-type U32Table<'data, E> = ParsingTable<'data, E, u32>;
+impl SerializeAt for u32 {
+    fn serialize_at<E: EndianParse>(
+        &self,
+        endian: E,
+        _class: Class,
+        offset: &mut usize,
+        data: &mut [u8],
+    ) -> Result<(), ParseError> {
+        endian.write_u32_at(offset, data, *self)
+    }
+}
 
 /// Header at the start of SysV Hash Table sections of type [SHT_HASH](crate::abi::SHT_HASH).
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -51,6 +67,19 @@ impl ParseAt for SysVHashHeader {
     }
 }
 
+impl SerializeAt for SysVHashHeader {
+    fn serialize_at<E: EndianParse>(
+        &self,
+        endian: E,
+        _class: Class,
+        offset: &mut usize,
+        data: &mut [u8],
+    ) -> Result<(), ParseError> {
+        endian.write_u32_at(offset, data, self.nbucket)?;
+        endian.write_u32_at(offset, data, self.nchain)
+    }
+}
+
 /// Calculate the SysV hash value for a given symbol name.
 pub fn sysv_hash(name: &[u8]) -> u32 {
     let mut hash = 0u32;
@@ -61,42 +90,94 @@ pub fn sysv_hash(name: &[u8]) -> u32 {
     hash & 0xfffffff
 }
 
-#[derive(Debug)]
+/// Get the size of the bucket and chain words (and header fields) of a SysV hash table for
+/// the given class and machine.
+///
+/// They're 4 bytes on every machine but 64-bit Alpha and s390x, whose psABIs use 8-byte words
+/// and give their `.hash` sections an `sh_entsize` of 8. Notably MIPS64 `.hash` sections use
+/// 4-byte words like everyone else's; its `DT_MIPS_XHASH` table is a variant of `.gnu.hash`.
+pub fn sysv_hash_word_size(class: Class, e_machine: u16) -> WordSize {
+    match (class, e_machine) {
+        (Class::ELF64, abi::EM_ALPHA | abi::EM_S390) => WordSize::Class,
+        _ => WordSize::U32,
+    }
+}
+
+#[derive(Debug, Clone)]
 pub struct SysVHashTable<'data, E: EndianParse> {
-    buckets: U32Table<'data, E>,
-    chains: U32Table<'data, E>,
+    buckets: WordTable<'data, E>,
+    chains: WordTable<'data, E>,
 }
 
 /// This constructs a lazy-parsing type that keeps a reference to the provided data
 /// bytes from which it lazily parses and interprets its contents.
 impl<'data, E: EndianParse> SysVHashTable<'data, E> {
-    /// Construct a SysVHashTable from given bytes. Keeps a reference to the data for lazy parsing.
+    /// Construct a SysVHashTable with 4-byte words from given bytes. Keeps a reference to the
+    /// data for lazy parsing.
+    ///
+    /// This is the table layout on every machine but 64-bit Alpha and s390x, see
+    /// [SysVHashTable::new_for_machine].
     pub fn new(endian: E, class: Class, data: &'data [u8]) -> Result<Self, ParseError> {
+        Self::with_word_size(endian, class, WordSize::U32, data)
+    }
+
+    /// Construct a SysVHashTable from given bytes, with the word size that the given
+    /// `e_machine` uses, see [sysv_hash_word_size].
+    pub fn new_for_machine(
+        endian: E,
+        class: Class,
+        e_machine: u16,
+        data: &'data [u8],
+    ) -> Result<Self, ParseError> {
+        Self::with_word_size(endian, class, sysv_hash_word_size(class, e_machine), data)
+    }
+
+    fn with_word_size(
+        endian: E,
+        class: Class,
+        size: WordSize,
+        data: &'data [u8],
+    ) -> Result<Self, ParseError> {
         let mut offset = 0;
-        let hdr = SysVHashHeader::parse_at(endian, class, &mut offset, data)?;
+        let (word_size, nbucket, nchain) = match (size, class) {
+            (WordSize::Class, Class::ELF64) => (
+                size_of::<u64>(),
+                endian.parse_u64_at(&mut offset, data)?,
+                endian.parse_u64_at(&mut offset, data)?,
+            ),
+            _ => {
+                let hdr = SysVHashHeader::parse_at(endian, class, &mut offset, data)?;
+                (size_of::<u32>(), hdr.nbucket as u64, hdr.nchain as u64)
+            }
+        };
 
-        let buckets_size = size_of::<u32>()
-            .checked_mul(hdr.nbucket.try_into()?)
+        let buckets_size = word_size
+            .checked_mul(nbucket.try_into()?)
             .ok_or(ParseError::IntegerOverflow)?;
         let buckets_end = offset
             .checked_add(buckets_size)
             .ok_or(ParseError::IntegerOverflow)?;
         let buckets_buf = data.get_bytes(offset..buckets_end)?;
-        let buckets = U32Table::new(endian, class, buckets_buf);
+        let buckets = WordTable::new(endian, class, size, buckets_buf);
         offset = buckets_end;
 
-        let chains_size = size_of::<u32>()
-            .checked_mul(hdr.nchain.try_into()?)
+        let chains_size = word_size
+            .checked_mul(nchain.try_into()?)
             .ok_or(ParseError::IntegerOverflow)?;
         let chains_end = offset
             .checked_add(chains_size)
             .ok_or(ParseError::IntegerOverflow)?;
         let chains_buf = data.get_bytes(offset..chains_end)?;
-        let chains = U32Table::new(endian, class, chains_buf);
+        let chains = WordTable::new(endian, class, size, chains_buf);
 
         Ok(SysVHashTable { buckets, chains })
     }
 
+    /// Get the size of the table's words in bytes
+    pub fn word_size(&self) -> usize {
+        self.buckets.word_size()
+    }
+
     /// Use the hash table to find the symbol table entry with the given name and hash.
     pub fn find(
         &self,
@@ -127,6 +208,123 @@ impl<'data, E: EndianParse> SysVHashTable<'data, E> {
         }
         Ok(None)
     }
+
+    /// Get the number of hash buckets in the table.
+    pub fn nbucket(&self) -> usize {
+        self.buckets.len()
+    }
+
+    /// Get the number of chain entries in the table, which is the number of symbols in the
+    /// symbol table that it indexes.
+    pub fn nchain(&self) -> usize {
+        self.chains.len()
+    }
+
+    /// Get an iterator over the buckets, which yields the index of the first symbol in each
+    /// bucket's chain (or 0 for an empty bucket).
+    pub fn buckets(&self) -> WordIterator<'data, E> {
+        self.buckets.iter()
+    }
+
+    /// Get an iterator over the symbol indexes in the chain for the given bucket.
+    ///
+    /// The iterator yields a [ParseError::HashChainCycle] (and then stops) if the chain is
+    /// longer than the number of entries in the table, which means that it loops.
+    pub fn chain(&self, bucket: usize) -> Result<SysVHashChainIterator<'data, E>, ParseError> {
+        let index = self.buckets.get(bucket)? as usize;
+        Ok(SysVHashChainIterator {
+            chains: self.chains,
+            bucket,
+            index,
+            steps: 0,
+        })
+    }
+
+    /// Walk every bucket's chain and summarize how well the symbols are distributed, like
+    /// readelf's "histogram of bucket list lengths".
+    #[cfg(feature = "alloc")]
+    pub fn stats(&self) -> Result<HashStats, ParseError> {
+        let mut lengths = Vec::with_capacity(self.nbucket());
+        for bucket in 0..self.nbucket() {
+            let mut len = 0;
+            for index in self.chain(bucket)? {
+                index?;
+                len += 1;
+            }
+            lengths.push(len);
+        }
+        Ok(HashStats::from_chain_lengths(&lengths))
+    }
+}
+
+/// An iterator over the symbol indexes in a [SysVHashTable] bucket's chain, see
+/// [SysVHashTable::chain].
+#[derive(Debug, Clone)]
+pub struct SysVHashChainIterator<'data, E: EndianParse> {
+    chains: WordTable<'data, E>,
+    bucket: usize,
+    index: usize,
+    steps: usize,
+}
+
+impl<'data, E: EndianParse> Iterator for SysVHashChainIterator<'data, E> {
+    type Item = Result<usize, ParseError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index == 0 {
+            return None;
+        }
+
+        // A chain can't visit more symbols than there are, so if it does then it loops
+        if self.steps >= self.chains.len() {
+            self.index = 0;
+            return Some(Err(ParseError::HashChainCycle(self.bucket)));
+        }
+        self.steps += 1;
+
+        let index = self.index;
+        match self.chains.get(index) {
+            Ok(next) => {
+                self.index = next as usize;
+                Some(Ok(index))
+            }
+            Err(err) => {
+                self.index = 0;
+                Some(Err(err))
+            }
+        }
+    }
+}
+
+/// Statistics about the distribution of symbols across a hash table's buckets.
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HashStats {
+    /// The number of buckets in the table
+    pub nbucket: usize,
+    /// The total number of symbols across all of the buckets' chains
+    pub nsymbols: usize,
+    /// The length of the longest chain
+    pub max_chain_len: usize,
+    /// `histogram[len]` is the number of buckets whose chain has `len` symbols
+    pub histogram: Vec<usize>,
+}
+
+#[cfg(feature = "alloc")]
+impl HashStats {
+    fn from_chain_lengths(lengths: &[usize]) -> Self {
+        let max_chain_len = lengths.iter().copied().max().unwrap_or(0);
+        let mut histogram = vec![0; max_chain_len + 1];
+        for len in lengths {
+            histogram[*len] += 1;
+        }
+        HashStats {
+            nbucket: lengths.len(),
+            nsymbols: lengths.iter().sum(),
+            max_chain_len,
+            histogram,
+        }
+    }
 }
 
 /// Calculate the GNU hash for a given symbol name.
@@ -172,7 +370,20 @@ impl ParseAt for GnuHashHeader {
     }
 }
 
-type U64Table<'data, E> = ParsingTable<'data, E, u64>;
+impl SerializeAt for GnuHashHeader {
+    fn serialize_at<E: EndianParse>(
+        &self,
+        endian: E,
+        _class: Class,
+        offset: &mut usize,
+        data: &mut [u8],
+    ) -> Result<(), ParseError> {
+        endian.write_u32_at(offset, data, self.nbucket)?;
+        endian.write_u32_at(offset, data, self.table_start_idx)?;
+        endian.write_u32_at(offset, data, self.nbloom)?;
+        endian.write_u32_at(offset, data, self.nshift)
+    }
+}
 
 impl ParseAt for u64 {
     fn parse_at<E: EndianParse>(
@@ -190,15 +401,25 @@ impl ParseAt for u64 {
     }
 }
 
-#[derive(Debug)]
+impl SerializeAt for u64 {
+    fn serialize_at<E: EndianParse>(
+        &self,
+        endian: E,
+        _class: Class,
+        offset: &mut usize,
+        data: &mut [u8],
+    ) -> Result<(), ParseError> {
+        endian.write_u64_at(offset, data, *self)
+    }
+}
+
+#[derive(Debug, Clone)]
 pub struct GnuHashTable<'data, E: EndianParse> {
     pub hdr: GnuHashHeader,
 
-    endian: E,
-    class: Class,
-    bloom: &'data [u8],
-    buckets: U32Table<'data, E>,
-    chains: U32Table<'data, E>,
+    bloom: WordTable<'data, E>,
+    buckets: WordTable<'data, E>,
+    chains: WordTable<'data, E>,
 }
 
 impl<'data, E: EndianParse> GnuHashTable<'data, E> {
@@ -230,20 +451,18 @@ impl<'data, E: EndianParse> GnuHashTable<'data, E> {
             .checked_add(buckets_size)
             .ok_or(ParseError::IntegerOverflow)?;
         let buckets_buf = data.get_bytes(offset..buckets_end)?;
-        let buckets = U32Table::new(endian, class, buckets_buf);
+        let buckets = WordTable::new(endian, class, WordSize::U32, buckets_buf);
         offset = buckets_end;
 
         // the rest of the section is the chains
         let chains_buf = data
             .get(offset..)
             .ok_or(ParseError::SliceReadError((offset, data.len())))?;
-        let chains = U32Table::new(endian, class, chains_buf);
+        let chains = WordTable::new(endian, class, WordSize::U32, chains_buf);
 
         Ok(GnuHashTable {
             hdr,
-            endian,
-            class,
-            bloom: bloom_buf,
+            bloom: WordTable::new(endian, class, WordSize::Class, bloom_buf),
             buckets,
             chains,
         })
@@ -264,21 +483,10 @@ impl<'data, E: EndianParse> GnuHashTable<'data, E> {
 
         let hash = gnu_hash(name);
 
-        // Test against bloom filter.
-        let (bloom_width, filter) = match self.class {
-            Class::ELF32 => {
-                let bloom_width: u32 = 8 * size_of::<u32>() as u32; // 32
-                let bloom_idx = (hash / (bloom_width)) % self.hdr.nbloom;
-                let bloom_table = U32Table::new(self.endian, self.class, self.bloom);
-                (bloom_width, bloom_table.get(bloom_idx as usize)? as u64)
-            }
-            Class::ELF64 => {
-                let bloom_width: u32 = 8 * size_of::<u64>() as u32; // 64
-                let bloom_idx = (hash / (bloom_width)) % self.hdr.nbloom;
-                let bloom_table = U64Table::new(self.endian, self.class, self.bloom);
-                (bloom_width, bloom_table.get(bloom_idx as usize)?)
-            }
-        };
+        // Test against bloom filter. Its words are 32 bits for ELF32 and 64 bits for ELF64.
+        let bloom_width = 8 * self.bloom.word_size() as u32;
+        let bloom_idx = (hash / bloom_width) % self.hdr.nbloom;
+        let filter = self.bloom.get(bloom_idx as usize)?;
 
         // Check bloom filter for both hashes - symbol is present in the hash table IFF both bits are set.
         if filter & (1 << (hash % bloom_width)) == 0 {
@@ -300,7 +508,7 @@ impl<'data, E: EndianParse> GnuHashTable<'data, E> {
 
         let chain_len = self.chains.len();
         for chain_idx in (chain_start_idx - table_start_idx)..chain_len {
-            let chain_hash = self.chains.get(chain_idx)?;
+            let chain_hash = self.chains.get(chain_idx)? as u32;
 
             // compare the hashes by or'ing the 1's bit back on
             if hash | 1 == chain_hash | 1 {
@@ -325,6 +533,288 @@ impl<'data, E: EndianParse> GnuHashTable<'data, E> {
 
         Ok(None)
     }
+
+    /// Get the number of symbols in the symbol table that this hash table indexes, which the
+    /// table doesn't record directly. It's found by following the chain of the last nonempty
+    /// bucket to its end, which is how the dynamic linker sizes `DT_SYMTAB`.
+    ///
+    /// Returns an empty Option if none of the symbols are hashed, since then the table only
+    /// says how many symbols come before the hashed ones.
+    pub fn symbol_count(&self) -> Result<Option<usize>, ParseError> {
+        let table_start_idx = self.hdr.table_start_idx as usize;
+        let mut last_start = 0;
+        for start in self.buckets.iter() {
+            last_start = last_start.max(start as usize);
+        }
+        if last_start < table_start_idx {
+            return Ok(None);
+        }
+
+        for chain_idx in (last_start - table_start_idx)..self.chains.len() {
+            if self.chains.get(chain_idx)? & 1 != 0 {
+                return chain_idx
+                    .checked_add(table_start_idx + 1)
+                    .ok_or(ParseError::IntegerOverflow)
+                    .map(Some);
+            }
+        }
+        Err(ParseError::BadOffset(self.chains.len() as u64))
+    }
+
+    /// Get an iterator which walks each bucket's chain in turn, yielding the bucket, symbol
+    /// table index and hash value (whose low bit marks the end of the chain) of each hashed
+    /// symbol. Buckets whose chains start before `symoffset` are treated as empty, like
+    /// [GnuHashTable::find] does.
+    ///
+    /// The iterator yields a [ParseError::BadOffset] with the symbol index (and then stops) if
+    /// a chain runs off the end of the table without being terminated.
+    pub fn iter_chains(&self) -> GnuHashChainIterator<'data, E> {
+        GnuHashChainIterator {
+            buckets: self.buckets,
+            chains: self.chains,
+            table_start_idx: self.hdr.table_start_idx as usize,
+            bucket: 0,
+            chain_idx: None,
+            done: false,
+        }
+    }
+
+    /// Get an iterator over the symbol table indexes of all the hashed symbols, which are the
+    /// ones that can be looked up by name. For a `.dynsym`, these are the exported symbols.
+    ///
+    /// This only reads the hash table, not the symbol table. See [GnuHashTable::iter_chains].
+    pub fn symbol_indexes(&self) -> impl Iterator<Item = Result<usize, ParseError>> + '_ {
+        self.iter_chains()
+            .map(|result| result.map(|(_, index, _)| index))
+    }
+}
+
+/// An iterator over the chains of a [GnuHashTable], see [GnuHashTable::iter_chains].
+#[derive(Debug, Clone)]
+pub struct GnuHashChainIterator<'data, E: EndianParse> {
+    buckets: WordTable<'data, E>,
+    chains: WordTable<'data, E>,
+    table_start_idx: usize,
+    bucket: usize,
+    /// The position in `chains` of the next entry in the current bucket's chain
+    chain_idx: Option<usize>,
+    done: bool,
+}
+
+impl<'data, E: EndianParse> Iterator for GnuHashChainIterator<'data, E> {
+    /// The bucket, symbol table index and hash value
+    type Item = Result<(usize, usize, u32), ParseError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while !self.done {
+            if let Some(chain_idx) = self.chain_idx {
+                let sym_idx = chain_idx + self.table_start_idx;
+                let hash = match self.chains.get(chain_idx) {
+                    Ok(hash) => hash as u32,
+                    Err(_) => {
+                        self.done = true;
+                        return Some(Err(ParseError::BadOffset(sym_idx as u64)));
+                    }
+                };
+                let bucket = self.bucket;
+                if hash & 1 != 0 {
+                    self.chain_idx = None;
+                    self.bucket += 1;
+                } else {
+                    self.chain_idx = Some(chain_idx + 1);
+                }
+                return Some(Ok((bucket, sym_idx, hash)));
+            }
+
+            if self.bucket >= self.buckets.len() {
+                return None;
+            }
+            let start = match self.buckets.get(self.bucket) {
+                Ok(start) => start as usize,
+                Err(err) => {
+                    self.done = true;
+                    return Some(Err(err));
+                }
+            };
+            if start < self.table_start_idx {
+                self.bucket += 1;
+            } else {
+                self.chain_idx = Some(start - self.table_start_idx);
+            }
+        }
+        None
+    }
+}
+
+/// Bucket counts to pick from when building a hash table, following what GNU ld does. Using
+/// primes helps spread the hashes across the buckets.
+#[cfg(feature = "alloc")]
+const BUCKET_COUNTS: &[usize] = &[
+    1, 3, 17, 37, 67, 97, 131, 197, 263, 521, 1031, 2053, 4099, 8209, 16411, 32771, 65537, 131101,
+    262147,
+];
+
+/// Pick the number of buckets to use for a hash table of `nsyms` symbols.
+#[cfg(feature = "alloc")]
+fn choose_nbucket(nsyms: usize) -> usize {
+    BUCKET_COUNTS
+        .iter()
+        .copied()
+        .take_while(|count| *count <= nsyms)
+        .last()
+        .unwrap_or(1)
+}
+
+/// Build the contents of a SysV hash section of type [SHT_HASH](crate::abi::SHT_HASH) for a
+/// symbol table whose symbol at index `i` is named `names[i]`.
+///
+/// The null symbol at index 0 and any symbols with empty names are left out of the buckets,
+/// but still count towards the table's `nchain`, which must equal the symbol table's length.
+///
+/// The table has 4-byte words, see [build_sysv_hash_for_machine] for machines that use 8-byte
+/// ones.
+#[cfg(feature = "alloc")]
+pub fn build_sysv_hash<E: EndianParse>(
+    endian: E,
+    class: Class,
+    names: &[&[u8]],
+) -> Result<Vec<u8>, ParseError> {
+    build_sysv_hash_words(endian, class, WordSize::U32, names)
+}
+
+/// Like [build_sysv_hash], but with the word size that the given `e_machine` uses, see
+/// [sysv_hash_word_size].
+#[cfg(feature = "alloc")]
+pub fn build_sysv_hash_for_machine<E: EndianParse>(
+    endian: E,
+    class: Class,
+    e_machine: u16,
+    names: &[&[u8]],
+) -> Result<Vec<u8>, ParseError> {
+    build_sysv_hash_words(endian, class, sysv_hash_word_size(class, e_machine), names)
+}
+
+#[cfg(feature = "alloc")]
+fn build_sysv_hash_words<E: EndianParse>(
+    endian: E,
+    class: Class,
+    size: WordSize,
+    names: &[&[u8]],
+) -> Result<Vec<u8>, ParseError> {
+    let nbucket = choose_nbucket(names.len());
+    let mut buckets = vec![0u32; nbucket];
+    let mut chains = vec![0u32; names.len()];
+
+    // Prepend each symbol to its bucket's chain
+    for (index, name) in names.iter().enumerate().skip(1) {
+        if name.is_empty() {
+            continue;
+        }
+        let bucket = sysv_hash(name) as usize % nbucket;
+        chains[index] = buckets[bucket];
+        buckets[bucket] = index.try_into()?;
+    }
+
+    let hdr = [nbucket.try_into()?, names.len().try_into()?];
+    let wide = matches!((size, class), (WordSize::Class, Class::ELF64));
+    let word_size = if wide {
+        size_of::<u64>()
+    } else {
+        size_of::<u32>()
+    };
+    let mut data = vec![0u8; word_size * (2 + nbucket + names.len())];
+    let mut offset = 0;
+    for word in hdr.iter().chain(buckets.iter()).chain(chains.iter()) {
+        if wide {
+            endian.write_u64_at(&mut offset, &mut data, *word as u64)?;
+        } else {
+            word.serialize_at(endian, class, &mut offset, &mut data)?;
+        }
+    }
+    Ok(data)
+}
+
+/// The contents of a GNU hash section built by [build_gnu_hash].
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GnuHashSection {
+    /// The section data
+    pub data: Vec<u8>,
+    /// The order in which the hashed symbols must be placed in the symbol table, starting
+    /// at `symoffset`: `order[i]` is the index into the given names of the symbol that goes at
+    /// symbol table index `symoffset + i`.
+    pub order: Vec<usize>,
+}
+
+/// Build the contents of a GNU hash section of type [SHT_GNU_HASH](crate::abi::SHT_GNU_HASH)
+/// for the given symbol `names`, which get placed at the end of the symbol table starting at
+/// index `symoffset`. Symbols before `symoffset` (such as the null symbol and undefined
+/// symbols) can't be looked up through the table.
+///
+/// GNU hash tables require the hashed symbols to be grouped by bucket, so the returned
+/// [GnuHashSection::order] says which order to emit them in.
+#[cfg(feature = "alloc")]
+pub fn build_gnu_hash<E: EndianParse>(
+    endian: E,
+    class: Class,
+    symoffset: usize,
+    names: &[&[u8]],
+) -> Result<GnuHashSection, ParseError> {
+    let nbucket = choose_nbucket(names.len());
+    let hashes: Vec<u32> = names.iter().map(|name| gnu_hash(name)).collect();
+
+    let mut order: Vec<usize> = (0..names.len()).collect();
+    order.sort_by_key(|i| hashes[*i] as usize % nbucket);
+
+    // Size the bloom filter for roughly 12 bits per symbol
+    let (word_bits, nshift) = match class {
+        Class::ELF32 => (32, 5),
+        Class::ELF64 => (64, 6),
+    };
+    let nbloom = (names.len() * 12 / word_bits).max(1).next_power_of_two();
+    let mut bloom = vec![0u64; nbloom];
+    for hash in hashes.iter() {
+        let word = (*hash as usize / word_bits) % nbloom;
+        bloom[word] |= 1 << (*hash as usize % word_bits);
+        bloom[word] |= 1 << ((*hash >> nshift) as usize % word_bits);
+    }
+
+    let mut buckets = vec![0u32; nbucket];
+    let mut chains = vec![0u32; names.len()];
+    for (pos, i) in order.iter().enumerate() {
+        let bucket = hashes[*i] as usize % nbucket;
+        if buckets[bucket] == 0 {
+            buckets[bucket] = (symoffset + pos).try_into()?;
+        }
+        // The low bit marks the last symbol in each bucket's chain
+        let is_last = order
+            .get(pos + 1)
+            .map_or(true, |next| hashes[*next] as usize % nbucket != bucket);
+        chains[pos] = (hashes[*i] & !1) | is_last as u32;
+    }
+
+    let hdr = GnuHashHeader {
+        nbucket: nbucket.try_into()?,
+        table_start_idx: symoffset.try_into()?,
+        nbloom: nbloom.try_into()?,
+        nshift,
+    };
+    let size = GnuHashHeader::size_for(class)
+        + nbloom * word_bits / 8
+        + size_of::<u32>() * (nbucket + names.len());
+    let mut data = vec![0u8; size];
+    let mut offset = 0;
+    hdr.serialize_at(endian, class, &mut offset, &mut data)?;
+    for word in bloom.iter() {
+        match class {
+            Class::ELF32 => (*word as u32).serialize_at(endian, class, &mut offset, &mut data)?,
+            Class::ELF64 => word.serialize_at(endian, class, &mut offset, &mut data)?,
+        }
+    }
+    for word in buckets.iter().chain(chains.iter()) {
+        word.serialize_at(endian, class, &mut offset, &mut data)?;
+    }
+    Ok(GnuHashSection { data, order })
 }
 
 #[cfg(test)]
@@ -402,6 +892,88 @@ mod sysv_parse_tests {
     }
 }
 
+#[cfg(test)]
+mod sysv_chain_tests {
+    use super::*;
+    use crate::endian::{AnyEndian, LittleEndian};
+    use crate::ElfBytes;
+
+    fn table_bytes(words: &[u32]) -> Vec<u8> {
+        words.iter().flat_map(|word| word.to_le_bytes()).collect()
+    }
+
+    #[test]
+    fn chains_and_stats() {
+        let path = std::path::PathBuf::from("sample-objects/symver.x86_64.so");
+        let file_data = std::fs::read(path).expect("Could not read file.");
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("Open test1");
+        let common = file.find_common_data().expect("shdrs should parse");
+        let hash_table = common.sysv_hash.unwrap();
+        let dynsyms = common.dynsyms.unwrap();
+
+        assert_eq!(hash_table.nchain(), dynsyms.len());
+        assert_eq!(hash_table.buckets().count(), hash_table.nbucket());
+
+        // Every symbol but the null symbol is in exactly one chain
+        let mut seen = vec![false; dynsyms.len()];
+        for bucket in 0..hash_table.nbucket() {
+            for index in hash_table.chain(bucket).expect("bucket should exist") {
+                let index = index.expect("chain should parse");
+                assert!(!seen[index]);
+                seen[index] = true;
+            }
+        }
+        assert_eq!(seen.iter().filter(|seen| **seen).count(), dynsyms.len() - 1);
+
+        let stats = hash_table.stats().expect("should parse");
+        assert_eq!(stats.nbucket, hash_table.nbucket());
+        assert_eq!(stats.nsymbols, dynsyms.len() - 1);
+        assert_eq!(stats.histogram.len(), stats.max_chain_len + 1);
+        assert_eq!(stats.histogram.iter().sum::<usize>(), stats.nbucket);
+    }
+
+    #[test]
+    fn stats_of_handmade_table() {
+        // nbucket=3, nchain=5: bucket 0 -> 1 -> 3, bucket 1 empty, bucket 2 -> 2 -> 4
+        let data = table_bytes(&[3, 5, 1, 0, 2, 0, 3, 4, 0, 0]);
+        let table = SysVHashTable::new(LittleEndian, Class::ELF64, &data).expect("should parse");
+
+        let chain: Vec<usize> = table.chain(0).unwrap().map(|i| i.unwrap()).collect();
+        assert_eq!(chain, [1, 3]);
+        assert_eq!(table.chain(1).unwrap().count(), 0);
+        let chain: Vec<usize> = table.chain(2).unwrap().map(|i| i.unwrap()).collect();
+        assert_eq!(chain, [2, 4]);
+        assert!(matches!(table.chain(3), Err(ParseError::BadOffset(_))));
+
+        assert_eq!(
+            table.stats().expect("should parse"),
+            HashStats {
+                nbucket: 3,
+                nsymbols: 4,
+                max_chain_len: 2,
+                histogram: vec![1, 0, 2],
+            }
+        );
+    }
+
+    #[test]
+    fn chain_cycle_is_reported() {
+        // nbucket=1, nchain=3: bucket 0 -> 1 -> 2 -> 1 -> ...
+        let data = table_bytes(&[1, 3, 1, 0, 2, 1]);
+        let table = SysVHashTable::new(LittleEndian, Class::ELF32, &data).expect("should parse");
+
+        let results: Vec<_> = table.chain(0).unwrap().collect();
+        assert_eq!(results.len(), 4);
+        assert!(matches!(results[3], Err(ParseError::HashChainCycle(0))));
+
+        let result = table.stats();
+        assert!(
+            matches!(result, Err(ParseError::HashChainCycle(0))),
+            "Unexpected Error type found: {result:?}"
+        );
+    }
+}
+
 #[cfg(test)]
 mod gnu_parse_tests {
     use super::*;
@@ -493,3 +1065,404 @@ mod gnu_parse_tests {
         test_parse_fuzz_too_short::<_, GnuHashHeader>(BigEndian, Class::ELF64);
     }
 }
+
+#[cfg(test)]
+mod build_tests {
+    use super::*;
+    use crate::endian::{AnyEndian, BigEndian, LittleEndian};
+    use crate::symbol::Symbol;
+    use crate::ElfBytes;
+
+    /// Build a symbol table and string table with the given names, in order
+    fn symbols<E: EndianParse>(endian: E, class: Class, names: &[&[u8]]) -> (Vec<u8>, Vec<u8>) {
+        let mut strtab = vec![0u8];
+        let mut symtab = vec![0u8; names.len() * Symbol::size_for(class)];
+        let mut offset = 0;
+        for name in names {
+            let sym = Symbol {
+                st_name: strtab.len() as u32,
+                ..Default::default()
+            };
+            strtab.extend_from_slice(name);
+            strtab.push(0);
+            sym.serialize_at(endian, class, &mut offset, &mut symtab)
+                .expect("should write");
+        }
+        (symtab, strtab)
+    }
+
+    fn names(count: usize) -> Vec<Vec<u8>> {
+        (0..count)
+            .map(|i| format!("symbol_{i}").into_bytes())
+            .collect()
+    }
+
+    fn sysv_roundtrip<E: EndianParse>(endian: E, class: Class, count: usize) {
+        let names = names(count);
+        let mut all: Vec<&[u8]> = vec![b""];
+        all.extend(names.iter().map(|name| name.as_slice()));
+
+        let data = build_sysv_hash(endian, class, &all).expect("should build");
+        let table = SysVHashTable::new(endian, class, &data).expect("should parse");
+        assert_eq!(table.nchain(), all.len());
+
+        let (symtab_data, strtab_data) = symbols(endian, class, &all);
+        let symtab = SymbolTable::new(endian, class, &symtab_data);
+        let strtab = StringTable::new(&strtab_data);
+        for (index, name) in all.iter().enumerate().skip(1) {
+            let (found, _) = table
+                .find(name, &symtab, &strtab)
+                .expect("should parse")
+                .expect("should find symbol");
+            assert_eq!(found, index);
+        }
+        assert!(table
+            .find(b"missing", &symtab, &strtab)
+            .expect("should parse")
+            .is_none());
+    }
+
+    fn gnu_roundtrip<E: EndianParse>(endian: E, class: Class, count: usize) {
+        let names = names(count);
+        let hashed: Vec<&[u8]> = names.iter().map(|name| name.as_slice()).collect();
+        let symoffset = 2;
+        let section = build_gnu_hash(endian, class, symoffset, &hashed).expect("should build");
+        let table = GnuHashTable::new(endian, class, &section.data).expect("should parse");
+
+        // Lay out the symbol table as the builder asked
+        let mut all: Vec<&[u8]> = vec![b"", b"undefined"];
+        all.extend(section.order.iter().map(|i| hashed[*i]));
+        let (symtab_data, strtab_data) = symbols(endian, class, &all);
+        let symtab = SymbolTable::new(endian, class, &symtab_data);
+        let strtab = StringTable::new(&strtab_data);
+        let expected = if count == 0 { None } else { Some(all.len()) };
+        assert_eq!(table.symbol_count().expect("should parse"), expected);
+
+        for (index, name) in all.iter().enumerate().skip(symoffset) {
+            let (found, _) = table
+                .find(name, &symtab, &strtab)
+                .expect("should parse")
+                .expect("should find symbol");
+            assert_eq!(found, index);
+        }
+        assert!(table
+            .find(b"undefined", &symtab, &strtab)
+            .expect("should parse")
+            .is_none());
+
+        // Walking the chains visits every hashed symbol once, in symbol table order
+        let nbucket = table.hdr.nbucket as usize;
+        let mut next_index = symoffset;
+        for result in table.iter_chains() {
+            let (bucket, index, hash) = result.expect("chain should parse");
+            assert_eq!(index, next_index);
+            next_index += 1;
+            assert_eq!(hash | 1, gnu_hash(all[index]) | 1);
+            assert_eq!(bucket, gnu_hash(all[index]) as usize % nbucket);
+        }
+        assert_eq!(next_index, all.len());
+        assert!(table
+            .find(b"missing", &symtab, &strtab)
+            .expect("should parse")
+            .is_none());
+    }
+
+    #[test]
+    fn sysv_hash_roundtrip() {
+        for count in [0, 1, 5, 100, 2000] {
+            sysv_roundtrip(LittleEndian, Class::ELF64, count);
+            sysv_roundtrip(BigEndian, Class::ELF32, count);
+        }
+    }
+
+    #[test]
+    fn gnu_hash_roundtrip() {
+        for count in [0, 1, 5, 100, 2000] {
+            gnu_roundtrip(LittleEndian, Class::ELF64, count);
+            gnu_roundtrip(BigEndian, Class::ELF32, count);
+        }
+    }
+
+    #[test]
+    fn gnu_chain_runs_off_end() {
+        let names: Vec<&[u8]> = vec![b"a", b"b", b"c"];
+        let mut section = build_gnu_hash(LittleEndian, Class::ELF64, 1, &names).unwrap();
+        // Clear the end-of-chain bits so the last chain never terminates
+        let len = section.data.len();
+        for word in section.data[len - 12..].chunks_mut(4) {
+            word[0] &= !1;
+        }
+        let table = GnuHashTable::new(LittleEndian, Class::ELF64, &section.data).unwrap();
+        let results: Vec<_> = table.iter_chains().collect();
+        assert!(matches!(
+            results.last(),
+            Some(Err(ParseError::BadOffset(4)))
+        ));
+        assert!(results[..results.len() - 1].iter().all(|r| r.is_ok()));
+    }
+
+    #[test]
+    fn sample_gnu_hash_exports() {
+        let path = std::path::PathBuf::from("sample-objects/symver.x86_64.so");
+        let file_data = std::fs::read(path).expect("Could not read file.");
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("Open test1");
+        let shdr = file
+            .section_header_by_name(".gnu.hash")
+            .expect("should parse")
+            .expect("should have .gnu.hash");
+        let (data, _) = file.section_data(&shdr).expect("should read");
+        let table =
+            GnuHashTable::new(file.ehdr.endianness, file.ehdr.class, data).expect("should parse");
+        let (dynsyms, strtab) = file.dynamic_symbol_table().expect("should parse").unwrap();
+
+        let indexes: Vec<usize> = table
+            .symbol_indexes()
+            .map(|index| index.expect("should parse"))
+            .collect();
+        let symoffset = table.hdr.table_start_idx as usize;
+        assert_eq!(indexes, (symoffset..dynsyms.len()).collect::<Vec<_>>());
+
+        // Every hashed symbol can be found by name
+        for index in indexes {
+            let sym = dynsyms.get(index).expect("should parse");
+            let name = strtab.get_raw(sym.st_name as usize).expect("should parse");
+            let (found, _) = table
+                .find(name, &dynsyms, &strtab)
+                .expect("should parse")
+                .expect("should find symbol");
+            assert_eq!(found, index);
+        }
+    }
+
+    #[test]
+    fn rebuild_sample_dynsym_hash() {
+        let path = std::path::PathBuf::from("sample-objects/symver.x86_64.so");
+        let file_data = std::fs::read(path).expect("Could not read file.");
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("Open test1");
+        let (dynsyms, strtab) = file.dynamic_symbol_table().expect("should parse").unwrap();
+        let names: Vec<&[u8]> = dynsyms
+            .iter()
+            .map(|sym| strtab.get_raw(sym.st_name as usize).expect("should parse"))
+            .collect();
+
+        let data =
+            build_sysv_hash(file.ehdr.endianness, file.ehdr.class, &names).expect("should build");
+        let table =
+            SysVHashTable::new(file.ehdr.endianness, file.ehdr.class, &data).expect("should parse");
+        for (index, name) in names.iter().enumerate().skip(1) {
+            let (found, _) = table
+                .find(name, &dynsyms, &strtab)
+                .expect("should parse")
+                .expect("should find symbol");
+            assert_eq!(found, index);
+        }
+    }
+}
+
+#[cfg(test)]
+mod machine_tests {
+    use super::*;
+    use crate::builder::ElfBuilder;
+    use crate::endian::{AnyEndian, BigEndian, LittleEndian};
+    use crate::section::SectionHeader;
+    use crate::ElfBytes;
+
+    /// Build a shared object whose `.dynsym` is indexed by a `.hash` section laid out for the
+    /// given machine
+    fn build_object<E: EndianParse>(endian: E, e_machine: u16, names: &[&[u8]]) -> Vec<u8> {
+        let class = Class::ELF64;
+        let mut dynstr = vec![0u8];
+        let mut dynsym = vec![0u8; names.len() * Symbol::size_for(class)];
+        let mut offset = 0;
+        for name in names {
+            let sym = Symbol {
+                st_name: if name.is_empty() {
+                    0
+                } else {
+                    dynstr.len() as u32
+                },
+                ..Default::default()
+            };
+            if !name.is_empty() {
+                dynstr.extend_from_slice(name);
+                dynstr.push(0);
+            }
+            sym.serialize_at(endian, class, &mut offset, &mut dynsym)
+                .expect("should write");
+        }
+        let hash =
+            build_sysv_hash_for_machine(endian, class, e_machine, names).expect("should build");
+
+        let mut builder = ElfBuilder::new(endian, class, abi::ET_DYN, e_machine);
+        let strtab_shdr = SectionHeader {
+            sh_type: abi::SHT_STRTAB,
+            ..Default::default()
+        };
+        let dynstr_index = builder.add_section(".dynstr", strtab_shdr, &dynstr);
+        let dynsym_shdr = SectionHeader {
+            sh_type: abi::SHT_DYNSYM,
+            sh_link: dynstr_index as u32,
+            sh_entsize: Symbol::size_for(class) as u64,
+            ..Default::default()
+        };
+        let dynsym_index = builder.add_section(".dynsym", dynsym_shdr, &dynsym);
+        let hash_shdr = SectionHeader {
+            sh_type: abi::SHT_HASH,
+            sh_link: dynsym_index as u32,
+            ..Default::default()
+        };
+        builder.add_section(".hash", hash_shdr, &hash);
+        builder.build().expect("should build")
+    }
+
+    fn check_lookups(data: &[u8], names: &[&[u8]], word_size: usize) {
+        let file = ElfBytes::<AnyEndian>::minimal_parse(data).expect("should parse");
+        let common = file.find_common_data().expect("should parse");
+        let table = common.sysv_hash.expect("should have .hash section");
+        assert_eq!(table.word_size(), word_size);
+        assert_eq!(table.nchain(), names.len());
+
+        let (dynsyms, dynstrs) = (common.dynsyms.unwrap(), common.dynsyms_strs.unwrap());
+        for (index, name) in names.iter().enumerate().skip(1) {
+            let (found, _) = table
+                .find(name, &dynsyms, &dynstrs)
+                .expect("should parse")
+                .expect("should find symbol");
+            assert_eq!(found, index);
+            let (sh_type, found, _) = file
+                .symbol_by_name(name)
+                .expect("should parse")
+                .expect("should find symbol");
+            assert_eq!((sh_type, found), (abi::SHT_DYNSYM, index));
+        }
+        assert!(table
+            .find(b"missing", &dynsyms, &dynstrs)
+            .expect("should parse")
+            .is_none());
+    }
+
+    const NAMES: &[&[u8]] = &[b"", b"memset", b"use_memset_v2", b"__gmon_start__", b"main"];
+
+    #[test]
+    fn word_sizes() {
+        use abi::{EM_ALPHA, EM_MIPS, EM_S390, EM_X86_64};
+        assert_eq!(sysv_hash_word_size(Class::ELF64, EM_X86_64), WordSize::U32);
+        assert_eq!(sysv_hash_word_size(Class::ELF64, EM_MIPS), WordSize::U32);
+        assert_eq!(sysv_hash_word_size(Class::ELF64, EM_ALPHA), WordSize::Class);
+        assert_eq!(sysv_hash_word_size(Class::ELF64, EM_S390), WordSize::Class);
+        // 31-bit s390 uses 4-byte words
+        assert_eq!(sysv_hash_word_size(Class::ELF32, EM_S390), WordSize::U32);
+    }
+
+    #[test]
+    fn x86_64_hash() {
+        let data = build_object(LittleEndian, abi::EM_X86_64, NAMES);
+        check_lookups(&data, NAMES, 4);
+    }
+
+    #[test]
+    fn mips64_hash() {
+        let data = build_object(BigEndian, abi::EM_MIPS, NAMES);
+        check_lookups(&data, NAMES, 4);
+    }
+
+    #[test]
+    fn s390x_hash() {
+        let data = build_object(BigEndian, abi::EM_S390, NAMES);
+        check_lookups(&data, NAMES, 8);
+
+        // Reading its 8-byte words as 4-byte ones finds nothing: nbucket is the high half of
+        // the first word, which is zero
+        let hash = build_sysv_hash_for_machine(BigEndian, Class::ELF64, abi::EM_S390, NAMES)
+            .expect("should build");
+        let table = SysVHashTable::new(BigEndian, Class::ELF64, &hash).expect("should parse");
+        assert_eq!(table.nbucket(), 0);
+    }
+}
+
+#[cfg(test)]
+mod serialize_tests {
+    use super::*;
+    use crate::endian::{BigEndian, LittleEndian};
+    use crate::parse::test_serialize_roundtrip;
+
+    #[test]
+    fn serialize_sysvhdr32_lsb_roundtrip() {
+        test_serialize_roundtrip::<_, SysVHashHeader>(LittleEndian, Class::ELF32);
+    }
+
+    #[test]
+    fn serialize_gnuhdr32_lsb_roundtrip() {
+        test_serialize_roundtrip::<_, GnuHashHeader>(LittleEndian, Class::ELF32);
+    }
+
+    #[test]
+    fn serialize_u32_32_lsb_roundtrip() {
+        test_serialize_roundtrip::<_, u32>(LittleEndian, Class::ELF32);
+    }
+
+    #[test]
+    fn serialize_u64_32_lsb_roundtrip() {
+        test_serialize_roundtrip::<_, u64>(LittleEndian, Class::ELF32);
+    }
+
+    #[test]
+    fn serialize_sysvhdr32_msb_roundtrip() {
+        test_serialize_roundtrip::<_, SysVHashHeader>(BigEndian, Class::ELF32);
+    }
+
+    #[test]
+    fn serialize_gnuhdr32_msb_roundtrip() {
+        test_serialize_roundtrip::<_, GnuHashHeader>(BigEndian, Class::ELF32);
+    }
+
+    #[test]
+    fn serialize_u32_32_msb_roundtrip() {
+        test_serialize_roundtrip::<_, u32>(BigEndian, Class::ELF32);
+    }
+
+    #[test]
+    fn serialize_u64_32_msb_roundtrip() {
+        test_serialize_roundtrip::<_, u64>(BigEndian, Class::ELF32);
+    }
+
+    #[test]
+    fn serialize_sysvhdr64_lsb_roundtrip() {
+        test_serialize_roundtrip::<_, SysVHashHeader>(LittleEndian, Class::ELF64);
+    }
+
+    #[test]
+    fn serialize_gnuhdr64_lsb_roundtrip() {
+        test_serialize_roundtrip::<_, GnuHashHeader>(LittleEndian, Class::ELF64);
+    }
+
+    #[test]
+    fn serialize_u32_64_lsb_roundtrip() {
+        test_serialize_roundtrip::<_, u32>(LittleEndian, Class::ELF64);
+    }
+
+    #[test]
+    fn serialize_u64_64_lsb_roundtrip() {
+        test_serialize_roundtrip::<_, u64>(LittleEndian, Class::ELF64);
+    }
+
+    #[test]
+    fn serialize_sysvhdr64_msb_roundtrip() {
+        test_serialize_roundtrip::<_, SysVHashHeader>(BigEndian, Class::ELF64);
+    }
+
+    #[test]
+    fn serialize_gnuhdr64_msb_roundtrip() {
+        test_serialize_roundtrip::<_, GnuHashHeader>(BigEndian, Class::ELF64);
+    }
+
+    #[test]
+    fn serialize_u32_64_msb_roundtrip() {
+        test_serialize_roundtrip::<_, u32>(BigEndian, Class::ELF64);
+    }
+
+    #[test]
+    fn serialize_u64_64_msb_roundtrip() {
+        test_serialize_roundtrip::<_, u64>(BigEndian, Class::ELF64);
+    }
+}
@@ -0,0 +1,104 @@
+//! Parsing the tables of constructor and destructor functions: [SHT_PREINIT_ARRAY](crate::abi::SHT_PREINIT_ARRAY),
+//! [SHT_INIT_ARRAY](crate::abi::SHT_INIT_ARRAY) and [SHT_FINI_ARRAY](crate::abi::SHT_FINI_ARRAY)
+//!
+//! These tables hold function addresses, 4 or 8 bytes each depending on the ELF class. In
+//! position-independent objects, the dynamic linker fills them in with relative relocations.
+//! GNU ld also writes the link-time addresses into the tables, but other linkers may leave them
+//! as 0. Entries of 0 or -1 are padding or terminators, which [AddrTable::functions] skips.
+//!
+//! Example usage:
+//! ```
+//! use elf::endian::AnyEndian;
+//! use elf::ElfBytes;
+//!
+//! let path = std::path::PathBuf::from("sample-objects/basic.x86_64");
+//! let file_data = std::fs::read(path).expect("Could not read file.");
+//! let file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("Open test1");
+//!
+//! let init = file.init_functions().expect("tables should parse");
+//! assert_eq!(init.init, Some(0x400390));
+//! let init_array = init.init_array.expect("should have .init_array");
+//! assert_eq!(init_array.functions().collect::<Vec<_>>(), vec![0x4004c0]);
+//! ```
+use crate::endian::EndianParse;
+use crate::parse::{WordIterator, WordTable};
+
+/// A table of class-sized addresses, like the contents of an `.init_array` section. These are
+/// [WordTable]s of [WordSize::Class](crate::parse::WordSize::Class) words.
+pub type AddrTable<'data, E> = WordTable<'data, E>;
+
+/// Iterates over the entries of an [AddrTable]
+pub type AddrIterator<'data, E> = WordIterator<'data, E>;
+
+impl<'data, E: EndianParse> WordTable<'data, E> {
+    /// Get an iterator over the function addresses in the table, in table order, skipping
+    /// the entries of 0 or -1 which are padding or terminators.
+    pub fn functions(&self) -> impl Iterator<Item = u64> + 'data
+    where
+        E: 'data,
+    {
+        let all_ones = u64::MAX >> (64 - 8 * self.word_size());
+        self.iter()
+            .filter(move |addr| *addr != 0 && *addr != all_ones)
+    }
+}
+
+/// The functions which are run when an object is loaded and unloaded, as found by
+/// [ElfBytes::init_functions](crate::ElfBytes::init_functions).
+///
+/// When loading, the `preinit_array` functions (only in executables) run first, then the
+/// `init` function, then the `init_array` functions. When unloading, the `fini_array`
+/// functions run in reverse table order, then the `fini` function.
+#[derive(Debug, Clone)]
+pub struct InitFunctions<'data, E: EndianParse> {
+    /// The `.preinit_array` section or `DT_PREINIT_ARRAY` table
+    pub preinit_array: Option<AddrTable<'data, E>>,
+    /// The `DT_INIT` function
+    pub init: Option<u64>,
+    /// The `.init_array` section or `DT_INIT_ARRAY` table
+    pub init_array: Option<AddrTable<'data, E>>,
+    /// The `.fini_array` section or `DT_FINI_ARRAY` table
+    pub fini_array: Option<AddrTable<'data, E>>,
+    /// The `DT_FINI` function
+    pub fini: Option<u64>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::endian::{BigEndian, LittleEndian};
+    use crate::file::Class;
+    use crate::parse::{ParseError, WordSize};
+
+    #[test]
+    fn addr_table_32() {
+        let data = [
+            0x00, 0x00, 0x10, 0x00, 0xff, 0xff, 0xff, 0xff, 0x00, 0x00, 0x20, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x12,
+        ];
+        let table = AddrTable::new(BigEndian, Class::ELF32, WordSize::Class, &data);
+        assert_eq!(table.len(), 4);
+        assert_eq!(table.get(1).unwrap(), 0xffffffff);
+        assert!(matches!(table.get(4), Err(ParseError::BadOffset(4))));
+        assert_eq!(
+            table.iter().collect::<Vec<_>>(),
+            vec![0x1000, 0xffffffff, 0x2000, 0]
+        );
+        assert_eq!(table.functions().collect::<Vec<_>>(), vec![0x1000, 0x2000]);
+    }
+
+    #[test]
+    fn addr_table_64() {
+        let mut data = Vec::new();
+        for addr in [u64::MAX, 0x401000, 0xffffffff, 0] {
+            data.extend_from_slice(&addr.to_le_bytes());
+        }
+        let table = AddrTable::new(LittleEndian, Class::ELF64, WordSize::Class, &data);
+        assert_eq!(table.len(), 4);
+        assert_eq!(
+            table.functions().collect::<Vec<_>>(),
+            vec![0x401000, 0xffffffff]
+        );
+        assert!(AddrTable::new(LittleEndian, Class::ELF64, WordSize::Class, &data[..7]).is_empty());
+    }
+}
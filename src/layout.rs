@@ -0,0 +1,191 @@
+//! Mapping out which parts of an ELF file are referenced by its headers
+//!
+//! The file header, program and section header tables, and each section's and segment's
+//! contents all claim a range of bytes in the file. Bytes that nothing claims are either gaps
+//! between the claimed ranges (usually alignment padding) or an overlay after the last claimed
+//! byte, which is where packers, installers, and AppImages append their payloads.
+//!
+//! Example usage:
+//! ```
+//! use elf::endian::AnyEndian;
+//! use elf::layout::LayoutOwner;
+//! use elf::ElfBytes;
+//!
+//! let path = std::path::PathBuf::from("sample-objects/basic.x86_64");
+//! let mut file_data = std::fs::read(path).expect("Could not read file.");
+//! let file_len = file_data.len();
+//! file_data.extend_from_slice(b"hidden payload");
+//!
+//! let file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("Open test1");
+//! let layout = file.layout().expect("layout should be computed");
+//! assert_eq!(layout.ranges[0].owner, LayoutOwner::FileHeader);
+//! assert_eq!(layout.overlay(), Some(file_len..file_len + 14));
+//! ```
+use core::ops::Range;
+
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::vec::Vec;
+
+/// What claims a range of bytes in the file
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LayoutOwner {
+    /// The ELF file header
+    FileHeader,
+    /// The program header table
+    ProgramHeaders,
+    /// The section header table
+    SectionHeaders,
+    /// The contents of the section with the given section header index
+    Section(usize),
+    /// The contents of the segment with the given program header index
+    Segment(usize),
+}
+
+/// A range of file bytes and what claims them
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LayoutRange {
+    /// The claimed file bytes
+    pub range: Range<usize>,
+    /// What claims them
+    pub owner: LayoutOwner,
+}
+
+/// The ranges of an ELF file's bytes which are referenced by its headers.
+///
+/// Each claim is recorded separately, so ranges can overlap. Segments are expected to overlap
+/// the sections and header tables that they load, but sections and header tables overlapping
+/// each other usually means the file is corrupt or has been crafted to hide data; see
+/// [FileLayout::overlaps].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileLayout {
+    /// The claimed ranges, sorted by their start and then their end. Empty ranges, such as those
+    /// of `SHT_NOBITS` sections, aren't included.
+    pub ranges: Vec<LayoutRange>,
+    /// The length of the file
+    pub file_len: usize,
+}
+
+impl FileLayout {
+    /// Build a layout from the claims in any order.
+    pub fn new(mut ranges: Vec<LayoutRange>, file_len: usize) -> Self {
+        ranges.retain(|claim| !claim.range.is_empty());
+        // This is a stable sort, so claims of the same range stay in the order given
+        ranges.sort_by_key(|claim| (claim.range.start, claim.range.end));
+        FileLayout { ranges, file_len }
+    }
+
+    /// Get the end of the last claimed byte
+    fn claimed_end(&self) -> usize {
+        self.ranges
+            .iter()
+            .map(|claim| claim.range.end)
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Get the range of bytes after the last claimed byte, up to the end of the file.
+    /// Returns an empty Option if there are no such bytes.
+    pub fn overlay(&self) -> Option<Range<usize>> {
+        let end = self.claimed_end();
+        if end < self.file_len {
+            Some(end..self.file_len)
+        } else {
+            None
+        }
+    }
+
+    /// Get the ranges of unclaimed bytes between the claimed ranges, in file order. Bytes after
+    /// the last claimed byte are the [FileLayout::overlay], not a gap.
+    pub fn gaps(&self) -> Vec<Range<usize>> {
+        let mut gaps = Vec::new();
+        let mut covered = 0;
+        for claim in self.ranges.iter() {
+            if claim.range.start > covered {
+                gaps.push(covered..claim.range.start);
+            }
+            covered = covered.max(claim.range.end);
+        }
+        gaps
+    }
+
+    /// Get the pairs of overlapping claims, ignoring segments (which are expected to overlap
+    /// what they load). Each pair is in file order.
+    pub fn overlaps(&self) -> Vec<(&LayoutRange, &LayoutRange)> {
+        let claims: Vec<_> = self
+            .ranges
+            .iter()
+            .filter(|claim| !matches!(claim.owner, LayoutOwner::Segment(_)))
+            .collect();
+        let mut overlaps = Vec::new();
+        for (i, first) in claims.iter().enumerate() {
+            for second in claims[i + 1..].iter() {
+                // The claims are sorted by start, so no later claim can overlap this one
+                if second.range.start >= first.range.end {
+                    break;
+                }
+                overlaps.push((*first, *second));
+            }
+        }
+        overlaps
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn claim(range: Range<usize>, owner: LayoutOwner) -> LayoutRange {
+        LayoutRange { range, owner }
+    }
+
+    #[test]
+    fn gaps_overlay_and_overlaps() {
+        let layout = FileLayout::new(
+            vec![
+                claim(0x100..0x180, LayoutOwner::SectionHeaders),
+                claim(0..0x40, LayoutOwner::FileHeader),
+                claim(0x40..0x78, LayoutOwner::ProgramHeaders),
+                claim(0..0x100, LayoutOwner::Segment(0)),
+                claim(0x80..0x90, LayoutOwner::Section(1)),
+                claim(0x88..0xa0, LayoutOwner::Section(2)),
+                claim(0xa0..0xa0, LayoutOwner::Section(3)),
+            ],
+            0x200,
+        );
+        assert_eq!(
+            layout.ranges,
+            vec![
+                claim(0..0x40, LayoutOwner::FileHeader),
+                claim(0..0x100, LayoutOwner::Segment(0)),
+                claim(0x40..0x78, LayoutOwner::ProgramHeaders),
+                claim(0x80..0x90, LayoutOwner::Section(1)),
+                claim(0x88..0xa0, LayoutOwner::Section(2)),
+                claim(0x100..0x180, LayoutOwner::SectionHeaders),
+            ]
+        );
+        // The segment covers the padding between the sections
+        assert_eq!(layout.gaps(), vec![]);
+        assert_eq!(layout.overlay(), Some(0x180..0x200));
+        assert_eq!(
+            layout.overlaps(),
+            vec![(&layout.ranges[3], &layout.ranges[4])]
+        );
+    }
+
+    #[test]
+    fn gaps_without_segments() {
+        let layout = FileLayout::new(
+            vec![
+                claim(0..0x40, LayoutOwner::FileHeader),
+                claim(0x48..0x50, LayoutOwner::Section(1)),
+                claim(0x60..0x80, LayoutOwner::Section(2)),
+                claim(0x60..0x70, LayoutOwner::Section(3)),
+                claim(0x80..0xc0, LayoutOwner::SectionHeaders),
+            ],
+            0xc0,
+        );
+        assert_eq!(layout.gaps(), vec![0x40..0x48, 0x50..0x60]);
+        assert_eq!(layout.overlay(), None);
+        assert_eq!(layout.overlaps().len(), 1);
+    }
+}
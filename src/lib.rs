@@ -8,6 +8,17 @@
 //! The no_std variant merely disables the additional stream-oriented `std:: Read + Seek` interface.
 //! All core parsing functionality is the same!
 //!
+//! The features come in three tiers:
+//! * With `--no-default-features`, the crate is `no_std` and never allocates. All of the
+//!   lazy-parsing tables, iterators and [ElfBytes] lookups are available, and errors
+//!   implement `Display` and carry an [ErrorContext](parse::ErrorContext) describing which
+//!   structure failed to parse.
+//! * The `alloc` feature adds the interfaces which need a heap but not an OS: builders and
+//!   serializers for new objects, owned and sorted lookup indexes, and the cached section
+//!   header lookups.
+//! * The `std` feature (on by default) adds the [ElfStream] interface for `Read + Seek`
+//!   sources, `std::error::Error` impls, and filesystem helpers. The `arbitrary`, `mmap`, `xz` and `zlib` features build on this tier.
+//!
 //! ### ✨ Endian-aware ✨
 //! This crate handles translating between file and host endianness when
 //! parsing the ELF contents and provides four endian parsing implementations
@@ -24,12 +35,17 @@
 //! with an unexpected byte ordering.
 //!
 //! ### ✨ Zero-alloc parser ✨
-//! This crate implements parsing in a way that avoids heap allocations. ELF structures
+//! The core parsing interfaces avoid heap allocations. ELF structures
 //! are parsed and stored on the stack and provided by patterns such as lazily parsed iterators
 //! that yield stack allocated rust types, or lazily parsing tables that only parse out a particular
 //! entry on table.get(index). The structures are copy-converted as needed from the underlying file
 //! data into Rust's native struct representation.
 //!
+//! With `--no-default-features`, nothing allocates at all. The `alloc` tier (on by default)
+//! trades a little of that for faster lookups: [ElfBytes] parses its section headers into a
+//! cache up front and indexes their names, and the owned indexes and builders allocate as they
+//! go. The `std` tier's [ElfStream] reads the tables it parses into buffers.
+//!
 //! ### ✨ Fuzz Tested ✨
 //! Various parts of the library are fuzz tested for panics and crashes (see `fuzz/`).
 //!
@@ -44,6 +60,9 @@
 //! library developer as having truly been "right" in why some unsafe block was
 //! safe. 💃
 //!
+//! The one exception is the optional, off-by-default `mmap` feature, since mapping a file into
+//! memory can't be done safely.
+//!
 //! Note: I'd love to see this crate be enhanced further once rust provides safe transmutes.
 //!
 //! See: <https://github.com/rust-lang/project-safe-transmute>
@@ -64,6 +83,12 @@
 //! how you wish. Do you know that you want to do multiple passes over pairs from different tables? Just
 //! zip/collect them into another type so you only parse/endian-flip each entry once!
 //!
+//! ### ✨ Shareable across threads ✨
+//! The parsed views are plain wrappers around the borrowed bytes, and the one cache (of
+//! [ElfBytes]'s section headers) is built when the file is parsed and then only read, so [ElfBytes] and the
+//! tables it hands out are `Send + Sync` and can be shared with worker threads by reference. The lazy-parsing tables and iterators are also
+//! cheap to `Clone`, so each worker can own its own handle.
+//!
 //! ### ✨ Stream-based lazy i/o interface ✨
 //! The [ElfStream] parser type takes a `std:: Read + Seek` (such as `std::fs::File`) where ranges of
 //! file contents are read lazily on-demand based on what the user wants to parse.
@@ -129,28 +154,90 @@
 #![cfg_attr(all(feature = "nightly", not(feature = "std")), feature(error_in_core))]
 #![warn(rust_2018_idioms)]
 #![deny(missing_debug_implementations)]
-#![forbid(unsafe_code)]
+#![cfg_attr(not(feature = "mmap"), forbid(unsafe_code))]
+// Mapping a file is inherently unsafe, so the optional mmap support gets a single
+// audited exception for it, see OwnedElfBytes::open_mmap.
+#![cfg_attr(feature = "mmap", deny(unsafe_code))]
 
 #[cfg(all(feature = "alloc", not(feature = "std")))]
 extern crate alloc;
 
 pub mod abi;
 
+#[cfg(feature = "archive")]
+pub mod archive;
+
+pub mod arm_exidx;
+pub mod attributes;
+
+#[cfg(feature = "alloc")]
+pub mod builder;
+
 pub mod compression;
+pub mod debuglink;
+pub mod detect;
+
+#[cfg(feature = "alloc")]
+pub mod diff;
+
+#[cfg(feature = "xz")]
+pub mod debugdata;
+
 pub mod dynamic;
+pub mod eh_frame_hdr;
 pub mod file;
+
+#[cfg(feature = "arbitrary")]
+pub mod fuzz;
+
 pub mod gnu_symver;
+pub mod go;
+
+#[cfg(feature = "alloc")]
+pub mod got;
+
 pub mod hash;
+pub mod init_array;
+
+#[cfg(feature = "alloc")]
+pub mod layout;
+
+pub mod liblist;
+pub mod mips;
+pub mod modinfo;
 pub mod note;
+
+#[cfg(feature = "alloc")]
+pub mod patch;
+
+#[cfg(feature = "alloc")]
+pub mod plt;
+
+pub mod relocate;
 pub mod relocation;
 pub mod section;
 pub mod segment;
+pub mod sframe;
+pub mod stack_sizes;
+
+#[cfg(feature = "alloc")]
+pub mod strip;
+
+#[cfg(feature = "alloc")]
+pub mod swap;
+
 pub mod string_table;
 pub mod symbol;
 
 #[cfg(feature = "to_str")]
 pub mod to_str;
 
+#[cfg(feature = "alloc")]
+pub mod validate;
+
+#[cfg(feature = "demangle")]
+pub mod demangle;
+
 pub mod endian;
 pub mod parse;
 
@@ -163,4 +250,9 @@ mod elf_stream;
 #[cfg(feature = "std")]
 pub use elf_stream::ElfStream;
 
+#[cfg(feature = "std")]
+mod elf_owned;
+#[cfg(feature = "std")]
+pub use elf_owned::OwnedElfBytes;
+
 pub use parse::ParseError;
@@ -0,0 +1,173 @@
+//! Parsing the library list of prelinked objects: `.gnu.liblist`, [SHT_GNU_LIBLIST](crate::abi::SHT_GNU_LIBLIST)
+//!
+//! The prelink tool records the shared libraries that it resolved an object against, along
+//! with their time stamps and checksums, so that the dynamic linker can check that they
+//! haven't changed before using the prelinked addresses. The library names are offsets into
+//! the string table linked by the section's `sh_link` (usually `.dynstr`).
+use crate::endian::EndianParse;
+use crate::file::Class;
+use crate::parse::{ParseAt, ParseError, ParsingIterator};
+use crate::string_table::StringTable;
+
+/// C-style 32-bit ELF library list entry definition
+///
+/// These C-style definitions are for users who want to implement their own ELF manipulation logic.
+#[derive(Debug)]
+#[repr(C)]
+pub struct Elf32_Lib {
+    pub l_name: u32,
+    pub l_time_stamp: u32,
+    pub l_checksum: u32,
+    pub l_version: u32,
+    pub l_flags: u32,
+}
+
+/// C-style 64-bit ELF library list entry definition, which has the same layout as [Elf32_Lib]
+///
+/// These C-style definitions are for users who want to implement their own ELF manipulation logic.
+#[derive(Debug)]
+#[repr(C)]
+pub struct Elf64_Lib {
+    pub l_name: u32,
+    pub l_time_stamp: u32,
+    pub l_checksum: u32,
+    pub l_version: u32,
+    pub l_flags: u32,
+}
+
+/// An entry in the `.gnu.liblist` section
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LibListEntry {
+    /// Offset to the library's name in the linked string table, in bytes
+    pub l_name: u32,
+    /// The library's time stamp when the object was prelinked
+    pub l_time_stamp: u32,
+    /// The library's checksum when the object was prelinked
+    pub l_checksum: u32,
+    /// The library's interface version
+    pub l_version: u32,
+    /// Bitmask of `LL_*` flags, such as [LL_EXACT_MATCH](crate::abi::LL_EXACT_MATCH)
+    pub l_flags: u32,
+}
+
+impl ParseAt for LibListEntry {
+    fn parse_at<E: EndianParse>(
+        endian: E,
+        _class: Class,
+        offset: &mut usize,
+        data: &[u8],
+    ) -> Result<Self, ParseError> {
+        Ok(LibListEntry {
+            l_name: endian.parse_u32_at(offset, data)?,
+            l_time_stamp: endian.parse_u32_at(offset, data)?,
+            l_checksum: endian.parse_u32_at(offset, data)?,
+            l_version: endian.parse_u32_at(offset, data)?,
+            l_flags: endian.parse_u32_at(offset, data)?,
+        })
+    }
+
+    #[inline]
+    fn size_for(_class: Class) -> usize {
+        20
+    }
+}
+
+/// Iterates over the entries of a `.gnu.liblist` section along with their library names.
+///
+/// Yields an error for an entry whose name can't be found in the string table.
+#[derive(Debug, Clone)]
+pub struct LibListIterator<'data, E: EndianParse> {
+    entries: ParsingIterator<'data, E, LibListEntry>,
+    strtab: StringTable<'data>,
+}
+
+impl<'data, E: EndianParse> LibListIterator<'data, E> {
+    pub fn new(endian: E, class: Class, data: &'data [u8], strtab: StringTable<'data>) -> Self {
+        LibListIterator {
+            entries: ParsingIterator::new(endian, class, data),
+            strtab,
+        }
+    }
+}
+
+impl<'data, E: EndianParse> Iterator for LibListIterator<'data, E> {
+    type Item = Result<(&'data str, LibListEntry), ParseError>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let entry = self.entries.next()?;
+        Some(
+            self.strtab
+                .get(entry.l_name as usize)
+                .map(|name| (name, entry)),
+        )
+    }
+}
+
+#[cfg(test)]
+mod parse_tests {
+    use super::*;
+    use crate::abi;
+    use crate::endian::{BigEndian, LittleEndian};
+    use crate::parse::{test_parse_for, test_parse_fuzz_too_short};
+
+    #[test]
+    fn parse_liblist_entry_lsb() {
+        test_parse_for(
+            LittleEndian,
+            Class::ELF64,
+            LibListEntry {
+                l_name: 0x03020100,
+                l_time_stamp: 0x07060504,
+                l_checksum: 0x0B0A0908,
+                l_version: 0x0F0E0D0C,
+                l_flags: 0x13121110,
+            },
+        );
+    }
+
+    #[test]
+    fn parse_liblist_entry_msb() {
+        test_parse_for(
+            BigEndian,
+            Class::ELF32,
+            LibListEntry {
+                l_name: 0x00010203,
+                l_time_stamp: 0x04050607,
+                l_checksum: 0x08090A0B,
+                l_version: 0x0C0D0E0F,
+                l_flags: 0x10111213,
+            },
+        );
+    }
+
+    #[test]
+    fn parse_liblist_entry_fuzz_too_short() {
+        test_parse_fuzz_too_short::<_, LibListEntry>(LittleEndian, Class::ELF32);
+    }
+
+    #[test]
+    fn iterate_with_names() {
+        let strtab = StringTable::new(b"\0libc.so.6\0libm.so.6\0");
+        let mut data = Vec::new();
+        for (name, flags) in [(1u32, abi::LL_EXACT_MATCH), (11, abi::LL_NONE), (30, 0)] {
+            for field in [name, 0x5a5a5a5a, 0x12345678, 0, flags] {
+                data.extend_from_slice(&field.to_le_bytes());
+            }
+        }
+        let mut iter = LibListIterator::new(LittleEndian, Class::ELF64, &data, strtab);
+        let (name, entry) = iter
+            .next()
+            .expect("should have entry")
+            .expect("should parse");
+        assert_eq!(name, "libc.so.6");
+        assert_eq!(entry.l_checksum, 0x12345678);
+        assert_eq!(entry.l_flags, abi::LL_EXACT_MATCH);
+        let (name, _) = iter
+            .next()
+            .expect("should have entry")
+            .expect("should parse");
+        assert_eq!(name, "libm.so.6");
+        // The last name is past the end of the string table
+        assert!(iter.next().expect("should have entry").is_err());
+        assert!(iter.next().is_none());
+    }
+}
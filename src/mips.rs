@@ -0,0 +1,305 @@
+//! Parsing the MIPS `.MIPS.abiflags` (`SHT_MIPS_ABIFLAGS`) and `.reginfo` (`SHT_MIPS_REGINFO`)
+//! sections
+//!
+//! These record the ISA, floating point ABI, and register usage that an object was built for,
+//! which linkers check to refuse mixing incompatible objects.
+//!
+//! Example usage:
+//! ```
+//! use elf::abi;
+//! use elf::endian::AnyEndian;
+//! use elf::mips::mips_fp_abi_compatible;
+//! use elf::ElfBytes;
+//!
+//! let path = std::path::PathBuf::from("sample-objects/abiflags.mips.o");
+//! let file_data = std::fs::read(path).expect("Could not read file.");
+//! let file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("Open test1");
+//!
+//! let abiflags = file
+//!     .mips_abiflags()
+//!     .expect("section should parse")
+//!     .expect("file should have a .MIPS.abiflags section");
+//! assert_eq!(abiflags.fp_abi, abi::MIPS_ABI_FP_XX);
+//! assert!(mips_fp_abi_compatible(abiflags.fp_abi, abi::MIPS_ABI_FP_DOUBLE));
+//! assert!(!mips_fp_abi_compatible(abiflags.fp_abi, abi::MIPS_ABI_FP_SOFT));
+//! ```
+use crate::abi;
+use crate::endian::EndianParse;
+use crate::file::Class;
+use crate::parse::{ParseAt, ParseError};
+
+/// C-style MIPS ABI flags definition
+///
+/// These C-style definitions are for users who want to implement their own ELF manipulation logic.
+#[derive(Debug)]
+#[repr(C)]
+pub struct Elf_MIPS_ABIFlags_v0 {
+    pub version: u16,
+    pub isa_level: u8,
+    pub isa_rev: u8,
+    pub gpr_size: u8,
+    pub cpr1_size: u8,
+    pub cpr2_size: u8,
+    pub fp_abi: u8,
+    pub isa_ext: u32,
+    pub ases: u32,
+    pub flags1: u32,
+    pub flags2: u32,
+}
+
+/// The contents of a `.MIPS.abiflags` section, which has the same layout for both ELF classes.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MipsAbiFlags {
+    /// The version of the structure, which is 0
+    pub version: u16,
+    /// The ISA level, e.g. 32 for MIPS32
+    pub isa_level: u8,
+    /// The ISA revision, e.g. 2 for MIPS32r2
+    pub isa_rev: u8,
+    /// The size of the general purpose registers, see the `AFL_REG_*` values
+    pub gpr_size: u8,
+    /// The size of the coprocessor 1 (FPU) registers, see the `AFL_REG_*` values
+    pub cpr1_size: u8,
+    /// The size of the coprocessor 2 registers, see the `AFL_REG_*` values
+    pub cpr2_size: u8,
+    /// The floating point ABI, see the `MIPS_ABI_FP_*` values
+    pub fp_abi: u8,
+    /// The processor-specific ISA extension, see the `AFL_EXT_*` values
+    pub isa_ext: u32,
+    /// A mask of the ASEs used, see the `AFL_ASE_*` bits
+    pub ases: u32,
+    /// See the `AFL_FLAGS1_*` bits
+    pub flags1: u32,
+    /// Reserved for future use, and 0
+    pub flags2: u32,
+}
+
+impl ParseAt for MipsAbiFlags {
+    fn parse_at<E: EndianParse>(
+        endian: E,
+        _class: Class,
+        offset: &mut usize,
+        data: &[u8],
+    ) -> Result<Self, ParseError> {
+        Ok(MipsAbiFlags {
+            version: endian.parse_u16_at(offset, data)?,
+            isa_level: endian.parse_u8_at(offset, data)?,
+            isa_rev: endian.parse_u8_at(offset, data)?,
+            gpr_size: endian.parse_u8_at(offset, data)?,
+            cpr1_size: endian.parse_u8_at(offset, data)?,
+            cpr2_size: endian.parse_u8_at(offset, data)?,
+            fp_abi: endian.parse_u8_at(offset, data)?,
+            isa_ext: endian.parse_u32_at(offset, data)?,
+            ases: endian.parse_u32_at(offset, data)?,
+            flags1: endian.parse_u32_at(offset, data)?,
+            flags2: endian.parse_u32_at(offset, data)?,
+        })
+    }
+
+    #[inline]
+    fn size_for(_class: Class) -> usize {
+        24
+    }
+}
+
+/// C-style 32-bit MIPS register info definition
+///
+/// These C-style definitions are for users who want to implement their own ELF manipulation logic.
+#[derive(Debug)]
+#[repr(C)]
+pub struct Elf32_RegInfo {
+    pub ri_gprmask: u32,
+    pub ri_cprmask: [u32; 4],
+    pub ri_gp_value: i32,
+}
+
+/// C-style 64-bit MIPS register info definition
+///
+/// These C-style definitions are for users who want to implement their own ELF manipulation logic.
+#[derive(Debug)]
+#[repr(C)]
+pub struct Elf64_RegInfo {
+    pub ri_gprmask: u32,
+    pub ri_pad: u32,
+    pub ri_cprmask: [u32; 4],
+    pub ri_gp_value: i64,
+}
+
+/// The contents of a `.reginfo` section
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MipsRegInfo {
+    /// A mask of the general purpose registers used
+    pub ri_gprmask: u32,
+    /// A mask of the registers used in each of the four coprocessors
+    pub ri_cprmask: [u32; 4],
+    /// The initial value of the gp register
+    pub ri_gp_value: i64,
+}
+
+impl ParseAt for MipsRegInfo {
+    fn parse_at<E: EndianParse>(
+        endian: E,
+        class: Class,
+        offset: &mut usize,
+        data: &[u8],
+    ) -> Result<Self, ParseError> {
+        let ri_gprmask = endian.parse_u32_at(offset, data)?;
+        if class == Class::ELF64 {
+            let _ri_pad = endian.parse_u32_at(offset, data)?;
+        }
+        let ri_cprmask = [
+            endian.parse_u32_at(offset, data)?,
+            endian.parse_u32_at(offset, data)?,
+            endian.parse_u32_at(offset, data)?,
+            endian.parse_u32_at(offset, data)?,
+        ];
+        let ri_gp_value = match class {
+            Class::ELF32 => endian.parse_i32_at(offset, data)? as i64,
+            Class::ELF64 => endian.parse_i64_at(offset, data)?,
+        };
+        Ok(MipsRegInfo {
+            ri_gprmask,
+            ri_cprmask,
+            ri_gp_value,
+        })
+    }
+
+    #[inline]
+    fn size_for(class: Class) -> usize {
+        match class {
+            Class::ELF32 => 24,
+            Class::ELF64 => 32,
+        }
+    }
+}
+
+/// Check whether objects using the two given `MIPS_ABI_FP_*` floating point ABIs can be linked
+/// together, following the rules that GNU ld applies.
+///
+/// Objects that don't use floating point ([abi::MIPS_ABI_FP_ANY]) link with anything, and
+/// [abi::MIPS_ABI_FP_XX] code links with any of the double-precision hard-float ABIs.
+/// Otherwise, the ABIs must match, except that [abi::MIPS_ABI_FP_64] and
+/// [abi::MIPS_ABI_FP_64A] are compatible with each other.
+pub fn mips_fp_abi_compatible(a: u8, b: u8) -> bool {
+    if a == b || a == abi::MIPS_ABI_FP_ANY || b == abi::MIPS_ABI_FP_ANY {
+        return true;
+    }
+    let double = |fp_abi: u8| {
+        matches!(
+            fp_abi,
+            abi::MIPS_ABI_FP_DOUBLE | abi::MIPS_ABI_FP_64 | abi::MIPS_ABI_FP_64A
+        )
+    };
+    match (a, b) {
+        (abi::MIPS_ABI_FP_XX, other) | (other, abi::MIPS_ABI_FP_XX) => double(other),
+        (abi::MIPS_ABI_FP_64, abi::MIPS_ABI_FP_64A)
+        | (abi::MIPS_ABI_FP_64A, abi::MIPS_ABI_FP_64) => true,
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod parse_tests {
+    use super::*;
+    use crate::endian::{BigEndian, LittleEndian};
+    use crate::parse::{test_parse_for, test_parse_fuzz_too_short};
+
+    #[test]
+    fn parse_abiflags_lsb() {
+        test_parse_for(
+            LittleEndian,
+            Class::ELF32,
+            MipsAbiFlags {
+                version: 0x0100,
+                isa_level: 2,
+                isa_rev: 3,
+                gpr_size: 4,
+                cpr1_size: 5,
+                cpr2_size: 6,
+                fp_abi: 7,
+                isa_ext: 0x0B0A0908,
+                ases: 0x0F0E0D0C,
+                flags1: 0x13121110,
+                flags2: 0x17161514,
+            },
+        );
+    }
+
+    #[test]
+    fn parse_abiflags_msb() {
+        test_parse_for(
+            BigEndian,
+            Class::ELF64,
+            MipsAbiFlags {
+                version: 0x0001,
+                isa_level: 2,
+                isa_rev: 3,
+                gpr_size: 4,
+                cpr1_size: 5,
+                cpr2_size: 6,
+                fp_abi: 7,
+                isa_ext: 0x08090A0B,
+                ases: 0x0C0D0E0F,
+                flags1: 0x10111213,
+                flags2: 0x14151617,
+            },
+        );
+    }
+
+    #[test]
+    fn parse_abiflags_fuzz_too_short() {
+        test_parse_fuzz_too_short::<_, MipsAbiFlags>(LittleEndian, Class::ELF32);
+        test_parse_fuzz_too_short::<_, MipsAbiFlags>(LittleEndian, Class::ELF64);
+    }
+
+    #[test]
+    fn parse_reginfo32_msb() {
+        test_parse_for(
+            BigEndian,
+            Class::ELF32,
+            MipsRegInfo {
+                ri_gprmask: 0x00010203,
+                ri_cprmask: [0x04050607, 0x08090A0B, 0x0C0D0E0F, 0x10111213],
+                ri_gp_value: 0x14151617,
+            },
+        );
+    }
+
+    #[test]
+    fn parse_reginfo64_lsb() {
+        test_parse_for(
+            LittleEndian,
+            Class::ELF64,
+            MipsRegInfo {
+                ri_gprmask: 0x03020100,
+                ri_cprmask: [0x0B0A0908, 0x0F0E0D0C, 0x13121110, 0x17161514],
+                ri_gp_value: 0x1F1E1D1C1B1A1918,
+            },
+        );
+    }
+
+    #[test]
+    fn parse_reginfo_fuzz_too_short() {
+        test_parse_fuzz_too_short::<_, MipsRegInfo>(LittleEndian, Class::ELF32);
+        test_parse_fuzz_too_short::<_, MipsRegInfo>(LittleEndian, Class::ELF64);
+    }
+
+    #[test]
+    fn fp_abi_compatibility() {
+        use abi::*;
+        assert!(mips_fp_abi_compatible(MIPS_ABI_FP_ANY, MIPS_ABI_FP_SOFT));
+        assert!(mips_fp_abi_compatible(MIPS_ABI_FP_SOFT, MIPS_ABI_FP_SOFT));
+        assert!(mips_fp_abi_compatible(MIPS_ABI_FP_XX, MIPS_ABI_FP_DOUBLE));
+        assert!(mips_fp_abi_compatible(MIPS_ABI_FP_64A, MIPS_ABI_FP_XX));
+        assert!(mips_fp_abi_compatible(MIPS_ABI_FP_64, MIPS_ABI_FP_64A));
+        assert!(!mips_fp_abi_compatible(MIPS_ABI_FP_XX, MIPS_ABI_FP_SINGLE));
+        assert!(!mips_fp_abi_compatible(MIPS_ABI_FP_DOUBLE, MIPS_ABI_FP_64));
+        assert!(!mips_fp_abi_compatible(
+            MIPS_ABI_FP_SOFT,
+            MIPS_ABI_FP_DOUBLE
+        ));
+        assert!(!mips_fp_abi_compatible(MIPS_ABI_FP_OLD_64, MIPS_ABI_FP_XX));
+    }
+}
@@ -0,0 +1,201 @@
+//! Parsing Linux kernel module metadata: the `.modinfo` section, as found by
+//! [ElfBytes::modinfo](crate::ElfBytes::modinfo), and the appended module signature, as found
+//! by [ElfBytes::module_signature](crate::ElfBytes::module_signature)
+//!
+//! Kernel modules (`.ko` files) are relocatable objects. Their `.modinfo` section holds
+//! NUL-terminated `key=value` strings, like `license=GPL`, `vermagic=...` and
+//! `depends=...`, which may be separated by extra NUL padding.
+//!
+//! A signed module has its signature appended after the end of the ELF object. The signature
+//! data is followed by a 12-byte descriptor of its lengths and algorithms, and then by the
+//! [MODULE_SIG_STRING] marker at the very end of the file.
+use crate::parse::{ParseError, ReadBytesExt};
+
+/// The name of the section holding a kernel module's metadata
+pub const MODINFO_SECTION_NAME: &str = ".modinfo";
+
+/// The marker at the end of a signed kernel module
+pub const MODULE_SIG_STRING: &[u8] = b"~Module signature appended~\n";
+
+/// The size of the descriptor between the signature data and [MODULE_SIG_STRING]
+pub const MODULE_SIGNATURE_SIZE: usize = 12;
+
+/// The signature is a PGP key signature, which is no longer supported by the kernel
+pub const PKEY_ID_PGP: u8 = 0;
+/// The signature is an X.509 certificate signature
+pub const PKEY_ID_X509: u8 = 1;
+/// The signature is a PKCS#7 message, which holds the signer, key ID and algorithms itself.
+/// This is the only type produced by modern kernels' `sign-file`.
+pub const PKEY_ID_PKCS7: u8 = 2;
+
+/// Iterates over the `key=value` entries of a `.modinfo` section, as (key, value) pairs.
+///
+/// Empty strings from NUL padding are skipped. An entry without an `=` is yielded as a key
+/// with an empty value.
+#[derive(Debug, Clone)]
+pub struct ModInfoIterator<'data> {
+    data: &'data [u8],
+}
+
+impl<'data> ModInfoIterator<'data> {
+    pub fn new(data: &'data [u8]) -> Self {
+        ModInfoIterator { data }
+    }
+}
+
+impl<'data> Iterator for ModInfoIterator<'data> {
+    type Item = (&'data [u8], &'data [u8]);
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if self.data.is_empty() {
+                return None;
+            }
+            let end = self
+                .data
+                .iter()
+                .position(|byte| *byte == 0)
+                .unwrap_or(self.data.len());
+            let entry = &self.data[..end];
+            self.data = &self.data[(end + 1).min(self.data.len())..];
+            if entry.is_empty() {
+                continue;
+            }
+            return Some(match entry.iter().position(|byte| *byte == b'=') {
+                Some(eq) => (&entry[..eq], &entry[eq + 1..]),
+                None => (entry, &[]),
+            });
+        }
+    }
+}
+
+/// The signature appended to a signed kernel module
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ModuleSignature<'data> {
+    /// The public-key crypto algorithm, which is 0 for [PKEY_ID_PKCS7] signatures
+    pub algo: u8,
+    /// The digest algorithm, which is 0 for [PKEY_ID_PKCS7] signatures
+    pub hash: u8,
+    /// The type of signature, such as [PKEY_ID_PKCS7]
+    pub id_type: u8,
+    /// The signer's name, which is empty for [PKEY_ID_PKCS7] signatures
+    pub signer: &'data [u8],
+    /// The key identifier, which is empty for [PKEY_ID_PKCS7] signatures
+    pub key_id: &'data [u8],
+    /// The signature data, e.g. the DER-encoded PKCS#7 message
+    pub signature: &'data [u8],
+}
+
+impl<'data> ModuleSignature<'data> {
+    /// Parse the signature at the end of the given file data.
+    ///
+    /// Returns an empty Option if the data doesn't end with [MODULE_SIG_STRING].
+    pub fn parse(data: &'data [u8]) -> Result<Option<Self>, ParseError> {
+        if !data.ends_with(MODULE_SIG_STRING) {
+            return Ok(None);
+        }
+        let info_end = data.len() - MODULE_SIG_STRING.len();
+        let info_start = info_end
+            .checked_sub(MODULE_SIGNATURE_SIZE)
+            .ok_or(ParseError::BadOffset(info_end as u64))?;
+        let info = data.get_bytes(info_start..info_end)?;
+
+        let signer_len = info[3] as usize;
+        let key_id_len = info[4] as usize;
+        // info[5..8] is padding, and the signature length is always big-endian
+        let sig_len = u32::from_be_bytes([info[8], info[9], info[10], info[11]]) as usize;
+
+        let signer_start = signer_len
+            .checked_add(key_id_len)
+            .and_then(|len| len.checked_add(sig_len))
+            .and_then(|len| info_start.checked_sub(len))
+            .ok_or(ParseError::BadOffset(info_start as u64))?;
+        let key_id_start = signer_start + signer_len;
+        let sig_start = key_id_start + key_id_len;
+        Ok(Some(ModuleSignature {
+            algo: info[0],
+            hash: info[1],
+            id_type: info[2],
+            signer: data.get_bytes(signer_start..key_id_start)?,
+            key_id: data.get_bytes(key_id_start..sig_start)?,
+            signature: data.get_bytes(sig_start..info_start)?,
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn modinfo_entries() {
+        let data = b"license=GPL\0\0\0\0depends=\0intree\0vermagic=6.1.0 SMP mod_unload \0";
+        let entries: Vec<_> = ModInfoIterator::new(data).collect();
+        assert_eq!(
+            entries,
+            vec![
+                (&b"license"[..], &b"GPL"[..]),
+                (b"depends", b""),
+                (b"intree", b""),
+                (b"vermagic", b"6.1.0 SMP mod_unload "),
+            ]
+        );
+
+        // An unterminated final entry
+        let entries: Vec<_> = ModInfoIterator::new(b"a=b=c\0name=x").collect();
+        assert_eq!(entries, vec![(&b"a"[..], &b"b=c"[..]), (b"name", b"x")]);
+    }
+
+    #[test]
+    fn pkcs7_signature() {
+        let mut data = b"\x7fELF...".to_vec();
+        data.extend_from_slice(b"0\x82sig");
+        data.extend_from_slice(&[0, 0, PKEY_ID_PKCS7, 0, 0, 0, 0, 0, 0, 0, 0, 5]);
+        data.extend_from_slice(MODULE_SIG_STRING);
+        let sig = ModuleSignature::parse(&data)
+            .expect("should parse")
+            .expect("should be signed");
+        assert_eq!(sig.id_type, PKEY_ID_PKCS7);
+        assert_eq!(sig.signer, b"");
+        assert_eq!(sig.key_id, b"");
+        assert_eq!(sig.signature, b"0\x82sig");
+    }
+
+    #[test]
+    fn x509_signature() {
+        let mut data = b"signerkeysig".to_vec();
+        data.extend_from_slice(&[1, 2, PKEY_ID_X509, 6, 3, 0, 0, 0, 0, 0, 0, 3]);
+        data.extend_from_slice(MODULE_SIG_STRING);
+        let sig = ModuleSignature::parse(&data)
+            .expect("should parse")
+            .expect("should be signed");
+        assert_eq!((sig.algo, sig.hash, sig.id_type), (1, 2, PKEY_ID_X509));
+        assert_eq!(sig.signer, b"signer");
+        assert_eq!(sig.key_id, b"key");
+        assert_eq!(sig.signature, b"sig");
+    }
+
+    #[test]
+    fn bad_signatures() {
+        assert_eq!(
+            ModuleSignature::parse(b"\x7fELF").expect("should parse"),
+            None
+        );
+
+        // Too short for the descriptor
+        let mut data = b"\0\0".to_vec();
+        data.extend_from_slice(MODULE_SIG_STRING);
+        assert!(matches!(
+            ModuleSignature::parse(&data),
+            Err(ParseError::BadOffset(_))
+        ));
+
+        // The signature length runs off the start of the file
+        let mut data = b"sig".to_vec();
+        data.extend_from_slice(&[0, 0, PKEY_ID_PKCS7, 0, 0, 0, 0, 0, 0, 0, 1, 0]);
+        data.extend_from_slice(MODULE_SIG_STRING);
+        assert!(matches!(
+            ModuleSignature::parse(&data),
+            Err(ParseError::BadOffset(_))
+        ));
+    }
+}
@@ -38,7 +38,11 @@ use crate::parse::{ParseAt, ParseError, ReadBytesExt};
 use core::mem::size_of;
 use core::str::from_utf8;
 
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::vec::Vec;
+
 /// This enum contains parsed Note variants which can be matched on
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[derive(Debug, PartialEq, Eq)]
 pub enum Note<'data> {
     /// (name: [abi::ELF_NOTE_GNU], n_type: [abi::NT_GNU_ABI_TAG])
@@ -50,6 +54,20 @@ pub enum Note<'data> {
 }
 
 impl<'data> Note<'data> {
+    /// The owner name of this note, trimmed at the first NUL (see [note_owner]).
+    pub fn owner(&self) -> &'data [u8] {
+        match self {
+            Note::GnuAbiTag(_) | Note::GnuBuildId(_) => b"GNU",
+            Note::Unknown(note) => note.owner(),
+        }
+    }
+
+    /// Returns true if this note's owner matches `owner`. Both sides are compared up to their
+    /// first NUL, so `b"GNU"` and `b"GNU\0"` are equivalent.
+    pub fn is_owned_by(&self, owner: &[u8]) -> bool {
+        self.owner() == note_owner(owner)
+    }
+
     fn parse_at<E: EndianParse>(
         endian: E,
         _class: Class,
@@ -97,9 +115,10 @@ impl<'data> Note<'data> {
                 .ok_or(ParseError::IntegerOverflow)?;
         }
 
-        // Interpret the note contents to try to return a known note variant
-        match name {
-            abi::ELF_NOTE_GNU => match nhdr.n_type {
+        // Interpret the note contents to try to return a known note variant. Owners are
+        // compared without their NUL terminator, since some producers omit it from namesz.
+        match note_owner(name) {
+            b"GNU" => match nhdr.n_type {
                 abi::NT_GNU_ABI_TAG => {
                     let mut offset = 0;
                     Ok(Note::GnuAbiTag(NoteGnuAbiTag::parse_at(
@@ -131,6 +150,7 @@ impl<'data> Note<'data> {
 /// For example, if the 3 integers are 6, 0, and 7, this signifies a 6.0.7 kernel.
 ///
 /// (see: <https://raw.githubusercontent.com/wiki/hjl-tools/linux-abi/linux-abi-draft.pdf>)
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct NoteGnuAbiTag {
     pub os: u32,
@@ -159,32 +179,126 @@ impl ParseAt for NoteGnuAbiTag {
     }
 }
 
+/// Display adapter for writing out raw bytes as lowercase hex
+#[cfg(feature = "serde")]
+struct HexBytes<'a>(&'a [u8]);
+
+#[cfg(feature = "serde")]
+impl core::fmt::Display for HexBytes<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        for byte in self.0 {
+            write!(f, "{byte:02x}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "serde")]
+fn serialize_hex<S: serde::Serializer>(bytes: &&[u8], serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.collect_str(&HexBytes(bytes))
+}
+
 /// Contains a build ID note which is unique among the set of meaningful contents
 /// for ELF files and identical when the output file would otherwise have been identical.
 /// This is a zero-copy type which merely contains a slice of the note data from which it was parsed.
 ///
+/// With the `serde` feature, the build ID serializes as a lowercase hex string, as in
+/// `"8c331317dd5ad783a90dd2b7d74dd8afa76e03d1"`.
+///
 /// (see: <https://raw.githubusercontent.com/wiki/hjl-tools/linux-abi/linux-abi-draft.pdf>)
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub struct NoteGnuBuildId<'data>(pub &'data [u8]);
+pub struct NoteGnuBuildId<'data>(
+    #[cfg_attr(feature = "serde", serde(serialize_with = "serialize_hex"))] pub &'data [u8],
+);
 
 /// Contains the raw fields found in any ELF note. Used for notes that we don't know
 /// how to parse into more specific types.
-#[derive(Debug, PartialEq, Eq)]
+///
+/// With the `serde` feature, the raw `name` and `desc` bytes serialize as lowercase hex strings.
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct NoteAny<'data> {
     pub n_type: u64,
+    #[cfg_attr(feature = "serde", serde(serialize_with = "serialize_hex"))]
     pub name: &'data [u8],
+    #[cfg_attr(feature = "serde", serde(serialize_with = "serialize_hex"))]
     pub desc: &'data [u8],
 }
 
 impl<'data> NoteAny<'data> {
-    /// Parses the note's name bytes as a utf8 sequence, with any trailing NUL bytes removed
-    pub fn name_str(&self) -> Result<&str, ParseError> {
-        let name = from_utf8(self.name)?;
-        Ok(name.trim_end_matches('\0'))
+    /// The note's owner name, i.e. the raw `name` bytes trimmed at the first NUL
+    pub fn owner(&self) -> &'data [u8] {
+        note_owner(self.name)
+    }
+
+    /// Returns true if this note's owner matches `owner`, ignoring NUL terminators and padding
+    pub fn is_owned_by(&self, owner: &[u8]) -> bool {
+        self.owner() == note_owner(owner)
+    }
+
+    /// Parses the note's owner name as a utf8 sequence, with the NUL terminator and anything
+    /// after it removed
+    pub fn name_str(&self) -> Result<&'data str, ParseError> {
+        Ok(from_utf8(self.owner())?)
+    }
+}
+
+/// Trim a raw note name at its first NUL byte.
+///
+/// The gABI says `namesz` includes the terminating NUL, but some producers leave it out and
+/// others pad the name with extra NULs. This gives the owner name that all of those spellings
+/// have in common, e.g. `b"GNU\0"`, `b"GNU"` and `b"GNU\0\0\0\0\0"` all give `b"GNU"`.
+pub fn note_owner(name: &[u8]) -> &[u8] {
+    match name.iter().position(|byte| *byte == 0) {
+        Some(end) => &name[..end],
+        None => name,
+    }
+}
+
+/// The operating system identified by a BSD ident note, as found by
+/// [ElfBytes::os_abi_note](crate::ElfBytes::os_abi_note)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OsIdent {
+    /// (name: [abi::ELF_NOTE_FREEBSD], n_type: [abi::NT_FREEBSD_ABI_TAG])
+    FreeBsd {
+        /// The `__FreeBSD_version` the object was built for, e.g. 1400097 for 14.0
+        version: u32,
+        /// The [abi::NT_FREEBSD_FEATURE_CTL] word of `NT_FREEBSD_FCTL_*` bits, if present
+        feature_ctl: Option<u32>,
+    },
+    /// (name: [abi::ELF_NOTE_NETBSD], n_type: [abi::NT_NETBSD_IDENT])
+    NetBsd {
+        /// The `__NetBSD_Version__` the object was built for, encoded as `MMmmrrpp00`,
+        /// e.g. 1000000000 for 10.0
+        version: u32,
+    },
+    /// (name: [abi::ELF_NOTE_OPENBSD], n_type: [abi::NT_OPENBSD_IDENT]). The note doesn't
+    /// record a version.
+    OpenBsd,
+}
+
+impl OsIdent {
+    /// Get the major release number from the note's version, if it has one
+    pub fn major(&self) -> Option<u32> {
+        match self {
+            OsIdent::FreeBsd { version, .. } => Some(version / 100000),
+            OsIdent::NetBsd { version } => Some(version / 100000000),
+            OsIdent::OpenBsd => None,
+        }
+    }
+
+    /// Get the minor release number from the note's version, if it has one
+    pub fn minor(&self) -> Option<u32> {
+        match self {
+            OsIdent::FreeBsd { version, .. } => Some(version / 1000 % 100),
+            OsIdent::NetBsd { version } => Some(version / 1000000 % 100),
+            OsIdent::OpenBsd => None,
+        }
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct NoteIterator<'data, E: EndianParse> {
     endian: E,
     class: Class,
@@ -223,6 +337,83 @@ impl<'data, E: EndianParse> Iterator for NoteIterator<'data, E> {
     }
 }
 
+/// Builder for the contents of a new [SHT_NOTE](abi::SHT_NOTE) section or
+/// [PT_NOTE](abi::PT_NOTE) segment holding one or more notes.
+///
+/// Each note is written as a 32-bit `namesz`/`descsz`/`type` header, which is what toolchains
+/// emit for both classes, followed by the name and the desc, each padded out to the note
+/// alignment. Use an alignment of 4 for most notes, and 8 for notes that are laid out like
+/// `.note.gnu.property` in 64-bit objects. The section or segment holding the notes should
+/// declare the same alignment.
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone)]
+pub struct NoteBuilder<E: EndianParse> {
+    endian: E,
+    align: usize,
+    data: Vec<u8>,
+}
+
+#[cfg(feature = "alloc")]
+impl<E: EndianParse> NoteBuilder<E> {
+    /// Create a new builder for notes with the given byte order and alignment, which must be
+    /// either 4 or 8.
+    pub fn new(endian: E, align: usize) -> Result<Self, ParseError> {
+        if align != 4 && align != 8 {
+            return Err(ParseError::UnexpectedAlignment(align));
+        }
+        Ok(NoteBuilder {
+            endian,
+            align,
+            data: Vec::new(),
+        })
+    }
+
+    /// Append a note with the given name, type and desc.
+    ///
+    /// The name gets a NUL terminator unless it already ends in one, so both `b"GNU"` and
+    /// [abi::ELF_NOTE_GNU] produce the same note. An empty name is written with a `namesz`
+    /// of 0. Returns [ParseError::TryFromIntError] if the type or a size doesn't fit in the
+    /// 32-bit header.
+    pub fn add(&mut self, name: &[u8], n_type: u64, desc: &[u8]) -> Result<(), ParseError> {
+        let terminate = !name.is_empty() && name.last() != Some(&0);
+        let namesz: u32 = (name.len() + terminate as usize).try_into()?;
+        let descsz: u32 = desc.len().try_into()?;
+        let n_type: u32 = n_type.try_into()?;
+
+        let mut header = [0u8; 12];
+        let mut offset = 0;
+        self.endian.write_u32_at(&mut offset, &mut header, namesz)?;
+        self.endian.write_u32_at(&mut offset, &mut header, descsz)?;
+        self.endian.write_u32_at(&mut offset, &mut header, n_type)?;
+        self.data.extend_from_slice(&header);
+
+        self.data.extend_from_slice(name);
+        if terminate {
+            self.data.push(0);
+        }
+        self.pad();
+        self.data.extend_from_slice(desc);
+        self.pad();
+        Ok(())
+    }
+
+    /// Append an [abi::NT_GNU_BUILD_ID] note holding the given build id, as found in a
+    /// `.note.gnu.build-id` section.
+    pub fn add_gnu_build_id(&mut self, build_id: &[u8]) -> Result<(), ParseError> {
+        self.add(abi::ELF_NOTE_GNU, abi::NT_GNU_BUILD_ID, build_id)
+    }
+
+    /// Get the finished note section or segment contents.
+    pub fn build(self) -> Vec<u8> {
+        self.data
+    }
+
+    fn pad(&mut self) {
+        let pad = (self.align - self.data.len() % self.align) % self.align;
+        self.data.resize(self.data.len() + pad, 0);
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 struct NoteHeader {
     pub n_namesz: u64,
@@ -570,6 +761,78 @@ mod parse_tests {
         assert!(matches!(note.name_str(), Err(ParseError::Utf8Error(_))));
     }
 
+    #[test]
+    fn parse_gnu_build_id_with_and_without_name_nul() {
+        // namesz 4: "GNU\0"
+        let with_nul = [
+            0x04, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00, // namesz 4, descsz 4
+            0x03, 0x00, 0x00, 0x00, 0x47, 0x4e, 0x55, 0x00, // NT_GNU_BUILD_ID, name GNU\0
+            0xde, 0xad, 0xbe, 0xef, // desc
+        ];
+        // namesz 3: "GNU", padded to 4
+        let without_nul = [
+            0x03, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00, // namesz 3, descsz 4
+            0x03, 0x00, 0x00, 0x00, 0x47, 0x4e, 0x55, 0x00, // NT_GNU_BUILD_ID, name GNU + pad
+            0xde, 0xad, 0xbe, 0xef, // desc
+        ];
+        for data in [&with_nul[..], &without_nul[..]] {
+            let mut offset = 0;
+            let note = Note::parse_at(LittleEndian, Class::ELF64, 4, &mut offset, data)
+                .expect("Failed to parse");
+            assert_eq!(
+                note,
+                Note::GnuBuildId(NoteGnuBuildId(&[0xde, 0xad, 0xbe, 0xef]))
+            );
+            assert_eq!(offset, 20);
+            assert_eq!(note.owner(), b"GNU");
+            assert!(note.is_owned_by(b"GNU"));
+            assert!(note.is_owned_by(abi::ELF_NOTE_GNU));
+        }
+    }
+
+    #[test]
+    fn parse_gnu_abi_tag_without_name_nul() {
+        let data = [
+            0x03, 0x00, 0x00, 0x00, 0x10, 0x00, 0x00, 0x00, // namesz 3, descsz 16
+            0x01, 0x00, 0x00, 0x00, 0x47, 0x4e, 0x55, 0x00, // NT_GNU_ABI_TAG, name GNU + pad
+            0x00, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, // os 0, major 3
+            0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // minor 2, subminor 0
+        ];
+        let mut offset = 0;
+        let note = Note::parse_at(LittleEndian, Class::ELF64, 4, &mut offset, &data)
+            .expect("Failed to parse");
+        assert_eq!(
+            note,
+            Note::GnuAbiTag(NoteGnuAbiTag {
+                os: 0,
+                major: 3,
+                minor: 2,
+                subminor: 0
+            })
+        );
+    }
+
+    #[test]
+    fn note_any_owner_trims_at_first_nul() {
+        for name in [&b"GNU\0"[..], b"GNU", b"GNU\0\0\0\0\0", b"GNU\0junk"] {
+            let note = NoteAny {
+                n_type: 42,
+                name,
+                desc: &[],
+            };
+            assert_eq!(note.owner(), b"GNU");
+            assert_eq!(note.name, name);
+            assert!(note.is_owned_by(b"GNU"));
+            assert!(note.is_owned_by(b"GNU\0"));
+            assert!(!note.is_owned_by(b"GN"));
+            assert!(!note.is_owned_by(b"GNUU"));
+            assert_eq!(note.name_str().expect("Failed to parse utf8"), "GNU");
+        }
+
+        assert_eq!(note_owner(b""), b"");
+        assert_eq!(note_owner(b"\0"), b"");
+    }
+
     use crate::parse::{test_parse_for, test_parse_fuzz_too_short};
 
     #[test]
@@ -644,3 +907,126 @@ mod parse_tests {
         test_parse_fuzz_too_short::<_, NoteHeader>(BigEndian, Class::ELF64);
     }
 }
+
+#[cfg(all(test, feature = "alloc"))]
+mod build_tests {
+    use super::*;
+    use crate::endian::{AnyEndian, BigEndian, LittleEndian};
+    use crate::ElfBytes;
+
+    #[test]
+    fn roundtrip() {
+        for align in [4, 8] {
+            for endian in [AnyEndian::Little, AnyEndian::Big] {
+                let mut builder = NoteBuilder::new(endian, align).expect("should create");
+                builder
+                    .add_gnu_build_id(&[0xde, 0xad, 0xbe, 0xef, 0x01])
+                    .unwrap();
+                builder.add(b"Vendor", 0x1234, b"hi").unwrap();
+                builder.add(b"", 7, &[]).unwrap();
+                let data = builder.build();
+                assert_eq!(data.len() % align, 0);
+
+                let notes: Vec<_> = NoteIterator::new(endian, Class::ELF64, align, &data).collect();
+                assert_eq!(
+                    notes,
+                    [
+                        Note::GnuBuildId(NoteGnuBuildId(&[0xde, 0xad, 0xbe, 0xef, 0x01])),
+                        Note::Unknown(NoteAny {
+                            n_type: 0x1234,
+                            name: b"Vendor\0",
+                            desc: b"hi",
+                        }),
+                        Note::Unknown(NoteAny {
+                            n_type: 7,
+                            name: &[],
+                            desc: &[],
+                        }),
+                    ]
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn padding() {
+        let mut builder = NoteBuilder::new(BigEndian, 8).expect("should create");
+        builder.add(b"GNU", 5, &[1, 2, 3, 4]).unwrap();
+        #[rustfmt::skip]
+        assert_eq!(
+            builder.build(),
+            [
+                0, 0, 0, 4, 0, 0, 0, 4, 0, 0, 0, 5,
+                b'G', b'N', b'U', 0,
+                1, 2, 3, 4, 0, 0, 0, 0,
+            ]
+        );
+    }
+
+    #[test]
+    fn bad_alignment() {
+        assert!(matches!(
+            NoteBuilder::new(LittleEndian, 0),
+            Err(ParseError::UnexpectedAlignment(0))
+        ));
+        assert!(matches!(
+            NoteBuilder::new(LittleEndian, 16),
+            Err(ParseError::UnexpectedAlignment(16))
+        ));
+    }
+
+    #[test]
+    fn matches_linker_build_id() {
+        let file_data = std::fs::read("sample-objects/basic.x86_64").expect("should read");
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("should parse");
+        let shdr = file
+            .section_header_by_name(".note.gnu.build-id")
+            .expect("should parse")
+            .expect("should have a build-id");
+        let (linked, _) = file.section_data(&shdr).expect("should read");
+        let build_id = file
+            .gnu_build_id()
+            .expect("should parse")
+            .expect("should have a build-id");
+
+        let mut builder =
+            NoteBuilder::new(file.ehdr.endianness, shdr.sh_addralign as usize).unwrap();
+        builder.add_gnu_build_id(build_id.0).unwrap();
+        assert_eq!(builder.build(), linked);
+    }
+}
+
+#[cfg(all(test, feature = "serde"))]
+mod serde_tests {
+    use super::*;
+    use crate::parse::test_serde_roundtrip;
+
+    #[test]
+    fn serde_gnu_abi_tag() {
+        let tag = NoteGnuAbiTag {
+            os: 0,
+            major: 2,
+            minor: 6,
+            subminor: 32,
+        };
+        test_serde_roundtrip(&tag, r#"{"os":0,"major":2,"minor":6,"subminor":32}"#);
+    }
+
+    #[test]
+    fn serde_note_bytes_as_hex() {
+        let note = Note::GnuBuildId(NoteGnuBuildId(&[0x8c, 0x33, 0x13, 0x00, 0xff]));
+        let json = serde_json::to_string(&note).expect("Failed to serialize");
+        assert_eq!(json, r#"{"GnuBuildId":"8c331300ff"}"#);
+
+        let note = Note::Unknown(NoteAny {
+            n_type: 3,
+            name: b"GNU\0",
+            desc: &[0xde, 0xad],
+        });
+        let json = serde_json::to_string(&note).expect("Failed to serialize");
+        assert_eq!(
+            json,
+            r#"{"Unknown":{"n_type":3,"name":"474e5500","desc":"dead"}}"#
+        );
+    }
+}
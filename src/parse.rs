@@ -1,11 +1,17 @@
 //! Utilities to drive safe and lazy parsing of ELF structures.
 use core::{marker::PhantomData, ops::Range};
 
+use crate::detect::{detect_format, DetectedFormat};
 use crate::endian::EndianParse;
 use crate::file::Class;
 
+/// The errors that parsing can return, which are generic over the context that can be
+/// attached to them.
+///
+/// This is used through its [ParseError] and [BareParseError] aliases, with and without the
+/// [ErrorContext] that the file-level interfaces attach.
 #[derive(Debug)]
-pub enum ParseError {
+pub enum GenericParseError<C> {
     /// Returned when the ELF File Header's magic bytes weren't ELF's defined
     /// magic bytes
     BadMagic([u8; 4]),
@@ -43,6 +49,36 @@ pub enum ParseError {
     SliceReadError((usize, usize)),
     /// Returned when doing math with parsed elf fields that resulted in integer overflow.
     IntegerOverflow,
+    /// Returned when walking the hash chain for the given bucket didn't terminate within
+    /// the number of entries in the table, as happens when a corrupt chain loops back on itself.
+    HashChainCycle(usize),
+    /// Returned when decoding a value with a `DW_EH_PE_*` pointer encoding that isn't supported,
+    /// such as one applied relative to the text section.
+    UnsupportedPointerEncoding(u8),
+    /// Returned when decompressing a section compressed with a `ch_type` that isn't supported,
+    /// such as one whose decompression feature isn't enabled.
+    UnsupportedCompression(u32),
+    /// Returned when an `ar` archive member's header at the given offset is malformed, such as
+    /// one with a size that isn't a decimal number.
+    BadArchiveHeader(usize),
+    /// Returned when an `ar` archive, or its member at the given offset, is in a variant of the
+    /// format that isn't supported, like a thin archive or a BSD-style `#1/len` name.
+    UnsupportedArchiveFormat(usize),
+    /// Returned when a table's size in bytes, as computed from the entry counts and sizes
+    /// in the file, was larger than the [ParseOptions::max_table_bytes] limit. Contains the
+    /// table's size and the limit.
+    LimitExceeded((u64, u64)),
+    /// Returned when a relocation's type isn't one that the operation supports for the
+    /// machine, such as when it's not known where its implicit addend is stored. Contains
+    /// the `e_machine` and the relocation type.
+    UnsupportedRelocation((u16, u32)),
+    /// Returned when applying a relocation against a symbol whose value couldn't be resolved.
+    /// Contains the symbol's index.
+    UnresolvedSymbol(u32),
+    /// Returned when the file header's `e_shstrndx` (or the `sh_link` of section header 0
+    /// that it escapes to) doesn't refer to a [SHT_STRTAB](crate::abi::SHT_STRTAB) section,
+    /// such as when it's out of range or refers to a SHT_NOBITS section. Contains the index.
+    BadShstrndx(u32),
     /// Returned when parsing a string out of a StringTable that contained
     /// invalid Utf8
     Utf8Error(core::str::Utf8Error),
@@ -57,117 +93,476 @@ pub enum ParseError {
     /// Returned when parsing an ELF structure out of an io stream encountered
     /// an io error.
     IOError(std::io::Error),
+    /// Returned by the [ElfBytes](crate::ElfBytes) interfaces to describe which ELF structure
+    /// was being parsed (and where in the file it lives) when the underlying error occurred.
+    Context(C),
+}
+
+/// The error type returned throughout the crate, which may have an [ErrorContext] attached.
+pub type ParseError = GenericParseError<ErrorContext>;
+
+/// A [ParseError] which can't have any context attached, as held by an [ErrorContext].
+pub type BareParseError = GenericParseError<core::convert::Infallible>;
+
+/// Describes which ELF structure was being parsed when a [ParseError] occurred.
+///
+/// This gets attached by the file-level interfaces, which know what they're asking for, while
+/// the low-level [ParseAt] impls return the bare errors. The context is held by value along
+/// with the underlying error, so it's available without the `alloc` feature too. If context
+/// is attached to an error more than once, then the innermost, most specific one is kept.
+///
+/// ```
+/// use elf::endian::AnyEndian;
+/// use elf::parse::{BareParseError, ParseError};
+/// use elf::ElfBytes;
+///
+/// let path = std::path::PathBuf::from("sample-objects/basic.x86_64");
+/// let file_data = std::fs::read(path).unwrap();
+/// let truncated = &file_data[..file_data.len() - 1];
+///
+/// let err = ElfBytes::<AnyEndian>::minimal_parse(truncated).unwrap_err();
+/// assert!(format!("{err}").starts_with("error parsing section header table at file offset 0x12c0: "));
+/// match err {
+///     ParseError::Context(ctx) => {
+///         assert_eq!(ctx.what, "section header table");
+///         assert!(matches!(ctx.source, BareParseError::SliceReadError(_)));
+///     }
+///     _ => panic!("should have context"),
+/// }
+/// ```
+#[derive(Debug)]
+pub struct ErrorContext {
+    /// What was being parsed, e.g. "section header" or "symbol table"
+    pub what: &'static str,
+    /// The index of the structure in its table, if it has one
+    pub index: Option<usize>,
+    /// The absolute file offset of the structure
+    pub file_offset: u64,
+    /// The underlying error
+    pub source: BareParseError,
+}
+
+impl ParseError {
+    /// Get the context attached to this error, if any.
+    pub fn context(&self) -> Option<&ErrorContext> {
+        match self {
+            ParseError::Context(ctx) => Some(ctx),
+            _ => None,
+        }
+    }
+
+    /// Get the underlying error without any [ErrorContext] that was attached to it.
+    ///
+    /// This is handy for matching on the kind of error that occurred.
+    pub fn into_root_cause(self) -> BareParseError {
+        match self.split_context() {
+            Ok(err) => err,
+            Err(ctx) => ctx.source,
+        }
+    }
+}
+
+impl<C> GenericParseError<C> {
+    /// Split the error into the underlying error, which can be given any context type, or
+    /// else the context that was attached to it.
+    fn split_context<D>(self) -> Result<GenericParseError<D>, C> {
+        Ok(match self {
+            GenericParseError::BadMagic(x) => GenericParseError::BadMagic(x),
+            GenericParseError::UnsupportedElfClass(x) => GenericParseError::UnsupportedElfClass(x),
+            GenericParseError::UnsupportedElfEndianness(x) => {
+                GenericParseError::UnsupportedElfEndianness(x)
+            }
+            GenericParseError::UnsupportedVersion(x) => GenericParseError::UnsupportedVersion(x),
+            GenericParseError::BadOffset(x) => GenericParseError::BadOffset(x),
+            GenericParseError::StringTableMissingNul(x) => {
+                GenericParseError::StringTableMissingNul(x)
+            }
+            GenericParseError::BadEntsize(x) => GenericParseError::BadEntsize(x),
+            GenericParseError::UnexpectedSectionType(x) => {
+                GenericParseError::UnexpectedSectionType(x)
+            }
+            GenericParseError::UnexpectedSegmentType(x) => {
+                GenericParseError::UnexpectedSegmentType(x)
+            }
+            GenericParseError::UnexpectedAlignment(x) => GenericParseError::UnexpectedAlignment(x),
+            GenericParseError::SliceReadError(x) => GenericParseError::SliceReadError(x),
+            GenericParseError::IntegerOverflow => GenericParseError::IntegerOverflow,
+            GenericParseError::HashChainCycle(x) => GenericParseError::HashChainCycle(x),
+            GenericParseError::UnsupportedPointerEncoding(x) => {
+                GenericParseError::UnsupportedPointerEncoding(x)
+            }
+            GenericParseError::UnsupportedCompression(x) => {
+                GenericParseError::UnsupportedCompression(x)
+            }
+            GenericParseError::BadArchiveHeader(x) => GenericParseError::BadArchiveHeader(x),
+            GenericParseError::UnsupportedArchiveFormat(x) => {
+                GenericParseError::UnsupportedArchiveFormat(x)
+            }
+            GenericParseError::LimitExceeded(x) => GenericParseError::LimitExceeded(x),
+            GenericParseError::UnsupportedRelocation(x) => {
+                GenericParseError::UnsupportedRelocation(x)
+            }
+            GenericParseError::UnresolvedSymbol(x) => GenericParseError::UnresolvedSymbol(x),
+            GenericParseError::BadShstrndx(x) => GenericParseError::BadShstrndx(x),
+            GenericParseError::Utf8Error(x) => GenericParseError::Utf8Error(x),
+            GenericParseError::TryFromSliceError(x) => GenericParseError::TryFromSliceError(x),
+            GenericParseError::TryFromIntError(x) => GenericParseError::TryFromIntError(x),
+            #[cfg(feature = "std")]
+            GenericParseError::IOError(x) => GenericParseError::IOError(x),
+            GenericParseError::Context(ctx) => return Err(ctx),
+        })
+    }
+}
+
+impl core::fmt::Display for ErrorContext {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "error parsing {}", self.what)?;
+        if let Some(index) = self.index {
+            write!(f, " {index}")?;
+        }
+        write!(
+            f,
+            " at file offset {:#x}: {}",
+            self.file_offset, self.source
+        )
+    }
+}
+
+/// Options for controlling how strictly an ELF object is parsed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseOptions {
+    /// In strict mode (the default), any malformed structure is an error.
+    ///
+    /// In permissive mode, recoverable problems are recorded as [ParseWarning]s and parsing
+    /// continues with best-effort values, such as truncating a header table that runs off the
+    /// end of the file to the entries that fit. Hard structural failures like bad magic bytes
+    /// remain errors in both modes.
+    pub strict: bool,
+    /// The largest table, in bytes, that will be used when its size is computed from counts
+    /// in the file, like `e_shnum` or a hash table's `nchain`. Larger tables are a
+    /// [ParseError::LimitExceeded], even in permissive mode. For an
+    /// [ElfStream](crate::ElfStream), this limits every read from the stream.
+    ///
+    /// Defaults to `None`, which limits tables to the size of the input.
+    pub max_table_bytes: Option<u64>,
+}
+
+impl Default for ParseOptions {
+    fn default() -> Self {
+        ParseOptions {
+            strict: true,
+            max_table_bytes: None,
+        }
+    }
+}
+
+impl ParseOptions {
+    /// Options for parsing in permissive mode, see [ParseOptions::strict].
+    pub fn permissive() -> Self {
+        ParseOptions {
+            strict: false,
+            ..Default::default()
+        }
+    }
+
+    /// Limit the size of the tables that get parsed, see [ParseOptions::max_table_bytes].
+    pub fn with_max_table_bytes(self, max_table_bytes: u64) -> Self {
+        ParseOptions {
+            max_table_bytes: Some(max_table_bytes),
+            ..self
+        }
+    }
+
+    /// Check a table's `size` against the limit for an input of `input_len` bytes.
+    pub(crate) fn check_table_size(&self, size: u64, input_len: usize) -> Result<(), ParseError> {
+        let limit = self.max_table_bytes.unwrap_or(input_len as u64);
+        match size <= limit {
+            true => Ok(()),
+            false => Err(ParseError::LimitExceeded((size, limit))),
+        }
+    }
+}
+
+/// A recoverable problem that was worked around while parsing in permissive mode.
+#[derive(Debug)]
+pub enum ParseWarning {
+    /// A table ran off the end of the file, so only the `found` entries that fit (out of the
+    /// `expected` entries it was declared to have) are used.
+    TruncatedTable {
+        what: &'static str,
+        file_offset: u64,
+        expected: usize,
+        found: usize,
+    },
+    /// A table couldn't be located at all, so it's treated as if it weren't present.
+    IgnoredTable {
+        what: &'static str,
+        file_offset: u64,
+        error: ParseError,
+    },
+    /// The file header's `what` version field (`EI_VERSION` or `e_version`) holds `found`
+    /// rather than [EV_CURRENT](crate::abi::EV_CURRENT), so the file is parsed as if it were
+    /// the current version.
+    UnsupportedVersion { what: &'static str, found: u32 },
+    /// The section header string table couldn't be loaded, so sections are treated as if
+    /// they had no names.
+    IgnoredSectionNames(ParseError),
+    /// The `sh_link` of the section at `index` is out of range, so the section it should link
+    /// to (such as a symbol table's string table) is treated as empty.
+    BadLink { index: usize, sh_link: u32 },
+    /// The table in the section at `index` has an `sh_entsize` of 0, so its entries are taken
+    /// to be the size of their structure for the file's class.
+    ZeroEntsize { index: usize },
+    /// The data of the section at `index` overlaps the data of the section at `other`. This
+    /// doesn't stop either of them from being read.
+    OverlappingSections { index: usize, other: usize },
+}
+
+impl core::fmt::Display for ParseWarning {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ParseWarning::TruncatedTable {
+                what,
+                file_offset,
+                expected,
+                found,
+            } => write!(
+                f,
+                "{what} at file offset {file_offset:#x} was truncated to {found} of {expected} entries"
+            ),
+            ParseWarning::IgnoredTable {
+                what,
+                file_offset,
+                error,
+            } => write!(f, "ignoring {what} at file offset {file_offset:#x}: {error}"),
+            ParseWarning::UnsupportedVersion { what, found } => write!(
+                f,
+                "{what} is {found}, expected {}",
+                crate::abi::EV_CURRENT
+            ),
+            ParseWarning::IgnoredSectionNames(error) => {
+                write!(f, "ignoring the section names: {error}")
+            }
+            ParseWarning::BadLink { index, sh_link } => {
+                write!(f, "section {index} has an out of range sh_link {sh_link}")
+            }
+            ParseWarning::ZeroEntsize { index } => {
+                write!(f, "section {index} has an sh_entsize of 0")
+            }
+            ParseWarning::OverlappingSections { index, other } => {
+                write!(f, "section {index} overlaps with section {other}")
+            }
+        }
+    }
+}
+
+/// Internal helper for attaching an [ErrorContext] to the errors from some parsing operation.
+pub(crate) trait ResultContextExt<T> {
+    fn context(self, what: &'static str, index: Option<usize>, file_offset: u64) -> Self;
+}
+
+impl<T> ResultContextExt<T> for Result<T, ParseError> {
+    fn context(self, what: &'static str, index: Option<usize>, file_offset: u64) -> Self {
+        self.map_err(|err| {
+            let ctx = match err.split_context() {
+                Ok(source) => ErrorContext {
+                    what,
+                    index,
+                    file_offset,
+                    source,
+                },
+                // Keep the more specific context that was attached first
+                Err(ctx) => ctx,
+            };
+            ParseError::Context(ctx)
+        })
+    }
 }
 
 #[cfg(feature = "std")]
-impl std::error::Error for ParseError {
+impl std::error::Error for ErrorContext {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.source)
+    }
+}
+
+#[cfg(all(feature = "nightly", not(feature = "std")))]
+impl core::error::Error for ErrorContext {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        Some(&self.source)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<C: std::error::Error> std::error::Error for GenericParseError<C> {
     fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
         match *self {
-            ParseError::BadMagic(_) => None,
-            ParseError::UnsupportedElfClass(_) => None,
-            ParseError::UnsupportedElfEndianness(_) => None,
-            ParseError::UnsupportedVersion(_) => None,
-            ParseError::BadOffset(_) => None,
-            ParseError::StringTableMissingNul(_) => None,
-            ParseError::BadEntsize(_) => None,
-            ParseError::UnexpectedSectionType(_) => None,
-            ParseError::UnexpectedSegmentType(_) => None,
-            ParseError::UnexpectedAlignment(_) => None,
-            ParseError::SliceReadError(_) => None,
-            ParseError::IntegerOverflow => None,
-            ParseError::Utf8Error(ref err) => Some(err),
-            ParseError::TryFromSliceError(ref err) => Some(err),
-            ParseError::TryFromIntError(ref err) => Some(err),
-            ParseError::IOError(ref err) => Some(err),
+            GenericParseError::BadMagic(_) => None,
+            GenericParseError::UnsupportedElfClass(_) => None,
+            GenericParseError::UnsupportedElfEndianness(_) => None,
+            GenericParseError::UnsupportedVersion(_) => None,
+            GenericParseError::BadOffset(_) => None,
+            GenericParseError::StringTableMissingNul(_) => None,
+            GenericParseError::BadEntsize(_) => None,
+            GenericParseError::UnexpectedSectionType(_) => None,
+            GenericParseError::UnexpectedSegmentType(_) => None,
+            GenericParseError::UnexpectedAlignment(_) => None,
+            GenericParseError::SliceReadError(_) => None,
+            GenericParseError::IntegerOverflow => None,
+            GenericParseError::HashChainCycle(_) => None,
+            GenericParseError::UnsupportedPointerEncoding(_) => None,
+            GenericParseError::UnsupportedCompression(_) => None,
+            GenericParseError::BadArchiveHeader(_) => None,
+            GenericParseError::UnsupportedArchiveFormat(_) => None,
+            GenericParseError::LimitExceeded(_) => None,
+            GenericParseError::UnsupportedRelocation(_) => None,
+            GenericParseError::UnresolvedSymbol(_) => None,
+            GenericParseError::BadShstrndx(_) => None,
+            GenericParseError::Utf8Error(ref err) => Some(err),
+            GenericParseError::TryFromSliceError(ref err) => Some(err),
+            GenericParseError::TryFromIntError(ref err) => Some(err),
+            GenericParseError::IOError(ref err) => Some(err),
+            GenericParseError::Context(ref ctx) => ctx.source(),
         }
     }
 }
 
 #[cfg(all(feature = "nightly", not(feature = "std")))]
-impl core::error::Error for ParseError {
+impl<C: core::error::Error> core::error::Error for GenericParseError<C> {
     fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
         match *self {
-            ParseError::BadMagic(_) => None,
-            ParseError::UnsupportedElfClass(_) => None,
-            ParseError::UnsupportedElfEndianness(_) => None,
-            ParseError::UnsupportedVersion(_) => None,
-            ParseError::BadOffset(_) => None,
-            ParseError::StringTableMissingNul(_) => None,
-            ParseError::BadEntsize(_) => None,
-            ParseError::UnexpectedSectionType(_) => None,
-            ParseError::UnexpectedSegmentType(_) => None,
-            ParseError::UnexpectedAlignment(_) => None,
-            ParseError::SliceReadError(_) => None,
-            ParseError::IntegerOverflow => None,
-            ParseError::Utf8Error(ref err) => Some(err),
-            ParseError::TryFromSliceError(ref err) => Some(err),
-            ParseError::TryFromIntError(ref err) => Some(err),
-        }
-    }
-}
-
-impl core::fmt::Display for ParseError {
+            GenericParseError::BadMagic(_) => None,
+            GenericParseError::UnsupportedElfClass(_) => None,
+            GenericParseError::UnsupportedElfEndianness(_) => None,
+            GenericParseError::UnsupportedVersion(_) => None,
+            GenericParseError::BadOffset(_) => None,
+            GenericParseError::StringTableMissingNul(_) => None,
+            GenericParseError::BadEntsize(_) => None,
+            GenericParseError::UnexpectedSectionType(_) => None,
+            GenericParseError::UnexpectedSegmentType(_) => None,
+            GenericParseError::UnexpectedAlignment(_) => None,
+            GenericParseError::SliceReadError(_) => None,
+            GenericParseError::IntegerOverflow => None,
+            GenericParseError::HashChainCycle(_) => None,
+            GenericParseError::UnsupportedPointerEncoding(_) => None,
+            GenericParseError::UnsupportedCompression(_) => None,
+            GenericParseError::BadArchiveHeader(_) => None,
+            GenericParseError::UnsupportedArchiveFormat(_) => None,
+            GenericParseError::LimitExceeded(_) => None,
+            GenericParseError::UnsupportedRelocation(_) => None,
+            GenericParseError::UnresolvedSymbol(_) => None,
+            GenericParseError::BadShstrndx(_) => None,
+            GenericParseError::Utf8Error(ref err) => Some(err),
+            GenericParseError::TryFromSliceError(ref err) => Some(err),
+            GenericParseError::TryFromIntError(ref err) => Some(err),
+            GenericParseError::Context(ref ctx) => ctx.source(),
+        }
+    }
+}
+
+impl<C: core::fmt::Display> core::fmt::Display for GenericParseError<C> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         match *self {
-            ParseError::BadMagic(ref magic) => {
-                write!(f, "Invalid Magic Bytes: {magic:X?}")
+            GenericParseError::BadMagic(ref magic) => {
+                write!(f, "Invalid Magic Bytes: {magic:X?}")?;
+                match detect_format(magic) {
+                    DetectedFormat::Elf | DetectedFormat::Unknown => Ok(()),
+                    format => write!(f, " (this looks like {})", format.description()),
+                }
             }
-            ParseError::UnsupportedElfClass(class) => {
+            GenericParseError::UnsupportedElfClass(class) => {
                 write!(f, "Unsupported ELF Class: {class}")
             }
-            ParseError::UnsupportedElfEndianness(endianness) => {
+            GenericParseError::UnsupportedElfEndianness(endianness) => {
                 write!(f, "Unsupported ELF Endianness: {endianness}")
             }
-            ParseError::UnsupportedVersion((found, expected)) => {
+            GenericParseError::UnsupportedVersion((found, expected)) => {
                 write!(
                     f,
                     "Unsupported ELF Version field found: {found} expected: {expected}"
                 )
             }
-            ParseError::BadOffset(offset) => {
+            GenericParseError::BadOffset(offset) => {
                 write!(f, "Bad offset: {offset:#X}")
             }
-            ParseError::StringTableMissingNul(offset) => {
+            GenericParseError::StringTableMissingNul(offset) => {
                 write!(
                     f,
                     "Could not find terminating NUL byte starting at offset: {offset:#X}"
                 )
             }
-            ParseError::BadEntsize((found, expected)) => {
+            GenericParseError::BadEntsize((found, expected)) => {
                 write!(
                     f,
                     "Invalid entsize. Expected: {expected:#X}, Found: {found:#X}"
                 )
             }
-            ParseError::UnexpectedSectionType((found, expected)) => {
+            GenericParseError::UnexpectedSectionType((found, expected)) => {
                 write!(
                     f,
                     "Could not interpret section of type {found} as type {expected}"
                 )
             }
-            ParseError::UnexpectedSegmentType((found, expected)) => {
+            GenericParseError::UnexpectedSegmentType((found, expected)) => {
                 write!(
                     f,
                     "Could not interpret section of type {found} as type {expected}"
                 )
             }
-            ParseError::UnexpectedAlignment(align) => {
+            GenericParseError::UnexpectedAlignment(align) => {
                 write!(
                     f,
                     "Could not interpret section with unexpected alignment of {align}"
                 )
             }
-            ParseError::SliceReadError((start, end)) => {
+            GenericParseError::SliceReadError((start, end)) => {
                 write!(f, "Could not read bytes in range [{start:#X}, {end:#X})")
             }
-            ParseError::IntegerOverflow => {
+            GenericParseError::IntegerOverflow => {
                 write!(f, "Integer overflow detected")
             }
-            ParseError::Utf8Error(ref err) => err.fmt(f),
-            ParseError::TryFromSliceError(ref err) => err.fmt(f),
-            ParseError::TryFromIntError(ref err) => err.fmt(f),
+            GenericParseError::HashChainCycle(bucket) => {
+                write!(f, "Hash chain for bucket {bucket} does not terminate")
+            }
+            GenericParseError::UnsupportedPointerEncoding(encoding) => {
+                write!(f, "Unsupported DW_EH_PE pointer encoding: {encoding:#04x}")
+            }
+            GenericParseError::UnsupportedCompression(ch_type) => {
+                write!(f, "Unsupported section compression type: {ch_type}")
+            }
+            GenericParseError::BadArchiveHeader(offset) => {
+                write!(f, "Malformed archive member header at offset {offset:#x}")
+            }
+            GenericParseError::UnsupportedArchiveFormat(offset) => {
+                write!(f, "Unsupported archive format at offset {offset:#x}")
+            }
+            GenericParseError::LimitExceeded((size, limit)) => {
+                write!(
+                    f,
+                    "Table of {size} bytes exceeds the limit of {limit} bytes"
+                )
+            }
+            GenericParseError::UnsupportedRelocation((e_machine, r_type)) => {
+                write!(
+                    f,
+                    "Unsupported relocation type {r_type} for machine {e_machine}"
+                )
+            }
+            GenericParseError::UnresolvedSymbol(index) => {
+                write!(f, "Could not resolve the value of symbol {index}")
+            }
+            GenericParseError::BadShstrndx(index) => {
+                write!(
+                    f,
+                    "Section header string table index {index} does not refer to a string table"
+                )
+            }
+            GenericParseError::Utf8Error(ref err) => err.fmt(f),
+            GenericParseError::TryFromSliceError(ref err) => err.fmt(f),
+            GenericParseError::TryFromIntError(ref err) => err.fmt(f),
             #[cfg(feature = "std")]
-            ParseError::IOError(ref err) => err.fmt(f),
+            GenericParseError::IOError(ref err) => err.fmt(f),
+            GenericParseError::Context(ref ctx) => ctx.fmt(f),
         }
     }
 }
@@ -197,6 +592,26 @@ impl From<std::io::Error> for ParseError {
     }
 }
 
+impl From<core::convert::Infallible> for ParseError {
+    fn from(never: core::convert::Infallible) -> Self {
+        match never {}
+    }
+}
+
+/// Converts a [ParseError] into an [std::io::Error], so that `?` works on parsing results in
+/// `Read` and `Write` based code. An [ParseError::IOError] gives back the wrapped error, while
+/// all other errors become [InvalidData](std::io::ErrorKind::InvalidData) errors wrapping the
+/// [ParseError].
+#[cfg(feature = "std")]
+impl From<ParseError> for std::io::Error {
+    fn from(err: ParseError) -> std::io::Error {
+        match err {
+            ParseError::IOError(err) => err,
+            err => std::io::Error::new(std::io::ErrorKind::InvalidData, err),
+        }
+    }
+}
+
 /// Trait for safely parsing an ELF structure of a given class (32/64 bit) with
 /// an given endian-awareness at the given offset into the data buffer.
 ///
@@ -215,27 +630,62 @@ pub trait ParseAt: Sized {
     ) -> Result<Self, ParseError>;
 
     /// Returns the expected size of the type being parsed for the given ELF class
+    ///
+    /// The sizes of the standard table entries are also available as consts for sizing
+    /// buffers at compile time, e.g. [ELF64_SHDR_SIZE](crate::section::ELF64_SHDR_SIZE) or
+    /// [ELF32_SYM_SIZE](crate::symbol::ELF32_SYM_SIZE).
     fn size_for(class: Class) -> usize;
 
-    /// Checks whether the given entsize matches what we need to parse this type
+    /// Checks whether the given entsize is big enough to hold this type
     ///
-    /// Returns a ParseError for bad/unexpected entsizes that don't match what this type parses.
+    /// Some toolchains pad their table entries, so an entsize larger than what this type parses
+    /// is accepted and returned to be used as the table's stride (see
+    /// [ParsingTable::with_entsize]). Returns a ParseError for entsizes that are too small
+    /// (including zero).
     fn validate_entsize(class: Class, entsize: usize) -> Result<usize, ParseError> {
         let expected = Self::size_for(class);
-        match entsize == expected {
+        match entsize >= expected {
             true => Ok(entsize),
             false => Err(ParseError::BadEntsize((entsize as u64, expected as u64))),
         }
     }
 }
 
+/// Trait for safely serializing an ELF structure of a given class (32/64 bit) with
+/// an given endian-awareness at the given offset into a data buffer.
+///
+/// This is the inverse of [ParseAt], and writes out the exact field widths and layout for
+/// the class. Fields holding values that don't fit in the class's narrower field widths
+/// return a ParseError rather than being silently truncated.
+pub trait SerializeAt {
+    /// Serialize this type by using the given endian-awareness and ELF class layout,
+    /// advancing the offset past the written bytes.
+    fn serialize_at<E: EndianParse>(
+        &self,
+        endian: E,
+        class: Class,
+        offset: &mut usize,
+        data: &mut [u8],
+    ) -> Result<(), ParseError>;
+}
+
 /// Lazy-parsing iterator which wraps bytes and parses out a `P: ParseAt` on each `next()`
-#[derive(Debug)]
+///
+/// The iterator knows how many whole entries its bytes hold, so it's an [ExactSizeIterator]
+/// and [DoubleEndedIterator], and `nth()` (and so `skip()`) jumps straight to the entry
+/// without parsing the ones before it. Trailing bytes that don't make up a whole entry
+/// are ignored.
+///
+/// Entries are parsed at a stride of `entsize` bytes, which is the size of `P` unless the
+/// iterator was created with a larger, padded entry size.
+#[derive(Debug, Clone)]
 pub struct ParsingIterator<'data, E: EndianParse, P: ParseAt> {
     endian: E,
     class: Class,
+    entsize: usize,
     data: &'data [u8],
     offset: usize,
+    end: usize,
     // This struct doesn't technically own a P, but it yields them
     // as it iterates
     pd: PhantomData<&'data P>,
@@ -243,11 +693,21 @@ pub struct ParsingIterator<'data, E: EndianParse, P: ParseAt> {
 
 impl<'data, E: EndianParse, P: ParseAt> ParsingIterator<'data, E, P> {
     pub fn new(endian: E, class: Class, data: &'data [u8]) -> Self {
+        Self::with_entsize(endian, class, P::size_for(class), data)
+    }
+
+    /// Create an iterator over entries which are `entsize` bytes apart, such as for a table
+    /// whose entries have trailing padding. An `entsize` smaller than the size of `P` is
+    /// treated as the size of `P`.
+    pub fn with_entsize(endian: E, class: Class, entsize: usize, data: &'data [u8]) -> Self {
+        let entsize = entsize.max(P::size_for(class));
         ParsingIterator {
             endian,
             class,
+            entsize,
             data,
             offset: 0,
+            end: data.len() - data.len() % entsize,
             pd: PhantomData,
         }
     }
@@ -256,43 +716,267 @@ impl<'data, E: EndianParse, P: ParseAt> ParsingIterator<'data, E, P> {
 impl<'data, E: EndianParse, P: ParseAt> Iterator for ParsingIterator<'data, E, P> {
     type Item = P;
     fn next(&mut self) -> Option<Self::Item> {
-        if self.data.is_empty() {
+        if self.offset >= self.end {
+            return None;
+        }
+
+        let mut offset = self.offset;
+        self.offset += self.entsize;
+        Self::Item::parse_at(self.endian, self.class, &mut offset, self.data).ok()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+
+    fn count(self) -> usize {
+        self.len()
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let skip = n
+            .checked_mul(self.entsize)
+            .and_then(|skip| self.offset.checked_add(skip));
+        self.offset = match skip {
+            Some(offset) if offset < self.end => offset,
+            _ => self.end,
+        };
+        self.next()
+    }
+
+    fn last(mut self) -> Option<Self::Item> {
+        self.next_back()
+    }
+}
+
+impl<'data, E: EndianParse, P: ParseAt> DoubleEndedIterator for ParsingIterator<'data, E, P> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.offset >= self.end {
+            return None;
+        }
+
+        self.end -= self.entsize;
+        let mut offset = self.end;
+        Self::Item::parse_at(self.endian, self.class, &mut offset, self.data).ok()
+    }
+}
+
+impl<'data, E: EndianParse, P: ParseAt> ExactSizeIterator for ParsingIterator<'data, E, P> {
+    fn len(&self) -> usize {
+        self.end.saturating_sub(self.offset) / self.entsize
+    }
+}
+
+impl<'data, E: EndianParse, P: ParseAt> ParsingIterator<'data, E, P> {
+    /// Get an iterator over the remaining entries which yields each entry's parse result,
+    /// rather than ending at the first one which fails to parse.
+    ///
+    /// See [TryParsingIterator]. The trailing partial entry is only yielded (as an error) if
+    /// this iterator hasn't already been advanced from the back.
+    pub fn try_iter(&self) -> TryParsingIterator<'data, E, P> {
+        let whole_end = self.data.len() - self.data.len() % self.entsize;
+        let end = match self.end == whole_end {
+            true => self.data.len(),
+            false => self.end,
+        };
+        TryParsingIterator {
+            endian: self.endian,
+            class: self.class,
+            entsize: self.entsize,
+            data: self.data,
+            offset: self.offset.min(end),
+            end,
+            pd: PhantomData,
+        }
+    }
+}
+
+/// Lazy-parsing iterator which yields the `Result` of parsing each `P: ParseAt` entry in
+/// its bytes, as returned by [ParsingTable::try_iter].
+///
+/// Unlike [ParsingIterator], a bad entry doesn't end the iteration or get silently dropped:
+/// the iterator yields its error and moves on to the next entry. Trailing bytes that don't
+/// make up a whole entry are yielded as a final [ParseError::SliceReadError] with the byte
+/// range the entry would cover, so a truncated table still yields all of its whole entries
+/// followed by an error for the truncated one. The index of an entry is its byte offset
+/// divided by the entry size, or equivalently its position in the iteration.
+///
+/// Since each entry (including the partial one) yields exactly one item, the iterator is an
+/// [ExactSizeIterator].
+#[derive(Debug, Clone)]
+pub struct TryParsingIterator<'data, E: EndianParse, P: ParseAt> {
+    endian: E,
+    class: Class,
+    entsize: usize,
+    data: &'data [u8],
+    offset: usize,
+    end: usize,
+    // This struct doesn't technically own a P, but it yields them
+    // as it iterates
+    pd: PhantomData<&'data P>,
+}
+
+impl<'data, E: EndianParse, P: ParseAt> Iterator for TryParsingIterator<'data, E, P> {
+    type Item = Result<P, ParseError>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.offset >= self.end {
             return None;
         }
 
-        Self::Item::parse_at(self.endian, self.class, &mut self.offset, self.data).ok()
+        let start = self.offset;
+        let entry_end = start + self.entsize;
+        if entry_end > self.end {
+            self.offset = self.end;
+            return Some(Err(ParseError::SliceReadError((start, entry_end))));
+        }
+
+        let mut offset = start;
+        let result = P::parse_at(self.endian, self.class, &mut offset, self.data);
+        self.offset = entry_end;
+        Some(result)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+}
+
+impl<'data, E: EndianParse, P: ParseAt> ExactSizeIterator for TryParsingIterator<'data, E, P> {
+    fn len(&self) -> usize {
+        let remaining = self.end.saturating_sub(self.offset);
+        remaining / self.entsize + (remaining % self.entsize != 0) as usize
     }
 }
 
 /// Lazy-parsing table which wraps bytes and parses out a `P: ParseAt` at a given index into
 /// the table on each `get()`.
+///
+/// Entries are `entsize` bytes apart, which is the size of `P` unless the table was created
+/// with a larger, padded entry size by [ParsingTable::with_entsize].
+///
+/// Indexes are always the entries' positions in the on-disk table, including any null entry
+/// at index 0 (like the null symbol or the null section header), and the iterators yield the
+/// entries in table order without skipping any. [ParsingTable::iter_enumerated] pairs each
+/// entry with its index.
+///
+/// A sub-range of a table from [ParsingTable::entries_from] keeps those indexes: it holds the
+/// entries from its [first_index](ParsingTable::first_index) on, and its `get(index)` is the
+/// full table's `get(index)`. Only its [len](ParsingTable::len) and iterators are limited to
+/// the entries in the sub-range.
 #[derive(Debug, Clone, Copy)]
 pub struct ParsingTable<'data, E: EndianParse, P: ParseAt> {
     endian: E,
     class: Class,
+    entsize: usize,
     data: &'data [u8],
+    // The index in the full table of this table's first entry
+    first_index: usize,
     // This struct doesn't technically own a P, but it yields them
     pd: PhantomData<&'data P>,
 }
 
 impl<'data, E: EndianParse, P: ParseAt> ParsingTable<'data, E, P> {
     pub fn new(endian: E, class: Class, data: &'data [u8]) -> Self {
+        Self::with_entsize(endian, class, P::size_for(class), data)
+    }
+
+    /// Create a table whose entries are `entsize` bytes apart, such as one whose declared
+    /// `sh_entsize` includes trailing padding after each entry. An `entsize` smaller than the
+    /// size of `P` is treated as the size of `P`, so it should be checked with
+    /// [ParseAt::validate_entsize] first.
+    pub fn with_entsize(endian: E, class: Class, entsize: usize, data: &'data [u8]) -> Self {
         ParsingTable {
             endian,
             class,
+            entsize: entsize.max(P::size_for(class)),
             data,
+            first_index: 0,
             pd: PhantomData,
         }
     }
 
+    /// Get the stride between the table's entries
+    pub fn entsize(&self) -> usize {
+        self.entsize
+    }
+
     /// Get a lazy-parsing iterator for the table's bytes
+    ///
+    /// This yields every entry in table order, starting from the table's
+    /// [first_index](ParsingTable::first_index). It only ends early if an entry fails to
+    /// parse; see [ParsingTable::try_iter] to get those errors instead.
     pub fn iter(&self) -> ParsingIterator<'data, E, P> {
-        ParsingIterator::new(self.endian, self.class, self.data)
+        ParsingIterator::with_entsize(self.endian, self.class, self.entsize, self.data)
     }
 
-    /// Returns the number of elements of type P in the table.
+    /// Get a lazy-parsing iterator which yields each entry along with its index in the table.
+    ///
+    /// The indexes are the ones that [SectionHeader](crate::section::SectionHeader) links,
+    /// [Symbol](crate::symbol::Symbol) section indexes, relocation symbol indexes and so on
+    /// refer to.
+    pub fn iter_enumerated(&self) -> EnumeratedParsingIterator<'data, E, P> {
+        EnumeratedParsingIterator {
+            iter: self.iter(),
+            first_index: self.first_index,
+        }
+    }
+
+    /// Like [ParsingTable::iter_enumerated], but starting from the entry at index `start`.
+    /// The yielded indexes are still the entries' indexes in the table.
+    ///
+    /// Returns a [ParseError::BadOffset] with the `start` if it's outside of the table.
+    pub fn iter_enumerated_from(
+        &self,
+        start: usize,
+    ) -> Result<EnumeratedParsingIterator<'data, E, P>, ParseError> {
+        Ok(self.entries_from(start)?.iter_enumerated())
+    }
+
+    /// Get the sub-range of the table's entries from index `start` on. It takes and yields the
+    /// same indexes as this table, so its `get(start)` is the first entry, and its
+    /// [first_index](ParsingTable::first_index) is `start`.
+    ///
+    /// Returns a [ParseError::BadOffset] with the `start` if it's outside of the table. The
+    /// index just past the last entry gives an empty sub-range.
+    pub fn entries_from(&self, start: usize) -> Result<Self, ParseError> {
+        let skip = start
+            .checked_sub(self.first_index)
+            .filter(|skip| *skip <= self.len())
+            .ok_or(ParseError::BadOffset(start as u64))?;
+        Ok(ParsingTable {
+            data: &self.data[skip * self.entsize..],
+            first_index: start,
+            ..*self
+        })
+    }
+
+    /// Get the index of the table's first entry, which is 0 unless the table is a sub-range
+    /// from [ParsingTable::entries_from].
+    pub fn first_index(&self) -> usize {
+        self.first_index
+    }
+
+    /// Get an iterator over the table's entries which yields each entry's parse result, so a
+    /// bad entry or a truncated final entry is reported as an error instead of ending the
+    /// iteration. See [TryParsingIterator].
+    pub fn try_iter(&self) -> TryParsingIterator<'data, E, P> {
+        TryParsingIterator {
+            endian: self.endian,
+            class: self.class,
+            entsize: self.entsize,
+            data: self.data,
+            offset: 0,
+            end: self.data.len(),
+            pd: PhantomData,
+        }
+    }
+
+    /// Returns the number of elements of type P in the table, which for a sub-range from
+    /// [ParsingTable::entries_from] is the number from its first index on.
     pub fn len(&self) -> usize {
-        self.data.len() / P::size_for(self.class)
+        self.data.len() / self.entsize
     }
 
     /// Returns whether the table is empty (contains zero elements).
@@ -301,21 +985,81 @@ impl<'data, E: EndianParse, P: ParseAt> ParsingTable<'data, E, P> {
     }
 
     /// Parse the element at `index` in the table.
+    ///
+    /// Returns a [ParseError::BadOffset] with the `index` if it's outside of the table.
     pub fn get(&self, index: usize) -> Result<P, ParseError> {
-        if self.data.is_empty() {
-            return Err(ParseError::BadOffset(index as u64));
-        }
-
-        let entsize = P::size_for(self.class);
-        let mut start = index
-            .checked_mul(entsize)
+        let position = index
+            .checked_sub(self.first_index)
+            .ok_or(ParseError::BadOffset(index as u64))?;
+        let mut start = position
+            .checked_mul(self.entsize)
             .ok_or(ParseError::IntegerOverflow)?;
-        if start > self.data.len() {
+        if start >= self.data.len() {
             return Err(ParseError::BadOffset(index as u64));
         }
 
         P::parse_at(self.endian, self.class, &mut start, self.data)
     }
+
+    /// Parse the element at `index` in the table, along with the range of bytes within the
+    /// table that it occupies. The range spans the table's whole `entsize`, including any
+    /// padding after the parsed fields, and is relative to the first entry's bytes.
+    ///
+    /// Returns a [ParseError::BadOffset] with the `index` if the entry doesn't fit entirely
+    /// within the table.
+    pub fn get_with_range(&self, index: usize) -> Result<(P, core::ops::Range<usize>), ParseError> {
+        let position = index
+            .checked_sub(self.first_index)
+            .filter(|position| *position < self.len())
+            .ok_or(ParseError::BadOffset(index as u64))?;
+        let start = position * self.entsize;
+        let entry = P::parse_at(self.endian, self.class, &mut start.clone(), self.data)?;
+        Ok((entry, start..start + self.entsize))
+    }
+}
+
+/// Lazy-parsing iterator which yields each entry of a [ParsingTable] along with its index in
+/// the table, see [ParsingTable::iter_enumerated].
+#[derive(Debug, Clone)]
+pub struct EnumeratedParsingIterator<'data, E: EndianParse, P: ParseAt> {
+    iter: ParsingIterator<'data, E, P>,
+    first_index: usize,
+}
+
+impl<'data, E: EndianParse, P: ParseAt> Iterator for EnumeratedParsingIterator<'data, E, P> {
+    type Item = (usize, P);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let entry = self.iter.next()?;
+        // The inner iterator has moved past the entry it just parsed
+        let index = self.iter.offset / self.iter.entsize - 1;
+        Some((self.first_index + index, entry))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let entry = self.iter.nth(n)?;
+        let index = self.iter.offset / self.iter.entsize - 1;
+        Some((self.first_index + index, entry))
+    }
+}
+
+impl<'data, E: EndianParse, P: ParseAt> DoubleEndedIterator
+    for EnumeratedParsingIterator<'data, E, P>
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let entry = self.iter.next_back()?;
+        let index = self.iter.end / self.iter.entsize;
+        Some((self.first_index + index, entry))
+    }
+}
+
+impl<'data, E: EndianParse, P: ParseAt> ExactSizeIterator
+    for EnumeratedParsingIterator<'data, E, P>
+{
 }
 
 impl<'data, E: EndianParse, P: ParseAt> IntoIterator for ParsingTable<'data, E, P> {
@@ -323,13 +1067,226 @@ impl<'data, E: EndianParse, P: ParseAt> IntoIterator for ParsingTable<'data, E,
     type Item = P;
 
     fn into_iter(self) -> Self::IntoIter {
-        ParsingIterator::new(self.endian, self.class, self.data)
+        self.iter()
     }
 }
 
+/// The size of each word in a [WordTable]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WordSize {
+    /// 4-byte words in both classes, like hash table buckets and chains
+    U32,
+    /// Words sized by the ELF class, 4 bytes for ELF32 and 8 bytes for ELF64, like addresses
+    Class,
+}
+
+/// Lazy-parsing table of words, which are either u32s or class-sized addresses.
+///
+/// Lots of ELF data is a plain array of words: init and fini arrays, the GOT, hash table
+/// buckets and chains, and extended section indexes. This reads the words with the file's
+/// byte order and class, and yields them all as u64s. Any trailing bytes that don't make up a
+/// whole entry are ignored.
+#[derive(Debug, Clone, Copy)]
+pub struct WordTable<'data, E: EndianParse> {
+    endian: E,
+    word_size: usize,
+    entsize: usize,
+    data: &'data [u8],
+}
+
+impl<'data, E: EndianParse> WordTable<'data, E> {
+    pub fn new(endian: E, class: Class, size: WordSize, data: &'data [u8]) -> Self {
+        let word_size = Self::size_for(class, size);
+        Self::with_entsize(endian, class, size, word_size, data)
+    }
+
+    /// Create a table whose words are `entsize` bytes apart. An `entsize` smaller than the
+    /// word size is treated as the word size, like [ParsingTable::with_entsize].
+    pub fn with_entsize(
+        endian: E,
+        class: Class,
+        size: WordSize,
+        entsize: usize,
+        data: &'data [u8],
+    ) -> Self {
+        let word_size = Self::size_for(class, size);
+        WordTable {
+            endian,
+            word_size,
+            entsize: entsize.max(word_size),
+            data,
+        }
+    }
+
+    fn size_for(class: Class, size: WordSize) -> usize {
+        match (size, class) {
+            (WordSize::U32, _) | (WordSize::Class, Class::ELF32) => 4,
+            (WordSize::Class, Class::ELF64) => 8,
+        }
+    }
+
+    /// Get the size of each word in bytes
+    pub fn word_size(&self) -> usize {
+        self.word_size
+    }
+
+    /// Get the stride between the table's words
+    pub fn entsize(&self) -> usize {
+        self.entsize
+    }
+
+    /// Returns the number of words in the table.
+    pub fn len(&self) -> usize {
+        self.data.len() / self.entsize
+    }
+
+    /// Returns whether the table is empty (contains zero words).
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Read the word at `index` in the table.
+    ///
+    /// Returns a [ParseError::BadOffset] with the `index` if it's past the end of the table.
+    pub fn get(&self, index: usize) -> Result<u64, ParseError> {
+        if index >= self.len() {
+            return Err(ParseError::BadOffset(index as u64));
+        }
+        let mut offset = index * self.entsize;
+        match self.word_size {
+            4 => Ok(self.endian.parse_u32_at(&mut offset, self.data)? as u64),
+            _ => self.endian.parse_u64_at(&mut offset, self.data),
+        }
+    }
+
+    /// Get an iterator over the table's words, in table order
+    pub fn iter(&self) -> WordIterator<'data, E> {
+        WordIterator {
+            table: *self,
+            index: 0,
+            end: self.len(),
+        }
+    }
+}
+
+impl<'data, E: EndianParse> IntoIterator for WordTable<'data, E> {
+    type IntoIter = WordIterator<'data, E>;
+    type Item = u64;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Iterates over the words of a [WordTable]
+#[derive(Debug, Clone)]
+pub struct WordIterator<'data, E: EndianParse> {
+    table: WordTable<'data, E>,
+    index: usize,
+    end: usize,
+}
+
+impl<'data, E: EndianParse> Iterator for WordIterator<'data, E> {
+    type Item = u64;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index >= self.end {
+            return None;
+        }
+        let word = self.table.get(self.index).ok()?;
+        self.index += 1;
+        Some(word)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.index = self.index.saturating_add(n).min(self.end);
+        self.next()
+    }
+}
+
+impl<'data, E: EndianParse> DoubleEndedIterator for WordIterator<'data, E> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.index >= self.end {
+            return None;
+        }
+        self.end -= 1;
+        self.table.get(self.end).ok()
+    }
+}
+
+impl<'data, E: EndianParse> ExactSizeIterator for WordIterator<'data, E> {
+    fn len(&self) -> usize {
+        self.end - self.index
+    }
+}
+
+/// Copy out the `N` bytes of a fixed-size entry at `offset` with a single bounds check,
+/// advancing the offset past it.
+///
+/// This backs the native-endian fast path for entry tables: once the entry is copied into
+/// an array (which has no alignment requirements), its fields can be read out at constant
+/// offsets with [ne_u16]/[ne_u32]/[ne_u64] instead of bounds-checking each field in turn.
+pub(crate) fn read_entry<const N: usize>(
+    offset: &mut usize,
+    data: &[u8],
+) -> Result<[u8; N], ParseError> {
+    let end = offset.checked_add(N).ok_or(ParseError::IntegerOverflow)?;
+    let buf: [u8; N] = data.get_bytes(*offset..end)?.try_into()?;
+    *offset = end;
+    Ok(buf)
+}
+
+#[inline(always)]
+pub(crate) fn ne_u16<const N: usize>(buf: &[u8; N], at: usize) -> u16 {
+    u16::from_ne_bytes([buf[at], buf[at + 1]])
+}
+
+#[inline(always)]
+pub(crate) fn ne_u32<const N: usize>(buf: &[u8; N], at: usize) -> u32 {
+    u32::from_ne_bytes([buf[at], buf[at + 1], buf[at + 2], buf[at + 3]])
+}
+
+#[inline(always)]
+pub(crate) fn ne_u64<const N: usize>(buf: &[u8; N], at: usize) -> u64 {
+    u64::from_ne_bytes([
+        buf[at],
+        buf[at + 1],
+        buf[at + 2],
+        buf[at + 3],
+        buf[at + 4],
+        buf[at + 5],
+        buf[at + 6],
+        buf[at + 7],
+    ])
+}
+
+/// Get the range of file offsets `offset..offset + size`, using checked integer math.
+///
+/// File offsets stay u64 until they're used to index into memory, so that the tables of a
+/// 64-bit object which lie past 4GiB can still be located (and streamed) on 32-bit hosts.
+pub(crate) fn file_range(offset: u64, size: u64) -> Result<Range<u64>, ParseError> {
+    let end = offset
+        .checked_add(size)
+        .ok_or(ParseError::IntegerOverflow)?;
+    Ok(offset..end)
+}
+
 // Simple convenience extension trait to wrap get() with .ok_or(SliceReadError)
 pub(crate) trait ReadBytesExt<'data> {
     fn get_bytes(self, range: Range<usize>) -> Result<&'data [u8], ParseError>;
+
+    /// Like [ReadBytesExt::get_bytes], for a range of file offsets (see [file_range]). This
+    /// is the point where the offsets are converted to usize.
+    fn get_file_bytes(self, range: Range<u64>) -> Result<&'data [u8], ParseError>
+    where
+        Self: Sized,
+    {
+        self.get_bytes(range.start.try_into()?..range.end.try_into()?)
+    }
 }
 
 impl<'data> ReadBytesExt<'data> for &'data [u8] {
@@ -341,6 +1298,29 @@ impl<'data> ReadBytesExt<'data> for &'data [u8] {
     }
 }
 
+/// Read an unsigned LEB128-encoded integer out of `data` at `offset`, advancing the offset
+/// past it.
+pub(crate) fn parse_uleb128(offset: &mut usize, data: &[u8]) -> Result<u64, ParseError> {
+    let mut value = 0u64;
+    let mut shift = 0u32;
+    loop {
+        let byte = *data
+            .get(*offset)
+            .ok_or(ParseError::SliceReadError((*offset, *offset + 1)))?;
+        *offset += 1;
+
+        let bits = (byte & 0x7f) as u64;
+        if shift >= 64 || (shift == 63 && bits > 1) {
+            return Err(ParseError::IntegerOverflow);
+        }
+        value |= bits << shift;
+        if byte & 0x80 == 0 {
+            return Ok(value);
+        }
+        shift += 7;
+    }
+}
+
 #[cfg(test)]
 pub(crate) fn test_parse_for<E: EndianParse, P: ParseAt + core::fmt::Debug + PartialEq>(
     endian: E,
@@ -378,8 +1358,168 @@ pub(crate) fn test_parse_fuzz_too_short<E: EndianParse, P: ParseAt + core::fmt::
     }
 }
 
+#[cfg(test)]
+pub(crate) fn test_serialize_roundtrip<
+    E: EndianParse,
+    P: ParseAt + SerializeAt + core::fmt::Debug + PartialEq,
+>(
+    endian: E,
+    class: Class,
+) {
+    let size = P::size_for(class);
+    let mut data = vec![0u8; size];
+    for (n, elem) in data.iter_mut().enumerate().take(size) {
+        *elem = n as u8;
+    }
+
+    let mut offset = 0;
+    let entry = P::parse_at(endian, class, &mut offset, data.as_ref()).expect("Failed to parse");
+
+    let mut buf = vec![0u8; size];
+    let mut offset = 0;
+    entry
+        .serialize_at(endian, class, &mut offset, buf.as_mut())
+        .expect("Failed to serialize");
+    assert_eq!(offset, size);
+    assert_eq!(buf, data);
+
+    // Serializing into a buffer that's too small should fail without panicking
+    for n in 0..size {
+        let mut offset = 0;
+        let error = entry
+            .serialize_at(endian, class, &mut offset, &mut buf[..n])
+            .expect_err("Expected an error");
+        assert!(
+            matches!(error, ParseError::SliceReadError(_)),
+            "Unexpected Error type found: {error}"
+        );
+    }
+}
+
+/// Check that `value` serializes to exactly the given `json` (which locks in the field names)
+/// and deserializes back to the same value.
+#[cfg(all(test, feature = "serde"))]
+pub(crate) fn test_serde_roundtrip<T>(value: &T, json: &str)
+where
+    T: serde::Serialize + serde::de::DeserializeOwned + core::fmt::Debug + PartialEq,
+{
+    let serialized = serde_json::to_string(value).expect("Failed to serialize");
+    assert_eq!(serialized, json);
+    let deserialized: T = serde_json::from_str(&serialized).expect("Failed to deserialize");
+    assert_eq!(&deserialized, value);
+}
+
+#[cfg(test)]
+mod context_tests {
+    use super::*;
+
+    #[test]
+    fn context_display() {
+        let err: Result<(), ParseError> = Err(ParseError::BadOffset(0x1c));
+        let err = err.context("section header", Some(12), 0x4a0).unwrap_err();
+        assert_eq!(
+            format!("{err}"),
+            "error parsing section header 12 at file offset 0x4a0: Bad offset: 0x1C"
+        );
+
+        // The innermost context is kept
+        let err: Result<(), ParseError> = Err(err);
+        let err = err.context("section data", None, 0x40).unwrap_err();
+        assert_eq!(
+            format!("{err}"),
+            "error parsing section header 12 at file offset 0x4a0: Bad offset: 0x1C"
+        );
+        assert!(matches!(
+            err.into_root_cause(),
+            BareParseError::BadOffset(0x1c)
+        ));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn context_source() {
+        use std::error::Error;
+        let err: Result<(), ParseError> = Err(ParseError::IntegerOverflow);
+        let err = err.context("symbol table", None, 0).unwrap_err();
+        let source = err.source().expect("should have a source");
+        assert_eq!(format!("{source}"), "Integer overflow detected");
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod error_source_tests {
+    use super::*;
+    use std::error::Error;
+
+    #[test]
+    fn wrapped_errors_are_sources() {
+        let io = std::io::Error::new(std::io::ErrorKind::UnexpectedEof, "short read");
+        let err = ParseError::from(io);
+        let source = err.source().expect("should have a source");
+        let source = source
+            .downcast_ref::<std::io::Error>()
+            .expect("should be an io::Error");
+        assert_eq!(source.kind(), std::io::ErrorKind::UnexpectedEof);
+
+        let bytes = vec![0xc3u8, 0x28];
+        let err = ParseError::from(core::str::from_utf8(&bytes).unwrap_err());
+        let source = err.source().expect("should have a source");
+        assert!(source.is::<core::str::Utf8Error>());
+
+        let err = ParseError::from(u8::try_from(256u32).unwrap_err());
+        let source = err.source().expect("should have a source");
+        assert!(source.is::<core::num::TryFromIntError>());
+
+        let err = ParseError::from(<[u8; 4]>::try_from(&bytes[..]).unwrap_err());
+        let source = err.source().expect("should have a source");
+        assert!(source.is::<core::array::TryFromSliceError>());
+    }
+
+    #[test]
+    fn structural_errors_have_no_source() {
+        assert!(ParseError::BadMagic([0; 4]).source().is_none());
+        assert!(ParseError::BadOffset(7).source().is_none());
+        assert!(ParseError::IntegerOverflow.source().is_none());
+        assert!(ParseError::SliceReadError((0, 4)).source().is_none());
+        assert!(ParseError::UnexpectedSectionType((1, 2)).source().is_none());
+    }
+
+    #[test]
+    fn cause_chain_reaches_the_wrapped_error() {
+        let bytes = vec![0xc3u8, 0x28];
+        let err: Result<(), ParseError> = Err(core::str::from_utf8(&bytes).unwrap_err().into());
+        let err = err.context("string table", None, 0x200).unwrap_err();
+
+        let mut chain = Vec::new();
+        let mut next: Option<&dyn Error> = Some(&err);
+        while let Some(err) = next {
+            chain.push(err.to_string());
+            next = err.source();
+        }
+        assert_eq!(chain.len(), 3);
+        assert!(chain[0].starts_with("error parsing string table at file offset 0x200"));
+        assert!(chain[2].contains("invalid utf-8"));
+    }
+
+    #[test]
+    fn into_io_error() {
+        let io = std::io::Error::new(std::io::ErrorKind::UnexpectedEof, "short read");
+        let err = std::io::Error::from(ParseError::IOError(io));
+        assert_eq!(err.kind(), std::io::ErrorKind::UnexpectedEof);
+
+        let err = std::io::Error::from(ParseError::BadOffset(7));
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+        let inner = err.get_ref().expect("should wrap the ParseError");
+        assert!(matches!(
+            inner.downcast_ref::<ParseError>(),
+            Some(ParseError::BadOffset(7))
+        ));
+    }
+}
+
 #[cfg(test)]
 mod read_bytes_tests {
+    use super::file_range;
     use super::ParseError;
     use super::ReadBytesExt;
 
@@ -390,6 +1530,35 @@ mod read_bytes_tests {
         assert_eq!(subslice, [1, 2]);
     }
 
+    #[test]
+    fn file_range_above_4gib() {
+        let range = file_range(0x1_0000_0000, 0x10).expect("should fit");
+        assert_eq!(range, 0x1_0000_0000..0x1_0000_0010);
+        let range = file_range(0xffff_fff0, 0x20).expect("should fit");
+        assert_eq!(range, 0xffff_fff0..0x1_0000_0010);
+        assert!(matches!(
+            file_range(u64::MAX - 1, 2),
+            Err(ParseError::IntegerOverflow)
+        ));
+    }
+
+    #[test]
+    fn get_file_bytes() {
+        let data = &[0u8, 1, 2, 3];
+        assert_eq!(data.get_file_bytes(1..3).expect("should fit"), [1, 2]);
+        let err = data
+            .get_file_bytes(0x1_0000_0000..0x1_0000_0010)
+            .expect_err("should be out of range");
+        // 64-bit hosts can represent the range, but 32-bit ones can't
+        assert!(
+            matches!(
+                err,
+                ParseError::SliceReadError(_) | ParseError::TryFromIntError(_)
+            ),
+            "Unexpected Error type found: {err}"
+        );
+    }
+
     #[test]
     fn get_bytes_out_of_range_errors() {
         let data = &[0u8, 1, 2, 3];
@@ -401,6 +1570,47 @@ mod read_bytes_tests {
     }
 }
 
+#[cfg(test)]
+mod uleb128_tests {
+    use super::*;
+
+    #[test]
+    fn parse_uleb128_values() {
+        let data = [0x02, 0x7f, 0x80, 0x01, 0xe5, 0x8e, 0x26];
+        let mut offset = 0;
+        assert_eq!(parse_uleb128(&mut offset, &data).unwrap(), 2);
+        assert_eq!(parse_uleb128(&mut offset, &data).unwrap(), 127);
+        assert_eq!(parse_uleb128(&mut offset, &data).unwrap(), 128);
+        assert_eq!(parse_uleb128(&mut offset, &data).unwrap(), 624485);
+        assert_eq!(offset, data.len());
+
+        let max = [0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x01];
+        let mut offset = 0;
+        assert_eq!(parse_uleb128(&mut offset, &max).unwrap(), u64::MAX);
+    }
+
+    #[test]
+    fn parse_uleb128_truncated() {
+        let mut offset = 0;
+        let result = parse_uleb128(&mut offset, &[0x80, 0x80]);
+        assert!(
+            matches!(result, Err(ParseError::SliceReadError((2, 3)))),
+            "Unexpected Error type found: {result:?}"
+        );
+    }
+
+    #[test]
+    fn parse_uleb128_too_big() {
+        let data = [0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x02];
+        let mut offset = 0;
+        let result = parse_uleb128(&mut offset, &data);
+        assert!(
+            matches!(result, Err(ParseError::IntegerOverflow)),
+            "Unexpected Error type found: {result:?}"
+        );
+    }
+}
+
 #[cfg(test)]
 mod parsing_table_tests {
     use crate::endian::{AnyEndian, BigEndian, LittleEndian};
@@ -412,9 +1622,14 @@ mod parsing_table_tests {
     #[test]
     fn test_u32_validate_entsize() {
         assert!(matches!(u32::validate_entsize(Class::ELF32, 4), Ok(4)));
+        assert!(matches!(u32::validate_entsize(Class::ELF32, 8), Ok(8)));
         assert!(matches!(
-            u32::validate_entsize(Class::ELF32, 8),
-            Err(ParseError::BadEntsize((8, 4)))
+            u32::validate_entsize(Class::ELF32, 2),
+            Err(ParseError::BadEntsize((2, 4)))
+        ));
+        assert!(matches!(
+            u32::validate_entsize(Class::ELF32, 0),
+            Err(ParseError::BadEntsize((0, 4)))
         ));
     }
 
@@ -493,10 +1708,293 @@ mod parsing_table_tests {
         assert!(matches!(table.get(7), Err(ParseError::BadOffset(7))));
     }
 
+    #[test]
+    fn test_u32_table_get_one_past_end() {
+        let data = vec![0u8, 1, 2, 3, 4, 5, 6, 7];
+        let table = U32Table::new(LittleEndian, Class::ELF32, data.as_ref());
+        assert!(matches!(table.get(2), Err(ParseError::BadOffset(2))));
+    }
+
+    #[test]
+    fn test_u32_iter_exact_size() {
+        let data = vec![0u8, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13];
+        let table = U32Table::new(LittleEndian, Class::ELF32, data.as_ref());
+        let mut iter = table.iter();
+        assert_eq!(iter.len(), 3);
+        assert_eq!(iter.size_hint(), (3, Some(3)));
+        assert_eq!(iter.next(), Some(0x03020100));
+        assert_eq!(iter.len(), 2);
+        assert_eq!(iter.clone().count(), 2);
+        assert_eq!(iter.next(), Some(0x07060504));
+        assert_eq!(iter.next(), Some(0x0b0a0908));
+        // The trailing partial entry is ignored
+        assert_eq!(iter.len(), 0);
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn test_u32_iter_double_ended() {
+        let data = vec![0u8, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12];
+        let table = U32Table::new(BigEndian, Class::ELF32, data.as_ref());
+        let mut iter = table.iter();
+        assert_eq!(iter.next_back(), Some(0x08090a0b));
+        assert_eq!(iter.next(), Some(0x00010203));
+        assert_eq!(iter.len(), 1);
+        assert_eq!(iter.next_back(), Some(0x04050607));
+        assert_eq!(iter.next_back(), None);
+        assert_eq!(iter.next(), None);
+
+        let values: Vec<u32> = table.iter().rev().collect();
+        assert_eq!(values, [0x08090a0b, 0x04050607, 0x00010203]);
+        assert_eq!(table.iter().last(), Some(0x08090a0b));
+    }
+
+    #[test]
+    fn test_u32_iter_nth() {
+        let data = vec![0u8, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11];
+        let table = U32Table::new(LittleEndian, Class::ELF32, data.as_ref());
+        let mut iter = table.iter();
+        assert_eq!(iter.nth(1), Some(0x07060504));
+        assert_eq!(iter.len(), 1);
+        assert_eq!(iter.nth(1), None);
+        assert_eq!(iter.next(), None);
+
+        let mut iter = table.iter();
+        assert_eq!(iter.nth(3), None);
+        assert_eq!(iter.len(), 0);
+        assert_eq!(table.iter().nth(usize::MAX), None);
+        let values: Vec<u32> = table.iter().skip(2).collect();
+        assert_eq!(values, [0x0b0a0908]);
+    }
+
+    #[test]
+    fn test_u32_try_iter_truncated() {
+        let data = vec![0u8, 1, 2, 3, 4, 5, 6, 7, 8, 9];
+        let table = U32Table::new(LittleEndian, Class::ELF32, data.as_ref());
+        let mut iter = table.try_iter();
+        assert_eq!(iter.len(), 3);
+        assert_eq!(iter.size_hint(), (3, Some(3)));
+        assert!(matches!(iter.next(), Some(Ok(0x03020100))));
+        assert!(matches!(iter.next(), Some(Ok(0x07060504))));
+        assert_eq!(iter.len(), 1);
+        assert!(matches!(
+            iter.next(),
+            Some(Err(ParseError::SliceReadError((8, 12))))
+        ));
+        assert_eq!(iter.len(), 0);
+        assert!(iter.next().is_none());
+
+        // Whole tables have no trailing error
+        let table = U32Table::new(LittleEndian, Class::ELF32, &data[..8]);
+        assert_eq!(table.try_iter().count(), 2);
+        assert!(table.try_iter().all(|entry| entry.is_ok()));
+    }
+
+    #[test]
+    fn test_u32_iter_try_iter() {
+        let data = vec![0u8, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13];
+        let table = U32Table::new(BigEndian, Class::ELF32, data.as_ref());
+        let mut iter = table.iter();
+        iter.next();
+        let values: Vec<_> = iter.try_iter().collect();
+        assert!(matches!(
+            values[..],
+            [
+                Ok(0x04050607),
+                Ok(0x08090a0b),
+                Err(ParseError::SliceReadError((12, 16)))
+            ]
+        ));
+
+        // Entries taken from the back have already been accounted for
+        iter.next_back();
+        assert_eq!(iter.try_iter().len(), 1);
+        assert!(matches!(iter.try_iter().next(), Some(Ok(0x04050607))));
+    }
+
+    #[test]
+    fn test_u32_table_padded_entries() {
+        let data = vec![
+            0u8, 1, 2, 3, 0xaa, 0xaa, 4, 5, 6, 7, 0xaa, 0xaa, 8, 9, 10, 11,
+        ];
+        let table = U32Table::with_entsize(LittleEndian, Class::ELF32, 6, data.as_ref());
+        assert_eq!(table.len(), 2);
+        assert_eq!(table.entsize(), 6);
+        assert!(matches!(table.get(1), Ok(0x07060504)));
+        assert!(matches!(table.get(3), Err(ParseError::BadOffset(3))));
+        assert_eq!(table.iter().collect::<Vec<_>>(), [0x03020100, 0x07060504]);
+        assert_eq!(
+            table.iter().rev().collect::<Vec<_>>(),
+            [0x07060504, 0x03020100]
+        );
+        assert_eq!(table.iter().nth(1), Some(0x07060504));
+
+        // The trailing entry is missing its padding
+        let mut iter = table.try_iter();
+        assert_eq!(iter.len(), 3);
+        assert!(matches!(
+            iter.nth(2),
+            Some(Err(ParseError::SliceReadError((12, 18))))
+        ));
+
+        // Entsizes smaller than the type are treated as the type's size
+        let table = U32Table::with_entsize(LittleEndian, Class::ELF32, 1, data.as_ref());
+        assert_eq!(table.len(), 4);
+    }
+
+    #[test]
+    fn test_u32_table_get_with_range() {
+        let data = vec![
+            0u8, 1, 2, 3, 0xaa, 0xaa, 4, 5, 6, 7, 0xaa, 0xaa, 8, 9, 10, 11,
+        ];
+        let table = U32Table::with_entsize(LittleEndian, Class::ELF32, 6, data.as_ref());
+        assert!(matches!(table.get_with_range(0), Ok((0x03020100, range)) if range == (0..6)));
+        assert!(matches!(table.get_with_range(1), Ok((0x07060504, range)) if range == (6..12)));
+        // The trailing entry can be parsed by get(), but isn't a whole entry
+        assert!(matches!(table.get(2), Ok(0x0b0a0908)));
+        assert!(matches!(
+            table.get_with_range(2),
+            Err(ParseError::BadOffset(2))
+        ));
+    }
+
     #[test]
     fn test_u32_table_get_unaligned() {
         let data = [0u8, 1, 2, 3, 4, 5, 6, 7];
         let table = U32Table::new(LittleEndian, Class::ELF32, data.get(1..).unwrap());
         assert!(matches!(table.get(0), Ok(0x04030201)));
     }
+
+    #[test]
+    fn test_u32_table_iter_enumerated() {
+        let data = [
+            0u8, 1, 2, 3, 0xaa, 0xaa, 4, 5, 6, 7, 0xaa, 0xaa, 8, 9, 10, 11, 0xaa,
+        ];
+        let table = U32Table::with_entsize(LittleEndian, Class::ELF32, 6, data.as_ref());
+        assert_eq!(
+            table.iter_enumerated().collect::<Vec<_>>(),
+            [(0, 0x03020100), (1, 0x07060504)]
+        );
+        assert_eq!(
+            table.iter_enumerated().rev().collect::<Vec<_>>(),
+            [(1, 0x07060504), (0, 0x03020100)]
+        );
+        assert_eq!(table.iter_enumerated().nth(1), Some((1, 0x07060504)));
+        assert_eq!(table.iter_enumerated().len(), 2);
+
+        // Starting part way through keeps the indexes in the table
+        let iter = table.iter_enumerated_from(1).expect("should start");
+        assert_eq!(iter.len(), 1);
+        assert_eq!(iter.rev().collect::<Vec<_>>(), [(1, 0x07060504)]);
+        let mut iter = table.iter_enumerated_from(2).expect("should start");
+        assert_eq!(iter.next(), None);
+        assert!(matches!(
+            table.iter_enumerated_from(3),
+            Err(ParseError::BadOffset(3))
+        ));
+
+        // and so do sub-ranges
+        let rest = table.entries_from(1).expect("should start");
+        assert_eq!((rest.first_index(), rest.len()), (1, 1));
+        assert_eq!(rest.get(1).expect("should parse"), 0x07060504);
+        assert!(matches!(rest.get(0), Err(ParseError::BadOffset(0))));
+        assert!(matches!(rest.get_with_range(1), Ok((0x07060504, range)) if range == (0..6)));
+        assert!(matches!(
+            rest.get_with_range(2),
+            Err(ParseError::BadOffset(2))
+        ));
+        assert_eq!(
+            rest.iter_enumerated().collect::<Vec<_>>(),
+            [(1, 0x07060504)]
+        );
+        assert_eq!(rest.iter().collect::<Vec<_>>(), [0x07060504]);
+        assert!(matches!(
+            rest.iter_enumerated_from(0),
+            Err(ParseError::BadOffset(0))
+        ));
+        let empty = rest.entries_from(2).expect("should start");
+        assert!(empty.is_empty());
+        assert_eq!(empty.first_index(), 2);
+    }
+}
+
+#[cfg(test)]
+mod word_table_tests {
+    use super::*;
+    use crate::endian::{BigEndian, LittleEndian};
+
+    #[test]
+    fn u32_words() {
+        let data = [0, 0, 0, 1, 0, 0, 0, 2, 0xff, 0xff, 0xff, 0xff, 0x12];
+        for class in [Class::ELF32, Class::ELF64] {
+            let table = WordTable::new(BigEndian, class, WordSize::U32, &data);
+            assert_eq!(table.word_size(), 4);
+            assert_eq!(table.len(), 3);
+            assert_eq!(table.get(2).unwrap(), 0xffffffff);
+            assert!(matches!(table.get(3), Err(ParseError::BadOffset(3))));
+            assert_eq!(table.iter().collect::<Vec<_>>(), vec![1, 2, 0xffffffff]);
+        }
+    }
+
+    #[test]
+    fn class_words() {
+        let mut data = Vec::new();
+        for word in [0x401000u64, u64::MAX, 7] {
+            data.extend_from_slice(&word.to_le_bytes());
+        }
+        let table = WordTable::new(LittleEndian, Class::ELF64, WordSize::Class, &data);
+        assert_eq!(table.word_size(), 8);
+        assert_eq!(table.iter().len(), 3);
+        assert_eq!(
+            table.iter().rev().collect::<Vec<_>>(),
+            vec![7, u64::MAX, 0x401000]
+        );
+        assert_eq!(table.iter().nth(1), Some(u64::MAX));
+
+        let table = WordTable::new(LittleEndian, Class::ELF32, WordSize::Class, &data[..8]);
+        assert_eq!(table.iter().collect::<Vec<_>>(), vec![0x401000, 0]);
+        assert!(WordTable::new(LittleEndian, Class::ELF64, WordSize::Class, &data[..7]).is_empty());
+    }
+
+    #[test]
+    fn padded_words() {
+        let data = [1, 0, 0, 0, 0xaa, 0xaa, 2, 0, 0, 0, 0xaa, 0xaa];
+        let table = WordTable::with_entsize(LittleEndian, Class::ELF32, WordSize::U32, 6, &data);
+        assert_eq!(table.entsize(), 6);
+        assert_eq!(table.iter().collect::<Vec<_>>(), vec![1, 2]);
+
+        // A stride smaller than the word is treated as the word size
+        let table = WordTable::with_entsize(LittleEndian, Class::ELF32, WordSize::U32, 1, &data);
+        assert_eq!(table.entsize(), 4);
+        assert_eq!(table.len(), 3);
+    }
+}
+
+#[cfg(test)]
+mod size_const_tests {
+    use super::*;
+    use crate::compression::{CompressionHeader, ELF32_CHDR_SIZE, ELF64_CHDR_SIZE};
+    use crate::dynamic::{Dyn, ELF32_DYN_SIZE, ELF64_DYN_SIZE};
+    use crate::relocation::{
+        Rel, Rela, ELF32_RELA_SIZE, ELF32_REL_SIZE, ELF64_RELA_SIZE, ELF64_REL_SIZE,
+    };
+    use crate::section::{SectionHeader, ELF32_SHDR_SIZE, ELF64_SHDR_SIZE};
+    use crate::segment::{ProgramHeader, ELF32_PHDR_SIZE, ELF64_PHDR_SIZE};
+    use crate::symbol::{Symbol, ELF32_SYM_SIZE, ELF64_SYM_SIZE};
+
+    fn check<P: ParseAt>(elf32: usize, elf64: usize) {
+        assert_eq!(P::size_for(Class::ELF32), elf32);
+        assert_eq!(P::size_for(Class::ELF64), elf64);
+    }
+
+    #[test]
+    fn consts_match_size_for() {
+        check::<SectionHeader>(ELF32_SHDR_SIZE, ELF64_SHDR_SIZE);
+        check::<ProgramHeader>(ELF32_PHDR_SIZE, ELF64_PHDR_SIZE);
+        check::<Symbol>(ELF32_SYM_SIZE, ELF64_SYM_SIZE);
+        check::<Rel>(ELF32_REL_SIZE, ELF64_REL_SIZE);
+        check::<Rela>(ELF32_RELA_SIZE, ELF64_RELA_SIZE);
+        check::<Dyn>(ELF32_DYN_SIZE, ELF64_DYN_SIZE);
+        check::<CompressionHeader>(ELF32_CHDR_SIZE, ELF64_CHDR_SIZE);
+    }
 }
@@ -0,0 +1,889 @@
+//! Editing the interpreter and dynamic string entries of linked objects, like `patchelf`
+//!
+//! [set_interpreter] replaces the `PT_INTERP` path, while [set_soname], [replace_needed] and
+//! [set_runpath] rewrite the strings referred to by `DT_SONAME`, `DT_NEEDED` and `DT_RUNPATH`.
+//!
+//! New strings are written in place when they fit and nothing else refers to the bytes they
+//! overwrite. Otherwise they're appended to the end of the file in a new `PT_LOAD` segment, along
+//! with a copy of the program header table that includes the new segment (and a copy of
+//! `.dynstr` for the dynamic strings). Nothing already in the file gets moved, so all other
+//! addresses stay valid. Cases which can't be handled safely are refused with a [PatchError].
+//!
+//! Example usage:
+//! ```
+//! use elf::endian::AnyEndian;
+//! use elf::patch::set_interpreter;
+//! use elf::ElfBytes;
+//!
+//! let path = std::path::PathBuf::from("sample-objects/basic.x86_64");
+//! let mut file_data = std::fs::read(path).expect("Could not read file.");
+//! set_interpreter(&mut file_data, "/opt/my-libc/lib/ld-linux-x86-64.so.2").expect("should patch");
+//!
+//! let file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("should parse");
+//! assert_eq!(
+//!     file.interpreter_str().expect("should parse"),
+//!     Some("/opt/my-libc/lib/ld-linux-x86-64.so.2")
+//! );
+//! ```
+use crate::abi;
+use crate::dynamic::Dyn;
+use crate::endian::{AnyEndian, EndianParse};
+use crate::file::{Class, FileHeader};
+use crate::parse::{ParseAt, ParseError, SerializeAt};
+use crate::section::SectionHeader;
+use crate::segment::ProgramHeader;
+use crate::symbol::Symbol;
+use crate::ElfBytes;
+
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::vec::Vec;
+
+/// The ways that patching an object can fail
+#[derive(Debug)]
+pub enum PatchError {
+    /// The object couldn't be parsed
+    Parse(ParseError),
+    /// The object has no `PT_INTERP` segment to patch
+    NoInterpreter,
+    /// The object has no `PT_DYNAMIC` segment, or it's missing the `DT_STRTAB`/`DT_STRSZ` entries
+    NoDynamic,
+    /// The object has no dynamic entry with the given tag to rewrite
+    MissingDynamicEntry(i64),
+    /// The object has no `DT_NEEDED` entry naming the library to replace
+    NeededNotFound,
+    /// Patching the object would need changes that can't be made safely, for the given reason
+    Unsupported(&'static str),
+}
+
+impl From<ParseError> for PatchError {
+    fn from(err: ParseError) -> Self {
+        PatchError::Parse(err)
+    }
+}
+
+impl core::fmt::Display for PatchError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            PatchError::Parse(err) => write!(f, "could not parse object: {err}"),
+            PatchError::NoInterpreter => write!(f, "object has no PT_INTERP segment"),
+            PatchError::NoDynamic => {
+                write!(f, "object has no dynamic string table")
+            }
+            PatchError::MissingDynamicEntry(tag) => {
+                write!(f, "object has no dynamic entry with tag {tag:#x}")
+            }
+            PatchError::NeededNotFound => {
+                write!(f, "object has no DT_NEEDED entry for the library")
+            }
+            PatchError::Unsupported(reason) => write!(f, "unsupported patch: {reason}"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for PatchError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            PatchError::Parse(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+/// The headers of the object being patched, copied out so the data can be modified
+struct Image {
+    ehdr: FileHeader<AnyEndian>,
+    phdrs: Vec<ProgramHeader>,
+    shdrs: Vec<SectionHeader>,
+}
+
+impl Image {
+    fn parse(data: &[u8]) -> Result<Self, PatchError> {
+        let file = ElfBytes::<AnyEndian>::minimal_parse(data)?;
+        if file.ehdr.e_phnum == abi::PN_XNUM {
+            return Err(PatchError::Unsupported(
+                "program header table uses extended numbering",
+            ));
+        }
+        let phdrs = match file.segments() {
+            Some(phdrs) => phdrs.iter().collect(),
+            None => return Err(PatchError::Unsupported("object has no program headers")),
+        };
+        let shdrs = match file.section_headers() {
+            Some(shdrs) => shdrs.iter().collect(),
+            None => Vec::new(),
+        };
+        Ok(Image {
+            ehdr: file.ehdr,
+            phdrs,
+            shdrs,
+        })
+    }
+
+    fn endian(&self) -> AnyEndian {
+        self.ehdr.endianness
+    }
+
+    fn class(&self) -> Class {
+        self.ehdr.class
+    }
+
+    fn find_phdr(&self, p_type: u32) -> Option<usize> {
+        self.phdrs.iter().position(|phdr| phdr.p_type == p_type)
+    }
+
+    /// Translate a virtual address to a file offset through the `PT_LOAD` segments
+    fn vaddr_to_offset(&self, vaddr: u64) -> Option<usize> {
+        let phdr = self.phdrs.iter().find(|phdr| {
+            phdr.p_type == abi::PT_LOAD
+                && vaddr >= phdr.p_vaddr
+                && vaddr - phdr.p_vaddr < phdr.p_filesz
+        })?;
+        usize::try_from(phdr.p_offset + (vaddr - phdr.p_vaddr)).ok()
+    }
+
+    /// Append `contents` to the file in a new read-only `PT_LOAD` segment, preceded by room
+    /// for the program header table which then includes the new segment. Returns the file
+    /// offset and virtual address of the appended contents.
+    ///
+    /// Linux kernels before 5.18 pass the program headers' address to the dynamic linker in
+    /// `AT_PHDR` as the first `PT_LOAD` segment's address plus `e_phoff`, so the new segment
+    /// keeps the first one's difference between address and offset. It goes in the first gap
+    /// between the other segments' pages that it fits in, and the file is padded out to the
+    /// offset that this puts it at. That's refused if it needs more than [MAX_PADDING] bytes.
+    ///
+    /// The program header table gets written out by [Image::write_headers].
+    fn append_load(
+        &mut self,
+        data: &mut Vec<u8>,
+        contents: &[u8],
+    ) -> Result<(u64, u64), PatchError> {
+        let phdr_index = self.find_phdr(abi::PT_PHDR);
+        if phdr_index.is_none() && self.find_phdr(abi::PT_INTERP).is_some() {
+            // The dynamic linker finds the program headers through PT_PHDR
+            return Err(PatchError::Unsupported(
+                "executable has no PT_PHDR segment to describe the moved program headers",
+            ));
+        }
+        let first_load = match self.find_phdr(abi::PT_LOAD) {
+            Some(index) => self.phdrs[index],
+            None => return Err(PatchError::Unsupported("object has no PT_LOAD segments")),
+        };
+        let phnum = u16::try_from(self.phdrs.len() + 1)
+            .ok()
+            .filter(|phnum| *phnum < abi::PN_XNUM)
+            .ok_or(PatchError::Unsupported("too many program headers"))?;
+        let table_size = (ProgramHeader::size_for(self.class()) * phnum as usize) as u64;
+        let size = table_size + contents.len() as u64;
+
+        // The segments' pages mustn't overlap for any page size up to 64KiB, and the new
+        // segment's address is congruent to its offset modulo the first one's alignment
+        let align = first_load.p_align.max(0x1000);
+        let page = align.min(0x10000);
+        let delta = first_load
+            .p_vaddr
+            .checked_sub(first_load.p_offset)
+            .filter(|delta| delta % align == 0)
+            .ok_or(PatchError::Unsupported(
+                "first PT_LOAD segment's address isn't aligned with its offset",
+            ))?;
+        let word_align = match self.class() {
+            Class::ELF32 => 4,
+            Class::ELF64 => 8,
+        };
+        let mut offset =
+            align_up(data.len() as u64, word_align).ok_or(ParseError::IntegerOverflow)?;
+        loop {
+            let vaddr = offset
+                .checked_add(delta)
+                .ok_or(ParseError::IntegerOverflow)?;
+            let end = vaddr
+                .checked_add(size)
+                .and_then(|end| align_up(end, page))
+                .ok_or(ParseError::IntegerOverflow)?;
+            let start = vaddr - vaddr % page;
+            let overlap = self.phdrs.iter().find(|phdr| {
+                let seg_start = phdr.p_vaddr - phdr.p_vaddr % page;
+                let seg_end = phdr.p_vaddr.saturating_add(phdr.p_memsz);
+                phdr.p_type == abi::PT_LOAD && seg_start < end && seg_end > start
+            });
+            // Move past the overlapping segment, which is always forwards
+            match overlap {
+                Some(phdr) => {
+                    offset = phdr
+                        .p_vaddr
+                        .checked_add(phdr.p_memsz)
+                        .and_then(|seg_end| align_up(seg_end, page))
+                        .and_then(|seg_end| seg_end.checked_sub(delta))
+                        .ok_or(ParseError::IntegerOverflow)?;
+                }
+                None => break,
+            }
+        }
+        if offset - data.len() as u64 > MAX_PADDING {
+            return Err(PatchError::Unsupported(
+                "no room for another segment near the end of the file",
+            ));
+        }
+        let vaddr = offset + delta;
+
+        // PT_LOAD segments are sorted by address
+        let index = self
+            .phdrs
+            .iter()
+            .rposition(|phdr| phdr.p_type == abi::PT_LOAD && phdr.p_vaddr < vaddr)
+            .map_or(0, |index| index + 1);
+        self.phdrs.insert(
+            index,
+            ProgramHeader {
+                p_type: abi::PT_LOAD,
+                p_offset: offset,
+                p_vaddr: vaddr,
+                p_paddr: vaddr,
+                p_filesz: size,
+                p_memsz: size,
+                p_flags: abi::PF_R,
+                p_align: align,
+            },
+        );
+        let phdr_index = phdr_index.map(|phdr_index| match phdr_index >= index {
+            true => phdr_index + 1,
+            false => phdr_index,
+        });
+        if let Some(index) = phdr_index {
+            let phdr = &mut self.phdrs[index];
+            phdr.p_offset = offset;
+            phdr.p_vaddr = vaddr;
+            phdr.p_paddr = vaddr;
+            phdr.p_filesz = table_size;
+            phdr.p_memsz = table_size;
+        }
+        self.ehdr.e_phoff = offset;
+        self.ehdr.e_phnum = phnum;
+
+        let contents_offset = offset + table_size;
+        data.resize(contents_offset as usize, 0);
+        data.extend_from_slice(contents);
+        Ok((contents_offset, vaddr + table_size))
+    }
+
+    /// Write out the file header, program header table, and section header table
+    fn write_headers(&self, data: &mut [u8]) -> Result<(), PatchError> {
+        let (endian, class) = (self.endian(), self.class());
+        self.ehdr.serialize_at(endian, class, &mut 0, data)?;
+        let mut offset = self.ehdr.e_phoff as usize;
+        for phdr in self.phdrs.iter() {
+            phdr.serialize_at(endian, class, &mut offset, data)?;
+        }
+        let mut offset = self.ehdr.e_shoff as usize;
+        for shdr in self.shdrs.iter() {
+            shdr.serialize_at(endian, class, &mut offset, data)?;
+        }
+        Ok(())
+    }
+
+    /// Point the section header that described the data at `old_offset` at its new location
+    fn move_section(&mut self, sh_type: u32, old_offset: u64, offset: u64, addr: u64, size: u64) {
+        let shdr = self
+            .shdrs
+            .iter_mut()
+            .find(|shdr| shdr.sh_type == sh_type && shdr.sh_offset == old_offset);
+        if let Some(shdr) = shdr {
+            shdr.sh_offset = offset;
+            shdr.sh_addr = addr;
+            shdr.sh_size = size;
+        }
+    }
+}
+
+/// The most zero bytes that [Image::append_load] pads a file with to keep its program
+/// headers at the address that old kernels expect
+const MAX_PADDING: u64 = 16 << 20;
+
+fn align_up(value: u64, align: u64) -> Option<u64> {
+    let rem = value % align;
+    match rem {
+        0 => Some(value),
+        _ => value.checked_add(align - rem),
+    }
+}
+
+fn nul_terminated(value: &str) -> Result<Vec<u8>, PatchError> {
+    if value.as_bytes().contains(&0) {
+        return Err(PatchError::Unsupported("string contains a NUL byte"));
+    }
+    let mut bytes = Vec::with_capacity(value.len() + 1);
+    bytes.extend_from_slice(value.as_bytes());
+    bytes.push(0);
+    Ok(bytes)
+}
+
+/// Replace the program interpreter path in the `PT_INTERP` segment (and `.interp` section).
+///
+/// Returns a [PatchError::NoInterpreter] if the object doesn't have one, as for statically
+/// linked executables and most shared libraries.
+pub fn set_interpreter(data: &mut Vec<u8>, interp: &str) -> Result<(), PatchError> {
+    let new = nul_terminated(interp)?;
+    let mut image = Image::parse(data)?;
+    let index = image
+        .find_phdr(abi::PT_INTERP)
+        .ok_or(PatchError::NoInterpreter)?;
+    let old = image.phdrs[index];
+
+    if new.len() as u64 <= old.p_filesz {
+        let (start, end) = old.get_file_data_range()?;
+        let slot = data
+            .get_mut(start..end)
+            .ok_or(ParseError::SliceReadError((start, end)))?;
+        slot.fill(0);
+        slot[..new.len()].copy_from_slice(&new);
+        return Ok(());
+    }
+
+    let (offset, vaddr) = image.append_load(data, &new)?;
+    // The indexes shifted if the new PT_LOAD went before the PT_INTERP
+    let index = image
+        .find_phdr(abi::PT_INTERP)
+        .ok_or(PatchError::NoInterpreter)?;
+    let phdr = &mut image.phdrs[index];
+    phdr.p_offset = offset;
+    phdr.p_vaddr = vaddr;
+    phdr.p_paddr = vaddr;
+    phdr.p_filesz = new.len() as u64;
+    phdr.p_memsz = new.len() as u64;
+    image.move_section(
+        abi::SHT_PROGBITS,
+        old.p_offset,
+        offset,
+        vaddr,
+        new.len() as u64,
+    );
+    image.write_headers(data)
+}
+
+/// A dynamic entry, along with the file offset of its value
+#[derive(Debug, Clone, Copy)]
+struct DynEntry {
+    d_tag: i64,
+    d_val: u64,
+    val_offset: usize,
+}
+
+/// The dynamic section and the string table it refers to
+struct DynamicInfo {
+    entries: Vec<DynEntry>,
+    strtab_vaddr: u64,
+    strtab_offset: usize,
+    strtab_size: usize,
+}
+
+impl DynamicInfo {
+    fn parse(image: &Image, data: &[u8]) -> Result<Self, PatchError> {
+        let index = image
+            .find_phdr(abi::PT_DYNAMIC)
+            .ok_or(PatchError::NoDynamic)?;
+        let (start, end) = image.phdrs[index].get_file_data_range()?;
+        let class = image.class();
+        let entsize = Dyn::size_for(class);
+        let val_size = entsize / 2;
+
+        let mut entries = Vec::new();
+        let mut offset = start;
+        while offset + entsize <= end {
+            let entry_offset = offset;
+            let entry = Dyn::parse_at(image.endian(), class, &mut offset, data)?;
+            if entry.d_tag == abi::DT_NULL {
+                break;
+            }
+            entries.push(DynEntry {
+                d_tag: entry.d_tag,
+                d_val: entry.d_val(),
+                val_offset: entry_offset + val_size,
+            });
+        }
+
+        let value = |d_tag| {
+            entries
+                .iter()
+                .find(|entry| entry.d_tag == d_tag)
+                .map(|entry| entry.d_val)
+        };
+        let strtab_vaddr = value(abi::DT_STRTAB).ok_or(PatchError::NoDynamic)?;
+        let strtab_size: usize = value(abi::DT_STRSZ)
+            .ok_or(PatchError::NoDynamic)?
+            .try_into()
+            .map_err(ParseError::from)?;
+        let strtab_offset = image
+            .vaddr_to_offset(strtab_vaddr)
+            .ok_or(PatchError::Unsupported(
+                "DT_STRTAB is not in a PT_LOAD segment",
+            ))?;
+        Ok(DynamicInfo {
+            entries,
+            strtab_vaddr,
+            strtab_offset,
+            strtab_size,
+        })
+    }
+
+    fn strtab<'data>(&self, data: &'data [u8]) -> Result<&'data [u8], ParseError> {
+        let end = self
+            .strtab_offset
+            .checked_add(self.strtab_size)
+            .ok_or(ParseError::IntegerOverflow)?;
+        data.get(self.strtab_offset..end)
+            .ok_or(ParseError::SliceReadError((self.strtab_offset, end)))
+    }
+
+    fn string<'data>(&self, data: &'data [u8], offset: u64) -> Result<&'data [u8], ParseError> {
+        let strtab = self.strtab(data)?;
+        let start = offset as usize;
+        let rest = strtab.get(start..).ok_or(ParseError::BadOffset(offset))?;
+        let len = rest
+            .iter()
+            .position(|byte| *byte == 0)
+            .ok_or(ParseError::StringTableMissingNul(offset))?;
+        Ok(&rest[..len])
+    }
+
+    /// Get the file offset of the field holding a string table offset in each `Elf_Verneed`,
+    /// paired with whether it's the `vn_file` (library name) rather than a version name.
+    fn verneed_refs(&self, image: &Image, data: &[u8]) -> Result<Vec<(usize, bool)>, PatchError> {
+        let mut refs = Vec::new();
+        let (vaddr, count) = match (self.value(abi::DT_VERNEED), self.value(abi::DT_VERNEEDNUM)) {
+            (Some(vaddr), Some(count)) => (vaddr, count),
+            _ => return Ok(refs),
+        };
+        let endian = image.endian();
+        let mut need = image.vaddr_to_offset(vaddr).ok_or(PatchError::Unsupported(
+            "DT_VERNEED is not in a PT_LOAD segment",
+        ))?;
+        for _ in 0..count {
+            let mut offset = need + 4;
+            let aux_count = endian.parse_u16_at(&mut (need + 2), data)?;
+            refs.push((offset, true));
+            let _vn_file = endian.parse_u32_at(&mut offset, data)?;
+            let vn_aux = endian.parse_u32_at(&mut offset, data)? as usize;
+            let vn_next = endian.parse_u32_at(&mut offset, data)? as usize;
+
+            let mut aux = need + vn_aux;
+            for _ in 0..aux_count {
+                refs.push((aux + 8, false));
+                let vna_next = endian.parse_u32_at(&mut (aux + 12), data)? as usize;
+                aux += vna_next;
+            }
+            if vn_next == 0 {
+                break;
+            }
+            need += vn_next;
+        }
+        Ok(refs)
+    }
+
+    /// Get the file offset of the `vda_name` field of each `Elf_Verdaux`
+    fn verdef_refs(&self, image: &Image, data: &[u8]) -> Result<Vec<usize>, PatchError> {
+        let mut refs = Vec::new();
+        let (vaddr, count) = match (self.value(abi::DT_VERDEF), self.value(abi::DT_VERDEFNUM)) {
+            (Some(vaddr), Some(count)) => (vaddr, count),
+            _ => return Ok(refs),
+        };
+        let endian = image.endian();
+        let mut def = image.vaddr_to_offset(vaddr).ok_or(PatchError::Unsupported(
+            "DT_VERDEF is not in a PT_LOAD segment",
+        ))?;
+        for _ in 0..count {
+            let aux_count = endian.parse_u16_at(&mut (def + 6), data)?;
+            let vd_aux = endian.parse_u32_at(&mut (def + 12), data)? as usize;
+            let vd_next = endian.parse_u32_at(&mut (def + 16), data)? as usize;
+            let mut aux = def + vd_aux;
+            for _ in 0..aux_count {
+                refs.push(aux);
+                let vda_next = endian.parse_u32_at(&mut (aux + 4), data)? as usize;
+                aux += vda_next;
+            }
+            if vd_next == 0 {
+                break;
+            }
+            def += vd_next;
+        }
+        Ok(refs)
+    }
+
+    fn value(&self, d_tag: i64) -> Option<u64> {
+        self.entries
+            .iter()
+            .find(|entry| entry.d_tag == d_tag)
+            .map(|entry| entry.d_val)
+    }
+}
+
+/// The dynamic tags whose values are offsets into the dynamic string table
+const DYNAMIC_STRING_TAGS: [i64; 7] = [
+    abi::DT_NEEDED,
+    abi::DT_SONAME,
+    abi::DT_RPATH,
+    abi::DT_RUNPATH,
+    abi::DT_CONFIG,
+    abi::DT_DEPAUDIT,
+    abi::DT_AUDIT,
+];
+
+fn write_word(image: &Image, data: &mut [u8], offset: usize, value: u64) -> Result<(), ParseError> {
+    let mut offset = offset;
+    match image.class() {
+        Class::ELF32 => {
+            let value = u32::try_from(value)?;
+            image.endian().write_u32_at(&mut offset, data, value)
+        }
+        Class::ELF64 => image.endian().write_u64_at(&mut offset, data, value),
+    }
+}
+
+/// Point the `targets` (indexes of dynamic entries) at the string `value`, also updating the
+/// `vn_file` of any `Elf_Verneed` that named the old string if `update_verneed` is set.
+fn set_dynamic_string(
+    data: &mut Vec<u8>,
+    image: &mut Image,
+    dynamic: &DynamicInfo,
+    targets: &[usize],
+    value: &str,
+    update_verneed: bool,
+) -> Result<(), PatchError> {
+    let new = nul_terminated(value)?;
+    let endian = image.endian();
+    let old_offset = dynamic.entries[targets[0]].d_val;
+    if targets
+        .iter()
+        .any(|index| dynamic.entries[*index].d_val != old_offset)
+    {
+        return Err(PatchError::Unsupported(
+            "the rewritten entries refer to different strings",
+        ));
+    }
+    let old_len = dynamic.string(data, old_offset)?.len() as u64;
+
+    // Collect all the other string table references, to check whether the old string's bytes
+    // can be overwritten in place. The .dynsym can only be found through the section headers.
+    let dynsym = image
+        .shdrs
+        .iter()
+        .find(|shdr| shdr.sh_type == abi::SHT_DYNSYM)
+        .copied();
+    let mut refs: Vec<u64> = Vec::new();
+    for (index, entry) in dynamic.entries.iter().enumerate() {
+        if DYNAMIC_STRING_TAGS.contains(&entry.d_tag) && !targets.contains(&index) {
+            refs.push(entry.d_val);
+        }
+    }
+    let verneed_refs = dynamic.verneed_refs(image, data)?;
+    let mut vn_file_refs = Vec::new();
+    for (offset, is_file) in verneed_refs.iter() {
+        let name = endian.parse_u32_at(&mut offset.clone(), data)? as u64;
+        if *is_file && update_verneed && name == old_offset {
+            vn_file_refs.push(*offset);
+        } else {
+            refs.push(name);
+        }
+    }
+    for offset in dynamic.verdef_refs(image, data)? {
+        refs.push(endian.parse_u32_at(&mut offset.clone(), data)? as u64);
+    }
+    if let Some(shdr) = dynsym {
+        let (start, end) = shdr.get_data_range()?;
+        let entsize = (shdr.sh_entsize as usize).max(Symbol::size_for(image.class()));
+        let mut entry = start;
+        while entry + entsize <= end {
+            let sym = Symbol::parse_at(endian, image.class(), &mut entry.clone(), data)?;
+            refs.push(sym.st_name as u64);
+            entry += entsize;
+        }
+    }
+    // Linkers merge strings with common suffixes, so a string starting before the old one can
+    // also run into it
+    let strtab = dynamic.strtab(data)?;
+    let overlaps = refs.iter().any(|name| match name.cmp(&old_offset) {
+        core::cmp::Ordering::Less => !strtab[*name as usize..old_offset as usize].contains(&0),
+        _ => *name <= old_offset + old_len,
+    });
+
+    if new.len() as u64 <= old_len + 1 && dynsym.is_some() && !overlaps {
+        let start = dynamic.strtab_offset + old_offset as usize;
+        let slot = &mut data[start..start + old_len as usize + 1];
+        slot.fill(0);
+        slot[..new.len()].copy_from_slice(&new);
+        return Ok(());
+    }
+
+    // Append a copy of the string table with the new string on the end
+    let mut strtab = dynamic.strtab(data)?.to_vec();
+    let new_offset = strtab.len() as u64;
+    strtab.extend_from_slice(&new);
+    let (offset, vaddr) = image.append_load(data, &strtab)?;
+    for entry in dynamic.entries.iter() {
+        match entry.d_tag {
+            abi::DT_STRTAB => write_word(image, data, entry.val_offset, vaddr)?,
+            abi::DT_STRSZ => write_word(image, data, entry.val_offset, strtab.len() as u64)?,
+            _ => (),
+        }
+    }
+    for index in targets {
+        write_word(image, data, dynamic.entries[*index].val_offset, new_offset)?;
+    }
+    for offset in vn_file_refs {
+        endian.write_u32_at(&mut offset.clone(), data, new_offset as u32)?;
+    }
+    let old_strtab_offset = dynamic.strtab_offset as u64;
+    let shdr = image
+        .shdrs
+        .iter_mut()
+        .find(|shdr| shdr.sh_type == abi::SHT_STRTAB && shdr.sh_addr == dynamic.strtab_vaddr);
+    if let Some(shdr) = shdr {
+        debug_assert_eq!(shdr.sh_offset, old_strtab_offset);
+        shdr.sh_offset = offset;
+        shdr.sh_addr = vaddr;
+        shdr.sh_size = strtab.len() as u64;
+    }
+    image.write_headers(data)
+}
+
+/// Rewrite the string of the object's `DT_SONAME` entry.
+///
+/// Returns a [PatchError::MissingDynamicEntry] if the object has no `DT_SONAME`, since adding a
+/// dynamic entry would mean moving the dynamic section.
+pub fn set_soname(data: &mut Vec<u8>, soname: &str) -> Result<(), PatchError> {
+    set_tag_string(data, abi::DT_SONAME, soname)
+}
+
+/// Rewrite the string of the object's `DT_RUNPATH` entry.
+///
+/// Returns a [PatchError::MissingDynamicEntry] if the object has no `DT_RUNPATH` (such as
+/// objects using the older `DT_RPATH`), since adding a dynamic entry would mean moving the
+/// dynamic section.
+pub fn set_runpath(data: &mut Vec<u8>, runpath: &str) -> Result<(), PatchError> {
+    set_tag_string(data, abi::DT_RUNPATH, runpath)
+}
+
+fn set_tag_string(data: &mut Vec<u8>, d_tag: i64, value: &str) -> Result<(), PatchError> {
+    let mut image = Image::parse(data)?;
+    let dynamic = DynamicInfo::parse(&image, data)?;
+    let targets: Vec<usize> = dynamic
+        .entries
+        .iter()
+        .enumerate()
+        .filter(|(_, entry)| entry.d_tag == d_tag)
+        .map(|(index, _)| index)
+        .collect();
+    if targets.is_empty() {
+        return Err(PatchError::MissingDynamicEntry(d_tag));
+    }
+    set_dynamic_string(data, &mut image, &dynamic, &targets, value, false)
+}
+
+/// Replace the `DT_NEEDED` entry for the library named `old` with one for `new`, also updating
+/// the library name in its symbol version requirements.
+pub fn replace_needed(data: &mut Vec<u8>, old: &str, new: &str) -> Result<(), PatchError> {
+    let mut image = Image::parse(data)?;
+    let dynamic = DynamicInfo::parse(&image, data)?;
+    let mut targets = Vec::new();
+    for (index, entry) in dynamic.entries.iter().enumerate() {
+        if entry.d_tag == abi::DT_NEEDED && dynamic.string(data, entry.d_val)? == old.as_bytes() {
+            targets.push(index);
+        }
+    }
+    if targets.is_empty() {
+        return Err(PatchError::NeededNotFound);
+    }
+    set_dynamic_string(data, &mut image, &dynamic, &targets, new, true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn read(path: &str) -> Vec<u8> {
+        std::fs::read(std::path::PathBuf::from(path)).expect("Could not read file.")
+    }
+
+    fn interpreter(data: &[u8]) -> Vec<u8> {
+        let file = ElfBytes::<AnyEndian>::minimal_parse(data).expect("should parse");
+        let phdr = file
+            .segments()
+            .expect("should have segments")
+            .iter()
+            .find(|phdr| phdr.p_type == abi::PT_INTERP)
+            .expect("should have PT_INTERP");
+        file.segment_data(&phdr).expect("should read").to_vec()
+    }
+
+    fn dynamic_string(data: &[u8], d_tag: i64) -> Vec<u8> {
+        let image = Image::parse(data).expect("should parse");
+        let dynamic = DynamicInfo::parse(&image, data).expect("should parse");
+        let offset = dynamic.value(d_tag).expect("should have entry");
+        dynamic.string(data, offset).expect("should read").to_vec()
+    }
+
+    #[test]
+    fn set_interpreter_in_place() {
+        let mut data = read("sample-objects/basic.x86_64");
+        let len = data.len();
+        set_interpreter(&mut data, "/lib/ld.so").expect("should patch");
+        assert_eq!(data.len(), len);
+        let interp = interpreter(&data);
+        assert_eq!(interp.len(), 28);
+        assert_eq!(&interp[..11], b"/lib/ld.so\0");
+        assert!(interp[11..].iter().all(|byte| *byte == 0));
+    }
+
+    #[test]
+    fn set_interpreter_appends_load() {
+        let mut data = read("sample-objects/basic.x86_64");
+        let path = "/opt/some/much/longer/path/to/ld-linux-x86-64.so.2";
+        set_interpreter(&mut data, path).expect("should patch");
+        let mut expected = path.as_bytes().to_vec();
+        expected.push(0);
+        assert_eq!(interpreter(&data), expected);
+
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&data).expect("should parse");
+        let phdrs: Vec<ProgramHeader> = file
+            .segments()
+            .expect("should have segments")
+            .iter()
+            .collect();
+        assert_eq!(phdrs.len(), 9);
+        // The new segment fits in the gap between the text and data segments
+        let load = phdrs[3];
+        assert_eq!(load.p_type, abi::PT_LOAD);
+        assert_eq!(load.p_offset, file.ehdr.e_phoff);
+        assert_eq!(load.p_offset + load.p_filesz, data.len() as u64);
+        assert_eq!(load.p_vaddr % load.p_align, load.p_offset % load.p_align);
+        assert!(phdrs[2].p_vaddr + phdrs[2].p_memsz <= load.p_vaddr);
+        assert!(load.p_vaddr + load.p_memsz <= phdrs[4].p_vaddr);
+        // The program headers can be found from the first PT_LOAD segment and e_phoff
+        let first_load = phdrs[2];
+        assert_eq!(first_load.p_type, abi::PT_LOAD);
+        assert_eq!(
+            load.p_vaddr - load.p_offset,
+            first_load.p_vaddr - first_load.p_offset
+        );
+        assert_eq!(phdrs[0].p_type, abi::PT_PHDR);
+        assert_eq!(phdrs[0].p_vaddr, load.p_vaddr);
+
+        let (shdrs, strtab) = file.section_headers_with_strtab().expect("should parse");
+        let (shdrs, strtab) = (
+            shdrs.expect("should have shdrs"),
+            strtab.expect("should have strtab"),
+        );
+        let interp = shdrs
+            .iter()
+            .find(|shdr| matches!(strtab.get(shdr.sh_name as usize), Ok(".interp")))
+            .expect("should have .interp");
+        assert_eq!(interp.sh_offset, phdrs[1].p_offset);
+        assert_eq!(interp.sh_addr, phdrs[1].p_vaddr);
+        assert_eq!(interp.sh_size, expected.len() as u64);
+    }
+
+    #[test]
+    fn set_runpath_refuses_large_padding() {
+        let mut data = read("sample-objects/patch.x86_64.so");
+        let mut image = Image::parse(&data).expect("should parse");
+        let last_load = image
+            .phdrs
+            .iter_mut()
+            .rev()
+            .find(|phdr| phdr.p_type == abi::PT_LOAD)
+            .expect("should have PT_LOAD");
+        // Keeping the first PT_LOAD's address minus offset would need a huge gap in the file
+        last_load.p_memsz = 1 << 32;
+        image.write_headers(&mut data).expect("should write");
+
+        let len = data.len();
+        assert!(matches!(
+            set_runpath(&mut data, "$ORIGIN/../lib:/usr/local/lib"),
+            Err(PatchError::Unsupported(_))
+        ));
+        assert_eq!(data.len(), len);
+    }
+
+    #[test]
+    fn set_interpreter_without_interpreter() {
+        let mut data = read("sample-objects/patch.x86_64.so");
+        assert!(matches!(
+            set_interpreter(&mut data, "/lib/ld.so"),
+            Err(PatchError::NoInterpreter)
+        ));
+        assert!(matches!(
+            set_interpreter(&mut data, "/lib/ld\0.so"),
+            Err(PatchError::Unsupported(_))
+        ));
+    }
+
+    #[test]
+    fn set_soname_in_place() {
+        let mut data = read("sample-objects/patch.x86_64.so");
+        let len = data.len();
+        set_soname(&mut data, "libpatch.so.2").expect("should patch");
+        assert_eq!(data.len(), len);
+        assert_eq!(dynamic_string(&data, abi::DT_SONAME), b"libpatch.so.2");
+        assert_eq!(dynamic_string(&data, abi::DT_RUNPATH), b"/opt/patch/lib");
+    }
+
+    #[test]
+    fn set_runpath_appends_strtab() {
+        let mut data = read("sample-objects/patch.x86_64.so");
+        let len = data.len();
+        set_runpath(&mut data, "$ORIGIN/../lib:/usr/local/lib").expect("should patch");
+        assert!(data.len() > len);
+        assert_eq!(
+            dynamic_string(&data, abi::DT_RUNPATH),
+            b"$ORIGIN/../lib:/usr/local/lib"
+        );
+        assert_eq!(dynamic_string(&data, abi::DT_SONAME), b"libpatch.so.1");
+        assert_eq!(dynamic_string(&data, abi::DT_NEEDED), b"libc.so.6");
+
+        // The .dynstr section header follows the string table
+        let image = Image::parse(&data).expect("should parse");
+        let dynamic = DynamicInfo::parse(&image, &data).expect("should parse");
+        let dynstr = image
+            .shdrs
+            .iter()
+            .find(|shdr| {
+                shdr.sh_type == abi::SHT_STRTAB && shdr.sh_flags & abi::SHF_ALLOC as u64 != 0
+            })
+            .expect("should have .dynstr");
+        assert_eq!(dynstr.sh_addr, dynamic.strtab_vaddr);
+        assert_eq!(dynstr.sh_offset, dynamic.strtab_offset as u64);
+        assert_eq!(dynstr.sh_size, dynamic.strtab_size as u64);
+    }
+
+    #[test]
+    fn replace_needed_updates_verneed() {
+        let mut data = read("sample-objects/patch.x86_64.so");
+        replace_needed(&mut data, "libc.so.6", "libc-renamed.so.6").expect("should patch");
+        assert_eq!(dynamic_string(&data, abi::DT_NEEDED), b"libc-renamed.so.6");
+
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&data).expect("should parse");
+        // The version table finds the strings through the .dynstr section header
+        let requirement = file
+            .symbol_version_table()
+            .expect("should parse")
+            .expect("should have version table")
+            .get_requirement(1)
+            .expect("should parse")
+            .expect("should have requirement");
+        assert_eq!(requirement.file, "libc-renamed.so.6");
+        assert_eq!(requirement.name, "GLIBC_2.2.5");
+    }
+
+    #[test]
+    fn missing_entries() {
+        let mut data = read("sample-objects/basic.x86_64");
+        assert!(matches!(
+            set_soname(&mut data, "libbasic.so"),
+            Err(PatchError::MissingDynamicEntry(abi::DT_SONAME))
+        ));
+        assert!(matches!(
+            replace_needed(&mut data, "libm.so.6", "libfoo.so"),
+            Err(PatchError::NeededNotFound)
+        ));
+    }
+}
@@ -0,0 +1,82 @@
+//! Mapping PLT entries to the functions they call, as found by [ElfBytes::plt_entries](crate::ElfBytes::plt_entries)
+//!
+//! Each PLT entry jumps through a GOT slot which the dynamic linker fills in by applying the
+//! corresponding `DT_JMPREL` relocation, and the linker lays out the entries in the same order
+//! as the relocations. So the n'th relocation's symbol is the target of the n'th PLT entry,
+//! which lives at a fixed offset from the start of the PLT for each machine.
+//!
+//! Example usage:
+//! ```
+//! use elf::endian::AnyEndian;
+//! use elf::plt::PltTarget;
+//! use elf::ElfBytes;
+//!
+//! let path = std::path::PathBuf::from("sample-objects/basic.x86_64");
+//! let file_data = std::fs::read(path).expect("Could not read file.");
+//! let file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("Open test1");
+//!
+//! let entries = file
+//!     .plt_entries()
+//!     .expect("relocations should parse")
+//!     .expect("should have PLT entries");
+//! assert_eq!(entries[0].addr, 0x4003c0);
+//! assert!(matches!(entries[0].target, PltTarget::Symbol { name: Some("memset"), .. }));
+//! ```
+use crate::abi;
+
+/// What a PLT entry calls
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PltTarget<'data> {
+    /// The entry calls the symbol at the given index in the `.dynsym`. The name is an empty
+    /// Option if the dynamic symbol table couldn't be found.
+    Symbol {
+        index: u32,
+        name: Option<&'data str>,
+    },
+    /// The entry calls the function chosen by the ifunc resolver at the given address, for an
+    /// `IRELATIVE` relocation. These don't have a symbol.
+    IRelative { resolver: u64 },
+}
+
+/// An entry in the `.plt` (or `.plt.sec`) section
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PltEntry<'data> {
+    /// The virtual address of the entry
+    pub addr: u64,
+    /// What the entry calls
+    pub target: PltTarget<'data>,
+}
+
+/// How a machine lays out its PLT
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct PltLayout {
+    /// The size of the header entry (PLT0) before the first function's entry
+    pub header_size: u64,
+    /// The size of each function's entry
+    pub entry_size: u64,
+    /// The relocation type for ifunc resolvers
+    pub irelative: u32,
+    /// The relocation type for TLS descriptors, which go in `DT_JMPREL` without a PLT entry
+    pub tlsdesc: u32,
+}
+
+impl PltLayout {
+    /// Get the PLT layout for the machine, if it's supported
+    pub fn for_machine(e_machine: u16) -> Option<Self> {
+        match e_machine {
+            abi::EM_X86_64 => Some(PltLayout {
+                header_size: 16,
+                entry_size: 16,
+                irelative: abi::R_X86_64_IRELATIVE,
+                tlsdesc: abi::R_X86_64_TLSDESC,
+            }),
+            abi::EM_AARCH64 => Some(PltLayout {
+                header_size: 32,
+                entry_size: 16,
+                irelative: abi::R_AARCH64_IRELATIVE,
+                tlsdesc: abi::R_AARCH64_TLSDESC,
+            }),
+            _ => None,
+        }
+    }
+}
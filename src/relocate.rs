@@ -0,0 +1,363 @@
+//! Applying dynamic relocations to a loaded memory image
+//!
+//! This handles the simple relocations that a static-PIE style loader needs to process: the
+//! relative, absolute, and GOT/PLT slot relocations, along with [SHT_RELR](abi::SHT_RELR)
+//! relative relocations. Relocations that need more machinery, like TLS or copy relocations,
+//! are reported as [ParseError::UnsupportedRelocation].
+//!
+//! Example usage:
+//! ```
+//! use elf::abi;
+//! use elf::endian::LittleEndian;
+//! use elf::file::Class;
+//! use elf::relocate::{RelocationEntry, Relocator};
+//! use elf::relocation::Rela;
+//!
+//! let mut image = [0u8; 16];
+//! let relocs = [
+//!     RelocationEntry::Rela(Rela {
+//!         r_offset: 0,
+//!         r_sym: 0,
+//!         r_type: abi::R_X86_64_RELATIVE,
+//!         r_addend: 0x40,
+//!     }),
+//!     RelocationEntry::Rela(Rela {
+//!         r_offset: 8,
+//!         r_sym: 1,
+//!         r_type: abi::R_X86_64_GLOB_DAT,
+//!         r_addend: 0,
+//!     }),
+//! ];
+//!
+//! let relocator = Relocator::new(LittleEndian, Class::ELF64, abi::EM_X86_64);
+//! relocator
+//!     .apply_relocations(&mut image, 0x7000_0000, relocs, |sym| match sym {
+//!         1 => Some(0x1234),
+//!         _ => None,
+//!     })
+//!     .expect("should apply");
+//! assert_eq!(image[..8], 0x7000_0040u64.to_le_bytes());
+//! assert_eq!(image[8..], 0x1234u64.to_le_bytes());
+//! ```
+use crate::abi;
+use crate::endian::EndianParse;
+use crate::file::Class;
+use crate::parse::ParseError;
+use crate::relocation::{Rel, Rela};
+
+/// A single relocation to apply with [Relocator::apply_relocations].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RelocationEntry {
+    /// A relocation whose addend is read from the field being relocated
+    Rel(Rel),
+    /// A relocation with an explicit addend
+    Rela(Rela),
+    /// A relative relocation of the word at the given address, as yielded by a
+    /// [RelrIterator](crate::relocation::RelrIterator)
+    Relr(u64),
+}
+
+/// How a relocation type computes the value that it writes, in terms of the load base `B`,
+/// the symbol value `S` and the addend `A`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RelocationKind {
+    /// Nothing gets written
+    None,
+    /// `B + A`, written in a field of `size` bytes
+    Relative { size: usize },
+    /// `S`, written in a field of `size` bytes. The addend is ignored.
+    Symbol { size: usize },
+    /// `S + A`, written in a field of `size` bytes
+    Absolute { size: usize },
+}
+
+impl RelocationKind {
+    /// Classify the given relocation type for the machine, or `None` if it's not supported.
+    pub fn of(e_machine: u16, r_type: u32) -> Option<Self> {
+        match e_machine {
+            abi::EM_X86_64 => match r_type {
+                abi::R_X86_64_NONE => Some(RelocationKind::None),
+                abi::R_X86_64_RELATIVE => Some(RelocationKind::Relative { size: 8 }),
+                abi::R_X86_64_GLOB_DAT | abi::R_X86_64_JUMP_SLOT => {
+                    Some(RelocationKind::Symbol { size: 8 })
+                }
+                abi::R_X86_64_64 => Some(RelocationKind::Absolute { size: 8 }),
+                abi::R_X86_64_32 => Some(RelocationKind::Absolute { size: 4 }),
+                _ => None,
+            },
+            abi::EM_AARCH64 => match r_type {
+                abi::R_AARCH64_NONE => Some(RelocationKind::None),
+                abi::R_AARCH64_RELATIVE => Some(RelocationKind::Relative { size: 8 }),
+                // The AArch64 ELF ABI defines these as S + A
+                abi::R_AARCH64_GLOB_DAT | abi::R_AARCH64_JUMP_SLOT | abi::R_AARCH64_ABS64 => {
+                    Some(RelocationKind::Absolute { size: 8 })
+                }
+                abi::R_AARCH64_ABS32 => Some(RelocationKind::Absolute { size: 4 }),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+}
+
+/// Applies relocations for an object of a given byte order, class and machine.
+#[derive(Debug, Clone, Copy)]
+pub struct Relocator<E: EndianParse> {
+    endian: E,
+    class: Class,
+    e_machine: u16,
+}
+
+impl<E: EndianParse> Relocator<E> {
+    /// Create a new relocator for objects with the given byte order, class and `e_machine`.
+    pub fn new(endian: E, class: Class, e_machine: u16) -> Self {
+        Relocator {
+            endian,
+            class,
+            e_machine,
+        }
+    }
+
+    /// Apply the relocations to the memory image of an object which has been loaded at `base`.
+    ///
+    /// The `image` holds the object's segments as laid out in memory, so the byte at
+    /// `image[n]` is the one at virtual address `n` before the object was relocated, and each
+    /// relocation's `r_offset` indexes into it. The `resolver` gets the index of a
+    /// relocation's symbol and returns the symbol's runtime value, or `None` if it can't be
+    /// resolved. Relative relocations don't need a symbol and don't call the `resolver`.
+    ///
+    /// Relocations are applied in order, stopping at the first one that fails with:
+    /// * [ParseError::UnsupportedRelocation] for a type that [RelocationKind::of] doesn't know
+    /// * [ParseError::UnresolvedSymbol] if the `resolver` returned `None`
+    /// * [ParseError::BadOffset] if the relocated field isn't within `image`
+    /// * [ParseError::IntegerOverflow] if the value doesn't fit in a 4-byte field
+    pub fn apply_relocations<I, F>(
+        &self,
+        image: &mut [u8],
+        base: u64,
+        relocs: I,
+        mut resolver: F,
+    ) -> Result<(), ParseError>
+    where
+        I: IntoIterator<Item = RelocationEntry>,
+        F: FnMut(u32) -> Option<u64>,
+    {
+        for reloc in relocs {
+            let (r_offset, r_sym, r_type, addend) = match reloc {
+                RelocationEntry::Rela(rela) => {
+                    (rela.r_offset, rela.r_sym, rela.r_type, Some(rela.r_addend))
+                }
+                RelocationEntry::Rel(rel) => (rel.r_offset, rel.r_sym, rel.r_type, None),
+                RelocationEntry::Relr(addr) => {
+                    let size = match self.class {
+                        Class::ELF32 => 4,
+                        Class::ELF64 => 8,
+                    };
+                    let addend = self.read(image, addr, size)?;
+                    self.write(image, addr, size, base.wrapping_add(addend as u64))?;
+                    continue;
+                }
+            };
+
+            let kind = RelocationKind::of(self.e_machine, r_type)
+                .ok_or(ParseError::UnsupportedRelocation((self.e_machine, r_type)))?;
+            let size = match kind {
+                RelocationKind::None => continue,
+                RelocationKind::Relative { size }
+                | RelocationKind::Symbol { size }
+                | RelocationKind::Absolute { size } => size,
+            };
+            let addend = match addend {
+                Some(addend) => addend,
+                None => self.read(image, r_offset, size)?,
+            };
+
+            let value = match kind {
+                RelocationKind::Relative { .. } => base.wrapping_add(addend as u64),
+                RelocationKind::Symbol { .. } => {
+                    resolver(r_sym).ok_or(ParseError::UnresolvedSymbol(r_sym))?
+                }
+                _ => resolver(r_sym)
+                    .ok_or(ParseError::UnresolvedSymbol(r_sym))?
+                    .wrapping_add(addend as u64),
+            };
+            self.write(image, r_offset, size, value)?;
+        }
+        Ok(())
+    }
+
+    /// Read the sign-extended value of the `size` byte field at `addr`
+    fn read(&self, image: &[u8], addr: u64, size: usize) -> Result<i64, ParseError> {
+        let mut offset: usize = addr.try_into().map_err(|_| ParseError::BadOffset(addr))?;
+        let value = match size {
+            4 => self.endian.parse_i32_at(&mut offset, image).map(i64::from),
+            _ => self.endian.parse_i64_at(&mut offset, image),
+        };
+        value.map_err(|_| ParseError::BadOffset(addr))
+    }
+
+    fn write(
+        &self,
+        image: &mut [u8],
+        addr: u64,
+        size: usize,
+        value: u64,
+    ) -> Result<(), ParseError> {
+        let mut offset: usize = addr.try_into().map_err(|_| ParseError::BadOffset(addr))?;
+        let written = match size {
+            4 => {
+                // Accept values which fit either zero- or sign-extended
+                let fits = value <= u32::MAX as u64 || (value as i64) >= i32::MIN as i64;
+                if !fits {
+                    return Err(ParseError::IntegerOverflow);
+                }
+                self.endian.write_u32_at(&mut offset, image, value as u32)
+            }
+            _ => self.endian.write_u64_at(&mut offset, image, value),
+        };
+        written.map_err(|_| ParseError::BadOffset(addr))
+    }
+}
+
+#[cfg(test)]
+mod apply_tests {
+    use super::*;
+    use crate::endian::{BigEndian, LittleEndian};
+    use crate::relocation::RelrIterator;
+
+    fn rela(r_offset: u64, r_sym: u32, r_type: u32, r_addend: i64) -> RelocationEntry {
+        RelocationEntry::Rela(Rela {
+            r_offset,
+            r_sym,
+            r_type,
+            r_addend,
+        })
+    }
+
+    fn resolve(sym: u32) -> Option<u64> {
+        match sym {
+            1 => Some(0x1000),
+            2 => Some(0xffff_ffff_0000_0000),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn x86_64() {
+        let mut image = [0u8; 40];
+        image[32..40].copy_from_slice(&0x10u64.to_le_bytes());
+        let relocs = [
+            rela(0, 0, abi::R_X86_64_RELATIVE, 0x20),
+            rela(8, 1, abi::R_X86_64_JUMP_SLOT, 0x99),
+            rela(16, 1, abi::R_X86_64_64, -8),
+            rela(24, 1, abi::R_X86_64_32, 4),
+            rela(28, 0, abi::R_X86_64_NONE, 0),
+            // Implicit addend
+            RelocationEntry::Rel(Rel {
+                r_offset: 32,
+                r_sym: 0,
+                r_type: abi::R_X86_64_RELATIVE,
+            }),
+        ];
+        let relocator = Relocator::new(LittleEndian, Class::ELF64, abi::EM_X86_64);
+        relocator
+            .apply_relocations(&mut image, 0x5000, relocs, resolve)
+            .expect("should apply");
+
+        assert_eq!(image[0..8], 0x5020u64.to_le_bytes());
+        assert_eq!(image[8..16], 0x1000u64.to_le_bytes());
+        assert_eq!(image[16..24], 0xff8u64.to_le_bytes());
+        assert_eq!(image[24..28], 0x1004u32.to_le_bytes());
+        assert_eq!(image[28..32], [0; 4]);
+        assert_eq!(image[32..40], 0x5010u64.to_le_bytes());
+    }
+
+    #[test]
+    fn aarch64_big_endian() {
+        let mut image = [0u8; 20];
+        let relocs = [
+            rela(0, 0, abi::R_AARCH64_RELATIVE, 0x20),
+            rela(8, 1, abi::R_AARCH64_GLOB_DAT, 0x8),
+            rela(16, 1, abi::R_AARCH64_ABS32, -0x1001),
+        ];
+        let relocator = Relocator::new(BigEndian, Class::ELF64, abi::EM_AARCH64);
+        relocator
+            .apply_relocations(&mut image, 0x40_0000, relocs, resolve)
+            .expect("should apply");
+
+        assert_eq!(image[0..8], 0x40_0020u64.to_be_bytes());
+        assert_eq!(image[8..16], 0x1008u64.to_be_bytes());
+        assert_eq!(image[16..20], (-1i32).to_be_bytes());
+    }
+
+    #[test]
+    fn relr() {
+        let entries: [u64; 2] = [0x0, 0b101];
+        let relr: Vec<u8> = entries.iter().flat_map(|e| e.to_le_bytes()).collect();
+
+        let mut image = [0u8; 32];
+        image[16..24].copy_from_slice(&0x30u64.to_le_bytes());
+        let relocator = Relocator::new(LittleEndian, Class::ELF64, abi::EM_AARCH64);
+        relocator
+            .apply_relocations(
+                &mut image,
+                0x1_0000,
+                RelrIterator::new(LittleEndian, Class::ELF64, &relr).map(RelocationEntry::Relr),
+                |_| None,
+            )
+            .expect("should apply");
+
+        assert_eq!(image[0..8], 0x1_0000u64.to_le_bytes());
+        assert_eq!(image[8..16], [0; 8]);
+        assert_eq!(image[16..24], 0x1_0030u64.to_le_bytes());
+        assert_eq!(image[24..32], [0; 8]);
+    }
+
+    #[test]
+    fn errors() {
+        let mut image = [0u8; 8];
+        let relocator = Relocator::new(LittleEndian, Class::ELF64, abi::EM_X86_64);
+
+        let result =
+            relocator.apply_relocations(&mut image, 0, [rela(0, 3, abi::R_X86_64_64, 0)], resolve);
+        assert!(matches!(result, Err(ParseError::UnresolvedSymbol(3))));
+
+        let result = relocator.apply_relocations(
+            &mut image,
+            0,
+            [rela(0, 1, abi::R_X86_64_COPY, 0)],
+            resolve,
+        );
+        assert!(matches!(
+            result,
+            Err(ParseError::UnsupportedRelocation((
+                abi::EM_X86_64,
+                abi::R_X86_64_COPY
+            )))
+        ));
+
+        let result = relocator.apply_relocations(
+            &mut image,
+            0,
+            [rela(4, 0, abi::R_X86_64_RELATIVE, 0)],
+            resolve,
+        );
+        assert!(matches!(result, Err(ParseError::BadOffset(4))));
+
+        let result =
+            relocator.apply_relocations(&mut image, 0, [rela(0, 2, abi::R_X86_64_32, 0)], resolve);
+        assert!(matches!(result, Err(ParseError::IntegerOverflow)));
+
+        let relocator = Relocator::new(LittleEndian, Class::ELF32, abi::EM_386);
+        let result = relocator.apply_relocations(
+            &mut image,
+            0,
+            [rela(0, 0, abi::R_386_RELATIVE, 0)],
+            resolve,
+        );
+        assert!(matches!(
+            result,
+            Err(ParseError::UnsupportedRelocation((abi::EM_386, 8)))
+        ));
+    }
+}
@@ -1,10 +1,33 @@
 //! Parsing relocation sections: `.rel.*`, `.rela.*`, [SHT_REL](crate::abi::SHT_REL), [SHT_RELA](crate::abi::SHT_RELA)
+use crate::abi;
 use crate::endian::EndianParse;
 use crate::file::Class;
-use crate::parse::{ParseAt, ParseError, ParsingIterator};
+use crate::parse::{
+    ne_u32, ne_u64, read_entry, ParseAt, ParseError, ParsingIterator, ParsingTable, SerializeAt,
+};
+
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::{vec, vec::Vec};
 
 pub type RelIterator<'data, E> = ParsingIterator<'data, E, Rel>;
 pub type RelaIterator<'data, E> = ParsingIterator<'data, E, Rela>;
+pub type RelTable<'data, E> = ParsingTable<'data, E, Rel>;
+pub type RelaTable<'data, E> = ParsingTable<'data, E, Rela>;
+
+/// The dynamic relocations that the dynamic linker applies, as described by the dynamic section.
+///
+/// The PLT relocations are either all [Rel]s or all [Rela]s, depending on the `DT_PLTREL` entry.
+#[derive(Debug, Clone, Default)]
+pub struct DynamicRelocations<'data, E: EndianParse> {
+    /// The `DT_REL` table
+    pub rel: Option<RelIterator<'data, E>>,
+    /// The `DT_RELA` table
+    pub rela: Option<RelaIterator<'data, E>>,
+    /// The `DT_JMPREL` table, if `DT_PLTREL` is `DT_REL`
+    pub plt_rel: Option<RelIterator<'data, E>>,
+    /// The `DT_JMPREL` table, if `DT_PLTREL` is `DT_RELA`
+    pub plt_rela: Option<RelaIterator<'data, E>>,
+}
 
 /// C-style 32-bit ELF Relocation definition
 ///
@@ -26,6 +49,7 @@ pub struct Elf64_Rel {
     pub r_info: u64,
 }
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Rel {
     pub r_offset: u64,
@@ -33,8 +57,9 @@ pub struct Rel {
     pub r_type: u32,
 }
 
-impl ParseAt for Rel {
-    fn parse_at<E: EndianParse>(
+impl Rel {
+    /// Parse a relocation field-by-field, which works for either byte order.
+    pub(crate) fn parse_fields<E: EndianParse>(
         endian: E,
         class: Class,
         offset: &mut usize,
@@ -62,11 +87,135 @@ impl ParseAt for Rel {
         }
     }
 
+    /// Parse a relocation whose byte order matches the host's from a single copy of its bytes.
+    pub(crate) fn parse_native(
+        class: Class,
+        offset: &mut usize,
+        data: &[u8],
+    ) -> Result<Self, ParseError> {
+        match class {
+            Class::ELF32 => {
+                let buf: [u8; 8] = read_entry(offset, data)?;
+                let r_info = ne_u32(&buf, 4);
+                Ok(Rel {
+                    r_offset: ne_u32(&buf, 0) as u64,
+                    r_sym: r_info >> 8,
+                    r_type: r_info & 0xFF,
+                })
+            }
+            Class::ELF64 => {
+                let buf: [u8; 16] = read_entry(offset, data)?;
+                let r_info = ne_u64(&buf, 8);
+                Ok(Rel {
+                    r_offset: ne_u64(&buf, 0),
+                    r_sym: (r_info >> 32) as u32,
+                    r_type: (r_info & 0xFFFFFFFF) as u32,
+                })
+            }
+        }
+    }
+}
+
+impl Rel {
+    /// Get the raw `r_info` field for the class, as it's stored in the file
+    pub fn r_info(&self, class: Class) -> Result<u64, ParseError> {
+        r_info_for(class, self.r_sym, self.r_type)
+    }
+
+    /// Decode the `r_info` field according to the machine's conventions. See [RelocationInfo].
+    pub fn info<E: EndianParse>(
+        &self,
+        endian: E,
+        class: Class,
+        e_machine: Option<u16>,
+    ) -> Result<RelocationInfo, ParseError> {
+        Ok(RelocationInfo::decode(
+            endian,
+            class,
+            e_machine,
+            self.r_info(class)?,
+        ))
+    }
+
+    /// Check that the symbol index is in range for a symbol table with `symtab_len` entries.
+    ///
+    /// Note that the generic split of `r_info` gives the wrong symbol index for little-endian
+    /// MIPS64 objects, so use [RelocationInfo::validate_against] for those.
+    pub fn validate_against(&self, symtab_len: usize) -> Result<(), ParseError> {
+        check_sym_index(self.r_sym, symtab_len)
+    }
+
+    /// Get a value which renders the relocation in the style of a `readelf -rW` line, with
+    /// the type named for the given machine. See [RelocationDisplay].
+    #[cfg(feature = "to_str")]
+    pub fn display(&self, class: Class, e_machine: u16) -> RelocationDisplay {
+        RelocationDisplay {
+            r_offset: self.r_offset,
+            r_sym: self.r_sym,
+            r_type: self.r_type,
+            class,
+            e_machine,
+        }
+    }
+}
+
+pub const ELF32_REL_SIZE: usize = 8;
+pub const ELF64_REL_SIZE: usize = 16;
+
+impl ParseAt for Rel {
+    fn parse_at<E: EndianParse>(
+        endian: E,
+        class: Class,
+        offset: &mut usize,
+        data: &[u8],
+    ) -> Result<Self, ParseError> {
+        if endian.is_native() {
+            Rel::parse_native(class, offset, data)
+        } else {
+            Rel::parse_fields(endian, class, offset, data)
+        }
+    }
+
     #[inline]
     fn size_for(class: Class) -> usize {
         match class {
-            Class::ELF32 => 8,
-            Class::ELF64 => 16,
+            Class::ELF32 => ELF32_REL_SIZE,
+            Class::ELF64 => ELF64_REL_SIZE,
+        }
+    }
+}
+
+/// Pack the symbol index and relocation type back into the class's r_info layout.
+fn r_info_for(class: Class, r_sym: u32, r_type: u32) -> Result<u64, ParseError> {
+    match class {
+        Class::ELF32 => {
+            if r_sym > 0xFFFFFF || r_type > 0xFF {
+                return Err(ParseError::IntegerOverflow);
+            }
+            Ok(((r_sym << 8) | r_type) as u64)
+        }
+        Class::ELF64 => Ok(((r_sym as u64) << 32) | r_type as u64),
+    }
+}
+
+impl SerializeAt for Rel {
+    fn serialize_at<E: EndianParse>(
+        &self,
+        endian: E,
+        class: Class,
+        offset: &mut usize,
+        data: &mut [u8],
+    ) -> Result<(), ParseError> {
+        let r_info = r_info_for(class, self.r_sym, self.r_type)?;
+        match class {
+            Class::ELF32 => {
+                endian.write_u32_at(offset, data, self.r_offset.try_into()?)?;
+                endian.write_u32_at(offset, data, r_info as u32)
+            }
+            Class::ELF64 => {
+                endian.write_u64_at(offset, data, self.r_offset)?;
+                endian.write_u64_at(offset, data, r_info)
+            }
         }
     }
 }
@@ -93,6 +242,8 @@ pub struct Elf64_Rela {
     pub r_addend: i64,
 }
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Rela {
     pub r_offset: u64,
@@ -101,8 +252,9 @@ pub struct Rela {
     pub r_addend: i64,
 }
 
-impl ParseAt for Rela {
-    fn parse_at<E: EndianParse>(
+impl Rela {
+    /// Parse a relocation field-by-field, which works for either byte order.
+    pub(crate) fn parse_fields<E: EndianParse>(
         endian: E,
         class: Class,
         offset: &mut usize,
@@ -134,11 +286,568 @@ impl ParseAt for Rela {
         }
     }
 
+    /// Parse a relocation whose byte order matches the host's from a single copy of its bytes.
+    pub(crate) fn parse_native(
+        class: Class,
+        offset: &mut usize,
+        data: &[u8],
+    ) -> Result<Self, ParseError> {
+        match class {
+            Class::ELF32 => {
+                let buf: [u8; 12] = read_entry(offset, data)?;
+                let r_info = ne_u32(&buf, 4);
+                Ok(Rela {
+                    r_offset: ne_u32(&buf, 0) as u64,
+                    r_sym: r_info >> 8,
+                    r_type: r_info & 0xFF,
+                    r_addend: ne_u32(&buf, 8) as i32 as i64,
+                })
+            }
+            Class::ELF64 => {
+                let buf: [u8; 24] = read_entry(offset, data)?;
+                let r_info = ne_u64(&buf, 8);
+                Ok(Rela {
+                    r_offset: ne_u64(&buf, 0),
+                    r_sym: (r_info >> 32) as u32,
+                    r_type: (r_info & 0xFFFFFFFF) as u32,
+                    r_addend: ne_u64(&buf, 16) as i64,
+                })
+            }
+        }
+    }
+}
+
+impl Rela {
+    /// Get the raw `r_info` field for the class, as it's stored in the file
+    pub fn r_info(&self, class: Class) -> Result<u64, ParseError> {
+        r_info_for(class, self.r_sym, self.r_type)
+    }
+
+    /// Decode the `r_info` field according to the machine's conventions. See [RelocationInfo].
+    pub fn info<E: EndianParse>(
+        &self,
+        endian: E,
+        class: Class,
+        e_machine: Option<u16>,
+    ) -> Result<RelocationInfo, ParseError> {
+        Ok(RelocationInfo::decode(
+            endian,
+            class,
+            e_machine,
+            self.r_info(class)?,
+        ))
+    }
+
+    /// Check that the symbol index is in range for a symbol table with `symtab_len` entries.
+    ///
+    /// Note that the generic split of `r_info` gives the wrong symbol index for little-endian
+    /// MIPS64 objects, so use [RelocationInfo::validate_against] for those.
+    pub fn validate_against(&self, symtab_len: usize) -> Result<(), ParseError> {
+        check_sym_index(self.r_sym, symtab_len)
+    }
+
+    /// Get a value which renders the relocation in the style of a `readelf -rW` line, with
+    /// the type named for the given machine. See [RelocationDisplay].
+    #[cfg(feature = "to_str")]
+    pub fn display(&self, class: Class, e_machine: u16) -> RelocationDisplay {
+        RelocationDisplay {
+            r_offset: self.r_offset,
+            r_sym: self.r_sym,
+            r_type: self.r_type,
+            class,
+            e_machine,
+        }
+    }
+}
+
+pub const ELF32_RELA_SIZE: usize = 12;
+pub const ELF64_RELA_SIZE: usize = 24;
+
+impl ParseAt for Rela {
+    fn parse_at<E: EndianParse>(
+        endian: E,
+        class: Class,
+        offset: &mut usize,
+        data: &[u8],
+    ) -> Result<Self, ParseError> {
+        if endian.is_native() {
+            Rela::parse_native(class, offset, data)
+        } else {
+            Rela::parse_fields(endian, class, offset, data)
+        }
+    }
+
     #[inline]
     fn size_for(class: Class) -> usize {
         match class {
-            Class::ELF32 => 12,
-            Class::ELF64 => 24,
+            Class::ELF32 => ELF32_RELA_SIZE,
+            Class::ELF64 => ELF64_RELA_SIZE,
+        }
+    }
+}
+
+impl SerializeAt for Rela {
+    fn serialize_at<E: EndianParse>(
+        &self,
+        endian: E,
+        class: Class,
+        offset: &mut usize,
+        data: &mut [u8],
+    ) -> Result<(), ParseError> {
+        let r_info = r_info_for(class, self.r_sym, self.r_type)?;
+        match class {
+            Class::ELF32 => {
+                endian.write_u32_at(offset, data, self.r_offset.try_into()?)?;
+                endian.write_u32_at(offset, data, r_info as u32)?;
+                endian.write_i32_at(offset, data, self.r_addend.try_into()?)
+            }
+            Class::ELF64 => {
+                endian.write_u64_at(offset, data, self.r_offset)?;
+                endian.write_u64_at(offset, data, r_info)?;
+                endian.write_i64_at(offset, data, self.r_addend)
+            }
+        }
+    }
+}
+
+/// The serialized contents of a new [SHT_REL](abi::SHT_REL) or [SHT_RELA](abi::SHT_RELA)
+/// section, along with the `sh_entsize` to declare for it.
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RelocationSectionData {
+    /// The contents of the relocation section
+    pub data: Vec<u8>,
+    /// The size of each entry, which goes in the section's sh_entsize
+    pub entsize: u64,
+}
+
+/// Serialize the given relocations into the contents of a [SHT_REL](abi::SHT_REL) section
+/// for an object of the given class and byte order.
+///
+/// Returns [ParseError::IntegerOverflow] if an ELF32 relocation's symbol index doesn't fit in
+/// 24 bits or its type doesn't fit in 8 bits, or [ParseError::TryFromIntError] if its
+/// `r_offset` doesn't fit in 32 bits.
+#[cfg(feature = "alloc")]
+pub fn build_rel_section<E: EndianParse>(
+    endian: E,
+    class: Class,
+    rels: &[Rel],
+) -> Result<RelocationSectionData, ParseError> {
+    serialize_entries(endian, class, rels)
+}
+
+/// Serialize the given relocations into the contents of a [SHT_RELA](abi::SHT_RELA) section
+/// for an object of the given class and byte order.
+///
+/// This fails in the same cases as [build_rel_section], and also with
+/// [ParseError::TryFromIntError] if an ELF32 relocation's `r_addend` doesn't fit in 32 bits.
+#[cfg(feature = "alloc")]
+pub fn build_rela_section<E: EndianParse>(
+    endian: E,
+    class: Class,
+    relas: &[Rela],
+) -> Result<RelocationSectionData, ParseError> {
+    serialize_entries(endian, class, relas)
+}
+
+#[cfg(feature = "alloc")]
+fn serialize_entries<E: EndianParse, P: ParseAt + SerializeAt>(
+    endian: E,
+    class: Class,
+    entries: &[P],
+) -> Result<RelocationSectionData, ParseError> {
+    let entsize = P::size_for(class);
+    let size = entsize
+        .checked_mul(entries.len())
+        .ok_or(ParseError::IntegerOverflow)?;
+    let mut data = vec![0u8; size];
+    let mut offset = 0;
+    for entry in entries {
+        entry.serialize_at(endian, class, &mut offset, &mut data)?;
+    }
+    Ok(RelocationSectionData {
+        data,
+        entsize: entsize as u64,
+    })
+}
+
+/// Iterator over the addresses relocated by a [SHT_RELR](abi::SHT_RELR) table of relative
+/// relocations, as found through [abi::DT_RELR].
+///
+/// Each entry is a word of the object's class. An even entry is the address of the next
+/// relocation, and an odd entry is a bitmap of which of the following words (beyond the
+/// last address) also get relocated, with bit `n` standing for the `n - 1`th word. A
+/// trailing partial word is ignored.
+#[derive(Debug, Clone)]
+pub struct RelrIterator<'data, E: EndianParse> {
+    endian: E,
+    class: Class,
+    data: &'data [u8],
+    offset: usize,
+    /// The address that the next bitmap entry starts from
+    next_addr: u64,
+    /// The bits of the current bitmap that haven't been returned yet
+    bitmap: u64,
+    /// The address of bit 0 of the current (already shifted) bitmap
+    bitmap_addr: u64,
+}
+
+impl<'data, E: EndianParse> RelrIterator<'data, E> {
+    pub fn new(endian: E, class: Class, data: &'data [u8]) -> Self {
+        RelrIterator {
+            endian,
+            class,
+            data,
+            offset: 0,
+            next_addr: 0,
+            bitmap: 0,
+            bitmap_addr: 0,
+        }
+    }
+
+    fn word_size(&self) -> u64 {
+        match self.class {
+            Class::ELF32 => 4,
+            Class::ELF64 => 8,
+        }
+    }
+}
+
+impl<'data, E: EndianParse> Iterator for RelrIterator<'data, E> {
+    type Item = u64;
+    fn next(&mut self) -> Option<Self::Item> {
+        let word = self.word_size();
+        loop {
+            if self.bitmap != 0 {
+                let bit = self.bitmap.trailing_zeros() as u64;
+                self.bitmap &= self.bitmap - 1;
+                return Some(self.bitmap_addr.wrapping_add(bit * word));
+            }
+
+            let entry = match self.class {
+                Class::ELF32 => self.endian.parse_u32_at(&mut self.offset, self.data).ok()? as u64,
+                Class::ELF64 => self.endian.parse_u64_at(&mut self.offset, self.data).ok()?,
+            };
+            if entry & 1 == 0 {
+                self.next_addr = entry.wrapping_add(word);
+                return Some(entry);
+            }
+            self.bitmap = entry >> 1;
+            self.bitmap_addr = self.next_addr;
+            self.next_addr = self.next_addr.wrapping_add((word * 8 - 1) * word);
+        }
+    }
+}
+
+/// Get the size in bits of the field that holds a [Rel]'s implicit addend for the given
+/// machine and relocation type, or `None` if it's unknown.
+///
+/// A size of 0 means the relocation has no addend, like `R_*_NONE` and `R_*_COPY`. This
+/// covers the data relocations on [abi::EM_386] and [abi::EM_ARM], the main users of
+/// [SHT_REL](abi::SHT_REL). ARM relocations that patch instruction fields, like
+/// [abi::R_ARM_CALL], spread the addend across the instruction's encoding and aren't covered.
+pub fn implicit_addend_bits(e_machine: u16, r_type: u32) -> Option<u32> {
+    match e_machine {
+        abi::EM_386 => match r_type {
+            abi::R_386_NONE | abi::R_386_COPY | abi::R_386_TLS_DESC_CALL => Some(0),
+            abi::R_386_8 | abi::R_386_PC8 => Some(8),
+            abi::R_386_16 | abi::R_386_PC16 => Some(16),
+            abi::R_386_32
+            | abi::R_386_PC32
+            | abi::R_386_GOT32
+            | abi::R_386_PLT32
+            | abi::R_386_GLOB_DAT
+            | abi::R_386_JMP_SLOT
+            | abi::R_386_RELATIVE
+            | abi::R_386_GOTOFF
+            | abi::R_386_GOTPC
+            | abi::R_386_32PLT
+            | abi::R_386_TLS_TPOFF
+            | abi::R_386_TLS_IE
+            | abi::R_386_TLS_GOTIE
+            | abi::R_386_TLS_LE
+            | abi::R_386_TLS_GD
+            | abi::R_386_TLS_LDM
+            | abi::R_386_TLS_LDO_32
+            | abi::R_386_TLS_IE_32
+            | abi::R_386_TLS_LE_32
+            | abi::R_386_TLS_DTPMOD32
+            | abi::R_386_TLS_DTPOFF32
+            | abi::R_386_TLS_TPOFF32
+            | abi::R_386_SIZE32
+            | abi::R_386_TLS_GOTDESC
+            | abi::R_386_IRELATIVE
+            | abi::R_386_GOT32X => Some(32),
+            _ => None,
+        },
+        abi::EM_ARM => match r_type {
+            abi::R_ARM_NONE | abi::R_ARM_COPY | abi::R_ARM_V4BX => Some(0),
+            abi::R_ARM_ABS8 => Some(8),
+            abi::R_ARM_ABS16 => Some(16),
+            abi::R_ARM_PREL31 => Some(31),
+            abi::R_ARM_ABS32
+            | abi::R_ARM_REL32
+            | abi::R_ARM_SBREL32
+            | abi::R_ARM_TLS_DTPMOD32
+            | abi::R_ARM_TLS_DTPOFF32
+            | abi::R_ARM_TLS_TPOFF32
+            | abi::R_ARM_GLOB_DAT
+            | abi::R_ARM_JUMP_SLOT
+            | abi::R_ARM_RELATIVE
+            | abi::R_ARM_GOTOFF32
+            | abi::R_ARM_BASE_PREL
+            | abi::R_ARM_BASE_BREL
+            | abi::R_ARM_TARGET1
+            | abi::R_ARM_TARGET2
+            | abi::R_ARM_ABS32_NOI
+            | abi::R_ARM_REL32_NOI
+            | abi::R_ARM_GOT_PREL
+            | abi::R_ARM_TLS_GD32
+            | abi::R_ARM_TLS_LDM32
+            | abi::R_ARM_TLS_LDO32
+            | abi::R_ARM_TLS_IE32
+            | abi::R_ARM_TLS_LE32
+            | abi::R_ARM_IRELATIVE => Some(32),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Convert a [Rel] into the equivalent [Rela] by reading its implicit addend out of the
+/// data it relocates.
+///
+/// The `section_data` holds the bytes being relocated, which start at `section_vaddr`. For
+/// the relocations of a relocatable object that's the target section's contents and 0, since
+/// `r_offset` is an offset into the section, and for dynamic relocations it's the contents of
+/// the segment or section holding `r_offset` and its virtual address.
+///
+/// The addend is sign-extended from the size given by [implicit_addend_bits]. Returns
+/// [ParseError::UnsupportedRelocation] if the size isn't known, and an error if the field at
+/// `r_offset` isn't within `section_data`.
+pub fn rel_to_rela<E: EndianParse>(
+    rel: &Rel,
+    endian: E,
+    e_machine: u16,
+    section_data: &[u8],
+    section_vaddr: u64,
+) -> Result<Rela, ParseError> {
+    let bits = implicit_addend_bits(e_machine, rel.r_type)
+        .ok_or(ParseError::UnsupportedRelocation((e_machine, rel.r_type)))?;
+
+    let r_addend = if bits == 0 {
+        0
+    } else {
+        let mut offset: usize = rel
+            .r_offset
+            .checked_sub(section_vaddr)
+            .ok_or(ParseError::BadOffset(rel.r_offset))?
+            .try_into()?;
+        let raw = match bits {
+            8 => endian.parse_u8_at(&mut offset, section_data)? as u64,
+            16 => endian.parse_u16_at(&mut offset, section_data)? as u64,
+            _ => endian.parse_u32_at(&mut offset, section_data)? as u64,
+        };
+        let shift = 64 - bits;
+        ((raw << shift) as i64) >> shift
+    };
+
+    Ok(Rela {
+        r_offset: rel.r_offset,
+        r_sym: rel.r_sym,
+        r_type: rel.r_type,
+        r_addend,
+    })
+}
+
+/// A relocation of a section's data joined with the name of its symbol, as found by
+/// [ElfBytes::relocation_map_for](crate::ElfBytes::relocation_map_for).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SectionRelocation<'data> {
+    /// The offset of the relocated field from the start of the target section
+    pub offset: u64,
+    /// The relocation itself. A [Rel]'s implicit addend is read out of the target section.
+    pub rela: Rela,
+    /// The name of the relocation's symbol, or of the section for section symbols. This is
+    /// empty for relocations which don't have a symbol.
+    pub symbol_name: Option<&'data str>,
+    /// Whether the relocated field overlaps with that of another relocation in the map
+    pub overlaps: bool,
+}
+
+/// Iterator adaptor that converts each [Rel] of an [SHT_REL](abi::SHT_REL) section into a
+/// [Rela] with [rel_to_rela].
+#[derive(Debug, Clone)]
+pub struct RelToRelaIterator<'data, E: EndianParse> {
+    rels: RelIterator<'data, E>,
+    endian: E,
+    e_machine: u16,
+    section_data: &'data [u8],
+    section_vaddr: u64,
+}
+
+impl<'data, E: EndianParse> RelToRelaIterator<'data, E> {
+    /// Wrap the [Rel]s which apply to `section_data` at `section_vaddr`. See [rel_to_rela].
+    pub fn new(
+        rels: RelIterator<'data, E>,
+        endian: E,
+        e_machine: u16,
+        section_data: &'data [u8],
+        section_vaddr: u64,
+    ) -> Self {
+        RelToRelaIterator {
+            rels,
+            endian,
+            e_machine,
+            section_data,
+            section_vaddr,
+        }
+    }
+}
+
+impl<'data, E: EndianParse> Iterator for RelToRelaIterator<'data, E> {
+    type Item = Result<Rela, ParseError>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let rel = self.rels.next()?;
+        Some(rel_to_rela(
+            &rel,
+            self.endian,
+            self.e_machine,
+            self.section_data,
+            self.section_vaddr,
+        ))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.rels.size_hint()
+    }
+}
+
+impl<'data, E: EndianParse> ExactSizeIterator for RelToRelaIterator<'data, E> {}
+
+/// Check that a relocation's symbol index is either 0 (`STN_UNDEF`) or indexes the symbol table
+fn check_sym_index(r_sym: u32, symtab_len: usize) -> Result<(), ParseError> {
+    if r_sym != 0 && r_sym as usize >= symtab_len {
+        return Err(ParseError::BadOffset(r_sym as u64));
+    }
+    Ok(())
+}
+
+/// A relocation's `r_info` field, decoded according to the conventions of the machine.
+///
+/// The generic ABI splits `r_info` into a symbol index and a type, with 24 and 8 bits in ELF32
+/// and 32 and 32 bits in ELF64, which is what [Rel] and [Rela] hold. Some 64-bit machines pack
+/// more into the type:
+/// * SPARC: the low 8 bits are the type, and the upper 24 bits are signed data for the
+///   relocation, like the offset for [R_SPARC_OLO10](abi::R_SPARC_OLO10).
+/// * MIPS: there are up to three types which are applied in turn, along with a special symbol
+///   for the second and third. The symbol index comes first in the file, so for little-endian
+///   objects the generic split swaps the symbol index and the types.
+///
+/// ELF32 objects use the generic split for all machines.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RelocationInfo {
+    /// The symbol table index
+    pub r_sym: u32,
+    /// The relocation type
+    pub r_type: u32,
+    /// The second relocation type, for MIPS64
+    pub r_type2: u8,
+    /// The third relocation type, for MIPS64
+    pub r_type3: u8,
+    /// The special symbol for the second and third types, like [RSS_GP](abi::RSS_GP), for MIPS64
+    pub r_ssym: u8,
+    /// The sign-extended type data, for SPARC64
+    pub r_type_data: i32,
+}
+
+impl RelocationInfo {
+    /// Decode a raw `r_info` field which was read in the file's byte order.
+    ///
+    /// Passing `None` for the machine gives the generic split.
+    pub fn decode<E: EndianParse>(
+        endian: E,
+        class: Class,
+        e_machine: Option<u16>,
+        r_info: u64,
+    ) -> Self {
+        let mut info = RelocationInfo {
+            r_sym: 0,
+            r_type: 0,
+            r_type2: 0,
+            r_type3: 0,
+            r_ssym: 0,
+            r_type_data: 0,
+        };
+        match (class, e_machine) {
+            (Class::ELF32, _) => {
+                info.r_sym = (r_info as u32) >> 8;
+                info.r_type = r_info as u32 & 0xFF;
+            }
+            (Class::ELF64, Some(abi::EM_SPARC | abi::EM_SPARC32PLUS | abi::EM_SPARCV9)) => {
+                info.r_sym = (r_info >> 32) as u32;
+                info.r_type = r_info as u32 & 0xFF;
+                // Shift the 24-bit data down from the top of the word to sign-extend it
+                info.r_type_data = (r_info as u32 as i32) >> 8;
+            }
+            (Class::ELF64, Some(abi::EM_MIPS)) => {
+                let (r_sym, types) = if endian.is_little() {
+                    (r_info as u32, ((r_info >> 32) as u32).swap_bytes())
+                } else {
+                    ((r_info >> 32) as u32, r_info as u32)
+                };
+                info.r_sym = r_sym;
+                info.r_type = types & 0xFF;
+                info.r_type2 = (types >> 8) as u8;
+                info.r_type3 = (types >> 16) as u8;
+                info.r_ssym = (types >> 24) as u8;
+            }
+            (Class::ELF64, _) => {
+                info.r_sym = (r_info >> 32) as u32;
+                info.r_type = r_info as u32;
+            }
+        }
+        info
+    }
+
+    /// Check that the symbol index is in range for a symbol table with `symtab_len` entries.
+    /// The undefined symbol index of 0 is always allowed.
+    pub fn validate_against(&self, symtab_len: usize) -> Result<(), ParseError> {
+        check_sym_index(self.r_sym, symtab_len)
+    }
+}
+
+/// Renders a [Rel] or [Rela] in the style of a `readelf -rW` line (without the symbol and
+/// addend, which need the symbol table): offset, info and type. See [Rela::display].
+#[cfg(feature = "to_str")]
+#[derive(Debug, Clone, Copy)]
+pub struct RelocationDisplay {
+    r_offset: u64,
+    r_sym: u32,
+    r_type: u32,
+    class: Class,
+    e_machine: u16,
+}
+
+#[cfg(feature = "to_str")]
+impl core::fmt::Display for RelocationDisplay {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        use crate::to_str::{r_type_to_str, write_padded};
+
+        match self.class {
+            Class::ELF32 => {
+                let r_info = (self.r_sym << 8) | (self.r_type & 0xFF);
+                write!(f, "{:08x}  {:08x} ", self.r_offset, r_info)?;
+            }
+            Class::ELF64 => {
+                let r_info = ((self.r_sym as u64) << 32) | self.r_type as u64;
+                write!(f, "{:016x}  {:016x} ", self.r_offset, r_info)?;
+            }
+        }
+        let width = 22;
+        match r_type_to_str(self.r_type, self.e_machine) {
+            Some(name) => write!(f, "{name:<width$}"),
+            None => write_padded(f, width, format_args!("unrecognized: {:x}", self.r_type)),
         }
     }
 }
@@ -297,3 +1006,536 @@ mod parse_tests {
         test_parse_fuzz_too_short::<_, Rela>(BigEndian, Class::ELF64);
     }
 }
+
+#[cfg(test)]
+mod serialize_tests {
+    use super::*;
+    use crate::endian::{BigEndian, LittleEndian};
+    use crate::parse::test_serialize_roundtrip;
+
+    #[test]
+    fn serialize_rel32_lsb_roundtrip() {
+        test_serialize_roundtrip::<_, Rel>(LittleEndian, Class::ELF32);
+    }
+
+    #[test]
+    fn serialize_rel32_msb_roundtrip() {
+        test_serialize_roundtrip::<_, Rel>(BigEndian, Class::ELF32);
+    }
+
+    #[test]
+    fn serialize_rel64_lsb_roundtrip() {
+        test_serialize_roundtrip::<_, Rel>(LittleEndian, Class::ELF64);
+    }
+
+    #[test]
+    fn serialize_rel64_msb_roundtrip() {
+        test_serialize_roundtrip::<_, Rel>(BigEndian, Class::ELF64);
+    }
+
+    #[test]
+    fn serialize_rela32_lsb_roundtrip() {
+        test_serialize_roundtrip::<_, Rela>(LittleEndian, Class::ELF32);
+    }
+
+    #[test]
+    fn serialize_rela32_msb_roundtrip() {
+        test_serialize_roundtrip::<_, Rela>(BigEndian, Class::ELF32);
+    }
+
+    #[test]
+    fn serialize_rela64_lsb_roundtrip() {
+        test_serialize_roundtrip::<_, Rela>(LittleEndian, Class::ELF64);
+    }
+
+    #[test]
+    fn serialize_rela64_msb_roundtrip() {
+        test_serialize_roundtrip::<_, Rela>(BigEndian, Class::ELF64);
+    }
+
+    #[test]
+    fn serialize_rel32_sym_too_large() {
+        let rel = Rel {
+            r_offset: 0,
+            r_sym: 0x01000000,
+            r_type: 0,
+        };
+        let mut buf = [0u8; 8];
+        let mut offset = 0;
+        assert!(matches!(
+            rel.serialize_at(LittleEndian, Class::ELF32, &mut offset, &mut buf),
+            Err(ParseError::IntegerOverflow)
+        ));
+    }
+
+    #[test]
+    fn serialize_rela32_addend_too_large() {
+        let rela = Rela {
+            r_offset: 0,
+            r_sym: 0,
+            r_type: 0,
+            r_addend: i32::MIN as i64 - 1,
+        };
+        let mut buf = [0u8; 12];
+        let mut offset = 0;
+        assert!(matches!(
+            rela.serialize_at(LittleEndian, Class::ELF32, &mut offset, &mut buf),
+            Err(ParseError::TryFromIntError(_))
+        ));
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod build_tests {
+    use super::*;
+    use crate::endian::{AnyEndian, LittleEndian};
+
+    fn rels() -> Vec<Rel> {
+        vec![
+            Rel {
+                r_offset: 0x1000,
+                r_sym: 0,
+                r_type: abi::R_X86_64_RELATIVE,
+            },
+            Rel {
+                r_offset: 0x1008,
+                r_sym: 0xABCDEF,
+                r_type: 0xFF,
+            },
+            Rel {
+                r_offset: 0xFFFF_FFFF,
+                r_sym: 1,
+                r_type: abi::R_X86_64_64,
+            },
+        ]
+    }
+
+    #[test]
+    fn rel_roundtrip() {
+        for class in [Class::ELF32, Class::ELF64] {
+            for endian in [AnyEndian::Little, AnyEndian::Big] {
+                let section = build_rel_section(endian, class, &rels()).expect("should build");
+                assert_eq!(section.entsize as usize, Rel::size_for(class));
+                assert_eq!(section.data.len(), 3 * section.entsize as usize);
+
+                let table = RelTable::new(endian, class, &section.data);
+                let parsed: Vec<Rel> = table.iter().collect();
+                assert_eq!(parsed, rels());
+            }
+        }
+    }
+
+    #[test]
+    fn rela_roundtrip() {
+        let relas: Vec<Rela> = rels()
+            .into_iter()
+            .zip([0i64, -8, i32::MAX as i64])
+            .map(|(rel, r_addend)| Rela {
+                r_offset: rel.r_offset,
+                r_sym: rel.r_sym,
+                r_type: rel.r_type,
+                r_addend,
+            })
+            .collect();
+        for class in [Class::ELF32, Class::ELF64] {
+            for endian in [AnyEndian::Little, AnyEndian::Big] {
+                let section = build_rela_section(endian, class, &relas).expect("should build");
+                assert_eq!(section.entsize as usize, Rela::size_for(class));
+
+                let table = RelaTable::new(endian, class, &section.data);
+                let parsed: Vec<Rela> = table.iter().collect();
+                assert_eq!(parsed, relas);
+                for (rela, raw) in parsed.iter().zip(relas.iter()) {
+                    assert_eq!(rela.r_info(class).unwrap(), raw.r_info(class).unwrap());
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn r_info_packing() {
+        let section = build_rel_section(LittleEndian, Class::ELF32, &rels()[1..2]).unwrap();
+        assert_eq!(section.data[4..8], 0xABCDEFFFu32.to_le_bytes());
+        let section = build_rel_section(LittleEndian, Class::ELF64, &rels()[1..2]).unwrap();
+        assert_eq!(section.data[8..16], 0x00ABCDEF_000000FFu64.to_le_bytes());
+    }
+
+    #[test]
+    fn elf32_overflow() {
+        let rel = Rel {
+            r_offset: 0,
+            r_sym: 0x01000000,
+            r_type: 0,
+        };
+        assert!(matches!(
+            build_rel_section(LittleEndian, Class::ELF32, core::slice::from_ref(&rel)),
+            Err(ParseError::IntegerOverflow)
+        ));
+        assert!(build_rel_section(LittleEndian, Class::ELF64, &[rel]).is_ok());
+    }
+}
+
+#[cfg(test)]
+mod relr_tests {
+    use super::*;
+    use crate::endian::{BigEndian, LittleEndian};
+
+    #[test]
+    fn relr64() {
+        let entries: [u64; 4] = [0x1000, 0b1011, 0b11, 0x2000];
+        let data: Vec<u8> = entries.iter().flat_map(|e| e.to_le_bytes()).collect();
+        let addrs: Vec<u64> = RelrIterator::new(LittleEndian, Class::ELF64, &data).collect();
+        assert_eq!(addrs, [0x1000, 0x1008, 0x1018, 0x1008 + 63 * 8, 0x2000]);
+    }
+
+    #[test]
+    fn relr32() {
+        // A bitmap with all bits set covers the next 31 words
+        let entries: [u32; 3] = [0x100, 0xffffffff, 0x3];
+        let mut data: Vec<u8> = entries.iter().flat_map(|e| e.to_be_bytes()).collect();
+        // Trailing partial entry is ignored
+        data.push(0);
+        let addrs: Vec<u64> = RelrIterator::new(BigEndian, Class::ELF32, &data).collect();
+        let mut expected: Vec<u64> = (0..32).map(|n| 0x100 + n * 4).collect();
+        expected.push(0x104 + 31 * 4);
+        assert_eq!(addrs, expected);
+    }
+}
+
+#[cfg(test)]
+mod implicit_addend_tests {
+    use super::*;
+    use crate::endian::{BigEndian, LittleEndian};
+
+    fn rel(r_offset: u64, r_type: u32) -> Rel {
+        Rel {
+            r_offset,
+            r_sym: 3,
+            r_type,
+        }
+    }
+
+    #[test]
+    fn i386_widths() {
+        #[rustfmt::skip]
+        let data = [
+            0xfc, 0xff, 0xff, 0xff, // -4
+            0x10, 0x00, // 16
+            0x80, // -128
+            0x00,
+        ];
+        let rela = rel_to_rela(
+            &rel(0x100, abi::R_386_PC32),
+            LittleEndian,
+            abi::EM_386,
+            &data,
+            0x100,
+        )
+        .expect("should convert");
+        assert_eq!(
+            rela,
+            Rela {
+                r_offset: 0x100,
+                r_sym: 3,
+                r_type: abi::R_386_PC32,
+                r_addend: -4,
+            }
+        );
+        let rela = rel_to_rela(
+            &rel(0x104, abi::R_386_16),
+            LittleEndian,
+            abi::EM_386,
+            &data,
+            0x100,
+        )
+        .expect("should convert");
+        assert_eq!(rela.r_addend, 16);
+        let rela = rel_to_rela(
+            &rel(0x106, abi::R_386_PC8),
+            LittleEndian,
+            abi::EM_386,
+            &data,
+            0x100,
+        )
+        .expect("should convert");
+        assert_eq!(rela.r_addend, -128);
+
+        // No addend to read, so the offset doesn't matter
+        let rela = rel_to_rela(
+            &rel(0x1000, abi::R_386_COPY),
+            LittleEndian,
+            abi::EM_386,
+            &data,
+            0,
+        )
+        .expect("should convert");
+        assert_eq!(rela.r_addend, 0);
+    }
+
+    #[test]
+    fn arm_prel31() {
+        // The top bit belongs to the .ARM.exidx entry, not the addend
+        let data = [0xff, 0xff, 0xff, 0xfe, 0x00, 0x00, 0x00, 0x08];
+        let rela = rel_to_rela(&rel(0, abi::R_ARM_PREL31), BigEndian, abi::EM_ARM, &data, 0)
+            .expect("should convert");
+        assert_eq!(rela.r_addend, -2);
+        let rela = rel_to_rela(&rel(4, abi::R_ARM_ABS32), BigEndian, abi::EM_ARM, &data, 0)
+            .expect("should convert");
+        assert_eq!(rela.r_addend, 8);
+    }
+
+    #[test]
+    fn unsupported_types() {
+        let data = [0u8; 4];
+        assert!(matches!(
+            rel_to_rela(
+                &rel(0, abi::R_ARM_CALL),
+                LittleEndian,
+                abi::EM_ARM,
+                &data,
+                0
+            ),
+            Err(ParseError::UnsupportedRelocation((
+                abi::EM_ARM,
+                abi::R_ARM_CALL
+            )))
+        ));
+        assert!(matches!(
+            rel_to_rela(
+                &rel(0, abi::R_X86_64_64),
+                LittleEndian,
+                abi::EM_X86_64,
+                &data,
+                0
+            ),
+            Err(ParseError::UnsupportedRelocation((
+                abi::EM_X86_64,
+                abi::R_X86_64_64
+            )))
+        ));
+    }
+
+    #[test]
+    fn out_of_bounds() {
+        let data = [0u8; 4];
+        assert!(rel_to_rela(&rel(2, abi::R_386_32), LittleEndian, abi::EM_386, &data, 0).is_err());
+        assert!(matches!(
+            rel_to_rela(&rel(2, abi::R_386_32), LittleEndian, abi::EM_386, &data, 4),
+            Err(ParseError::BadOffset(2))
+        ));
+    }
+
+    #[test]
+    fn iterator() {
+        #[rustfmt::skip]
+        let rels = [
+            0x00, 0x00, 0x00, 0x00, 0x02, 0x01, 0x00, 0x00, // R_386_PC32 against symbol 1
+            0x04, 0x00, 0x00, 0x00, 0x01, 0x02, 0x00, 0x00, // R_386_32 against symbol 2
+            0x04, 0x00, 0x00, 0x00, 0x1c, 0x00, 0x00, 0x00, // Unknown type 28
+        ];
+        let text = [0xfc, 0xff, 0xff, 0xff, 0x08, 0x00, 0x00, 0x00];
+        let table = RelTable::new(LittleEndian, Class::ELF32, &rels);
+        let mut iter = RelToRelaIterator::new(table.iter(), LittleEndian, abi::EM_386, &text, 0);
+        assert_eq!(iter.len(), 3);
+
+        let rela = iter.next().unwrap().expect("should convert");
+        assert_eq!(
+            (rela.r_sym, rela.r_type, rela.r_addend),
+            (1, abi::R_386_PC32, -4)
+        );
+        let rela = iter.next().unwrap().expect("should convert");
+        assert_eq!(
+            (rela.r_sym, rela.r_type, rela.r_addend),
+            (2, abi::R_386_32, 8)
+        );
+        assert!(matches!(
+            iter.next(),
+            Some(Err(ParseError::UnsupportedRelocation((abi::EM_386, 28))))
+        ));
+        assert!(iter.next().is_none());
+    }
+}
+
+#[cfg(all(test, feature = "serde"))]
+mod serde_tests {
+    use super::*;
+    use crate::parse::test_serde_roundtrip;
+
+    #[test]
+    fn serde_rel() {
+        let rel = Rel {
+            r_offset: 0x3fe0,
+            r_sym: 2,
+            r_type: 6,
+        };
+        test_serde_roundtrip(&rel, r#"{"r_offset":16352,"r_sym":2,"r_type":6}"#);
+    }
+
+    #[test]
+    fn serde_rela() {
+        let rela = Rela {
+            r_offset: 0x3fe0,
+            r_sym: 2,
+            r_type: 6,
+            r_addend: -4,
+        };
+        test_serde_roundtrip(
+            &rela,
+            r#"{"r_offset":16352,"r_sym":2,"r_type":6,"r_addend":-4}"#,
+        );
+    }
+}
+
+#[cfg(all(test, feature = "to_str"))]
+mod display_tests {
+    use super::*;
+
+    #[test]
+    fn display_relocation() {
+        // Rows from `readelf -rW` of sample-objects/basic.x86_64 and symver.armhf.so
+        let rela = Rela {
+            r_offset: 0x6008a8,
+            r_sym: 3,
+            r_type: abi::R_X86_64_GLOB_DAT,
+            r_addend: 0,
+        };
+        assert_eq!(
+            format!("{}", rela.display(Class::ELF64, abi::EM_X86_64)),
+            "00000000006008a8  0000000300000006 R_X86_64_GLOB_DAT     "
+        );
+        let rel = Rel {
+            r_offset: 0x10f00,
+            r_sym: 0,
+            r_type: abi::R_ARM_RELATIVE,
+        };
+        assert_eq!(
+            format!("{}", rel.display(Class::ELF32, abi::EM_ARM)),
+            "00010f00  00000017 R_ARM_RELATIVE        "
+        );
+        assert_eq!(
+            format!("{}", rel.display(Class::ELF32, abi::EM_68K)),
+            "00010f00  00000017 unrecognized: 17      "
+        );
+    }
+}
+
+#[cfg(test)]
+mod info_tests {
+    use super::*;
+    use crate::endian::{BigEndian, LittleEndian};
+
+    #[test]
+    fn sparc32_rela() {
+        // A big-endian SPARC32 R_SPARC_32 relocation against symbol 0x012345 with addend 8
+        let data = [
+            0x00, 0x01, 0x00, 0x40, 0x01, 0x23, 0x45, 0x03, 0x00, 0x00, 0x00, 0x08,
+        ];
+        let table = RelaTable::new(BigEndian, Class::ELF32, &data);
+        let rela = table.get(0).expect("should parse");
+        assert_eq!(rela.r_info(Class::ELF32).unwrap(), 0x01234503);
+
+        // The symbol index takes all of the upper 24 bits, rather than being read as the type
+        // data which SPARC64 keeps there.
+        let info = rela
+            .info(BigEndian, Class::ELF32, Some(abi::EM_SPARC32PLUS))
+            .expect("should decode");
+        assert_eq!(info.r_sym, 0x012345);
+        assert_eq!(info.r_type, abi::R_SPARC_32);
+        assert_eq!(info.r_type_data, 0);
+        let misread = RelocationInfo::decode(
+            BigEndian,
+            Class::ELF64,
+            Some(abi::EM_SPARCV9),
+            rela.r_info(Class::ELF32).unwrap(),
+        );
+        assert_eq!((misread.r_sym, misread.r_type_data), (0, 0x012345));
+
+        assert!(info.validate_against(0x012346).is_ok());
+        assert!(matches!(
+            rela.validate_against(0x012345),
+            Err(ParseError::BadOffset(0x012345))
+        ));
+    }
+
+    #[test]
+    fn sparc64_type_data() {
+        // R_SPARC_OLO10 against symbol 7 with an offset of -4 in the type data
+        let rela = Rela {
+            r_offset: 0x1000,
+            r_sym: 7,
+            r_type: 0xFFFFFC00 | abi::R_SPARC_OLO10,
+            r_addend: 0,
+        };
+        let generic = rela.info(BigEndian, Class::ELF64, None).unwrap();
+        assert_eq!(generic.r_type, 0xFFFFFC21);
+        let info = rela
+            .info(BigEndian, Class::ELF64, Some(abi::EM_SPARCV9))
+            .unwrap();
+        assert_eq!(info.r_sym, 7);
+        assert_eq!(info.r_type, abi::R_SPARC_OLO10);
+        assert_eq!(info.r_type_data, -4);
+    }
+
+    #[test]
+    fn mips64_composite() {
+        // Symbol 5, with types R_MIPS_REL32, R_MIPS_64 and R_MIPS_NONE and no special symbol
+        let le = [
+            0x00, 0x10, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x12, 0x03,
+        ];
+        let rel = RelTable::new(LittleEndian, Class::ELF64, &le)
+            .get(0)
+            .unwrap();
+        // The generic split swaps the symbol index and the types
+        assert_eq!((rel.r_sym, rel.r_type), (0x03120000, 5));
+        assert!(rel.validate_against(6).is_err());
+        let info = rel
+            .info(LittleEndian, Class::ELF64, Some(abi::EM_MIPS))
+            .unwrap();
+        assert_eq!(info.r_sym, 5);
+        assert_eq!(info.r_type, abi::R_MIPS_REL32);
+        assert_eq!(info.r_type2, abi::R_MIPS_64 as u8);
+        assert_eq!(info.r_type3, abi::R_MIPS_NONE as u8);
+        assert_eq!(info.r_ssym, abi::RSS_UNDEF);
+        assert!(info.validate_against(6).is_ok());
+
+        let be = [
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x10, 0x00, 0x00, 0x00, 0x00, 0x05, 0x01, 0x00,
+            0x12, 0x03,
+        ];
+        let rel = RelTable::new(BigEndian, Class::ELF64, &be).get(0).unwrap();
+        let info = rel
+            .info(BigEndian, Class::ELF64, Some(abi::EM_MIPS))
+            .unwrap();
+        assert_eq!(info.r_sym, 5);
+        assert_eq!(
+            (info.r_type, info.r_type2, info.r_type3, info.r_ssym),
+            (abi::R_MIPS_REL32, abi::R_MIPS_64 as u8, 0, abi::RSS_GP)
+        );
+    }
+
+    #[test]
+    fn r_info_roundtrip() {
+        let rel = Rel {
+            r_offset: 0,
+            r_sym: 0x0F0E0D0C,
+            r_type: 0x0B0A0908,
+        };
+        assert_eq!(rel.r_info(Class::ELF64).unwrap(), 0x0F0E0D0C0B0A0908);
+        assert!(matches!(
+            rel.r_info(Class::ELF32),
+            Err(ParseError::IntegerOverflow)
+        ));
+        let info = rel.info(LittleEndian, Class::ELF64, None).unwrap();
+        assert_eq!((info.r_sym, info.r_type), (rel.r_sym, rel.r_type));
+        assert!(rel.validate_against(0).is_err());
+
+        // STN_UNDEF is fine even without a symbol table
+        let rel = Rel {
+            r_offset: 0,
+            r_sym: 0,
+            r_type: 8,
+        };
+        assert!(rel.validate_against(0).is_ok());
+    }
+}
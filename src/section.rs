@@ -1,10 +1,60 @@
 //! Parsing the Section Header table
+#[cfg(feature = "to_str")]
+use crate::abi;
 use crate::endian::EndianParse;
 use crate::file::Class;
-use crate::parse::{ParseAt, ParseError, ParsingTable};
+use crate::parse::{file_range, ParseAt, ParseError, ParsingIterator, ParsingTable, SerializeAt};
+use crate::string_table::StringTable;
+#[cfg(feature = "to_str")]
+use crate::to_str::ReadelfDisplay;
 
 pub type SectionHeaderTable<'data, E> = ParsingTable<'data, E, SectionHeader>;
 
+impl<'data, E: EndianParse> SectionHeaderTable<'data, E> {
+    /// Get an iterator over all the section headers with the given name (looked up in the
+    /// section name string table `strtab`), along with their indexes, in table order.
+    ///
+    /// Objects can have several sections with the same name, like multiple `.note` or `.text`
+    /// sections. Headers whose names can't be read are skipped, and so is the reserved header
+    /// at index 0, which isn't a real section.
+    pub fn iter_named<'name>(
+        &self,
+        strtab: &StringTable<'data>,
+        name: &'name [u8],
+    ) -> SectionHeaderNameIterator<'data, 'name, E> {
+        SectionHeaderNameIterator {
+            iter: self.iter(),
+            strtab: *strtab,
+            name,
+            index: self.first_index(),
+        }
+    }
+}
+
+/// Iterator over the section headers with a given name, see [SectionHeaderTable::iter_named].
+#[derive(Debug, Clone)]
+pub struct SectionHeaderNameIterator<'data, 'name, E: EndianParse> {
+    iter: ParsingIterator<'data, E, SectionHeader>,
+    strtab: StringTable<'data>,
+    name: &'name [u8],
+    index: usize,
+}
+
+impl<'data, 'name, E: EndianParse> Iterator for SectionHeaderNameIterator<'data, 'name, E> {
+    type Item = (usize, SectionHeader);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        for shdr in self.iter.by_ref() {
+            let index = self.index;
+            self.index += 1;
+            if index != 0 && self.strtab.get_raw(shdr.sh_name as usize).ok() == Some(self.name) {
+                return Some((index, shdr));
+            }
+        }
+        None
+    }
+}
+
 /// C-style 32-bit ELF Section Header definition
 ///
 /// These C-style definitions are for users who want to implement their own ELF manipulation logic.
@@ -44,7 +94,9 @@ pub struct Elf64_Shdr {
 /// Encapsulates the contents of an ELF Section Header
 ///
 /// This is a Rust-native type that represents a Section Header that is bit-width-agnostic.
-#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
 pub struct SectionHeader {
     /// Section Name
     pub sh_name: u32,
@@ -68,6 +120,9 @@ pub struct SectionHeader {
     pub sh_entsize: u64,
 }
 
+pub const ELF32_SHDR_SIZE: usize = 40;
+pub const ELF64_SHDR_SIZE: usize = 64;
+
 impl ParseAt for SectionHeader {
     fn parse_at<E: EndianParse>(
         endian: E,
@@ -106,23 +161,193 @@ impl ParseAt for SectionHeader {
     #[inline]
     fn size_for(class: Class) -> usize {
         match class {
-            Class::ELF32 => 40,
-            Class::ELF64 => 64,
+            Class::ELF32 => ELF32_SHDR_SIZE,
+            Class::ELF64 => ELF64_SHDR_SIZE,
+        }
+    }
+}
+
+impl SerializeAt for SectionHeader {
+    fn serialize_at<E: EndianParse>(
+        &self,
+        endian: E,
+        class: Class,
+        offset: &mut usize,
+        data: &mut [u8],
+    ) -> Result<(), ParseError> {
+        match class {
+            Class::ELF32 => {
+                endian.write_u32_at(offset, data, self.sh_name)?;
+                endian.write_u32_at(offset, data, self.sh_type)?;
+                endian.write_u32_at(offset, data, self.sh_flags.try_into()?)?;
+                endian.write_u32_at(offset, data, self.sh_addr.try_into()?)?;
+                endian.write_u32_at(offset, data, self.sh_offset.try_into()?)?;
+                endian.write_u32_at(offset, data, self.sh_size.try_into()?)?;
+                endian.write_u32_at(offset, data, self.sh_link)?;
+                endian.write_u32_at(offset, data, self.sh_info)?;
+                endian.write_u32_at(offset, data, self.sh_addralign.try_into()?)?;
+                endian.write_u32_at(offset, data, self.sh_entsize.try_into()?)
+            }
+            Class::ELF64 => {
+                endian.write_u32_at(offset, data, self.sh_name)?;
+                endian.write_u32_at(offset, data, self.sh_type)?;
+                endian.write_u64_at(offset, data, self.sh_flags)?;
+                endian.write_u64_at(offset, data, self.sh_addr)?;
+                endian.write_u64_at(offset, data, self.sh_offset)?;
+                endian.write_u64_at(offset, data, self.sh_size)?;
+                endian.write_u32_at(offset, data, self.sh_link)?;
+                endian.write_u32_at(offset, data, self.sh_info)?;
+                endian.write_u64_at(offset, data, self.sh_addralign)?;
+                endian.write_u64_at(offset, data, self.sh_entsize)
+            }
         }
     }
 }
 
 impl SectionHeader {
-    /// Helper method which uses checked integer math to get a tuple of (start,end) for
-    /// this SectionHeader's (sh_offset, sh_offset + sh_size)
+    /// Helper method which uses checked integer math to get the range of file offsets for
+    /// this SectionHeader's data, i.e. sh_offset..sh_offset + sh_size
+    pub(crate) fn file_range(&self) -> Result<core::ops::Range<u64>, ParseError> {
+        file_range(self.sh_offset, self.sh_size)
+    }
+
+    /// Like [SectionHeader::file_range], but as a tuple of (start, end) positions in memory
+    #[cfg(feature = "alloc")]
     pub(crate) fn get_data_range(&self) -> Result<(usize, usize), ParseError> {
-        let start: usize = self.sh_offset.try_into()?;
-        let size: usize = self.sh_size.try_into()?;
-        let end = start.checked_add(size).ok_or(ParseError::IntegerOverflow)?;
-        Ok((start, end))
+        let range = self.file_range()?;
+        Ok((range.start.try_into()?, range.end.try_into()?))
+    }
+
+    /// Get a value which renders the section header in the style of a `readelf -SW` line,
+    /// with the address column sized for the given class and processor-specific types named
+    /// for the given machine. See [SectionHeader]'s Display impl.
+    #[cfg(feature = "to_str")]
+    pub fn display(&self, class: Class, e_machine: u16) -> ReadelfDisplay<'_, SectionHeader> {
+        ReadelfDisplay {
+            value: self,
+            class,
+            e_machine,
+        }
+    }
+}
+
+/// Renders the section header in the style of an ELF64 `readelf -SW` line (without the index
+/// and name, which lives in the section header string table): type, address, offset, size,
+/// entsize, flags, link, info and alignment.
+///
+/// The header doesn't know which machine it's for, so processor-specific types are shown
+/// relative to `SHT_LOPROC`. Use [SectionHeader::display] for ELF32's narrower address column
+/// and the machine's type names.
+#[cfg(feature = "to_str")]
+impl core::fmt::Display for SectionHeader {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.display(Class::ELF64, abi::EM_NONE).fmt(f)
+    }
+}
+
+#[cfg(feature = "to_str")]
+impl core::fmt::Display for ReadelfDisplay<'_, SectionHeader> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        use crate::to_str::{sh_type_to_str, write_padded};
+
+        let shdr = self.value;
+        let name = match shdr.sh_type {
+            abi::SHT_SYMTAB_SHNDX => Some("SYMTAB SECTION INDICES"),
+            abi::SHT_GNU_VERDEF => Some("VERDEF"),
+            abi::SHT_GNU_VERNEED => Some("VERNEED"),
+            abi::SHT_GNU_VERSYM => Some("VERSYM"),
+            sh_type => sh_type_to_str(sh_type, self.e_machine).map(|name| &name[4..]),
+        };
+        match (name, shdr.sh_type) {
+            (Some(name), _) => write!(f, "{name:<15}")?,
+            (None, sh_type @ abi::SHT_LOOS..=abi::SHT_HIOS) => {
+                write_padded(f, 15, format_args!("LOOS+{:#x}", sh_type - abi::SHT_LOOS))?
+            }
+            (None, sh_type @ abi::SHT_LOPROC..=abi::SHT_HIPROC) => write_padded(
+                f,
+                15,
+                format_args!("LOPROC+{:#x}", sh_type - abi::SHT_LOPROC),
+            )?,
+            (None, sh_type @ abi::SHT_LOUSER..=abi::SHT_HIUSER) => write_padded(
+                f,
+                15,
+                format_args!("LOUSER+{:#x}", sh_type - abi::SHT_LOUSER),
+            )?,
+            (None, sh_type) => write!(f, "{sh_type:08x}       ")?,
+        }
+        match self.class {
+            Class::ELF32 => write!(f, " {:08x}", shdr.sh_addr)?,
+            Class::ELF64 => write!(f, " {:016x}", shdr.sh_addr)?,
+        }
+        write!(
+            f,
+            " {:06x} {:06x} {:02x} ",
+            shdr.sh_offset, shdr.sh_size, shdr.sh_entsize
+        )?;
+        write_sh_flags(f, 3, shdr.sh_flags)?;
+        write!(
+            f,
+            " {:2} {:3} {:2}",
+            shdr.sh_link, shdr.sh_info, shdr.sh_addralign
+        )
     }
 }
 
+/// Write out readelf's key letters for the section flags, right-aligned to `width`
+#[cfg(feature = "to_str")]
+fn write_sh_flags(
+    f: &mut core::fmt::Formatter<'_>,
+    width: usize,
+    sh_flags: u64,
+) -> core::fmt::Result {
+    const KEYS: &[(u32, char)] = &[
+        (abi::SHF_WRITE, 'W'),
+        (abi::SHF_ALLOC, 'A'),
+        (abi::SHF_EXECINSTR, 'X'),
+        (abi::SHF_MERGE, 'M'),
+        (abi::SHF_STRINGS, 'S'),
+        (abi::SHF_INFO_LINK, 'I'),
+        (abi::SHF_LINK_ORDER, 'L'),
+        (abi::SHF_OS_NONCONFORMING, 'O'),
+        (abi::SHF_GROUP, 'G'),
+        (abi::SHF_TLS, 'T'),
+        (abi::SHF_COMPRESSED, 'C'),
+        (SHF_EXCLUDE, 'E'),
+    ];
+    // GNU's flag for sections which are left out of the linked output
+    const SHF_EXCLUDE: u32 = 0x80000000;
+
+    let mut letters = [' '; 16];
+    let mut len = 0;
+    let mut remaining = sh_flags;
+    for &(flag, key) in KEYS {
+        if remaining & flag as u64 != 0 {
+            letters[len] = key;
+            len += 1;
+            remaining &= !(flag as u64);
+        }
+    }
+    for (mask, key) in [
+        (abi::SHF_MASKOS as u64, 'o'),
+        (abi::SHF_MASKPROC as u64, 'p'),
+        (!0, 'x'),
+    ] {
+        if remaining & mask != 0 {
+            letters[len] = key;
+            len += 1;
+            remaining &= !mask;
+        }
+    }
+
+    for _ in len..width {
+        f.write_str(" ")?;
+    }
+    for key in &letters[..len] {
+        write!(f, "{key}")?;
+    }
+    Ok(())
+}
+
 #[cfg(test)]
 mod parse_tests {
     use super::*;
@@ -229,3 +454,175 @@ mod parse_tests {
         test_parse_fuzz_too_short::<_, SectionHeader>(BigEndian, Class::ELF64);
     }
 }
+
+#[cfg(test)]
+mod serialize_tests {
+    use super::*;
+    use crate::endian::{BigEndian, LittleEndian};
+    use crate::parse::test_serialize_roundtrip;
+
+    #[test]
+    fn serialize_shdr32_lsb_roundtrip() {
+        test_serialize_roundtrip::<_, SectionHeader>(LittleEndian, Class::ELF32);
+    }
+
+    #[test]
+    fn serialize_shdr32_msb_roundtrip() {
+        test_serialize_roundtrip::<_, SectionHeader>(BigEndian, Class::ELF32);
+    }
+
+    #[test]
+    fn serialize_shdr64_lsb_roundtrip() {
+        test_serialize_roundtrip::<_, SectionHeader>(LittleEndian, Class::ELF64);
+    }
+
+    #[test]
+    fn serialize_shdr64_msb_roundtrip() {
+        test_serialize_roundtrip::<_, SectionHeader>(BigEndian, Class::ELF64);
+    }
+
+    #[test]
+    fn serialize_shdr32_value_too_large() {
+        let shdr = SectionHeader {
+            sh_name: 0,
+            sh_type: 0,
+            sh_flags: 0,
+            sh_addr: 0,
+            sh_offset: 0x1_0000_0000,
+            sh_size: 0,
+            sh_link: 0,
+            sh_info: 0,
+            sh_addralign: 0,
+            sh_entsize: 0,
+        };
+        let mut buf = [0u8; 40];
+        let mut offset = 0;
+        assert!(matches!(
+            shdr.serialize_at(LittleEndian, Class::ELF32, &mut offset, &mut buf),
+            Err(ParseError::TryFromIntError(_))
+        ));
+    }
+}
+
+#[cfg(all(test, feature = "to_str"))]
+mod display_tests {
+    use super::*;
+
+    fn shdr(
+        sh_type: u32,
+        sh_flags: u64,
+        sh_addr: u64,
+        sh_offset: u64,
+        sh_size: u64,
+    ) -> SectionHeader {
+        SectionHeader {
+            sh_name: 0,
+            sh_type,
+            sh_flags,
+            sh_addr,
+            sh_offset,
+            sh_size,
+            sh_link: 0,
+            sh_info: 0,
+            sh_addralign: 4,
+            sh_entsize: 0,
+        }
+    }
+
+    #[test]
+    fn display_section_header() {
+        // The rows for .fini and .bss from `readelf -SW sample-objects/basic.x86_64`
+        let fini = shdr(
+            abi::SHT_PROGBITS,
+            (abi::SHF_ALLOC | abi::SHF_EXECINSTR) as u64,
+            0x400584,
+            0x584,
+            9,
+        );
+        assert_eq!(
+            format!("{fini}"),
+            "PROGBITS        0000000000400584 000584 000009 00  AX  0   0  4"
+        );
+        let bss = SectionHeader {
+            sh_addralign: 32,
+            ..shdr(
+                abi::SHT_NOBITS,
+                (abi::SHF_WRITE | abi::SHF_ALLOC) as u64,
+                0x600900,
+                0x8e8,
+                0x40020,
+            )
+        };
+        assert_eq!(
+            format!("{bss}"),
+            "NOBITS          0000000000600900 0008e8 040020 00  WA  0   0 32"
+        );
+    }
+
+    #[test]
+    fn display_section_header_elf32() {
+        // The rows for .dynsym and .ARM.attributes from `readelf -SW sample-objects/symver.armhf.so`
+        let dynsym = SectionHeader {
+            sh_link: 5,
+            sh_info: 3,
+            sh_entsize: 16,
+            ..shdr(abi::SHT_DYNSYM, abi::SHF_ALLOC as u64, 0x18c, 0x18c, 0xc0)
+        };
+        assert_eq!(
+            format!("{}", dynsym.display(Class::ELF32, abi::EM_ARM)),
+            "DYNSYM          0000018c 00018c 0000c0 10   A  5   3  4"
+        );
+        let attributes = SectionHeader {
+            sh_addralign: 1,
+            ..shdr(abi::SHT_ARM_ATTRIBUTES, 0, 0, 0x1057, 0x31)
+        };
+        assert_eq!(
+            format!("{}", attributes.display(Class::ELF32, abi::EM_ARM)),
+            "ARM_ATTRIBUTES  00000000 001057 000031 00      0   0  1"
+        );
+        // Without the machine, processor-specific types are only known by number
+        assert_eq!(
+            format!("{}", attributes.display(Class::ELF32, abi::EM_NONE)),
+            "LOPROC+0x3      00000000 001057 000031 00      0   0  1"
+        );
+    }
+
+    #[test]
+    fn display_section_flags() {
+        let mut shdr = shdr(abi::SHT_PROGBITS, 0, 0, 0, 0);
+        shdr.sh_flags =
+            (abi::SHF_MERGE | abi::SHF_STRINGS | abi::SHF_INFO_LINK | abi::SHF_TLS) as u64;
+        assert!(format!("{shdr}").contains(" MSIT "));
+        shdr.sh_flags = abi::SHF_MASKOS as u64 | 0x1000_0000 | 0x1000_0000_0000;
+        assert!(format!("{shdr}").contains(" opx "));
+    }
+}
+
+#[cfg(all(test, feature = "serde"))]
+mod serde_tests {
+    use super::*;
+    use crate::parse::test_serde_roundtrip;
+
+    #[test]
+    fn serde_section_header() {
+        let shdr = SectionHeader {
+            sh_name: 27,
+            sh_type: 1,
+            sh_flags: 6,
+            sh_addr: 0x1000,
+            sh_offset: 0x1000,
+            sh_size: 0x185,
+            sh_link: 0,
+            sh_info: 0,
+            sh_addralign: 16,
+            sh_entsize: 0,
+        };
+        test_serde_roundtrip(
+            &shdr,
+            concat!(
+                r#"{"sh_name":27,"sh_type":1,"sh_flags":6,"sh_addr":4096,"sh_offset":4096,"#,
+                r#""sh_size":389,"sh_link":0,"sh_info":0,"sh_addralign":16,"sh_entsize":0}"#
+            ),
+        );
+    }
+}
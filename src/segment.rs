@@ -1,7 +1,11 @@
 //! Parsing the Program Header table aka Segment table aka `Elf_Phdr`
+use crate::abi;
 use crate::endian::EndianParse;
 use crate::file::Class;
-use crate::parse::{ParseAt, ParseError, ParsingTable};
+use crate::parse::{file_range, ParseAt, ParseError, ParsingTable, SerializeAt};
+use crate::section::SectionHeader;
+#[cfg(feature = "to_str")]
+use crate::to_str::ReadelfDisplay;
 
 pub type SegmentTable<'data, E> = ParsingTable<'data, E, ProgramHeader>;
 
@@ -41,7 +45,9 @@ pub struct Elf64_Phdr {
 ///
 /// The program header table is an array of program header structures describing
 /// the various segments for program execution.
-#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
 pub struct ProgramHeader {
     /// Program segment type
     pub p_type: u32,
@@ -61,6 +67,9 @@ pub struct ProgramHeader {
     pub p_align: u64,
 }
 
+pub const ELF32_PHDR_SIZE: usize = 32;
+pub const ELF64_PHDR_SIZE: usize = 56;
+
 impl ParseAt for ProgramHeader {
     fn parse_at<E: EndianParse>(
         endian: E,
@@ -105,21 +114,230 @@ impl ParseAt for ProgramHeader {
     #[inline]
     fn size_for(class: Class) -> usize {
         match class {
-            Class::ELF32 => 32,
-            Class::ELF64 => 56,
+            Class::ELF32 => ELF32_PHDR_SIZE,
+            Class::ELF64 => ELF64_PHDR_SIZE,
+        }
+    }
+}
+
+impl SerializeAt for ProgramHeader {
+    fn serialize_at<E: EndianParse>(
+        &self,
+        endian: E,
+        class: Class,
+        offset: &mut usize,
+        data: &mut [u8],
+    ) -> Result<(), ParseError> {
+        match class {
+            Class::ELF32 => {
+                endian.write_u32_at(offset, data, self.p_type)?;
+                endian.write_u32_at(offset, data, self.p_offset.try_into()?)?;
+                endian.write_u32_at(offset, data, self.p_vaddr.try_into()?)?;
+                endian.write_u32_at(offset, data, self.p_paddr.try_into()?)?;
+                endian.write_u32_at(offset, data, self.p_filesz.try_into()?)?;
+                endian.write_u32_at(offset, data, self.p_memsz.try_into()?)?;
+                endian.write_u32_at(offset, data, self.p_flags)?;
+                endian.write_u32_at(offset, data, self.p_align.try_into()?)
+            }
+            Class::ELF64 => {
+                // Note: 64-bit fields are in a different order
+                endian.write_u32_at(offset, data, self.p_type)?;
+                endian.write_u32_at(offset, data, self.p_flags)?;
+                endian.write_u64_at(offset, data, self.p_offset)?;
+                endian.write_u64_at(offset, data, self.p_vaddr)?;
+                endian.write_u64_at(offset, data, self.p_paddr)?;
+                endian.write_u64_at(offset, data, self.p_filesz)?;
+                endian.write_u64_at(offset, data, self.p_memsz)?;
+                endian.write_u64_at(offset, data, self.p_align)
+            }
         }
     }
 }
 
 impl ProgramHeader {
-    /// Helper method which uses checked integer math to get a tuple of (start, end) for
-    /// the location in bytes for this ProgramHeader's data in the file.
-    /// i.e. (p_offset, p_offset + p_filesz)
+    /// Helper method which uses checked integer math to get the range of file offsets for
+    /// this ProgramHeader's data in the file, i.e. p_offset..p_offset + p_filesz
+    pub(crate) fn file_range(&self) -> Result<core::ops::Range<u64>, ParseError> {
+        file_range(self.p_offset, self.p_filesz)
+    }
+
+    /// Like [ProgramHeader::file_range], but as a tuple of (start, end) positions in memory
+    #[cfg(feature = "alloc")]
     pub(crate) fn get_file_data_range(&self) -> Result<(usize, usize), ParseError> {
-        let start: usize = self.p_offset.try_into()?;
-        let size: usize = self.p_filesz.try_into()?;
-        let end = start.checked_add(size).ok_or(ParseError::IntegerOverflow)?;
-        Ok((start, end))
+        let range = self.file_range()?;
+        Ok((range.start.try_into()?, range.end.try_into()?))
+    }
+
+    /// Check whether the given section's memory lies within this segment's, i.e. whether
+    /// sh_addr..sh_addr + sh_size is inside p_vaddr..p_vaddr + p_memsz.
+    ///
+    /// This follows the memory half of binutils' `ELF_SECTION_IN_SEGMENT`: sections without
+    /// [SHF_ALLOC](abi::SHF_ALLOC) are never in a segment's memory, [SHT_NOBITS](abi::SHT_NOBITS)
+    /// sections count with their full size even though they have no file data, and `.tbss`
+    /// sections (NOBITS and [SHF_TLS](abi::SHF_TLS)) only count for the [PT_TLS](abi::PT_TLS)
+    /// segment since they take no space in the others. An empty section counts when its address
+    /// is inside the segment, or at the start of an empty segment.
+    pub fn contains_section(&self, shdr: &SectionHeader) -> bool {
+        if shdr.sh_flags & abi::SHF_ALLOC as u64 == 0 {
+            return false;
+        }
+        let tls_flag = shdr.sh_flags & abi::SHF_TLS as u64 != 0;
+        if shdr.sh_type == abi::SHT_NOBITS && tls_flag && self.p_type != abi::PT_TLS {
+            return false;
+        }
+        let start = match shdr.sh_addr.checked_sub(self.p_vaddr) {
+            Some(start) => start,
+            None => return false,
+        };
+        if shdr.sh_size == 0 {
+            return start < self.p_memsz || (start == 0 && self.p_memsz == 0);
+        }
+        start
+            .checked_add(shdr.sh_size)
+            .map_or(false, |end| end <= self.p_memsz)
+    }
+
+    /// Get a value which renders the program header in the style of a `readelf -lW` line,
+    /// with the columns sized for the given class and processor-specific types named for the
+    /// given machine. See [ProgramHeader]'s Display impl.
+    #[cfg(feature = "to_str")]
+    pub fn display(&self, class: Class, e_machine: u16) -> ReadelfDisplay<'_, ProgramHeader> {
+        ReadelfDisplay {
+            value: self,
+            class,
+            e_machine,
+        }
+    }
+}
+
+/// The thread-local storage template described by a [PT_TLS](crate::abi::PT_TLS) segment,
+/// see [ElfBytes::tls_segment](crate::ElfBytes::tls_segment).
+///
+/// Each thread gets its own TLS block of `memsz` bytes aligned to `align`, which starts
+/// with a copy of the template's file data (`.tdata`) and is zero-filled after it (`.tbss`).
+/// The st_values of [STT_TLS](crate::abi::STT_TLS) symbols are offsets into this block.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TlsSegment {
+    /// The PT_TLS program header itself
+    pub phdr: ProgramHeader,
+    /// The range of file offsets of the initialization template, p_offset..p_offset + p_filesz
+    pub template: core::ops::Range<u64>,
+    /// The size of each thread's TLS block, including the zero-filled part after the template
+    pub memsz: u64,
+    /// The alignment of each thread's TLS block, which is at least 1
+    pub align: u64,
+}
+
+impl TlsSegment {
+    /// Describe the TLS block of the given PT_TLS program header. Returns a
+    /// [ParseError::IntegerOverflow] if its template's file range overflows.
+    pub fn new(phdr: ProgramHeader) -> Result<Self, ParseError> {
+        Ok(TlsSegment {
+            template: phdr.file_range()?,
+            memsz: phdr.p_memsz,
+            align: phdr.p_align.max(1),
+            phdr,
+        })
+    }
+
+    /// The size of the zero-filled part of the TLS block after the template, i.e. `.tbss`
+    pub fn zero_fill_size(&self) -> u64 {
+        self.memsz.saturating_sub(self.phdr.p_filesz)
+    }
+}
+
+/// How much of an object's relocated data its [PT_GNU_RELRO](crate::abi::PT_GNU_RELRO)
+/// segment makes read-only, in the terms checksec uses. See
+/// [ElfBytes::relro](crate::ElfBytes::relro).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Relro {
+    /// There's no PT_GNU_RELRO segment, so all relocated data stays writable
+    None,
+    /// The PT_GNU_RELRO segment protects some data, but the lazily bound PLT slots in `.got.plt`
+    /// stay writable
+    Partial,
+    /// The object is bound at load time and `.got.plt` (if any) is read-only after relocation
+    Full,
+}
+
+/// Which of the sections that attackers like to overwrite lie within the
+/// [PT_GNU_RELRO](crate::abi::PT_GNU_RELRO) segment, see
+/// [ElfBytes::relro_coverage](crate::ElfBytes::relro_coverage).
+///
+/// Each field is empty if the object doesn't have that section, or `Some(false)` if the
+/// section is only partly covered or not at all.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RelroCoverage {
+    /// The PT_GNU_RELRO program header itself
+    pub phdr: ProgramHeader,
+    /// `.got.plt`, the PLT's function pointers which lazy binding writes to
+    pub got_plt: Option<bool>,
+    /// `.got`, the other GOT entries
+    pub got: Option<bool>,
+    /// `.init_array`, the constructor pointers
+    pub init_array: Option<bool>,
+    /// `.fini_array`, the destructor pointers
+    pub fini_array: Option<bool>,
+    /// `.dynamic`, whose entries the dynamic linker reads
+    pub dynamic: Option<bool>,
+}
+
+/// Renders the program header in the style of an ELF64 `readelf -lW` line: type, offset,
+/// virtual and physical addresses, file and memory sizes, flags and alignment. Use
+/// [ProgramHeader::display] for ELF32's narrower columns and processor-specific type names.
+#[cfg(feature = "to_str")]
+impl core::fmt::Display for ProgramHeader {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.display(Class::ELF64, abi::EM_NONE).fmt(f)
+    }
+}
+
+#[cfg(feature = "to_str")]
+impl core::fmt::Display for ReadelfDisplay<'_, ProgramHeader> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        use crate::to_str::{p_type_to_str, write_padded};
+
+        let phdr = self.value;
+        // p_type_to_str only knows the generic and GNU types, so name the processor-specific
+        // ones readelf knows here
+        let name = match (self.e_machine, phdr.p_type) {
+            (abi::EM_ARM, abi::PT_ARM_EXIDX) => Some("EXIDX"),
+            (abi::EM_AARCH64, abi::PT_AARCH64_MEMTAG_MTE) => Some("AARCH64_MEMTAG_MTE"),
+            (abi::EM_RISCV, abi::PT_RISCV_ATTRIBUTES) => Some("RISCV_ATTRIBUTES"),
+            (_, p_type) => p_type_to_str(p_type).map(|name| &name[3..]),
+        };
+        match (name, phdr.p_type) {
+            // readelf cuts long names off at the column width
+            (Some(name), _) => write!(f, "{name:<14.14}")?,
+            (None, p_type @ abi::PT_LOOS..=abi::PT_HIOS) => {
+                write_padded(f, 14, format_args!("LOOS+{:#x}", p_type - abi::PT_LOOS))?
+            }
+            (None, p_type @ abi::PT_LOPROC..=abi::PT_HIPROC) => {
+                write_padded(f, 14, format_args!("LOPROC+{:#x}", p_type - abi::PT_LOPROC))?
+            }
+            (None, p_type) => write_padded(f, 14, format_args!("<unknown>: {p_type:x}"))?,
+        }
+        let flag = |bit, key| if phdr.p_flags & bit != 0 { key } else { ' ' };
+        match self.class {
+            Class::ELF32 => write!(
+                f,
+                " {:#08x} {:#010x} {:#010x} {:#07x} {:#07x}",
+                phdr.p_offset, phdr.p_vaddr, phdr.p_paddr, phdr.p_filesz, phdr.p_memsz
+            )?,
+            Class::ELF64 => write!(
+                f,
+                " {:#08x} {:#018x} {:#018x} {:#08x} {:#08x}",
+                phdr.p_offset, phdr.p_vaddr, phdr.p_paddr, phdr.p_filesz, phdr.p_memsz
+            )?,
+        }
+        write!(
+            f,
+            " {}{}{} {:#x}",
+            flag(abi::PF_R, 'R'),
+            flag(abi::PF_W, 'W'),
+            flag(abi::PF_X, 'E'),
+            phdr.p_align
+        )
     }
 }
 
@@ -221,3 +439,135 @@ mod parse_tests {
         test_parse_fuzz_too_short::<_, ProgramHeader>(BigEndian, Class::ELF64);
     }
 }
+
+#[cfg(test)]
+mod serialize_tests {
+    use super::*;
+    use crate::endian::{BigEndian, LittleEndian};
+    use crate::parse::test_serialize_roundtrip;
+
+    #[test]
+    fn serialize_phdr32_lsb_roundtrip() {
+        test_serialize_roundtrip::<_, ProgramHeader>(LittleEndian, Class::ELF32);
+    }
+
+    #[test]
+    fn serialize_phdr32_msb_roundtrip() {
+        test_serialize_roundtrip::<_, ProgramHeader>(BigEndian, Class::ELF32);
+    }
+
+    #[test]
+    fn serialize_phdr64_lsb_roundtrip() {
+        test_serialize_roundtrip::<_, ProgramHeader>(LittleEndian, Class::ELF64);
+    }
+
+    #[test]
+    fn serialize_phdr64_msb_roundtrip() {
+        test_serialize_roundtrip::<_, ProgramHeader>(BigEndian, Class::ELF64);
+    }
+}
+
+#[cfg(all(test, feature = "to_str"))]
+mod display_tests {
+    use super::*;
+
+    #[test]
+    fn display_program_header() {
+        // The rows for the second LOAD and GNU_STACK from `readelf -lW sample-objects/basic.x86_64`
+        let load = ProgramHeader {
+            p_type: abi::PT_LOAD,
+            p_offset: 0x6c0,
+            p_vaddr: 0x6006c0,
+            p_paddr: 0x6006c0,
+            p_filesz: 0x228,
+            p_memsz: 0x40260,
+            p_flags: abi::PF_R | abi::PF_W,
+            p_align: 0x200000,
+        };
+        assert_eq!(
+            format!("{load}"),
+            "LOAD           0x0006c0 0x00000000006006c0 0x00000000006006c0 0x000228 0x040260 RW  0x200000"
+        );
+        let stack = ProgramHeader {
+            p_type: abi::PT_GNU_STACK,
+            p_offset: 0,
+            p_vaddr: 0,
+            p_paddr: 0,
+            p_filesz: 0,
+            p_memsz: 0,
+            p_flags: abi::PF_R | abi::PF_W,
+            p_align: 0x10,
+        };
+        assert_eq!(
+            format!("{stack}"),
+            "GNU_STACK      0x000000 0x0000000000000000 0x0000000000000000 0x000000 0x000000 RW  0x10"
+        );
+    }
+
+    #[test]
+    fn display_program_header_elf32() {
+        // The first LOAD row from `readelf -lW sample-objects/symver.armhf.so`
+        let load = ProgramHeader {
+            p_type: abi::PT_LOAD,
+            p_offset: 0,
+            p_vaddr: 0,
+            p_paddr: 0,
+            p_filesz: 0x644,
+            p_memsz: 0x644,
+            p_flags: abi::PF_R | abi::PF_X,
+            p_align: 0x10000,
+        };
+        assert_eq!(
+            format!("{}", load.display(Class::ELF32, abi::EM_ARM)),
+            "LOAD           0x000000 0x00000000 0x00000000 0x00644 0x00644 R E 0x10000"
+        );
+    }
+
+    #[test]
+    fn display_processor_specific_types() {
+        let mut phdr = ProgramHeader {
+            p_type: abi::PT_RISCV_ATTRIBUTES,
+            p_offset: 0x106b,
+            p_vaddr: 0,
+            p_paddr: 0,
+            p_filesz: 0x33,
+            p_memsz: 0,
+            p_flags: abi::PF_R,
+            p_align: 1,
+        };
+        assert_eq!(
+            format!("{}", phdr.display(Class::ELF64, abi::EM_RISCV)),
+            "RISCV_ATTRIBUT 0x00106b 0x0000000000000000 0x0000000000000000 0x000033 0x000000 R   0x1"
+        );
+        assert!(format!("{phdr}").starts_with("LOPROC+0x3     0x00106b "));
+        phdr.p_type = 0x12345;
+        assert!(format!("{phdr}").starts_with("<unknown>: 12345 0x00106b "));
+    }
+}
+
+#[cfg(all(test, feature = "serde"))]
+mod serde_tests {
+    use super::*;
+    use crate::parse::test_serde_roundtrip;
+
+    #[test]
+    fn serde_program_header() {
+        let phdr = ProgramHeader {
+            p_type: 1,
+            p_offset: 0,
+            p_vaddr: 0x400000,
+            p_paddr: 0x400000,
+            p_filesz: 0x6b4,
+            p_memsz: 0x6b4,
+            p_flags: 5,
+            p_align: 0x200000,
+        };
+        test_serde_roundtrip(
+            &phdr,
+            concat!(
+                r#"{"p_type":1,"p_offset":0,"p_vaddr":4194304,"p_paddr":4194304,"#,
+                r#""p_filesz":1716,"p_memsz":1716,"p_flags":5,"p_align":2097152}"#
+            ),
+        );
+    }
+}
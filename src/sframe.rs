@@ -0,0 +1,419 @@
+//! Parsing the SFrame stack trace format from `.sframe` sections (`SHT_GNU_SFRAME`) and
+//! `PT_GNU_SFRAME` segments
+//!
+//! An SFrame section starts with a header describing the target and the sizes of its two
+//! sub-sections: an array of function descriptor entries (FDEs), sorted by function address,
+//! followed by the frame row entries (FREs) that each FDE's rows are found in. This parses the
+//! header and the FDEs, leaving the FREs as raw data.
+//!
+//! See: <https://sourceware.org/binutils/docs/sframe-spec.html>
+//!
+//! Example usage:
+//! ```
+//! use elf::abi;
+//! use elf::endian::AnyEndian;
+//! use elf::ElfBytes;
+//!
+//! let path = std::path::PathBuf::from("sample-objects/sframe.x86_64.so");
+//! let file_data = std::fs::read(path).expect("Could not read file.");
+//! let file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("Open test1");
+//!
+//! let sframe = file
+//!     .sframe()
+//!     .expect("section should parse")
+//!     .expect("file should have an .sframe section");
+//! assert_eq!(sframe.header.abi_arch, abi::SFRAME_ABI_AMD64_ENDIAN_LITTLE);
+//!
+//! let funcs: Vec<_> = sframe
+//!     .fdes()
+//!     .map(|fde| (fde.func_start_address, fde.func_size, fde.func_num_fres))
+//!     .collect();
+//! assert_eq!(funcs, vec![(0x1000, 4, 1), (0x1004, 48, 3)]);
+//! ```
+use crate::abi;
+use crate::endian::EndianParse;
+use crate::parse::{ParseError, ReadBytesExt};
+
+/// The name of the section holding SFrame data
+pub const SFRAME_SECTION_NAME: &str = ".sframe";
+
+/// The size of the SFrame preamble and header, not including the auxiliary header
+pub const SFRAME_HEADER_SIZE: usize = 28;
+
+/// The header at the start of an SFrame section
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SFrameHeader {
+    /// The format version, see the `SFRAME_VERSION_*` values
+    pub version: u8,
+    /// See the `SFRAME_F_*` bits
+    pub flags: u8,
+    /// The target ABI and architecture, see the `SFRAME_ABI_*` values
+    pub abi_arch: u8,
+    /// The fixed offset of the frame pointer from the CFA, for ABIs that have one
+    pub cfa_fixed_fp_offset: i8,
+    /// The fixed offset of the return address from the CFA, for ABIs that have one
+    pub cfa_fixed_ra_offset: i8,
+    /// The size of the auxiliary header which follows this header
+    pub auxhdr_len: u8,
+    /// The number of function descriptor entries
+    pub num_fdes: u32,
+    /// The number of frame row entries
+    pub num_fres: u32,
+    /// The size of the frame row entries sub-section in bytes
+    pub fre_len: u32,
+    /// The offset of the function descriptor entries sub-section from the end of the headers
+    pub fdeoff: u32,
+    /// The offset of the frame row entries sub-section from the end of the headers
+    pub freoff: u32,
+}
+
+impl SFrameHeader {
+    /// Parse the header at the start of an SFrame section's data, checking its magic number
+    /// and version.
+    pub fn parse<E: EndianParse>(endian: E, data: &[u8]) -> Result<Self, ParseError> {
+        let mut offset = 0;
+        let magic = endian.parse_u16_at(&mut offset, data)?;
+        if magic != abi::SFRAME_MAGIC {
+            let bytes = data.get_bytes(0..2)?;
+            return Err(ParseError::BadMagic([bytes[0], bytes[1], 0, 0]));
+        }
+        let version = endian.parse_u8_at(&mut offset, data)?;
+        if version != abi::SFRAME_VERSION_1 && version != abi::SFRAME_VERSION_2 {
+            return Err(ParseError::UnsupportedVersion((
+                version as u64,
+                abi::SFRAME_VERSION_2 as u64,
+            )));
+        }
+        Ok(SFrameHeader {
+            version,
+            flags: endian.parse_u8_at(&mut offset, data)?,
+            abi_arch: endian.parse_u8_at(&mut offset, data)?,
+            cfa_fixed_fp_offset: endian.parse_u8_at(&mut offset, data)? as i8,
+            cfa_fixed_ra_offset: endian.parse_u8_at(&mut offset, data)? as i8,
+            auxhdr_len: endian.parse_u8_at(&mut offset, data)?,
+            num_fdes: endian.parse_u32_at(&mut offset, data)?,
+            num_fres: endian.parse_u32_at(&mut offset, data)?,
+            fre_len: endian.parse_u32_at(&mut offset, data)?,
+            fdeoff: endian.parse_u32_at(&mut offset, data)?,
+            freoff: endian.parse_u32_at(&mut offset, data)?,
+        })
+    }
+
+    /// The size of the headers, including the auxiliary header
+    pub fn size(&self) -> usize {
+        SFRAME_HEADER_SIZE + self.auxhdr_len as usize
+    }
+
+    /// The size of each function descriptor entry, which depends on the version
+    pub fn fde_size(&self) -> usize {
+        match self.version {
+            abi::SFRAME_VERSION_1 => 17,
+            _ => 20,
+        }
+    }
+}
+
+/// A function descriptor entry from an SFrame section
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SFrameFde {
+    /// The address of the start of the function, resolved from the entry's relative offset
+    pub func_start_address: u64,
+    /// The size of the function in bytes
+    pub func_size: u32,
+    /// The offset of the function's first frame row entry in the FRE sub-section
+    pub func_start_fre_off: u32,
+    /// The number of frame row entries for the function
+    pub func_num_fres: u32,
+    /// The FRE type, FDE type, and (for AArch64) pointer authentication key bits
+    pub func_info: u8,
+    /// The size of the repetitive code block, for [abi::SFRAME_FDE_TYPE_PCMASK] functions.
+    /// This is 0 for version 1, which doesn't have the field.
+    pub func_rep_size: u8,
+}
+
+impl SFrameFde {
+    /// The size of the start address offsets in this function's frame row entries, see the
+    /// `SFRAME_FRE_TYPE_*` values
+    pub fn fre_type(&self) -> u8 {
+        self.func_info & 0xf
+    }
+
+    /// How this function's frame row entries' start addresses are interpreted, see the
+    /// `SFRAME_FDE_TYPE_*` values
+    pub fn fde_type(&self) -> u8 {
+        (self.func_info >> 4) & 0x1
+    }
+
+    /// Which AArch64 pointer authentication key signs the return addresses: 0 for A, 1 for B
+    pub fn pauth_key(&self) -> u8 {
+        (self.func_info >> 5) & 0x1
+    }
+}
+
+/// The contents of an SFrame section
+///
+/// The function start addresses are resolved relative to `addr`, the virtual address that the
+/// section gets loaded at.
+#[derive(Debug, Clone, Copy)]
+pub struct SFrameSection<'data, E: EndianParse> {
+    endian: E,
+    addr: u64,
+    data: &'data [u8],
+    /// The section's header
+    pub header: SFrameHeader,
+}
+
+impl<'data, E: EndianParse> SFrameSection<'data, E> {
+    /// Parse the header of the SFrame data loaded at `addr`, and check that the FDE and FRE
+    /// sub-sections it describes fit in the data.
+    pub fn new(endian: E, addr: u64, data: &'data [u8]) -> Result<Self, ParseError> {
+        let header = SFrameHeader::parse(endian, data)?;
+
+        let fdes_len = (header.num_fdes as usize)
+            .checked_mul(header.fde_size())
+            .ok_or(ParseError::IntegerOverflow)?;
+        let fdes_start = header
+            .size()
+            .checked_add(header.fdeoff as usize)
+            .ok_or(ParseError::IntegerOverflow)?;
+        let fdes_end = fdes_start
+            .checked_add(fdes_len)
+            .ok_or(ParseError::IntegerOverflow)?;
+        data.get_bytes(fdes_start..fdes_end)?;
+
+        let fres_start = header
+            .size()
+            .checked_add(header.freoff as usize)
+            .ok_or(ParseError::IntegerOverflow)?;
+        let fres_end = fres_start
+            .checked_add(header.fre_len as usize)
+            .ok_or(ParseError::IntegerOverflow)?;
+        data.get_bytes(fres_start..fres_end)?;
+
+        Ok(SFrameSection {
+            endian,
+            addr,
+            data,
+            header,
+        })
+    }
+
+    /// Get the function descriptor entry at the given index.
+    pub fn get_fde(&self, index: usize) -> Result<SFrameFde, ParseError> {
+        if index >= self.header.num_fdes as usize {
+            return Err(ParseError::BadOffset(index as u64));
+        }
+        // The sub-section's bounds were checked on construction
+        let start = self.header.size() + self.header.fdeoff as usize;
+        let mut offset = start + index * self.header.fde_size();
+        let field_offset = offset;
+
+        let func_start = self.endian.parse_i32_at(&mut offset, self.data)?;
+        let func_size = self.endian.parse_u32_at(&mut offset, self.data)?;
+        let func_start_fre_off = self.endian.parse_u32_at(&mut offset, self.data)?;
+        let func_num_fres = self.endian.parse_u32_at(&mut offset, self.data)?;
+        let func_info = self.endian.parse_u8_at(&mut offset, self.data)?;
+        let func_rep_size = match self.header.version {
+            abi::SFRAME_VERSION_1 => 0,
+            _ => self.endian.parse_u8_at(&mut offset, self.data)?,
+        };
+
+        let base = match self.header.flags & abi::SFRAME_F_FDE_FUNC_START_PCREL {
+            0 => self.addr,
+            _ => self.addr.wrapping_add(field_offset as u64),
+        };
+        Ok(SFrameFde {
+            func_start_address: base.wrapping_add(func_start as i64 as u64),
+            func_size,
+            func_start_fre_off,
+            func_num_fres,
+            func_info,
+            func_rep_size,
+        })
+    }
+
+    /// Get an iterator over the function descriptor entries.
+    pub fn fdes(&self) -> SFrameFdeIterator<'data, E> {
+        SFrameFdeIterator {
+            section: *self,
+            index: 0,
+        }
+    }
+
+    /// Get the raw frame row entries sub-section.
+    pub fn fre_data(&self) -> &'data [u8] {
+        // The sub-section's bounds were checked on construction
+        let start = self.header.size() + self.header.freoff as usize;
+        &self.data[start..start + self.header.fre_len as usize]
+    }
+}
+
+/// Iterates over the function descriptor entries of an [SFrameSection]
+#[derive(Debug, Clone)]
+pub struct SFrameFdeIterator<'data, E: EndianParse> {
+    section: SFrameSection<'data, E>,
+    index: usize,
+}
+
+impl<'data, E: EndianParse> Iterator for SFrameFdeIterator<'data, E> {
+    type Item = SFrameFde;
+    fn next(&mut self) -> Option<Self::Item> {
+        // The FDE sub-section's bounds were checked on construction, so getting entries
+        // can't fail
+        let fde = self.section.get_fde(self.index).ok()?;
+        self.index += 1;
+        Some(fde)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::endian::{BigEndian, LittleEndian};
+
+    // A version 2 section with 2 FDEs and 4 bytes of FREs, at address 0x2000
+    #[rustfmt::skip]
+    const SFRAME_V2: [u8; 72] = [
+        // preamble
+        0xe2, 0xde, 0x02, 0x01,
+        // abi/arch, fp offset, ra offset, auxhdr len
+        0x03, 0x00, 0xf8, 0x00,
+        // num fdes, num fres, fre len
+        0x02, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00,
+        // fde off, fre off
+        0x00, 0x00, 0x00, 0x00, 0x28, 0x00, 0x00, 0x00,
+        // fde 0: start -0x1000, size 0x10, fre off 0, 1 fre, ADDR1, rep size 0
+        0x00, 0xf0, 0xff, 0xff, 0x10, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        // fde 1: start -0xff0, size 0x20, fre off 2, 1 fre, PCMASK ADDR2, rep size 16
+        0x10, 0xf0, 0xff, 0xff, 0x20, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00,
+        0x01, 0x00, 0x00, 0x00, 0x11, 0x10, 0x00, 0x00,
+        // fres
+        0x00, 0x03, 0x00, 0x03,
+    ];
+
+    #[test]
+    fn parse_header() {
+        let section = SFrameSection::new(LittleEndian, 0x2000, &SFRAME_V2).expect("should parse");
+        assert_eq!(
+            section.header,
+            SFrameHeader {
+                version: abi::SFRAME_VERSION_2,
+                flags: abi::SFRAME_F_FDE_SORTED,
+                abi_arch: abi::SFRAME_ABI_AMD64_ENDIAN_LITTLE,
+                cfa_fixed_fp_offset: 0,
+                cfa_fixed_ra_offset: -8,
+                auxhdr_len: 0,
+                num_fdes: 2,
+                num_fres: 2,
+                fre_len: 4,
+                fdeoff: 0,
+                freoff: 0x28,
+            }
+        );
+        assert_eq!(section.fre_data(), &[0x00, 0x03, 0x00, 0x03]);
+    }
+
+    #[test]
+    fn parse_fdes() {
+        let section = SFrameSection::new(LittleEndian, 0x2000, &SFRAME_V2).expect("should parse");
+        let fdes: Vec<_> = section.fdes().collect();
+        assert_eq!(
+            fdes,
+            vec![
+                SFrameFde {
+                    func_start_address: 0x1000,
+                    func_size: 0x10,
+                    func_start_fre_off: 0,
+                    func_num_fres: 1,
+                    func_info: 0x00,
+                    func_rep_size: 0,
+                },
+                SFrameFde {
+                    func_start_address: 0x1010,
+                    func_size: 0x20,
+                    func_start_fre_off: 2,
+                    func_num_fres: 1,
+                    func_info: 0x11,
+                    func_rep_size: 16,
+                },
+            ]
+        );
+        assert_eq!(fdes[1].fre_type(), abi::SFRAME_FRE_TYPE_ADDR2);
+        assert_eq!(fdes[1].fde_type(), abi::SFRAME_FDE_TYPE_PCMASK);
+        assert_eq!(fdes[1].pauth_key(), 0);
+
+        let result = section.get_fde(2);
+        assert!(
+            matches!(result, Err(ParseError::BadOffset(2))),
+            "Unexpected Error type found: {result:?}"
+        );
+    }
+
+    #[test]
+    fn parse_pcrel_fdes() {
+        let mut data = SFRAME_V2;
+        data[3] |= abi::SFRAME_F_FDE_FUNC_START_PCREL;
+        let section = SFrameSection::new(LittleEndian, 0x2000, &data).expect("should parse");
+        let starts: Vec<_> = section.fdes().map(|fde| fde.func_start_address).collect();
+        // relative to the FDEs at 0x201c and 0x2030
+        assert_eq!(starts, vec![0x101c, 0x1040]);
+    }
+
+    #[test]
+    fn parse_big_endian_magic() {
+        let data = [0xde, 0xe2, 0x02, 0x00];
+        let result = SFrameHeader::parse(BigEndian, &data);
+        assert!(
+            matches!(result, Err(ParseError::SliceReadError(_))),
+            "Unexpected Error type found: {result:?}"
+        );
+
+        // the magic is in the target's byte order
+        let result = SFrameHeader::parse(LittleEndian, &data);
+        assert!(
+            matches!(result, Err(ParseError::BadMagic([0xde, 0xe2, 0, 0]))),
+            "Unexpected Error type found: {result:?}"
+        );
+    }
+
+    #[test]
+    fn bad_version_errors() {
+        let mut data = SFRAME_V2;
+        data[2] = 3;
+        let result = SFrameSection::new(LittleEndian, 0, &data);
+        assert!(
+            matches!(result, Err(ParseError::UnsupportedVersion((3, 2)))),
+            "Unexpected Error type found: {result:?}"
+        );
+    }
+
+    #[test]
+    fn sub_sections_past_end_error() {
+        // too many FDEs
+        let mut data = SFRAME_V2;
+        data[8] = 3;
+        let result = SFrameSection::new(LittleEndian, 0, &data);
+        assert!(
+            matches!(result, Err(ParseError::SliceReadError((28, 88)))),
+            "Unexpected Error type found: {result:?}"
+        );
+
+        // FREs past the end
+        let mut data = SFRAME_V2;
+        data[16] = 5;
+        let result = SFrameSection::new(LittleEndian, 0, &data);
+        assert!(
+            matches!(result, Err(ParseError::SliceReadError((68, 73)))),
+            "Unexpected Error type found: {result:?}"
+        );
+    }
+
+    #[test]
+    fn parse_fuzz_too_short() {
+        for n in 0..SFRAME_V2.len() {
+            let result = SFrameSection::new(LittleEndian, 0, &SFRAME_V2[..n]);
+            assert!(result.is_err(), "should fail to parse {n} bytes");
+        }
+    }
+}
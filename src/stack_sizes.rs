@@ -0,0 +1,191 @@
+//! Parsing the `.stack_sizes` sections emitted by LLVM's `-fstack-size-section`
+//!
+//! Each entry is a function's address (4 or 8 bytes, depending on the ELF class) followed by
+//! the ULEB128-encoded size of its stack frame. With `-ffunction-sections`, there's a separate
+//! `.stack_sizes` section for each function, linked by `sh_link` to the text section it
+//! describes.
+//!
+//! Example usage:
+//! ```
+//! use elf::endian::AnyEndian;
+//! use elf::ElfBytes;
+//!
+//! # #[cfg(feature = "alloc")]
+//! # {
+//! let path = std::path::PathBuf::from("sample-objects/stack_sizes.x86_64");
+//! let file_data = std::fs::read(path).expect("Could not read file.");
+//! let file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("Open test1");
+//!
+//! let sizes: Vec<_> = file
+//!     .function_stack_sizes()
+//!     .expect("section should parse")
+//!     .into_iter()
+//!     .map(|func| (func.name.unwrap(), func.stack_size))
+//!     .collect();
+//! assert_eq!(sizes, vec![("small", 16), ("big", 256)]);
+//! # }
+//! ```
+use crate::endian::EndianParse;
+use crate::file::Class;
+use crate::parse::{parse_uleb128, ParseError};
+
+/// The name of the sections holding stack sizes
+pub const STACK_SIZES_SECTION_NAME: &str = ".stack_sizes";
+
+/// An entry in a `.stack_sizes` section
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StackSize {
+    /// The address of the function
+    pub addr: u64,
+    /// The size of the function's stack frame in bytes
+    pub stack_size: u64,
+}
+
+/// Iterates over the entries of a `.stack_sizes` section.
+///
+/// Yields an error and stops if an entry is truncated or its ULEB128 size is malformed.
+#[derive(Debug, Clone)]
+pub struct StackSizeIterator<'data, E: EndianParse> {
+    endian: E,
+    class: Class,
+    data: &'data [u8],
+    offset: usize,
+}
+
+impl<'data, E: EndianParse> StackSizeIterator<'data, E> {
+    /// Parse the entries of a `.stack_sizes` section's data, with addresses sized by `class`.
+    pub fn new(endian: E, class: Class, data: &'data [u8]) -> Self {
+        StackSizeIterator {
+            endian,
+            class,
+            data,
+            offset: 0,
+        }
+    }
+
+    fn parse_next(&mut self) -> Result<StackSize, ParseError> {
+        let addr = match self.class {
+            Class::ELF32 => self.endian.parse_u32_at(&mut self.offset, self.data)? as u64,
+            Class::ELF64 => self.endian.parse_u64_at(&mut self.offset, self.data)?,
+        };
+        let stack_size = parse_uleb128(&mut self.offset, self.data)?;
+        Ok(StackSize { addr, stack_size })
+    }
+}
+
+impl<'data, E: EndianParse> Iterator for StackSizeIterator<'data, E> {
+    type Item = Result<StackSize, ParseError>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.offset >= self.data.len() {
+            return None;
+        }
+        let result = self.parse_next();
+        if result.is_err() {
+            self.offset = self.data.len();
+        }
+        Some(result)
+    }
+}
+
+/// A `.stack_sizes` section found in an object
+#[derive(Debug, Clone)]
+pub struct StackSizesSection<'data, E: EndianParse> {
+    /// The section header index of the `.stack_sizes` section
+    pub shndx: usize,
+    /// The section header index of the text section that the entries describe, from the
+    /// section's `sh_link`. This is 0 if the section isn't linked to a text section.
+    pub text_shndx: usize,
+    /// The section's entries
+    pub sizes: StackSizeIterator<'data, E>,
+}
+
+/// A function's stack size, joined with its name from the symbol table
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FunctionStackSize<'data> {
+    /// The name of the function symbol at the entry's address, if one was found
+    pub name: Option<&'data str>,
+    /// The address of the function
+    pub addr: u64,
+    /// The size of the function's stack frame in bytes
+    pub stack_size: u64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::endian::{BigEndian, LittleEndian};
+
+    #[test]
+    fn parse_elf64_lsb() {
+        let data = [
+            // 16 bytes at 0x400010
+            0x10, 0x00, 0x40, 0x00, 0x00, 0x00, 0x00, 0x00, 0x10,
+            // 256 bytes at 0x400020
+            0x20, 0x00, 0x40, 0x00, 0x00, 0x00, 0x00, 0x00, 0x80, 0x02,
+        ];
+        let sizes: Vec<_> = StackSizeIterator::new(LittleEndian, Class::ELF64, &data)
+            .collect::<Result<_, _>>()
+            .expect("should parse");
+        assert_eq!(
+            sizes,
+            vec![
+                StackSize {
+                    addr: 0x400010,
+                    stack_size: 16
+                },
+                StackSize {
+                    addr: 0x400020,
+                    stack_size: 256
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_elf32_msb() {
+        let data = [
+            0x00, 0x01, 0x00, 0x00, 0x08, 0x00, 0x01, 0x00, 0x04, 0xe5, 0x8e, 0x26,
+        ];
+        let sizes: Vec<_> = StackSizeIterator::new(BigEndian, Class::ELF32, &data)
+            .collect::<Result<_, _>>()
+            .expect("should parse");
+        assert_eq!(
+            sizes,
+            vec![
+                StackSize {
+                    addr: 0x10000,
+                    stack_size: 8
+                },
+                StackSize {
+                    addr: 0x10004,
+                    stack_size: 624485
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_fuzz_too_short() {
+        let data = [0x00, 0x01, 0x00, 0x00, 0x80, 0x02];
+        for n in 1..data.len() {
+            let mut iter = StackSizeIterator::new(BigEndian, Class::ELF32, &data[..n]);
+            let result = iter.next().expect("should yield the error");
+            assert!(result.is_err(), "should fail to parse {n} bytes");
+            assert!(iter.next().is_none());
+        }
+    }
+
+    #[test]
+    fn uleb_overflow_errors() {
+        let mut data = vec![0u8; 4];
+        data.extend_from_slice(&[0xff; 10]);
+        data.push(0x01);
+        let mut iter = StackSizeIterator::new(LittleEndian, Class::ELF32, &data);
+        let result = iter.next().expect("should yield the error");
+        assert!(
+            matches!(result, Err(ParseError::IntegerOverflow)),
+            "Unexpected Error type found: {result:?}"
+        );
+        assert!(iter.next().is_none());
+    }
+}
@@ -2,6 +2,14 @@
 use crate::parse::ParseError;
 use core::str::from_utf8;
 
+#[cfg(feature = "alloc")]
+use core::ops::Bound;
+
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::{borrow::Cow, collections::BTreeMap, string::String, vec, vec::Vec};
+#[cfg(feature = "std")]
+use std::{borrow::Cow, collections::BTreeMap};
+
 #[derive(Debug, Default, Clone, Copy)]
 pub struct StringTable<'data> {
     data: &'data [u8],
@@ -33,6 +41,150 @@ impl<'data> StringTable<'data> {
         let raw_data = self.get_raw(offset)?;
         Ok(from_utf8(raw_data)?)
     }
+
+    /// Like [StringTable::get], but replaces any invalid UTF-8 sequences in the string with
+    /// [U+FFFD REPLACEMENT CHARACTER](char::REPLACEMENT_CHARACTER) instead of erroring.
+    ///
+    /// Offsets outside the table, or strings missing their NUL terminator, are still errors.
+    #[cfg(feature = "alloc")]
+    pub fn get_lossy(&self, offset: usize) -> Result<Cow<'data, str>, ParseError> {
+        let raw_data = self.get_raw(offset)?;
+        Ok(String::from_utf8_lossy(raw_data))
+    }
+
+    /// Iterate over every string in the table, yielding each one's offset along with its
+    /// bytes (without the NUL terminator).
+    ///
+    /// Empty strings (such as the mandatory leading NUL or padding between entries) are
+    /// skipped. A table which doesn't end with a NUL still yields its trailing fragment.
+    pub fn iter(&self) -> StringTableIterator<'data> {
+        StringTableIterator {
+            data: self.data,
+            offset: 0,
+        }
+    }
+
+    /// Like [StringTable::iter], but yields the strings as UTF-8, with any invalid
+    /// sequences replaced by [U+FFFD REPLACEMENT CHARACTER](char::REPLACEMENT_CHARACTER).
+    #[cfg(feature = "alloc")]
+    pub fn strings_lossy(&self) -> impl Iterator<Item = (usize, Cow<'data, str>)> {
+        self.iter()
+            .map(|(offset, raw)| (offset, String::from_utf8_lossy(raw)))
+    }
+}
+
+/// Iterator over the `(offset, string)` pairs in a [StringTable].
+#[derive(Debug, Clone)]
+pub struct StringTableIterator<'data> {
+    data: &'data [u8],
+    offset: usize,
+}
+
+impl<'data> Iterator for StringTableIterator<'data> {
+    type Item = (usize, &'data [u8]);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let rest = self.data.get(self.offset..)?;
+            if rest.is_empty() {
+                return None;
+            }
+
+            let start = self.offset;
+            let len = rest.iter().position(|&b| b == 0u8).unwrap_or(rest.len());
+            // Step past the string and its terminating NUL (if any)
+            self.offset += len + 1;
+            if len != 0 {
+                return Some((start, &rest[..len]));
+            }
+        }
+    }
+}
+
+/// Builder for the contents of a new string table section, such as a `.strtab` or `.shstrtab`.
+///
+/// Identical strings are only stored once. If tail merging is enabled, then a string which is
+/// a suffix of a previously inserted string reuses the end of that string, e.g. inserting
+/// `.text` after `.rela.text` yields an offset 5 bytes into `.rela.text`. Offsets are fixed
+/// as soon as a string is inserted, so inserting longer strings first gives the most merging.
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone)]
+pub struct StringTableBuilder {
+    data: Vec<u8>,
+    // Maps each inserted string (stored byte-reversed, so that suffixes become prefixes)
+    // to the offset it was written to.
+    offsets: BTreeMap<Vec<u8>, u32>,
+    tail_merge: bool,
+}
+
+#[cfg(feature = "alloc")]
+impl StringTableBuilder {
+    /// Create a new builder which deduplicates identical strings.
+    pub fn new() -> Self {
+        let mut offsets = BTreeMap::new();
+        offsets.insert(Vec::new(), 0);
+        StringTableBuilder {
+            data: vec![0u8],
+            offsets,
+            tail_merge: false,
+        }
+    }
+
+    /// Create a new builder which deduplicates identical strings and also reuses the tails
+    /// of previously inserted strings.
+    pub fn with_tail_merging() -> Self {
+        StringTableBuilder {
+            tail_merge: true,
+            ..Self::new()
+        }
+    }
+
+    /// Add a string to the table (if not already present) and return its offset.
+    ///
+    /// The empty string always lives at offset 0.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `s` contains a NUL byte, or if the table grows past `u32::MAX` bytes.
+    pub fn insert(&mut self, s: &[u8]) -> u32 {
+        assert!(!s.contains(&0), "string table entries can't contain a NUL");
+        let key: Vec<u8> = s.iter().rev().copied().collect();
+
+        if let Some((existing, &offset)) = self
+            .offsets
+            .range::<[u8], _>((Bound::Included(key.as_slice()), Bound::Unbounded))
+            .next()
+        {
+            if existing.as_slice() == key.as_slice() {
+                return offset;
+            }
+            if self.tail_merge && existing.starts_with(&key) {
+                return offset + (existing.len() - key.len()) as u32;
+            }
+        }
+
+        let offset: u32 = self
+            .data
+            .len()
+            .try_into()
+            .expect("string table should fit in a u32");
+        self.data.extend_from_slice(s);
+        self.data.push(0);
+        self.offsets.insert(key, offset);
+        offset
+    }
+
+    /// Get the finished string table contents, starting with the mandatory leading NUL.
+    pub fn build(self) -> Vec<u8> {
+        self.data
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Default for StringTableBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 #[cfg(test)]
@@ -70,6 +222,26 @@ mod tests {
         assert_eq!(st.get(1).unwrap(), "ELF");
     }
 
+    #[test]
+    fn test_get_lossy_works() {
+        let data = [0u8, 0x45, 0x4C, 0x46, 0u8, 0x45, 0xff, 0x46, 0u8];
+        let st = StringTable::new(&data);
+        assert!(matches!(st.get_lossy(1).unwrap(), Cow::Borrowed("ELF")));
+        assert!(matches!(st.get(5), Err(ParseError::Utf8Error(_))));
+        assert_eq!(st.get_lossy(5).unwrap(), "E\u{FFFD}F");
+    }
+
+    #[test]
+    fn test_get_lossy_index_out_of_bounds_errors() {
+        let data = [0u8, 0x45, 0x4C, 0x46, 0u8];
+        let st = StringTable::new(&data);
+        let result = st.get_lossy(7);
+        assert!(
+            matches!(result, Err(ParseError::BadOffset(7))),
+            "Unexpected Error type found: {result:?}"
+        );
+    }
+
     #[test]
     fn test_get_raw_index_out_of_bounds_errors() {
         let data = [0u8, 0x45, 0x4C, 0x46, 0u8];
@@ -114,3 +286,97 @@ mod tests {
         );
     }
 }
+
+#[cfg(test)]
+mod iter_tests {
+    use super::*;
+
+    #[test]
+    fn iter_empty_table() {
+        let st = StringTable::default();
+        assert_eq!(st.iter().next(), None);
+        let st = StringTable::new(&[0u8]);
+        assert_eq!(st.iter().next(), None);
+    }
+
+    #[test]
+    fn iter_yields_offsets_and_strings() {
+        let data = b"\0.text\0\0.data\0x\0";
+        let st = StringTable::new(data);
+        let strings: Vec<(usize, &[u8])> = st.iter().collect();
+        assert_eq!(
+            strings,
+            [(1, &b".text"[..]), (8, &b".data"[..]), (14, &b"x"[..])]
+        );
+        for (offset, s) in strings {
+            assert_eq!(st.get_raw(offset).unwrap(), s);
+        }
+    }
+
+    #[test]
+    fn iter_yields_trailing_fragment() {
+        let data = b"\0foo\0bar";
+        let st = StringTable::new(data);
+        let strings: Vec<(usize, &[u8])> = st.iter().collect();
+        assert_eq!(strings, [(1, &b"foo"[..]), (5, &b"bar"[..])]);
+    }
+
+    #[test]
+    fn strings_lossy_replaces_invalid_utf8() {
+        let data = b"\0caf\xe9\0ok\0";
+        let st = StringTable::new(data);
+        let strings: Vec<(usize, Cow<'_, str>)> = st.strings_lossy().collect();
+        assert_eq!(strings.len(), 2);
+        assert_eq!(strings[0], (1, Cow::Owned(String::from("caf\u{FFFD}"))));
+        assert!(matches!(strings[1], (6, Cow::Borrowed("ok"))));
+    }
+}
+
+#[cfg(test)]
+mod builder_tests {
+    use super::*;
+
+    #[test]
+    fn build_empty() {
+        let builder = StringTableBuilder::new();
+        assert_eq!(builder.build(), [0u8]);
+    }
+
+    #[test]
+    fn insert_dedups() {
+        let mut builder = StringTableBuilder::new();
+        assert_eq!(builder.insert(b""), 0);
+        assert_eq!(builder.insert(b"foo"), 1);
+        assert_eq!(builder.insert(b"bar"), 5);
+        assert_eq!(builder.insert(b"foo"), 1);
+        assert_eq!(builder.insert(b"oo"), 9);
+        assert_eq!(builder.build(), b"\0foo\0bar\0oo\0");
+    }
+
+    #[test]
+    fn insert_tail_merges() {
+        let mut builder = StringTableBuilder::with_tail_merging();
+        assert_eq!(builder.insert(b".rela.text"), 1);
+        assert_eq!(builder.insert(b".text"), 6);
+        assert_eq!(builder.insert(b"text"), 7);
+        assert_eq!(builder.insert(b".rela.text"), 1);
+        assert_eq!(builder.insert(b"a.text"), 5);
+        // Not a suffix of anything present, so it doesn't merge
+        assert_eq!(builder.insert(b".rela"), 12);
+        assert_eq!(builder.insert(b"la"), 15);
+        assert_eq!(builder.insert(b""), 0);
+
+        let data = builder.build();
+        assert_eq!(data, b"\0.rela.text\0.rela\0");
+        let st = StringTable::new(&data);
+        assert_eq!(st.get(6).unwrap(), ".text");
+        assert_eq!(st.get(15).unwrap(), "la");
+    }
+
+    #[test]
+    #[should_panic]
+    fn insert_with_nul_panics() {
+        let mut builder = StringTableBuilder::new();
+        builder.insert(b"foo\0bar");
+    }
+}
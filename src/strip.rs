@@ -0,0 +1,543 @@
+//! Removing sections from an ELF object, like `strip` and `objcopy --remove-section`
+//!
+//! [strip_sections] copies an object while leaving out the sections picked by a predicate.
+//! Everything inside a segment keeps its file offset, so the program headers and the loaded
+//! contents come out byte-identical (apart from the file header's section header table fields)
+//! and the result still runs. The remaining sections are laid
+//! out again after that, followed by a rebuilt `.shstrtab` and section header table.
+//!
+//! Example usage:
+//! ```
+//! use elf::endian::AnyEndian;
+//! use elf::strip::{is_strip_all_section, strip_sections};
+//! use elf::ElfBytes;
+//!
+//! let path = std::path::PathBuf::from("sample-objects/basic.x86_64");
+//! let file_data = std::fs::read(path).expect("Could not read file.");
+//! let file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("Open test1");
+//!
+//! let stripped_data = strip_sections(&file, is_strip_all_section).expect("should strip");
+//! assert!(stripped_data.len() < file_data.len());
+//!
+//! let stripped = ElfBytes::<AnyEndian>::minimal_parse(&stripped_data).expect("should parse");
+//! assert!(stripped.symbol_table().expect("should parse").is_none());
+//! assert!(stripped.dynamic_symbol_table().expect("should parse").is_some());
+//! ```
+use crate::abi;
+use crate::endian::EndianParse;
+use crate::file::Class;
+use crate::parse::{ParseAt, ParseError, SerializeAt};
+use crate::section::SectionHeader;
+use crate::string_table::StringTableBuilder;
+use crate::symbol::Symbol;
+use crate::ElfBytes;
+
+#[cfg(not(feature = "std"))]
+use alloc::{borrow::Cow, vec, vec::Vec};
+#[cfg(feature = "std")]
+use std::borrow::Cow;
+
+/// A predicate for [strip_sections] matching what `strip --strip-all` removes from a linked
+/// object: the `.symtab` and `.strtab` sections, and the DWARF debug sections.
+pub fn is_strip_all_section(name: &str, shdr: &SectionHeader) -> bool {
+    shdr.sh_type == abi::SHT_SYMTAB
+        || name == ".strtab"
+        || name.starts_with(".debug")
+        || name.starts_with(".zdebug")
+}
+
+/// Returns true if the section's `sh_info` holds a section header index
+fn info_is_section(shdr: &SectionHeader) -> bool {
+    shdr.sh_flags & abi::SHF_INFO_LINK as u64 != 0
+        || (matches!(shdr.sh_type, abi::SHT_REL | abi::SHT_RELA) && shdr.sh_info != 0)
+}
+
+/// Returns true if the section's `sh_link` holds a section header index
+fn link_is_section(shdr: &SectionHeader) -> bool {
+    shdr.sh_link != 0
+}
+
+/// Copy an ELF object, leaving out the sections for which `remove(name, shdr)` returns true.
+///
+/// Section 0 is never removed. Removing a section also removes the sections that depend on
+/// it: non-allocated sections whose `sh_link` refers to it (like a `.symtab` on its `.strtab`),
+/// and sections whose `sh_info` refers to it (like the relocations that apply to it). Allocated
+/// sections keep their place in the loaded image, so their references to removed sections are
+/// nulled out instead. The old `.shstrtab` is removed unless a kept section uses it as its
+/// string table.
+///
+/// The section indexes in kept non-allocated symbol tables and section groups are updated.
+/// Symbols defined in a removed section become undefined (`SHN_UNDEF`). Allocated symbol
+/// tables like `.dynsym` are part of the loaded image and are left untouched.
+///
+/// Returns a [ParseError::BadOffset] if the object has no section header table, and a
+/// [ParseError::UnexpectedAlignment] or [ParseError::LimitExceeded] if a section that gets
+/// laid out again has an `sh_addralign` that isn't a power of two or is larger than the
+/// file's [max_table_bytes](crate::parse::ParseOptions::max_table_bytes) limit.
+pub fn strip_sections<E, F>(file: &ElfBytes<'_, E>, mut remove: F) -> Result<Vec<u8>, ParseError>
+where
+    E: EndianParse,
+    F: FnMut(&str, &SectionHeader) -> bool,
+{
+    let endian = file.ehdr.endianness;
+    let class = file.ehdr.class;
+    let (shdrs, shstrtab) = file.section_headers_with_strtab()?;
+    let shdrs: Vec<SectionHeader> = match shdrs {
+        Some(shdrs) => shdrs.iter().collect(),
+        None => return Err(ParseError::BadOffset(file.ehdr.e_shoff)),
+    };
+    let mut names = Vec::with_capacity(shdrs.len());
+    for shdr in shdrs.iter() {
+        let name = match shstrtab {
+            Some(ref strtab) => strtab.get(shdr.sh_name as usize)?,
+            None => "",
+        };
+        names.push(name);
+    }
+
+    // Pick out the sections to remove, then the ones depending on them until nothing changes
+    let mut removed: Vec<bool> = shdrs
+        .iter()
+        .zip(names.iter())
+        .enumerate()
+        .map(|(index, (shdr, name))| index != 0 && remove(name, shdr))
+        .collect();
+    let is_removed = |removed: &[bool], index: u32| -> bool {
+        removed.get(index as usize).copied().unwrap_or(false)
+    };
+    loop {
+        let mut changed = false;
+        for (index, shdr) in shdrs.iter().enumerate().skip(1) {
+            if removed[index] {
+                continue;
+            }
+            let alloc = shdr.sh_flags & abi::SHF_ALLOC as u64 != 0;
+            let lost_link = link_is_section(shdr) && is_removed(&removed, shdr.sh_link);
+            let lost_info = info_is_section(shdr) && is_removed(&removed, shdr.sh_info);
+            if !alloc && (lost_link || lost_info) {
+                removed[index] = true;
+                changed = true;
+            }
+        }
+        if !changed {
+            break;
+        }
+    }
+    let shstrndx = match file.ehdr.e_shstrndx {
+        abi::SHN_XINDEX => shdrs[0].sh_link as usize,
+        shstrndx => shstrndx as usize,
+    };
+    if shstrndx != 0 && shstrndx < removed.len() {
+        let in_use = shdrs.iter().enumerate().any(|(index, shdr)| {
+            index != shstrndx && !removed[index] && shdr.sh_link as usize == shstrndx
+        });
+        removed[shstrndx] = !in_use;
+    }
+
+    // Map the old section indexes to new ones, with 0 for removed sections
+    let mut new_index = vec![0u32; shdrs.len()];
+    let mut count = 0u32;
+    for (index, removed) in removed.iter().enumerate() {
+        if !removed {
+            new_index[index] = count;
+            count += 1;
+        }
+    }
+    let remap = |index: u32| new_index.get(index as usize).copied().unwrap_or(0);
+
+    // Everything up to the end of the last segment keeps its place
+    let mut fixed_end = file.ehdr.e_ehsize as usize;
+    let mut segment_ranges = Vec::new();
+    if let Some(phdrs) = file.segments() {
+        let phdrs_end = (file.ehdr.e_phoff as usize)
+            .checked_add(phdrs.len() * file.ehdr.e_phentsize as usize)
+            .ok_or(ParseError::IntegerOverflow)?;
+        fixed_end = fixed_end.max(phdrs_end);
+        for phdr in phdrs.iter().filter(|phdr| phdr.p_filesz > 0) {
+            let (start, end) = phdr.get_file_data_range()?;
+            fixed_end = fixed_end.max(end);
+            segment_ranges.push((start, end));
+        }
+    }
+    let in_segment = |shdr: &SectionHeader| -> bool {
+        let start = shdr.sh_offset;
+        let end = start.saturating_add(match shdr.sh_type {
+            abi::SHT_NOBITS => 0,
+            _ => shdr.sh_size,
+        });
+        segment_ranges
+            .iter()
+            .any(|&(seg_start, seg_end)| start >= seg_start as u64 && end <= seg_end as u64)
+    };
+
+    let mut buf = file
+        .data
+        .get(..fixed_end)
+        .ok_or(ParseError::SliceReadError((0, fixed_end)))?
+        .to_vec();
+
+    // Lay out the kept sections, rewriting the section indexes in their headers and contents
+    let mut shstrtab_builder = StringTableBuilder::with_tail_merging();
+    let mut new_shdrs = Vec::with_capacity(count as usize + 1);
+    for (index, shdr) in shdrs.iter().enumerate() {
+        if removed[index] {
+            continue;
+        }
+        let mut new_shdr = *shdr;
+        if index == 0 {
+            new_shdrs.push(new_shdr);
+            continue;
+        }
+        new_shdr.sh_name = shstrtab_builder.insert(names[index].as_bytes());
+        if link_is_section(shdr) {
+            new_shdr.sh_link = remap(shdr.sh_link);
+        }
+        if info_is_section(shdr) {
+            new_shdr.sh_info = remap(shdr.sh_info);
+        }
+
+        if in_segment(shdr) {
+            new_shdrs.push(new_shdr);
+            continue;
+        }
+        let (data, _) = file.section_data(shdr)?;
+        let data = match shdr.sh_type {
+            abi::SHT_SYMTAB => {
+                let entsize = (shdr.sh_entsize as usize).max(Symbol::size_for(class));
+                Cow::Owned(remap_symbols(endian, class, entsize, data, &remap)?)
+            }
+            abi::SHT_GROUP => Cow::Owned(remap_group(endian, data, &removed, &remap)?),
+            _ => Cow::Borrowed(data),
+        };
+        let align = check_alignment(file, shdr.sh_addralign)?;
+        let offset = align_to(buf.len(), align)?;
+        new_shdr.sh_offset = offset as u64;
+        if shdr.sh_type != abi::SHT_NOBITS {
+            new_shdr.sh_size = data.len() as u64;
+            let padding = (offset - buf.len()) as u64;
+            file.options.check_table_size(padding, file.data.len())?;
+            buf.resize(offset, 0);
+            buf.extend_from_slice(&data);
+        }
+        new_shdrs.push(new_shdr);
+    }
+
+    // Append the rebuilt .shstrtab and the section header table
+    let shstrtab_name = shstrtab_builder.insert(b".shstrtab");
+    let shstrtab_data = shstrtab_builder.build();
+    new_shdrs.push(SectionHeader {
+        sh_name: shstrtab_name,
+        sh_type: abi::SHT_STRTAB,
+        sh_offset: buf.len() as u64,
+        sh_size: shstrtab_data.len() as u64,
+        sh_addralign: 1,
+        ..Default::default()
+    });
+    buf.extend_from_slice(&shstrtab_data);
+
+    let word_align = match class {
+        Class::ELF32 => 4,
+        Class::ELF64 => 8,
+    };
+    let shoff = align_to(buf.len(), word_align)?;
+    let shentsize = SectionHeader::size_for(class);
+    buf.resize(shoff + shentsize * new_shdrs.len(), 0);
+
+    // Fix up the file header, using the extended numbering in section 0 for counts and
+    // indexes that don't fit in the file header
+    let mut ehdr = file.ehdr;
+    ehdr.e_shoff = shoff as u64;
+    ehdr.e_shentsize = shentsize as u16;
+    match u16::try_from(new_shdrs.len()) {
+        Ok(shnum) if shnum < abi::SHN_LORESERVE => {
+            ehdr.e_shnum = shnum;
+            new_shdrs[0].sh_size = 0;
+        }
+        _ => {
+            ehdr.e_shnum = 0;
+            new_shdrs[0].sh_size = new_shdrs.len() as u64;
+        }
+    }
+    let new_shstrndx = new_shdrs.len() - 1;
+    match u16::try_from(new_shstrndx) {
+        Ok(shstrndx) if shstrndx < abi::SHN_LORESERVE => {
+            ehdr.e_shstrndx = shstrndx;
+            new_shdrs[0].sh_link = 0;
+        }
+        _ => {
+            ehdr.e_shstrndx = abi::SHN_XINDEX;
+            new_shdrs[0].sh_link = new_shstrndx.try_into()?;
+        }
+    }
+    ehdr.serialize_at(endian, class, &mut 0, &mut buf)?;
+
+    let mut offset = shoff;
+    for shdr in new_shdrs.iter() {
+        shdr.serialize_at(endian, class, &mut offset, &mut buf)?;
+    }
+    Ok(buf)
+}
+
+/// Check that a section's `sh_addralign` is usable for laying it out again, so that a corrupt
+/// one can't make the padding before it arbitrarily large
+fn check_alignment<E: EndianParse>(file: &ElfBytes<'_, E>, align: u64) -> Result<u64, ParseError> {
+    if align > 1 && !align.is_power_of_two() {
+        return Err(ParseError::UnexpectedAlignment(align.try_into()?));
+    }
+    file.options.check_table_size(align, file.data.len())?;
+    Ok(align)
+}
+
+fn align_to(offset: usize, align: u64) -> Result<usize, ParseError> {
+    if align <= 1 {
+        return Ok(offset);
+    }
+    let align: usize = align.try_into()?;
+    let pad = (align - offset % align) % align;
+    offset.checked_add(pad).ok_or(ParseError::IntegerOverflow)
+}
+
+/// Rewrite the `st_shndx` of each symbol (`entsize` bytes apart) in a symbol table's data
+fn remap_symbols<E: EndianParse>(
+    endian: E,
+    class: Class,
+    entsize: usize,
+    data: &[u8],
+    remap: &impl Fn(u32) -> u32,
+) -> Result<Vec<u8>, ParseError> {
+    let mut out = data.to_vec();
+    let mut start = 0;
+    while start + entsize <= data.len() {
+        let mut offset = start;
+        let mut sym = Symbol::parse_at(endian, class, &mut offset, data)?;
+        if let Some(shndx) = sym.shndx().section() {
+            sym.st_shndx = remap(shndx as u32) as u16;
+        }
+        let mut offset = start;
+        sym.serialize_at(endian, class, &mut offset, &mut out)?;
+        start += entsize;
+    }
+    Ok(out)
+}
+
+/// Rewrite the member section indexes of a section group's data, dropping removed members
+fn remap_group<E: EndianParse>(
+    endian: E,
+    data: &[u8],
+    removed: &[bool],
+    remap: &impl Fn(u32) -> u32,
+) -> Result<Vec<u8>, ParseError> {
+    let mut offset = 0;
+    let flags = endian.parse_u32_at(&mut offset, data)?;
+    let mut members = Vec::new();
+    while offset < data.len() {
+        let member = endian.parse_u32_at(&mut offset, data)?;
+        if !removed.get(member as usize).copied().unwrap_or(false) {
+            members.push(remap(member));
+        }
+    }
+
+    let mut out = vec![0u8; 4 * (members.len() + 1)];
+    let mut offset = 0;
+    endian.write_u32_at(&mut offset, &mut out, flags)?;
+    for member in members {
+        endian.write_u32_at(&mut offset, &mut out, member)?;
+    }
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::endian::{AnyEndian, LittleEndian};
+
+    fn section_names(file: &ElfBytes<'_, AnyEndian>) -> Vec<String> {
+        let (shdrs, strtab) = file.section_headers_with_strtab().expect("should parse");
+        let strtab = strtab.expect("should have names");
+        shdrs
+            .expect("should have shdrs")
+            .iter()
+            .map(|shdr| String::from(strtab.get(shdr.sh_name as usize).expect("should parse")))
+            .collect()
+    }
+
+    #[test]
+    fn strip_all_preserves_segments() {
+        let path = std::path::PathBuf::from("sample-objects/basic.x86_64");
+        let file_data = std::fs::read(path).expect("Could not read file.");
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("Open test1");
+        let stripped_data = strip_sections(&file, is_strip_all_section).expect("should strip");
+        let stripped = ElfBytes::<AnyEndian>::minimal_parse(&stripped_data).expect("should parse");
+
+        let mut expected = section_names(&file);
+        expected.retain(|name| !matches!(name.as_str(), ".symtab" | ".strtab" | ".shstrtab"));
+        expected.push(String::from(".shstrtab"));
+        assert_eq!(section_names(&stripped), expected);
+        // The same size that GNU strip produces
+        assert_eq!(stripped_data.len(), 4440);
+
+        // The program headers and everything they load are untouched, apart from the file
+        // header's section header table fields
+        let phdrs = file.segments().expect("should have phdrs");
+        let stripped_phdrs = stripped.segments().expect("should have phdrs");
+        assert_eq!(
+            phdrs.iter().collect::<Vec<_>>(),
+            stripped_phdrs.iter().collect::<Vec<_>>()
+        );
+        for phdr in phdrs.iter() {
+            let skip = if phdr.p_offset == 0 {
+                0x40.min(phdr.p_filesz as usize)
+            } else {
+                0
+            };
+            assert_eq!(
+                file.segment_data(&phdr).expect("should read")[skip..],
+                stripped.segment_data(&phdr).expect("should read")[skip..]
+            );
+        }
+
+        // The kept sections have the same headers and contents, apart from their names
+        let shdrs = file.section_headers().expect("should have shdrs");
+        let stripped_shdrs = stripped.section_headers().expect("should have shdrs");
+        for index in 1..28 {
+            let mut shdr = shdrs.get(index).expect("should parse");
+            let stripped_shdr = stripped_shdrs.get(index).expect("should parse");
+            if index == 27 {
+                // .comment was moved up to where .symtab was
+                assert_eq!(stripped_shdr.sh_offset, 0x8e8);
+                shdr.sh_offset = 0x8e8;
+            }
+            shdr.sh_name = stripped_shdr.sh_name;
+            assert_eq!(shdr, stripped_shdr);
+            assert_eq!(
+                file.section_data(&shdr).expect("should read"),
+                stripped.section_data(&stripped_shdr).expect("should read")
+            );
+        }
+    }
+
+    #[test]
+    fn strip_relocatable_removes_dependents() {
+        let path = std::path::PathBuf::from("sample-objects/stack_sizes.x86_64.o");
+        let file_data = std::fs::read(path).expect("Could not read file.");
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("Open test1");
+        let stripped_data =
+            strip_sections(&file, |name, _| name == ".text.small").expect("should strip");
+        let stripped = ElfBytes::<AnyEndian>::minimal_parse(&stripped_data).expect("should parse");
+
+        // The .stack_sizes linked to .text.small and its relocations go too, while the
+        // .strtab shared by the section and symbol names stays
+        assert_eq!(
+            section_names(&stripped),
+            vec![
+                "",
+                ".strtab",
+                ".text",
+                ".text.big",
+                ".stack_sizes",
+                ".rela.stack_sizes",
+                ".note.GNU-stack",
+                ".symtab",
+                ".shstrtab",
+            ]
+        );
+        let shdrs = stripped.section_headers().expect("should have shdrs");
+        let links: Vec<_> = shdrs
+            .iter()
+            .map(|shdr| (shdr.sh_link, shdr.sh_info))
+            .collect();
+        assert_eq!(
+            links,
+            vec![
+                (0, 0),
+                (0, 0),
+                (0, 0),
+                (0, 0),
+                (3, 0),
+                (7, 4),
+                (0, 0),
+                (1, 4),
+                (0, 0)
+            ]
+        );
+
+        let (symtab, strtab) = stripped
+            .symbol_table()
+            .expect("should parse")
+            .expect("should have symbols");
+        let symbols: Vec<_> = symtab
+            .iter()
+            .map(|sym| (strtab.get(sym.st_name as usize).unwrap(), sym.st_shndx))
+            .collect();
+        assert_eq!(
+            symbols,
+            vec![
+                ("", abi::SHN_UNDEF),
+                ("stack_sizes.ll", abi::SHN_ABS),
+                ("", abi::SHN_UNDEF),
+                ("", 3),
+                ("small", abi::SHN_UNDEF),
+                ("big", 3),
+            ]
+        );
+
+        let sizes = stripped.stack_sizes().expect("should parse");
+        assert_eq!(sizes.len(), 1);
+        assert_eq!(sizes[0].text_shndx, 3);
+    }
+
+    #[test]
+    fn remap_group_members() {
+        let data = [
+            0x01, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x05, 0x00,
+            0x00, 0x00,
+        ];
+        let removed = [false, false, false, true, false, false];
+        let new_index = [0u32, 1, 2, 0, 3, 4];
+        let remap = |index: u32| new_index[index as usize];
+        let out = remap_group(LittleEndian, &data, &removed, &remap).expect("should remap");
+        assert_eq!(
+            out,
+            vec![0x01, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00]
+        );
+    }
+
+    #[test]
+    fn corrupt_alignment_errors() {
+        let path = std::path::PathBuf::from("sample-objects/basic.x86_64");
+        let mut data = std::fs::read(path).expect("Could not read file.");
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&data).expect("Open test1");
+        let index = section_names(&file)
+            .iter()
+            .position(|name| name == ".comment")
+            .expect("should have .comment");
+        let shoff = file.ehdr.e_shoff as usize;
+        let shentsize = file.ehdr.e_shentsize as usize;
+        // sh_addralign is at offset 48 in an Elf64_Shdr
+        let field = shoff + index * shentsize + 48;
+
+        for (align, too_big) in [(1u64 << 62, true), (24, false)] {
+            data[field..field + 8].copy_from_slice(&align.to_le_bytes());
+            let file = ElfBytes::<AnyEndian>::minimal_parse(&data).expect("Open test1");
+            let err = strip_sections(&file, is_strip_all_section).expect_err("should error");
+            match too_big {
+                true => assert!(matches!(err, ParseError::LimitExceeded((x, _)) if x == align)),
+                false => assert!(matches!(err, ParseError::UnexpectedAlignment(24))),
+            }
+        }
+    }
+
+    #[test]
+    fn no_section_headers_errors() {
+        let path = std::path::PathBuf::from("sample-objects/basic.x86_64");
+        let mut file_data = std::fs::read(path).expect("Could not read file.");
+        file_data[0x28..0x30].copy_from_slice(&[0; 8]);
+        file_data[0x3c..0x40].copy_from_slice(&[0; 4]);
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("Open test1");
+        let result = strip_sections(&file, is_strip_all_section);
+        assert!(
+            matches!(result, Err(ParseError::BadOffset(0))),
+            "Unexpected Error type found: {result:?}"
+        );
+    }
+}
@@ -0,0 +1,443 @@
+//! Converting an ELF object to the opposite byte order
+//!
+//! [swap_endianness] copies an object while re-serializing the structures that this crate
+//! understands in the opposite byte order: the file header, the program and section header
+//! tables, and the contents of symbol tables, dynamic tables, relocations, hash tables, symbol
+//! versioning tables, and so on. Everything keeps its file offset, and bytes that aren't part
+//! of such a structure are copied verbatim. Since swapping the byte order twice gives back the
+//! original order, converting the result back reproduces the original object byte-for-byte.
+//!
+//! The contents of sections like `.text`, `.rodata`, `.data` or `.eh_frame` can't be converted
+//! without knowing what they hold, so they're copied verbatim and listed in
+//! [SwappedElf::verbatim_sections]. Only the headers of notes are converted, along with the
+//! descriptors of GNU ABI tag notes; other note descriptors are copied verbatim.
+//!
+//! Example usage:
+//! ```
+//! use elf::endian::AnyEndian;
+//! use elf::swap::swap_endianness;
+//! use elf::ElfBytes;
+//!
+//! let path = std::path::PathBuf::from("sample-objects/basic.x86_64");
+//! let file_data = std::fs::read(path).expect("Could not read file.");
+//! let file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("Open test1");
+//!
+//! let swapped = swap_endianness(&file).expect("should swap");
+//! let big = ElfBytes::<AnyEndian>::minimal_parse(&swapped.data).expect("should parse");
+//! assert_eq!(big.ehdr.endianness, AnyEndian::Big);
+//! assert_eq!(big.ehdr.e_entry, file.ehdr.e_entry);
+//!
+//! // Converting back gives the original file
+//! let back = swap_endianness(&big).expect("should swap");
+//! assert_eq!(back.data, file_data);
+//! ```
+use crate::abi;
+use crate::compression::CompressionHeader;
+use crate::dynamic::Dyn;
+use crate::endian::{AnyEndian, EndianParse};
+use crate::file::Class;
+use crate::note::note_owner;
+use crate::parse::{ParseAt, ParseError, ReadBytesExt, SerializeAt};
+use crate::relocation::{Rel, Rela};
+use crate::section::SectionHeader;
+use crate::symbol::Symbol;
+use crate::ElfBytes;
+
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+/// An ELF object converted to the opposite byte order, as made by [swap_endianness]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SwappedElf {
+    /// The converted object
+    pub data: Vec<u8>,
+    /// The indexes of the sections whose contents were copied verbatim because their format
+    /// isn't understood, in section header order. Sections without byte-order-dependent
+    /// contents, like string tables and `SHT_NOBITS` sections, aren't listed.
+    pub verbatim_sections: Vec<usize>,
+}
+
+/// Converts structures from the source object's bytes into the output's bytes, at the same
+/// offsets
+struct Swapper<'a, E: EndianParse> {
+    from: E,
+    to: AnyEndian,
+    class: Class,
+    src: &'a [u8],
+    out: &'a mut [u8],
+}
+
+impl<'a, E: EndianParse> Swapper<'a, E> {
+    /// Convert the structure at the given offset
+    fn swap<P: ParseAt + SerializeAt>(&mut self, offset: usize) -> Result<P, ParseError> {
+        let value = P::parse_at(self.from, self.class, &mut offset.clone(), self.src)?;
+        value.serialize_at(self.to, self.class, &mut offset.clone(), self.out)?;
+        Ok(value)
+    }
+
+    /// Convert a table of structures, `entsize` bytes apart
+    fn swap_table<P: ParseAt + SerializeAt>(
+        &mut self,
+        start: usize,
+        size: usize,
+        entsize: usize,
+    ) -> Result<(), ParseError> {
+        let entsize = match entsize {
+            0 => P::size_for(self.class),
+            entsize => entsize,
+        };
+        let mut offset = start;
+        while offset + P::size_for(self.class) <= start + size {
+            self.swap::<P>(offset)?;
+            offset += entsize;
+        }
+        Ok(())
+    }
+
+    /// Convert the integer fields of the given widths, laid out one after another from the
+    /// given offset, returning the last field's value
+    fn swap_fields(&mut self, offset: usize, widths: &[usize]) -> Result<u64, ParseError> {
+        let mut offset = offset;
+        let mut value = 0;
+        for width in widths {
+            let mut write_offset = offset;
+            value = match width {
+                2 => {
+                    let value = self.from.parse_u16_at(&mut offset, self.src)?;
+                    self.to.write_u16_at(&mut write_offset, self.out, value)?;
+                    value as u64
+                }
+                4 => {
+                    let value = self.from.parse_u32_at(&mut offset, self.src)?;
+                    self.to.write_u32_at(&mut write_offset, self.out, value)?;
+                    value as u64
+                }
+                _ => {
+                    let value = self.from.parse_u64_at(&mut offset, self.src)?;
+                    self.to.write_u64_at(&mut write_offset, self.out, value)?;
+                    value
+                }
+            };
+        }
+        Ok(value)
+    }
+
+    /// Convert a table of integers of the given width, ignoring any trailing partial entry
+    fn swap_words(&mut self, start: usize, size: usize, width: usize) -> Result<(), ParseError> {
+        for index in 0..size / width {
+            self.swap_fields(start + index * width, &[width])?;
+        }
+        Ok(())
+    }
+
+    fn word_size(&self) -> usize {
+        match self.class {
+            Class::ELF32 => 4,
+            Class::ELF64 => 8,
+        }
+    }
+
+    /// Convert the contents of the section at the given file range, returning false if its
+    /// format isn't understood
+    fn swap_section(
+        &mut self,
+        shdr: &SectionHeader,
+        start: usize,
+        size: usize,
+    ) -> Result<bool, ParseError> {
+        let entsize = shdr.sh_entsize as usize;
+        match shdr.sh_type {
+            abi::SHT_NULL | abi::SHT_STRTAB => {}
+            abi::SHT_SYMTAB | abi::SHT_DYNSYM => self.swap_table::<Symbol>(start, size, entsize)?,
+            abi::SHT_DYNAMIC => self.swap_table::<Dyn>(start, size, entsize)?,
+            abi::SHT_REL => self.swap_table::<Rel>(start, size, entsize)?,
+            abi::SHT_RELA => self.swap_table::<Rela>(start, size, entsize)?,
+            abi::SHT_HASH => self.swap_words(start, size, if entsize == 8 { 8 } else { 4 })?,
+            abi::SHT_GNU_HASH => self.swap_gnu_hash(start, size)?,
+            abi::SHT_NOTE => self.swap_notes(start, size, shdr.sh_addralign as usize)?,
+            abi::SHT_GROUP | abi::SHT_SYMTAB_SHNDX | abi::SHT_GNU_LIBLIST => {
+                self.swap_words(start, size, 4)?
+            }
+            abi::SHT_INIT_ARRAY | abi::SHT_FINI_ARRAY | abi::SHT_PREINIT_ARRAY | abi::SHT_RELR => {
+                self.swap_words(start, size, self.word_size())?
+            }
+            abi::SHT_GNU_VERSYM => self.swap_words(start, size, 2)?,
+            // vd_version, vd_flags, vd_ndx, vd_cnt, vd_hash, vd_aux, vd_next and
+            // vda_name, vda_next
+            abi::SHT_GNU_VERDEF => {
+                self.swap_version_chain(start, size, &[2, 2, 2, 2, 4, 4], &[4, 4])?
+            }
+            // vn_version, vn_cnt, vn_file, vn_aux, vn_next and
+            // vna_hash, vna_flags, vna_other, vna_name, vna_next
+            abi::SHT_GNU_VERNEED => {
+                self.swap_version_chain(start, size, &[2, 2, 4, 4], &[4, 2, 2, 4, 4])?
+            }
+            _ => return Ok(false),
+        }
+        Ok(true)
+    }
+
+    fn swap_gnu_hash(&mut self, start: usize, size: usize) -> Result<(), ParseError> {
+        // nbucket, table_start_idx, nbloom, nshift
+        self.swap_fields(start, &[4, 4])?;
+        let nbloom = self.swap_fields(start + 8, &[4])? as usize;
+        self.swap_fields(start + 12, &[4])?;
+        let bloom_size = nbloom
+            .checked_mul(self.word_size())
+            .ok_or(ParseError::IntegerOverflow)?;
+        let bloom_start = start + 16;
+        let bloom_end = bloom_start
+            .checked_add(bloom_size)
+            .filter(|end| *end <= start + size)
+            .ok_or(ParseError::SliceReadError((bloom_start, start + size)))?;
+        self.swap_words(bloom_start, bloom_size, self.word_size())?;
+        // The buckets and chains fill the rest of the section
+        self.swap_words(bloom_end, start + size - bloom_end, 4)
+    }
+
+    fn swap_notes(&mut self, start: usize, size: usize, align: usize) -> Result<(), ParseError> {
+        let align = align.max(4);
+        let pad = |len: usize| (len + align - 1) & !(align - 1);
+        let end = start + size;
+        let mut offset = start;
+        while offset + 12 <= end {
+            let namesz = self.from.parse_u32_at(&mut offset.clone(), self.src)? as usize;
+            let descsz = self.from.parse_u32_at(&mut (offset + 4), self.src)? as usize;
+            let n_type = self.swap_fields(offset, &[4, 4, 4])?;
+            let name_start = offset + 12;
+            let name = self
+                .src
+                .get_bytes(name_start..name_start.saturating_add(namesz))?;
+            let desc_start = name_start + pad(namesz);
+            if note_owner(name) == b"GNU" && n_type == abi::NT_GNU_ABI_TAG && descsz == 16 {
+                self.swap_words(desc_start, descsz, 4)?;
+            }
+            offset = desc_start
+                .checked_add(pad(descsz))
+                .ok_or(ParseError::IntegerOverflow)?;
+        }
+        Ok(())
+    }
+
+    /// Convert a chain of version definitions or requirements, each of which has a chain of
+    /// auxiliary entries. `entry` gives the widths of an entry's fields up to the offset of its
+    /// auxiliary entries, which is followed by the offset of the next entry (or 0 at the end
+    /// of the chain). `aux` gives the widths of all of an auxiliary entry's fields, the last
+    /// of which is the offset of the next auxiliary entry.
+    fn swap_version_chain(
+        &mut self,
+        start: usize,
+        size: usize,
+        entry: &[usize],
+        aux: &[usize],
+    ) -> Result<(), ParseError> {
+        let entry_size: usize = entry.iter().sum();
+        let aux_size: usize = aux.iter().sum();
+        // Every entry takes up some space, so there can't be more of them than this
+        let max_entries = size / aux_size.min(entry_size);
+
+        let mut offset = start;
+        for _ in 0..max_entries {
+            let aux_offset = self.swap_fields(offset, entry)? as usize;
+            let next = self.swap_fields(offset + entry_size, &[4])? as usize;
+
+            let mut aux_start = offset.checked_add(aux_offset);
+            for _ in 0..max_entries {
+                let aux_start_offset = match aux_start {
+                    Some(aux_start) if aux_offset != 0 && aux_start + aux_size <= start + size => {
+                        aux_start
+                    }
+                    _ => break,
+                };
+                let aux_next = self.swap_fields(aux_start_offset, aux)? as usize;
+                if aux_next == 0 {
+                    break;
+                }
+                aux_start = aux_start_offset.checked_add(aux_next);
+            }
+
+            if next == 0 {
+                break;
+            }
+            offset = offset
+                .checked_add(next)
+                .ok_or(ParseError::IntegerOverflow)?;
+            if offset + entry_size + 4 > start + size {
+                return Err(ParseError::BadOffset(offset as u64));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Convert an ELF object to the opposite byte order, with `EI_DATA` updated to match.
+///
+/// The section contents are converted by their section type, so without a section header
+/// table only the file header and program headers are converted. Compressed sections have
+/// their compression header converted and their compressed contents copied verbatim.
+pub fn swap_endianness<E: EndianParse>(file: &ElfBytes<'_, E>) -> Result<SwappedElf, ParseError> {
+    let src = file.data;
+    let mut out = src.to_vec();
+    let to = match file.ehdr.endianness.is_little() {
+        true => AnyEndian::Big,
+        false => AnyEndian::Little,
+    };
+    let mut swapper = Swapper {
+        from: file.ehdr.endianness,
+        to,
+        class: file.ehdr.class,
+        src,
+        out: &mut out,
+    };
+
+    // The file header writes out its own identification bytes, but keep the original
+    // EI_VERSION and padding
+    file.ehdr
+        .serialize_at(to, file.ehdr.class, &mut 0, swapper.out)?;
+    swapper.out[abi::EI_VERSION..abi::EI_NIDENT]
+        .copy_from_slice(&src[abi::EI_VERSION..abi::EI_NIDENT]);
+
+    if let Some(phdrs) = file.segments() {
+        let start: usize = file.ehdr.e_phoff.try_into()?;
+        let size = phdrs.len() * file.ehdr.e_phentsize as usize;
+        swapper.swap_table::<crate::segment::ProgramHeader>(
+            start,
+            size,
+            file.ehdr.e_phentsize as usize,
+        )?;
+    }
+
+    let mut verbatim_sections = Vec::new();
+    if let Some(shdrs) = file.section_headers() {
+        let start: usize = file.ehdr.e_shoff.try_into()?;
+        let size = shdrs.len() * file.ehdr.e_shentsize as usize;
+        swapper.swap_table::<SectionHeader>(start, size, file.ehdr.e_shentsize as usize)?;
+
+        for (index, shdr) in shdrs.iter().enumerate() {
+            if shdr.sh_type == abi::SHT_NOBITS || shdr.sh_size == 0 {
+                continue;
+            }
+            let (start, end) = shdr.get_data_range()?;
+            src.get_bytes(start..end)?;
+            if shdr.sh_flags & abi::SHF_COMPRESSED as u64 != 0 {
+                swapper.swap::<CompressionHeader>(start)?;
+                verbatim_sections.push(index);
+                continue;
+            }
+            if !swapper.swap_section(&shdr, start, end - start)? {
+                verbatim_sections.push(index);
+            }
+        }
+    }
+
+    Ok(SwappedElf {
+        data: out,
+        verbatim_sections,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn round_trip(path: &str) -> (Vec<u8>, SwappedElf) {
+        let file_data = std::fs::read(path).expect("Could not read file.");
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("should parse");
+        let swapped = swap_endianness(&file).expect("should swap");
+        assert_ne!(swapped.data, file_data);
+
+        let other = ElfBytes::<AnyEndian>::minimal_parse(&swapped.data).expect("should parse");
+        assert_ne!(other.ehdr.endianness, file.ehdr.endianness);
+        let back = swap_endianness(&other).expect("should swap back");
+        assert_eq!(back.data, file_data, "{path} should round trip");
+        assert_eq!(back.verbatim_sections, swapped.verbatim_sections);
+        (file_data, swapped)
+    }
+
+    #[test]
+    fn round_trips() {
+        for path in [
+            "sample-objects/basic.x86_64",
+            "sample-objects/symver.x86_64.so",
+            "sample-objects/symver.powerpc64.so",
+            "sample-objects/symver.m68k.so",
+            "sample-objects/stack_sizes.x86_64.o",
+            "sample-objects/debug_gz.x86_64.o",
+            "sample-objects/shnum.x86_64",
+        ] {
+            round_trip(path);
+        }
+    }
+
+    #[test]
+    fn structures_match() {
+        let (file_data, swapped) = round_trip("sample-objects/symver.x86_64.so");
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("should parse");
+        let other = ElfBytes::<AnyEndian>::minimal_parse(&swapped.data).expect("should parse");
+        assert_eq!(other.ehdr.endianness, AnyEndian::Big);
+        assert_eq!(file.ehdr.e_entry, other.ehdr.e_entry);
+
+        let a = file.find_common_data().expect("should parse");
+        let b = other.find_common_data().expect("should parse");
+        let (a_syms, b_syms) = (a.dynsyms.unwrap(), b.dynsyms.unwrap());
+        assert_eq!(
+            a_syms.iter().collect::<Vec<_>>(),
+            b_syms.iter().collect::<Vec<_>>()
+        );
+        assert_eq!(
+            a.dynamic.unwrap().iter().collect::<Vec<_>>(),
+            b.dynamic.unwrap().iter().collect::<Vec<_>>()
+        );
+
+        // The hash table still finds the symbols
+        let strtab = b.dynsyms_strs.unwrap();
+        let (index, sym) = b
+            .gnu_hash
+            .unwrap()
+            .find(b"use_memset", &b_syms, &strtab)
+            .expect("should search")
+            .expect("should find use_memset");
+        assert_eq!((index, sym.st_value), (9, 0x1119));
+
+        // The symbol versions
+        let a_versions = file.symbol_version_table().unwrap().unwrap();
+        let b_versions = other.symbol_version_table().unwrap().unwrap();
+        for index in 1..a_syms.len() {
+            assert_eq!(
+                a_versions.get_requirement(index).unwrap(),
+                b_versions.get_requirement(index).unwrap()
+            );
+            let definition = |table: &crate::gnu_symver::SymbolVersionTable<'_, AnyEndian>| {
+                table.get_definition(index).unwrap().map(|def| {
+                    let names: Vec<String> =
+                        def.names.map(|name| String::from(name.unwrap())).collect();
+                    (def.hash, def.flags, names)
+                })
+            };
+            assert_eq!(definition(&a_versions), definition(&b_versions));
+        }
+
+        assert_eq!(file.gnu_build_id().unwrap(), other.gnu_build_id().unwrap());
+    }
+
+    #[test]
+    fn verbatim_sections() {
+        let (file_data, swapped) = round_trip("sample-objects/basic.x86_64");
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("should parse");
+        let (shdrs, strtab) = file.section_headers_with_strtab().unwrap();
+        let (shdrs, strtab) = (shdrs.unwrap(), strtab.unwrap());
+        let names: Vec<_> = swapped
+            .verbatim_sections
+            .iter()
+            .map(|index| {
+                let shdr = shdrs.get(*index).unwrap();
+                strtab.get(shdr.sh_name as usize).unwrap()
+            })
+            .collect();
+        assert!(names.contains(&".text"));
+        assert!(names.contains(&".eh_frame"));
+        assert!(!names.contains(&".dynsym"));
+        assert!(!names.contains(&".dynstr"));
+        assert!(!names.contains(&".bss"));
+    }
+}
@@ -2,10 +2,220 @@
 use crate::abi;
 use crate::endian::EndianParse;
 use crate::file::Class;
-use crate::parse::{ParseAt, ParseError, ParsingTable};
+use crate::parse::{
+    ne_u16, ne_u32, ne_u64, read_entry, EnumeratedParsingIterator, ParseAt, ParseError,
+    ParsingIterator, ParsingTable, SerializeAt, WordTable,
+};
+#[cfg(feature = "alloc")]
+use crate::section::SectionHeader;
+use crate::string_table::StringTable;
+#[cfg(feature = "alloc")]
+use crate::string_table::StringTableBuilder;
+#[cfg(feature = "to_str")]
+use crate::to_str::ReadelfDisplay;
+
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::{borrow::Cow, string::String, vec, vec::Vec};
+#[cfg(feature = "std")]
+use std::borrow::Cow;
 
 pub type SymbolTable<'data, E> = ParsingTable<'data, E, Symbol>;
 
+/// Iterator over the symbols of a [SymbolTable] along with their indexes in the table, see
+/// [ParsingTable::iter_enumerated].
+pub type EnumeratedSymbolIterator<'data, E> = EnumeratedParsingIterator<'data, E, Symbol>;
+
+/// Table of extended section header indexes from a [SHT_SYMTAB_SHNDX](abi::SHT_SYMTAB_SHNDX)
+/// section. It holds one [WordSize::U32](crate::parse::WordSize::U32) word for each symbol in
+/// the associated symbol table.
+pub type SymbolShndxTable<'data, E> = WordTable<'data, E>;
+
+impl<'data, E: EndianParse> SymbolTable<'data, E> {
+    /// Get the symbol at the given index along with its resolved section header index.
+    ///
+    /// If the symbol's st_shndx is [SHN_XINDEX](abi::SHN_XINDEX), then the real section
+    /// header index is read out of the corresponding entry in the given `shndx_table`.
+    /// Otherwise, the symbol's st_shndx is returned as-is (including the other reserved
+    /// indexes like [SHN_ABS](abi::SHN_ABS)).
+    pub fn get_with_shndx(
+        &self,
+        index: usize,
+        shndx_table: &SymbolShndxTable<'data, E>,
+    ) -> Result<(Symbol, u32), ParseError> {
+        let sym = self.get(index)?;
+        let shndx = if sym.st_shndx == abi::SHN_XINDEX {
+            // The table's words are u32s
+            shndx_table.get(index)? as u32
+        } else {
+            sym.st_shndx as u32
+        };
+        Ok((sym, shndx))
+    }
+
+    /// Get the index of the section that the symbol at the given index is defined in, or
+    /// `None` if its st_shndx is one of the reserved values that don't refer to a section,
+    /// like [SHN_UNDEF](abi::SHN_UNDEF) or [SHN_ABS](abi::SHN_ABS). See [Symbol::shndx].
+    ///
+    /// Symbols with [SHN_XINDEX](abi::SHN_XINDEX) are resolved through the `shndx_table`.
+    /// Returns a [ParseError::UnexpectedSectionType] if there's no table to resolve one with.
+    pub fn section_index(
+        &self,
+        index: usize,
+        shndx_table: Option<&SymbolShndxTable<'data, E>>,
+    ) -> Result<Option<u32>, ParseError> {
+        let sym = self.get(index)?;
+        match (sym.shndx(), shndx_table) {
+            (SymbolShndx::Section(shndx), _) => Ok(Some(shndx as u32)),
+            // The table's words are u32s
+            (SymbolShndx::XIndex, Some(table)) => Ok(Some(table.get(index)? as u32)),
+            (SymbolShndx::XIndex, None) => Err(ParseError::UnexpectedSectionType((
+                abi::SHT_NULL,
+                abi::SHT_SYMTAB_SHNDX,
+            ))),
+            _ => Ok(None),
+        }
+    }
+
+    /// Get an iterator over the symbols whose st_shndx is `shndx`, along with their indexes
+    /// in the table, in table order.
+    ///
+    /// This compares the raw st_shndx, so symbols with [SHN_XINDEX](abi::SHN_XINDEX) aren't
+    /// resolved. Use [SymbolTable::iter_for_section_with_shndx] for objects with an extended
+    /// index table.
+    pub fn iter_for_section(&self, shndx: u16) -> SectionSymbolIterator<'data, E> {
+        SectionSymbolIterator {
+            iter: self.iter(),
+            index: self.first_index(),
+            shndx: shndx as u32,
+            shndx_table: None,
+        }
+    }
+
+    /// Get an iterator over the symbols defined in the section at index `shndx`, along with
+    /// their indexes in the table, in table order.
+    ///
+    /// Symbols with [SHN_XINDEX](abi::SHN_XINDEX) are matched by their resolved index from
+    /// the given `shndx_table`, like [SymbolTable::get_with_shndx].
+    pub fn iter_for_section_with_shndx(
+        &self,
+        shndx: u32,
+        shndx_table: &SymbolShndxTable<'data, E>,
+    ) -> SectionSymbolIterator<'data, E> {
+        SectionSymbolIterator {
+            iter: self.iter(),
+            index: self.first_index(),
+            shndx,
+            shndx_table: Some(*shndx_table),
+        }
+    }
+
+    /// Get the sub-range of just the non-local symbols in a [SHT_SYMTAB](abi::SHT_SYMTAB)
+    /// table, given the table section's `sh_info`, which is the index of the first non-local
+    /// symbol. See [ParsingTable::entries_from].
+    ///
+    /// The returned table takes and yields the same symbol indexes as this one, so its first
+    /// symbol is `get(sh_info)`, while its `len()` only counts the non-local symbols. Returns a
+    /// [ParseError::BadOffset] if `sh_info` is past the end of the table.
+    pub fn globals(&self, sh_info: u32) -> Result<SymbolTable<'data, E>, ParseError> {
+        self.entries_from(sh_info as usize)
+    }
+
+    /// Get a lazy-parsing iterator over the symbols in the table joined with their names
+    /// from the given associated string table.
+    pub fn iter_with_names(&self, strtab: &StringTable<'data>) -> SymbolNameIterator<'data, E> {
+        SymbolNameIterator {
+            iter: self.iter(),
+            strtab: *strtab,
+        }
+    }
+}
+
+/// Iterator over the symbols in a [SymbolTable] joined with their names from the table's
+/// associated [StringTable].
+///
+/// Each item is a Result, as the symbol's name may fail to be read from the string table.
+#[derive(Debug, Clone)]
+pub struct SymbolNameIterator<'data, E: EndianParse> {
+    iter: ParsingIterator<'data, E, Symbol>,
+    strtab: StringTable<'data>,
+}
+
+impl<'data, E: EndianParse> SymbolNameIterator<'data, E> {
+    /// Adapt this iterator to read the symbol names with [StringTable::get_lossy], so that
+    /// names which aren't valid UTF-8 are still yielded (with the invalid sequences replaced)
+    /// rather than as errors.
+    #[cfg(feature = "alloc")]
+    pub fn lossy(self) -> impl Iterator<Item = Result<(Symbol, Cow<'data, str>), ParseError>> {
+        let strtab = self.strtab;
+        self.iter.map(move |sym| {
+            strtab
+                .get_lossy(sym.st_name as usize)
+                .map(|name| (sym, name))
+        })
+    }
+
+    /// Adapt this iterator to yield the symbol names demangled with [crate::demangle::demangle].
+    #[cfg(feature = "demangle")]
+    pub fn demangled(self) -> impl Iterator<Item = Result<(Symbol, Cow<'data, str>), ParseError>> {
+        self.map(|result| result.map(|(sym, name)| (sym, crate::demangle::demangle(name))))
+    }
+}
+
+impl<'data, E: EndianParse> Iterator for SymbolNameIterator<'data, E> {
+    type Item = Result<(Symbol, &'data str), ParseError>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let sym = self.iter.next()?;
+        Some(
+            self.strtab
+                .get(sym.st_name as usize)
+                .map(|name| (sym, name)),
+        )
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+/// Iterates over the symbols in a section, as (index, [Symbol]) pairs. See
+/// [SymbolTable::iter_for_section].
+#[derive(Debug, Clone)]
+pub struct SectionSymbolIterator<'data, E: EndianParse> {
+    iter: ParsingIterator<'data, E, Symbol>,
+    index: usize,
+    shndx: u32,
+    shndx_table: Option<SymbolShndxTable<'data, E>>,
+}
+
+impl<'data, E: EndianParse> Iterator for SectionSymbolIterator<'data, E> {
+    type Item = (usize, Symbol);
+    fn next(&mut self) -> Option<Self::Item> {
+        for sym in self.iter.by_ref() {
+            let index = self.index;
+            self.index += 1;
+            let shndx = match (&self.shndx_table, sym.shndx()) {
+                // Without a table, this compares the raw st_shndx like iter_for_section says
+                (None, _) => sym.st_shndx as u32,
+                (Some(_), SymbolShndx::Section(shndx)) => shndx as u32,
+                (Some(table), SymbolShndx::XIndex) => match table.get(index) {
+                    // The table's words are u32s
+                    Ok(shndx) => shndx as u32,
+                    Err(_) => continue,
+                },
+                (Some(_), SymbolShndx::Undefined) => abi::SHN_UNDEF as u32,
+                // Reserved indexes like SHN_ABS don't refer to a real section
+                (Some(_), _) => continue,
+            };
+            if shndx == self.shndx {
+                return Some((index, sym));
+            }
+        }
+        None
+    }
+}
+
+impl<'data, E: EndianParse> ExactSizeIterator for SymbolNameIterator<'data, E> {}
+
 /// C-style 32-bit ELF Symbol definition
 ///
 /// These C-style definitions are for users who want to implement their own ELF manipulation logic.
@@ -34,7 +244,9 @@ pub struct Elf64_Sym {
     pub st_size: u64,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
 pub struct Symbol {
     /// This member holds an index into the symbol table's string table,
     /// which holds the character representations of the symbol names. If the
@@ -71,6 +283,8 @@ pub struct Symbol {
     ///   linker, the section offset (file interpretation) gives way to a
     ///   virtual address (memory interpretation) for which the section number
     ///   is irrelevant.
+    /// * For [STT_TLS](abi::STT_TLS) symbols, st_value is an offset into the TLS
+    ///   block rather than an address, see [Symbol::tls_offset].
     pub st_value: u64,
 
     /// This member gives the symbol's size.
@@ -80,7 +294,82 @@ pub struct Symbol {
     pub st_size: u64,
 }
 
+/// What a symbol's st_shndx refers to, see [Symbol::shndx].
+///
+/// Only [SymbolShndx::Section] holds an index into the section header table. The other
+/// variants are the reserved values in [SHN_LORESERVE, SHN_HIRESERVE](abi::SHN_LORESERVE),
+/// along with [SHN_UNDEF](abi::SHN_UNDEF).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SymbolShndx {
+    /// [SHN_UNDEF](abi::SHN_UNDEF): the symbol is undefined in this object
+    Undefined,
+    /// [SHN_ABS](abi::SHN_ABS): the symbol's value is absolute
+    Absolute,
+    /// [SHN_COMMON](abi::SHN_COMMON): the symbol labels an unallocated common block
+    Common,
+    /// [SHN_XINDEX](abi::SHN_XINDEX): the real section index is in the symbol table's
+    /// [SHT_SYMTAB_SHNDX](abi::SHT_SYMTAB_SHNDX) section, see [SymbolTable::section_index]
+    XIndex,
+    /// A value in [SHN_LOPROC, SHN_HIPROC](abi::SHN_LOPROC)
+    ProcessorSpecific(u16),
+    /// A value in [SHN_LOOS, SHN_HIOS](abi::SHN_LOOS)
+    OsSpecific(u16),
+    /// Any other reserved value without a defined meaning
+    Reserved(u16),
+    /// The index of the section that the symbol is defined in
+    Section(u16),
+}
+
+impl From<u16> for SymbolShndx {
+    fn from(st_shndx: u16) -> Self {
+        match st_shndx {
+            abi::SHN_UNDEF => SymbolShndx::Undefined,
+            abi::SHN_ABS => SymbolShndx::Absolute,
+            abi::SHN_COMMON => SymbolShndx::Common,
+            abi::SHN_XINDEX => SymbolShndx::XIndex,
+            abi::SHN_LOPROC..=abi::SHN_HIPROC => SymbolShndx::ProcessorSpecific(st_shndx),
+            abi::SHN_LOOS..=abi::SHN_HIOS => SymbolShndx::OsSpecific(st_shndx),
+            // Everything else in [SHN_LORESERVE, SHN_HIRESERVE] not matched above
+            0xff40..=abi::SHN_HIRESERVE => SymbolShndx::Reserved(st_shndx),
+            _ => SymbolShndx::Section(st_shndx),
+        }
+    }
+}
+
+impl SymbolShndx {
+    /// Get the section header index, if this refers to a section
+    pub fn section(&self) -> Option<u16> {
+        match self {
+            SymbolShndx::Section(shndx) => Some(*shndx),
+            _ => None,
+        }
+    }
+}
+
+/// Displays the special values with readelf's names, e.g. `UND`, `ABS` or `PRC[0xff00]`,
+/// and section indexes as plain numbers.
+impl core::fmt::Display for SymbolShndx {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            SymbolShndx::Undefined => f.pad("UND"),
+            SymbolShndx::Absolute => f.pad("ABS"),
+            SymbolShndx::Common => f.pad("COM"),
+            SymbolShndx::XIndex => f.pad("XIDX"),
+            SymbolShndx::ProcessorSpecific(shndx) => write!(f, "PRC[{shndx:#06x}]"),
+            SymbolShndx::OsSpecific(shndx) => write!(f, "OS [{shndx:#06x}]"),
+            SymbolShndx::Reserved(shndx) => write!(f, "RSV[{shndx:#06x}]"),
+            SymbolShndx::Section(shndx) => shndx.fmt(f),
+        }
+    }
+}
+
 impl Symbol {
+    /// Classify the symbol's st_shndx, so that the reserved values like
+    /// [SHN_ABS](abi::SHN_ABS) can't be mistaken for section header indexes.
+    pub fn shndx(&self) -> SymbolShndx {
+        SymbolShndx::from(self.st_shndx)
+    }
+
     /// Returns true if a symbol is undefined in this ELF object.
     ///
     /// When linking and loading, undefined symbols in this object get linked to
@@ -89,21 +378,501 @@ impl Symbol {
         self.st_shndx == abi::SHN_UNDEF
     }
 
+    /// Returns true if the symbol has an absolute value that is not affected by relocation
+    /// (its st_shndx is [SHN_ABS](abi::SHN_ABS)).
+    pub fn is_absolute(&self) -> bool {
+        self.st_shndx == abi::SHN_ABS
+    }
+
+    /// Returns true if the symbol labels a common block that has not yet been allocated
+    /// (its st_shndx is [SHN_COMMON](abi::SHN_COMMON)).
+    pub fn is_common(&self) -> bool {
+        self.st_shndx == abi::SHN_COMMON
+    }
+
+    /// Returns true if the symbol is a thread-local variable (its type is
+    /// [STT_TLS](abi::STT_TLS)).
+    pub fn is_tls(&self) -> bool {
+        self.st_symtype() == abi::STT_TLS
+    }
+
+    /// Get the offset of a defined thread-local symbol into its TLS block, or an empty Option
+    /// if this isn't one. Such symbols' st_values aren't addresses, so this is the accessor to
+    /// use for them rather than st_value.
+    ///
+    /// In executables and shared objects the offset is from the start of the
+    /// [PT_TLS](abi::PT_TLS) segment's template, see
+    /// [ElfBytes::tls_segment](crate::ElfBytes::tls_segment). In relocatable objects, it's
+    /// from the start of the symbol's section like for other symbols.
+    pub fn tls_offset(&self) -> Option<u64> {
+        if self.is_tls() && !self.is_undefined() {
+            Some(self.st_value)
+        } else {
+            None
+        }
+    }
+
+    /// The symbol's type, encoded in the low 4 bits of st_info. See the `STT_*` constants in [abi].
     pub fn st_symtype(&self) -> u8 {
         self.st_info & 0xf
     }
 
+    /// The symbol's binding, encoded in the high 4 bits of st_info. See the `STB_*` constants in [abi].
     pub fn st_bind(&self) -> u8 {
         self.st_info >> 4
     }
 
+    /// The symbol's visibility, encoded in the low 2 bits of st_other. See the `STV_*` constants in [abi].
     pub fn st_vis(&self) -> u8 {
         self.st_other & 0x3
     }
+
+    /// Set the symbol's binding and type, which are packed together into st_info.
+    pub fn set_st_info(&mut self, bind: u8, symtype: u8) {
+        self.st_info = (bind << 4) | (symtype & 0xf);
+    }
+
+    /// Set the symbol's visibility in the low 2 bits of st_other, keeping the other bits as-is.
+    pub fn set_st_vis(&mut self, vis: u8) {
+        self.st_other = (self.st_other & !0x3) | (vis & 0x3);
+    }
+
+    /// Get a value which renders the symbol in the style of a `readelf --syms` line, with the
+    /// value column sized for the given class. See [Symbol]'s Display impl.
+    #[cfg(feature = "to_str")]
+    pub fn display(&self, class: Class) -> ReadelfDisplay<'_, Symbol> {
+        ReadelfDisplay {
+            value: self,
+            class,
+            e_machine: abi::EM_NONE,
+        }
+    }
 }
 
-impl ParseAt for Symbol {
-    fn parse_at<E: EndianParse>(
+/// A symbol which an object makes available to others through its `.dynsym`.
+///
+/// See [ElfBytes::exports](crate::ElfBytes::exports).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SymbolExport<'data> {
+    /// The symbol's index in the `.dynsym`
+    pub index: usize,
+    pub symbol: Symbol,
+    pub name: &'data str,
+    /// The GNU symbol version that the symbol is defined with (if any).
+    pub version: Option<&'data str>,
+    /// Whether the version is hidden, meaning that it is not the default version of this
+    /// symbol and can only be bound to by a requirement on that specific version (`name@VERSION`).
+    pub hidden: bool,
+}
+
+/// A symbol which an object requires from others through its `.dynsym`.
+///
+/// See [ElfBytes::imports](crate::ElfBytes::imports).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SymbolImport<'data> {
+    /// The symbol's index in the `.dynsym`
+    pub index: usize,
+    pub symbol: Symbol,
+    pub name: &'data str,
+    /// The GNU symbol version that is required for this symbol (if any).
+    pub version: Option<&'data str>,
+    /// The needed library which is expected to provide the required symbol version (if any).
+    pub library: Option<&'data str>,
+}
+
+impl<'data> SymbolImport<'data> {
+    /// Returns true if the import is a weak reference, meaning that the object can still be
+    /// loaded if nothing provides a definition for it.
+    pub fn is_optional(&self) -> bool {
+        self.symbol.st_bind() == abi::STB_WEAK
+    }
+}
+
+/// Write out a symbol attribute name with its `STx_` prefix trimmed, left-aligned and padded
+/// out to at least `width`. Values without a name in the OS or processor-specific ranges get
+/// rendered as `LOOS+n` and `LOPROC+n`.
+#[cfg(feature = "to_str")]
+fn fmt_st_attr(
+    f: &mut core::fmt::Formatter<'_>,
+    value: u8,
+    name: Option<&'static str>,
+    os_range: (u8, u8),
+    proc_range: (u8, u8),
+    width: usize,
+) -> core::fmt::Result {
+    use core::fmt::Write;
+
+    let written = if let Some(name) = name {
+        let name = &name[4..];
+        f.write_str(name)?;
+        name.len()
+    } else if (os_range.0..=os_range.1).contains(&value) {
+        write!(f, "LOOS+{}", value - os_range.0)?;
+        6
+    } else if (proc_range.0..=proc_range.1).contains(&value) {
+        write!(f, "LOPROC+{}", value - proc_range.0)?;
+        8
+    } else {
+        write!(f, "{value}")?;
+        if value < 10 {
+            1
+        } else {
+            2
+        }
+    };
+
+    for _ in written..width {
+        f.write_char(' ')?;
+    }
+    Ok(())
+}
+
+/// Renders the symbol in the style of an ELF64 `readelf --syms` line (without the name, which
+/// lives in the associated string table): value, size, type, binding, visibility and section
+/// index. Use [Symbol::display] for ELF32's narrower value column.
+#[cfg(feature = "to_str")]
+impl core::fmt::Display for Symbol {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.display(Class::ELF64).fmt(f)
+    }
+}
+
+#[cfg(feature = "to_str")]
+impl core::fmt::Display for ReadelfDisplay<'_, Symbol> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        use crate::to_str::{st_bind_to_str, st_symtype_to_str, st_vis_to_str};
+        use core::fmt::Write;
+
+        let sym = self.value;
+        match self.class {
+            Class::ELF32 => write!(f, "{:08x} ", sym.st_value)?,
+            Class::ELF64 => write!(f, "{:016x} ", sym.st_value)?,
+        }
+        // Like readelf, switch to hex for sizes which don't fit in the column
+        if sym.st_size < 100000 {
+            write!(f, "{:5} ", sym.st_size)?;
+        } else {
+            write!(f, "{:#x} ", sym.st_size)?;
+        }
+        fmt_st_attr(
+            f,
+            sym.st_symtype(),
+            st_symtype_to_str(sym.st_symtype()),
+            (abi::STT_LOOS, abi::STT_HIOS),
+            (abi::STT_LOPROC, abi::STT_HIPROC),
+            7,
+        )?;
+        f.write_char(' ')?;
+        fmt_st_attr(
+            f,
+            sym.st_bind(),
+            st_bind_to_str(sym.st_bind()),
+            (abi::STB_LOOS, abi::STB_HIOS),
+            (abi::STB_LOPROC, abi::STB_HIPROC),
+            6,
+        )?;
+        f.write_char(' ')?;
+        // st_vis is only 2 bits, so every value has a name
+        fmt_st_attr(
+            f,
+            sym.st_vis(),
+            st_vis_to_str(sym.st_vis()),
+            (1, 0),
+            (1, 0),
+            7,
+        )?;
+        write!(f, " {:>4}", sym.shndx())
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone)]
+struct SymbolizerEntry<'data> {
+    sym: Symbol,
+    name: &'data str,
+    /// The largest st_value + st_size of this and all entries sorted before it. This lets
+    /// lookups stop walking backwards once no earlier symbol could contain the address.
+    max_end: u64,
+}
+
+/// An address-sorted index over the function and object symbols in a symbol table
+/// for repeated address-to-symbol lookups.
+///
+/// Building the index does a single pass over the table and a sort, after which each
+/// [Symbolizer::symbolize] lookup is a binary search.
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone)]
+pub struct Symbolizer<'data> {
+    entries: Vec<SymbolizerEntry<'data>>,
+    load_bias: u64,
+}
+
+#[cfg(feature = "alloc")]
+impl<'data> Symbolizer<'data> {
+    /// Build an index over the [abi::STT_FUNC] and [abi::STT_OBJECT] symbols in the given
+    /// symbol table. Undefined, absolute and common symbols are skipped as they don't
+    /// describe a location in the object, and so are [abi::STT_TLS] symbols as their
+    /// st_values are offsets into the TLS block (see [Symbol::tls_offset]).
+    ///
+    /// The `load_bias` is subtracted from addresses passed to [Symbolizer::symbolize], which is
+    /// how callers map runtime addresses of a relocated [abi::ET_DYN] object back to its st_values.
+    pub fn new<E: EndianParse>(
+        symtab: &SymbolTable<'data, E>,
+        strtab: &StringTable<'data>,
+        load_bias: u64,
+    ) -> Result<Self, ParseError> {
+        let mut entries = Vec::new();
+        for sym in symtab.iter() {
+            if !matches!(sym.st_symtype(), abi::STT_FUNC | abi::STT_OBJECT)
+                || sym.is_undefined()
+                || sym.is_absolute()
+                || sym.is_common()
+            {
+                continue;
+            }
+            let name = strtab.get(sym.st_name as usize)?;
+            entries.push(SymbolizerEntry {
+                sym,
+                name,
+                max_end: 0,
+            });
+        }
+
+        // Symbols starting at the same address are ordered largest first, so that walking
+        // backwards from a lookup finds the most specific containing symbol first.
+        entries.sort_by(|a, b| {
+            a.sym
+                .st_value
+                .cmp(&b.sym.st_value)
+                .then(b.sym.st_size.cmp(&a.sym.st_size))
+        });
+
+        let mut max_end = 0;
+        for entry in entries.iter_mut() {
+            max_end = max_end.max(entry.sym.st_value.saturating_add(entry.sym.st_size));
+            entry.max_end = max_end;
+        }
+
+        Ok(Symbolizer { entries, load_bias })
+    }
+
+    /// Returns the number of symbols in the index.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns whether the index contains zero symbols.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Find the symbol whose `[st_value, st_value + st_size)` range contains the given address,
+    /// returning it along with its name and the address's offset into the symbol.
+    ///
+    /// If there are overlapping symbols, then the one that starts closest to the address wins.
+    /// If no symbol contains the address but the nearest preceding symbol has a size of zero,
+    /// then that symbol is returned instead.
+    pub fn symbolize(&self, addr: u64) -> Option<(Symbol, &'data str, u64)> {
+        let addr = addr.checked_sub(self.load_bias)?;
+
+        // Index of the first symbol that starts after addr
+        let end = self.entries.partition_point(|e| e.sym.st_value <= addr);
+        if end == 0 {
+            return None;
+        }
+
+        for entry in self.entries[..end].iter().rev() {
+            if entry.max_end <= addr {
+                break;
+            }
+            if addr < entry.sym.st_value.saturating_add(entry.sym.st_size) {
+                return Some((entry.sym.clone(), entry.name, addr - entry.sym.st_value));
+            }
+        }
+
+        let nearest = &self.entries[end - 1];
+        if nearest.sym.st_size == 0 {
+            return Some((
+                nearest.sym.clone(),
+                nearest.name,
+                addr - nearest.sym.st_value,
+            ));
+        }
+        None
+    }
+}
+
+/// A name-sorted index over a symbol table for repeated by-name lookups, such as in a
+/// `.symtab` which has no hash table.
+///
+/// Building the index does a single pass over the table and a sort, after which each
+/// [SymbolNameIndex::lookup] is a binary search. Symbols with empty names aren't indexed.
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone)]
+pub struct SymbolNameIndex<'data> {
+    // Sorted by name, and then by index, so duplicate names stay in table order
+    entries: Vec<(&'data [u8], usize, Symbol)>,
+}
+
+#[cfg(feature = "alloc")]
+impl<'data> SymbolNameIndex<'data> {
+    /// Build an index over the named symbols in the given symbol table.
+    pub fn build<E: EndianParse>(
+        symtab: &SymbolTable<'data, E>,
+        strtab: &StringTable<'data>,
+    ) -> Result<Self, ParseError> {
+        let mut entries = Vec::new();
+        for (index, sym) in symtab.iter().enumerate() {
+            let name = strtab.get_raw(sym.st_name as usize)?;
+            if !name.is_empty() {
+                entries.push((name, index, sym));
+            }
+        }
+        // The sort is stable and entries were pushed in table order
+        entries.sort_by(|a, b| a.0.cmp(b.0));
+        Ok(SymbolNameIndex { entries })
+    }
+
+    /// Returns the number of symbols in the index.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns whether the index contains zero symbols.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Get all the symbols with the given name along with their indexes, in table order.
+    pub fn lookup<'a>(&'a self, name: &'a [u8]) -> impl Iterator<Item = (usize, &'a Symbol)> + 'a {
+        let start = self.entries.partition_point(|entry| entry.0 < name);
+        self.entries[start..]
+            .iter()
+            .take_while(move |entry| entry.0 == name)
+            .map(|(_, index, sym)| (*index, sym))
+    }
+}
+
+/// Builder for the contents of a new symbol table and its companion string table.
+///
+/// The table starts with the mandatory NULL symbol, followed by all the [abi::STB_LOCAL]
+/// symbols and then all the others, as required by the gABI. Within each group, symbols
+/// keep the order they were added in.
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone, Default)]
+pub struct SymbolTableBuilder {
+    locals: Vec<(String, Symbol)>,
+    others: Vec<(String, Symbol)>,
+}
+
+/// The serialized output of a [SymbolTableBuilder], along with the values needed to fill in
+/// the section headers for the two tables.
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SymbolTableData {
+    /// The contents of the symbol table section
+    pub symtab: Vec<u8>,
+    /// The contents of the string table section holding the symbol names
+    pub strtab: Vec<u8>,
+    /// The index of the first non-local symbol, which goes in the symbol table's sh_info
+    pub first_global: u32,
+    /// The size of each symbol table entry, which goes in the symbol table's sh_entsize
+    pub entsize: u64,
+    /// The alignment of the symbol table, which goes in its sh_addralign
+    pub align: u64,
+}
+
+#[cfg(feature = "alloc")]
+impl SymbolTableData {
+    /// Get a [abi::SHT_SYMTAB] section header for the symbol table, linked to the string
+    /// table at section index `strtab_index`. The name, address and offset are left as 0.
+    pub fn symtab_header(&self, strtab_index: u32) -> SectionHeader {
+        SectionHeader {
+            sh_type: abi::SHT_SYMTAB,
+            sh_link: strtab_index,
+            sh_info: self.first_global,
+            sh_addralign: self.align,
+            sh_entsize: self.entsize,
+            sh_size: self.symtab.len() as u64,
+            ..Default::default()
+        }
+    }
+
+    /// Get a [abi::SHT_STRTAB] section header for the string table. The name, address and
+    /// offset are left as 0.
+    pub fn strtab_header(&self) -> SectionHeader {
+        SectionHeader {
+            sh_type: abi::SHT_STRTAB,
+            sh_addralign: 1,
+            sh_size: self.strtab.len() as u64,
+            ..Default::default()
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl SymbolTableBuilder {
+    /// Create a new builder for an empty symbol table.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a symbol with the given name. Its `st_name` is filled in when the table is built.
+    ///
+    /// The binding, type and visibility can be set with [Symbol::set_st_info] and
+    /// [Symbol::set_st_vis].
+    pub fn add_symbol(&mut self, name: &str, sym: Symbol) {
+        let entry = (String::from(name), sym);
+        if entry.1.st_bind() == abi::STB_LOCAL {
+            self.locals.push(entry);
+        } else {
+            self.others.push(entry);
+        }
+    }
+
+    /// Returns the number of symbols added, not counting the NULL symbol.
+    pub fn len(&self) -> usize {
+        self.locals.len() + self.others.len()
+    }
+
+    /// Returns whether no symbols have been added.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Serialize the symbol table and its string table for an object of the given class and
+    /// byte order.
+    pub fn build<E: EndianParse>(
+        &self,
+        endian: E,
+        class: Class,
+    ) -> Result<SymbolTableData, ParseError> {
+        let entsize = Symbol::size_for(class);
+        let mut symtab = vec![0u8; (self.len() + 1) * entsize];
+        let mut strtab = StringTableBuilder::with_tail_merging();
+        let mut offset = entsize;
+        for (name, sym) in self.locals.iter().chain(self.others.iter()) {
+            let mut sym = sym.clone();
+            sym.st_name = strtab.insert(name.as_bytes());
+            sym.serialize_at(endian, class, &mut offset, &mut symtab)?;
+        }
+
+        Ok(SymbolTableData {
+            symtab,
+            strtab: strtab.build(),
+            first_global: (self.locals.len() + 1).try_into()?,
+            entsize: entsize as u64,
+            align: match class {
+                Class::ELF32 => 4,
+                Class::ELF64 => 8,
+            },
+        })
+    }
+}
+
+impl Symbol {
+    /// Parse a symbol field-by-field, which works for either byte order.
+    pub(crate) fn parse_fields<E: EndianParse>(
         endian: E,
         class: Class,
         offset: &mut usize,
@@ -142,11 +911,90 @@ impl ParseAt for Symbol {
         })
     }
 
+    /// Parse a symbol whose byte order matches the host's from a single copy of its bytes.
+    pub(crate) fn parse_native(
+        class: Class,
+        offset: &mut usize,
+        data: &[u8],
+    ) -> Result<Self, ParseError> {
+        match class {
+            Class::ELF32 => {
+                let buf: [u8; 16] = read_entry(offset, data)?;
+                Ok(Symbol {
+                    st_name: ne_u32(&buf, 0),
+                    st_value: ne_u32(&buf, 4) as u64,
+                    st_size: ne_u32(&buf, 8) as u64,
+                    st_info: buf[12],
+                    st_other: buf[13],
+                    st_shndx: ne_u16(&buf, 14),
+                })
+            }
+            Class::ELF64 => {
+                let buf: [u8; 24] = read_entry(offset, data)?;
+                Ok(Symbol {
+                    st_name: ne_u32(&buf, 0),
+                    st_info: buf[4],
+                    st_other: buf[5],
+                    st_shndx: ne_u16(&buf, 6),
+                    st_value: ne_u64(&buf, 8),
+                    st_size: ne_u64(&buf, 16),
+                })
+            }
+        }
+    }
+}
+
+pub const ELF32_SYM_SIZE: usize = 16;
+pub const ELF64_SYM_SIZE: usize = 24;
+
+impl ParseAt for Symbol {
+    fn parse_at<E: EndianParse>(
+        endian: E,
+        class: Class,
+        offset: &mut usize,
+        data: &[u8],
+    ) -> Result<Self, ParseError> {
+        if endian.is_native() {
+            Symbol::parse_native(class, offset, data)
+        } else {
+            Symbol::parse_fields(endian, class, offset, data)
+        }
+    }
+
     #[inline]
     fn size_for(class: Class) -> usize {
         match class {
-            Class::ELF32 => 16,
-            Class::ELF64 => 24,
+            Class::ELF32 => ELF32_SYM_SIZE,
+            Class::ELF64 => ELF64_SYM_SIZE,
+        }
+    }
+}
+
+impl SerializeAt for Symbol {
+    fn serialize_at<E: EndianParse>(
+        &self,
+        endian: E,
+        class: Class,
+        offset: &mut usize,
+        data: &mut [u8],
+    ) -> Result<(), ParseError> {
+        match class {
+            Class::ELF32 => {
+                endian.write_u32_at(offset, data, self.st_name)?;
+                endian.write_u32_at(offset, data, self.st_value.try_into()?)?;
+                endian.write_u32_at(offset, data, self.st_size.try_into()?)?;
+                endian.write_u8_at(offset, data, self.st_info)?;
+                endian.write_u8_at(offset, data, self.st_other)?;
+                endian.write_u16_at(offset, data, self.st_shndx)
+            }
+            Class::ELF64 => {
+                endian.write_u32_at(offset, data, self.st_name)?;
+                endian.write_u8_at(offset, data, self.st_info)?;
+                endian.write_u8_at(offset, data, self.st_other)?;
+                endian.write_u16_at(offset, data, self.st_shndx)?;
+                endian.write_u64_at(offset, data, self.st_value)?;
+                endian.write_u64_at(offset, data, self.st_size)
+            }
         }
     }
 }
@@ -155,6 +1003,72 @@ impl ParseAt for Symbol {
 mod symbol_tests {
     use super::*;
 
+    #[test]
+    fn tls_offset() {
+        let mut sym = Symbol {
+            st_value: 0x10,
+            st_shndx: 3,
+            ..Default::default()
+        };
+        sym.set_st_info(abi::STB_GLOBAL, abi::STT_OBJECT);
+        assert!(!sym.is_tls());
+        assert_eq!(sym.tls_offset(), None);
+
+        sym.set_st_info(abi::STB_GLOBAL, abi::STT_TLS);
+        assert!(sym.is_tls());
+        assert_eq!(sym.tls_offset(), Some(0x10));
+
+        // Undefined TLS symbols are resolved from another object's TLS block
+        sym.st_shndx = abi::SHN_UNDEF;
+        assert_eq!(sym.tls_offset(), None);
+    }
+
+    #[test]
+    fn shndx_classifies_reserved_values() {
+        let cases = [
+            (abi::SHN_UNDEF, SymbolShndx::Undefined, "UND"),
+            (1, SymbolShndx::Section(1), "1"),
+            (0xfeff, SymbolShndx::Section(0xfeff), "65279"),
+            (
+                abi::SHN_LOPROC,
+                SymbolShndx::ProcessorSpecific(0xff00),
+                "PRC[0xff00]",
+            ),
+            (
+                abi::SHN_HIPROC,
+                SymbolShndx::ProcessorSpecific(0xff1f),
+                "PRC[0xff1f]",
+            ),
+            (
+                abi::SHN_LOOS,
+                SymbolShndx::OsSpecific(0xff20),
+                "OS [0xff20]",
+            ),
+            (
+                abi::SHN_HIOS,
+                SymbolShndx::OsSpecific(0xff3f),
+                "OS [0xff3f]",
+            ),
+            (0xff40, SymbolShndx::Reserved(0xff40), "RSV[0xff40]"),
+            (abi::SHN_ABS, SymbolShndx::Absolute, "ABS"),
+            (abi::SHN_COMMON, SymbolShndx::Common, "COM"),
+            (abi::SHN_XINDEX, SymbolShndx::XIndex, "XIDX"),
+        ];
+        for (st_shndx, expected, name) in cases {
+            let sym = Symbol {
+                st_shndx,
+                ..Default::default()
+            };
+            assert_eq!(sym.shndx(), expected);
+            assert_eq!(format!("{}", sym.shndx()), name);
+            let section = match expected {
+                SymbolShndx::Section(shndx) => Some(shndx),
+                _ => None,
+            };
+            assert_eq!(sym.shndx().section(), section);
+        }
+    }
+
     #[test]
     fn symbol_undefined() {
         let undef_sym = Symbol {
@@ -177,6 +1091,682 @@ mod symbol_tests {
         };
         assert!(!def_sym.is_undefined());
     }
+
+    #[test]
+    fn symbol_absolute_and_common() {
+        let mut sym = Symbol {
+            st_name: 0,
+            st_value: 0,
+            st_size: 0,
+            st_shndx: abi::SHN_ABS,
+            st_info: 0,
+            st_other: 0,
+        };
+        assert!(sym.is_absolute());
+        assert!(!sym.is_common());
+
+        sym.st_shndx = abi::SHN_COMMON;
+        assert!(!sym.is_absolute());
+        assert!(sym.is_common());
+    }
+
+    #[test]
+    fn symbol_info_accessors() {
+        let sym = Symbol {
+            st_name: 0,
+            st_value: 0,
+            st_size: 0,
+            st_shndx: 1,
+            st_info: (abi::STB_WEAK << 4) | abi::STT_TLS,
+            st_other: 0xf0 | abi::STV_PROTECTED,
+        };
+        assert_eq!(sym.st_bind(), abi::STB_WEAK);
+        assert_eq!(sym.st_symtype(), abi::STT_TLS);
+        assert_eq!(sym.st_vis(), abi::STV_PROTECTED);
+    }
+
+    #[cfg(feature = "to_str")]
+    #[test]
+    fn symbol_display() {
+        let sym = Symbol {
+            st_name: 0,
+            st_value: 0x1139,
+            st_size: 34,
+            st_shndx: 14,
+            st_info: (abi::STB_GLOBAL << 4) | abi::STT_FUNC,
+            st_other: abi::STV_DEFAULT,
+        };
+        assert_eq!(
+            format!("{sym}"),
+            "0000000000001139    34 FUNC    GLOBAL DEFAULT   14"
+        );
+
+        let sym = Symbol {
+            st_name: 0,
+            st_value: 0,
+            st_size: 0,
+            st_shndx: abi::SHN_UNDEF,
+            st_info: (abi::STB_WEAK << 4) | abi::STT_NOTYPE,
+            st_other: abi::STV_HIDDEN,
+        };
+        assert_eq!(
+            format!("{sym}"),
+            "0000000000000000     0 NOTYPE  WEAK   HIDDEN   UND"
+        );
+    }
+
+    #[cfg(feature = "to_str")]
+    #[test]
+    fn symbol_display_elf32_and_large_sizes() {
+        // Rows from `readelf -sW` of sample-objects/symver.armhf.so and basic.x86_64
+        let sym = Symbol {
+            st_name: 0,
+            st_value: 0,
+            st_size: 0,
+            st_shndx: abi::SHN_ABS,
+            st_info: (abi::STB_GLOBAL << 4) | abi::STT_OBJECT,
+            st_other: abi::STV_DEFAULT,
+        };
+        assert_eq!(
+            format!("{}", sym.display(Class::ELF32)),
+            "00000000     0 OBJECT  GLOBAL DEFAULT  ABS"
+        );
+
+        let sym = Symbol {
+            st_name: 0,
+            st_value: 0x600920,
+            st_size: 0x40000,
+            st_shndx: 26,
+            st_info: (abi::STB_GLOBAL << 4) | abi::STT_OBJECT,
+            st_other: abi::STV_DEFAULT,
+        };
+        assert_eq!(
+            format!("{sym}"),
+            "0000000000600920 0x40000 OBJECT  GLOBAL DEFAULT   26"
+        );
+    }
+
+    #[cfg(feature = "to_str")]
+    #[test]
+    fn symbol_display_os_and_proc_ranges() {
+        // STT_LOOS is STT_GNU_IFUNC and STB_LOOS is STB_GNU_UNIQUE, but the rest of the
+        // OS and processor-specific ranges are unnamed.
+        let sym = Symbol {
+            st_name: 0,
+            st_value: 0,
+            st_size: 0,
+            st_shndx: abi::SHN_ABS,
+            st_info: ((abi::STB_LOOS + 2) << 4) | (abi::STT_LOOS + 1),
+            st_other: 0,
+        };
+        assert_eq!(
+            format!("{sym}"),
+            "0000000000000000     0 LOOS+1  LOOS+2 DEFAULT  ABS"
+        );
+
+        let sym = Symbol {
+            st_name: 0,
+            st_value: 0,
+            st_size: 0,
+            st_shndx: abi::SHN_COMMON,
+            st_info: (abi::STB_LOPROC << 4) | (abi::STT_LOPROC + 2),
+            st_other: abi::STV_INTERNAL,
+        };
+        assert_eq!(
+            format!("{sym}"),
+            "0000000000000000     0 LOPROC+2 LOPROC+0 INTERNAL  COM"
+        );
+
+        let sym = Symbol {
+            st_name: 0,
+            st_value: 0,
+            st_size: 0,
+            st_shndx: 2,
+            st_info: (abi::STB_LOOS << 4) | 8,
+            st_other: 0,
+        };
+        assert_eq!(
+            format!("{sym}"),
+            "0000000000000000     0 8       GNU_UNIQUE DEFAULT    2"
+        );
+    }
+}
+
+#[cfg(test)]
+mod table_tests {
+    use super::*;
+    use crate::endian::LittleEndian;
+    use crate::parse::WordSize;
+
+    #[test]
+    fn get_with_shndx_substitutes_xindex() {
+        // Three ELF64 symbols: one in a normal section, one with SHN_XINDEX and one SHN_ABS
+        let mut sym_data = [0u8; 72];
+        sym_data[6..8].copy_from_slice(&7u16.to_le_bytes());
+        sym_data[30..32].copy_from_slice(&abi::SHN_XINDEX.to_le_bytes());
+        sym_data[54..56].copy_from_slice(&abi::SHN_ABS.to_le_bytes());
+        let symtab = SymbolTable::new(LittleEndian, Class::ELF64, &sym_data);
+
+        let mut shndx_data = [0u8; 12];
+        shndx_data[4..8].copy_from_slice(&70000u32.to_le_bytes());
+        let shndx_table =
+            SymbolShndxTable::new(LittleEndian, Class::ELF64, WordSize::U32, &shndx_data);
+
+        let (sym, shndx) = symtab
+            .get_with_shndx(0, &shndx_table)
+            .expect("should parse");
+        assert_eq!(sym.st_shndx, 7);
+        assert_eq!(shndx, 7);
+
+        let (sym, shndx) = symtab
+            .get_with_shndx(1, &shndx_table)
+            .expect("should parse");
+        assert_eq!(sym.st_shndx, abi::SHN_XINDEX);
+        assert_eq!(shndx, 70000);
+
+        let (sym, shndx) = symtab
+            .get_with_shndx(2, &shndx_table)
+            .expect("should parse");
+        assert_eq!(sym.st_shndx, abi::SHN_ABS);
+        assert_eq!(shndx, abi::SHN_ABS as u32);
+    }
+
+    #[test]
+    fn iter_for_section_resolves_xindex() {
+        // Four ELF64 symbols in sections 7, 0xfff1 (by SHN_XINDEX), SHN_ABS and 7 (by SHN_XINDEX)
+        let mut sym_data = [0u8; 96];
+        sym_data[6..8].copy_from_slice(&7u16.to_le_bytes());
+        sym_data[30..32].copy_from_slice(&abi::SHN_XINDEX.to_le_bytes());
+        sym_data[54..56].copy_from_slice(&abi::SHN_ABS.to_le_bytes());
+        sym_data[78..80].copy_from_slice(&abi::SHN_XINDEX.to_le_bytes());
+        let symtab = SymbolTable::new(LittleEndian, Class::ELF64, &sym_data);
+
+        let mut shndx_data = [0u8; 16];
+        shndx_data[4..8].copy_from_slice(&0xfff1u32.to_le_bytes());
+        shndx_data[12..16].copy_from_slice(&7u32.to_le_bytes());
+        let shndx_table =
+            SymbolShndxTable::new(LittleEndian, Class::ELF64, WordSize::U32, &shndx_data);
+
+        let indexes = |iter: SectionSymbolIterator<'_, LittleEndian>| {
+            iter.map(|(index, _)| index).collect::<Vec<_>>()
+        };
+        assert_eq!(indexes(symtab.iter_for_section(7)), vec![0]);
+        assert_eq!(indexes(symtab.iter_for_section(abi::SHN_ABS)), vec![2]);
+        assert_eq!(
+            indexes(symtab.iter_for_section_with_shndx(7, &shndx_table)),
+            vec![0, 3]
+        );
+        // The section whose index is the same as SHN_ABS doesn't pick up the absolute symbol
+        assert_eq!(
+            indexes(symtab.iter_for_section_with_shndx(0xfff1, &shndx_table)),
+            vec![1]
+        );
+    }
+
+    #[test]
+    fn get_with_shndx_short_shndx_table() {
+        let mut sym_data = [0u8; 24];
+        sym_data[6..8].copy_from_slice(&abi::SHN_XINDEX.to_le_bytes());
+        let symtab = SymbolTable::new(LittleEndian, Class::ELF64, &sym_data);
+        let shndx_table = SymbolShndxTable::new(LittleEndian, Class::ELF64, WordSize::U32, &[]);
+
+        assert!(matches!(
+            symtab.get_with_shndx(0, &shndx_table),
+            Err(ParseError::BadOffset(0))
+        ));
+    }
+
+    #[test]
+    fn section_index_resolves_xindex() {
+        // Three ELF64 symbols: one in section 7, one with SHN_XINDEX and one SHN_ABS
+        let mut sym_data = [0u8; 72];
+        sym_data[6..8].copy_from_slice(&7u16.to_le_bytes());
+        sym_data[30..32].copy_from_slice(&abi::SHN_XINDEX.to_le_bytes());
+        sym_data[54..56].copy_from_slice(&abi::SHN_ABS.to_le_bytes());
+        let symtab = SymbolTable::new(LittleEndian, Class::ELF64, &sym_data);
+
+        let mut shndx_data = [0u8; 12];
+        shndx_data[4..8].copy_from_slice(&70000u32.to_le_bytes());
+        let shndx_table =
+            SymbolShndxTable::new(LittleEndian, Class::ELF64, WordSize::U32, &shndx_data);
+
+        let table = Some(&shndx_table);
+        assert_eq!(
+            symtab.section_index(0, table).expect("should parse"),
+            Some(7)
+        );
+        assert_eq!(
+            symtab.section_index(1, table).expect("should parse"),
+            Some(70000)
+        );
+        assert_eq!(symtab.section_index(2, table).expect("should parse"), None);
+
+        // Without the table, only the SHN_XINDEX symbol can't be resolved
+        assert_eq!(
+            symtab.section_index(0, None).expect("should parse"),
+            Some(7)
+        );
+        assert!(matches!(
+            symtab.section_index(1, None),
+            Err(ParseError::UnexpectedSectionType((
+                abi::SHT_NULL,
+                abi::SHT_SYMTAB_SHNDX
+            )))
+        ));
+        assert_eq!(symtab.section_index(2, None).expect("should parse"), None);
+    }
+}
+
+#[cfg(test)]
+mod name_iterator_tests {
+    use super::*;
+    use crate::endian::LittleEndian;
+
+    fn sym32(st_name: u32) -> [u8; 16] {
+        let mut buf = [0u8; 16];
+        buf[0..4].copy_from_slice(&st_name.to_le_bytes());
+        buf
+    }
+
+    #[test]
+    fn iter_with_names() {
+        let syms = [sym32(0), sym32(1), sym32(100), sym32(5)].concat();
+        let symtab = SymbolTable::new(LittleEndian, Class::ELF32, &syms);
+        let strtab = StringTable::new(b"\0foo\0bar\0");
+
+        let mut iter = symtab.iter_with_names(&strtab);
+        let (sym, name) = iter.next().unwrap().expect("should read name");
+        assert_eq!((sym.st_name, name), (0, ""));
+        let (sym, name) = iter.next().unwrap().expect("should read name");
+        assert_eq!((sym.st_name, name), (1, "foo"));
+        // A bad name doesn't end iteration
+        assert!(matches!(iter.next(), Some(Err(ParseError::BadOffset(100)))));
+        let (sym, name) = iter.next().unwrap().expect("should read name");
+        assert_eq!((sym.st_name, name), (5, "bar"));
+        assert!(iter.next().is_none());
+    }
+
+    #[test]
+    fn iter_with_names_lossy() {
+        let syms = [sym32(1), sym32(100), sym32(5)].concat();
+        let symtab = SymbolTable::new(LittleEndian, Class::ELF32, &syms);
+        let strtab = StringTable::new(b"\0foo\0b\xffr\0");
+
+        // The strict iterator errors on the invalid name
+        let mut iter = symtab.iter_with_names(&strtab);
+        assert!(iter.next().unwrap().is_ok());
+        assert!(iter.next().unwrap().is_err());
+        assert!(matches!(iter.next(), Some(Err(ParseError::Utf8Error(_)))));
+
+        let mut iter = symtab.iter_with_names(&strtab).lossy();
+        let (sym, name) = iter.next().unwrap().expect("should read name");
+        assert_eq!((sym.st_name, name.as_ref()), (1, "foo"));
+        // Bad offsets are still errors
+        assert!(matches!(iter.next(), Some(Err(ParseError::BadOffset(100)))));
+        let (sym, name) = iter.next().unwrap().expect("should read name");
+        assert_eq!((sym.st_name, name.as_ref()), (5, "b\u{FFFD}r"));
+        assert!(iter.next().is_none());
+    }
+
+    #[cfg(feature = "demangle")]
+    #[test]
+    fn iter_with_names_demangled() {
+        let syms = [sym32(0), sym32(1), sym32(6)].concat();
+        let symtab = SymbolTable::new(LittleEndian, Class::ELF32, &syms);
+        let strtab = StringTable::new(b"\0main\0_ZN3foo3barEv\0");
+
+        let names: Vec<_> = symtab
+            .iter_with_names(&strtab)
+            .demangled()
+            .map(|result| result.expect("should read name").1)
+            .collect();
+        assert_eq!(names, ["", "main", "foo::bar()"]);
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod symbolizer_tests {
+    use super::*;
+    use crate::endian::LittleEndian;
+
+    fn sym64(st_name: u32, st_info: u8, st_shndx: u16, st_value: u64, st_size: u64) -> [u8; 24] {
+        let mut buf = [0u8; 24];
+        buf[0..4].copy_from_slice(&st_name.to_le_bytes());
+        buf[4] = st_info;
+        buf[6..8].copy_from_slice(&st_shndx.to_le_bytes());
+        buf[8..16].copy_from_slice(&st_value.to_le_bytes());
+        buf[16..24].copy_from_slice(&st_size.to_le_bytes());
+        buf
+    }
+
+    #[test]
+    fn symbolize() {
+        let strs = b"\0outer\0inner\0label\0abs\0undef\0data\0";
+        let func = (abi::STB_GLOBAL << 4) | abi::STT_FUNC;
+        let object = (abi::STB_GLOBAL << 4) | abi::STT_OBJECT;
+        let syms = [
+            sym64(0, 0, 0, 0, 0),
+            sym64(1, func, 1, 0x1000, 0x100),
+            sym64(7, func, 1, 0x1010, 0x10),
+            sym64(13, func, 1, 0x2000, 0),
+            sym64(19, object, abi::SHN_ABS, 0x1000, 0x1000),
+            sym64(23, func, abi::SHN_UNDEF, 0x1000, 0x1000),
+            sym64(29, object, 2, 0x3000, 8),
+        ]
+        .concat();
+        let symtab = SymbolTable::new(LittleEndian, Class::ELF64, &syms);
+        let strtab = StringTable::new(strs);
+
+        let symbolizer = Symbolizer::new(&symtab, &strtab, 0).expect("should build");
+        assert_eq!(symbolizer.len(), 4);
+
+        let (sym, name, offset) = symbolizer.symbolize(0x1004).expect("should find outer");
+        assert_eq!((name, offset, sym.st_value), ("outer", 4, 0x1000));
+
+        // inner overlaps with outer and starts closer
+        let (_, name, offset) = symbolizer.symbolize(0x1018).expect("should find inner");
+        assert_eq!((name, offset), ("inner", 8));
+
+        // past the end of inner but still within outer
+        let (_, name, offset) = symbolizer.symbolize(0x1020).expect("should find outer");
+        assert_eq!((name, offset), ("outer", 0x20));
+
+        // zero-sized symbols cover everything up until the next symbol
+        let (_, name, offset) = symbolizer.symbolize(0x2345).expect("should find label");
+        assert_eq!((name, offset), ("label", 0x345));
+
+        let (_, name, offset) = symbolizer.symbolize(0x3007).expect("should find data");
+        assert_eq!((name, offset), ("data", 7));
+
+        assert!(symbolizer.symbolize(0x3008).is_none());
+        assert!(symbolizer.symbolize(0x1100).is_none());
+        assert!(symbolizer.symbolize(0xfff).is_none());
+    }
+
+    #[test]
+    fn symbolize_with_load_bias() {
+        let strs = b"\0func\0";
+        let syms = [
+            sym64(0, 0, 0, 0, 0),
+            sym64(1, (abi::STB_GLOBAL << 4) | abi::STT_FUNC, 1, 0x1000, 0x10),
+        ]
+        .concat();
+        let symtab = SymbolTable::new(LittleEndian, Class::ELF64, &syms);
+        let strtab = StringTable::new(strs);
+
+        let symbolizer = Symbolizer::new(&symtab, &strtab, 0x7f00_0000_0000).expect("should build");
+        let (_, name, offset) = symbolizer
+            .symbolize(0x7f00_0000_1008)
+            .expect("should find func");
+        assert_eq!((name, offset), ("func", 8));
+        assert!(symbolizer.symbolize(0x1008).is_none());
+    }
+
+    #[test]
+    fn symbolize_overflowing_symbol() {
+        let strs = b"\0func\0corrupt\0";
+        let func = (abi::STB_GLOBAL << 4) | abi::STT_FUNC;
+        let syms = [
+            sym64(0, 0, 0, 0, 0),
+            sym64(1, func, 1, 0x1000, 0x10),
+            sym64(6, func, 1, u64::MAX - 1, 0x10),
+        ]
+        .concat();
+        let symtab = SymbolTable::new(LittleEndian, Class::ELF64, &syms);
+        let strtab = StringTable::new(strs);
+
+        let symbolizer = Symbolizer::new(&symtab, &strtab, 0).expect("should build");
+        let (_, name, offset) = symbolizer
+            .symbolize(u64::MAX - 1)
+            .expect("should find corrupt");
+        assert_eq!((name, offset), ("corrupt", 0));
+        let (_, name, offset) = symbolizer.symbolize(0x1008).expect("should find func");
+        assert_eq!((name, offset), ("func", 8));
+    }
+}
+
+#[cfg(test)]
+mod globals_tests {
+    use super::*;
+    use crate::endian::LittleEndian;
+
+    #[test]
+    fn globals() {
+        let mut data = [0u8; 4 * 16];
+        for (n, sym) in data.chunks_mut(16).enumerate() {
+            sym[4] = n as u8;
+        }
+        let symtab = SymbolTable::new(LittleEndian, Class::ELF32, &data);
+
+        let globals = symtab.globals(3).expect("should split");
+        assert_eq!(globals.len(), 1);
+        assert_eq!(globals.get(3).expect("should parse").st_value, 3);
+        assert!(matches!(globals.get(0), Err(ParseError::BadOffset(0))));
+        let enumerated: Vec<_> = globals.iter_enumerated().collect();
+        assert_eq!(enumerated.len(), 1);
+        assert_eq!(enumerated[0].0, 3);
+        assert_eq!(
+            globals
+                .iter_for_section(0)
+                .map(|(index, _)| index)
+                .collect::<Vec<_>>(),
+            [3]
+        );
+
+        assert!(symtab.globals(4).expect("should split").is_empty());
+        assert!(matches!(symtab.globals(5), Err(ParseError::BadOffset(5))));
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod name_index_tests {
+    use super::*;
+    use crate::endian::AnyEndian;
+    use crate::ElfBytes;
+
+    #[test]
+    fn duplicates_in_table_order() {
+        let mut builder = SymbolTableBuilder::new();
+        for (name, st_value) in [("b", 1), ("a", 2), ("b", 3), ("c", 4), ("", 5), ("b", 6)] {
+            builder.add_symbol(
+                name,
+                Symbol {
+                    st_value,
+                    ..Default::default()
+                },
+            );
+        }
+        let data = builder
+            .build(AnyEndian::Little, Class::ELF64)
+            .expect("should build");
+        let symtab = SymbolTable::new(AnyEndian::Little, Class::ELF64, &data.symtab);
+        let strtab = StringTable::new(&data.strtab);
+
+        let index = SymbolNameIndex::build(&symtab, &strtab).expect("should build");
+        assert_eq!(index.len(), 5);
+        let found: Vec<(usize, u64)> = index
+            .lookup(b"b")
+            .map(|(i, sym)| (i, sym.st_value))
+            .collect();
+        assert_eq!(found, [(1, 1), (3, 3), (6, 6)]);
+        let found: Vec<usize> = index.lookup(b"c").map(|(i, _)| i).collect();
+        assert_eq!(found, [4]);
+        assert_eq!(index.lookup(b"").count(), 0);
+        assert_eq!(index.lookup(b"bb").count(), 0);
+    }
+
+    #[test]
+    fn sample_symtab() {
+        let file_data = std::fs::read("sample-objects/basic.x86_64").expect("should read");
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("should parse");
+        let (symtab, strtab) = file.symbol_table().expect("should parse").unwrap();
+        let index = SymbolNameIndex::build(&symtab, &strtab).expect("should build");
+
+        for (i, sym) in symtab.iter().enumerate() {
+            let name = strtab.get_raw(sym.st_name as usize).unwrap();
+            if name.is_empty() {
+                continue;
+            }
+            assert!(index.lookup(name).any(|(found, _)| found == i));
+        }
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod table_builder_tests {
+    use super::*;
+    use crate::endian::{AnyEndian, LittleEndian};
+
+    fn sym(st_value: u64, st_size: u64, bind: u8, symtype: u8, vis: u8, st_shndx: u16) -> Symbol {
+        let mut sym = Symbol {
+            st_shndx,
+            st_value,
+            st_size,
+            ..Default::default()
+        };
+        sym.set_st_info(bind, symtype);
+        sym.set_st_vis(vis);
+        sym
+    }
+
+    fn roundtrip(endian: AnyEndian, class: Class) {
+        let inputs = [
+            (
+                "main",
+                sym(
+                    0x1000,
+                    0x20,
+                    abi::STB_GLOBAL,
+                    abi::STT_FUNC,
+                    abi::STV_DEFAULT,
+                    1,
+                ),
+            ),
+            (
+                "file.c",
+                sym(
+                    0,
+                    0,
+                    abi::STB_LOCAL,
+                    abi::STT_FILE,
+                    abi::STV_DEFAULT,
+                    abi::SHN_ABS,
+                ),
+            ),
+            (
+                "helper",
+                sym(
+                    0x1020,
+                    0x8,
+                    abi::STB_WEAK,
+                    abi::STT_FUNC,
+                    abi::STV_HIDDEN,
+                    1,
+                ),
+            ),
+            (
+                "counter",
+                sym(
+                    0x2000,
+                    4,
+                    abi::STB_LOCAL,
+                    abi::STT_OBJECT,
+                    abi::STV_DEFAULT,
+                    2,
+                ),
+            ),
+            (
+                "puts",
+                sym(0, 0, abi::STB_GLOBAL, abi::STT_NOTYPE, abi::STV_DEFAULT, 0),
+            ),
+            (
+                "ounter",
+                sym(
+                    0x2004,
+                    4,
+                    abi::STB_LOCAL,
+                    abi::STT_OBJECT,
+                    abi::STV_INTERNAL,
+                    2,
+                ),
+            ),
+        ];
+        let mut builder = SymbolTableBuilder::new();
+        for (name, sym) in inputs.iter() {
+            builder.add_symbol(name, sym.clone());
+        }
+        assert_eq!(builder.len(), inputs.len());
+
+        let data = builder.build(endian, class).expect("should build");
+        assert_eq!(data.first_global, 4);
+        assert_eq!(data.entsize as usize, Symbol::size_for(class));
+        assert_eq!(
+            data.symtab.len(),
+            (inputs.len() + 1) * data.entsize as usize
+        );
+
+        let shdr = data.symtab_header(7);
+        assert_eq!(shdr.sh_type, abi::SHT_SYMTAB);
+        assert_eq!((shdr.sh_link, shdr.sh_info), (7, 4));
+        assert_eq!(shdr.sh_size, data.symtab.len() as u64);
+        assert_eq!(data.strtab_header().sh_type, abi::SHT_STRTAB);
+
+        let symtab = SymbolTable::new(endian, class, &data.symtab);
+        let strtab = StringTable::new(&data.strtab);
+        let read: Vec<(Symbol, &str)> = symtab
+            .iter_with_names(&strtab)
+            .map(|result| result.expect("should read name"))
+            .collect();
+
+        assert_eq!(read[0], (Symbol::default(), ""));
+        let locals = inputs.iter().filter(|(_, s)| s.st_bind() == abi::STB_LOCAL);
+        let others = inputs.iter().filter(|(_, s)| s.st_bind() != abi::STB_LOCAL);
+        for ((sym, name), (want_name, want)) in read[1..].iter().zip(locals.chain(others)) {
+            assert_eq!(name, want_name);
+            assert_eq!(
+                Symbol {
+                    st_name: 0,
+                    ..sym.clone()
+                },
+                *want
+            );
+        }
+        assert!(read[1..4]
+            .iter()
+            .all(|(s, _)| s.st_bind() == abi::STB_LOCAL));
+        assert!(read[4..].iter().all(|(s, _)| s.st_bind() != abi::STB_LOCAL));
+    }
+
+    #[test]
+    fn roundtrip_all_formats() {
+        for class in [Class::ELF32, Class::ELF64] {
+            roundtrip(AnyEndian::Little, class);
+            roundtrip(AnyEndian::Big, class);
+        }
+    }
+
+    #[test]
+    fn empty_table() {
+        let builder = SymbolTableBuilder::new();
+        assert!(builder.is_empty());
+        let data = builder
+            .build(LittleEndian, Class::ELF64)
+            .expect("should build");
+        assert_eq!(data.symtab, [0u8; 24]);
+        assert_eq!(data.strtab, b"\0");
+        assert_eq!(data.first_global, 1);
+    }
+
+    #[test]
+    fn set_st_vis_keeps_other_bits() {
+        let mut sym = Symbol {
+            st_other: 0xf0,
+            ..Default::default()
+        };
+        sym.set_st_vis(abi::STV_PROTECTED);
+        assert_eq!(sym.st_other, 0xf3);
+        assert_eq!(sym.st_vis(), abi::STV_PROTECTED);
+    }
 }
 
 #[cfg(test)]
@@ -269,3 +1859,55 @@ mod parse_tests {
         test_parse_fuzz_too_short::<_, Symbol>(BigEndian, Class::ELF64);
     }
 }
+
+#[cfg(test)]
+mod serialize_tests {
+    use super::*;
+    use crate::endian::{BigEndian, LittleEndian};
+    use crate::parse::test_serialize_roundtrip;
+
+    #[test]
+    fn serialize_sym32_lsb_roundtrip() {
+        test_serialize_roundtrip::<_, Symbol>(LittleEndian, Class::ELF32);
+    }
+
+    #[test]
+    fn serialize_sym32_msb_roundtrip() {
+        test_serialize_roundtrip::<_, Symbol>(BigEndian, Class::ELF32);
+    }
+
+    #[test]
+    fn serialize_sym64_lsb_roundtrip() {
+        test_serialize_roundtrip::<_, Symbol>(LittleEndian, Class::ELF64);
+    }
+
+    #[test]
+    fn serialize_sym64_msb_roundtrip() {
+        test_serialize_roundtrip::<_, Symbol>(BigEndian, Class::ELF64);
+    }
+}
+
+#[cfg(all(test, feature = "serde"))]
+mod serde_tests {
+    use super::*;
+    use crate::parse::test_serde_roundtrip;
+
+    #[test]
+    fn serde_symbol() {
+        let sym = Symbol {
+            st_name: 49,
+            st_value: 0x4004e6,
+            st_size: 32,
+            st_shndx: 13,
+            st_info: 0x12,
+            st_other: 0,
+        };
+        test_serde_roundtrip(
+            &sym,
+            concat!(
+                r#"{"st_name":49,"st_shndx":13,"st_info":18,"st_other":0,"#,
+                r#""st_value":4195558,"st_size":32}"#
+            ),
+        );
+    }
+}
@@ -1,5 +1,6 @@
 //! Optional module for getting string representations of ELF constants
 use crate::abi;
+use crate::file::Class;
 
 #[cfg(all(feature = "alloc", not(feature = "std")))]
 use alloc::{
@@ -26,6 +27,37 @@ pub fn e_osabi_to_str(e_osabi: u8) -> Option<&'static str> {
         abi::ELFOSABI_FENIXOS => Some("ELFOSABI_FENIXOS"),
         abi::ELFOSABI_CLOUDABI => Some("ELFOSABI_CLOUDABI"),
         abi::ELFOSABI_OPENVOS => Some("ELFOSABI_OPENVOS"),
+        abi::ELFOSABI_CUDA => Some("ELFOSABI_CUDA"),
+        abi::ELFOSABI_ARM_AEABI => Some("ELFOSABI_ARM_AEABI"),
+        abi::ELFOSABI_ARM => Some("ELFOSABI_ARM"),
+        abi::ELFOSABI_STANDALONE => Some("ELFOSABI_STANDALONE"),
+        _ => None,
+    }
+}
+
+pub fn e_osabi_to_human_str(e_osabi: u8) -> Option<&'static str> {
+    match e_osabi {
+        abi::ELFOSABI_SYSV => Some("UNIX - System V"),
+        abi::ELFOSABI_HPUX => Some("UNIX - HP-UX"),
+        abi::ELFOSABI_NETBSD => Some("UNIX - NetBSD"),
+        abi::ELFOSABI_GNU => Some("UNIX - GNU"),
+        abi::ELFOSABI_SOLARIS => Some("UNIX - Solaris"),
+        abi::ELFOSABI_AIX => Some("UNIX - AIX"),
+        abi::ELFOSABI_IRIX => Some("UNIX - IRIX"),
+        abi::ELFOSABI_FREEBSD => Some("UNIX - FreeBSD"),
+        abi::ELFOSABI_TRU64 => Some("UNIX - TRU64"),
+        abi::ELFOSABI_MODESTO => Some("Novell - Modesto"),
+        abi::ELFOSABI_OPENBSD => Some("UNIX - OpenBSD"),
+        abi::ELFOSABI_OPENVMS => Some("VMS - OpenVMS"),
+        abi::ELFOSABI_NSK => Some("HP - Non-Stop Kernel"),
+        abi::ELFOSABI_AROS => Some("AROS"),
+        abi::ELFOSABI_FENIXOS => Some("FenixOS"),
+        abi::ELFOSABI_CLOUDABI => Some("Nuxi CloudABI"),
+        abi::ELFOSABI_OPENVOS => Some("Stratus Technologies OpenVOS"),
+        abi::ELFOSABI_CUDA => Some("NVIDIA CUDA"),
+        abi::ELFOSABI_ARM_AEABI => Some("ARM EABI"),
+        abi::ELFOSABI_ARM => Some("ARM"),
+        abi::ELFOSABI_STANDALONE => Some("Standalone App"),
         _ => None,
     }
 }
@@ -64,7 +96,13 @@ pub fn e_type_to_str(e_type: u16) -> Option<&'static str> {
 pub fn e_type_to_string(e_type: u16) -> String {
     match e_type_to_str(e_type) {
         Some(s) => s.to_string(),
-        None => format!("e_type({e_type:#x})"),
+        None => match e_type {
+            abi::ET_LOOS..=abi::ET_HIOS => format!("ET_LOOS+{:#x}", e_type - abi::ET_LOOS),
+            abi::ET_LOPROC..=abi::ET_HIPROC => {
+                format!("ET_LOPROC+{:#x}", e_type - abi::ET_LOPROC)
+            }
+            _ => format!("e_type({e_type:#x})"),
+        },
     }
 }
 
@@ -258,7 +296,25 @@ pub fn e_machine_to_human_str(e_machine: u16) -> Option<&'static str> {
         abi::EM_MOXIE => Some("Moxie processor family"),
         abi::EM_AMDGPU => Some("AMD GPU architecture"),
         abi::EM_RISCV => Some("RISC-V"),
+        abi::EM_LANAI => Some("Lanai 32-bit processor"),
+        abi::EM_CEVA => Some("CEVA Processor Architecture Family"),
+        abi::EM_CEVA_X2 => Some("CEVA X2 Processor Family"),
         abi::EM_BPF => Some("Linux BPF"),
+        abi::EM_GRAPHCORE_IPU => Some("Graphcore Intelligent Processing Unit"),
+        abi::EM_IMG1 => Some("Imagination Technologies"),
+        abi::EM_NFP => Some("Netronome Flow Processor"),
+        abi::EM_VE => Some("NEC Vector Engine"),
+        abi::EM_CSKY => Some("C-SKY processor family"),
+        abi::EM_ARC_COMPACT3_64 => Some("Synopsys ARCv2.3 64-bit"),
+        abi::EM_MCS6502 => Some("MOS Technology MCS 6502 processor"),
+        abi::EM_ARC_COMPACT3 => Some("Synopsys ARCv2.3 32-bit"),
+        abi::EM_KVX => Some("Kalray VLIW core of the MPPA processor family"),
+        abi::EM_65816 => Some("WDC 65816/65C816"),
+        abi::EM_LOONGARCH => Some("LoongArch"),
+        abi::EM_KF32 => Some("ChipON KungFu32"),
+        abi::EM_U16_U8CORE => Some("LAPIS nX-U16/U8"),
+        abi::EM_TACHYUM => Some("Tachyum"),
+        abi::EM_56800EF => Some("NXP 56800EF Digital Signal Controller (DSC)"),
         _ => None,
     }
 }
@@ -447,7 +503,25 @@ pub fn e_machine_to_str(e_machine: u16) -> Option<&'static str> {
         abi::EM_MOXIE => Some("EM_MOXIE"),
         abi::EM_AMDGPU => Some("EM_AMDGPU"),
         abi::EM_RISCV => Some("RISC-V"),
+        abi::EM_LANAI => Some("EM_LANAI"),
+        abi::EM_CEVA => Some("EM_CEVA"),
+        abi::EM_CEVA_X2 => Some("EM_CEVA_X2"),
         abi::EM_BPF => Some("EM_BPF"),
+        abi::EM_GRAPHCORE_IPU => Some("EM_GRAPHCORE_IPU"),
+        abi::EM_IMG1 => Some("EM_IMG1"),
+        abi::EM_NFP => Some("EM_NFP"),
+        abi::EM_VE => Some("EM_VE"),
+        abi::EM_CSKY => Some("EM_CSKY"),
+        abi::EM_ARC_COMPACT3_64 => Some("EM_ARC_COMPACT3_64"),
+        abi::EM_MCS6502 => Some("EM_MCS6502"),
+        abi::EM_ARC_COMPACT3 => Some("EM_ARC_COMPACT3"),
+        abi::EM_KVX => Some("EM_KVX"),
+        abi::EM_65816 => Some("EM_65816"),
+        abi::EM_LOONGARCH => Some("EM_LOONGARCH"),
+        abi::EM_KF32 => Some("EM_KF32"),
+        abi::EM_U16_U8CORE => Some("EM_U16_U8CORE"),
+        abi::EM_TACHYUM => Some("EM_TACHYUM"),
+        abi::EM_56800EF => Some("EM_56800EF"),
         _ => None,
     }
 }
@@ -460,7 +534,13 @@ pub fn e_machine_to_string(e_machine: u16) -> String {
     }
 }
 
-pub fn sh_type_to_str(sh_type: u32) -> Option<&'static str> {
+/// Get the name of the given section type, including the processor-specific types that
+/// apply to the given `e_machine`.
+///
+/// Types in the OS- and processor-specific ranges which aren't recognized return None, just
+/// like any other unknown type. Use [sh_type_to_string] (or check against
+/// [SHT_LOOS](abi::SHT_LOOS) etc yourself) to render them relative to the start of their range.
+pub fn sh_type_to_str(sh_type: u32, e_machine: u16) -> Option<&'static str> {
     match sh_type {
         abi::SHT_NULL => Some("SHT_NULL"),
         abi::SHT_PROGBITS => Some("SHT_PROGBITS"),
@@ -479,21 +559,64 @@ pub fn sh_type_to_str(sh_type: u32) -> Option<&'static str> {
         abi::SHT_PREINIT_ARRAY => Some("SHT_PREINIT_ARRAY"),
         abi::SHT_GROUP => Some("SHT_GROUP"),
         abi::SHT_SYMTAB_SHNDX => Some("SHT_SYMTAB_SHNDX"),
+        abi::SHT_RELR => Some("SHT_RELR"),
+        abi::SHT_GNU_SFRAME => Some("SHT_GNU_SFRAME"),
         abi::SHT_GNU_ATTRIBUTES => Some("SHT_GNU_ATTRIBUTES"),
         abi::SHT_GNU_HASH => Some("SHT_GNU_HASH"),
         abi::SHT_GNU_LIBLIST => Some("SHT_GNU_LIBLIST"),
         abi::SHT_GNU_VERDEF => Some("SHT_GNU_VERDEF"),
         abi::SHT_GNU_VERNEED => Some("SHT_GNU_VERNEED"),
         abi::SHT_GNU_VERSYM => Some("SHT_GNU_VERSYM"),
+        abi::SHT_LOPROC..=abi::SHT_HIPROC => sh_type_to_proc_str(sh_type, e_machine),
+        _ => None,
+    }
+}
+
+fn sh_type_to_proc_str(sh_type: u32, e_machine: u16) -> Option<&'static str> {
+    match (e_machine, sh_type) {
+        (abi::EM_ARM, abi::SHT_ARM_EXIDX) => Some("SHT_ARM_EXIDX"),
+        (abi::EM_ARM, abi::SHT_ARM_PREEMPTMAP) => Some("SHT_ARM_PREEMPTMAP"),
+        (abi::EM_ARM, abi::SHT_ARM_ATTRIBUTES) => Some("SHT_ARM_ATTRIBUTES"),
+        (abi::EM_ARM, abi::SHT_ARM_DEBUGOVERLAY) => Some("SHT_ARM_DEBUGOVERLAY"),
+        (abi::EM_ARM, abi::SHT_ARM_OVERLAYSECTION) => Some("SHT_ARM_OVERLAYSECTION"),
+        (abi::EM_AARCH64, abi::SHT_AARCH64_ATTRIBUTES) => Some("SHT_AARCH64_ATTRIBUTES"),
+        (abi::EM_IA_64, abi::SHT_IA_64_EXT) => Some("SHT_IA_64_EXT"),
+        (abi::EM_IA_64, abi::SHT_IA_64_UNWIND) => Some("SHT_IA_64_UNWIND"),
+        (abi::EM_MIPS | abi::EM_MIPS_RS3_LE, _) => match sh_type {
+            abi::SHT_MIPS_LIBLIST => Some("SHT_MIPS_LIBLIST"),
+            abi::SHT_MIPS_CONFLICT => Some("SHT_MIPS_CONFLICT"),
+            abi::SHT_MIPS_GPTAB => Some("SHT_MIPS_GPTAB"),
+            abi::SHT_MIPS_UCODE => Some("SHT_MIPS_UCODE"),
+            abi::SHT_MIPS_DEBUG => Some("SHT_MIPS_DEBUG"),
+            abi::SHT_MIPS_REGINFO => Some("SHT_MIPS_REGINFO"),
+            abi::SHT_MIPS_OPTIONS => Some("SHT_MIPS_OPTIONS"),
+            abi::SHT_MIPS_DWARF => Some("SHT_MIPS_DWARF"),
+            abi::SHT_MIPS_ABIFLAGS => Some("SHT_MIPS_ABIFLAGS"),
+            _ => None,
+        },
+        (abi::EM_CSKY, abi::SHT_CSKY_ATTRIBUTES) => Some("SHT_CSKY_ATTRIBUTES"),
+        (abi::EM_RISCV, abi::SHT_RISCV_ATTRIBUTES) => Some("SHT_RISCV_ATTRIBUTES"),
+        (abi::EM_X86_64, abi::SHT_X86_64_UNWIND) => Some("SHT_X86_64_UNWIND"),
         _ => None,
     }
 }
 
+/// Like [sh_type_to_str], but renders unrecognized types relative to the start of their
+/// reserved range (e.g. `SHT_LOOS+0x1`), or as `sh_type(0x..)` if they're outside of them.
 #[cfg(feature = "alloc")]
-pub fn sh_type_to_string(sh_type: u32) -> String {
-    match sh_type_to_str(sh_type) {
+pub fn sh_type_to_string(sh_type: u32, e_machine: u16) -> String {
+    match sh_type_to_str(sh_type, e_machine) {
         Some(s) => s.to_string(),
-        None => format!("sh_type({sh_type:#x})"),
+        None => match sh_type {
+            abi::SHT_LOOS..=abi::SHT_HIOS => format!("SHT_LOOS+{:#x}", sh_type - abi::SHT_LOOS),
+            abi::SHT_LOPROC..=abi::SHT_HIPROC => {
+                format!("SHT_LOPROC+{:#x}", sh_type - abi::SHT_LOPROC)
+            }
+            abi::SHT_LOUSER..=abi::SHT_HIUSER => {
+                format!("SHT_LOUSER+{:#x}", sh_type - abi::SHT_LOUSER)
+            }
+            _ => format!("sh_type({sh_type:#x})"),
+        },
     }
 }
 
@@ -524,6 +647,7 @@ pub fn p_type_to_str(p_type: u32) -> Option<&'static str> {
         abi::PT_GNU_STACK => Some("PT_GNU_STACK"),
         abi::PT_GNU_RELRO => Some("PT_GNU_RELRO"),
         abi::PT_GNU_PROPERTY => Some("PT_GNU_PROPERTY"),
+        abi::PT_GNU_SFRAME => Some("PT_GNU_SFRAME"),
         _ => None,
     }
 }
@@ -554,7 +678,13 @@ pub fn st_symtype_to_str(st_symtype: u8) -> Option<&'static str> {
 pub fn st_symtype_to_string(st_symtype: u8) -> String {
     match st_symtype_to_str(st_symtype) {
         Some(s) => s.to_string(),
-        None => format!("st_symtype({st_symtype:#x})"),
+        None => match st_symtype {
+            abi::STT_LOOS..=abi::STT_HIOS => format!("STT_LOOS+{}", st_symtype - abi::STT_LOOS),
+            abi::STT_LOPROC..=abi::STT_HIPROC => {
+                format!("STT_LOPROC+{}", st_symtype - abi::STT_LOPROC)
+            }
+            _ => format!("st_symtype({st_symtype:#x})"),
+        },
     }
 }
 
@@ -572,7 +702,13 @@ pub fn st_bind_to_str(st_bind: u8) -> Option<&'static str> {
 pub fn st_bind_to_string(st_bind: u8) -> String {
     match st_bind_to_str(st_bind) {
         Some(s) => s.to_string(),
-        None => format!("st_bind({st_bind:#x})"),
+        None => match st_bind {
+            abi::STB_LOOS..=abi::STB_HIOS => format!("STB_LOOS+{}", st_bind - abi::STB_LOOS),
+            abi::STB_LOPROC..=abi::STB_HIPROC => {
+                format!("STB_LOPROC+{}", st_bind - abi::STB_LOPROC)
+            }
+            _ => format!("st_bind({st_bind:#x})"),
+        },
     }
 }
 
@@ -612,7 +748,104 @@ pub fn note_abi_tag_os_to_str(os: u32) -> Option<&'static str> {
     }
 }
 
-pub fn d_tag_to_str(d_tag: i64) -> Option<&'static str> {
+/// Get the name of the given note type, which depends on the note's owner `name` (with or
+/// without its trailing NUL), and for the process state notes, on the file's `e_type` being
+/// [ET_CORE](abi::ET_CORE).
+pub fn note_type_to_str(n_type: u64, name: &[u8], e_type: u16) -> Option<&'static str> {
+    match crate::note::note_owner(name) {
+        b"CORE" | b"LINUX" if e_type == abi::ET_CORE => core_note_type_to_str(n_type),
+        b"GNU" => match n_type {
+            abi::NT_GNU_ABI_TAG => Some("NT_GNU_ABI_TAG"),
+            abi::NT_GNU_HWCAP => Some("NT_GNU_HWCAP"),
+            abi::NT_GNU_BUILD_ID => Some("NT_GNU_BUILD_ID"),
+            abi::NT_GNU_GOLD_VERSION => Some("NT_GNU_GOLD_VERSION"),
+            abi::NT_GNU_PROPERTY_TYPE_0 => Some("NT_GNU_PROPERTY_TYPE_0"),
+            _ => None,
+        },
+        b"FreeBSD" => match n_type {
+            abi::NT_FREEBSD_ABI_TAG => Some("NT_FREEBSD_ABI_TAG"),
+            abi::NT_FREEBSD_NOINIT_TAG => Some("NT_FREEBSD_NOINIT_TAG"),
+            abi::NT_FREEBSD_ARCH_TAG => Some("NT_FREEBSD_ARCH_TAG"),
+            abi::NT_FREEBSD_FEATURE_CTL => Some("NT_FREEBSD_FEATURE_CTL"),
+            _ => None,
+        },
+        b"NetBSD" => match n_type {
+            abi::NT_NETBSD_IDENT => Some("NT_NETBSD_IDENT"),
+            abi::NT_NETBSD_PAX => Some("NT_NETBSD_PAX"),
+            abi::NT_NETBSD_MARCH => Some("NT_NETBSD_MARCH"),
+            _ => None,
+        },
+        b"OpenBSD" => match n_type {
+            abi::NT_OPENBSD_IDENT => Some("NT_OPENBSD_IDENT"),
+            _ => None,
+        },
+        b"FDO" => match n_type {
+            abi::NT_FDO_PACKAGING_METADATA => Some("NT_FDO_PACKAGING_METADATA"),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+fn core_note_type_to_str(n_type: u64) -> Option<&'static str> {
+    match n_type {
+        abi::NT_PRSTATUS => Some("NT_PRSTATUS"),
+        abi::NT_FPREGSET => Some("NT_FPREGSET"),
+        abi::NT_PRPSINFO => Some("NT_PRPSINFO"),
+        abi::NT_TASKSTRUCT => Some("NT_TASKSTRUCT"),
+        abi::NT_PLATFORM => Some("NT_PLATFORM"),
+        abi::NT_AUXV => Some("NT_AUXV"),
+        abi::NT_GWINDOWS => Some("NT_GWINDOWS"),
+        abi::NT_ASRS => Some("NT_ASRS"),
+        abi::NT_PSTATUS => Some("NT_PSTATUS"),
+        abi::NT_PSINFO => Some("NT_PSINFO"),
+        abi::NT_PRCRED => Some("NT_PRCRED"),
+        abi::NT_UTSNAME => Some("NT_UTSNAME"),
+        abi::NT_LWPSTATUS => Some("NT_LWPSTATUS"),
+        abi::NT_LWPSINFO => Some("NT_LWPSINFO"),
+        abi::NT_PRFPXREG => Some("NT_PRFPXREG"),
+        abi::NT_SIGINFO => Some("NT_SIGINFO"),
+        abi::NT_FILE => Some("NT_FILE"),
+        abi::NT_PRXFPREG => Some("NT_PRXFPREG"),
+        abi::NT_PPC_VMX => Some("NT_PPC_VMX"),
+        abi::NT_PPC_SPE => Some("NT_PPC_SPE"),
+        abi::NT_PPC_VSX => Some("NT_PPC_VSX"),
+        abi::NT_PPC_TAR => Some("NT_PPC_TAR"),
+        abi::NT_PPC_PPR => Some("NT_PPC_PPR"),
+        abi::NT_PPC_DSCR => Some("NT_PPC_DSCR"),
+        abi::NT_PPC_EBB => Some("NT_PPC_EBB"),
+        abi::NT_PPC_PMU => Some("NT_PPC_PMU"),
+        abi::NT_PPC_TM_CGPR => Some("NT_PPC_TM_CGPR"),
+        abi::NT_PPC_TM_CFPR => Some("NT_PPC_TM_CFPR"),
+        abi::NT_PPC_TM_CVMX => Some("NT_PPC_TM_CVMX"),
+        abi::NT_PPC_TM_CVSX => Some("NT_PPC_TM_CVSX"),
+        abi::NT_PPC_TM_SPR => Some("NT_PPC_TM_SPR"),
+        abi::NT_PPC_TM_CTAR => Some("NT_PPC_TM_CTAR"),
+        abi::NT_PPC_TM_CPPR => Some("NT_PPC_TM_CPPR"),
+        abi::NT_PPC_TM_CDSCR => Some("NT_PPC_TM_CDSCR"),
+        abi::NT_PPC_PKEY => Some("NT_PPC_PKEY"),
+        abi::NT_386_TLS => Some("NT_386_TLS"),
+        abi::NT_386_IOPERM => Some("NT_386_IOPERM"),
+        abi::NT_X86_XSTATE => Some("NT_X86_XSTATE"),
+        abi::NT_ARM_VFP => Some("NT_ARM_VFP"),
+        abi::NT_ARM_TLS => Some("NT_ARM_TLS"),
+        abi::NT_ARM_HW_BREAK => Some("NT_ARM_HW_BREAK"),
+        abi::NT_ARM_HW_WATCH => Some("NT_ARM_HW_WATCH"),
+        abi::NT_ARM_SYSTEM_CALL => Some("NT_ARM_SYSTEM_CALL"),
+        abi::NT_ARM_SVE => Some("NT_ARM_SVE"),
+        abi::NT_ARM_PAC_MASK => Some("NT_ARM_PAC_MASK"),
+        abi::NT_ARM_PACA_KEYS => Some("NT_ARM_PACA_KEYS"),
+        abi::NT_ARM_PACG_KEYS => Some("NT_ARM_PACG_KEYS"),
+        abi::NT_ARM_TAGGED_ADDR_CTRL => Some("NT_ARM_TAGGED_ADDR_CTRL"),
+        abi::NT_ARM_PAC_ENABLED_KEYS => Some("NT_ARM_PAC_ENABLED_KEYS"),
+        abi::NT_VMCOREDD => Some("NT_VMCOREDD"),
+        _ => None,
+    }
+}
+
+/// Get the name of the given dynamic tag, including the processor-specific tags that apply
+/// to the given `e_machine`.
+pub fn d_tag_to_str(d_tag: i64, e_machine: u16) -> Option<&'static str> {
     match d_tag {
         abi::DT_NULL => Some("DT_NULL"),
         abi::DT_NEEDED => Some("DT_NEEDED"),
@@ -648,6 +881,9 @@ pub fn d_tag_to_str(d_tag: i64) -> Option<&'static str> {
         abi::DT_PREINIT_ARRAY => Some("DT_PREINIT_ARRAY"),
         abi::DT_PREINIT_ARRAYSZ => Some("DT_PREINIT_ARRAYSZ"),
         abi::DT_SYMTAB_SHNDX => Some("DT_SYMTAB_SHNDX"),
+        abi::DT_RELRSZ => Some("DT_RELRSZ"),
+        abi::DT_RELR => Some("DT_RELR"),
+        abi::DT_RELRENT => Some("DT_RELRENT"),
         abi::DT_GUILE_GC_ROOT => Some("DT_GUILE_GC_ROOT"),
         abi::DT_GUILE_GC_ROOT_SZ => Some("DT_GUILE_GC_ROOT_SZ"),
         abi::DT_GUILE_ENTRY => Some("DT_GUILE_ENTRY"),
@@ -685,8 +921,1015 @@ pub fn d_tag_to_str(d_tag: i64) -> Option<&'static str> {
         abi::DT_VERNEED => Some("DT_VERNEED"),
         abi::DT_VERNEEDNUM => Some("DT_VERNEEDNUM"),
         abi::DT_HIOS => Some("DT_HIOS"),
-        abi::DT_LOPROC => Some("DT_LOPROC"),
-        abi::DT_HIPROC => Some("DT_HIPROC"),
+        abi::DT_LOPROC..=abi::DT_HIPROC => d_tag_to_proc_str(d_tag, e_machine),
+        _ => None,
+    }
+}
+
+fn d_tag_to_proc_str(d_tag: i64, e_machine: u16) -> Option<&'static str> {
+    match (e_machine, d_tag) {
+        (abi::EM_AARCH64, abi::DT_AARCH64_BTI_PLT) => Some("DT_AARCH64_BTI_PLT"),
+        (abi::EM_AARCH64, abi::DT_AARCH64_PAC_PLT) => Some("DT_AARCH64_PAC_PLT"),
+        (abi::EM_AARCH64, abi::DT_AARCH64_VARIANT_PCS) => Some("DT_AARCH64_VARIANT_PCS"),
+        (abi::EM_PPC, abi::DT_PPC_GOT) => Some("DT_PPC_GOT"),
+        (abi::EM_PPC, abi::DT_PPC_OPT) => Some("DT_PPC_OPT"),
+        (abi::EM_PPC64, abi::DT_PPC64_GLINK) => Some("DT_PPC64_GLINK"),
+        (abi::EM_PPC64, abi::DT_PPC64_OPD) => Some("DT_PPC64_OPD"),
+        (abi::EM_PPC64, abi::DT_PPC64_OPDSZ) => Some("DT_PPC64_OPDSZ"),
+        (abi::EM_PPC64, abi::DT_PPC64_OPT) => Some("DT_PPC64_OPT"),
+        (abi::EM_MIPS | abi::EM_MIPS_RS3_LE, _) => match d_tag {
+            abi::DT_MIPS_RLD_VERSION => Some("DT_MIPS_RLD_VERSION"),
+            abi::DT_MIPS_TIME_STAMP => Some("DT_MIPS_TIME_STAMP"),
+            abi::DT_MIPS_ICHECKSUM => Some("DT_MIPS_ICHECKSUM"),
+            abi::DT_MIPS_IVERSION => Some("DT_MIPS_IVERSION"),
+            abi::DT_MIPS_FLAGS => Some("DT_MIPS_FLAGS"),
+            abi::DT_MIPS_BASE_ADDRESS => Some("DT_MIPS_BASE_ADDRESS"),
+            abi::DT_MIPS_MSYM => Some("DT_MIPS_MSYM"),
+            abi::DT_MIPS_CONFLICT => Some("DT_MIPS_CONFLICT"),
+            abi::DT_MIPS_LIBLIST => Some("DT_MIPS_LIBLIST"),
+            abi::DT_MIPS_LOCAL_GOTNO => Some("DT_MIPS_LOCAL_GOTNO"),
+            abi::DT_MIPS_CONFLICTNO => Some("DT_MIPS_CONFLICTNO"),
+            abi::DT_MIPS_LIBLISTNO => Some("DT_MIPS_LIBLISTNO"),
+            abi::DT_MIPS_SYMTABNO => Some("DT_MIPS_SYMTABNO"),
+            abi::DT_MIPS_UNREFEXTNO => Some("DT_MIPS_UNREFEXTNO"),
+            abi::DT_MIPS_GOTSYM => Some("DT_MIPS_GOTSYM"),
+            abi::DT_MIPS_HIPAGENO => Some("DT_MIPS_HIPAGENO"),
+            abi::DT_MIPS_RLD_MAP => Some("DT_MIPS_RLD_MAP"),
+            abi::DT_MIPS_PLTGOT => Some("DT_MIPS_PLTGOT"),
+            abi::DT_MIPS_RWPLT => Some("DT_MIPS_RWPLT"),
+            abi::DT_MIPS_RLD_MAP_REL => Some("DT_MIPS_RLD_MAP_REL"),
+            _ => None,
+        },
+        (_, abi::DT_LOPROC) => Some("DT_LOPROC"),
+        (_, abi::DT_HIPROC) => Some("DT_HIPROC"),
+        _ => None,
+    }
+}
+
+/// Like [d_tag_to_str], but renders unrecognized tags relative to the start of their
+/// reserved range (e.g. `DT_LOPROC+0x1`), or as `d_tag(0x..)` if they're outside of them.
+#[cfg(feature = "alloc")]
+pub fn d_tag_to_string(d_tag: i64, e_machine: u16) -> String {
+    match d_tag_to_str(d_tag, e_machine) {
+        Some(s) => s.to_string(),
+        None => match d_tag {
+            abi::DT_LOOS..=abi::DT_HIOS => format!("DT_LOOS+{:#x}", d_tag - abi::DT_LOOS),
+            abi::DT_LOPROC..=abi::DT_HIPROC => {
+                format!("DT_LOPROC+{:#x}", d_tag - abi::DT_LOPROC)
+            }
+            _ => format!("d_tag({d_tag:#x})"),
+        },
+    }
+}
+
+/// Get the name of the given relocation type for the given `e_machine`, for the machines that
+/// [abi] has relocation types for.
+pub fn r_type_to_str(r_type: u32, e_machine: u16) -> Option<&'static str> {
+    match e_machine {
+        abi::EM_386 => r_type_to_i386_str(r_type),
+        abi::EM_X86_64 => r_type_to_x86_64_str(r_type),
+        abi::EM_AARCH64 => r_type_to_aarch64_str(r_type),
+        abi::EM_ARM => r_type_to_arm_str(r_type),
+        abi::EM_MIPS => r_type_to_mips_str(r_type),
+        abi::EM_PPC => r_type_to_ppc_str(r_type),
+        abi::EM_PPC64 => r_type_to_ppc64_str(r_type),
+        abi::EM_RISCV => r_type_to_riscv_str(r_type),
+        abi::EM_SPARC | abi::EM_SPARC32PLUS | abi::EM_SPARCV9 => r_type_to_sparc_str(r_type),
+        _ => None,
+    }
+}
+
+/// Like [r_type_to_str], but renders unrecognized types as `r_type(0x..)`.
+#[cfg(feature = "alloc")]
+pub fn r_type_to_string(r_type: u32, e_machine: u16) -> String {
+    match r_type_to_str(r_type, e_machine) {
+        Some(s) => s.to_string(),
+        None => format!("r_type({r_type:#x})"),
+    }
+}
+
+fn r_type_to_i386_str(r_type: u32) -> Option<&'static str> {
+    match r_type {
+        abi::R_386_NONE => Some("R_386_NONE"),
+        abi::R_386_32 => Some("R_386_32"),
+        abi::R_386_PC32 => Some("R_386_PC32"),
+        abi::R_386_GOT32 => Some("R_386_GOT32"),
+        abi::R_386_PLT32 => Some("R_386_PLT32"),
+        abi::R_386_COPY => Some("R_386_COPY"),
+        abi::R_386_GLOB_DAT => Some("R_386_GLOB_DAT"),
+        abi::R_386_JMP_SLOT => Some("R_386_JMP_SLOT"),
+        abi::R_386_RELATIVE => Some("R_386_RELATIVE"),
+        abi::R_386_GOTOFF => Some("R_386_GOTOFF"),
+        abi::R_386_GOTPC => Some("R_386_GOTPC"),
+        abi::R_386_32PLT => Some("R_386_32PLT"),
+        abi::R_386_TLS_TPOFF => Some("R_386_TLS_TPOFF"),
+        abi::R_386_TLS_IE => Some("R_386_TLS_IE"),
+        abi::R_386_TLS_GOTIE => Some("R_386_TLS_GOTIE"),
+        abi::R_386_TLS_LE => Some("R_386_TLS_LE"),
+        abi::R_386_TLS_GD => Some("R_386_TLS_GD"),
+        abi::R_386_TLS_LDM => Some("R_386_TLS_LDM"),
+        abi::R_386_16 => Some("R_386_16"),
+        abi::R_386_PC16 => Some("R_386_PC16"),
+        abi::R_386_8 => Some("R_386_8"),
+        abi::R_386_PC8 => Some("R_386_PC8"),
+        abi::R_386_TLS_LDO_32 => Some("R_386_TLS_LDO_32"),
+        abi::R_386_TLS_IE_32 => Some("R_386_TLS_IE_32"),
+        abi::R_386_TLS_LE_32 => Some("R_386_TLS_LE_32"),
+        abi::R_386_TLS_DTPMOD32 => Some("R_386_TLS_DTPMOD32"),
+        abi::R_386_TLS_DTPOFF32 => Some("R_386_TLS_DTPOFF32"),
+        abi::R_386_TLS_TPOFF32 => Some("R_386_TLS_TPOFF32"),
+        abi::R_386_SIZE32 => Some("R_386_SIZE32"),
+        abi::R_386_TLS_GOTDESC => Some("R_386_TLS_GOTDESC"),
+        abi::R_386_TLS_DESC_CALL => Some("R_386_TLS_DESC_CALL"),
+        abi::R_386_TLS_DESC => Some("R_386_TLS_DESC"),
+        abi::R_386_IRELATIVE => Some("R_386_IRELATIVE"),
+        abi::R_386_GOT32X => Some("R_386_GOT32X"),
+        _ => None,
+    }
+}
+
+fn r_type_to_x86_64_str(r_type: u32) -> Option<&'static str> {
+    match r_type {
+        abi::R_X86_64_NONE => Some("R_X86_64_NONE"),
+        abi::R_X86_64_64 => Some("R_X86_64_64"),
+        abi::R_X86_64_PC32 => Some("R_X86_64_PC32"),
+        abi::R_X86_64_GOT32 => Some("R_X86_64_GOT32"),
+        abi::R_X86_64_PLT32 => Some("R_X86_64_PLT32"),
+        abi::R_X86_64_COPY => Some("R_X86_64_COPY"),
+        abi::R_X86_64_GLOB_DAT => Some("R_X86_64_GLOB_DAT"),
+        abi::R_X86_64_JUMP_SLOT => Some("R_X86_64_JUMP_SLOT"),
+        abi::R_X86_64_RELATIVE => Some("R_X86_64_RELATIVE"),
+        abi::R_X86_64_GOTPCREL => Some("R_X86_64_GOTPCREL"),
+        abi::R_X86_64_32 => Some("R_X86_64_32"),
+        abi::R_X86_64_32S => Some("R_X86_64_32S"),
+        abi::R_X86_64_16 => Some("R_X86_64_16"),
+        abi::R_X86_64_PC16 => Some("R_X86_64_PC16"),
+        abi::R_X86_64_8 => Some("R_X86_64_8"),
+        abi::R_X86_64_PC8 => Some("R_X86_64_PC8"),
+        abi::R_X86_64_DTPMOD64 => Some("R_X86_64_DTPMOD64"),
+        abi::R_X86_64_DTPOFF64 => Some("R_X86_64_DTPOFF64"),
+        abi::R_X86_64_TPOFF64 => Some("R_X86_64_TPOFF64"),
+        abi::R_X86_64_TLSGD => Some("R_X86_64_TLSGD"),
+        abi::R_X86_64_TLSLD => Some("R_X86_64_TLSLD"),
+        abi::R_X86_64_DTPOFF32 => Some("R_X86_64_DTPOFF32"),
+        abi::R_X86_64_GOTTPOFF => Some("R_X86_64_GOTTPOFF"),
+        abi::R_X86_64_TPOFF32 => Some("R_X86_64_TPOFF32"),
+        abi::R_X86_64_PC64 => Some("R_X86_64_PC64"),
+        abi::R_X86_64_GOTOFF64 => Some("R_X86_64_GOTOFF64"),
+        abi::R_X86_64_GOTPC32 => Some("R_X86_64_GOTPC32"),
+        abi::R_X86_64_GOT64 => Some("R_X86_64_GOT64"),
+        abi::R_X86_64_GOTPCREL64 => Some("R_X86_64_GOTPCREL64"),
+        abi::R_X86_64_GOTPC64 => Some("R_X86_64_GOTPC64"),
+        abi::R_X86_64_PLTOFF64 => Some("R_X86_64_PLTOFF64"),
+        abi::R_X86_64_SIZE32 => Some("R_X86_64_SIZE32"),
+        abi::R_X86_64_SIZE64 => Some("R_X86_64_SIZE64"),
+        abi::R_X86_64_GOTPC32_TLSDESC => Some("R_X86_64_GOTPC32_TLSDESC"),
+        abi::R_X86_64_TLSDESC_CALL => Some("R_X86_64_TLSDESC_CALL"),
+        abi::R_X86_64_TLSDESC => Some("R_X86_64_TLSDESC"),
+        abi::R_X86_64_IRELATIVE => Some("R_X86_64_IRELATIVE"),
+        abi::R_X86_64_RELATIVE64 => Some("R_X86_64_RELATIVE64"),
+        abi::R_X86_64_GOTPCRELX => Some("R_X86_64_GOTPCRELX"),
+        abi::R_X86_64_REX_GOTPCRELX => Some("R_X86_64_REX_GOTPCRELX"),
+        _ => None,
+    }
+}
+
+fn r_type_to_aarch64_str(r_type: u32) -> Option<&'static str> {
+    match r_type {
+        abi::R_AARCH64_NONE => Some("R_AARCH64_NONE"),
+        abi::R_AARCH64_P32_ABS32 => Some("R_AARCH64_P32_ABS32"),
+        abi::R_AARCH64_P32_COPY => Some("R_AARCH64_P32_COPY"),
+        abi::R_AARCH64_P32_GLOB_DAT => Some("R_AARCH64_P32_GLOB_DAT"),
+        abi::R_AARCH64_P32_JUMP_SLOT => Some("R_AARCH64_P32_JUMP_SLOT"),
+        abi::R_AARCH64_P32_RELATIVE => Some("R_AARCH64_P32_RELATIVE"),
+        abi::R_AARCH64_P32_TLS_DTPMOD => Some("R_AARCH64_P32_TLS_DTPMOD"),
+        abi::R_AARCH64_P32_TLS_DTPREL => Some("R_AARCH64_P32_TLS_DTPREL"),
+        abi::R_AARCH64_P32_TLS_TPREL => Some("R_AARCH64_P32_TLS_TPREL"),
+        abi::R_AARCH64_P32_TLSDESC => Some("R_AARCH64_P32_TLSDESC"),
+        abi::R_AARCH64_P32_IRELATIVE => Some("R_AARCH64_P32_IRELATIVE"),
+        abi::R_AARCH64_ABS64 => Some("R_AARCH64_ABS64"),
+        abi::R_AARCH64_ABS32 => Some("R_AARCH64_ABS32"),
+        abi::R_AARCH64_ABS16 => Some("R_AARCH64_ABS16"),
+        abi::R_AARCH64_PREL64 => Some("R_AARCH64_PREL64"),
+        abi::R_AARCH64_PREL32 => Some("R_AARCH64_PREL32"),
+        abi::R_AARCH64_PREL16 => Some("R_AARCH64_PREL16"),
+        abi::R_AARCH64_MOVW_UABS_G0 => Some("R_AARCH64_MOVW_UABS_G0"),
+        abi::R_AARCH64_MOVW_UABS_G0_NC => Some("R_AARCH64_MOVW_UABS_G0_NC"),
+        abi::R_AARCH64_MOVW_UABS_G1 => Some("R_AARCH64_MOVW_UABS_G1"),
+        abi::R_AARCH64_MOVW_UABS_G1_NC => Some("R_AARCH64_MOVW_UABS_G1_NC"),
+        abi::R_AARCH64_MOVW_UABS_G2 => Some("R_AARCH64_MOVW_UABS_G2"),
+        abi::R_AARCH64_MOVW_UABS_G2_NC => Some("R_AARCH64_MOVW_UABS_G2_NC"),
+        abi::R_AARCH64_MOVW_UABS_G3 => Some("R_AARCH64_MOVW_UABS_G3"),
+        abi::R_AARCH64_MOVW_SABS_G0 => Some("R_AARCH64_MOVW_SABS_G0"),
+        abi::R_AARCH64_MOVW_SABS_G1 => Some("R_AARCH64_MOVW_SABS_G1"),
+        abi::R_AARCH64_MOVW_SABS_G2 => Some("R_AARCH64_MOVW_SABS_G2"),
+        abi::R_AARCH64_LD_PREL_LO19 => Some("R_AARCH64_LD_PREL_LO19"),
+        abi::R_AARCH64_ADR_PREL_LO21 => Some("R_AARCH64_ADR_PREL_LO21"),
+        abi::R_AARCH64_ADR_PREL_PG_HI21 => Some("R_AARCH64_ADR_PREL_PG_HI21"),
+        abi::R_AARCH64_ADR_PREL_PG_HI21_NC => Some("R_AARCH64_ADR_PREL_PG_HI21_NC"),
+        abi::R_AARCH64_ADD_ABS_LO12_NC => Some("R_AARCH64_ADD_ABS_LO12_NC"),
+        abi::R_AARCH64_LDST8_ABS_LO12_NC => Some("R_AARCH64_LDST8_ABS_LO12_NC"),
+        abi::R_AARCH64_TSTBR14 => Some("R_AARCH64_TSTBR14"),
+        abi::R_AARCH64_CONDBR19 => Some("R_AARCH64_CONDBR19"),
+        abi::R_AARCH64_JUMP26 => Some("R_AARCH64_JUMP26"),
+        abi::R_AARCH64_CALL26 => Some("R_AARCH64_CALL26"),
+        abi::R_AARCH64_LDST16_ABS_LO12_NC => Some("R_AARCH64_LDST16_ABS_LO12_NC"),
+        abi::R_AARCH64_LDST32_ABS_LO12_NC => Some("R_AARCH64_LDST32_ABS_LO12_NC"),
+        abi::R_AARCH64_LDST64_ABS_LO12_NC => Some("R_AARCH64_LDST64_ABS_LO12_NC"),
+        abi::R_AARCH64_MOVW_PREL_G0 => Some("R_AARCH64_MOVW_PREL_G0"),
+        abi::R_AARCH64_MOVW_PREL_G0_NC => Some("R_AARCH64_MOVW_PREL_G0_NC"),
+        abi::R_AARCH64_MOVW_PREL_G1 => Some("R_AARCH64_MOVW_PREL_G1"),
+        abi::R_AARCH64_MOVW_PREL_G1_NC => Some("R_AARCH64_MOVW_PREL_G1_NC"),
+        abi::R_AARCH64_MOVW_PREL_G2 => Some("R_AARCH64_MOVW_PREL_G2"),
+        abi::R_AARCH64_MOVW_PREL_G2_NC => Some("R_AARCH64_MOVW_PREL_G2_NC"),
+        abi::R_AARCH64_MOVW_PREL_G3 => Some("R_AARCH64_MOVW_PREL_G3"),
+        abi::R_AARCH64_LDST128_ABS_LO12_NC => Some("R_AARCH64_LDST128_ABS_LO12_NC"),
+        abi::R_AARCH64_MOVW_GOTOFF_G0 => Some("R_AARCH64_MOVW_GOTOFF_G0"),
+        abi::R_AARCH64_MOVW_GOTOFF_G0_NC => Some("R_AARCH64_MOVW_GOTOFF_G0_NC"),
+        abi::R_AARCH64_MOVW_GOTOFF_G1 => Some("R_AARCH64_MOVW_GOTOFF_G1"),
+        abi::R_AARCH64_MOVW_GOTOFF_G1_NC => Some("R_AARCH64_MOVW_GOTOFF_G1_NC"),
+        abi::R_AARCH64_MOVW_GOTOFF_G2 => Some("R_AARCH64_MOVW_GOTOFF_G2"),
+        abi::R_AARCH64_MOVW_GOTOFF_G2_NC => Some("R_AARCH64_MOVW_GOTOFF_G2_NC"),
+        abi::R_AARCH64_MOVW_GOTOFF_G3 => Some("R_AARCH64_MOVW_GOTOFF_G3"),
+        abi::R_AARCH64_GOTREL64 => Some("R_AARCH64_GOTREL64"),
+        abi::R_AARCH64_GOTREL32 => Some("R_AARCH64_GOTREL32"),
+        abi::R_AARCH64_GOT_LD_PREL19 => Some("R_AARCH64_GOT_LD_PREL19"),
+        abi::R_AARCH64_LD64_GOTOFF_LO15 => Some("R_AARCH64_LD64_GOTOFF_LO15"),
+        abi::R_AARCH64_ADR_GOT_PAGE => Some("R_AARCH64_ADR_GOT_PAGE"),
+        abi::R_AARCH64_LD64_GOT_LO12_NC => Some("R_AARCH64_LD64_GOT_LO12_NC"),
+        abi::R_AARCH64_LD64_GOTPAGE_LO15 => Some("R_AARCH64_LD64_GOTPAGE_LO15"),
+        abi::R_AARCH64_TLSGD_ADR_PREL21 => Some("R_AARCH64_TLSGD_ADR_PREL21"),
+        abi::R_AARCH64_TLSGD_ADR_PAGE21 => Some("R_AARCH64_TLSGD_ADR_PAGE21"),
+        abi::R_AARCH64_TLSGD_ADD_LO12_NC => Some("R_AARCH64_TLSGD_ADD_LO12_NC"),
+        abi::R_AARCH64_TLSGD_MOVW_G1 => Some("R_AARCH64_TLSGD_MOVW_G1"),
+        abi::R_AARCH64_TLSGD_MOVW_G0_NC => Some("R_AARCH64_TLSGD_MOVW_G0_NC"),
+        abi::R_AARCH64_TLSLD_ADR_PREL21 => Some("R_AARCH64_TLSLD_ADR_PREL21"),
+        abi::R_AARCH64_TLSLD_ADR_PAGE21 => Some("R_AARCH64_TLSLD_ADR_PAGE21"),
+        abi::R_AARCH64_TLSLD_ADD_LO12_NC => Some("R_AARCH64_TLSLD_ADD_LO12_NC"),
+        abi::R_AARCH64_TLSLD_MOVW_G1 => Some("R_AARCH64_TLSLD_MOVW_G1"),
+        abi::R_AARCH64_TLSLD_MOVW_G0_NC => Some("R_AARCH64_TLSLD_MOVW_G0_NC"),
+        abi::R_AARCH64_TLSLD_LD_PREL19 => Some("R_AARCH64_TLSLD_LD_PREL19"),
+        abi::R_AARCH64_TLSLD_MOVW_DTPREL_G2 => Some("R_AARCH64_TLSLD_MOVW_DTPREL_G2"),
+        abi::R_AARCH64_TLSLD_MOVW_DTPREL_G1 => Some("R_AARCH64_TLSLD_MOVW_DTPREL_G1"),
+        abi::R_AARCH64_TLSLD_MOVW_DTPREL_G1_NC => Some("R_AARCH64_TLSLD_MOVW_DTPREL_G1_NC"),
+        abi::R_AARCH64_TLSLD_MOVW_DTPREL_G0 => Some("R_AARCH64_TLSLD_MOVW_DTPREL_G0"),
+        abi::R_AARCH64_TLSLD_MOVW_DTPREL_G0_NC => Some("R_AARCH64_TLSLD_MOVW_DTPREL_G0_NC"),
+        abi::R_AARCH64_TLSLD_ADD_DTPREL_HI12 => Some("R_AARCH64_TLSLD_ADD_DTPREL_HI12"),
+        abi::R_AARCH64_TLSLD_ADD_DTPREL_LO12 => Some("R_AARCH64_TLSLD_ADD_DTPREL_LO12"),
+        abi::R_AARCH64_TLSLD_ADD_DTPREL_LO12_NC => Some("R_AARCH64_TLSLD_ADD_DTPREL_LO12_NC"),
+        abi::R_AARCH64_TLSLD_LDST8_DTPREL_LO12 => Some("R_AARCH64_TLSLD_LDST8_DTPREL_LO12"),
+        abi::R_AARCH64_TLSLD_LDST8_DTPREL_LO12_NC => Some("R_AARCH64_TLSLD_LDST8_DTPREL_LO12_NC"),
+        abi::R_AARCH64_TLSLD_LDST16_DTPREL_LO12 => Some("R_AARCH64_TLSLD_LDST16_DTPREL_LO12"),
+        abi::R_AARCH64_TLSLD_LDST16_DTPREL_LO12_NC => Some("R_AARCH64_TLSLD_LDST16_DTPREL_LO12_NC"),
+        abi::R_AARCH64_TLSLD_LDST32_DTPREL_LO12 => Some("R_AARCH64_TLSLD_LDST32_DTPREL_LO12"),
+        abi::R_AARCH64_TLSLD_LDST32_DTPREL_LO12_NC => Some("R_AARCH64_TLSLD_LDST32_DTPREL_LO12_NC"),
+        abi::R_AARCH64_TLSLD_LDST64_DTPREL_LO12 => Some("R_AARCH64_TLSLD_LDST64_DTPREL_LO12"),
+        abi::R_AARCH64_TLSLD_LDST64_DTPREL_LO12_NC => Some("R_AARCH64_TLSLD_LDST64_DTPREL_LO12_NC"),
+        abi::R_AARCH64_TLSIE_MOVW_GOTTPREL_G1 => Some("R_AARCH64_TLSIE_MOVW_GOTTPREL_G1"),
+        abi::R_AARCH64_TLSIE_MOVW_GOTTPREL_G0_NC => Some("R_AARCH64_TLSIE_MOVW_GOTTPREL_G0_NC"),
+        abi::R_AARCH64_TLSIE_ADR_GOTTPREL_PAGE21 => Some("R_AARCH64_TLSIE_ADR_GOTTPREL_PAGE21"),
+        abi::R_AARCH64_TLSIE_LD64_GOTTPREL_LO12_NC => Some("R_AARCH64_TLSIE_LD64_GOTTPREL_LO12_NC"),
+        abi::R_AARCH64_TLSIE_LD_GOTTPREL_PREL19 => Some("R_AARCH64_TLSIE_LD_GOTTPREL_PREL19"),
+        abi::R_AARCH64_TLSLE_MOVW_TPREL_G2 => Some("R_AARCH64_TLSLE_MOVW_TPREL_G2"),
+        abi::R_AARCH64_TLSLE_MOVW_TPREL_G1 => Some("R_AARCH64_TLSLE_MOVW_TPREL_G1"),
+        abi::R_AARCH64_TLSLE_MOVW_TPREL_G1_NC => Some("R_AARCH64_TLSLE_MOVW_TPREL_G1_NC"),
+        abi::R_AARCH64_TLSLE_MOVW_TPREL_G0 => Some("R_AARCH64_TLSLE_MOVW_TPREL_G0"),
+        abi::R_AARCH64_TLSLE_MOVW_TPREL_G0_NC => Some("R_AARCH64_TLSLE_MOVW_TPREL_G0_NC"),
+        abi::R_AARCH64_TLSLE_ADD_TPREL_HI12 => Some("R_AARCH64_TLSLE_ADD_TPREL_HI12"),
+        abi::R_AARCH64_TLSLE_ADD_TPREL_LO12 => Some("R_AARCH64_TLSLE_ADD_TPREL_LO12"),
+        abi::R_AARCH64_TLSLE_ADD_TPREL_LO12_NC => Some("R_AARCH64_TLSLE_ADD_TPREL_LO12_NC"),
+        abi::R_AARCH64_TLSLE_LDST8_TPREL_LO12 => Some("R_AARCH64_TLSLE_LDST8_TPREL_LO12"),
+        abi::R_AARCH64_TLSLE_LDST8_TPREL_LO12_NC => Some("R_AARCH64_TLSLE_LDST8_TPREL_LO12_NC"),
+        abi::R_AARCH64_TLSLE_LDST16_TPREL_LO12 => Some("R_AARCH64_TLSLE_LDST16_TPREL_LO12"),
+        abi::R_AARCH64_TLSLE_LDST16_TPREL_LO12_NC => Some("R_AARCH64_TLSLE_LDST16_TPREL_LO12_NC"),
+        abi::R_AARCH64_TLSLE_LDST32_TPREL_LO12 => Some("R_AARCH64_TLSLE_LDST32_TPREL_LO12"),
+        abi::R_AARCH64_TLSLE_LDST32_TPREL_LO12_NC => Some("R_AARCH64_TLSLE_LDST32_TPREL_LO12_NC"),
+        abi::R_AARCH64_TLSLE_LDST64_TPREL_LO12 => Some("R_AARCH64_TLSLE_LDST64_TPREL_LO12"),
+        abi::R_AARCH64_TLSLE_LDST64_TPREL_LO12_NC => Some("R_AARCH64_TLSLE_LDST64_TPREL_LO12_NC"),
+        abi::R_AARCH64_TLSDESC_LD_PREL19 => Some("R_AARCH64_TLSDESC_LD_PREL19"),
+        abi::R_AARCH64_TLSDESC_ADR_PREL21 => Some("R_AARCH64_TLSDESC_ADR_PREL21"),
+        abi::R_AARCH64_TLSDESC_ADR_PAGE21 => Some("R_AARCH64_TLSDESC_ADR_PAGE21"),
+        abi::R_AARCH64_TLSDESC_LD64_LO12 => Some("R_AARCH64_TLSDESC_LD64_LO12"),
+        abi::R_AARCH64_TLSDESC_ADD_LO12 => Some("R_AARCH64_TLSDESC_ADD_LO12"),
+        abi::R_AARCH64_TLSDESC_OFF_G1 => Some("R_AARCH64_TLSDESC_OFF_G1"),
+        abi::R_AARCH64_TLSDESC_OFF_G0_NC => Some("R_AARCH64_TLSDESC_OFF_G0_NC"),
+        abi::R_AARCH64_TLSDESC_LDR => Some("R_AARCH64_TLSDESC_LDR"),
+        abi::R_AARCH64_TLSDESC_ADD => Some("R_AARCH64_TLSDESC_ADD"),
+        abi::R_AARCH64_TLSDESC_CALL => Some("R_AARCH64_TLSDESC_CALL"),
+        abi::R_AARCH64_TLSLE_LDST128_TPREL_LO12 => Some("R_AARCH64_TLSLE_LDST128_TPREL_LO12"),
+        abi::R_AARCH64_TLSLE_LDST128_TPREL_LO12_NC => Some("R_AARCH64_TLSLE_LDST128_TPREL_LO12_NC"),
+        abi::R_AARCH64_TLSLD_LDST128_DTPREL_LO12 => Some("R_AARCH64_TLSLD_LDST128_DTPREL_LO12"),
+        abi::R_AARCH64_TLSLD_LDST128_DTPREL_LO12_NC => {
+            Some("R_AARCH64_TLSLD_LDST128_DTPREL_LO12_NC")
+        }
+        abi::R_AARCH64_COPY => Some("R_AARCH64_COPY"),
+        abi::R_AARCH64_GLOB_DAT => Some("R_AARCH64_GLOB_DAT"),
+        abi::R_AARCH64_JUMP_SLOT => Some("R_AARCH64_JUMP_SLOT"),
+        abi::R_AARCH64_RELATIVE => Some("R_AARCH64_RELATIVE"),
+        abi::R_AARCH64_TLS_DTPMOD => Some("R_AARCH64_TLS_DTPMOD"),
+        abi::R_AARCH64_TLS_DTPREL => Some("R_AARCH64_TLS_DTPREL"),
+        abi::R_AARCH64_TLS_TPREL => Some("R_AARCH64_TLS_TPREL"),
+        abi::R_AARCH64_TLSDESC => Some("R_AARCH64_TLSDESC"),
+        abi::R_AARCH64_IRELATIVE => Some("R_AARCH64_IRELATIVE"),
+        _ => None,
+    }
+}
+
+fn r_type_to_arm_str(r_type: u32) -> Option<&'static str> {
+    match r_type {
+        abi::R_ARM_NONE => Some("R_ARM_NONE"),
+        abi::R_ARM_PC24 => Some("R_ARM_PC24"),
+        abi::R_ARM_ABS32 => Some("R_ARM_ABS32"),
+        abi::R_ARM_REL32 => Some("R_ARM_REL32"),
+        abi::R_ARM_LDR_PC_G0 => Some("R_ARM_LDR_PC_G0"),
+        abi::R_ARM_ABS16 => Some("R_ARM_ABS16"),
+        abi::R_ARM_ABS12 => Some("R_ARM_ABS12"),
+        abi::R_ARM_THM_ABS5 => Some("R_ARM_THM_ABS5"),
+        abi::R_ARM_ABS8 => Some("R_ARM_ABS8"),
+        abi::R_ARM_SBREL32 => Some("R_ARM_SBREL32"),
+        abi::R_ARM_THM_CALL => Some("R_ARM_THM_CALL"),
+        abi::R_ARM_THM_PC8 => Some("R_ARM_THM_PC8"),
+        abi::R_ARM_BREL_ADJ => Some("R_ARM_BREL_ADJ"),
+        abi::R_ARM_TLS_DESC => Some("R_ARM_TLS_DESC"),
+        abi::R_ARM_THM_SWI8 => Some("R_ARM_THM_SWI8"),
+        abi::R_ARM_XPC25 => Some("R_ARM_XPC25"),
+        abi::R_ARM_THM_XPC22 => Some("R_ARM_THM_XPC22"),
+        abi::R_ARM_TLS_DTPMOD32 => Some("R_ARM_TLS_DTPMOD32"),
+        abi::R_ARM_TLS_DTPOFF32 => Some("R_ARM_TLS_DTPOFF32"),
+        abi::R_ARM_TLS_TPOFF32 => Some("R_ARM_TLS_TPOFF32"),
+        abi::R_ARM_COPY => Some("R_ARM_COPY"),
+        abi::R_ARM_GLOB_DAT => Some("R_ARM_GLOB_DAT"),
+        abi::R_ARM_JUMP_SLOT => Some("R_ARM_JUMP_SLOT"),
+        abi::R_ARM_RELATIVE => Some("R_ARM_RELATIVE"),
+        abi::R_ARM_GOTOFF32 => Some("R_ARM_GOTOFF32"),
+        abi::R_ARM_BASE_PREL => Some("R_ARM_BASE_PREL"),
+        abi::R_ARM_BASE_BREL => Some("R_ARM_BASE_BREL"),
+        abi::R_ARM_PLT32 => Some("R_ARM_PLT32"),
+        abi::R_ARM_CALL => Some("R_ARM_CALL"),
+        abi::R_ARM_JUMP24 => Some("R_ARM_JUMP24"),
+        abi::R_ARM_THM_JUMP24 => Some("R_ARM_THM_JUMP24"),
+        abi::R_ARM_BASE_ABS => Some("R_ARM_BASE_ABS"),
+        abi::R_ARM_ALU_PCREL_7_0 => Some("R_ARM_ALU_PCREL_7_0"),
+        abi::R_ARM_ALU_PCREL_15_8 => Some("R_ARM_ALU_PCREL_15_8"),
+        abi::R_ARM_ALU_PCREL_23_15 => Some("R_ARM_ALU_PCREL_23_15"),
+        abi::R_ARM_LDR_SBREL_11_0 => Some("R_ARM_LDR_SBREL_11_0"),
+        abi::R_ARM_ALU_SBREL_19_12 => Some("R_ARM_ALU_SBREL_19_12"),
+        abi::R_ARM_ALU_SBREL_27_20 => Some("R_ARM_ALU_SBREL_27_20"),
+        abi::R_ARM_TARGET1 => Some("R_ARM_TARGET1"),
+        abi::R_ARM_SBREL31 => Some("R_ARM_SBREL31"),
+        abi::R_ARM_V4BX => Some("R_ARM_V4BX"),
+        abi::R_ARM_TARGET2 => Some("R_ARM_TARGET2"),
+        abi::R_ARM_PREL31 => Some("R_ARM_PREL31"),
+        abi::R_ARM_MOVW_ABS_NC => Some("R_ARM_MOVW_ABS_NC"),
+        abi::R_ARM_MOVT_ABS => Some("R_ARM_MOVT_ABS"),
+        abi::R_ARM_MOVW_PREL_NC => Some("R_ARM_MOVW_PREL_NC"),
+        abi::R_ARM_MOVT_PREL => Some("R_ARM_MOVT_PREL"),
+        abi::R_ARM_THM_MOVW_ABS_NC => Some("R_ARM_THM_MOVW_ABS_NC"),
+        abi::R_ARM_THM_MOVT_ABS => Some("R_ARM_THM_MOVT_ABS"),
+        abi::R_ARM_THM_MOVW_PREL_NC => Some("R_ARM_THM_MOVW_PREL_NC"),
+        abi::R_ARM_THM_MOVT_PREL => Some("R_ARM_THM_MOVT_PREL"),
+        abi::R_ARM_THM_JUMP19 => Some("R_ARM_THM_JUMP19"),
+        abi::R_ARM_THM_JUMP6 => Some("R_ARM_THM_JUMP6"),
+        abi::R_ARM_THM_ALU_PREL_11_0 => Some("R_ARM_THM_ALU_PREL_11_0"),
+        abi::R_ARM_THM_PC12 => Some("R_ARM_THM_PC12"),
+        abi::R_ARM_ABS32_NOI => Some("R_ARM_ABS32_NOI"),
+        abi::R_ARM_REL32_NOI => Some("R_ARM_REL32_NOI"),
+        abi::R_ARM_ALU_PC_G0_NC => Some("R_ARM_ALU_PC_G0_NC"),
+        abi::R_ARM_ALU_PC_G0 => Some("R_ARM_ALU_PC_G0"),
+        abi::R_ARM_ALU_PC_G1_NC => Some("R_ARM_ALU_PC_G1_NC"),
+        abi::R_ARM_ALU_PC_G1 => Some("R_ARM_ALU_PC_G1"),
+        abi::R_ARM_ALU_PC_G2 => Some("R_ARM_ALU_PC_G2"),
+        abi::R_ARM_LDR_PC_G1 => Some("R_ARM_LDR_PC_G1"),
+        abi::R_ARM_LDR_PC_G2 => Some("R_ARM_LDR_PC_G2"),
+        abi::R_ARM_LDRS_PC_G0 => Some("R_ARM_LDRS_PC_G0"),
+        abi::R_ARM_LDRS_PC_G1 => Some("R_ARM_LDRS_PC_G1"),
+        abi::R_ARM_LDRS_PC_G2 => Some("R_ARM_LDRS_PC_G2"),
+        abi::R_ARM_LDC_PC_G0 => Some("R_ARM_LDC_PC_G0"),
+        abi::R_ARM_LDC_PC_G1 => Some("R_ARM_LDC_PC_G1"),
+        abi::R_ARM_LDC_PC_G2 => Some("R_ARM_LDC_PC_G2"),
+        abi::R_ARM_ALU_SB_G0_NC => Some("R_ARM_ALU_SB_G0_NC"),
+        abi::R_ARM_ALU_SB_G0 => Some("R_ARM_ALU_SB_G0"),
+        abi::R_ARM_ALU_SB_G1_NC => Some("R_ARM_ALU_SB_G1_NC"),
+        abi::R_ARM_ALU_SB_G1 => Some("R_ARM_ALU_SB_G1"),
+        abi::R_ARM_ALU_SB_G2 => Some("R_ARM_ALU_SB_G2"),
+        abi::R_ARM_LDR_SB_G0 => Some("R_ARM_LDR_SB_G0"),
+        abi::R_ARM_LDR_SB_G1 => Some("R_ARM_LDR_SB_G1"),
+        abi::R_ARM_LDR_SB_G2 => Some("R_ARM_LDR_SB_G2"),
+        abi::R_ARM_LDRS_SB_G0 => Some("R_ARM_LDRS_SB_G0"),
+        abi::R_ARM_LDRS_SB_G1 => Some("R_ARM_LDRS_SB_G1"),
+        abi::R_ARM_LDRS_SB_G2 => Some("R_ARM_LDRS_SB_G2"),
+        abi::R_ARM_LDC_SB_G0 => Some("R_ARM_LDC_SB_G0"),
+        abi::R_ARM_LDC_SB_G1 => Some("R_ARM_LDC_SB_G1"),
+        abi::R_ARM_LDC_SB_G2 => Some("R_ARM_LDC_SB_G2"),
+        abi::R_ARM_MOVW_BREL_NC => Some("R_ARM_MOVW_BREL_NC"),
+        abi::R_ARM_MOVT_BREL => Some("R_ARM_MOVT_BREL"),
+        abi::R_ARM_MOVW_BREL => Some("R_ARM_MOVW_BREL"),
+        abi::R_ARM_THM_MOVW_BREL_NC => Some("R_ARM_THM_MOVW_BREL_NC"),
+        abi::R_ARM_THM_MOVT_BREL => Some("R_ARM_THM_MOVT_BREL"),
+        abi::R_ARM_THM_MOVW_BREL => Some("R_ARM_THM_MOVW_BREL"),
+        abi::R_ARM_TLS_GOTDESC => Some("R_ARM_TLS_GOTDESC"),
+        abi::R_ARM_TLS_CALL => Some("R_ARM_TLS_CALL"),
+        abi::R_ARM_TLS_DESCSEQ => Some("R_ARM_TLS_DESCSEQ"),
+        abi::R_ARM_THM_TLS_CALL => Some("R_ARM_THM_TLS_CALL"),
+        abi::R_ARM_PLT32_ABS => Some("R_ARM_PLT32_ABS"),
+        abi::R_ARM_GOT_ABS => Some("R_ARM_GOT_ABS"),
+        abi::R_ARM_GOT_PREL => Some("R_ARM_GOT_PREL"),
+        abi::R_ARM_GOT_BREL12 => Some("R_ARM_GOT_BREL12"),
+        abi::R_ARM_GOTOFF12 => Some("R_ARM_GOTOFF12"),
+        abi::R_ARM_GOTRELAX => Some("R_ARM_GOTRELAX"),
+        abi::R_ARM_GNU_VTENTRY => Some("R_ARM_GNU_VTENTRY"),
+        abi::R_ARM_GNU_VTINHERIT => Some("R_ARM_GNU_VTINHERIT"),
+        abi::R_ARM_THM_JUMP11 => Some("R_ARM_THM_JUMP11"),
+        abi::R_ARM_THM_JUMP8 => Some("R_ARM_THM_JUMP8"),
+        abi::R_ARM_TLS_GD32 => Some("R_ARM_TLS_GD32"),
+        abi::R_ARM_TLS_LDM32 => Some("R_ARM_TLS_LDM32"),
+        abi::R_ARM_TLS_LDO32 => Some("R_ARM_TLS_LDO32"),
+        abi::R_ARM_TLS_IE32 => Some("R_ARM_TLS_IE32"),
+        abi::R_ARM_TLS_LE32 => Some("R_ARM_TLS_LE32"),
+        abi::R_ARM_TLS_LDO12 => Some("R_ARM_TLS_LDO12"),
+        abi::R_ARM_TLS_LE12 => Some("R_ARM_TLS_LE12"),
+        abi::R_ARM_TLS_IE12GP => Some("R_ARM_TLS_IE12GP"),
+        abi::R_ARM_ME_TOO => Some("R_ARM_ME_TOO"),
+        abi::R_ARM_THM_TLS_DESCSEQ16 => Some("R_ARM_THM_TLS_DESCSEQ16"),
+        abi::R_ARM_THM_TLS_DESCSEQ32 => Some("R_ARM_THM_TLS_DESCSEQ32"),
+        abi::R_ARM_THM_GOT_BREL12 => Some("R_ARM_THM_GOT_BREL12"),
+        abi::R_ARM_THM_ALU_ABS_G0_NC => Some("R_ARM_THM_ALU_ABS_G0_NC"),
+        abi::R_ARM_THM_ALU_ABS_G1_NC => Some("R_ARM_THM_ALU_ABS_G1_NC"),
+        abi::R_ARM_THM_ALU_ABS_G2_NC => Some("R_ARM_THM_ALU_ABS_G2_NC"),
+        abi::R_ARM_THM_ALU_ABS_G3 => Some("R_ARM_THM_ALU_ABS_G3"),
+        abi::R_ARM_THM_BF16 => Some("R_ARM_THM_BF16"),
+        abi::R_ARM_THM_BF12 => Some("R_ARM_THM_BF12"),
+        abi::R_ARM_THM_BF18 => Some("R_ARM_THM_BF18"),
+        abi::R_ARM_IRELATIVE => Some("R_ARM_IRELATIVE"),
+        _ => None,
+    }
+}
+
+fn r_type_to_mips_str(r_type: u32) -> Option<&'static str> {
+    match r_type {
+        abi::R_MIPS_NONE => Some("R_MIPS_NONE"),
+        abi::R_MIPS_16 => Some("R_MIPS_16"),
+        abi::R_MIPS_32 => Some("R_MIPS_32"),
+        abi::R_MIPS_REL32 => Some("R_MIPS_REL32"),
+        abi::R_MIPS_26 => Some("R_MIPS_26"),
+        abi::R_MIPS_HI16 => Some("R_MIPS_HI16"),
+        abi::R_MIPS_LO16 => Some("R_MIPS_LO16"),
+        abi::R_MIPS_64 => Some("R_MIPS_64"),
+        abi::R_MIPS_SUB => Some("R_MIPS_SUB"),
+        abi::R_MIPS_JUMP_SLOT => Some("R_MIPS_JUMP_SLOT"),
+        _ => None,
+    }
+}
+
+fn r_type_to_ppc_str(r_type: u32) -> Option<&'static str> {
+    match r_type {
+        abi::R_PPC_NONE => Some("R_PPC_NONE"),
+        abi::R_PPC_ADDR32 => Some("R_PPC_ADDR32"),
+        abi::R_PPC_ADDR24 => Some("R_PPC_ADDR24"),
+        abi::R_PPC_ADDR16 => Some("R_PPC_ADDR16"),
+        abi::R_PPC_ADDR16_LO => Some("R_PPC_ADDR16_LO"),
+        abi::R_PPC_ADDR16_HI => Some("R_PPC_ADDR16_HI"),
+        abi::R_PPC_ADDR16_HA => Some("R_PPC_ADDR16_HA"),
+        abi::R_PPC_ADDR14 => Some("R_PPC_ADDR14"),
+        abi::R_PPC_ADDR14_BRTAKEN => Some("R_PPC_ADDR14_BRTAKEN"),
+        abi::R_PPC_ADDR14_BRNTAKEN => Some("R_PPC_ADDR14_BRNTAKEN"),
+        abi::R_PPC_REL24 => Some("R_PPC_REL24"),
+        abi::R_PPC_REL14 => Some("R_PPC_REL14"),
+        abi::R_PPC_REL14_BRTAKEN => Some("R_PPC_REL14_BRTAKEN"),
+        abi::R_PPC_REL14_BRNTAKEN => Some("R_PPC_REL14_BRNTAKEN"),
+        abi::R_PPC_GOT16 => Some("R_PPC_GOT16"),
+        abi::R_PPC_GOT16_LO => Some("R_PPC_GOT16_LO"),
+        abi::R_PPC_GOT16_HI => Some("R_PPC_GOT16_HI"),
+        abi::R_PPC_GOT16_HA => Some("R_PPC_GOT16_HA"),
+        abi::R_PPC_PLTREL24 => Some("R_PPC_PLTREL24"),
+        abi::R_PPC_COPY => Some("R_PPC_COPY"),
+        abi::R_PPC_GLOB_DAT => Some("R_PPC_GLOB_DAT"),
+        abi::R_PPC_JMP_SLOT => Some("R_PPC_JMP_SLOT"),
+        abi::R_PPC_RELATIVE => Some("R_PPC_RELATIVE"),
+        abi::R_PPC_LOCAL24PC => Some("R_PPC_LOCAL24PC"),
+        abi::R_PPC_UADDR32 => Some("R_PPC_UADDR32"),
+        abi::R_PPC_UADDR16 => Some("R_PPC_UADDR16"),
+        abi::R_PPC_REL32 => Some("R_PPC_REL32"),
+        abi::R_PPC_PLT32 => Some("R_PPC_PLT32"),
+        abi::R_PPC_PLTREL32 => Some("R_PPC_PLTREL32"),
+        abi::R_PPC_PLT16_LO => Some("R_PPC_PLT16_LO"),
+        abi::R_PPC_PLT16_HI => Some("R_PPC_PLT16_HI"),
+        abi::R_PPC_PLT16_HA => Some("R_PPC_PLT16_HA"),
+        abi::R_PPC_SDAREL16 => Some("R_PPC_SDAREL16"),
+        abi::R_PPC_SECTOFF => Some("R_PPC_SECTOFF"),
+        abi::R_PPC_SECTOFF_LO => Some("R_PPC_SECTOFF_LO"),
+        abi::R_PPC_SECTOFF_HI => Some("R_PPC_SECTOFF_HI"),
+        abi::R_PPC_SECTOFF_HA => Some("R_PPC_SECTOFF_HA"),
+        abi::R_PPC_TLS => Some("R_PPC_TLS"),
+        abi::R_PPC_DTPMOD32 => Some("R_PPC_DTPMOD32"),
+        abi::R_PPC_TPREL16 => Some("R_PPC_TPREL16"),
+        abi::R_PPC_TPREL16_LO => Some("R_PPC_TPREL16_LO"),
+        abi::R_PPC_TPREL16_HI => Some("R_PPC_TPREL16_HI"),
+        abi::R_PPC_TPREL16_HA => Some("R_PPC_TPREL16_HA"),
+        abi::R_PPC_TPREL32 => Some("R_PPC_TPREL32"),
+        abi::R_PPC_DTPREL16 => Some("R_PPC_DTPREL16"),
+        abi::R_PPC_DTPREL16_LO => Some("R_PPC_DTPREL16_LO"),
+        abi::R_PPC_DTPREL16_HI => Some("R_PPC_DTPREL16_HI"),
+        abi::R_PPC_DTPREL16_HA => Some("R_PPC_DTPREL16_HA"),
+        abi::R_PPC_DTPREL32 => Some("R_PPC_DTPREL32"),
+        abi::R_PPC_GOT_TLSGD16 => Some("R_PPC_GOT_TLSGD16"),
+        abi::R_PPC_GOT_TLSGD16_LO => Some("R_PPC_GOT_TLSGD16_LO"),
+        abi::R_PPC_GOT_TLSGD16_HI => Some("R_PPC_GOT_TLSGD16_HI"),
+        abi::R_PPC_GOT_TLSGD16_HA => Some("R_PPC_GOT_TLSGD16_HA"),
+        abi::R_PPC_GOT_TLSLD16 => Some("R_PPC_GOT_TLSLD16"),
+        abi::R_PPC_GOT_TLSLD16_LO => Some("R_PPC_GOT_TLSLD16_LO"),
+        abi::R_PPC_GOT_TLSLD16_HI => Some("R_PPC_GOT_TLSLD16_HI"),
+        abi::R_PPC_GOT_TLSLD16_HA => Some("R_PPC_GOT_TLSLD16_HA"),
+        abi::R_PPC_GOT_TPREL16 => Some("R_PPC_GOT_TPREL16"),
+        abi::R_PPC_GOT_TPREL16_LO => Some("R_PPC_GOT_TPREL16_LO"),
+        abi::R_PPC_GOT_TPREL16_HI => Some("R_PPC_GOT_TPREL16_HI"),
+        abi::R_PPC_GOT_TPREL16_HA => Some("R_PPC_GOT_TPREL16_HA"),
+        abi::R_PPC_GOT_DTPREL16 => Some("R_PPC_GOT_DTPREL16"),
+        abi::R_PPC_GOT_DTPREL16_LO => Some("R_PPC_GOT_DTPREL16_LO"),
+        abi::R_PPC_GOT_DTPREL16_HI => Some("R_PPC_GOT_DTPREL16_HI"),
+        abi::R_PPC_GOT_DTPREL16_HA => Some("R_PPC_GOT_DTPREL16_HA"),
+        abi::R_PPC_TLSGD => Some("R_PPC_TLSGD"),
+        abi::R_PPC_TLSLD => Some("R_PPC_TLSLD"),
+        abi::R_PPC_EMB_NADDR32 => Some("R_PPC_EMB_NADDR32"),
+        abi::R_PPC_EMB_NADDR16 => Some("R_PPC_EMB_NADDR16"),
+        abi::R_PPC_EMB_NADDR16_LO => Some("R_PPC_EMB_NADDR16_LO"),
+        abi::R_PPC_EMB_NADDR16_HI => Some("R_PPC_EMB_NADDR16_HI"),
+        abi::R_PPC_EMB_NADDR16_HA => Some("R_PPC_EMB_NADDR16_HA"),
+        abi::R_PPC_EMB_SDAI16 => Some("R_PPC_EMB_SDAI16"),
+        abi::R_PPC_EMB_SDA2I16 => Some("R_PPC_EMB_SDA2I16"),
+        abi::R_PPC_EMB_SDA2REL => Some("R_PPC_EMB_SDA2REL"),
+        abi::R_PPC_EMB_SDA21 => Some("R_PPC_EMB_SDA21"),
+        abi::R_PPC_EMB_MRKREF => Some("R_PPC_EMB_MRKREF"),
+        abi::R_PPC_EMB_RELSEC16 => Some("R_PPC_EMB_RELSEC16"),
+        abi::R_PPC_EMB_RELST_LO => Some("R_PPC_EMB_RELST_LO"),
+        abi::R_PPC_EMB_RELST_HI => Some("R_PPC_EMB_RELST_HI"),
+        abi::R_PPC_EMB_RELST_HA => Some("R_PPC_EMB_RELST_HA"),
+        abi::R_PPC_EMB_BIT_FLD => Some("R_PPC_EMB_BIT_FLD"),
+        abi::R_PPC_EMB_RELSDA => Some("R_PPC_EMB_RELSDA"),
+        abi::R_PPC_DIAB_SDA21_LO => Some("R_PPC_DIAB_SDA21_LO"),
+        abi::R_PPC_DIAB_SDA21_HI => Some("R_PPC_DIAB_SDA21_HI"),
+        abi::R_PPC_DIAB_SDA21_HA => Some("R_PPC_DIAB_SDA21_HA"),
+        abi::R_PPC_DIAB_RELSDA_LO => Some("R_PPC_DIAB_RELSDA_LO"),
+        abi::R_PPC_DIAB_RELSDA_HI => Some("R_PPC_DIAB_RELSDA_HI"),
+        abi::R_PPC_DIAB_RELSDA_HA => Some("R_PPC_DIAB_RELSDA_HA"),
+        abi::R_PPC_IRELATIVE => Some("R_PPC_IRELATIVE"),
+        abi::R_PPC_REL16 => Some("R_PPC_REL16"),
+        abi::R_PPC_REL16_LO => Some("R_PPC_REL16_LO"),
+        abi::R_PPC_REL16_HI => Some("R_PPC_REL16_HI"),
+        abi::R_PPC_REL16_HA => Some("R_PPC_REL16_HA"),
+        abi::R_PPC_TOC16 => Some("R_PPC_TOC16"),
         _ => None,
     }
 }
+
+fn r_type_to_ppc64_str(r_type: u32) -> Option<&'static str> {
+    match r_type {
+        abi::R_PPC64_NONE => Some("R_PPC64_NONE"),
+        abi::R_PPC64_ADDR32 => Some("R_PPC64_ADDR32"),
+        abi::R_PPC64_ADDR24 => Some("R_PPC64_ADDR24"),
+        abi::R_PPC64_ADDR16 => Some("R_PPC64_ADDR16"),
+        abi::R_PPC64_ADDR16_LO => Some("R_PPC64_ADDR16_LO"),
+        abi::R_PPC64_ADDR16_HI => Some("R_PPC64_ADDR16_HI"),
+        abi::R_PPC64_ADDR16_HA => Some("R_PPC64_ADDR16_HA"),
+        abi::R_PPC64_ADDR14 => Some("R_PPC64_ADDR14"),
+        abi::R_PPC64_ADDR14_BRTAKEN => Some("R_PPC64_ADDR14_BRTAKEN"),
+        abi::R_PPC64_ADDR14_BRNTAKEN => Some("R_PPC64_ADDR14_BRNTAKEN"),
+        abi::R_PPC64_REL24 => Some("R_PPC64_REL24"),
+        abi::R_PPC64_REL14 => Some("R_PPC64_REL14"),
+        abi::R_PPC64_REL14_BRTAKEN => Some("R_PPC64_REL14_BRTAKEN"),
+        abi::R_PPC64_REL14_BRNTAKEN => Some("R_PPC64_REL14_BRNTAKEN"),
+        abi::R_PPC64_GOT16 => Some("R_PPC64_GOT16"),
+        abi::R_PPC64_GOT16_LO => Some("R_PPC64_GOT16_LO"),
+        abi::R_PPC64_GOT16_HI => Some("R_PPC64_GOT16_HI"),
+        abi::R_PPC64_GOT16_HA => Some("R_PPC64_GOT16_HA"),
+        abi::R_PPC64_COPY => Some("R_PPC64_COPY"),
+        abi::R_PPC64_GLOB_DAT => Some("R_PPC64_GLOB_DAT"),
+        abi::R_PPC64_JMP_SLOT => Some("R_PPC64_JMP_SLOT"),
+        abi::R_PPC64_RELATIVE => Some("R_PPC64_RELATIVE"),
+        abi::R_PPC64_UADDR32 => Some("R_PPC64_UADDR32"),
+        abi::R_PPC64_UADDR16 => Some("R_PPC64_UADDR16"),
+        abi::R_PPC64_REL32 => Some("R_PPC64_REL32"),
+        abi::R_PPC64_PLT32 => Some("R_PPC64_PLT32"),
+        abi::R_PPC64_PLTREL32 => Some("R_PPC64_PLTREL32"),
+        abi::R_PPC64_PLT16_LO => Some("R_PPC64_PLT16_LO"),
+        abi::R_PPC64_PLT16_HI => Some("R_PPC64_PLT16_HI"),
+        abi::R_PPC64_PLT16_HA => Some("R_PPC64_PLT16_HA"),
+        abi::R_PPC64_SECTOFF => Some("R_PPC64_SECTOFF"),
+        abi::R_PPC64_SECTOFF_LO => Some("R_PPC64_SECTOFF_LO"),
+        abi::R_PPC64_SECTOFF_HI => Some("R_PPC64_SECTOFF_HI"),
+        abi::R_PPC64_SECTOFF_HA => Some("R_PPC64_SECTOFF_HA"),
+        abi::R_PPC64_ADDR30 => Some("R_PPC64_ADDR30"),
+        abi::R_PPC64_ADDR64 => Some("R_PPC64_ADDR64"),
+        abi::R_PPC64_ADDR16_HIGHER => Some("R_PPC64_ADDR16_HIGHER"),
+        abi::R_PPC64_ADDR16_HIGHERA => Some("R_PPC64_ADDR16_HIGHERA"),
+        abi::R_PPC64_ADDR16_HIGHEST => Some("R_PPC64_ADDR16_HIGHEST"),
+        abi::R_PPC64_ADDR16_HIGHESTA => Some("R_PPC64_ADDR16_HIGHESTA"),
+        abi::R_PPC64_UADDR64 => Some("R_PPC64_UADDR64"),
+        abi::R_PPC64_REL64 => Some("R_PPC64_REL64"),
+        abi::R_PPC64_PLT64 => Some("R_PPC64_PLT64"),
+        abi::R_PPC64_PLTREL64 => Some("R_PPC64_PLTREL64"),
+        abi::R_PPC64_TOC16 => Some("R_PPC64_TOC16"),
+        abi::R_PPC64_TOC16_LO => Some("R_PPC64_TOC16_LO"),
+        abi::R_PPC64_TOC16_HI => Some("R_PPC64_TOC16_HI"),
+        abi::R_PPC64_TOC16_HA => Some("R_PPC64_TOC16_HA"),
+        abi::R_PPC64_TOC => Some("R_PPC64_TOC"),
+        abi::R_PPC64_PLTGOT16 => Some("R_PPC64_PLTGOT16"),
+        abi::R_PPC64_PLTGOT16_LO => Some("R_PPC64_PLTGOT16_LO"),
+        abi::R_PPC64_PLTGOT16_HI => Some("R_PPC64_PLTGOT16_HI"),
+        abi::R_PPC64_PLTGOT16_HA => Some("R_PPC64_PLTGOT16_HA"),
+        abi::R_PPC64_ADDR16_DS => Some("R_PPC64_ADDR16_DS"),
+        abi::R_PPC64_ADDR16_LO_DS => Some("R_PPC64_ADDR16_LO_DS"),
+        abi::R_PPC64_GOT16_DS => Some("R_PPC64_GOT16_DS"),
+        abi::R_PPC64_GOT16_LO_DS => Some("R_PPC64_GOT16_LO_DS"),
+        abi::R_PPC64_PLT16_LO_DS => Some("R_PPC64_PLT16_LO_DS"),
+        abi::R_PPC64_SECTOFF_DS => Some("R_PPC64_SECTOFF_DS"),
+        abi::R_PPC64_SECTOFF_LO_DS => Some("R_PPC64_SECTOFF_LO_DS"),
+        abi::R_PPC64_TOC16_DS => Some("R_PPC64_TOC16_DS"),
+        abi::R_PPC64_TOC16_LO_DS => Some("R_PPC64_TOC16_LO_DS"),
+        abi::R_PPC64_PLTGOT16_DS => Some("R_PPC64_PLTGOT16_DS"),
+        abi::R_PPC64_PLTGOT16_LO_DS => Some("R_PPC64_PLTGOT16_LO_DS"),
+        abi::R_PPC64_TLS => Some("R_PPC64_TLS"),
+        abi::R_PPC64_DTPMOD64 => Some("R_PPC64_DTPMOD64"),
+        abi::R_PPC64_TPREL16 => Some("R_PPC64_TPREL16"),
+        abi::R_PPC64_TPREL16_HI => Some("R_PPC64_TPREL16_HI"),
+        abi::R_PPC64_TPREL16_HA => Some("R_PPC64_TPREL16_HA"),
+        abi::R_PPC64_TPREL64 => Some("R_PPC64_TPREL64"),
+        abi::R_PPC64_DTPREL16 => Some("R_PPC64_DTPREL16"),
+        abi::R_PPC64_DTPREL16_LO => Some("R_PPC64_DTPREL16_LO"),
+        abi::R_PPC64_DTPREL16_HI => Some("R_PPC64_DTPREL16_HI"),
+        abi::R_PPC64_DTPREL16_HA => Some("R_PPC64_DTPREL16_HA"),
+        abi::R_PPC64_DTPREL64 => Some("R_PPC64_DTPREL64"),
+        abi::R_PPC64_GOT_TLSGD16 => Some("R_PPC64_GOT_TLSGD16"),
+        abi::R_PPC64_GOT_TLSGD16_LO => Some("R_PPC64_GOT_TLSGD16_LO"),
+        abi::R_PPC64_GOT_TLSGD16_HI => Some("R_PPC64_GOT_TLSGD16_HI"),
+        abi::R_PPC64_GOT_TLSGD16_HA => Some("R_PPC64_GOT_TLSGD16_HA"),
+        abi::R_PPC64_GOT_TLSLD16 => Some("R_PPC64_GOT_TLSLD16"),
+        abi::R_PPC64_GOT_TLSLD16_LO => Some("R_PPC64_GOT_TLSLD16_LO"),
+        abi::R_PPC64_GOT_TLSLD16_HI => Some("R_PPC64_GOT_TLSLD16_HI"),
+        abi::R_PPC64_GOT_TLSLD16_HA => Some("R_PPC64_GOT_TLSLD16_HA"),
+        abi::R_PPC64_GOT_TPREL16_DS => Some("R_PPC64_GOT_TPREL16_DS"),
+        abi::R_PPC64_GOT_TPREL16_LO_DS => Some("R_PPC64_GOT_TPREL16_LO_DS"),
+        abi::R_PPC64_GOT_TPREL16_HI => Some("R_PPC64_GOT_TPREL16_HI"),
+        abi::R_PPC64_GOT_TPREL16_HA => Some("R_PPC64_GOT_TPREL16_HA"),
+        abi::R_PPC64_GOT_DTPREL16_DS => Some("R_PPC64_GOT_DTPREL16_DS"),
+        abi::R_PPC64_GOT_DTPREL16_LO_DS => Some("R_PPC64_GOT_DTPREL16_LO_DS"),
+        abi::R_PPC64_GOT_DTPREL16_HI => Some("R_PPC64_GOT_DTPREL16_HI"),
+        abi::R_PPC64_GOT_DTPREL16_HA => Some("R_PPC64_GOT_DTPREL16_HA"),
+        abi::R_PPC64_TPREL16_DS => Some("R_PPC64_TPREL16_DS"),
+        abi::R_PPC64_TPREL16_LO_DS => Some("R_PPC64_TPREL16_LO_DS"),
+        abi::R_PPC64_TPREL16_HIGHER => Some("R_PPC64_TPREL16_HIGHER"),
+        abi::R_PPC64_TPREL16_HIGHERA => Some("R_PPC64_TPREL16_HIGHERA"),
+        abi::R_PPC64_TPREL16_HIGHEST => Some("R_PPC64_TPREL16_HIGHEST"),
+        abi::R_PPC64_TPREL16_HIGHESTA => Some("R_PPC64_TPREL16_HIGHESTA"),
+        abi::R_PPC64_DTPREL16_DS => Some("R_PPC64_DTPREL16_DS"),
+        abi::R_PPC64_DTPREL16_LO_DS => Some("R_PPC64_DTPREL16_LO_DS"),
+        abi::R_PPC64_DTPREL16_HIGHER => Some("R_PPC64_DTPREL16_HIGHER"),
+        abi::R_PPC64_DTPREL16_HIGHERA => Some("R_PPC64_DTPREL16_HIGHERA"),
+        abi::R_PPC64_DTPREL16_HIGHEST => Some("R_PPC64_DTPREL16_HIGHEST"),
+        abi::R_PPC64_DTPREL16_HIGHESTA => Some("R_PPC64_DTPREL16_HIGHESTA"),
+        abi::R_PPC64_TLSGD => Some("R_PPC64_TLSGD"),
+        abi::R_PPC64_TLSLD => Some("R_PPC64_TLSLD"),
+        abi::R_PPC64_TOCSAVE => Some("R_PPC64_TOCSAVE"),
+        abi::R_PPC64_ADDR16_HIGH => Some("R_PPC64_ADDR16_HIGH"),
+        abi::R_PPC64_ADDR16_HIGHA => Some("R_PPC64_ADDR16_HIGHA"),
+        abi::R_PPC64_TPREL16_HIGH => Some("R_PPC64_TPREL16_HIGH"),
+        abi::R_PPC64_TPREL16_HIGHA => Some("R_PPC64_TPREL16_HIGHA"),
+        abi::R_PPC64_DTPREL16_HIGH => Some("R_PPC64_DTPREL16_HIGH"),
+        abi::R_PPC64_DTPREL16_HIGHA => Some("R_PPC64_DTPREL16_HIGHA"),
+        abi::R_PPC64_JMP_IREL => Some("R_PPC64_JMP_IREL"),
+        abi::R_PPC64_IRELATIVE => Some("R_PPC64_IRELATIVE"),
+        abi::R_PPC64_REL16 => Some("R_PPC64_REL16"),
+        abi::R_PPC64_REL16_LO => Some("R_PPC64_REL16_LO"),
+        abi::R_PPC64_REL16_HI => Some("R_PPC64_REL16_HI"),
+        abi::R_PPC64_REL16_HA => Some("R_PPC64_REL16_HA"),
+        _ => None,
+    }
+}
+
+fn r_type_to_riscv_str(r_type: u32) -> Option<&'static str> {
+    match r_type {
+        abi::R_RISCV_NONE => Some("R_RISCV_NONE"),
+        abi::R_RISCV_32 => Some("R_RISCV_32"),
+        abi::R_RISCV_64 => Some("R_RISCV_64"),
+        abi::R_RISCV_RELATIVE => Some("R_RISCV_RELATIVE"),
+        abi::R_RISCV_COPY => Some("R_RISCV_COPY"),
+        abi::R_RISCV_JUMP_SLOT => Some("R_RISCV_JUMP_SLOT"),
+        abi::R_RISCV_TLS_DTPMOD32 => Some("R_RISCV_TLS_DTPMOD32"),
+        abi::R_RISCV_TLS_DTPMOD64 => Some("R_RISCV_TLS_DTPMOD64"),
+        abi::R_RISCV_TLS_DTPREL32 => Some("R_RISCV_TLS_DTPREL32"),
+        abi::R_RISCV_TLS_DTPREL64 => Some("R_RISCV_TLS_DTPREL64"),
+        abi::R_RISCV_TLS_TPREL32 => Some("R_RISCV_TLS_TPREL32"),
+        abi::R_RISCV_TLS_TPREL64 => Some("R_RISCV_TLS_TPREL64"),
+        abi::R_RISCV_BRANCH => Some("R_RISCV_BRANCH"),
+        abi::R_RISCV_JAL => Some("R_RISCV_JAL"),
+        abi::R_RISCV_CALL => Some("R_RISCV_CALL"),
+        abi::R_RISCV_CALL_PLT => Some("R_RISCV_CALL_PLT"),
+        abi::R_RISCV_GOT_HI20 => Some("R_RISCV_GOT_HI20"),
+        abi::R_RISCV_TLS_GOT_HI20 => Some("R_RISCV_TLS_GOT_HI20"),
+        abi::R_RISCV_TLS_GD_HI20 => Some("R_RISCV_TLS_GD_HI20"),
+        abi::R_RISCV_PCREL_HI20 => Some("R_RISCV_PCREL_HI20"),
+        abi::R_RISCV_PCREL_LO12_I => Some("R_RISCV_PCREL_LO12_I"),
+        abi::R_RISCV_PCREL_LO12_S => Some("R_RISCV_PCREL_LO12_S"),
+        abi::R_RISCV_HI20 => Some("R_RISCV_HI20"),
+        abi::R_RISCV_LO12_I => Some("R_RISCV_LO12_I"),
+        abi::R_RISCV_LO12_S => Some("R_RISCV_LO12_S"),
+        abi::R_RISCV_TPREL_HI20 => Some("R_RISCV_TPREL_HI20"),
+        abi::R_RISCV_TPREL_LO12_I => Some("R_RISCV_TPREL_LO12_I"),
+        abi::R_RISCV_TPREL_LO12_S => Some("R_RISCV_TPREL_LO12_S"),
+        abi::R_RISCV_TPREL_ADD => Some("R_RISCV_TPREL_ADD"),
+        abi::R_RISCV_ADD8 => Some("R_RISCV_ADD8"),
+        abi::R_RISCV_ADD16 => Some("R_RISCV_ADD16"),
+        abi::R_RISCV_ADD32 => Some("R_RISCV_ADD32"),
+        abi::R_RISCV_ADD64 => Some("R_RISCV_ADD64"),
+        abi::R_RISCV_SUB8 => Some("R_RISCV_SUB8"),
+        abi::R_RISCV_SUB16 => Some("R_RISCV_SUB16"),
+        abi::R_RISCV_SUB32 => Some("R_RISCV_SUB32"),
+        abi::R_RISCV_SUB64 => Some("R_RISCV_SUB64"),
+        abi::R_RISCV_ALIGN => Some("R_RISCV_ALIGN"),
+        abi::R_RISCV_RVC_BRANCH => Some("R_RISCV_RVC_BRANCH"),
+        abi::R_RISCV_RVC_JUMP => Some("R_RISCV_RVC_JUMP"),
+        abi::R_RISCV_RVC_LUI => Some("R_RISCV_RVC_LUI"),
+        abi::R_RISCV_RELAX => Some("R_RISCV_RELAX"),
+        abi::R_RISCV_SUB6 => Some("R_RISCV_SUB6"),
+        abi::R_RISCV_SET6 => Some("R_RISCV_SET6"),
+        abi::R_RISCV_SET8 => Some("R_RISCV_SET8"),
+        abi::R_RISCV_SET16 => Some("R_RISCV_SET16"),
+        abi::R_RISCV_SET32 => Some("R_RISCV_SET32"),
+        abi::R_RISCV_32_PCREL => Some("R_RISCV_32_PCREL"),
+        abi::R_RISCV_IRELATIVE => Some("R_RISCV_IRELATIVE"),
+        _ => None,
+    }
+}
+
+fn r_type_to_sparc_str(r_type: u32) -> Option<&'static str> {
+    match r_type {
+        abi::R_SPARC_NONE => Some("R_SPARC_NONE"),
+        abi::R_SPARC_8 => Some("R_SPARC_8"),
+        abi::R_SPARC_16 => Some("R_SPARC_16"),
+        abi::R_SPARC_32 => Some("R_SPARC_32"),
+        abi::R_SPARC_WDISP30 => Some("R_SPARC_WDISP30"),
+        abi::R_SPARC_HI22 => Some("R_SPARC_HI22"),
+        abi::R_SPARC_13 => Some("R_SPARC_13"),
+        abi::R_SPARC_LO10 => Some("R_SPARC_LO10"),
+        abi::R_SPARC_COPY => Some("R_SPARC_COPY"),
+        abi::R_SPARC_GLOB_DAT => Some("R_SPARC_GLOB_DAT"),
+        abi::R_SPARC_JMP_SLOT => Some("R_SPARC_JMP_SLOT"),
+        abi::R_SPARC_RELATIVE => Some("R_SPARC_RELATIVE"),
+        abi::R_SPARC_UA32 => Some("R_SPARC_UA32"),
+        abi::R_SPARC_64 => Some("R_SPARC_64"),
+        abi::R_SPARC_OLO10 => Some("R_SPARC_OLO10"),
+        _ => None,
+    }
+}
+
+/// Counts the bytes written through it, for [write_padded].
+struct CountingWriter<'a, 'b> {
+    f: &'a mut core::fmt::Formatter<'b>,
+    written: usize,
+}
+
+impl core::fmt::Write for CountingWriter<'_, '_> {
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        self.written += s.len();
+        self.f.write_str(s)
+    }
+}
+
+/// Write out the formatted text left-aligned and padded with spaces to at least `width`, for
+/// the readelf-style columns whose text is built up from several pieces.
+pub(crate) fn write_padded(
+    f: &mut core::fmt::Formatter<'_>,
+    width: usize,
+    args: core::fmt::Arguments<'_>,
+) -> core::fmt::Result {
+    use core::fmt::Write;
+
+    let mut writer = CountingWriter { f, written: 0 };
+    writer.write_fmt(args)?;
+    for _ in writer.written..width {
+        writer.f.write_char(' ')?;
+    }
+    Ok(())
+}
+
+/// Renders a header or table entry in the style of readelf's wide output, with the column
+/// widths readelf uses for the given ELF class and the processor-specific names for the given
+/// machine. Made by the `display()` methods on
+/// [SectionHeader](crate::section::SectionHeader::display),
+/// [ProgramHeader](crate::segment::ProgramHeader::display) and
+/// [Dyn](crate::dynamic::Dyn::display).
+#[derive(Debug, Clone, Copy)]
+pub struct ReadelfDisplay<'a, T> {
+    pub(crate) value: &'a T,
+    pub(crate) class: Class,
+    pub(crate) e_machine: u16,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn e_machine_names() {
+        assert_eq!(e_machine_to_human_str(abi::EM_RISCV), Some("RISC-V"));
+        assert_eq!(e_machine_to_str(abi::EM_LOONGARCH), Some("EM_LOONGARCH"));
+        assert_eq!(e_machine_to_human_str(abi::EM_LOONGARCH), Some("LoongArch"));
+        assert_eq!(e_machine_to_str(abi::EM_BPF), Some("EM_BPF"));
+        assert_eq!(e_machine_to_str(0xfff0), None);
+        assert_eq!(e_machine_to_string(0xfff0), "e_machine(0xfff0)");
+    }
+
+    #[test]
+    fn e_type_names() {
+        assert_eq!(e_type_to_str(abi::ET_DYN), Some("ET_DYN"));
+        assert_eq!(e_type_to_human_str(abi::ET_CORE), Some("Core file"));
+        assert_eq!(e_type_to_str(abi::ET_LOOS), None);
+        assert_eq!(e_type_to_string(abi::ET_LOOS + 2), "ET_LOOS+0x2");
+        assert_eq!(e_type_to_string(abi::ET_HIPROC), "ET_LOPROC+0xff");
+        assert_eq!(e_type_to_string(0x10), "e_type(0x10)");
+    }
+
+    #[test]
+    fn sh_type_names() {
+        assert_eq!(
+            sh_type_to_str(abi::SHT_GNU_HASH, abi::EM_X86_64),
+            Some("SHT_GNU_HASH")
+        );
+        assert_eq!(
+            sh_type_to_str(abi::SHT_GNU_SFRAME, abi::EM_X86_64),
+            Some("SHT_GNU_SFRAME")
+        );
+        assert_eq!(
+            sh_type_to_str(abi::SHT_RELR, abi::EM_NONE),
+            Some("SHT_RELR")
+        );
+
+        // The same processor-specific value means different things on different machines
+        assert_eq!(
+            sh_type_to_str(0x70000001, abi::EM_ARM),
+            Some("SHT_ARM_EXIDX")
+        );
+        assert_eq!(
+            sh_type_to_str(0x70000001, abi::EM_X86_64),
+            Some("SHT_X86_64_UNWIND")
+        );
+        assert_eq!(
+            sh_type_to_str(0x70000001, abi::EM_IA_64),
+            Some("SHT_IA_64_UNWIND")
+        );
+        assert_eq!(sh_type_to_str(0x70000001, abi::EM_RISCV), None);
+        assert_eq!(
+            sh_type_to_str(0x70000001, abi::EM_CSKY),
+            Some("SHT_CSKY_ATTRIBUTES")
+        );
+        assert_eq!(
+            sh_type_to_str(0x70000003, abi::EM_RISCV),
+            Some("SHT_RISCV_ATTRIBUTES")
+        );
+        assert_eq!(
+            sh_type_to_str(0x70000006, abi::EM_MIPS),
+            Some("SHT_MIPS_REGINFO")
+        );
+        assert_eq!(sh_type_to_str(0x70000006, abi::EM_ARM), None);
+    }
+
+    #[test]
+    fn sh_type_strings() {
+        assert_eq!(sh_type_to_string(abi::SHT_NOTE, abi::EM_NONE), "SHT_NOTE");
+        assert_eq!(sh_type_to_string(0x70000001, abi::EM_ARM), "SHT_ARM_EXIDX");
+        assert_eq!(
+            sh_type_to_string(0x70000001, abi::EM_RISCV),
+            "SHT_LOPROC+0x1"
+        );
+        assert_eq!(sh_type_to_string(0x60000010, abi::EM_NONE), "SHT_LOOS+0x10");
+        assert_eq!(
+            sh_type_to_string(0x80000002, abi::EM_NONE),
+            "SHT_LOUSER+0x2"
+        );
+        assert_eq!(sh_type_to_string(0x30, abi::EM_NONE), "sh_type(0x30)");
+    }
+
+    #[test]
+    fn d_tag_names() {
+        assert_eq!(
+            d_tag_to_str(abi::DT_GNU_HASH, abi::EM_NONE),
+            Some("DT_GNU_HASH")
+        );
+        assert_eq!(d_tag_to_str(abi::DT_RELR, abi::EM_NONE), Some("DT_RELR"));
+        assert_eq!(
+            d_tag_to_str(abi::DT_FLAGS_1, abi::EM_X86_64),
+            Some("DT_FLAGS_1")
+        );
+        assert_eq!(
+            d_tag_to_str(0x70000000, abi::EM_PPC64),
+            Some("DT_PPC64_GLINK")
+        );
+        assert_eq!(d_tag_to_str(0x70000000, abi::EM_PPC), Some("DT_PPC_GOT"));
+        assert_eq!(d_tag_to_str(0x70000000, abi::EM_X86_64), Some("DT_LOPROC"));
+        assert_eq!(
+            d_tag_to_str(0x70000013, abi::EM_MIPS),
+            Some("DT_MIPS_GOTSYM")
+        );
+        assert_eq!(d_tag_to_str(0x70000013, abi::EM_ARM), None);
+        assert_eq!(
+            d_tag_to_str(0x70000001, abi::EM_AARCH64),
+            Some("DT_AARCH64_BTI_PLT")
+        );
+    }
+
+    #[test]
+    fn d_tag_strings() {
+        assert_eq!(d_tag_to_string(abi::DT_NEEDED, abi::EM_NONE), "DT_NEEDED");
+        assert_eq!(d_tag_to_string(0x70000013, abi::EM_ARM), "DT_LOPROC+0x13");
+        assert_eq!(d_tag_to_string(0x6000000e, abi::EM_NONE), "DT_LOOS+0x1");
+        assert_eq!(d_tag_to_string(0x50, abi::EM_NONE), "d_tag(0x50)");
+    }
+
+    #[test]
+    fn note_type_names() {
+        // n_type 1 means different things depending on the note's owner
+        assert_eq!(
+            note_type_to_str(1, abi::ELF_NOTE_CORE, abi::ET_CORE),
+            Some("NT_PRSTATUS")
+        );
+        assert_eq!(
+            note_type_to_str(1, b"GNU", abi::ET_DYN),
+            Some("NT_GNU_ABI_TAG")
+        );
+        assert_eq!(
+            note_type_to_str(1, abi::ELF_NOTE_FREEBSD, abi::ET_EXEC),
+            Some("NT_FREEBSD_ABI_TAG")
+        );
+        assert_eq!(
+            note_type_to_str(1, abi::ELF_NOTE_NETBSD, abi::ET_EXEC),
+            Some("NT_NETBSD_IDENT")
+        );
+        assert_eq!(
+            note_type_to_str(1, abi::ELF_NOTE_OPENBSD, abi::ET_EXEC),
+            Some("NT_OPENBSD_IDENT")
+        );
+        assert_eq!(
+            note_type_to_str(0xcafe1a7e, abi::ELF_NOTE_FDO, abi::ET_DYN),
+            Some("NT_FDO_PACKAGING_METADATA")
+        );
+        assert_eq!(note_type_to_str(1, b"Xen\0", abi::ET_EXEC), None);
+
+        // The process state notes only appear in core files
+        assert_eq!(note_type_to_str(1, abi::ELF_NOTE_CORE, abi::ET_EXEC), None);
+        assert_eq!(
+            note_type_to_str(abi::NT_X86_XSTATE, abi::ELF_NOTE_LINUX, abi::ET_CORE),
+            Some("NT_X86_XSTATE")
+        );
+        assert_eq!(
+            note_type_to_str(abi::NT_ARM_PAC_MASK, b"LINUX", abi::ET_CORE),
+            Some("NT_ARM_PAC_MASK")
+        );
+        assert_eq!(
+            note_type_to_str(abi::NT_FILE, b"CORE\0\0\0\0", abi::ET_CORE),
+            Some("NT_FILE")
+        );
+        assert_eq!(
+            note_type_to_str(abi::NT_GNU_BUILD_ID, abi::ELF_NOTE_GNU, abi::ET_CORE),
+            Some("NT_GNU_BUILD_ID")
+        );
+    }
+
+    #[test]
+    fn p_type_names() {
+        assert_eq!(p_type_to_str(abi::PT_GNU_SFRAME), Some("PT_GNU_SFRAME"));
+        assert_eq!(p_type_to_string(0x6474e555), "p_type(0x6474e555)");
+    }
+
+    #[test]
+    fn r_type_names() {
+        assert_eq!(
+            r_type_to_str(abi::R_X86_64_JUMP_SLOT, abi::EM_X86_64),
+            Some("R_X86_64_JUMP_SLOT")
+        );
+        assert_eq!(
+            r_type_to_str(abi::R_AARCH64_GLOB_DAT, abi::EM_AARCH64),
+            Some("R_AARCH64_GLOB_DAT")
+        );
+        assert_eq!(
+            r_type_to_str(abi::R_SPARC_OLO10, abi::EM_SPARCV9),
+            Some("R_SPARC_OLO10")
+        );
+        // The same number means different things on different machines
+        assert_eq!(r_type_to_str(23, abi::EM_ARM), Some("R_ARM_RELATIVE"));
+        assert_eq!(r_type_to_str(8, abi::EM_386), Some("R_386_RELATIVE"));
+        assert_eq!(r_type_to_str(23, abi::EM_NONE), None);
+        assert_eq!(r_type_to_string(0x1234, abi::EM_X86_64), "r_type(0x1234)");
+    }
+
+    #[test]
+    fn e_osabi_names() {
+        assert_eq!(e_osabi_to_str(abi::ELFOSABI_GNU), Some("ELFOSABI_LINUX"));
+        assert_eq!(e_osabi_to_human_str(abi::ELFOSABI_GNU), Some("UNIX - GNU"));
+        assert_eq!(
+            e_osabi_to_str(abi::ELFOSABI_STANDALONE),
+            Some("ELFOSABI_STANDALONE")
+        );
+        assert_eq!(e_osabi_to_str(100), None);
+        assert_eq!(e_osabi_to_string(100), "e_osabi(0x64)");
+    }
+}
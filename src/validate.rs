@@ -0,0 +1,925 @@
+//! Structural validation of ELF objects, in the spirit of `eu-elflint`
+//!
+//! The parser only checks what it needs to in order to safely parse the structures that are
+//! asked for. [ElfBytes::validate] goes further and checks the cross-field invariants of the
+//! whole object, reporting each problem it finds as a [ValidationIssue].
+//!
+//! This includes the [abi::PT_PHDR] self-reference that loaders use to find the program
+//! header table in memory: it has to match `e_phoff` and be mapped by a [abi::PT_LOAD].
+//!
+//! Example usage:
+//! ```
+//! use elf::endian::AnyEndian;
+//! use elf::validate::Severity;
+//! use elf::ElfBytes;
+//!
+//! let path = std::path::PathBuf::from("sample-objects/basic.x86_64");
+//! let file_data = std::fs::read(path).expect("Could not read file.");
+//! let file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("Open test1");
+//!
+//! let issues = file.validate();
+//! for issue in issues.iter() {
+//!     println!("{issue}");
+//! }
+//! assert!(!issues.iter().any(|issue| issue.severity == Severity::Error));
+//! ```
+use crate::abi;
+use crate::endian::EndianParse;
+use crate::file::{Class, FileHeader, ELF32_EHDR_TAILSIZE, ELF64_EHDR_TAILSIZE};
+use crate::parse::ParseAt;
+use crate::section::{SectionHeader, SectionHeaderTable};
+use crate::segment::{ProgramHeader, SegmentTable};
+use crate::string_table::StringTable;
+use crate::symbol::Symbol;
+use crate::ElfBytes;
+
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+/// How serious a [ValidationIssue] is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Severity {
+    /// The object is unusual, but tools can generally still make sense of it.
+    Warning,
+    /// The object violates the ELF spec, and tools can't be expected to handle it.
+    Error,
+}
+
+/// Where in the object a [ValidationIssue] was found.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Location {
+    FileHeader,
+    /// The program header table as a whole
+    ProgramHeaders,
+    /// The section header table as a whole
+    SectionHeaders,
+    /// The program header at the given index
+    ProgramHeader(usize),
+    /// The section header at the given index
+    SectionHeader(usize),
+    /// The symbol at `index` in the symbol table in the section at `section`
+    Symbol {
+        section: usize,
+        index: usize,
+    },
+}
+
+/// The kinds of problems that [ElfBytes::validate] can find.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IssueKind {
+    /// The `EI_PAD` bytes at the end of `e_ident` aren't all zero
+    NonzeroIdentPadding([u8; abi::EI_NIDENT - abi::EI_PAD]),
+    /// `e_ehsize` isn't the size of the file header for the object's class
+    BadEhsize { found: u16, expected: u16 },
+    /// `e_phentsize` isn't the size of a program header for the object's class
+    BadPhentsize { found: u16, expected: u16 },
+    /// `e_shentsize` isn't the size of a section header for the object's class
+    BadShentsize { found: u16, expected: u16 },
+    /// The section header string table (`e_shstrndx`) couldn't be loaded
+    BadShstrndx(u32),
+    /// The structure's file data lies (at least partly) past the end of the file
+    OutOfFile { offset: u64, size: u64 },
+    /// The structure's file data overlaps with that of another structure
+    Overlaps(Location),
+    /// A section's `sh_link` is not a valid section index
+    LinkOutOfRange(u32),
+    /// A section's `sh_link` points at a section whose type doesn't make sense for the link
+    UnexpectedLinkType { link: u32, sh_type: u32 },
+    /// A section's `sh_info` is not a valid section index
+    InfoOutOfRange(u32),
+    /// A name offset (`sh_name` or `st_name`) doesn't resolve in its string table
+    BadName(u32),
+    /// A symbol's `st_shndx` is not a valid section index
+    ShndxOutOfRange(u16),
+    /// A segment's `p_align` is not zero or a power of two
+    BadAlignment(u64),
+    /// A segment's `p_vaddr` and `p_offset` are not congruent modulo its `p_align`
+    Misaligned {
+        p_vaddr: u64,
+        p_offset: u64,
+        p_align: u64,
+    },
+    /// The [abi::PT_PHDR] segment's file range isn't the program header table's range from
+    /// `e_phoff`, `e_phentsize` and `e_phnum`
+    PhdrMismatch {
+        p_offset: u64,
+        p_filesz: u64,
+        e_phoff: u64,
+        size: u64,
+    },
+    /// The [abi::PT_PHDR] segment's memory isn't covered by any [abi::PT_LOAD] segment, so
+    /// the program header table won't be in the loaded image
+    PhdrNotLoaded { p_vaddr: u64, p_memsz: u64 },
+    /// There's more than one [abi::PT_TLS] segment. The index is that of the first one
+    MultipleTls(usize),
+    /// A field of the reserved section header at index 0 isn't zero, even though it's not
+    /// holding one of the file header's extended values
+    NonzeroShdr0Field { field: &'static str, value: u64 },
+}
+
+/// A problem found in an ELF object by [ElfBytes::validate].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ValidationIssue {
+    pub severity: Severity,
+    pub location: Location,
+    pub kind: IssueKind,
+}
+
+impl core::fmt::Display for Severity {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Severity::Warning => write!(f, "warning"),
+            Severity::Error => write!(f, "error"),
+        }
+    }
+}
+
+impl core::fmt::Display for Location {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Location::FileHeader => write!(f, "file header"),
+            Location::ProgramHeaders => write!(f, "program header table"),
+            Location::SectionHeaders => write!(f, "section header table"),
+            Location::ProgramHeader(index) => write!(f, "program header {index}"),
+            Location::SectionHeader(index) => write!(f, "section header {index}"),
+            Location::Symbol { section, index } => {
+                write!(f, "symbol {index} in section {section}")
+            }
+        }
+    }
+}
+
+impl core::fmt::Display for IssueKind {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            IssueKind::NonzeroIdentPadding(padding) => {
+                write!(f, "e_ident padding is nonzero:")?;
+                for byte in padding {
+                    write!(f, " {byte:02x}")?;
+                }
+                Ok(())
+            }
+            IssueKind::BadEhsize { found, expected } => {
+                write!(f, "e_ehsize is {found}, expected {expected}")
+            }
+            IssueKind::BadPhentsize { found, expected } => {
+                write!(f, "e_phentsize is {found}, expected {expected}")
+            }
+            IssueKind::BadShentsize { found, expected } => {
+                write!(f, "e_shentsize is {found}, expected {expected}")
+            }
+            IssueKind::BadShstrndx(index) => {
+                write!(f, "section name string table {index} can't be loaded")
+            }
+            IssueKind::OutOfFile { offset, size } => {
+                write!(f, "data at {offset:#x} of size {size:#x} runs past end of file")
+            }
+            IssueKind::Overlaps(other) => write!(f, "data overlaps with {other}"),
+            IssueKind::LinkOutOfRange(link) => write!(f, "sh_link {link} is out of range"),
+            IssueKind::UnexpectedLinkType { link, sh_type } => {
+                write!(f, "sh_link {link} points at a section of unexpected type ")?;
+                fmt_sh_type(f, *sh_type)
+            }
+            IssueKind::InfoOutOfRange(info) => write!(f, "sh_info {info} is out of range"),
+            IssueKind::BadName(offset) => {
+                write!(f, "name offset {offset:#x} doesn't resolve in its string table")
+            }
+            IssueKind::ShndxOutOfRange(shndx) => write!(f, "st_shndx {shndx} is out of range"),
+            IssueKind::BadAlignment(align) => {
+                write!(f, "p_align {align:#x} is not a power of two")
+            }
+            IssueKind::Misaligned {
+                p_vaddr,
+                p_offset,
+                p_align,
+            } => write!(
+                f,
+                "p_vaddr {p_vaddr:#x} and p_offset {p_offset:#x} are not congruent modulo p_align {p_align:#x}"
+            ),
+            IssueKind::PhdrMismatch {
+                p_offset,
+                p_filesz,
+                e_phoff,
+                size,
+            } => write!(
+                f,
+                "PT_PHDR at {p_offset:#x} of size {p_filesz:#x} doesn't match the program header table at {e_phoff:#x} of size {size:#x}"
+            ),
+            IssueKind::PhdrNotLoaded { p_vaddr, p_memsz } => write!(
+                f,
+                "PT_PHDR at {p_vaddr:#x} of size {p_memsz:#x} isn't mapped by any PT_LOAD"
+            ),
+            IssueKind::MultipleTls(first) => {
+                write!(f, "there's already a PT_TLS at program header {first}")
+            }
+            IssueKind::NonzeroShdr0Field { field, value } => {
+                write!(f, "{field} of the reserved header is {value:#x}, expected 0")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "to_str")]
+fn fmt_sh_type(f: &mut core::fmt::Formatter<'_>, sh_type: u32) -> core::fmt::Result {
+    match crate::to_str::sh_type_to_str(sh_type, abi::EM_NONE) {
+        Some(name) => write!(f, "{name}"),
+        None => write!(f, "{sh_type:#x}"),
+    }
+}
+
+#[cfg(not(feature = "to_str"))]
+fn fmt_sh_type(f: &mut core::fmt::Formatter<'_>, sh_type: u32) -> core::fmt::Result {
+    write!(f, "{sh_type:#x}")
+}
+
+impl core::fmt::Display for ValidationIssue {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}: {}: {}", self.severity, self.location, self.kind)
+    }
+}
+
+/// Collects up the issues found while validating an object.
+struct Validator {
+    issues: Vec<ValidationIssue>,
+}
+
+impl Validator {
+    fn error(&mut self, location: Location, kind: IssueKind) {
+        self.issues.push(ValidationIssue {
+            severity: Severity::Error,
+            location,
+            kind,
+        });
+    }
+
+    fn warning(&mut self, location: Location, kind: IssueKind) {
+        self.issues.push(ValidationIssue {
+            severity: Severity::Warning,
+            location,
+            kind,
+        });
+    }
+}
+
+/// The section types that a section of type `sh_type` is expected to link to (if any).
+fn expected_link_types(sh_type: u32) -> Option<&'static [u32]> {
+    match sh_type {
+        abi::SHT_SYMTAB | abi::SHT_DYNSYM => Some(&[abi::SHT_STRTAB]),
+        abi::SHT_DYNAMIC | abi::SHT_GNU_VERDEF | abi::SHT_GNU_VERNEED => Some(&[abi::SHT_STRTAB]),
+        abi::SHT_REL | abi::SHT_RELA | abi::SHT_HASH | abi::SHT_GNU_HASH => {
+            Some(&[abi::SHT_DYNSYM, abi::SHT_SYMTAB])
+        }
+        abi::SHT_GNU_VERSYM => Some(&[abi::SHT_DYNSYM]),
+        abi::SHT_SYMTAB_SHNDX | abi::SHT_GROUP => Some(&[abi::SHT_SYMTAB]),
+        _ => None,
+    }
+}
+
+/// Check everything that can be checked about the object. See [ElfBytes::validate].
+pub(crate) fn validate<E: EndianParse>(
+    file: &ElfBytes<'_, E>,
+    file_len: usize,
+) -> Vec<ValidationIssue> {
+    let mut v = Validator { issues: Vec::new() };
+    let ehdr = &file.ehdr;
+    let file_len = file_len as u64;
+
+    // File header fields
+    if ehdr.ident_padding().iter().any(|byte| *byte != 0) {
+        let mut padding = [0u8; abi::EI_NIDENT - abi::EI_PAD];
+        padding.copy_from_slice(ehdr.ident_padding());
+        v.warning(
+            Location::FileHeader,
+            IssueKind::NonzeroIdentPadding(padding),
+        );
+    }
+    let ehsize = abi::EI_NIDENT
+        + match ehdr.class {
+            Class::ELF32 => ELF32_EHDR_TAILSIZE,
+            Class::ELF64 => ELF64_EHDR_TAILSIZE,
+        };
+    if ehdr.e_ehsize as usize != ehsize {
+        v.error(
+            Location::FileHeader,
+            IssueKind::BadEhsize {
+                found: ehdr.e_ehsize,
+                expected: ehsize as u16,
+            },
+        );
+    }
+
+    // The (start, end, location) of all the structures with file data, for finding overlaps
+    let mut ranges: Vec<(u64, u64, Location)> = Vec::new();
+    ranges.push((0, ehsize as u64, Location::FileHeader));
+
+    if let Some(phdrs) = file.segments() {
+        let phentsize = ProgramHeader::size_for(ehdr.class);
+        if ehdr.e_phentsize as usize != phentsize {
+            v.error(
+                Location::FileHeader,
+                IssueKind::BadPhentsize {
+                    found: ehdr.e_phentsize,
+                    expected: phentsize as u16,
+                },
+            );
+        }
+        let size = (phdrs.len() * phentsize) as u64;
+        ranges.push((
+            ehdr.e_phoff,
+            ehdr.e_phoff.saturating_add(size),
+            Location::ProgramHeaders,
+        ));
+
+        let mut tls = None;
+        for (index, phdr) in phdrs.iter().enumerate() {
+            validate_phdr(&mut v, index, &phdr, file_len);
+            match phdr.p_type {
+                abi::PT_PHDR => {
+                    validate_phdr_segment(&mut v, index, &phdr, ehdr.e_phoff, size, &phdrs);
+                }
+                abi::PT_TLS => match tls {
+                    Some(first) => {
+                        v.error(
+                            Location::ProgramHeader(index),
+                            IssueKind::MultipleTls(first),
+                        );
+                    }
+                    None => tls = Some(index),
+                },
+                _ => {}
+            }
+        }
+    }
+
+    let shdrs = match file.section_headers() {
+        Some(shdrs) => shdrs,
+        None => {
+            find_overlaps(&mut v, ranges);
+            return v.issues;
+        }
+    };
+
+    let shentsize = SectionHeader::size_for(ehdr.class);
+    if ehdr.e_shentsize as usize != shentsize {
+        v.error(
+            Location::FileHeader,
+            IssueKind::BadShentsize {
+                found: ehdr.e_shentsize,
+                expected: shentsize as u16,
+            },
+        );
+    }
+    let size = (shdrs.len() * shentsize) as u64;
+    ranges.push((
+        ehdr.e_shoff,
+        ehdr.e_shoff.saturating_add(size),
+        Location::SectionHeaders,
+    ));
+
+    let shstrtab = match file.section_headers_with_strtab() {
+        Ok((_, shstrtab)) => shstrtab,
+        Err(_) => {
+            v.error(
+                Location::FileHeader,
+                IssueKind::BadShstrndx(ehdr.e_shstrndx as u32),
+            );
+            None
+        }
+    };
+
+    if let Ok(shdr0) = shdrs.get(0) {
+        validate_shdr0(&mut v, ehdr, &shdr0);
+    }
+
+    for (index, shdr) in shdrs.iter().enumerate() {
+        let location = Location::SectionHeader(index);
+        if let Some(shstrtab) = shstrtab {
+            if shstrtab.get_raw(shdr.sh_name as usize).is_err() {
+                v.error(location, IssueKind::BadName(shdr.sh_name));
+            }
+        }
+
+        if index != 0 && shdr.sh_type != abi::SHT_NULL && shdr.sh_type != abi::SHT_NOBITS {
+            let end = shdr.sh_offset.checked_add(shdr.sh_size);
+            if end.map_or(true, |end| end > file_len) {
+                v.error(
+                    location,
+                    IssueKind::OutOfFile {
+                        offset: shdr.sh_offset,
+                        size: shdr.sh_size,
+                    },
+                );
+            } else if shdr.sh_size != 0 {
+                ranges.push((shdr.sh_offset, shdr.sh_offset + shdr.sh_size, location));
+            }
+        }
+
+        validate_links(&mut v, index, &shdr, &shdrs);
+
+        if shdr.sh_type == abi::SHT_SYMTAB || shdr.sh_type == abi::SHT_DYNSYM {
+            validate_symbols(&mut v, file, index, &shdr, &shdrs);
+        }
+    }
+
+    find_overlaps(&mut v, ranges);
+    v.issues
+}
+
+fn validate_phdr(v: &mut Validator, index: usize, phdr: &ProgramHeader, file_len: u64) {
+    let location = Location::ProgramHeader(index);
+    let end = phdr.p_offset.checked_add(phdr.p_filesz);
+    if end.map_or(true, |end| end > file_len) {
+        v.error(
+            location,
+            IssueKind::OutOfFile {
+                offset: phdr.p_offset,
+                size: phdr.p_filesz,
+            },
+        );
+    }
+
+    if phdr.p_align > 1 {
+        if !phdr.p_align.is_power_of_two() {
+            v.error(location, IssueKind::BadAlignment(phdr.p_align));
+        } else if phdr.p_vaddr % phdr.p_align != phdr.p_offset % phdr.p_align {
+            v.error(
+                location,
+                IssueKind::Misaligned {
+                    p_vaddr: phdr.p_vaddr,
+                    p_offset: phdr.p_offset,
+                    p_align: phdr.p_align,
+                },
+            );
+        }
+    }
+}
+
+/// Section header 0 is all zeros, except for the fields holding the file header's extended
+/// values, see [ElfBytes::shdr0].
+fn validate_shdr0<E: EndianParse>(v: &mut Validator, ehdr: &FileHeader<E>, shdr0: &SectionHeader) {
+    let extended = |used: bool, value: u64| if used { 0 } else { value };
+    let fields = [
+        ("sh_name", shdr0.sh_name as u64),
+        ("sh_type", shdr0.sh_type as u64),
+        ("sh_flags", shdr0.sh_flags),
+        ("sh_addr", shdr0.sh_addr),
+        ("sh_offset", shdr0.sh_offset),
+        ("sh_size", extended(ehdr.e_shnum == 0, shdr0.sh_size)),
+        (
+            "sh_link",
+            extended(ehdr.e_shstrndx == abi::SHN_XINDEX, shdr0.sh_link as u64),
+        ),
+        (
+            "sh_info",
+            extended(ehdr.e_phnum == abi::PN_XNUM, shdr0.sh_info as u64),
+        ),
+        ("sh_addralign", shdr0.sh_addralign),
+        ("sh_entsize", shdr0.sh_entsize),
+    ];
+    for (field, value) in fields {
+        if value != 0 {
+            v.error(
+                Location::SectionHeader(0),
+                IssueKind::NonzeroShdr0Field { field, value },
+            );
+        }
+    }
+}
+
+/// The loader finds the program header table in memory through PT_PHDR, so it has to say
+/// where the table really is and be part of a loaded segment.
+fn validate_phdr_segment<E: EndianParse>(
+    v: &mut Validator,
+    index: usize,
+    phdr: &ProgramHeader,
+    e_phoff: u64,
+    size: u64,
+    phdrs: &SegmentTable<'_, E>,
+) {
+    let location = Location::ProgramHeader(index);
+    if phdr.p_offset != e_phoff || phdr.p_filesz != size {
+        v.error(
+            location,
+            IssueKind::PhdrMismatch {
+                p_offset: phdr.p_offset,
+                p_filesz: phdr.p_filesz,
+                e_phoff,
+                size,
+            },
+        );
+    }
+
+    let loaded = phdrs.iter().any(|load| {
+        load.p_type == abi::PT_LOAD
+            && phdr.p_vaddr >= load.p_vaddr
+            && phdr.p_vaddr - load.p_vaddr <= load.p_memsz
+            && phdr.p_memsz <= load.p_memsz - (phdr.p_vaddr - load.p_vaddr)
+    });
+    if !loaded {
+        v.error(
+            location,
+            IssueKind::PhdrNotLoaded {
+                p_vaddr: phdr.p_vaddr,
+                p_memsz: phdr.p_memsz,
+            },
+        );
+    }
+}
+
+fn validate_links<E: EndianParse>(
+    v: &mut Validator,
+    index: usize,
+    shdr: &SectionHeader,
+    shdrs: &SectionHeaderTable<'_, E>,
+) {
+    let location = Location::SectionHeader(index);
+    if let Some(expected) = expected_link_types(shdr.sh_type) {
+        match shdrs.get(shdr.sh_link as usize) {
+            // Relocations against absolute values only might not have any symbol table
+            Ok(_) if shdr.sh_link == 0 && matches!(shdr.sh_type, abi::SHT_REL | abi::SHT_RELA) => {}
+            Ok(link) if shdr.sh_link != 0 && expected.contains(&link.sh_type) => {}
+            Ok(link) => v.warning(
+                location,
+                IssueKind::UnexpectedLinkType {
+                    link: shdr.sh_link,
+                    sh_type: link.sh_type,
+                },
+            ),
+            Err(_) => v.error(location, IssueKind::LinkOutOfRange(shdr.sh_link)),
+        }
+    }
+
+    let info_is_index = shdr.sh_flags & abi::SHF_INFO_LINK as u64 != 0
+        || (matches!(shdr.sh_type, abi::SHT_REL | abi::SHT_RELA) && shdr.sh_info != 0);
+    if info_is_index && shdr.sh_info as usize >= shdrs.len() {
+        v.error(location, IssueKind::InfoOutOfRange(shdr.sh_info));
+    }
+}
+
+fn validate_symbols<E: EndianParse>(
+    v: &mut Validator,
+    file: &ElfBytes<'_, E>,
+    section: usize,
+    shdr: &SectionHeader,
+    shdrs: &SectionHeaderTable<'_, E>,
+) {
+    let symtab = match file.section_data(shdr) {
+        Ok((data, None)) if shdr.sh_entsize as usize >= Symbol::size_for(file.ehdr.class) => {
+            crate::symbol::SymbolTable::with_entsize(
+                file.ehdr.endianness,
+                file.ehdr.class,
+                shdr.sh_entsize as usize,
+                data,
+            )
+        }
+        _ => return,
+    };
+    let strtab = shdrs
+        .get(shdr.sh_link as usize)
+        .ok()
+        .filter(|strtab| strtab.sh_type == abi::SHT_STRTAB)
+        .and_then(|strtab| file.section_data(&strtab).ok())
+        .map(|(data, _)| StringTable::new(data));
+
+    for (index, sym) in symtab.iter().enumerate() {
+        let location = Location::Symbol { section, index };
+        if let Some(strtab) = strtab {
+            if strtab.get_raw(sym.st_name as usize).is_err() {
+                v.error(location, IssueKind::BadName(sym.st_name));
+            }
+        }
+
+        if let Some(shndx) = sym.shndx().section() {
+            if shndx as usize >= shdrs.len() {
+                v.error(location, IssueKind::ShndxOutOfRange(shndx));
+            }
+        }
+    }
+}
+
+/// Report every structure whose file range overlaps an earlier one.
+fn find_overlaps(v: &mut Validator, mut ranges: Vec<(u64, u64, Location)>) {
+    ranges.sort_by_key(|&(start, end, _)| (start, end));
+
+    // Sweep through in order of start offset, tracking the range that reaches the furthest
+    let mut furthest: Option<(u64, Location)> = None;
+    for (start, end, location) in ranges {
+        match furthest {
+            Some((furthest_end, other)) if start < furthest_end => {
+                v.error(location, IssueKind::Overlaps(other));
+                if end > furthest_end {
+                    furthest = Some((end, location));
+                }
+            }
+            _ => furthest = Some((end, location)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::endian::AnyEndian;
+    use crate::file::FileHeader;
+
+    fn sample(path: &str) -> (Vec<u8>, FileHeader<AnyEndian>) {
+        let data = std::fs::read(path).expect("Could not read file.");
+        let ehdr = ElfBytes::<AnyEndian>::minimal_parse(&data)
+            .expect("should parse")
+            .ehdr;
+        (data, ehdr)
+    }
+
+    fn validate(data: &[u8]) -> Vec<ValidationIssue> {
+        ElfBytes::<AnyEndian>::minimal_parse(data)
+            .expect("should parse")
+            .validate()
+    }
+
+    fn section(data: &[u8], name: &str) -> (usize, SectionHeader) {
+        let file = ElfBytes::<AnyEndian>::minimal_parse(data).expect("should parse");
+        let (shdrs, strtab) = file.section_headers_with_strtab().expect("should parse");
+        let strtab = strtab.unwrap();
+        shdrs
+            .unwrap()
+            .iter()
+            .enumerate()
+            .find(|(_, shdr)| matches!(strtab.get(shdr.sh_name as usize), Ok(n) if n == name))
+            .expect("should find section")
+    }
+
+    #[test]
+    fn well_formed_objects_have_no_errors() {
+        for path in [
+            "sample-objects/basic.x86_64",
+            "sample-objects/symver.x86_64.so",
+            "sample-objects/symver.aarch64.so",
+            "sample-objects/symver.m68k.so",
+        ] {
+            let (data, _) = sample(path);
+            let issues = validate(&data);
+            assert_eq!(issues, [], "{path}");
+        }
+    }
+
+    #[test]
+    fn bad_header_sizes() {
+        let (mut data, mut ehdr) = sample("sample-objects/basic.x86_64");
+        ehdr.e_ehsize = 60;
+        ehdr.write_back(&mut data).expect("should write");
+
+        let issues = validate(&data);
+        assert_eq!(
+            issues,
+            [ValidationIssue {
+                severity: Severity::Error,
+                location: Location::FileHeader,
+                kind: IssueKind::BadEhsize {
+                    found: 60,
+                    expected: 64
+                },
+            }]
+        );
+        assert_eq!(
+            format!("{}", issues[0]),
+            "error: file header: e_ehsize is 60, expected 64"
+        );
+    }
+
+    #[test]
+    fn nonzero_ident_padding() {
+        let (mut data, _) = sample("sample-objects/basic.x86_64");
+        data[abi::EI_PAD + 2] = 0x42;
+        data[abi::EI_NIDENT - 1] = 0xff;
+
+        let issues = validate(&data);
+        assert_eq!(
+            issues,
+            [ValidationIssue {
+                severity: Severity::Warning,
+                location: Location::FileHeader,
+                kind: IssueKind::NonzeroIdentPadding([0, 0, 0x42, 0, 0, 0, 0xff]),
+            }]
+        );
+        assert_eq!(
+            format!("{}", issues[0]),
+            "warning: file header: e_ident padding is nonzero: 00 00 42 00 00 00 ff"
+        );
+    }
+
+    #[test]
+    fn overlapping_sections() {
+        let (mut data, ehdr) = sample("sample-objects/basic.x86_64");
+        let (text_index, text) = section(&data, ".text");
+        let (index, mut shdr) = section(&data, ".rodata");
+        shdr.sh_offset = text.sh_offset + 4;
+        ehdr.patch_section_header(&mut data, index, &shdr)
+            .expect("should patch");
+
+        let issues = validate(&data);
+        assert!(issues.contains(&ValidationIssue {
+            severity: Severity::Error,
+            location: Location::SectionHeader(index),
+            kind: IssueKind::Overlaps(Location::SectionHeader(text_index)),
+        }));
+    }
+
+    #[test]
+    fn bad_links() {
+        let (mut data, ehdr) = sample("sample-objects/symver.x86_64.so");
+        let (dynsym_index, mut dynsym) = section(&data, ".dynsym");
+        let (text_index, _) = section(&data, ".text");
+        dynsym.sh_link = text_index as u32;
+        ehdr.patch_section_header(&mut data, dynsym_index, &dynsym)
+            .expect("should patch");
+
+        let (rela_index, mut rela) = section(&data, ".rela.plt");
+        rela.sh_link = 1000;
+        rela.sh_info = 2000;
+        ehdr.patch_section_header(&mut data, rela_index, &rela)
+            .expect("should patch");
+
+        let issues = validate(&data);
+        assert!(issues.contains(&ValidationIssue {
+            severity: Severity::Warning,
+            location: Location::SectionHeader(dynsym_index),
+            kind: IssueKind::UnexpectedLinkType {
+                link: text_index as u32,
+                sh_type: abi::SHT_PROGBITS,
+            },
+        }));
+        assert!(issues.contains(&ValidationIssue {
+            severity: Severity::Error,
+            location: Location::SectionHeader(rela_index),
+            kind: IssueKind::LinkOutOfRange(1000),
+        }));
+        assert!(issues.contains(&ValidationIssue {
+            severity: Severity::Error,
+            location: Location::SectionHeader(rela_index),
+            kind: IssueKind::InfoOutOfRange(2000),
+        }));
+    }
+
+    #[test]
+    fn bad_symbols() {
+        let (mut data, ehdr) = sample("sample-objects/basic.x86_64");
+        let (symtab_index, symtab) = section(&data, ".symtab");
+        let entsize = symtab.sh_entsize as usize;
+
+        // Point symbol 1's name past the end of the strtab and its shndx past the shdrs
+        let mut offset = symtab.sh_offset as usize + entsize;
+        let mut sym = Symbol::parse_at(ehdr.endianness, ehdr.class, &mut offset, &data)
+            .expect("should parse");
+        sym.st_name = 0xffff;
+        sym.st_shndx = 500;
+        let mut offset = symtab.sh_offset as usize + entsize;
+        crate::parse::SerializeAt::serialize_at(
+            &sym,
+            ehdr.endianness,
+            ehdr.class,
+            &mut offset,
+            &mut data,
+        )
+        .expect("should write");
+
+        let issues = validate(&data);
+        let location = Location::Symbol {
+            section: symtab_index,
+            index: 1,
+        };
+        assert_eq!(
+            issues,
+            [
+                ValidationIssue {
+                    severity: Severity::Error,
+                    location,
+                    kind: IssueKind::BadName(0xffff),
+                },
+                ValidationIssue {
+                    severity: Severity::Error,
+                    location,
+                    kind: IssueKind::ShndxOutOfRange(500),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn misaligned_segment() {
+        let (mut data, ehdr) = sample("sample-objects/basic.x86_64");
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&data).expect("should parse");
+        let (index, mut phdr) = file
+            .segments()
+            .unwrap()
+            .iter()
+            .enumerate()
+            .find(|(_, phdr)| phdr.p_type == abi::PT_LOAD)
+            .unwrap();
+        phdr.p_vaddr += 8;
+        ehdr.patch_program_header(&mut data, index, &phdr)
+            .expect("should patch");
+
+        let issues = validate(&data);
+        assert_eq!(
+            issues,
+            [ValidationIssue {
+                severity: Severity::Error,
+                location: Location::ProgramHeader(index),
+                kind: IssueKind::Misaligned {
+                    p_vaddr: phdr.p_vaddr,
+                    p_offset: phdr.p_offset,
+                    p_align: phdr.p_align,
+                },
+            }]
+        );
+    }
+
+    #[test]
+    fn nonzero_shdr0() {
+        let (mut data, ehdr) = sample("sample-objects/basic.x86_64");
+        let shdr0 = SectionHeader {
+            sh_flags: abi::SHF_ALLOC as u64,
+            // sh_size only holds the section count when e_shnum is 0
+            sh_size: ehdr.e_shnum as u64,
+            ..Default::default()
+        };
+        ehdr.patch_section_header(&mut data, 0, &shdr0)
+            .expect("should patch");
+
+        let issues = validate(&data);
+        assert_eq!(
+            issues,
+            [
+                ValidationIssue {
+                    severity: Severity::Error,
+                    location: Location::SectionHeader(0),
+                    kind: IssueKind::NonzeroShdr0Field {
+                        field: "sh_flags",
+                        value: 2,
+                    },
+                },
+                ValidationIssue {
+                    severity: Severity::Error,
+                    location: Location::SectionHeader(0),
+                    kind: IssueKind::NonzeroShdr0Field {
+                        field: "sh_size",
+                        value: 31,
+                    },
+                },
+            ]
+        );
+        assert_eq!(
+            format!("{}", issues[1]),
+            "error: section header 0: sh_size of the reserved header is 0x1f, expected 0"
+        );
+    }
+
+    #[test]
+    fn bad_phdr_segment() {
+        let (mut data, ehdr) = sample("sample-objects/basic.x86_64");
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&data).expect("should parse");
+        let (index, mut phdr) = file
+            .segments()
+            .unwrap()
+            .iter()
+            .enumerate()
+            .find(|(_, phdr)| phdr.p_type == abi::PT_PHDR)
+            .unwrap();
+        let size = phdr.p_filesz;
+
+        // Claim one header fewer than e_phnum, and move it below the lowest PT_LOAD
+        phdr.p_filesz -= ehdr.e_phentsize as u64;
+        phdr.p_vaddr = 0x1000;
+        ehdr.patch_program_header(&mut data, index, &phdr)
+            .expect("should patch");
+
+        let issues = validate(&data);
+        assert_eq!(
+            issues,
+            [
+                ValidationIssue {
+                    severity: Severity::Error,
+                    location: Location::ProgramHeader(index),
+                    kind: IssueKind::PhdrMismatch {
+                        p_offset: 0x40,
+                        p_filesz: size - 56,
+                        e_phoff: 0x40,
+                        size,
+                    },
+                },
+                ValidationIssue {
+                    severity: Severity::Error,
+                    location: Location::ProgramHeader(index),
+                    kind: IssueKind::PhdrNotLoaded {
+                        p_vaddr: 0x1000,
+                        p_memsz: size,
+                    },
+                },
+            ]
+        );
+        assert_eq!(
+            format!("{}", issues[1]),
+            "error: program header 0: PT_PHDR at 0x1000 of size 0x1c0 isn't mapped by any PT_LOAD"
+        );
+    }
+}